@@ -0,0 +1,146 @@
+//! Minimal Embedder.
+//!
+//! The intended way to host the kernel in another program:
+//! 1. Build a `ReactorConfig` and a Reactor (stores injected, nothing read from disk).
+//! 2. Feed input as `Event`s. Here: typed text instead of a microphone.
+//! 3. Call `tick_step` on a fixed cadence and hand each `EffectBatch` to an `EffectExecutor`.
+//! 4. Read the published `StateView` without touching the Reactor.
+//!
+//! Run with `cargo run --example minimal_embedder`. Type a line, or `quit` to exit.
+
+use std::io::BufRead;
+use std::time::Duration;
+
+use nexus::kernel::effects::EffectExecutor;
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::speech::planner::SpeechIntent;
+use nexus::memory::{FileSemanticStore, InMemoryEpisodicStore};
+
+/// "TTS" that prints to stdout. A real embedder would synthesize and play audio here,
+/// and report `AudioStatus` back into the kernel channel.
+struct StdoutExecutor {
+    // Kernel channel, for answers the kernel waits on
+    tx: tokio::sync::mpsc::Sender<Event>,
+}
+
+impl EffectExecutor for StdoutExecutor {
+    fn execute(&mut self, effect: SideEffect) {
+        match effect {
+            SideEffect::SpawnAudio(_, text) => println!("nexus> {}", text),
+            SideEffect::RequestSpeech { intent, .. } => match intent {
+                SpeechIntent::Clarification(seed) => println!("nexus> (clarify: {})", seed),
+                SpeechIntent::Confirmation(seed) => println!("nexus> (confirm: {})", seed),
+                SpeechIntent::Offer(seed) => println!("nexus> (offer: {})", seed),
+            },
+            SideEffect::StopAudio => println!("nexus> (stops talking)"),
+            SideEffect::PlayCue(cue) => println!("nexus> ({})", cue.sound()),
+            SideEffect::MaintenanceProgress(progress) => println!("nexus> (maintenance {}/{})", progress.completed, progress.total),
+            SideEffect::AskMemoryConsent(request) => println!("nexus> May I remember that? ({:?})", request.key),
+            SideEffect::DismissConsent(response) => println!("nexus> (heard: {:?})", response.state),
+            // Action tools (home automation) need a driver; see `integrations::home_driver`
+            SideEffect::InvokeTool { call, .. } => println!("nexus> (would run {:?})", call),
+            SideEffect::CancelTool { .. } => {}
+            // No LLM here: the kernel writes the template summary after its timeout
+            SideEffect::RequestSummary { .. } => {}
+            // No audio capture or transcription in a text-only embedder
+            SideEffect::RequestTranscription { .. }
+            | SideEffect::CancelTranscription { .. }
+            | SideEffect::SetCaptureFidelity(_) => {}
+            // Maintenance mode (`EnterMaintenance`): run the job and answer, or it times out as failed.
+            // A real embedder runs it on a background thread.
+            SideEffect::RunMaintenance(task) => {
+                let result = InputContent::MaintenanceResult(task.run());
+                let answer = Event::Input(InputEvent { source: "Embedder".to_string(), content: result, captured_at: None });
+                if self.tx.try_send(answer).is_err() {
+                    eprintln!("nexus! maintenance result not delivered");
+                }
+            }
+            // Stores write back off the tick; a real embedder saves on a background thread
+            SideEffect::PersistCalibration(snapshot) => {
+                if let Err(e) = snapshot.save() {
+                    eprintln!("nexus! calibration not saved: {}", e);
+                }
+            }
+            SideEffect::PersistCorrections(snapshot) => {
+                if let Err(e) = snapshot.save() {
+                    eprintln!("nexus! correction ledger not saved: {}", e);
+                }
+            }
+            SideEffect::PersistProfiles(snapshot) => {
+                if let Err(e) = snapshot.save() {
+                    eprintln!("nexus! profiles not saved: {}", e);
+                }
+            }
+            // Telemetry export is off unless `ReactorConfig::telemetry_export` turns it on
+            SideEffect::ExportTelemetry(export) => {
+                if let Err(e) = export.append() {
+                    eprintln!("nexus! telemetry snapshot not saved: {:?}", e);
+                }
+            }
+            SideEffect::Diagnostic { severity, code, context } => {
+                nexus::kernel::diagnostic::trace(severity, code, &context);
+                if severity.toast() {
+                    eprintln!("nexus! {} ({})", code.as_str(), context);
+                }
+            }
+            #[allow(deprecated)]
+            SideEffect::Log(_) => {}
+        }
+    }
+}
+
+fn typed(text: &str) -> Event {
+    Event::Input(InputEvent {
+        source: "Embedder".to_string(),
+        content: InputContent::Text(text.to_string()),
+        captured_at: None,
+    })
+}
+
+#[tokio::main]
+async fn main() {
+    // 1. Config + Reactor. Offline: reflex planning only, stores kept out of the working directory.
+    let config = ReactorConfig { llm_planning: false, ..Default::default() };
+    let semantic_path = std::env::temp_dir().join("nexus_minimal_embedder.json");
+    let (tx, rx) = tokio::sync::mpsc::channel(100);
+    let mut reactor = Reactor::builder(rx, tx.clone())
+        .config(config)
+        .episodic_store(Box::new(InMemoryEpisodicStore::new()))
+        .semantic_store(Box::new(FileSemanticStore::new(semantic_path)))
+        .build();
+    let view = reactor.subscribe_view();
+
+    let mut executor = StdoutExecutor { tx: tx.clone() };
+
+    // 2. Typed input on its own thread, forwarded into the kernel channel
+    let input = std::thread::spawn(move || {
+        for line in std::io::stdin().lock().lines().map_while(Result::ok) {
+            if line.trim() == "quit" || tx.blocking_send(typed(&line)).is_err() {
+                break;
+            }
+        }
+    });
+
+    // 3. Tick loop: drain events, step, execute
+    let mut cadence = tokio::time::interval(Duration::from_millis(nexus::kernel::time::TICK_MS));
+    loop {
+        cadence.tick().await;
+        let mut events = Vec::new();
+        while let Ok(event) = reactor.receiver.try_recv() {
+            events.push(event);
+        }
+        if events.is_empty() && input.is_finished() {
+            break;
+        }
+        let had_input = !events.is_empty();
+        executor.execute_batch(reactor.tick_step(events));
+
+        // 4. State view: read-only, never blocks the Reactor
+        if had_input {
+            let v = view.borrow().clone();
+            println!("   [tick {} | {:?} | focus {:?}]", v.tick.frame, v.presence, v.intent_focus);
+        }
+    }
+}
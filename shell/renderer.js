@@ -43,6 +43,11 @@ Speaking will immediately stop it.
 
 You don't need to say "stop."`,
 
+    `Nexus listens through your microphone.
+
+Your system may ask for permission.
+If you say no, you can still type.`,
+
     `Nexus forgets by default.
 
 It may remember patterns over time,
@@ -51,8 +56,16 @@ but it will ask before keeping anything important.
 It can correct itself.`
 ];
 
+// Screen that asks the OS for microphone access (see `request_capture_permission`)
+const MIC_SCREEN = 3;
+const MIC_DENIED_TEXT = `Microphone access is off.
+
+You can turn it on later in your system's privacy settings.
+Until then, Nexus will not listen. You can still type.`;
+
 const OnboardingManager = {
     currentScreen: 0,
+    micRequested: false,
 
     async check() {
         const completed = await invoke('get_onboarding_status');
@@ -70,15 +83,42 @@ const OnboardingManager = {
         console.log(`[Onboarding] Rendering screen ${this.currentScreen}`);
         onboardingText.textContent = ONBOARDING_SCREENS[this.currentScreen];
         // Final screen has "Begin" button
-        if (this.currentScreen === ONBOARDING_SCREENS.length - 1) {
+        if (this.currentScreen === MIC_SCREEN && !this.micRequested) {
+            onboardingButton.innerText = 'Allow microphone';
+        } else if (this.currentScreen === ONBOARDING_SCREENS.length - 1) {
             onboardingButton.innerText = 'Begin';
         } else {
             onboardingButton.innerText = 'Continue';
         }
     },
 
+    async requestMic() {
+        this.micRequested = true;
+        onboardingButton.disabled = true;
+        await invoke('request_capture_permission');
+        // The capture probe reports within ~1.5s; the kernel mirrors it into the State View
+        let permission = 'Unknown';
+        for (let i = 0; i < 10 && (permission === 'Unknown' || permission === 'NotDetermined'); i++) {
+            await new Promise((resolve) => setTimeout(resolve, 300));
+            permission = (await invoke('get_state_view')).capture_permission;
+        }
+        invoke('toggle_mic', { active: false });
+        console.log('[Onboarding] Microphone permission:', permission);
+        onboardingButton.disabled = false;
+        if (permission === 'Denied' || permission === 'Restricted') {
+            onboardingText.textContent = MIC_DENIED_TEXT;
+            onboardingButton.innerText = 'Continue';
+            return;
+        }
+        this.next();
+    },
+
     next() {
         console.log('[Onboarding] Next called');
+        if (this.currentScreen === MIC_SCREEN && !this.micRequested) {
+            this.requestMic();
+            return;
+        }
         this.currentScreen++;
         if (this.currentScreen >= ONBOARDING_SCREENS.length) {
             this.finish();
@@ -93,7 +133,7 @@ const OnboardingManager = {
         dom.onboardingOverlay.style.display = 'none';
         // Now trigger normal UI attach
         invoke('ui_attach');
-        dom.mic.click(); // Default mic ON
+        MicManager.attach(); // Default mic ON
     }
 };
 
@@ -103,7 +143,11 @@ listen('nexus-event', (event) => {
 
     switch (payload.type) {
         case 'PresenceUpdate':
-            updatePresence(payload.state);
+            updatePresence(payload.state, payload.listening);
+            break;
+        case 'Cognition':
+            // Planner working: a thinking hint, never a presence state
+            dom.body.classList.toggle('thinking', payload.state === 'Planning');
             break;
         case 'OutputEvent':
             renderOutput(payload);
@@ -120,9 +164,11 @@ listen('nexus-event', (event) => {
 });
 
 // --- 2. Presence Handling (Strict) ---
-function updatePresence(state) {
-    // RESET existing classes to ensure clean transition
+function updatePresence(state, listening = true) {
+    // RESET existing classes to ensure clean transition (the thinking hint is not presence)
+    const thinking = dom.body.classList.contains('thinking');
     dom.body.className = '';
+    dom.body.classList.toggle('thinking', thinking);
 
     // Set Text Content based on State
     // User Rule: "Make it textual, not symbolic. Or almost invisible."
@@ -151,6 +197,11 @@ function updatePresence(state) {
         default:
             text = "";
     }
+    // Half duplex: microphone ignored while Nexus speaks
+    if (!listening && text) {
+        text = "Speaking";
+        dom.body.classList.add('state-not-listening');
+    }
     dom.indicator.textContent = text;
 }
 
@@ -232,6 +283,11 @@ dom.input.addEventListener('input', (e) => {
 
 // Mic Toggle
 dom.mic.addEventListener('click', () => {
+    // Hands-free: clicking the mic turns it off (back to manual)
+    if (MicManager.auto) {
+        MicManager.setMode('Manual');
+        return;
+    }
     dom.mic.classList.toggle('active');
     const isActive = dom.mic.classList.contains('active');
     invoke('toggle_mic', { active: isActive });
@@ -330,55 +386,104 @@ window.addEventListener('DOMContentLoaded', async () => {
 function startKeySystems() {
     invoke('ui_attach');
     // Default Mic to ON for Phase D/M testing (unless changed by user preference later)
-    dom.mic.click();
+    MicManager.attach();
 }
+
+// --- Auto Mic ---
+// Manual: the mic button starts and stops capture. Auto (hands-free): capture runs continuously
+// and the shell forwards audio only while presence is Attentive/Engaged.
+const MicManager = {
+    auto: false,
+
+    // On attach: hands-free if NEXUS_MIC_MODE=auto, otherwise the mic toggle as before
+    async attach() {
+        const mode = await invoke('get_mic_mode');
+        if (mode === 'Auto') {
+            this.setMode('Auto');
+        } else {
+            dom.mic.click();
+        }
+    },
+
+    async setMode(mode) {
+        this.auto = mode === 'Auto';
+        dom.mic.classList.toggle('auto', this.auto);
+        // Manual starts with the mic off
+        dom.mic.classList.toggle('active', this.auto);
+        try {
+            await invoke('set_mic_mode', { mode });
+        } catch (err) {
+            console.error('[Mic] set_mic_mode failed:', err);
+        }
+    }
+};
 // --- Phase L: Memory Consent ---
 const ConsentManager = {
     overlay: document.getElementById('memory-consent-container'),
     yesBtn: document.getElementById('consent-yes'),
     noBtn: document.getElementById('consent-no'),
-    currentKey: null,
+    text: document.querySelector('#memory-consent-container .consent-text'),
+    // ConsentRequest { version, prompt_id, key, asked_at, expires_at, topic_id? }; the answer must echo it
+    currentRequest: null,
     timer: null,
 
     init() {
-        if (this.yesBtn) this.yesBtn.onclick = () => this.resolve('granted');
-        if (this.noBtn) this.noBtn.onclick = () => this.resolve('declined');
+        if (this.yesBtn) this.yesBtn.onclick = () => this.resolve('Granted');
+        if (this.noBtn) this.noBtn.onclick = () => this.resolve('Declined');
 
         listen('ask-memory-consent', (event) => {
-            console.log('[Consent] Asked for key:', event.payload.key);
-            this.show(event.payload.key);
+            console.log('[Consent] Asked for key:', event.payload.key, 'prompt', event.payload.prompt_id);
+            this.show(event.payload);
+        });
+
+        // Answered by voice: the kernel already applied it, just close the prompt
+        listen('dismiss-memory-consent', (event) => {
+            if (this.currentRequest && this.currentRequest.prompt_id === event.payload.prompt_id) {
+                console.log(`[Consent] Resolved by voice: ${event.payload.state}`);
+                this.hide();
+            }
         });
     },
 
-    show(key) {
+    show(request) {
         if (this.timer) clearTimeout(this.timer);
-        this.currentKey = key;
+        this.currentRequest = request;
 
-        // Render UI
+        // Render UI: a prompt with a topic_id covers the whole topic
+        if (this.text) {
+            this.text.textContent = request.topic_id
+                ? 'Should I remember things about this topic?'
+                : 'Should I remember this?';
+        }
         this.overlay.classList.remove('consent-hidden');
 
         // Auto-dismiss (Ignored)
-        // 10 seconds timeout
+        // 10 seconds timeout (well inside the kernel's prompt deadline)
         this.timer = setTimeout(() => {
             console.log('[Consent] Timed out -> Ignored');
-            this.resolve('ignored');
+            this.resolve('Ignored');
         }, 10000);
     },
 
     hide() {
         this.overlay.classList.add('consent-hidden');
         if (this.timer) clearTimeout(this.timer);
-        this.currentKey = null;
+        this.currentRequest = null;
     },
 
     resolve(state) {
-        if (!this.currentKey) return;
-
-        // Invoke Kernel Command
-        // We need to serialize key back to string if it is an object
-        const keyJson = JSON.stringify(this.currentKey);
-
-        invoke('resolve_memory_consent', { keyJson, state })
+        const request = this.currentRequest;
+        if (!request) return;
+
+        // ConsentResponse
+        const response = {
+            version: request.version,
+            prompt_id: request.prompt_id,
+            key: request.key,
+            state,
+        };
+
+        invoke('resolve_memory_consent', { response })
             .catch(err => console.error('[Consent] Failed to resolve:', err));
 
         console.log(`[Consent] Resolved: ${state}`);
@@ -388,3 +493,264 @@ const ConsentManager = {
 
 // Start logic
 ConsentManager.init();
+
+// --- Accessibility: Speech Captions ---
+// Each spoken output arrives as { text, words: [{ text, start_ms, end_ms }], duration_ms, audible }.
+// Words light up on their (estimated or TTS-reported) timing; a newer caption replaces the old one.
+const CaptionManager = {
+    bar: document.getElementById('caption-bar'),
+    timers: [],
+    LINGER_MS: 1500,
+
+    init() {
+        listen('caption', (event) => this.show(event.payload));
+    },
+
+    clear() {
+        this.timers.forEach(clearTimeout);
+        this.timers = [];
+    },
+
+    show(caption) {
+        this.clear();
+        this.bar.textContent = '';
+        const spans = caption.words.map((word) => {
+            const span = document.createElement('span');
+            span.className = 'caption-word';
+            span.textContent = word.text + ' ';
+            this.bar.appendChild(span);
+            return span;
+        });
+        this.bar.classList.remove('caption-hidden');
+
+        caption.words.forEach((word, i) => {
+            this.timers.push(setTimeout(() => spans[i].classList.add('spoken'), word.start_ms));
+        });
+        this.timers.push(setTimeout(() => this.bar.classList.add('caption-hidden'), caption.duration_ms + this.LINGER_MS));
+    }
+};
+
+CaptionManager.init();
+
+// --- Diagnostics: Error toasts ({ severity, code }) ---
+listen('toast', (event) => {
+    const toast = document.createElement('div');
+    toast.className = 'toast';
+    toast.setAttribute('role', 'alert');
+    toast.textContent = `Something went wrong (${event.payload.code})`;
+    document.body.appendChild(toast);
+    setTimeout(() => toast.remove(), 4000);
+});
+
+// --- Maintenance mode: migration screen ({ completed, total, next, last: { job, outcome, items } }) ---
+const MaintenanceScreen = {
+    screen: document.getElementById('maintenance-screen'),
+    bar: document.getElementById('maintenance-progress'),
+    step: document.getElementById('maintenance-step'),
+    LABELS: {
+        StoreMigration: 'Updating the memory format',
+        StoreCompaction: 'Compacting memory',
+        SemanticPromotion: 'Consolidating memories',
+        LogRotation: 'Rotating logs',
+        TelemetryExport: 'Writing telemetry',
+    },
+
+    init() {
+        listen('maintenance-progress', (event) => this.show(event.payload));
+    },
+
+    show(progress) {
+        // The core is listening and speaking again once every job has run
+        if (progress.completed >= progress.total) {
+            this.screen.style.display = 'none';
+            return;
+        }
+        this.screen.style.display = 'flex';
+        this.bar.max = progress.total;
+        this.bar.value = progress.completed;
+        this.step.textContent = `${this.LABELS[progress.next] || progress.next}… (${progress.completed + 1} of ${progress.total})`;
+    }
+};
+
+MaintenanceScreen.init();
+
+// --- Quiet Hours: local clock (Core has no wall clock) ---
+function reportLocalTime() {
+    const now = new Date();
+    invoke('report_local_time', { minuteOfDay: now.getHours() * 60 + now.getMinutes() });
+}
+reportLocalTime();
+setInterval(reportLocalTime, 60 * 1000);
+
+// --- Settings: output style, mic mode, calibration, subsystems, restart / quit the core ---
+// Both go through the kernel's graceful shutdown and return a ShutdownReport:
+// { tick, flushed: [{ store, error }], checkpoint_path, pending_effects_dropped, events_dropped }
+const SettingsManager = {
+    panel: document.getElementById('settings-panel'),
+    toggle: document.getElementById('settings-toggle'),
+    restartBtn: document.getElementById('restart-core'),
+    quitBtn: document.getElementById('quit-core'),
+    maintenanceBtn: document.getElementById('run-maintenance'),
+    status: document.getElementById('settings-status'),
+    formality: document.getElementById('style-formality'),
+    profanity: document.getElementById('style-profanity'),
+    emoji: document.getElementById('style-emoji'),
+    micAuto: document.getElementById('mic-auto'),
+    calibrateBtn: document.getElementById('calibrate-audio'),
+    calibration: document.getElementById('calibration-status'),
+    subsystems: document.querySelectorAll('[data-subsystem]'),
+    candidates: document.getElementById('memory-candidates'),
+    interruptions: document.getElementById('recent-interruptions'),
+
+    init() {
+        if (this.toggle) this.toggle.onclick = () => {
+            // Reload on open: the style may have changed by voice ("be more formal")
+            if (this.panel.classList.toggle('settings-hidden') === false) {
+                this.loadStyle();
+                this.loadSubsystems();
+                this.loadCandidates();
+                this.loadCalibration();
+                this.loadInterruptions();
+                if (this.micAuto) this.micAuto.checked = MicManager.auto;
+            }
+        };
+        if (this.restartBtn) this.restartBtn.onclick = () => this.run('restart_kernel');
+        if (this.quitBtn) this.quitBtn.onclick = () => this.run('shutdown_kernel');
+        // Store migration + compaction; the core shows the maintenance screen while it runs
+        if (this.maintenanceBtn) this.maintenanceBtn.onclick = () => invoke('enter_maintenance');
+        for (const input of [this.formality, this.profanity, this.emoji]) {
+            if (input) input.onchange = () => this.saveStyle();
+        }
+        if (this.micAuto) this.micAuto.onchange = () => MicManager.setMode(this.micAuto.checked ? 'Auto' : 'Manual');
+        if (this.calibrateBtn) this.calibrateBtn.onclick = () => this.calibrate();
+        for (const input of this.subsystems) {
+            // Applied at the next tick; no restart
+            input.onchange = () => invoke('set_subsystem', { subsystem: input.dataset.subsystem, enabled: input.checked });
+        }
+    },
+
+    // Output style of the active profile: { formality, emoji, profanity }
+    async loadStyle() {
+        try {
+            const style = await invoke('get_output_style');
+            this.formality.value = style.formality;
+            this.profanity.value = style.profanity;
+            this.emoji.checked = style.emoji;
+        } catch (err) {
+            console.error('[Settings] get_output_style failed:', err);
+        }
+    },
+
+    // Runtime switches from the State View: { vision, memory, proactive_speech, telemetry_export }
+    async loadSubsystems() {
+        const fields = { Vision: 'vision', Memory: 'memory', ProactiveSpeech: 'proactive_speech', TelemetryExport: 'telemetry_export' };
+        try {
+            const flags = (await invoke('get_state_view')).subsystems;
+            for (const input of this.subsystems) input.checked = flags[fields[input.dataset.subsystem]];
+        } catch (err) {
+            console.error('[Settings] get_state_view failed:', err);
+        }
+    },
+
+    // Core VAD calibration for the current mic + output device: { profile: { threshold_rms, echo_tail_ms, .. }, calibrating }
+    async loadCalibration() {
+        if (!this.calibration) return;
+        try {
+            const { profile, calibrating } = await invoke('get_audio_calibration');
+            this.calibration.textContent = calibrating
+                ? 'Listening… stay quiet'
+                : `Threshold ${profile.threshold_rms.toFixed(3)} · echo ${profile.echo_tail_ms} ms`;
+            if (calibrating) setTimeout(() => this.loadCalibration(), 1000);
+        } catch (err) {
+            console.error('[Settings] get_audio_calibration failed:', err);
+        }
+    },
+
+    // Measures the noise floor (3s of audio without playback); saved for this device pair
+    async calibrate() {
+        await invoke('calibrate_audio');
+        setTimeout(() => this.loadCalibration(), 200);
+    },
+
+    // Memory candidates: [{ id, key: { hypothesis }, reinforcement_count, age_ticks, consent, sensitive }]
+    async loadCandidates() {
+        if (!this.candidates) return;
+        let candidates = [];
+        try {
+            candidates = await invoke('list_memory_candidates');
+        } catch (err) {
+            console.error('[Settings] list_memory_candidates failed:', err);
+        }
+        this.candidates.replaceChildren(...candidates.map((c) => this.candidateRow(c)));
+    },
+
+    candidateRow(candidate) {
+        const row = document.createElement('li');
+        const minutes = Math.round(candidate.age_ticks * 20 / 60000); // 20ms ticks
+        const label = document.createElement('span');
+        label.textContent = `${candidate.key.hypothesis} · seen ${candidate.reinforcement_count}× · ${minutes} min`;
+        row.append(label);
+        for (const [action, text] of [['Promote', 'Remember'], ['Discard', 'Discard']]) {
+            const button = document.createElement('button');
+            button.textContent = text;
+            // A declined key stays declined; the kernel refuses the promotion
+            button.disabled = action === 'Promote' && candidate.consent === 'Declined';
+            button.onclick = async () => {
+                await invoke('resolve_memory_candidate', { id: candidate.id, action });
+                row.remove();
+            };
+            row.append(button);
+        }
+        return row;
+    },
+
+    // Last interruptions, newest first: [{ output_id, source, cause, spoken_chars, total_chars, age_ticks }]
+    async loadInterruptions() {
+        if (!this.interruptions) return;
+        let reports = [];
+        try {
+            reports = await invoke('get_recent_interruptions', { limit: 5 });
+        } catch (err) {
+            console.error('[Settings] get_recent_interruptions failed:', err);
+        }
+        this.interruptions.replaceChildren(...reports.map((r) => {
+            const row = document.createElement('li');
+            const seconds = Math.round(r.age_ticks * 20 / 1000); // 20ms ticks
+            row.textContent = `${seconds}s ago: ${r.cause} (${r.spoken_chars} of ${r.total_chars} characters said)`;
+            return row;
+        }));
+    },
+
+    async saveStyle() {
+        const style = { formality: this.formality.value, emoji: this.emoji.checked, profanity: this.profanity.value };
+        try {
+            await invoke('set_output_style', { style });
+        } catch (err) {
+            console.error('[Settings] set_output_style failed:', err);
+            this.status.textContent = `Failed: ${err}`;
+        }
+    },
+
+    async run(command) {
+        this.restartBtn.disabled = true;
+        this.status.textContent = 'Saving…';
+        try {
+            const report = await invoke(command);
+            this.status.textContent = this.describe(report);
+        } catch (err) {
+            console.error(`[Settings] ${command} failed:`, err);
+            this.status.textContent = `Failed: ${err}`;
+        } finally {
+            this.restartBtn.disabled = false;
+        }
+    },
+
+    describe(report) {
+        const failed = report.flushed.filter((f) => f.error).map((f) => f.store);
+        const saved = failed.length ? `Could not save: ${failed.join(', ')}.` : 'Everything saved.';
+        const dropped = report.pending_effects_dropped + report.events_dropped;
+        return `${saved} ${dropped ? `${dropped} unfinished actions dropped.` : 'Nothing was interrupted.'}`;
+    }
+};
+
+SettingsManager.init();
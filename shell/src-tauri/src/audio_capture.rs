@@ -1,6 +1,13 @@
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use tokio::sync::mpsc;
-use nexus::kernel::event::{Event, InputEvent, InputContent};
+use nexus::kernel::event::{Event, InputEvent};
+use nexus::audio::decimate::{ChunkShaper, FidelitySwitch, ReducedMode};
+use nexus::audio::mic::MicGate;
+use nexus::audio::permission::SilenceProbe;
+use nexus::audio::supervisor::{Heartbeat, Supervised};
+use nexus::kernel::presence::CapturePermission;
+use nexus::kernel::queue::DropCounter;
+use nexus::kernel::time::CaptureClock;
 use tracing::{info, error};
 
 pub enum AudioCommand {
@@ -8,36 +15,58 @@ pub enum AudioCommand {
     Stop,
 }
 
+/// Handle to the supervised audio actor: a lost actor (thread panic, dead stream) is restarted.
 pub struct AudioController {
-    cmd_tx: mpsc::Sender<AudioCommand>,
+    actor: Supervised<AudioCommand>,
 }
 
 impl AudioController {
-    pub fn new(cmd_tx: mpsc::Sender<AudioCommand>) -> Self {
-        Self { cmd_tx }
+    /// `launch` spawns a fresh actor on its own thread; called again on every restart.
+    pub fn spawn<L>(launch: L, core_tx: mpsc::Sender<Event>) -> Self
+    where
+        L: Fn(Heartbeat) -> (mpsc::Sender<AudioCommand>, std::thread::JoinHandle<()>) + Send + 'static,
+    {
+        Self { actor: Supervised::spawn(launch, || AudioCommand::Start, core_tx) }
     }
 
     pub fn start(&self) {
-        let _ = self.cmd_tx.blocking_send(AudioCommand::Start);
+        self.actor.set_running(true);
+        let _ = self.actor.sender().blocking_send(AudioCommand::Start);
     }
 
     pub fn stop(&self) {
-        let _ = self.cmd_tx.blocking_send(AudioCommand::Stop);
+        self.actor.set_running(false);
+        let _ = self.actor.sender().blocking_send(AudioCommand::Stop);
     }
 }
 
 pub struct AudioActor {
     stream: Option<cpal::Stream>,
     core_tx: mpsc::Sender<Event>,
+    // Chunks lost to a full kernel queue (the audio callback never blocks)
+    drops: DropCounter,
     cmd_rx: mpsc::Receiver<AudioCommand>,
+    // Kernel-driven: full-rate chunks only while a segment is buffering
+    fidelity: FidelitySwitch,
+    // Auto mic: chunks leave the callback unless presence is Suspended
+    gate: MicGate,
+    // Kernel tick: chunks are stamped when heard, not when the reactor drains them
+    clock: CaptureClock,
+    // Beaten by the stream callback (supervision)
+    heartbeat: Heartbeat,
 }
 
 impl AudioActor {
-    pub fn new(cmd_rx: mpsc::Receiver<AudioCommand>, core_tx: mpsc::Sender<Event>) -> Self {
+    pub fn new(cmd_rx: mpsc::Receiver<AudioCommand>, core_tx: mpsc::Sender<Event>, drops: DropCounter, fidelity: FidelitySwitch, gate: MicGate, clock: CaptureClock, heartbeat: Heartbeat) -> Self {
         Self {
             stream: None,
             core_tx,
+            drops,
             cmd_rx,
+            fidelity,
+            gate,
+            clock,
+            heartbeat,
         }
     }
 
@@ -75,28 +104,50 @@ impl AudioActor {
         let device = host.default_input_device()
             .ok_or("No input device found")?;
             
-        info!("[Audio] Device: {}", device.name().unwrap_or_default());
+        let device_name = device.name().unwrap_or_default();
+        info!("[Audio] Device: {}", device_name);
+        // Audio calibration is per device pair
+        let _ = self.core_tx.try_send(nexus::audio::capture::input_device_event(Some(device_name).filter(|name| !name.is_empty())));
+
+        // OS privacy settings, where readable. Known-blocked: report instead of capturing silence.
+        let permission = nexus::audio::permission::os_privacy_state();
+        if permission != CapturePermission::Unknown {
+            let _ = self.core_tx.try_send(nexus::audio::permission::permission_event(permission));
+        }
+        if matches!(permission, CapturePermission::Denied | CapturePermission::Restricted) {
+            return Err(format!("Microphone access {:?}", permission));
+        }
 
         let config: cpal::StreamConfig = device.default_input_config()
             .map_err(|e| format!("Default config error: {}", e))?
             .into();
 
+        let mut shaper = ChunkShaper::new(config.sample_rate.0, config.channels, ReducedMode::default(), self.fidelity.clone());
         let core_tx = self.core_tx.clone();
+        let drops = self.drops.clone();
+        let gate = self.gate.clone();
+        let clock = self.clock.clone();
+        let heartbeat = self.heartbeat.clone();
+        // Interleaved samples: the window covers all channels
+        let mut probe = SilenceProbe::new(config.sample_rate.0 * config.channels as u32);
         let err_fn = move |err| error!("[Audio] Stream Error: {}", err);
         
         let stream = device.build_input_stream(
             &config,
             move |data: &[f32], _: &_| {
-                let chunk = data.to_vec();
-                let evt = Event::Input(InputEvent {
-                    source: "Mic".to_string(),
-                    content: InputContent::AudioChunk(chunk)
-                });
-                
-                // Use try_send to avoid blocking audio thread
-                if let Err(_e) = core_tx.try_send(evt) {
-                    // warn!("[Audio] Drop");
+                // Before the gate: a closed gate is still a live stream
+                heartbeat.beat();
+                if let Some(state) = probe.observe(data) {
+                    drops.try_send(&core_tx, nexus::audio::permission::permission_event(state));
+                }
+                // Hard gate (auto mic): the permission probe still runs, the audio goes nowhere
+                if !gate.is_open() {
+                    return;
                 }
+                let evt = Event::Input(InputEvent::captured("Mic", shaper.shape(data), clock.now()));
+                
+                // Use try_send to avoid blocking audio thread (counted if the queue is full)
+                drops.try_send(&core_tx, evt);
             },
             err_fn,
             None
@@ -14,20 +14,69 @@ use std::fs;
 use serde::{Serialize, Deserialize};
 mod alpha;
 use alpha::AlphaAccess;
-use uuid::Uuid;
-use std::collections::HashMap;
 use std::time::{Duration, Instant};
-use tokio::task::JoinHandle;
 
-// Internal Driver Events (Never touch Kernel)
-enum DriverEvent {
-    GeneratedSpeech { output_id: Uuid, text: String },
-    SpeechFailed { output_id: Uuid },
+// UI side of the kernel's effects (captions, toasts, consent prompts); the executor runs the rest
+struct ShellHooks {
+    app: tauri::AppHandle,
+    fidelity: nexus::audio::decimate::FidelitySwitch,
+}
+
+impl nexus::kernel::driver::EffectHooks for ShellHooks {
+    fn caption(&self, caption: &nexus::outputs::captions::Caption) {
+        let _ = self.app.emit("caption", caption);
+    }
+
+    fn diagnostic(&self, severity: nexus::kernel::diagnostic::Severity, code: nexus::kernel::diagnostic::DiagnosticCode) {
+        if severity.toast() {
+            let _ = self.app.emit("toast", serde_json::json!({
+                "severity": severity,
+                "code": code.as_str(),
+            }));
+        }
+    }
+
+    fn maintenance(&self, progress: &nexus::kernel::maintenance::MaintenanceProgress) {
+        // Migration screen: shown until completed == total
+        let _ = self.app.emit("maintenance-progress", progress);
+    }
+
+    fn capture_fidelity(&self, fidelity: nexus::kernel::event::CaptureFidelity) {
+        self.fidelity.set(fidelity);
+    }
+
+    fn ask_consent(&self, request: &nexus::kernel::protocol::ConsentRequest) {
+        println!("[CONSENT] Asking user for key: {:?} (prompt {})", request.key, request.prompt_id);
+        let _ = self.app.emit("ask-memory-consent", request);
+    }
+
+    fn dismiss_consent(&self, response: &nexus::kernel::protocol::ConsentResponse) {
+        println!("[CONSENT] Answered by voice: {:?} (prompt {})", response.state, response.prompt_id);
+        let _ = self.app.emit("dismiss-memory-consent", response);
+    }
+
+    fn cue(&self, cue: nexus::kernel::backchannel::Cue) {
+        let _ = self.app.emit("listening-cue", cue.sound());
+    }
 }
 
 struct AudioState(audio_capture::AudioController);
+struct MicControl(nexus::audio::mic::MicGate);
 struct CoreSender(tokio::sync::mpsc::Sender<Event>);
 struct ReactorHandle(Arc<Mutex<nexus::kernel::reactor::Reactor>>);
+struct ViewHandle(nexus::kernel::view::StateViewReceiver);
+struct TelemetryState(nexus::kernel::telemetry::recorder::TelemetryHandle);
+struct OutputRouting(Arc<Mutex<nexus::outputs::device::OutputRouter>>);
+// Effects produced outside a tick (shutdown / restart), run by the kernel thread
+struct ControlSender(tokio::sync::mpsc::UnboundedSender<nexus::kernel::effects::EffectBatch>);
+
+fn output_device_event(router: &nexus::outputs::device::OutputRouter) -> Event {
+    Event::Input(nexus::kernel::event::InputEvent {
+        source: "Driver".to_string(),
+        content: nexus::kernel::event::InputContent::OutputDevice { device: router.current().map(str::to_string) },
+        captured_at: None,
+    })
+}
 
 #[derive(Serialize, Deserialize, Default)]
 struct OnboardingState {
@@ -68,6 +117,18 @@ fn send_input_fragment(text: String, core_state: tauri::State<'_, CoreSender>) {
     let evt = Event::Input(nexus::kernel::event::InputEvent {
         source: "Frontend".to_string(),
         content: nexus::kernel::event::InputContent::Text(text),
+        captured_at: None,
+    });
+    let _ = core_state.0.try_send(evt);
+}
+
+#[tauri::command]
+fn report_local_time(minute_of_day: u16, core_state: tauri::State<'_, CoreSender>) {
+    // Quiet hours: the kernel has no wall clock, the UI reports local time every minute
+    let evt = Event::Input(nexus::kernel::event::InputEvent {
+        source: "Frontend".to_string(),
+        content: nexus::kernel::event::InputContent::LocalTime { minute_of_day },
+        captured_at: None,
     });
     let _ = core_state.0.try_send(evt);
 }
@@ -94,7 +155,7 @@ fn mark_welcome_seen(app: tauri::AppHandle) {
 }
 
 #[tauri::command]
-fn complete_onboarding(app: tauri::AppHandle, reactor_handle: tauri::State<ReactorHandle>) {
+fn complete_onboarding(app: tauri::AppHandle, reactor_handle: tauri::State<ReactorHandle>, telemetry: tauri::State<TelemetryState>) {
     // 1. Persist
     let state = OnboardingState {
         completed: true,
@@ -110,12 +171,12 @@ fn complete_onboarding(app: tauri::AppHandle, reactor_handle: tauri::State<React
     // 2. Unlock Kernel
     if let Ok(mut reactor) = reactor_handle.0.lock() {
         reactor.set_mode(KernelMode::Active);
-        // 3. Emit Telemetry (Lifecycle)
-        reactor.telemetry.record(nexus::kernel::telemetry::event::TelemetryEvent::Lifecycle(
-            nexus::kernel::telemetry::event::LifecycleEvent::OnboardingCompleted
-        ));
-        println!("[Onboarding] Kernel unlocked and Telemetry emitted.");
+        println!("[Onboarding] Kernel unlocked.");
     }
+    // 3. Emit Telemetry (Lifecycle), no lock needed
+    telemetry.0.record(nexus::kernel::telemetry::event::TelemetryEvent::Lifecycle(
+        nexus::kernel::telemetry::event::LifecycleEvent::OnboardingCompleted
+    ));
 }
 
 #[tauri::command]
@@ -143,6 +204,189 @@ fn toggle_mic(active: bool, state: tauri::State<AudioState>, app: tauri::AppHand
     }
 }
 
+#[tauri::command]
+fn request_capture_permission(state: tauri::State<AudioState>) -> nexus::kernel::presence::CapturePermission {
+    // Onboarding: opening the stream is what makes the OS ask (macOS prompts on first use).
+    // The verdict reaches the kernel as InputContent::CapturePermission; the UI reads it from the State View.
+    println!("[Command] Requesting microphone permission");
+    state.0.start();
+    nexus::audio::permission::os_privacy_state()
+}
+
+#[tauri::command]
+fn get_mic_mode(mic: tauri::State<MicControl>) -> nexus::audio::mic::MicMode {
+    mic.0.mode()
+}
+
+#[tauri::command]
+fn set_mic_mode(mode: nexus::audio::mic::MicMode, mic: tauri::State<MicControl>, audio: tauri::State<AudioState>, view: tauri::State<ViewHandle>) {
+    // Settings: Auto keeps capturing and forwards unless paused (gated in the audio callback); Dormant needs the wake word.
+    // Manual hands the stream back to the mic toggle, off until clicked.
+    mic.0.set_mode(mode, view.0.borrow().presence);
+    match mode {
+        nexus::audio::mic::MicMode::Auto => audio.0.start(),
+        nexus::audio::mic::MicMode::Manual => audio.0.stop(),
+    }
+}
+
+#[tauri::command]
+fn list_memory_candidates(reactor_handle: tauri::State<ReactorHandle>) -> Result<Vec<nexus::kernel::memory::inspect::CandidateSummary>, String> {
+    // Memory inspector: what the heuristics have not promoted (yet)
+    let reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+    Ok(reactor.memory_candidates())
+}
+
+#[tauri::command]
+fn get_recent_interruptions(limit: Option<usize>, reactor_handle: tauri::State<ReactorHandle>) -> Result<Vec<nexus::kernel::cancel::InterruptionReport>, String> {
+    // "Why was I interrupted?": alpha users attach this to false barge-in reports
+    let reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+    Ok(reactor.recent_interruptions(limit.unwrap_or(5)))
+}
+
+#[tauri::command]
+fn resolve_memory_candidate(id: String, action: nexus::kernel::memory::inspect::CandidateAction, core_state: tauri::State<'_, CoreSender>) {
+    // Routed as input: the reducer and consent rules apply (a declined key stays declined)
+    let evt = Event::Input(nexus::kernel::event::InputEvent {
+        source: "Frontend".to_string(),
+        content: nexus::kernel::event::InputContent::MemoryCandidateAction { id, action },
+        captured_at: None,
+    });
+    let _ = core_state.0.try_send(evt);
+}
+
+#[tauri::command]
+fn forget_topic(topic_id: Option<String>, core_state: tauri::State<'_, CoreSender>) {
+    // Routed as input like the spoken command; no topic id forgets the active topic
+    let evt = Event::Input(nexus::kernel::event::InputEvent {
+        source: "Frontend".to_string(),
+        content: nexus::kernel::event::InputContent::ForgetTopic { topic_id },
+        captured_at: None,
+    });
+    let _ = core_state.0.try_send(evt);
+}
+
+#[tauri::command]
+fn set_subsystem(subsystem: nexus::kernel::subsystem::Subsystem, enabled: bool, core_state: tauri::State<'_, CoreSender>) {
+    // Settings: switch off vision, memory, proactive speech or telemetry export without a restart.
+    // Applied at the next tick boundary; the UI reads the flags back from the State View.
+    let evt = Event::Input(nexus::kernel::event::InputEvent {
+        source: "Frontend".to_string(),
+        content: nexus::kernel::event::InputContent::SubsystemControl { subsystem, enabled },
+        captured_at: None,
+    });
+    let _ = core_state.0.try_send(evt);
+}
+
+#[tauri::command]
+fn set_language(language: Option<String>, core_state: tauri::State<'_, CoreSender>) {
+    // Settings: pin the transcription and reply language ("de"), or detect it (None / "auto").
+    // The UI reads the preference and the language heard back from the State View.
+    let preference = nexus::kernel::language::LanguagePreference::parse(language.as_deref().unwrap_or_default());
+    let evt = Event::Input(nexus::kernel::event::InputEvent {
+        source: "Frontend".to_string(),
+        content: nexus::kernel::event::InputContent::Language(preference),
+        captured_at: None,
+    });
+    let _ = core_state.0.try_send(evt);
+}
+
+#[tauri::command]
+fn calibrate_audio(core_state: tauri::State<'_, CoreSender>) {
+    // Settings: the user stays quiet while the noise floor is measured for the current devices
+    let evt = Event::Input(nexus::kernel::event::InputEvent {
+        source: "Frontend".to_string(),
+        content: nexus::kernel::event::InputContent::CalibrateAudio,
+        captured_at: None,
+    });
+    let _ = core_state.0.try_send(evt);
+}
+
+#[tauri::command]
+fn enter_maintenance(jobs: Option<Vec<nexus::kernel::maintenance::MaintenanceJob>>, core_state: tauri::State<'_, CoreSender>) {
+    // Settings: migrate and compact the memory store. The UI shows `maintenance-progress` until it is done.
+    let jobs = jobs.unwrap_or_else(|| nexus::kernel::maintenance::MaintenanceJob::MIGRATION.to_vec());
+    let evt = Event::Input(nexus::kernel::event::InputEvent {
+        source: "Frontend".to_string(),
+        content: nexus::kernel::event::InputContent::EnterMaintenance { jobs },
+        captured_at: None,
+    });
+    let _ = core_state.0.try_send(evt);
+}
+
+#[tauri::command]
+fn get_audio_calibration(reactor_handle: tauri::State<ReactorHandle>) -> Result<serde_json::Value, String> {
+    // Thresholds in effect for the current device pair, and whether a run is still listening
+    let reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+    Ok(serde_json::json!({
+        "profile": reactor.audio_monitor.calibration(),
+        "calibrating": reactor.audio_monitor.is_calibrating(),
+    }))
+}
+
+#[tauri::command]
+fn get_vad_config(reactor_handle: tauri::State<ReactorHandle>) -> Result<nexus::kernel::audio::vad::VadConfig, String> {
+    let reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+    Ok(reactor.config.vad)
+}
+
+#[tauri::command]
+fn set_vad_config(vad: nexus::kernel::audio::vad::VadConfig, core_state: tauri::State<'_, CoreSender>) {
+    // Settings: VAD sensitivity and timing. Out-of-range values are clamped; a calibrated
+    // device pair keeps its measured thresholds (the segment timing still applies).
+    let evt = Event::Input(nexus::kernel::event::InputEvent {
+        source: "Frontend".to_string(),
+        content: nexus::kernel::event::InputContent::ConfigUpdate { vad },
+        captured_at: None,
+    });
+    let _ = core_state.0.try_send(evt);
+}
+
+#[tauri::command]
+fn set_conversation_mode(mode: nexus::kernel::presence::ConversationMode, reactor_handle: tauri::State<ReactorHandle>) -> Result<(), String> {
+    // Settings: half duplex never listens while speaking. The UI hears it as a PresenceUpdate.
+    let mut reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+    reactor.set_conversation_mode(mode);
+    Ok(())
+}
+
+#[tauri::command]
+fn get_output_style(reactor_handle: tauri::State<ReactorHandle>) -> Result<nexus::outputs::style::OutputStyle, String> {
+    let reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+    Ok(reactor.output_style())
+}
+
+#[tauri::command]
+fn set_output_style(style: nexus::outputs::style::OutputStyle, reactor_handle: tauri::State<ReactorHandle>) -> Result<(), String> {
+    // Settings: formality, emoji in captions, profanity tolerance. Saved with the active profile, off the lock.
+    let mut reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+    if let Some(snapshot) = reactor.set_output_style(style) {
+        std::thread::spawn(move || {
+            if let Err(e) = snapshot.save() {
+                eprintln!("[Settings] Failed to save profiles: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+#[tauri::command]
+fn list_output_devices() -> Vec<String> {
+    // Settings: device picker for speech playback
+    nexus::outputs::device::output_devices()
+}
+
+#[tauri::command]
+fn set_output_device(chain: Option<String>, routing: tauri::State<OutputRouting>, core_state: tauri::State<'_, CoreSender>) -> Result<Option<String>, String> {
+    // Settings: fallback chain, most preferred first (e.g. "Headset,Speakers"). None = system default.
+    // Returns the device now in use; the kernel hears about it right away, not on the next poll.
+    let mut router = routing.0.lock().map_err(|_| "Output router lock poisoned".to_string())?;
+    router.set_config(nexus::outputs::device::OutputDeviceConfig::parse(chain.as_deref().unwrap_or_default()));
+    if router.refresh(&nexus::outputs::device::output_devices()) {
+        let _ = core_state.0.try_send(output_device_event(&router));
+    }
+    Ok(router.current().map(str::to_string))
+}
+
 #[tauri::command]
 async fn ui_attach(app_handle: tauri::AppHandle, core_state: tauri::State<'_, CoreSender>) -> Result<(), ()> {
     // Phase M: Check Access
@@ -193,27 +437,126 @@ async fn ui_attach(app_handle: tauri::AppHandle, core_state: tauri::State<'_, Co
 }
 
 #[tauri::command]
-fn resolve_memory_consent(key_json: String, state: String, core_state: tauri::State<'_, CoreSender>) {
-    // Deserialize Key
-    if let Ok(key) = serde_json::from_str::<nexus::kernel::memory::types::MemoryKey>(&key_json) {
-        let consent_state = match state.as_str() {
-            "granted" => nexus::kernel::memory::consent::MemoryConsentState::Granted,
-            "declined" => nexus::kernel::memory::consent::MemoryConsentState::Declined,
-            _ => nexus::kernel::memory::consent::MemoryConsentState::Ignored,
-        };
-        
-        let evt = Event::Input(nexus::kernel::event::InputEvent {
-            source: "Frontend".to_string(),
-            content: nexus::kernel::event::InputContent::MemoryConsentResponse {
-                key,
-                state: consent_state,
-            }
-        });
-        
-        let _ = core_state.0.try_send(evt);
-    } else {
-        println!("[Error] Failed to deserialize MemoryKey in resolve_memory_consent");
+fn resolve_memory_consent(response: nexus::kernel::protocol::ConsentResponse, core_state: tauri::State<'_, CoreSender>) {
+    // The kernel checks version, prompt id and deadline
+    let evt = Event::Input(nexus::kernel::event::InputEvent {
+        source: "Frontend".to_string(),
+        content: nexus::kernel::event::InputContent::MemoryConsentResponse(response),
+        captured_at: None,
+    });
+    let _ = core_state.0.try_send(evt);
+}
+
+#[tauri::command]
+fn get_decision_trace(limit: Option<usize>, reactor_handle: tauri::State<ReactorHandle>) -> Result<Vec<nexus::kernel::trace::DecisionTrace>, String> {
+    // Debug: Why did the gate deny / which candidate won (last N decision ticks)
+    let reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+    if !reactor.trace.enabled {
+        return Err("Decision trace disabled (set NEXUS_DECISION_TRACE=1)".to_string());
     }
+    Ok(reactor.trace.recent(limit.unwrap_or(64)))
+}
+
+#[tauri::command]
+fn get_state_view(view: tauri::State<ViewHandle>) -> nexus::kernel::view::StateView {
+    // Lock-free: latest view published by the kernel thread (never touches the Reactor mutex)
+    (**view.0.borrow()).clone()
+}
+
+#[tauri::command]
+fn run_self_test(reactor_handle: tauri::State<ReactorHandle>) -> Result<nexus::kernel::self_test::SelfTestReport, String> {
+    // Advanced: "Run diagnostics". Copy under the lock, test outside it (no tick jitter).
+    let (state, tick, config, log_len) = {
+        let reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+        (reactor.state.clone(), reactor.tick, reactor.config, reactor.episodic.len())
+    };
+    Ok(nexus::kernel::self_test::run(&state, tick, config, log_len))
+}
+
+#[tauri::command]
+fn preview_telemetry_export(reactor_handle: tauri::State<ReactorHandle>) -> Result<nexus::kernel::telemetry::export::TelemetryExport, String> {
+    // Alpha: show the user exactly what a telemetry export would send (content-free, fresh salt)
+    let mut reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+    reactor.telemetry.drain();
+    Ok(reactor.telemetry.export_content_free(&nexus::kernel::telemetry::export::ExportSalt::random()))
+}
+
+#[tauri::command]
+fn recall_episodes(reactor_handle: tauri::State<ReactorHandle>, from_ms: u64, to_ms: u64, topic: Option<String>) -> Result<Vec<nexus::planner::tools::RecalledEpisode>, String> {
+    // "What did we talk about this morning": the UI computes the wall-clock bounds
+    let reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+    let query = nexus::memory::store::EpisodicQuery {
+        range: nexus::memory::store::TimeRange::WallClock { from_ms, to_ms },
+        topic,
+    };
+    Ok(reactor.recall_episodes(&query))
+}
+
+#[tauri::command]
+fn set_tool_consent(reactor_handle: tauri::State<ReactorHandle>, tool: String, granted: bool) -> Result<(), String> {
+    // Per-tool opt-in (e.g. "calendar_events"); ungranted tools are neither offered nor run
+    let mut reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+    reactor.tool_consent.set(&tool, granted);
+    Ok(())
+}
+
+#[tauri::command]
+fn preview_report_bundle(reactor_handle: tauri::State<ReactorHandle>) -> Result<Vec<nexus::kernel::report::BundleEntry>, String> {
+    // Step 1: show the user what the bug report will contain
+    let reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+    reactor.report_bundle().manifest().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn create_report_bundle(app: tauri::AppHandle, reactor_handle: tauri::State<ReactorHandle>, confirmed: bool) -> Result<String, String> {
+    // Step 2: only after the user confirmed the manifest. Collect under the lock, write outside it.
+    let bundle = {
+        let reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+        reactor.report_bundle()
+    };
+    let dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let path = dir.join(format!("nexus_report_{}.zip", bundle.tick.frame));
+    bundle.write_zip(&path, confirmed).map_err(|e| e.to_string())?;
+    Ok(path.display().to_string())
+}
+
+/// Checkpoint, telemetry spool and persisted state go next to the other app data.
+fn shutdown_options(app: &tauri::AppHandle) -> Result<nexus::kernel::shutdown::ShutdownOptions, String> {
+    let dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(nexus::kernel::shutdown::ShutdownOptions {
+        checkpoint: Some(dir.join("nexus_checkpoint.json")),
+        telemetry_spool: Some(dir.join("nexus_telemetry_spool.jsonl")),
+        state: Some(dir.join("nexus_state.json")),
+    })
+}
+
+#[tauri::command]
+fn restart_kernel(app: tauri::AppHandle, reactor_handle: tauri::State<ReactorHandle>, control: tauri::State<ControlSender>) -> Result<nexus::kernel::shutdown::ShutdownReport, String> {
+    // "Restart core": graceful shutdown, then a fresh kernel on the same channels (no app restart)
+    let options = shutdown_options(&app)?;
+    let (report, effects) = {
+        let mut reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+        reactor.restart(&options)
+    };
+    // Playback and tool drivers live on the kernel thread
+    let _ = control.0.send(effects);
+    println!("[Core] Restarted: {} pending effects dropped", report.pending_effects_dropped);
+    Ok(report)
+}
+
+#[tauri::command]
+fn shutdown_kernel(app: tauri::AppHandle, reactor_handle: tauri::State<ReactorHandle>, control: tauri::State<ControlSender>) -> Result<nexus::kernel::shutdown::ShutdownReport, String> {
+    let options = shutdown_options(&app)?;
+    let (report, effects) = {
+        let mut reactor = reactor_handle.0.lock().map_err(|_| "Reactor lock poisoned".to_string())?;
+        reactor.shutdown(&options)
+    };
+    let _ = control.0.send(effects);
+    println!("[Core] Shut down: {} pending effects dropped", report.pending_effects_dropped);
+    app.exit(0);
+    Ok(report)
 }
 
 #[tauri::command]
@@ -227,8 +570,10 @@ fn main() {
     // 0. Init Logger
     tracing_subscriber::fmt::init();
 
-    // 1. Setup Channels
-    let (tx, rx) = tokio::sync::mpsc::channel(100);
+    // 1. Setup Channels (NEXUS_EVENT_QUEUE / NEXUS_DRIVER_QUEUE: capacities for heavy streams)
+    let queues = nexus::kernel::queue::QueueConfig::from_env();
+    let (tx, rx) = queues.event_channel();
+    let (control_tx, mut control_rx) = tokio::sync::mpsc::unbounded_channel::<nexus::kernel::effects::EffectBatch>();
     
     // Parses CLI args to check for safe-mode
     // Note: Tauri's arg parsing happens inside run context usually, but we need it for Kernel init
@@ -240,41 +585,132 @@ fn main() {
     }
 
     // 2. Setup Reactor (The Core)
-    let config = nexus::kernel::reactor::ReactorConfig { safe_mode };
+    let decision_trace = cfg!(debug_assertions) || std::env::var("NEXUS_DECISION_TRACE").is_ok();
+    // Accessibility: captions without speech
+    let caption_only = std::env::var("NEXUS_CAPTION_ONLY").is_ok();
+    // Exchange summaries from the local LLM (template line when it does not answer)
+    let exchange_summaries = nexus::memory::summary::SummaryMode::Llm;
+    // e.g. NEXUS_QUIET_HOURS=22:00-08:00
+    let quiet_hours = std::env::var("NEXUS_QUIET_HOURS").ok().and_then(|s| nexus::kernel::quiet::QuietHours::parse(&s));
+    // NEXUS_CONVERSATION_MODE=half: never listen while speaking (until changed in settings)
+    let conversation_mode = nexus::kernel::presence::ConversationMode::from_env();
+    // NEXUS_OUTPUT_FILTER=off|redact|regenerate|silence (LLM text safety filter)
+    let output_filter = nexus::outputs::safety::SafetyConfig::from_env();
+    let tool_sandbox = nexus::planner::sandbox::SandboxConfig::from_env();
+    // NEXUS_BACKCHANNEL=1: "mm-hm" in the pauses of a long turn
+    let backchannel = std::env::var("NEXUS_BACKCHANNEL").is_ok_and(|v| v != "0");
+    // NEXUS_CHUNKED_SPEECH=1: speak replies a sentence at a time (an interruption drops only the rest)
+    let chunked_speech = std::env::var("NEXUS_CHUNKED_SPEECH").is_ok_and(|v| v != "0");
+    // NEXUS_MAINTENANCE_WINDOW=02:00-05:00 and/or NEXUS_MAINTENANCE_DORMANT=1: nightly jobs
+    let maintenance = nexus::kernel::maintenance::MaintenanceConfig::from_env();
+    // NEXUS_TTS=say|sapi|espeak|piper (default per platform)
+    let tts = nexus::outputs::tts::TtsEngineKind::from_env();
+    // nexus_vad.toml (or NEXUS_VAD_CONFIG): core VAD thresholds and timings (until changed in settings)
+    let vad = nexus::kernel::audio::vad::VadConfig::from_default_path();
+    let config = nexus::kernel::reactor::ReactorConfig { safe_mode, decision_trace, caption_only, exchange_summaries, quiet_hours, conversation_mode, output_filter, tool_sandbox, backchannel, maintenance, tts, vad, chunked_speech, ..Default::default() };
     let reactor = nexus::kernel::reactor::Reactor::new(rx, tx.clone(), config);
+    let view_handle = ViewHandle(reactor.subscribe_view());
+    let view_for_presence = reactor.subscribe_view();
+    let telemetry = reactor.telemetry.handle();
+    let capture_clock = reactor.capture_clock();
+    // Audio chunks lost to a full queue show up in the State View
+    let audio_drops = reactor.queue.drops();
     let reactor_arc = Arc::new(Mutex::new(reactor));
     
     // 3. Audio Actor (Shell -> AudioThread -> Core)
-    let (cmd_tx, cmd_rx) = tokio::sync::mpsc::channel(10);
     let audio_core_tx = tx.clone();
+    let capture_fidelity = nexus::audio::decimate::FidelitySwitch::new();
+    let actor_fidelity = capture_fidelity.clone();
+    // NEXUS_MIC_MODE=auto: hands-free (until changed in settings)
+    let mic_gate = nexus::audio::mic::MicGate::new(nexus::audio::mic::MicMode::from_env());
+    let actor_gate = mic_gate.clone();
     
-    // Spawn Audio Actor Thread
+    // Spawn Audio Actor Thread (supervised: relaunched if the actor is lost)
     println!("[Main] Spawning Audio Thread...");
-    std::thread::spawn(move || {
-        println!("[AudioThread] Running closure...");
-        let actor = audio_capture::AudioActor::new(cmd_rx, audio_core_tx);
-        actor.run();
-    });
-
-    let audio_controller = audio_capture::AudioController::new(cmd_tx);
+    let launch_audio = move |heartbeat: nexus::audio::supervisor::Heartbeat| {
+        let (cmd_tx, cmd_rx) = tokio::sync::mpsc::channel(10);
+        let (core_tx, drops, fidelity, gate, clock) = (audio_core_tx.clone(), audio_drops.clone(), actor_fidelity.clone(), actor_gate.clone(), capture_clock.clone());
+        let thread = std::thread::spawn(move || {
+            println!("[AudioThread] Running closure...");
+            let actor = audio_capture::AudioActor::new(cmd_rx, core_tx, drops, fidelity, gate, clock, heartbeat);
+            actor.run();
+        });
+        (cmd_tx, thread)
+    };
+    let audio_controller = audio_capture::AudioController::spawn(launch_audio, tx.clone());
+    // Playback route: NEXUS_OUTPUT_DEVICE until the user picks one in settings
+    let output_router = Arc::new(Mutex::new(nexus::outputs::device::OutputRouter::new(
+        nexus::outputs::device::OutputDeviceConfig::from_env()
+    )));
+
+    // Remote observation (alpha): only with the tester's explicit opt-in
+    #[cfg(feature = "dashboard")]
+    if std::env::var("NEXUS_DASHBOARD_CONSENT").as_deref() == Ok("1") {
+        let mut dashboard = nexus::dashboard::DashboardConfig { consent: true, ..Default::default() };
+        if let Some(bind) = std::env::var("NEXUS_DASHBOARD_BIND").ok().and_then(|b| b.parse().ok()) {
+            dashboard.bind = bind;
+        }
+        let reactor_for_dashboard = reactor_arc.clone();
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            if let Err(e) = rt.block_on(nexus::dashboard::server::serve(reactor_for_dashboard, dashboard)) {
+                println!("[Dashboard] Stopped: {}", e);
+            }
+        });
+    }
 
     // Clone for Tauri state
     let reactor_handle = ReactorHandle(reactor_arc.clone());
 
     tauri::Builder::default()
         .manage(AudioState(audio_controller))
+        .manage(MicControl(mic_gate.clone()))
         .manage(CoreSender(tx.clone()))
         .manage(reactor_handle)
+        .manage(view_handle)
+        .manage(TelemetryState(telemetry.clone()))
+        .manage(OutputRouting(output_router.clone()))
+        .manage(ControlSender(control_tx))
         .invoke_handler(tauri::generate_handler![
             send_input_fragment, 
+            report_local_time,
             toggle_mic,
+            request_capture_permission,
             ui_attach,
             get_onboarding_status,
             complete_onboarding,
             resolve_memory_consent,
             should_show_welcome,
             mark_welcome_seen,
-            grant_alpha_access
+            grant_alpha_access,
+            get_decision_trace,
+            run_self_test,
+            get_state_view,
+            preview_telemetry_export,
+            preview_report_bundle,
+            create_report_bundle,
+            recall_episodes,
+            set_tool_consent,
+            list_output_devices,
+            set_output_device,
+            set_conversation_mode,
+            get_mic_mode,
+            set_mic_mode,
+            set_subsystem,
+            set_language,
+            calibrate_audio,
+            enter_maintenance,
+            get_audio_calibration,
+            get_vad_config,
+            set_vad_config,
+            list_memory_candidates,
+            resolve_memory_candidate,
+            forget_topic,
+            get_recent_interruptions,
+            get_output_style,
+            set_output_style,
+            restart_kernel,
+            shutdown_kernel
         ])
 
     .setup(move |app| {
@@ -316,6 +752,24 @@ fn main() {
             let onboarding_state = load_onboarding_state(&handle);
             {
                 if let Ok(mut reactor) = reactor_arc.lock() {
+                    // Nightly telemetry spool rotation/export, next to the shutdown spool
+                    reactor.maintenance.telemetry_spool = handle.path().app_data_dir().ok().map(|dir| dir.join("nexus_telemetry_spool.jsonl"));
+                    // Dialogue phrasing from the config dir, unless NEXUS_PHRASES points elsewhere
+                    if std::env::var("NEXUS_PHRASES").is_err() {
+                        if let Ok(dir) = handle.path().app_config_dir() {
+                            match nexus::outputs::phrases::PhraseTemplates::open(&dir.join("phrases.json")) {
+                                Ok(templates) => reactor.templates = templates,
+                                Err(e) => println!("[Phrases] Invalid phrases.json, using built-in templates: {}", e),
+                            }
+                        }
+                    }
+                    // Memories, long-horizon intents and consent from the last shutdown
+                    if let Some(path) = handle.path().app_data_dir().ok().map(|dir| dir.join("nexus_state.json")).filter(|p| p.exists()) {
+                        match reactor.hydrate_from_disk(&path) {
+                            Ok(hydrated) => println!("[Core] Resumed {} memories and {} intents", hydrated.memories, hydrated.intents),
+                            Err(e) => println!("[Core] Starting without persisted state: {:?}", e),
+                        }
+                    }
                     if onboarding_state.completed {
                         reactor.set_mode(KernelMode::Active);
                         println!("[Onboarding] Already completed. Kernel Active.");
@@ -330,6 +784,10 @@ fn main() {
             let reactor_for_thread = reactor_arc.clone();
             let kernel_tx = tx.clone();
             let handle_for_thread = handle.clone();
+            let fidelity_for_thread = capture_fidelity.clone();
+            let router_for_thread = output_router.clone();
+            let view_for_thread = view_for_presence.clone();
+            let mic_for_thread = mic_gate.clone();
             
             // Spawn Kernel Thread
             std::thread::spawn(move || {
@@ -342,223 +800,138 @@ fn main() {
                     // Solution: Change Reactor::run() to take Arc<Mutex>, or
                     // Extract run logic here.
                     // For MVP, we'll call tick_step manually in a loop.
-                    use tokio::time::{interval, Duration};
-                    use tokio::process::Command; // Ensure Command is available
+                    use tokio::time::Duration;
 
                     // Initialize Services
                     let llm_service = nexus::services::llm::client::LLMService::new();
                     
-                    // Driver Internal Channel
-                    let (driver_tx, mut driver_rx) = tokio::sync::mpsc::channel(100);
-
-                    // Driver State
-                    let mut speech_tasks: HashMap<Uuid, JoinHandle<()>> = HashMap::new();
-                    let mut speech_dedupe: HashMap<Uuid, Instant> = HashMap::new();
                     let status_tx = kernel_tx.clone();
+                    // CalDAV calendar (read-only): fetched once per session, installed for the `calendar_events` tool
+                    if let Some(url) = nexus::integrations::calendar::caldav_url() {
+                        let reactor_for_calendar = reactor_for_thread.clone();
+                        tokio::spawn(async move {
+                            let now_ms = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0);
+                            let user = std::env::var("NEXUS_CALDAV_USER").ok();
+                            let password = std::env::var("NEXUS_CALDAV_PASSWORD").ok();
+                            let window = now_ms + nexus::integrations::calendar::CALDAV_WINDOW_MS;
+                            match nexus::integrations::calendar::fetch_caldav(&url, user.as_deref(), password.as_deref(), now_ms, window).await {
+                                Ok(calendar) => {
+                                    println!("[Calendar] Loaded {} events", calendar.len());
+                                    if let Ok(mut reactor) = reactor_for_calendar.lock() {
+                                        reactor.calendar = calendar;
+                                    }
+                                }
+                                Err(e) => println!("[Calendar] CalDAV fetch failed: {}", e),
+                            }
+                        });
+                    }
 
-                    let mut cadence = interval(Duration::from_millis(nexus::kernel::time::TICK_MS));
-                    let mut audio_child: Option<tokio::sync::oneshot::Sender<()>> = None;
+                    // Fine cadence while active; Dormant and idle: blocked on the channel (NEXUS_LOW_POWER)
+                    let mut cadence = nexus::kernel::cadence::DriverCadence::new(Duration::from_millis(nexus::kernel::time::TICK_MS), nexus::kernel::cadence::LowPowerConfig::from_env());
+                    // Stand-in channel (kept open) when the reactor's is not at hand; the fine cadence never reads it
+                    let (_no_events_tx, mut no_events) = tokio::sync::mpsc::channel::<Event>(1);
+                    // Side effects: playback, generated speech, transcription (NEXUS_WHISPER_MODEL), tools
+                    let mut executor = {
+                        let reactor = reactor_for_thread.lock().unwrap();
+                        nexus::kernel::driver::EffectExecutor::new(&reactor, queues.driver_capacity)
+                            .with_llm(llm_service)
+                            .with_hooks(ShellHooks { app: handle_for_thread.clone(), fidelity: fidelity_for_thread.clone() })
+                            .with_mic_gate(mic_for_thread.clone())
+                    };
+                    if !caption_only {
+                        // Synthesized audio for repeated phrases (pre-warmed with the canned ones)
+                        let canned = reactor_for_thread.lock().map(|r| nexus::outputs::tts_cache::canned_phrases_in(&r.templates)).unwrap_or_default();
+                        tokio::spawn(nexus::outputs::tts_cache::prewarm(executor.tts_cache(), canned));
+                    }
 
-                    cadence.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+                    // Output devices come and go (headset plugged in / out)
+                    let mut route_checked: Option<Instant> = None;
+                    // Half duplex: the UI shows when Nexus stops listening to speak
+                    let mut was_listening = true;
+                    // Thinking indicator while the LLM planner works
+                    let mut last_cognition = nexus::kernel::view::Cognition::Idle;
                     
                     loop {
-                        cadence.tick().await;
-
-                        // Drain Driver Events
-                        while let Ok(evt) = driver_rx.try_recv() {
-                            match evt {
-                                DriverEvent::GeneratedSpeech { output_id, text } => {
-                                    if speech_tasks.contains_key(&output_id) {
-                                        speech_tasks.remove(&output_id);
-                                        // Telemetry: Generated
-                                        let _ = status_tx.send(Event::Telemetry(
-                                            nexus::kernel::telemetry::event::TelemetryEvent::SpeechLifecycle(
-                                                nexus::kernel::telemetry::event::SpeechLifecycleEvent::Generated
-                                            )
-                                        )).await;
-
-                                        println!("[AUDIO-{:?}] Spawning 'say': '{}'", output_id, text);
-                                        // Kill existing
-                                        if let Some(stop_tx) = audio_child.take() {
-                                            let _ = stop_tx.send(()); 
-                                        }
-
-                                        match Command::new("say").arg(&text).kill_on_drop(true).spawn() {
-                                            Ok(mut child) => {
-                                                let tx_clone = status_tx.clone();
-                                                let (stop_tx, mut stop_rx) = tokio::sync::oneshot::channel();
-                                                audio_child = Some(stop_tx);
-                                                tokio::spawn(async move {
-                                                    let _ = tx_clone.send(Event::Input(nexus::kernel::event::InputEvent {
-                                                        source: "Driver".to_string(),
-                                                        content: nexus::kernel::event::InputContent::AudioStatus(
-                                                            nexus::kernel::event::AudioStatus::PlaybackStarted
-                                                        )
-                                                    })).await;
-                                                    tokio::select! { _ = child.wait() => {}, _ = &mut stop_rx => { let _ = child.kill().await; } }
-                                                    let _ = tx_clone.send(Event::Input(nexus::kernel::event::InputEvent {
-                                                        source: "Driver".to_string(),
-                                                        content: nexus::kernel::event::InputContent::AudioStatus(
-                                                            nexus::kernel::event::AudioStatus::PlaybackEnded
-                                                        )
-                                                    })).await;
-                                                });
-                                            },
-                                            Err(e) => println!("[AUDIO] Failed to spawn 'say': {}", e),
-                                        }
+                        let wake = if cadence.mode() == nexus::kernel::cadence::CadenceMode::LowPower {
+                            // Blocked off the reactor lock: commands keep working while Dormant
+                            let taken = reactor_for_thread.lock().ok().map(|mut r| std::mem::replace(&mut r.receiver, tokio::sync::mpsc::channel(1).1));
+                            match taken {
+                                Some(mut receiver) => {
+                                    let wake = cadence.wait(&mut receiver).await;
+                                    if let Ok(mut reactor) = reactor_for_thread.lock() {
+                                        reactor.receiver = receiver;
                                     }
-                                },
-                                DriverEvent::SpeechFailed { output_id } => {
-                                    speech_tasks.remove(&output_id);
-                                    let _ = status_tx.send(Event::Telemetry(
-                                        nexus::kernel::telemetry::event::TelemetryEvent::SpeechLifecycle(
-                                            nexus::kernel::telemetry::event::SpeechLifecycleEvent::Failed
-                                        )
-                                    )).await;
+                                    wake
+                                }
+                                None => cadence.wait(&mut no_events).await,
+                            }
+                        } else {
+                            cadence.wait(&mut no_events).await
+                        };
+
+                        if route_checked.is_none_or(|t| t.elapsed() >= Duration::from_secs(nexus::outputs::device::ROUTE_POLL_SECS)) {
+                            let first = route_checked.is_none();
+                            route_checked = Some(Instant::now());
+                            let available = nexus::outputs::device::output_devices();
+                            if let Ok(mut router) = router_for_thread.lock() {
+                                if router.refresh(&available) || first {
+                                    let _ = status_tx.try_send(output_device_event(&router));
                                 }
                             }
                         }
-                        
+
                         // Drain events and tick
-                        let mut effects = Vec::new();
+                        let mut effects = nexus::kernel::effects::EffectBatch::new();
+                        let mut context = None;
                         {
                             if let Ok(mut reactor) = reactor_for_thread.lock() {
-                                // Drain
-                                let mut events = Vec::new();
+                                reactor.advance_idle(wake.skipped);
+                                // Drain (after the event that ended a low-power wait)
+                                let mut events: Vec<Event> = wake.event.into_iter().collect();
                                 while let Ok(event) = reactor.receiver.try_recv() {
                                     events.push(event);
                                 }
                                 effects = reactor.tick_step(events);
+                                context = Some(nexus::kernel::driver::EffectContext::capture(&reactor, &effects));
                             }
                         }
+                        // Stop effects from a shutdown / restart since the last tick
+                        while let Ok(batch) = control_rx.try_recv() {
+                            effects.extend(batch);
+                        }
+                        // Auto mic: the audio callback forwards only while Attentive/Engaged
+                        mic_for_thread.follow(view_for_thread.borrow().presence);
+                        let listening = view_for_thread.borrow().listening;
+                        if listening != was_listening {
+                            was_listening = listening;
+                            let view = view_for_thread.borrow().clone();
+                            let _ = handle_for_thread.emit("nexus-event", serde_json::json!({
+                                "type": "PresenceUpdate",
+                                "state": view.presence,
+                                "listening": listening,
+                                "conversation_mode": view.conversation_mode,
+                            }));
+                        }
+                        let cognition = view_for_thread.borrow().cognition;
+                        if cognition != last_cognition {
+                            last_cognition = cognition;
+                            let _ = handle_for_thread.emit("nexus-event", serde_json::json!({
+                                "type": "Cognition",
+                                "state": cognition,
+                            }));
+                        }
                         
-                        // Execute side effects OUTSIDE lock
-                        // (Complex side effects like SpawnAudio need async context)
-                        // For now, just log them. Full effect handling is complex.
+                        // Execute side effects OUTSIDE lock, front to back (EffectBatch is phase-ordered)
+                        let Some(context) = context else { continue };
                         for effect in effects {
-                            match effect {
-                                nexus::kernel::scheduler::SideEffect::Log(msg) => println!("[LOG] {}", msg),
-                                nexus::kernel::scheduler::SideEffect::SpawnAudio(id, text) => {
-                                    println!("[AUDIO-{:?}] Spawning 'say': '{}'", id, text);
-                                    
-                                    // 1. Kill existing
-                                    if let Some(stop_tx) = audio_child.take() {
-                                         let _ = stop_tx.send(()); 
-                                    }
+                            executor.execute(effect, &context);
+                        }
+                        executor.poll(&context);
+                        executor.pump();
 
-                                    // 2. Spawn new (macOS only)
-                                    match Command::new("say")
-                                        .arg(&text)
-                                        .kill_on_drop(true)
-                                        .spawn() 
-                                    {
-                                        Ok(mut child) => {
-                                            let tx_clone = kernel_tx.clone();
-                                            let (stop_tx, mut stop_rx) = tokio::sync::oneshot::channel();
-                                            
-                                            tokio::spawn(async move {
-                                                // Signal Started
-                                                println!("[Driver] Audio Process Started");
-                                                let _ = tx_clone.send(Event::Input(nexus::kernel::event::InputEvent {
-                                                    source: "Driver".to_string(),
-                                                    content: nexus::kernel::event::InputContent::AudioStatus(
-                                                        nexus::kernel::event::AudioStatus::PlaybackStarted
-                                                    )
-                                                })).await;
-
-                                                // Race: Completion vs Kill
-                                                tokio::select! {
-                                                    res = child.wait() => {
-                                                        println!("[Driver] Audio Process Exited: {:?}", res);
-                                                    }
-                                                    _ = &mut stop_rx => {
-                                                        println!("[Driver] Audio Process KILLED");
-                                                        let _ = child.kill().await;
-                                                    }
-                                                }
-                                                
-                                                // Signal Ended
-                                                println!("[Driver] Sending PlaybackEnded");
-                                                let _ = tx_clone.send(Event::Input(nexus::kernel::event::InputEvent {
-                                                    source: "Driver".to_string(),
-                                                    content: nexus::kernel::event::InputContent::AudioStatus(
-                                                        nexus::kernel::event::AudioStatus::PlaybackEnded
-                                                    )
-                                                })).await;
-                                            });
-                                            
-                                            audio_child = Some(stop_tx);
-                                        },
-                                        Err(e) => println!("[AUDIO] Failed to spawn: {}", e),
-                                    }
-                                },
-                                nexus::kernel::scheduler::SideEffect::StopAudio => {
-                                    if let Some(stop_tx) = audio_child.take() {
-                                        println!("[AUDIO] KILL SWITCH ACTIVATED.");
-                                        let _ = stop_tx.send(());
-                                    }
-                                    for (_, task) in speech_tasks.drain() { task.abort(); }
-                                    let _ = status_tx.send(Event::Telemetry(
-                                        nexus::kernel::telemetry::event::TelemetryEvent::SpeechLifecycle(
-                                            nexus::kernel::telemetry::event::SpeechLifecycleEvent::Aborted
-                                        )
-                                    )).await;
-                                },
-                                nexus::kernel::scheduler::SideEffect::RequestTranscription { segment_id } => {
-                                    println!("[TRANSCRIPTION] Requested for: {}", segment_id);
-                                }
-                                nexus::kernel::scheduler::SideEffect::AskMemoryConsent { key, prompt_id: _ } => {
-                                    println!("[CONSENT] Asking user for key: {:?}", key);
-                                    let _ = handle_for_thread.emit("ask-memory-consent", serde_json::json!({
-                                        "key": key
-                                    }));
-                                },
-                                // Phase N: LLM Speech
-                                nexus::kernel::scheduler::SideEffect::RequestSpeech { intent, output_id } => {
-                                     // Dedupe
-                                     if let Some(inst) = speech_dedupe.get(&output_id) {
-                                         if inst.elapsed() < Duration::from_secs(10) { continue; }
-                                     }
-                                     speech_dedupe.insert(output_id, Instant::now());
-                                     
-                                     // Telemetry: Requested
-                                     let _ = status_tx.send(Event::Telemetry(
-                                         nexus::kernel::telemetry::event::TelemetryEvent::SpeechLifecycle(
-                                             nexus::kernel::telemetry::event::SpeechLifecycleEvent::Requested
-                                         )
-                                     )).await;
-
-                                     // Spawn Task
-                                     let service = llm_service.clone();
-                                     let dr_tx = driver_tx.clone();
-                                     let oid = output_id;
-                                     
-                                     let task = tokio::spawn(async move {
-                                         // Hard Timeout 2s
-                                         let result = tokio::time::timeout(Duration::from_secs(2), service.generate_speech(intent)).await;
-                                         
-                                         match result {
-                                             Ok(Ok(text)) => {
-                                                 let _ = dr_tx.send(DriverEvent::GeneratedSpeech { output_id: oid, text }).await;
-                                             },
-                                             Ok(Err(e)) => {
-                                                 println!("[LLM] Error: {}", e);
-                                                 let _ = dr_tx.send(DriverEvent::SpeechFailed { output_id: oid }).await;
-                                             },
-                                             Err(_) => { // Timeout
-                                                 println!("[LLM] Timeout");
-                                                 let _ = dr_tx.send(DriverEvent::SpeechFailed { output_id: oid }).await;
-                                             }
-                                         }
-                                     });
-                                     
-                                     speech_tasks.insert(output_id, task);
-                                }
-                            }
+                        if let Some(mode) = cadence.follow(view_for_thread.borrow().presence, executor.is_idle()) {
+                            println!("[Core] Cadence -> {:?}", mode);
                         }
-                        
-                        // Cleanup Dedupe (TTL)
-                        speech_dedupe.retain(|_, time| time.elapsed() < Duration::from_secs(10));
                     }
                 });
             });
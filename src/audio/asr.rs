@@ -0,0 +1,195 @@
+//! Driver-Side ASR Backends.
+//!
+//! `SideEffect::RequestTranscription` hands a finalized segment to the driver's
+//! `TranscriptionQueue`; each job runs one `AsrBackend` over the segment frames and reports
+//! the result back as `InputContent::ProvisionalText`, with the per-word confidence the
+//! backend gave (`Transcript::words`) and the language it heard (`Transcript::language`).
+//!
+//! Backends are blocking (whisper.cpp runs on the CPU); drivers call them off the runtime
+//! threads (`spawn_blocking`). `from_env` picks the backend:
+//! - `NEXUS_WHISPER_MODEL` set (and the `whisper` feature built in): `WhisperAsr` with that
+//!   ggml model file,
+//! - otherwise `MockAsr` (fixed text, for pipeline checks without a model).
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::kernel::audio::segment::WordConfidence;
+
+// Config Constants
+/// whisper.cpp only accepts 16kHz mono.
+pub const WHISPER_SAMPLE_RATE: u32 = 16_000;
+pub const MOCK_TEXT: &str = "Phase E verification successful. Gate is working.";
+pub const MOCK_CONFIDENCE: f32 = 0.9;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AsrError {
+    /// The model could not be loaded (missing file, wrong format)
+    Model(String),
+    /// The backend failed on this segment
+    Inference(String),
+}
+
+/// Text of one segment, word by word.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Transcript {
+    pub text: String,
+    pub words: Vec<WordConfidence>,
+    /// Language the segment was transcribed in (ISO 639-1; `None`: the backend cannot tell)
+    pub language: Option<String>,
+}
+
+impl Transcript {
+    /// Mean word confidence (0.0 if there are no words).
+    pub fn confidence(&self) -> f32 {
+        if self.words.is_empty() {
+            return 0.0;
+        }
+        self.words.iter().map(|w| w.confidence).sum::<f32>() / self.words.len() as f32
+    }
+
+    /// PURE FUNCTION: `text` with every word at the same confidence.
+    pub fn uniform(text: &str, confidence: f32) -> Self {
+        Self {
+            text: text.to_string(),
+            words: text.split_whitespace().map(|w| WordConfidence { word: w.to_string(), confidence }).collect(),
+            language: None,
+        }
+    }
+
+    /// PURE FUNCTION: Join sub-word tokens into words. A token starting with a space starts a
+    /// new word (whisper's BPE convention); a word's confidence is the mean of its tokens'.
+    pub fn from_tokens(tokens: &[(String, f32)]) -> Self {
+        let mut words: Vec<(String, Vec<f32>)> = Vec::new();
+        for (text, p) in tokens {
+            match words.last_mut() {
+                Some((word, probs)) if !text.starts_with(char::is_whitespace) => {
+                    word.push_str(text);
+                    probs.push(*p);
+                }
+                _ => {
+                    let text = text.trim_start();
+                    if !text.is_empty() {
+                        words.push((text.to_string(), vec![*p]));
+                    }
+                }
+            }
+        }
+        let words: Vec<WordConfidence> = words
+            .into_iter()
+            .map(|(word, probs)| WordConfidence { word, confidence: probs.iter().sum::<f32>() / probs.len() as f32 })
+            .collect();
+        let text = words.iter().map(|w| w.word.as_str()).collect::<Vec<_>>().join(" ");
+        Self { text, words, language: None }
+    }
+
+    /// Tag the transcript with the language it was heard in (see `kernel::language::normalize`).
+    pub fn in_language(mut self, language: Option<&str>) -> Self {
+        self.language = language.and_then(crate::kernel::language::normalize);
+        self
+    }
+}
+
+pub trait AsrBackend: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Transcribe one mono segment in `language` (the pinned language; `None`: detect it). Blocking.
+    fn transcribe(&self, frames: &[f32], sample_rate: u32, language: Option<&str>) -> Result<Transcript, AsrError>;
+}
+
+/// Fixed text after a fixed delay. Stands in for a model in pipeline checks.
+#[derive(Debug, Clone)]
+pub struct MockAsr {
+    pub text: String,
+    pub latency: Duration,
+    /// Language "detected" when none is pinned
+    pub language: Option<String>,
+}
+
+impl Default for MockAsr {
+    fn default() -> Self {
+        Self { text: MOCK_TEXT.to_string(), latency: Duration::from_millis(500), language: Some("en".to_string()) }
+    }
+}
+
+impl AsrBackend for MockAsr {
+    fn name(&self) -> &'static str {
+        "mock"
+    }
+
+    fn transcribe(&self, _frames: &[f32], _sample_rate: u32, language: Option<&str>) -> Result<Transcript, AsrError> {
+        std::thread::sleep(self.latency);
+        Ok(Transcript::uniform(&self.text, MOCK_CONFIDENCE).in_language(language.or(self.language.as_deref())))
+    }
+}
+
+/// whisper.cpp (via whisper-rs) with a local ggml model. Greedy decoding; the language is
+/// detected per segment unless one is pinned (needs a multilingual model).
+#[cfg(feature = "whisper")]
+pub struct WhisperAsr {
+    context: whisper_rs::WhisperContext,
+}
+
+#[cfg(feature = "whisper")]
+impl WhisperAsr {
+    pub fn load(model_path: &str) -> Result<Self, AsrError> {
+        let context = whisper_rs::WhisperContext::new_with_params(model_path, whisper_rs::WhisperContextParameters::default())
+            .map_err(|e| AsrError::Model(format!("{}: {:?}", model_path, e)))?;
+        Ok(Self { context })
+    }
+}
+
+#[cfg(feature = "whisper")]
+impl AsrBackend for WhisperAsr {
+    fn name(&self) -> &'static str {
+        "whisper"
+    }
+
+    fn transcribe(&self, frames: &[f32], sample_rate: u32, language: Option<&str>) -> Result<Transcript, AsrError> {
+        let inference = |e: whisper_rs::WhisperError| AsrError::Inference(format!("{:?}", e));
+        let factor = (sample_rate / WHISPER_SAMPLE_RATE).max(1) as usize;
+        let audio = crate::audio::decimate::decimate(frames, factor);
+
+        let mut params = whisper_rs::FullParams::new(whisper_rs::SamplingStrategy::Greedy { best_of: 1 });
+        params.set_language(Some(language.unwrap_or("auto")));
+        params.set_no_context(true);
+        params.set_print_special(false);
+        params.set_print_progress(false);
+        params.set_print_realtime(false);
+        params.set_print_timestamps(false);
+
+        let mut state = self.context.create_state().map_err(inference)?;
+        state.full(params, &audio).map_err(inference)?;
+
+        // Special tokens ([_BEG_], <|endoftext|>, timestamps) sort after the text tokens
+        let eot = self.context.token_eot();
+        let mut tokens = Vec::new();
+        for segment in 0..state.full_n_segments().map_err(inference)? {
+            for token in 0..state.full_n_tokens(segment).map_err(inference)? {
+                if state.full_get_token_id(segment, token).map_err(inference)? >= eot {
+                    continue;
+                }
+                let text = state.full_get_token_text_lossy(segment, token).map_err(inference)?;
+                tokens.push((text, state.full_get_token_prob(segment, token).map_err(inference)?));
+            }
+        }
+        let detected = match language {
+            Some(_) => None,
+            None => whisper_rs::get_lang_str(state.full_lang_id_from_state().map_err(inference)?),
+        };
+        Ok(Transcript::from_tokens(&tokens).in_language(language.or(detected)))
+    }
+}
+
+/// The backend named by the environment (see module docs). A model that fails to load
+/// falls back to the mock, with an error in the log.
+pub fn from_env() -> Arc<dyn AsrBackend> {
+    #[cfg(feature = "whisper")]
+    if let Some(path) = std::env::var("NEXUS_WHISPER_MODEL").ok().filter(|s| !s.is_empty()) {
+        match WhisperAsr::load(&path) {
+            Ok(whisper) => return Arc::new(whisper),
+            Err(e) => tracing::error!("[ASR] Whisper model not loaded, using mock: {:?}", e),
+        }
+    }
+    Arc::new(MockAsr::default())
+}
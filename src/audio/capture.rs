@@ -2,33 +2,97 @@ use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use ringbuf::traits::Producer;
 use tracing::{info, error};
 
+/// Capture Device Configuration.
+#[derive(Debug, Clone)]
+pub struct CaptureConfig {
+    /// Input device name (substring match). `None` = host default.
+    pub device_name: Option<String>,
+    /// Sample rates to try, in order. VAD supports 8k, 16k, 32k and 48k only.
+    pub preferred_rates: Vec<u32>,
+    /// Ring buffer capacity (samples) between the cpal callback and the AudioProcessor.
+    pub ring_capacity: usize,
+}
+
+impl Default for CaptureConfig {
+    fn default() -> Self {
+        Self {
+            device_name: None,
+            // We prioritize 16000 for efficiency.
+            preferred_rates: vec![16000, 32000, 48000, 8000],
+            // ~0.5s at 16kHz: room for GC/jitter without overflow
+            ring_capacity: 8192,
+        }
+    }
+}
+
+impl CaptureConfig {
+    /// Defaults, with the device overridable via `NEXUS_AUDIO_DEVICE`.
+    pub fn from_env() -> Self {
+        Self {
+            device_name: std::env::var("NEXUS_AUDIO_DEVICE").ok().filter(|s| !s.is_empty()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Tell the kernel which microphone is in use (its audio calibration is per device pair).
+pub fn input_device_event(device: Option<String>) -> crate::kernel::event::Event {
+    crate::kernel::event::Event::Input(crate::kernel::event::InputEvent {
+        source: "Capture".to_string(),
+        content: crate::kernel::event::InputContent::InputDevice { device },
+        captured_at: None,
+    })
+}
+
 pub struct AudioCapture {
     _stream: cpal::Stream,
+    /// Device the stream opened
+    pub device_name: String,
     pub sample_rate: u32,
+    /// Device channel count. Samples are downmixed to mono before the ring buffer.
+    pub channels: u16,
 }
 
 impl AudioCapture {
-    pub fn new<P>(mut producer: P) -> Result<Self, anyhow::Error> 
+    pub fn new<P>(producer: P) -> Result<Self, anyhow::Error>
+    where
+        P: Producer<Item = f32> + Send + 'static,
+    {
+        Self::with_config(producer, &CaptureConfig::default())
+    }
+
+    pub fn with_config<P>(producer: P, capture_config: &CaptureConfig) -> Result<Self, anyhow::Error>
+    where
+        P: Producer<Item = f32> + Send + 'static,
+    {
+        Self::supervised(producer, capture_config, crate::audio::supervisor::Heartbeat::new())
+    }
+
+    /// `with_config`, beating `heartbeat` on every captured buffer (see `audio::supervisor`).
+    pub fn supervised<P>(mut producer: P, capture_config: &CaptureConfig, heartbeat: crate::audio::supervisor::Heartbeat) -> Result<Self, anyhow::Error>
     where
         P: Producer<Item = f32> + Send + 'static,
     {
         let host = cpal::default_host();
-        let device = host.default_input_device()
-            .ok_or_else(|| anyhow::anyhow!("No input device available"))?;
+        let device = match &capture_config.device_name {
+            Some(name) => host.input_devices()?
+                .find(|d| d.name().map(|n| n.contains(name.as_str())).unwrap_or(false))
+                .ok_or_else(|| anyhow::anyhow!("Input device not found: {}", name))?,
+            None => host.default_input_device()
+                .ok_or_else(|| anyhow::anyhow!("No input device available"))?,
+        };
         
-        info!("Audio Input Device: {}", device.name().unwrap_or_default());
+        let device_name = device.name().unwrap_or_default();
+        info!("Audio Input Device: {}", device_name);
 
-        let _supported_configs_range = device.supported_input_configs()?;
-        
-        // We look for a config that supports standard VAD rates: 16000, 32000, 48000
-        // We prioritize 16000 for efficiency.
-        let target_rates = [16000, 32000, 48000, 8000];
+        // We look for a config that supports standard VAD rates.
+        let target_rates = &capture_config.preferred_rates;
         let mut selected_config = None;
         let mut selected_rate = 0;
 
         // Naive search for supported config
         // In reality, we might need to pick a range and clamp
-        for &rate in &target_rates {
+        for &rate in target_rates {
              let configs = device.supported_input_configs()?;
              for config_range in configs {
                  if config_range.min_sample_rate().0 <= rate && config_range.max_sample_rate().0 >= rate {
@@ -53,7 +117,8 @@ impl AudioCapture {
              def
         };
         
-        info!("Audio Config Selected: Rate={}Hz, Channels={}", selected_rate, config.channels());
+        let channels = config.channels();
+        info!("Audio Config Selected: Rate={}Hz, Channels={}", selected_rate, channels);
 
         let err_fn = |err| error!("an error occurred on stream: {}", err);
         
@@ -61,7 +126,8 @@ impl AudioCapture {
             cpal::SampleFormat::F32 => device.build_input_stream(
                 &config.into(),
                 move |data: &[f32], _: &_| {
-                    write_input_data(data, &mut producer)
+                    heartbeat.beat();
+                    write_input_data(data, channels, &mut producer)
                 },
                 err_fn,
                 None,
@@ -69,7 +135,8 @@ impl AudioCapture {
             cpal::SampleFormat::I16 => device.build_input_stream(
                 &config.into(),
                 move |data: &[i16], _: &_| {
-                    write_input_data_i16(data, &mut producer)
+                    heartbeat.beat();
+                    write_input_data_i16(data, channels, &mut producer)
                 },
                 err_fn,
                 None,
@@ -81,27 +148,172 @@ impl AudioCapture {
 
         Ok(Self {
             _stream: stream,
+            device_name,
             sample_rate: selected_rate,
+            channels,
         })
     }
 }
 
-fn write_input_data<P>(input: &[f32], producer: &mut P)
+fn write_input_data<P>(input: &[f32], channels: u16, producer: &mut P)
 where
     P: Producer<Item = f32>,
 {
     // If producer is full, we drop inputs (lossy)
     // Ringbuf push_slice might return partial
-    producer.push_slice(input);
+    if channels <= 1 {
+        producer.push_slice(input);
+        return;
+    }
+    // Downmix interleaved frames to mono (VAD expects a single channel)
+    for frame in input.chunks(channels as usize) {
+        let _ = producer.try_push(frame.iter().sum::<f32>() / frame.len() as f32);
+    }
 }
 
-fn write_input_data_i16<P>(input: &[i16], producer: &mut P)
+fn write_input_data_i16<P>(input: &[i16], channels: u16, producer: &mut P)
 where
     P: Producer<Item = f32>,
 {
-    // Convert to f32
-    for &sample in input {
-        let sample_f32 = sample as f32 / i16::MAX as f32;
-        let _ = producer.try_push(sample_f32);
+    // Convert to f32 (downmixing interleaved frames to mono)
+    for frame in input.chunks(channels.max(1) as usize) {
+        let sum: f32 = frame.iter().map(|&s| s as f32 / i16::MAX as f32).sum();
+        let _ = producer.try_push(sum / frame.len() as f32);
+    }
+}
+
+// === CAPTURE ACTOR ===
+// Owns the cpal stream on a dedicated thread (streams are not Send on every
+// platform) and feeds the ring buffer into the VAD AudioProcessor. Lets the
+// headless binary start/stop the microphone the same way the shell does.
+// Supervised (`audio::supervisor`): a lost actor is restarted.
+
+#[cfg(feature = "vad")]
+pub enum CaptureCommand {
+    Start,
+    Stop,
+}
+
+/// Handle to a running CaptureActor. Dropping it shuts the actor (and its supervisor) down.
+#[cfg(feature = "vad")]
+pub struct CaptureController {
+    actor: crate::audio::supervisor::Supervised<CaptureCommand>,
+}
+
+#[cfg(feature = "vad")]
+impl CaptureController {
+    // try_send: callable from both async and sync contexts
+    pub fn start(&self) {
+        self.actor.set_running(true);
+        let _ = self.actor.sender().try_send(CaptureCommand::Start);
+    }
+
+    pub fn stop(&self) {
+        self.actor.set_running(false);
+        let _ = self.actor.sender().try_send(CaptureCommand::Stop);
+    }
+
+    /// Times the actor was lost and restarted.
+    pub fn restarts(&self) -> u64 {
+        self.actor.restarts()
+    }
+}
+
+#[cfg(feature = "vad")]
+pub struct CaptureActor {
+    config: CaptureConfig,
+    core_tx: tokio::sync::mpsc::Sender<crate::kernel::event::Event>,
+    cmd_rx: tokio::sync::mpsc::Receiver<CaptureCommand>,
+    active: Option<AudioCapture>,
+    // Kernel tick: speech edges are stamped when heard
+    clock: crate::kernel::time::CaptureClock,
+    // Beaten by the stream callback (supervision)
+    heartbeat: crate::audio::supervisor::Heartbeat,
+}
+
+#[cfg(feature = "vad")]
+impl CaptureActor {
+    pub fn new(
+        config: CaptureConfig,
+        cmd_rx: tokio::sync::mpsc::Receiver<CaptureCommand>,
+        core_tx: tokio::sync::mpsc::Sender<crate::kernel::event::Event>,
+        clock: crate::kernel::time::CaptureClock,
+        heartbeat: crate::audio::supervisor::Heartbeat,
+    ) -> Self {
+        Self { config, core_tx, cmd_rx, active: None, clock, heartbeat }
+    }
+
+    /// Spawns the actor on its own thread (stopped) under supervision and returns its controller.
+    /// `clock`: `Reactor::capture_clock()`.
+    pub fn spawn(
+        config: CaptureConfig,
+        core_tx: tokio::sync::mpsc::Sender<crate::kernel::event::Event>,
+        clock: crate::kernel::time::CaptureClock,
+    ) -> CaptureController {
+        let supervisor_tx = core_tx.clone();
+        let launch = move |heartbeat: crate::audio::supervisor::Heartbeat| {
+            let (cmd_tx, cmd_rx) = tokio::sync::mpsc::channel(8);
+            let (config, core_tx, clock) = (config.clone(), core_tx.clone(), clock.clone());
+            let thread = std::thread::Builder::new()
+                .name("nexus-capture".to_string())
+                .spawn(move || CaptureActor::new(config, cmd_rx, core_tx, clock, heartbeat).run())
+                .expect("Failed to spawn capture thread");
+            (cmd_tx, thread)
+        };
+        let actor = crate::audio::supervisor::Supervised::spawn(launch, || CaptureCommand::Start, supervisor_tx);
+        CaptureController { actor }
+    }
+
+    pub fn run(mut self) {
+        info!("[Capture] Actor Started.");
+        while let Some(cmd) = self.cmd_rx.blocking_recv() {
+            match cmd {
+                CaptureCommand::Start => {
+                    if self.active.is_some() {
+                        info!("[Capture] Stream already running.");
+                        continue;
+                    }
+                    match self.start_stream() {
+                        Ok(capture) => self.active = Some(capture),
+                        Err(e) => error!("[Capture] Failed to start stream: {}", e),
+                    }
+                }
+                CaptureCommand::Stop => {
+                    // Dropping the stream drops the producer; the processor drains and exits.
+                    if self.active.take().is_some() {
+                        info!("[Capture] Stream Stopped.");
+                    }
+                }
+            }
+        }
+        info!("[Capture] Controller dropped. Actor exiting.");
+    }
+
+    fn start_stream(&self) -> Result<AudioCapture, anyhow::Error> {
+        use ringbuf::traits::Split;
+
+        // OS privacy settings, where readable. Known-blocked: report instead of capturing silence.
+        use crate::kernel::presence::CapturePermission;
+        let permission = crate::audio::permission::os_privacy_state();
+        if permission != CapturePermission::Unknown {
+            let _ = self.core_tx.blocking_send(crate::audio::permission::permission_event(permission));
+        }
+        if matches!(permission, CapturePermission::Denied | CapturePermission::Restricted) {
+            return Err(anyhow::anyhow!("Microphone access {:?}", permission));
+        }
+
+        let (producer, consumer) = ringbuf::HeapRb::<f32>::new(self.config.ring_capacity).split();
+        let capture = AudioCapture::supervised(producer, &self.config, self.heartbeat.clone())?;
+        // The actual device, also when it is the host default: swapping headsets swaps the default
+        let device = Some(capture.device_name.clone()).filter(|name| !name.is_empty());
+        let _ = self.core_tx.blocking_send(input_device_event(device));
+        let rate = capture.sample_rate;
+        let tx = self.core_tx.clone();
+        let clock = self.clock.clone();
+        std::thread::Builder::new()
+            .name("nexus-vad".to_string())
+            .spawn(move || crate::audio::processing::AudioProcessor::new(consumer, tx, rate, clock).run())?;
+        info!("[Capture] Stream Started at {}Hz.", rate);
+        Ok(capture)
     }
 }
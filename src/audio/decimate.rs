@@ -0,0 +1,143 @@
+//! Push-Mode Chunk Shaping (capture side).
+//!
+//! Full-rate chunks are only needed while the kernel buffers a segment (they
+//! end up in the WAV). Otherwise the kernel just runs energy VAD, so the capture
+//! layer sends a reduced `AudioSummary` instead. The kernel requests fidelity via
+//! `SideEffect::SetCaptureFidelity`; drivers forward it to the `FidelitySwitch`.
+//!
+//! The kernel only opens a segment after `min_speech_ms` of energy, and the switch
+//! flips a round trip later, so the onset went out as summaries. The shaper keeps
+//! the last full-rate samples while Reduced and sends them ahead of the first Full
+//! chunk: the segment starts with the first syllable (leading silence is trimmed
+//! before ASR, `kernel::audio::trim`).
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::kernel::event::{AudioSummary, CaptureFidelity, InputContent};
+
+/// Full-rate audio kept while Reduced: default speech onset (120ms) plus driver latency.
+pub const DEFAULT_PREROLL_MS: u32 = 500;
+
+/// What to send while fidelity is Reduced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReducedMode {
+    /// Mono chunk decimated to (roughly) `target_rate`.
+    Decimate { target_rate: u32 },
+    /// One RMS value per chunk.
+    Envelope,
+}
+
+impl Default for ReducedMode {
+    fn default() -> Self {
+        ReducedMode::Decimate { target_rate: 16000 }
+    }
+}
+
+/// Fidelity flag shared between the driver (writer) and the audio callback (reader).
+/// Lock-free: the audio thread must never block.
+#[derive(Debug, Clone, Default)]
+pub struct FidelitySwitch(Arc<AtomicBool>); // true = Full
+
+impl FidelitySwitch {
+    /// Starts Reduced (no segment is buffering at boot).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&self, fidelity: CaptureFidelity) {
+        self.0.store(fidelity == CaptureFidelity::Full, Ordering::Relaxed);
+    }
+
+    pub fn get(&self) -> CaptureFidelity {
+        if self.0.load(Ordering::Relaxed) { CaptureFidelity::Full } else { CaptureFidelity::Reduced }
+    }
+}
+
+/// Turns raw interleaved device buffers into kernel InputContent.
+pub struct ChunkShaper {
+    pub input_rate: u32,
+    pub channels: u16,
+    pub mode: ReducedMode,
+    switch: FidelitySwitch,
+    // Mono pre-roll ring (full rate), flushed into the first Full chunk
+    preroll: VecDeque<f32>,
+    preroll_capacity: usize,
+}
+
+impl ChunkShaper {
+    pub fn new(input_rate: u32, channels: u16, mode: ReducedMode, switch: FidelitySwitch) -> Self {
+        Self { input_rate, channels, mode, switch, preroll: VecDeque::new(), preroll_capacity: 0 }
+            .with_preroll_ms(DEFAULT_PREROLL_MS)
+    }
+
+    /// Pre-roll length; raise it with `VadConfig::min_speech_ms` (0 turns it off).
+    pub fn with_preroll_ms(mut self, ms: u32) -> Self {
+        self.preroll_capacity = (self.input_rate as u64 * ms as u64 / 1000) as usize;
+        self.preroll = VecDeque::with_capacity(self.preroll_capacity);
+        self
+    }
+
+    pub fn shape(&mut self, interleaved: &[f32]) -> InputContent {
+        let mono = downmix(interleaved, self.channels);
+        if self.switch.get() == CaptureFidelity::Full {
+            if self.preroll.is_empty() {
+                return InputContent::AudioChunk(mono);
+            }
+            let mut chunk: Vec<f32> = self.preroll.drain(..).collect();
+            chunk.extend_from_slice(&mono);
+            return InputContent::AudioChunk(chunk);
+        }
+        self.remember(&mono);
+        match self.mode {
+            ReducedMode::Decimate { target_rate } => {
+                let factor = (self.input_rate / target_rate.max(1)).max(1) as usize;
+                InputContent::AudioSummary(AudioSummary::Decimated {
+                    samples: decimate(&mono, factor),
+                    sample_rate: self.input_rate / factor as u32,
+                })
+            }
+            ReducedMode::Envelope => {
+                let sq_sum: f32 = mono.iter().map(|&x| x * x).sum();
+                let rms = if mono.is_empty() { 0.0 } else { (sq_sum / mono.len() as f32).sqrt() };
+                InputContent::AudioSummary(AudioSummary::Envelope {
+                    rms,
+                    duration_ms: (mono.len() as u64 * 1000) / self.input_rate.max(1) as u64,
+                })
+            }
+        }
+    }
+
+    // Keep the newest `preroll_capacity` samples
+    fn remember(&mut self, mono: &[f32]) {
+        if self.preroll_capacity == 0 {
+            return;
+        }
+        let keep = &mono[mono.len().saturating_sub(self.preroll_capacity)..];
+        let overflow = (self.preroll.len() + keep.len()).saturating_sub(self.preroll_capacity);
+        self.preroll.drain(..overflow);
+        self.preroll.extend(keep);
+    }
+}
+
+/// Average interleaved frames down to a single channel.
+pub fn downmix(interleaved: &[f32], channels: u16) -> Vec<f32> {
+    if channels <= 1 {
+        return interleaved.to_vec();
+    }
+    interleaved
+        .chunks(channels as usize)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect()
+}
+
+/// Box-filter decimation: averages each run of `factor` samples (crude low-pass,
+/// enough for energy VAD).
+pub fn decimate(mono: &[f32], factor: usize) -> Vec<f32> {
+    if factor <= 1 {
+        return mono.to_vec();
+    }
+    mono.chunks(factor)
+        .map(|run| run.iter().sum::<f32>() / run.len() as f32)
+        .collect()
+}
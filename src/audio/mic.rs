@@ -0,0 +1,97 @@
+//! Microphone Management (capture side).
+//!
+//! `Manual`: the UI mic toggle starts and stops the stream. `Auto`: the stream runs
+//! continuously and the audio callback forwards chunks unless presence is Suspended: a paused
+//! Nexus hears nothing, the gate is in the capture layer. While Dormant the kernel VAD still
+//! segments and ASR still transcribes, so the wake word can be heard, but a transcript only goes
+//! on to the planner if it opens with a wake phrase (`requires_wake_word`, applied by the
+//! transcription job). Drivers move the `MicGate` from the published State View.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::presence::PresenceState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MicMode {
+    /// The mic toggle decides; captured audio is always forwarded
+    #[default]
+    Manual,
+    /// Always capturing; forwarded unless paused (`forwards_audio`), addressed by name while Dormant
+    Auto,
+}
+
+impl MicMode {
+    /// From `NEXUS_MIC_MODE` (`auto` or `manual`; default manual).
+    pub fn from_env() -> Self {
+        match std::env::var("NEXUS_MIC_MODE").as_deref() {
+            Ok("auto") => MicMode::Auto,
+            _ => MicMode::Manual,
+        }
+    }
+}
+
+/// PURE FUNCTION: Does auto mode forward captured audio in this presence state?
+/// Everything but a pause: a Dormant Nexus must still hear its wake word.
+pub fn forwards_audio(presence: PresenceState) -> bool {
+    presence != PresenceState::Suspended
+}
+
+/// PURE FUNCTION: Must an auto-mode transcript open with the wake word to reach the planner?
+pub fn needs_wake_word(presence: PresenceState) -> bool {
+    presence == PresenceState::Dormant
+}
+
+/// Mode and forwarding flag shared between the driver (writer) and the audio callback (reader).
+/// Lock-free: the audio thread must never block. Starts Manual (open).
+#[derive(Debug, Clone)]
+pub struct MicGate {
+    auto: Arc<AtomicBool>,
+    open: Arc<AtomicBool>,
+    wake_only: Arc<AtomicBool>,
+}
+
+impl Default for MicGate {
+    fn default() -> Self {
+        Self { auto: Arc::new(AtomicBool::new(false)), open: Arc::new(AtomicBool::new(true)), wake_only: Arc::new(AtomicBool::new(false)) }
+    }
+}
+
+impl MicGate {
+    pub fn new(mode: MicMode) -> Self {
+        let gate = Self::default();
+        gate.auto.store(mode == MicMode::Auto, Ordering::Relaxed);
+        // Auto starts closed until the driver reports presence
+        gate.open.store(mode == MicMode::Manual, Ordering::Relaxed);
+        gate
+    }
+
+    pub fn mode(&self) -> MicMode {
+        if self.auto.load(Ordering::Relaxed) { MicMode::Auto } else { MicMode::Manual }
+    }
+
+    /// Switch modes; `presence` is the current state (the gate is right at once, not on the next tick).
+    pub fn set_mode(&self, mode: MicMode, presence: PresenceState) {
+        self.auto.store(mode == MicMode::Auto, Ordering::Relaxed);
+        self.follow(presence);
+    }
+
+    /// Driver, after every tick: Manual stays open, Auto opens exactly when presence forwards audio.
+    pub fn follow(&self, presence: PresenceState) {
+        let auto = self.mode() == MicMode::Auto;
+        self.open.store(!auto || forwards_audio(presence), Ordering::Relaxed);
+        self.wake_only.store(auto && needs_wake_word(presence), Ordering::Relaxed);
+    }
+
+    /// Audio callback: forward this chunk?
+    pub fn is_open(&self) -> bool {
+        self.open.load(Ordering::Relaxed)
+    }
+
+    /// Transcription: drop a transcript that does not open with a wake phrase?
+    pub fn requires_wake_word(&self) -> bool {
+        self.wake_only.load(Ordering::Relaxed)
+    }
+}
@@ -1,2 +1,10 @@
+pub mod asr;
 pub mod capture;
+pub mod decimate;
+pub mod mic;
+pub mod permission;
+pub mod supervisor;
+pub mod transcription;
+pub mod wake;
+#[cfg(feature = "vad")]
 pub mod processing;
@@ -0,0 +1,94 @@
+//! Microphone Permission Probing (capture side).
+//!
+//! On macOS and Windows a stream opened without microphone permission does not fail:
+//! it delivers digital silence. Two probes turn that into an explicit
+//! `InputContent::CapturePermission` report:
+//! - `os_privacy_state()` reads the OS privacy setting where it is readable without
+//!   platform frameworks (Windows consent store). Elsewhere it reports `Unknown`.
+//! - `SilenceProbe` watches the first samples of a stream. Real microphones always have
+//!   a noise floor, so a window of exact zeros means the OS is withholding audio.
+
+use crate::kernel::event::{Event, InputContent, InputEvent};
+use crate::kernel::presence::CapturePermission;
+
+// Config Constants
+const PROBE_WINDOW_MS: u32 = 1500;
+
+/// Build the kernel event for a permission report.
+pub fn permission_event(state: CapturePermission) -> Event {
+    Event::Input(InputEvent {
+        source: "Capture".to_string(),
+        content: InputContent::CapturePermission { state },
+        captured_at: None,
+    })
+}
+
+/// OS microphone privacy state, before opening a stream.
+#[cfg(target_os = "windows")]
+pub fn os_privacy_state() -> CapturePermission {
+    const STORE: &str = r"Software\Microsoft\Windows\CurrentVersion\CapabilityAccessManager\ConsentStore\microphone";
+    let query = |hive: &str| -> Option<String> {
+        let out = std::process::Command::new("reg")
+            .args(["query", &format!(r"{}\{}", hive, STORE), "/v", "Value"])
+            .output()
+            .ok()?;
+        let text = String::from_utf8_lossy(&out.stdout).to_string();
+        text.split_whitespace().last().map(|v| v.to_string())
+    };
+    // Machine-wide policy wins over the user's toggle
+    if query("HKLM").as_deref() == Some("Deny") {
+        return CapturePermission::Restricted;
+    }
+    match query("HKCU").as_deref() {
+        Some("Allow") => CapturePermission::Granted,
+        Some("Deny") => CapturePermission::Denied,
+        Some(_) => CapturePermission::NotDetermined,
+        None => CapturePermission::Unknown,
+    }
+}
+
+/// OS microphone privacy state, before opening a stream.
+/// Not readable without platform frameworks here; `SilenceProbe` decides once a stream runs.
+#[cfg(not(target_os = "windows"))]
+pub fn os_privacy_state() -> CapturePermission {
+    CapturePermission::Unknown
+}
+
+/// One-shot check on a freshly opened stream.
+#[derive(Debug, Clone)]
+pub struct SilenceProbe {
+    remaining: usize,
+    done: bool,
+}
+
+impl SilenceProbe {
+    /// `sample_rate` counts frames (mono) or interleaved samples alike: only the window length depends on it.
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            remaining: (sample_rate as usize * PROBE_WINDOW_MS as usize) / 1000,
+            done: false,
+        }
+    }
+
+    /// Feed captured samples. Returns the verdict exactly once:
+    /// `Granted` at the first non-zero sample, `Denied` after a full window of exact zeros.
+    pub fn observe(&mut self, samples: &[f32]) -> Option<CapturePermission> {
+        if self.done {
+            return None;
+        }
+        if samples.iter().any(|s| *s != 0.0) {
+            self.done = true;
+            return Some(CapturePermission::Granted);
+        }
+        self.remaining = self.remaining.saturating_sub(samples.len());
+        if self.remaining == 0 {
+            self.done = true;
+            return Some(CapturePermission::Denied);
+        }
+        None
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+}
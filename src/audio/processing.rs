@@ -1,6 +1,7 @@
 use ringbuf::traits::Consumer;
 use tokio::sync::mpsc;
 use crate::kernel::event::{Event, InputEvent, AudioSignal, InputContent};
+use crate::kernel::time::CaptureClock;
 use tracing::{info, debug};
 // use webrtc_vad::{Vad, SampleRate}; // Depending on crate version api
 
@@ -10,6 +11,8 @@ where C: Consumer<Item = f32> + Send
     consumer: C,
     tx: mpsc::Sender<Event>,
     sample_rate: u32,
+    // Kernel tick, to stamp edges with when they were heard
+    clock: CaptureClock,
     
     // State
     is_speaking: bool,
@@ -20,11 +23,12 @@ where C: Consumer<Item = f32> + Send
 impl<C> AudioProcessor<C>
 where C: Consumer<Item = f32> + Send
 {
-    pub fn new(consumer: C, tx: mpsc::Sender<Event>, sample_rate: u32) -> Self {
+    pub fn new(consumer: C, tx: mpsc::Sender<Event>, sample_rate: u32, clock: CaptureClock) -> Self {
         Self {
             consumer,
             tx,
             sample_rate,
+            clock,
             is_speaking: false,
             consecutive_speech: 0,
             consecutive_silence: 0,
@@ -52,6 +56,9 @@ where C: Consumer<Item = f32> + Send
              }
         }
 
+        // Microphone permission: an OS withholding audio delivers exact zeros
+        let mut permission_probe = crate::audio::permission::SilenceProbe::new(self.sample_rate);
+
         let mut frame_buf_f32: Vec<f32> = vec![0.0; frame_size];
         let mut frame_buf_i16: Vec<i16> = vec![0; frame_size];
 
@@ -63,12 +70,21 @@ where C: Consumer<Item = f32> + Send
             // 1. Read Frame
             // We need a full frame. If missing, sleep briefly.
             if self.consumer.occupied_len() < frame_size {
+                // Capture stream dropped (producer gone): nothing more will arrive
+                if !self.consumer.write_is_held() {
+                    info!("Audio Processor Stopped: capture closed");
+                    return;
+                }
                 std::thread::sleep(std::time::Duration::from_millis(10));
                 continue;
             }
 
             // Pop samples
             let _ = self.consumer.pop_slice(&mut frame_buf_f32);
+            if let Some(state) = permission_probe.observe(&frame_buf_f32) {
+                info!("Audio Processor: capture permission {:?}", state);
+                let _ = self.tx.blocking_send(crate::audio::permission::permission_event(state));
+            }
             
             // 2. Convert f32 -> i16
             for (i, &sample) in frame_buf_f32.iter().enumerate() {
@@ -94,20 +110,23 @@ where C: Consumer<Item = f32> + Send
                 self.consecutive_silence += 1;
             }
 
+            // Edges are stamped at the first frame of the run that confirmed them (the debounce
+            // window), plus whatever is still queued behind this frame in the ring buffer.
+            let backlog_ms = (self.consumer.occupied_len() * 1000) as u64 / self.sample_rate as u64;
             if !self.is_speaking && self.consecutive_speech >= min_speech_frames {
                 self.is_speaking = true;
                 info!("Audio Control: Speech START detected");
-                let _ = self.tx.blocking_send(Event::Input(InputEvent {
-                    source: "Audio".to_string(),
-                    content: InputContent::Audio(AudioSignal::SpeechStart),
-                }));
+                let at = self.clock.ago_ms(backlog_ms + (min_speech_frames * frame_ms) as u64);
+                let _ = self.tx.blocking_send(Event::Input(InputEvent::captured(
+                    "Audio", InputContent::Audio(AudioSignal::SpeechStart), at,
+                )));
             } else if self.is_speaking && self.consecutive_silence >= min_silence_frames {
                 self.is_speaking = false;
                 info!("Audio Control: Speech END detected");
-                let _ = self.tx.blocking_send(Event::Input(InputEvent {
-                    source: "Audio".to_string(),
-                    content: InputContent::Audio(AudioSignal::SpeechEnd),
-                }));
+                let at = self.clock.ago_ms(backlog_ms + (min_silence_frames * frame_ms) as u64);
+                let _ = self.tx.blocking_send(Event::Input(InputEvent::captured(
+                    "Audio", InputContent::Audio(AudioSignal::SpeechEnd), at,
+                )));
             }
         }
     }
@@ -0,0 +1,195 @@
+//! Capture Supervision (capture side).
+//!
+//! A device error can panic the audio actor thread or kill the stream inside the cpal
+//! callback. Nothing reports that: the microphone just goes quiet. The capture callback
+//! beats a `Heartbeat` on every buffer, and `Supervised` watches it next to the actor's
+//! thread. A running stream with a dead thread or a silent heartbeat is lost: the actor is
+//! launched again, told to start, and the kernel gets `InputContent::CaptureRestarted` so a
+//! segment spanning the gap is discarded.
+//!
+//! Restarts back off (`RESTART_BACKOFF_MAX`) while the device keeps failing, and the
+//! heartbeat is only judged while the driver wants the stream running.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use tokio::sync::mpsc;
+
+use crate::kernel::event::{Event, InputContent, InputEvent};
+
+// Config Constants
+/// No capture buffer for this long while running: the stream is lost. Callbacks arrive every ~10-20ms.
+pub const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(2);
+/// How often the supervisor thread checks.
+pub const SUPERVISE_INTERVAL: Duration = Duration::from_millis(500);
+/// Failed restarts in a row double the wait, up to 2^4 x the timeout (32s).
+const RESTART_BACKOFF_MAX: u32 = 4;
+
+/// Tell the kernel the capture actor was restarted (audio across the gap is unreliable).
+pub fn capture_restarted_event() -> Event {
+    Event::Input(InputEvent {
+        source: "Capture".to_string(),
+        content: InputContent::CaptureRestarted,
+        captured_at: None,
+    })
+}
+
+/// Beaten by the capture callback, read by the supervisor. Lock-free: the audio thread must never block.
+#[derive(Debug, Clone)]
+pub struct Heartbeat {
+    origin: Instant,
+    // Ms since `origin` of the last beat
+    last: Arc<AtomicU64>,
+}
+
+impl Default for Heartbeat {
+    fn default() -> Self {
+        Self { origin: Instant::now(), last: Arc::new(AtomicU64::new(0)) }
+    }
+}
+
+impl Heartbeat {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn beat(&self) {
+        self.last.store(self.origin.elapsed().as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Time since the last beat (or since creation), as of `now`.
+    pub fn silent_for(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.origin + Duration::from_millis(self.last.load(Ordering::Relaxed)))
+    }
+}
+
+/// Restart policy: when is a capture actor lost, and may it be restarted yet?
+#[derive(Debug, Clone)]
+pub struct CaptureSupervisor {
+    heartbeat: Heartbeat,
+    timeout: Duration,
+    // Restarts since the stream was last healthy (backoff)
+    failures: u32,
+    last_restart: Option<Instant>,
+    restarts: u64,
+}
+
+impl Default for CaptureSupervisor {
+    fn default() -> Self {
+        Self::new(HEARTBEAT_TIMEOUT)
+    }
+}
+
+impl CaptureSupervisor {
+    pub fn new(timeout: Duration) -> Self {
+        Self { heartbeat: Heartbeat::new(), timeout, failures: 0, last_restart: None, restarts: 0 }
+    }
+
+    /// Handle for the capture callback.
+    pub fn heartbeat(&self) -> Heartbeat {
+        self.heartbeat.clone()
+    }
+
+    /// Restarts so far.
+    pub fn restarts(&self) -> u64 {
+        self.restarts
+    }
+
+    /// Should the actor be restarted now? `running`: the driver wants the stream open.
+    /// `thread_alive`: the actor's thread has not exited (panicked).
+    pub fn check(&mut self, now: Instant, running: bool, thread_alive: bool) -> bool {
+        let silent = self.heartbeat.silent_for(now);
+        if !running || (thread_alive && silent < self.timeout) {
+            self.failures = 0;
+            return false;
+        }
+        let backoff = self.timeout * 2u32.pow(self.failures.min(RESTART_BACKOFF_MAX));
+        self.last_restart.is_none_or(|at| now.saturating_duration_since(at) >= backoff)
+    }
+
+    /// The actor was launched again: the new stream gets a full timeout to beat.
+    pub fn restarted(&mut self, now: Instant) {
+        self.failures += 1;
+        self.restarts += 1;
+        self.last_restart = Some(now);
+        self.heartbeat.beat();
+    }
+
+    /// Stream (re)started on request: a fresh timeout, not a failure.
+    pub fn started(&mut self) {
+        self.heartbeat.beat();
+    }
+}
+
+/// A capture actor kept alive by a supervisor thread. `launch` spawns the actor on its own
+/// thread with the heartbeat its callback beats, and returns its command sender and thread.
+/// Commands go through `sender()`: a restart swaps the actor behind it.
+pub struct Supervised<C> {
+    cmd_tx: Arc<Mutex<mpsc::Sender<C>>>,
+    running: Arc<AtomicBool>,
+    supervisor: Arc<Mutex<CaptureSupervisor>>,
+}
+
+impl<C: Send + 'static> Supervised<C> {
+    /// `start`: the command that opens the stream, sent to a restarted actor.
+    pub fn spawn<L>(launch: L, start: fn() -> C, core_tx: mpsc::Sender<Event>) -> Self
+    where
+        L: Fn(Heartbeat) -> (mpsc::Sender<C>, JoinHandle<()>) + Send + 'static,
+    {
+        let supervisor = Arc::new(Mutex::new(CaptureSupervisor::default()));
+        let (cmd_tx, mut thread) = launch(supervisor.lock().unwrap_or_else(|e| e.into_inner()).heartbeat());
+        let supervised = Self {
+            cmd_tx: Arc::new(Mutex::new(cmd_tx)),
+            running: Arc::new(AtomicBool::new(false)),
+            supervisor: supervisor.clone(),
+        };
+        let (slot, running) = (supervised.cmd_tx.clone(), supervised.running.clone());
+        let spawned = std::thread::Builder::new()
+            .name("nexus-capture-supervisor".to_string())
+            .spawn(move || loop {
+                std::thread::sleep(SUPERVISE_INTERVAL);
+                // Controller dropped: nothing left to supervise
+                if Arc::strong_count(&slot) == 1 {
+                    return;
+                }
+                let mut policy = supervisor.lock().unwrap_or_else(|e| e.into_inner());
+                let now = Instant::now();
+                if !policy.check(now, running.load(Ordering::Relaxed), !thread.is_finished()) {
+                    continue;
+                }
+                tracing::warn!("[Capture] Audio actor lost, restarting (restart {})", policy.restarts() + 1);
+                policy.restarted(now);
+                let (tx, relaunched) = launch(policy.heartbeat());
+                drop(policy);
+                thread = relaunched;
+                // The old actor sees its channel close and drops its stream
+                *slot.lock().unwrap_or_else(|e| e.into_inner()) = tx.clone();
+                let _ = tx.try_send(start());
+                let _ = core_tx.try_send(capture_restarted_event());
+            });
+        if let Err(e) = spawned {
+            tracing::error!("[Capture] No supervisor thread: {}", e);
+        }
+        supervised
+    }
+
+    /// The current actor's command sender.
+    pub fn sender(&self) -> mpsc::Sender<C> {
+        self.cmd_tx.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// The driver opened (or closed) the stream. Supervision only restarts a running stream.
+    pub fn set_running(&self, running: bool) {
+        if running && !self.running.swap(true, Ordering::Relaxed) {
+            self.supervisor.lock().unwrap_or_else(|e| e.into_inner()).started();
+        }
+        self.running.store(running, Ordering::Relaxed);
+    }
+
+    /// Restarts so far.
+    pub fn restarts(&self) -> u64 {
+        self.supervisor.lock().unwrap_or_else(|e| e.into_inner()).restarts()
+    }
+}
@@ -0,0 +1,171 @@
+//! Driver-Side Transcription Queue.
+//!
+//! Every `SideEffect::RequestTranscription` used to spawn its own WAV + ASR task.
+//! The queue bounds how many run at once, drops work the kernel no longer wants
+//! (`SideEffect::CancelTranscription`), and reports its depth for telemetry.
+//!
+//! Not part of the kernel: the driver owns the queue and pumps it once per tick.
+
+use std::collections::{HashMap, VecDeque};
+use tokio::task::JoinHandle;
+
+pub const DEFAULT_MAX_CONCURRENT: usize = 2;
+
+struct Job {
+    segment_id: String,
+    frames: Vec<f32>,
+}
+
+/// Queued + in-flight counts (queue-depth telemetry).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueueDepth {
+    pub queued: usize,
+    pub in_flight: usize,
+}
+
+pub struct TranscriptionQueue {
+    max_concurrent: usize,
+    pending: VecDeque<Job>,
+    running: HashMap<String, JoinHandle<()>>,
+}
+
+impl TranscriptionQueue {
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            max_concurrent: max_concurrent.max(1),
+            pending: VecDeque::new(),
+            running: HashMap::new(),
+        }
+    }
+
+    /// Queue a segment. Ignored if it is already queued or running.
+    pub fn enqueue(&mut self, segment_id: String, frames: Vec<f32>) {
+        if self.contains(&segment_id) {
+            return;
+        }
+        self.pending.push_back(Job { segment_id, frames });
+    }
+
+    pub fn contains(&self, segment_id: &str) -> bool {
+        self.running.contains_key(segment_id) || self.pending.iter().any(|j| j.segment_id == segment_id)
+    }
+
+    /// Drop a queued job or abort a running one. Returns false if the segment was unknown.
+    pub fn cancel(&mut self, segment_id: &str) -> bool {
+        if let Some(task) = self.running.remove(segment_id) {
+            task.abort();
+            return true;
+        }
+        let before = self.pending.len();
+        self.pending.retain(|j| j.segment_id != segment_id);
+        self.pending.len() != before
+    }
+
+    /// Reap finished tasks and start queued jobs up to the concurrency limit (FIFO).
+    pub fn pump(&mut self, mut spawn: impl FnMut(String, Vec<f32>) -> JoinHandle<()>) {
+        self.running.retain(|_, task| !task.is_finished());
+        while self.running.len() < self.max_concurrent {
+            let Some(job) = self.pending.pop_front() else { break };
+            let task = spawn(job.segment_id.clone(), job.frames);
+            self.running.insert(job.segment_id, task);
+        }
+    }
+
+    pub fn depth(&self) -> QueueDepth {
+        QueueDepth { queued: self.pending.len(), in_flight: self.running.len() }
+    }
+}
+
+/// WAV hand-off + ASR for one segment, in `language` (the pinned language; `None`: detect).
+/// Reports the transcript back as `ProvisionalText` (mean word confidence, plus the words and
+/// the language). Failures and empty transcripts send nothing:
+/// the kernel cancels the segment like any other stalled transcription.
+/// With `wake`, a transcript opening with a wake phrase is preceded by `WakeWord` and sent
+/// without the phrase ("nexus, yes" arrives as "yes"); a bare wake phrase sends no text.
+/// If the phrase is `required`, any other transcript is dropped.
+#[cfg(feature = "asr")]
+pub fn spawn_transcription(
+    backend: std::sync::Arc<dyn crate::audio::asr::AsrBackend>,
+    segment_id: String,
+    frames: Vec<f32>,
+    sample_rate: u32,
+    language: Option<String>,
+    wake: Option<crate::audio::wake::WakeSpotting>,
+    tx: tokio::sync::mpsc::Sender<crate::kernel::event::Event>,
+) -> JoinHandle<()> {
+    use tracing::{error, info};
+    tokio::spawn(async move {
+        // 1. Write to WAV (Temp, for inspection)
+        let file_path = format!("/tmp/nexus_seg_{}.wav", segment_id);
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+        match hound::WavWriter::create(&file_path, spec) {
+            Ok(mut writer) => {
+                let written = frames.iter().try_for_each(|&sample| writer.write_sample(sample)).and_then(|_| writer.finalize());
+                match written {
+                    Ok(()) => info!("[TRANSCRIPTION] Saved WAV to {}", file_path),
+                    Err(e) => error!("[TRANSCRIPTION] Failed to write WAV file: {}", e),
+                }
+            }
+            Err(e) => error!("[TRANSCRIPTION] Failed to write WAV file: {}", e),
+        }
+
+        // 2. ASR (blocking backend, off the runtime threads)
+        let name = backend.name();
+        let transcript = match tokio::task::spawn_blocking(move || backend.transcribe(&frames, sample_rate, language.as_deref())).await {
+            Ok(Ok(transcript)) => transcript,
+            Ok(Err(e)) => {
+                error!("[TRANSCRIPTION] {} failed for {}: {:?}", name, segment_id, e);
+                return;
+            }
+            Err(e) => {
+                error!("[TRANSCRIPTION] {} panicked for {}: {}", name, segment_id, e);
+                return;
+            }
+        };
+        if transcript.text.trim().is_empty() {
+            info!("[TRANSCRIPTION] No words in {}", segment_id);
+            return;
+        }
+
+        // 3. Wake word (ahead of the transcript, so presence is Engaged when it arrives)
+        let heard = wake.as_ref().and_then(|wake| wake.detector.detect(&transcript.text));
+        if heard.is_none() && wake.is_some_and(|wake| wake.required) {
+            info!("[TRANSCRIPTION] Not addressed while dormant, {} dropped", segment_id);
+            return;
+        }
+        let mut transcript = transcript;
+        if let Some(heard) = heard {
+            info!("[TRANSCRIPTION] Wake word {:?} in {}", heard.phrase, segment_id);
+            let _ = tx.send(crate::kernel::event::Event::Input(crate::kernel::event::InputEvent {
+                source: "ASR".to_string(),
+                content: crate::kernel::event::InputContent::WakeWord { phrase: heard.phrase },
+                captured_at: None,
+            })).await;
+            if heard.trailing.is_empty() {
+                return;
+            }
+            // The words after the phrase (backends report one per transcribed word)
+            let skip = transcript.words.len().saturating_sub(heard.trailing.split_whitespace().count());
+            transcript.words.drain(..skip);
+            transcript.text = heard.trailing;
+        }
+
+        let _ = tx.send(crate::kernel::event::Event::Input(crate::kernel::event::InputEvent {
+            source: "ASR".to_string(),
+            content: crate::kernel::event::InputContent::ProvisionalText {
+                confidence: transcript.confidence(),
+                content: transcript.text,
+                source_id: segment_id,
+                words: transcript.words,
+                language: transcript.language,
+                is_final: true,
+            },
+            captured_at: None,
+        })).await;
+    })
+}
@@ -0,0 +1,95 @@
+//! Wake Word: keyword spotting on transcriptions.
+//!
+//! Every ASR transcript is checked for a wake phrase at its start ("nexus", "hey nexus, ...").
+//! A match is reported as `InputContent::WakeWord` ahead of the transcript, and the kernel turns
+//! it into `PresenceRequest::WakeWordDetected`: Dormant -> Attentive -> Engaged. Whatever was
+//! said after the phrase is sent as the transcript, without the phrase; a bare wake phrase
+//! sends no text.
+//!
+//! Spotting runs on transcripts. Both mic modes wake from Dormant: `MicMode::Auto` keeps
+//! capturing while Dormant, and there only transcripts opening with a wake phrase go on
+//! (`WakeSpotting::required`).
+
+use std::sync::Arc;
+
+// Config Constants
+/// Wake phrases when `NEXUS_WAKE_WORDS` is not set.
+pub const DEFAULT_WAKE_WORDS: [&str; 1] = ["nexus"];
+/// Greetings allowed ahead of the phrase ("hey", "ok", ...).
+const LEAD_WORDS: [&str; 5] = ["hey", "hi", "hello", "ok", "okay"];
+/// At most this many lead words ("ok hey nexus").
+const MAX_LEAD_WORDS: usize = 2;
+
+/// A wake phrase heard at the start of a transcript.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WakeWordMatch {
+    /// The configured phrase that matched
+    pub phrase: String,
+    /// What was said after it, as transcribed (empty for a bare wake phrase)
+    pub trailing: String,
+}
+
+/// Wake phrases for one transcription job.
+#[derive(Debug, Clone)]
+pub struct WakeSpotting {
+    pub detector: Arc<WakeWordDetector>,
+    /// Only a transcript opening with a wake phrase goes on (auto mic while Dormant)
+    pub required: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct WakeWordDetector {
+    // Each phrase as normalized words
+    phrases: Vec<Vec<String>>,
+}
+
+impl Default for WakeWordDetector {
+    fn default() -> Self {
+        Self::new(&DEFAULT_WAKE_WORDS)
+    }
+}
+
+// Lowercase, without surrounding punctuation ("Nexus," -> "nexus")
+fn normalize(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase()
+}
+
+impl WakeWordDetector {
+    /// Phrases of one or more words; blank phrases are ignored.
+    pub fn new(phrases: &[&str]) -> Self {
+        let phrases = phrases
+            .iter()
+            .map(|p| p.split_whitespace().map(normalize).filter(|w| !w.is_empty()).collect::<Vec<_>>())
+            .filter(|p| !p.is_empty())
+            .collect();
+        Self { phrases }
+    }
+
+    /// From `NEXUS_WAKE_WORDS` (comma-separated phrases; default `DEFAULT_WAKE_WORDS`).
+    pub fn from_env() -> Self {
+        match std::env::var("NEXUS_WAKE_WORDS").ok().filter(|s| !s.trim().is_empty()) {
+            Some(list) => Self::new(&list.split(',').collect::<Vec<_>>()),
+            None => Self::default(),
+        }
+    }
+
+    /// PURE FUNCTION: The wake phrase `text` starts with, after up to `MAX_LEAD_WORDS` greetings.
+    /// A phrase later in the sentence is not a wake word ("I told nexus yesterday").
+    pub fn detect(&self, text: &str) -> Option<WakeWordMatch> {
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let normalized: Vec<String> = words.iter().map(|w| normalize(w)).collect();
+        for lead in 0..=MAX_LEAD_WORDS.min(normalized.len()) {
+            if !normalized[..lead].iter().all(|w| LEAD_WORDS.contains(&w.as_str())) {
+                break;
+            }
+            let rest = &normalized[lead..];
+            if let Some(phrase) = self.phrases.iter().find(|p| rest.starts_with(p)) {
+                return Some(WakeWordMatch {
+                    phrase: phrase.join(" "),
+                    trailing: words[lead + phrase.len()..].join(" "),
+                });
+            }
+        }
+        None
+    }
+}
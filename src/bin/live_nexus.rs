@@ -51,18 +51,20 @@ async fn main() {
     let rate = capture.sample_rate;
     tracing::info!("Audio Capture Initialized at {}Hz", rate);
     
-    std::thread::spawn(move || {
-        nexus::audio::processing::AudioProcessor::new(consumer, tx_audio, rate).run();
-    });
-    
     // The capture struct holds the stream. It must be kept alive.
     // We can move it into a task that just waits, or keep it in main scope.
     // Main loop runs 'reactor.run()'. If we keep `capture` in a variable here, it lives until main ends.
     // That is sufficient.
 
     // Initial Config
-    let config = nexus::kernel::reactor::ReactorConfig { safe_mode: false };
+    let config = nexus::kernel::reactor::ReactorConfig { safe_mode: false, ..Default::default() };
     let mut reactor = Reactor::new(rx_input, tx_input.clone(), config);
+
+    // Audio edges are stamped against the kernel's tick
+    let clock = reactor.capture_clock();
+    std::thread::spawn(move || {
+        nexus::audio::processing::AudioProcessor::new(consumer, tx_audio, rate, clock).run();
+    });
     
     // 4. Spawn Input Reader (Stdin)
     tokio::spawn(async move {
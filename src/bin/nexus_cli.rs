@@ -0,0 +1,57 @@
+//! `nexus-cli`: drive the kernel from stdin, no audio hardware (see `kernel::repl`).
+//!
+//! Flags: `--llm` plans with the local LLM (ticks then run in real time), `--seed N` pins ids.
+
+use std::io::{BufRead, Write};
+use std::time::Duration;
+
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::repl::{ReplCommand, ReplDriver};
+use nexus::kernel::time::TICK_MS;
+use tokio::sync::mpsc;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Kernel logs go to stderr (RUST_LOG), the session to stdout
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "warn".into()))
+        .with_writer(std::io::stderr)
+        .init();
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let llm_planning = args.iter().any(|a| a == "--llm");
+    let id_seed = args.iter().position(|a| a == "--seed").and_then(|i| args.get(i + 1)).and_then(|s| s.parse().ok());
+
+    let (tx, rx) = mpsc::channel(100);
+    let reactor = Reactor::new(rx, tx, ReactorConfig { llm_planning, id_seed, ..Default::default() });
+    let mut driver = ReplDriver::new(reactor);
+    if llm_planning {
+        driver = driver.paced(Duration::from_millis(TICK_MS));
+    }
+
+    println!("nexus-cli: type to talk, `:say TEXT` to speak, `:tick [N]`, `:state`, `:quit`");
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+    loop {
+        print!("> ");
+        stdout.flush()?;
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            break;
+        }
+        let command = match ReplCommand::parse(&line) {
+            Ok(Some(command)) => command,
+            Ok(None) => continue,
+            Err(e) => {
+                println!("{:?}", e);
+                continue;
+            }
+        };
+        match command {
+            ReplCommand::Quit => break,
+            ReplCommand::State => driver.summary().iter().for_each(|line| println!("{}", line)),
+            command => driver.run(command).iter().for_each(|tick| println!("{}", tick.render())),
+        }
+    }
+    Ok(())
+}
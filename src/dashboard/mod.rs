@@ -0,0 +1,107 @@
+//! Remote Observation Dashboard (read-only).
+//!
+//! Lets an alpha tester's session be watched remotely, with their consent. A
+//! `DashboardSnapshot` is everything an observer may see: live presence (the
+//! `StateView`), the intent board, telemetry counters and the last few dialogue turns.
+//! Sensitive turns and intents are redacted before they leave the Reactor.
+//!
+//! The snapshot is always compiled (the shell and tests use it). The HTTP server is
+//! behind the `dashboard` feature (`server`).
+//!
+//! Invariant: observation only. The server exposes GET routes and never sends events.
+
+#[cfg(feature = "dashboard")]
+pub mod server;
+
+use std::net::SocketAddr;
+use serde::Serialize;
+
+use crate::kernel::context::ContextId;
+use crate::kernel::intent::long_horizon::IntentStatus;
+use crate::kernel::intent::types::IntentHypothesis;
+use crate::kernel::memory::sensitivity::{RedactionLevel, SensitivityClass};
+use crate::kernel::dialogue::DialogueTurn;
+use crate::kernel::reactor::Reactor;
+use crate::kernel::telemetry::metrics::TelemetrySnapshot;
+use crate::kernel::view::StateView;
+
+// Config Constants
+const DEFAULT_BIND: &str = "127.0.0.1:7878"; // Loopback unless the tester opts in to more
+const DEFAULT_TURNS: usize = 20;
+const REDACTED: &str = "[redacted]";
+
+#[derive(Debug, Clone)]
+pub struct DashboardConfig {
+    pub bind: SocketAddr,
+    /// Dialogue turns shown (most recent).
+    pub turns: usize,
+    pub redaction: RedactionLevel,
+    /// The tester agreed to remote observation. The server refuses to start without it.
+    pub consent: bool,
+}
+
+impl Default for DashboardConfig {
+    fn default() -> Self {
+        Self {
+            bind: DEFAULT_BIND.parse().expect("valid default bind address"),
+            turns: DEFAULT_TURNS,
+            redaction: RedactionLevel::Mask,
+            consent: false,
+        }
+    }
+}
+
+/// One row of the intent board.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IntentCard {
+    pub id: String,
+    pub hypothesis: IntentHypothesis,
+    pub status: IntentStatus,
+    pub decay_score: f32,
+    pub context: ContextId,
+    pub sensitivity: Option<SensitivityClass>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DashboardSnapshot {
+    pub view: StateView,
+    pub intents: Vec<IntentCard>,
+    pub telemetry: TelemetrySnapshot,
+    pub turns: Vec<DialogueTurn>,
+}
+
+impl DashboardSnapshot {
+    /// Capture what an observer may see right now. Never mutates the Reactor.
+    pub fn capture(reactor: &Reactor, config: &DashboardConfig) -> Self {
+        let view = StateView::capture(reactor.tick, reactor.mode, reactor.audio_monitor.is_system_speaking(), reactor.config.conversation_mode, reactor.cognition(), reactor.queue.stats(), &reactor.state)
+            .with_volume(reactor.output_volume());
+
+        let mut intents: Vec<IntentCard> = reactor.state.active_intents().values()
+            .filter_map(|intent| {
+                let sensitivity = reactor.state.sensitive_intents().get(&intent.id).copied();
+                let id = match (sensitivity, config.redaction) {
+                    (Some(_), RedactionLevel::Omit) => return None,
+                    (Some(_), RedactionLevel::Mask) => REDACTED.to_string(),
+                    _ => intent.id.clone(),
+                };
+                Some(IntentCard {
+                    id,
+                    hypothesis: intent.hypothesis.clone(),
+                    status: intent.status,
+                    decay_score: intent.decay_score,
+                    context: intent.context.clone(),
+                    sensitivity,
+                })
+            })
+            .collect();
+        // Most alive first
+        intents.sort_by(|a, b| b.decay_score.partial_cmp(&a.decay_score).unwrap_or(std::cmp::Ordering::Equal));
+
+        Self {
+            view,
+            intents,
+            telemetry: reactor.telemetry.snapshot(),
+            turns: reactor.dialogue.recent(config.turns, config.redaction),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Dashboard HTTP server (feature `dashboard`).
+//!
+//! Routes (GET only):
+//! - `/`: single-page dashboard that polls the snapshot once a second.
+//! - `/api/snapshot`: the current `DashboardSnapshot` as JSON.
+
+use std::sync::{Arc, Mutex};
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::Html;
+use axum::routing::get;
+use axum::{Json, Router};
+
+use super::{DashboardConfig, DashboardSnapshot};
+use crate::kernel::reactor::Reactor;
+
+const INDEX_HTML: &str = include_str!("dashboard.html");
+
+#[derive(Clone)]
+struct DashboardState {
+    reactor: Arc<Mutex<Reactor>>,
+    config: Arc<DashboardConfig>,
+}
+
+/// Read-only routes over a shared Reactor.
+pub fn router(reactor: Arc<Mutex<Reactor>>, config: DashboardConfig) -> Router {
+    Router::new()
+        .route("/", get(index))
+        .route("/api/snapshot", get(snapshot))
+        .with_state(DashboardState { reactor, config: Arc::new(config) })
+}
+
+/// Serve until the listener fails. Refuses to start without the tester's consent.
+pub async fn serve(reactor: Arc<Mutex<Reactor>>, config: DashboardConfig) -> anyhow::Result<()> {
+    if !config.consent {
+        anyhow::bail!("remote observation needs the tester's consent (DashboardConfig::consent)");
+    }
+    let listener = tokio::net::TcpListener::bind(config.bind).await?;
+    tracing::info!("[Dashboard] Serving read-only dashboard on http://{}", config.bind);
+    axum::serve(listener, router(reactor, config)).await?;
+    Ok(())
+}
+
+async fn index() -> Html<&'static str> {
+    Html(INDEX_HTML)
+}
+
+async fn snapshot(State(state): State<DashboardState>) -> Result<Json<DashboardSnapshot>, StatusCode> {
+    // Lock only long enough to copy; the tick loop is never held up by a slow client
+    let snapshot = {
+        let reactor = state.reactor.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        DashboardSnapshot::capture(&reactor, &state.config)
+    };
+    Ok(Json(snapshot))
+}
@@ -0,0 +1,115 @@
+//! End-to-end encrypted envelopes for federation records.
+//!
+//! Each device holds a long-lived X25519 `DeviceKey`. Pairing exchanges public keys out of
+//! band (e.g. a QR code). Both sides derive the same AES-256-GCM key with HKDF-SHA256 over
+//! the shared secret, bound to the two device ids. Relays and transports only ever see a
+//! `SyncEnvelope`: sender, recipient, nonce and ciphertext.
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::{anyhow, bail, Result};
+use hkdf::Hkdf;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+use super::{DeviceId, SyncRecord};
+
+// Config Constants
+const KEY_INFO: &[u8] = b"nexus-federation-v1";
+
+/// Long-lived device identity.
+pub struct DeviceKey {
+    secret: StaticSecret,
+}
+
+impl DeviceKey {
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+        Self::from_bytes(bytes)
+    }
+
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self { secret: StaticSecret::from(bytes) }
+    }
+
+    /// Secret key material, for the device's keychain. Never sent to a peer.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.secret.to_bytes()
+    }
+
+    /// Shared with peers at pairing.
+    pub fn public(&self) -> [u8; 32] {
+        PublicKey::from(&self.secret).to_bytes()
+    }
+}
+
+/// One sealed batch of records.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncEnvelope {
+    pub from: DeviceId,
+    pub to: DeviceId,
+    pub nonce: Vec<u8>,
+    pub ciphertext: Vec<u8>,
+}
+
+/// Pairwise channel between this device and one peer.
+pub struct SecureChannel {
+    local: DeviceId,
+    peer: DeviceId,
+    cipher: Aes256Gcm,
+}
+
+impl SecureChannel {
+    pub fn new(local: &str, key: &DeviceKey, peer: &str, peer_public: [u8; 32]) -> Self {
+        let shared = key.secret.diffie_hellman(&PublicKey::from(peer_public));
+        // Same salt on both sides: device ids in sorted order
+        let (a, b) = if local < peer { (local, peer) } else { (peer, local) };
+        let salt = format!("{}\n{}", a, b);
+        let mut okm = [0u8; 32];
+        Hkdf::<Sha256>::new(Some(salt.as_bytes()), shared.as_bytes())
+            .expand(KEY_INFO, &mut okm)
+            .expect("32 bytes is a valid HKDF-SHA256 output length");
+        Self {
+            local: local.to_string(),
+            peer: peer.to_string(),
+            cipher: Aes256Gcm::new(&okm.into()),
+        }
+    }
+
+    pub fn seal(&self, records: &[SyncRecord]) -> Result<SyncEnvelope> {
+        let plaintext = serde_json::to_vec(records)?;
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let aad = aad(&self.local, &self.peer);
+        let ciphertext = self.cipher
+            .encrypt(&nonce, Payload { msg: &plaintext, aad: aad.as_bytes() })
+            .map_err(|_| anyhow!("federation: encryption failed"))?;
+        Ok(SyncEnvelope {
+            from: self.local.clone(),
+            to: self.peer.clone(),
+            nonce: nonce.to_vec(),
+            ciphertext,
+        })
+    }
+
+    /// Rejects envelopes not addressed from our peer to us, and any tampering.
+    pub fn open(&self, envelope: &SyncEnvelope) -> Result<Vec<SyncRecord>> {
+        if envelope.from != self.peer || envelope.to != self.local {
+            bail!("federation: envelope {} -> {} is not for this channel", envelope.from, envelope.to);
+        }
+        if envelope.nonce.len() != 12 {
+            bail!("federation: bad nonce length {}", envelope.nonce.len());
+        }
+        let aad = aad(&envelope.from, &envelope.to);
+        let plaintext = self.cipher
+            .decrypt(Nonce::from_slice(&envelope.nonce), Payload { msg: &envelope.ciphertext, aad: aad.as_bytes() })
+            .map_err(|_| anyhow!("federation: envelope failed authentication"))?;
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+}
+
+fn aad(from: &str, to: &str) -> String {
+    format!("{}->{}", from, to)
+}
@@ -0,0 +1,329 @@
+//! Kernel Federation (desktop + phone).
+//!
+//! Optional replication of long-term memory records and long-horizon intents between
+//! kernels owned by the same user. Each kernel keeps a `Replica`: one `SyncRecord` per
+//! memory / intent, stamped with a `VersionVector` bumped whenever the local copy changes.
+//!
+//! Exchange: the driver calls `Reactor::sync_outgoing(peer)`, seals the records with the
+//! `channel` (feature `federation`, end-to-end encrypted) and ships the envelope over any
+//! transport. The receiving driver opens it and feeds `InputContent::SyncReceived`; the
+//! Reactor applies accepted records as ordinary StateDeltas inside `tick_step`.
+//!
+//! Conflicts: a record that dominates ours wins, a dominated one is ignored. Concurrent
+//! edits resolve deterministically on both sides (see `resolve`): forgetting wins, then
+//! terminal intent status, then strength / decay score, then the origin device id.
+//!
+//! Consent is per device (`SyncScope`) and off by default. Nothing is sent to or accepted
+//! from a peer outside its scope. Sensitive memories and intents never leave the device.
+
+#[cfg(feature = "federation")]
+pub mod channel;
+
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::intent::long_horizon::{IntentId, IntentStatus, LongHorizonIntent};
+use crate::kernel::memory::types::{MemoryId, MemoryRecord};
+use crate::kernel::state::{SharedState, StateDelta};
+use crate::kernel::time::Tick;
+
+// Config Constants
+const STRENGTH_STEPS: f32 = 20.0; // Decay below 1/20 of strength is not a replicated edit
+
+pub type DeviceId = String;
+
+/// Per-device edit counters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionVector(BTreeMap<DeviceId, u64>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Causality {
+    Equal,
+    /// `self` happened before `other` (other dominates)
+    Before,
+    /// `self` dominates `other`
+    After,
+    Concurrent,
+}
+
+impl VersionVector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, device: &str) -> u64 {
+        self.0.get(device).copied().unwrap_or(0)
+    }
+
+    pub fn bump(&mut self, device: &str) {
+        *self.0.entry(device.to_string()).or_insert(0) += 1;
+    }
+
+    /// Pointwise maximum.
+    pub fn join(&mut self, other: &VersionVector) {
+        for (device, &count) in &other.0 {
+            let mine = self.0.entry(device.clone()).or_insert(0);
+            *mine = (*mine).max(count);
+        }
+    }
+
+    pub fn compare(&self, other: &VersionVector) -> Causality {
+        let mut less = false;
+        let mut greater = false;
+        for device in self.0.keys().chain(other.0.keys()) {
+            match self.get(device).cmp(&other.get(device)) {
+                Ordering::Less => less = true,
+                Ordering::Greater => greater = true,
+                Ordering::Equal => {}
+            }
+        }
+        match (less, greater) {
+            (false, false) => Causality::Equal,
+            (true, false) => Causality::Before,
+            (false, true) => Causality::After,
+            (true, true) => Causality::Concurrent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum SyncKey {
+    Memory(MemoryId),
+    Intent(IntentId),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SyncPayload {
+    Memory(MemoryRecord),
+    Intent(LongHorizonIntent),
+    /// Tombstone: the memory was forgotten (explicitly or by decay).
+    MemoryForgotten,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncRecord {
+    pub key: SyncKey,
+    pub clock: VersionVector,
+    /// Device that made the winning edit (tie-breaker for concurrent edits)
+    pub origin: DeviceId,
+    pub payload: SyncPayload,
+}
+
+/// What a peer device may exchange with this kernel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncScope {
+    pub memories: bool,
+    pub intents: bool,
+}
+
+impl SyncScope {
+    pub const NONE: SyncScope = SyncScope { memories: false, intents: false };
+    pub const ALL: SyncScope = SyncScope { memories: true, intents: true };
+
+    pub fn allows(&self, key: &SyncKey) -> bool {
+        match key {
+            SyncKey::Memory(_) => self.memories,
+            SyncKey::Intent(_) => self.intents,
+        }
+    }
+}
+
+struct Entry {
+    record: SyncRecord,
+    // Content digest of the local copy (ticks excluded: they are per-kernel clocks)
+    digest: u64,
+}
+
+/// This kernel's view of the replicated set.
+pub struct Replica {
+    pub device: DeviceId,
+    consent: HashMap<DeviceId, SyncScope>,
+    entries: HashMap<SyncKey, Entry>,
+}
+
+impl Replica {
+    pub fn new(device: impl Into<DeviceId>) -> Self {
+        Self {
+            device: device.into(),
+            consent: HashMap::new(),
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Device id from `NEXUS_DEVICE_ID`, else "local".
+    pub fn from_env() -> Self {
+        Self::new(std::env::var("NEXUS_DEVICE_ID").unwrap_or_else(|_| "local".to_string()))
+    }
+
+    pub fn set_consent(&mut self, peer: &str, scope: SyncScope) {
+        self.consent.insert(peer.to_string(), scope);
+    }
+
+    pub fn consent(&self, peer: &str) -> SyncScope {
+        self.consent.get(peer).copied().unwrap_or(SyncScope::NONE)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Stamp local edits since the last call: changed records bump our clock entry,
+    /// memories that disappeared become tombstones.
+    pub fn observe(&mut self, state: &SharedState) {
+        for record in state.long_term_memory().values().filter(|r| r.sensitivity.is_none()) {
+            self.stamp(SyncKey::Memory(record.id.clone()), SyncPayload::Memory(record.clone()));
+        }
+        for intent in state.active_intents().values().filter(|i| !state.sensitive_intents().contains_key(&i.id)) {
+            self.stamp(SyncKey::Intent(intent.id.clone()), SyncPayload::Intent(intent.clone()));
+        }
+        let gone: Vec<SyncKey> = self.entries.iter()
+            .filter(|(_, e)| matches!(e.record.payload, SyncPayload::Memory(_)))
+            .filter_map(|(key, _)| match key {
+                SyncKey::Memory(id) if !state.long_term_memory().contains_key(id) => Some(key.clone()),
+                _ => None,
+            })
+            .collect();
+        for key in gone {
+            self.stamp(key, SyncPayload::MemoryForgotten);
+        }
+    }
+
+    fn stamp(&mut self, key: SyncKey, payload: SyncPayload) {
+        let digest = digest(&payload);
+        if self.entries.get(&key).is_some_and(|e| e.digest == digest) {
+            return;
+        }
+        let mut clock = self.entries.remove(&key).map(|e| e.record.clock).unwrap_or_default();
+        clock.bump(&self.device);
+        let record = SyncRecord { key: key.clone(), clock, origin: self.device.clone(), payload };
+        self.entries.insert(key, Entry { record, digest });
+    }
+
+    /// Everything `peer` may receive, in key order. Empty without consent.
+    pub fn outgoing(&mut self, peer: &str, state: &SharedState) -> Vec<SyncRecord> {
+        let scope = self.consent(peer);
+        if scope == SyncScope::NONE {
+            return Vec::new();
+        }
+        self.observe(state);
+        let mut out: Vec<SyncRecord> = self.entries.values()
+            .map(|e| e.record.clone())
+            .filter(|r| scope.allows(&r.key))
+            .collect();
+        out.sort_by(|a, b| a.key.cmp(&b.key));
+        out
+    }
+
+    /// Merge records received from `peer`. Returns the deltas that bring local state in line.
+    pub fn apply(&mut self, peer: &str, records: Vec<SyncRecord>, state: &SharedState, tick: Tick) -> Vec<StateDelta> {
+        let scope = self.consent(peer);
+        if scope == SyncScope::NONE {
+            return Vec::new();
+        }
+        self.observe(state);
+        let mut deltas = Vec::new();
+        for incoming in records {
+            if !scope.allows(&incoming.key) || is_sensitive(&incoming.payload) || !key_matches(&incoming) {
+                continue;
+            }
+            let winner = match self.entries.get_mut(&incoming.key) {
+                None => incoming,
+                Some(local) => match local.record.clock.compare(&incoming.clock) {
+                    Causality::Before => incoming,
+                    Causality::Equal | Causality::After => continue,
+                    Causality::Concurrent => {
+                        let mut clock = local.record.clock.clone();
+                        clock.join(&incoming.clock);
+                        if resolve(&local.record, &incoming) == Ordering::Greater {
+                            // Ours wins; the joined clock makes the peer take it next exchange
+                            local.record.clock = clock;
+                            continue;
+                        }
+                        SyncRecord { clock, ..incoming }
+                    }
+                },
+            };
+            if let Some(delta) = localize(&winner, state, tick) {
+                deltas.push(delta);
+            }
+            let digest = digest(&winner.payload);
+            self.entries.insert(winner.key.clone(), Entry { record: winner, digest });
+        }
+        deltas
+    }
+}
+
+/// Order of two concurrent edits of the same key (Greater: `a` wins). Same answer on every device.
+pub fn resolve(a: &SyncRecord, b: &SyncRecord) -> Ordering {
+    let rank = |r: &SyncRecord| -> (u8, f32) {
+        match &r.payload {
+            SyncPayload::MemoryForgotten => (2, 0.0),
+            SyncPayload::Memory(m) => (1, m.strength),
+            SyncPayload::Intent(i) => {
+                let terminal = matches!(i.status, IntentStatus::Completed | IntentStatus::Invalidated);
+                (terminal as u8 + 1, i.decay_score)
+            }
+        }
+    };
+    let (ra, rb) = (rank(a), rank(b));
+    ra.0.cmp(&rb.0)
+        .then(ra.1.total_cmp(&rb.1))
+        .then_with(|| a.origin.cmp(&b.origin))
+}
+
+/// The delta applying `record` locally. Ticks are re-stamped to our clock.
+fn localize(record: &SyncRecord, state: &SharedState, tick: Tick) -> Option<StateDelta> {
+    match &record.payload {
+        SyncPayload::Memory(m) => Some(StateDelta::MemoryPromoted(MemoryRecord {
+            first_committed_at: tick,
+            last_accessed_at: tick,
+            ..m.clone()
+        })),
+        SyncPayload::Intent(i) => Some(StateDelta::LongHorizonIntentUpdate(LongHorizonIntent {
+            created_at: tick,
+            last_active_at: tick,
+            last_updated_at: tick,
+            suspended_at: i.suspended_at.map(|_| tick),
+            ..i.clone()
+        })),
+        SyncPayload::MemoryForgotten => match &record.key {
+            SyncKey::Memory(id) if state.long_term_memory().contains_key(id) => Some(StateDelta::MemoryForgotten(id.clone())),
+            _ => None,
+        },
+    }
+}
+
+fn is_sensitive(payload: &SyncPayload) -> bool {
+    matches!(payload, SyncPayload::Memory(m) if m.sensitivity.is_some())
+}
+
+fn key_matches(record: &SyncRecord) -> bool {
+    match (&record.key, &record.payload) {
+        (SyncKey::Memory(id), SyncPayload::Memory(m)) => *id == m.id,
+        (SyncKey::Memory(_), SyncPayload::MemoryForgotten) => true,
+        (SyncKey::Intent(id), SyncPayload::Intent(i)) => *id == i.id,
+        _ => false,
+    }
+}
+
+fn digest(payload: &SyncPayload) -> u64 {
+    let mut h = DefaultHasher::new();
+    match payload {
+        SyncPayload::Memory(m) => {
+            (0u8, &m.id, &m.intent.id, &m.intent.hypothesis, m.intent.semantic_hash).hash(&mut h);
+            ((m.strength * STRENGTH_STEPS).round() as u32, &m.topic_id, &m.context).hash(&mut h);
+        }
+        SyncPayload::Intent(i) => {
+            (1u8, &i.id, &i.hypothesis, i.status as u8, &i.context).hash(&mut h);
+        }
+        SyncPayload::MemoryForgotten => 2u8.hash(&mut h),
+    }
+    h.finish()
+}
@@ -0,0 +1,279 @@
+//! Calendar (read-only): ICS file or CalDAV collection.
+//!
+//! Source is `NEXUS_CALENDAR`: a local `.ics` path, or an `http(s)://` CalDAV collection
+//! URL (credentials in `NEXUS_CALDAV_USER` / `NEXUS_CALDAV_PASSWORD`, fetched by the host
+//! with `fetch_caldav`, feature `llm`).
+//!
+//! Only SUMMARY, LOCATION, DTSTART, DTEND and STATUS are parsed. Descriptions, attendees,
+//! URLs and attachments are never read, so they cannot reach the planner. What does leave
+//! this module is a `CalendarSummary` (textual firewall): titles are truncated, and a
+//! title that classifies as sensitive is replaced by `[private]` and only its class is kept.
+//!
+//! Times: UTC (`Z`) and all-day dates are exact. Floating and `TZID` times are read as UTC.
+
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::memory::sensitivity::{classify, SensitivityClass};
+
+// Config Constants
+const MAX_TITLE_CHARS: usize = 80;
+const MAX_EVENTS: usize = 10; // Per tool call (keeps the planner prompt bounded)
+const REDACTED: &str = "[private]";
+pub const CALDAV_WINDOW_MS: u64 = 30 * 24 * 3600 * 1000; // Fetch the next 30 days
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEvent {
+    pub summary: String,
+    pub location: Option<String>,
+    pub start_ms: u64,
+    pub end_ms: Option<u64>,
+    pub all_day: bool,
+}
+
+/// What the planner sees of an event.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CalendarSummary {
+    pub title: String,
+    pub start_ms: u64,
+    pub end_ms: Option<u64>,
+    pub all_day: bool,
+    pub has_location: bool,
+    pub sensitivity: Option<SensitivityClass>,
+}
+
+/// Tool arguments. `from_ms` defaults to now; `limit` is capped at `MAX_EVENTS`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CalendarQuery {
+    #[serde(default)]
+    pub from_ms: Option<u64>,
+    #[serde(default)]
+    pub to_ms: Option<u64>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Calendar {
+    events: Vec<CalendarEvent>, // Sorted by start
+}
+
+impl CalendarEvent {
+    pub fn summary(&self) -> CalendarSummary {
+        let sensitivity = classify(&self.summary);
+        let title = match sensitivity {
+            Some(_) => REDACTED.to_string(),
+            None => self.summary.chars().take(MAX_TITLE_CHARS).collect(),
+        };
+        CalendarSummary {
+            title,
+            start_ms: self.start_ms,
+            end_ms: self.end_ms,
+            all_day: self.all_day,
+            has_location: self.location.is_some(),
+            sensitivity,
+        }
+    }
+}
+
+impl Calendar {
+    pub fn new(mut events: Vec<CalendarEvent>) -> Self {
+        events.sort_by_key(|e| e.start_ms);
+        Self { events }
+    }
+
+    /// Parse iCalendar text. Malformed or cancelled VEVENTs are skipped.
+    pub fn from_ics(text: &str) -> Self {
+        Self::new(parse_ics(text))
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        Ok(Self::from_ics(&std::fs::read_to_string(path)?))
+    }
+
+    /// Production default: `NEXUS_CALENDAR` when it names a local file, else empty.
+    /// CalDAV URLs are fetched by the host (`fetch_caldav`) and installed later.
+    pub fn from_env() -> Self {
+        match std::env::var("NEXUS_CALENDAR") {
+            Ok(source) if caldav_url().is_none() => Self::load(Path::new(&source)).unwrap_or_else(|e| {
+                tracing::warn!("Failed to load calendar {}: {}", source, e);
+                Self::default()
+            }),
+            _ => Self::default(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Events still running or starting at/after `from_ms` (and before `to_ms`), soonest first.
+    pub fn query(&self, query: &CalendarQuery, now_ms: u64) -> Vec<CalendarSummary> {
+        let from = query.from_ms.unwrap_or(now_ms);
+        let limit = query.limit.unwrap_or(MAX_EVENTS).min(MAX_EVENTS);
+        self.events.iter()
+            .filter(|e| e.end_ms.unwrap_or(e.start_ms) >= from)
+            .filter(|e| query.to_ms.is_none_or(|to| e.start_ms < to))
+            .take(limit)
+            .map(CalendarEvent::summary)
+            .collect()
+    }
+}
+
+/// `NEXUS_CALENDAR` when it is a CalDAV (http/https) URL.
+pub fn caldav_url() -> Option<String> {
+    std::env::var("NEXUS_CALENDAR").ok().filter(|s| s.starts_with("http://") || s.starts_with("https://"))
+}
+
+// === ICS PARSING ===
+
+fn parse_ics(text: &str) -> Vec<CalendarEvent> {
+    // Unfold continuation lines (RFC 5545 §3.1)
+    let mut lines: Vec<String> = Vec::new();
+    for raw in text.lines() {
+        match raw.strip_prefix(' ').or_else(|| raw.strip_prefix('\t')) {
+            Some(rest) if !lines.is_empty() => lines.last_mut().unwrap().push_str(rest),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+
+    let mut events = Vec::new();
+    let mut current: Option<Vec<(String, String, String)>> = None; // (name, params, value)
+    for line in lines {
+        let Some((head, value)) = line.split_once(':') else { continue };
+        let (name, params) = head.split_once(';').unwrap_or((head, ""));
+        let name = name.to_ascii_uppercase();
+        match (name.as_str(), value.trim()) {
+            ("BEGIN", "VEVENT") => current = Some(Vec::new()),
+            ("END", "VEVENT") => {
+                if let Some(props) = current.take() {
+                    events.extend(build_event(&props));
+                }
+            }
+            _ => {
+                if let Some(props) = current.as_mut() {
+                    props.push((name, params.to_ascii_uppercase(), value.trim().to_string()));
+                }
+            }
+        }
+    }
+    events
+}
+
+fn build_event(props: &[(String, String, String)]) -> Option<CalendarEvent> {
+    let get = |name: &str| props.iter().find(|(n, _, _)| n == name);
+    if get("STATUS").is_some_and(|(_, _, v)| v.eq_ignore_ascii_case("CANCELLED")) {
+        return None;
+    }
+    let (_, start_params, start) = get("DTSTART")?;
+    let (start_ms, all_day) = parse_datetime(start, start_params)?;
+    let end_ms = get("DTEND").and_then(|(_, p, v)| parse_datetime(v, p)).map(|(ms, _)| ms);
+    Some(CalendarEvent {
+        summary: get("SUMMARY").map(|(_, _, v)| unescape(v)).unwrap_or_default(),
+        location: get("LOCATION").map(|(_, _, v)| unescape(v)).filter(|l| !l.is_empty()),
+        start_ms,
+        end_ms,
+        all_day,
+    })
+}
+
+fn unescape(value: &str) -> String {
+    value.replace("\\n", " ").replace("\\N", " ").replace("\\,", ",").replace("\\;", ";").replace("\\\\", "\\")
+}
+
+/// `YYYYMMDD` (all-day) or `YYYYMMDDTHHMMSS[Z]` -> (Unix ms, all_day).
+fn parse_datetime(value: &str, params: &str) -> Option<(u64, bool)> {
+    let digits = |s: &str| -> Option<i64> { s.chars().all(|c| c.is_ascii_digit()).then(|| s.parse().ok())? };
+    let date = value.get(0..8)?;
+    let (y, m, d) = (digits(&date[0..4])?, digits(&date[4..6])?, digits(&date[6..8])?);
+    let all_day = value.len() == 8 || params.contains("VALUE=DATE") && !params.contains("VALUE=DATE-TIME");
+    let secs_of_day = if all_day {
+        0
+    } else {
+        let time = value.get(9..15)?;
+        digits(&time[0..2])? * 3600 + digits(&time[2..4])? * 60 + digits(&time[4..6])?
+    };
+    let secs = days_from_civil(y, m, d) * 86_400 + secs_of_day;
+    u64::try_from(secs).ok().map(|s| (s * 1000, all_day))
+}
+
+// Howard Hinnant's days_from_civil: days since 1970-01-01 (proleptic Gregorian)
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+// === CALDAV ===
+
+/// Fetch events in `[from_ms, to_ms)` from a CalDAV collection (`REPORT calendar-query`).
+#[cfg(feature = "llm")]
+pub async fn fetch_caldav(url: &str, user: Option<&str>, password: Option<&str>, from_ms: u64, to_ms: u64) -> anyhow::Result<Calendar> {
+    let stamp = |ms: u64| {
+        let secs = (ms / 1000) as i64;
+        let (y, m, d) = civil_from_days(secs.div_euclid(86_400));
+        let sod = secs.rem_euclid(86_400);
+        format!("{:04}{:02}{:02}T{:02}{:02}{:02}Z", y, m, d, sod / 3600, sod % 3600 / 60, sod % 60)
+    };
+    let body = format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop><c:calendar-data/></d:prop>
+  <c:filter><c:comp-filter name="VCALENDAR"><c:comp-filter name="VEVENT">
+    <c:time-range start="{}" end="{}"/>
+  </c:comp-filter></c:comp-filter></c:filter>
+</c:calendar-query>"#,
+        stamp(from_ms),
+        stamp(to_ms)
+    );
+    let mut request = reqwest::Client::new()
+        .request(reqwest::Method::from_bytes(b"REPORT")?, url)
+        .header("Depth", "1")
+        .header("Content-Type", "application/xml; charset=utf-8")
+        .body(body);
+    if let Some(user) = user {
+        request = request.basic_auth(user, password);
+    }
+    let xml = request.send().await?.error_for_status()?.text().await?;
+    Ok(Calendar::new(calendar_data(&xml).iter().flat_map(|ics| parse_ics(ics)).collect()))
+}
+
+// Text of every `<*:calendar-data>` element in a multistatus response
+#[cfg(feature = "llm")]
+fn calendar_data(xml: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut rest = xml;
+    while let Some(at) = rest.find("calendar-data") {
+        let closing = rest[..at].rfind('<').is_some_and(|lt| rest[lt..].starts_with("</"));
+        let Some(gt) = rest[at..].find('>').map(|i| at + i) else { break };
+        let self_closing = rest[..gt].ends_with('/');
+        rest = &rest[gt + 1..];
+        if closing || self_closing {
+            continue;
+        }
+        let Some(end) = rest.find("</") else { break };
+        out.push(rest[..end].replace("&lt;", "<").replace("&gt;", ">").replace("&#13;", "").replace("&amp;", "&"));
+        rest = &rest[end..];
+    }
+    out
+}
+
+#[cfg(feature = "llm")]
+fn civil_from_days(z: i64) -> (i64, i64, i64) {
+    let z = z + 719_468;
+    let era = (if z >= 0 { z } else { z - 146_096 }) / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    (if m <= 2 { yoe + era * 400 + 1 } else { yoe + era * 400 }, m, d)
+}
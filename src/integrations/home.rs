@@ -0,0 +1,233 @@
+//! Home Automation Bridge (MQTT / Home Assistant).
+//!
+//! Maps Stable `Command` intents to device actions through a user-editable JSON mapping
+//! file (`nexus_home_mapping.json`, or the path in `NEXUS_HOME_MAPPING`):
+//!
+//! ```json
+//! {
+//!   "mqtt": { "host": "localhost", "port": 1883 },
+//!   "home_assistant": { "url": "http://homeassistant.local:8123" },
+//!   "rules": [
+//!     { "name": "kitchen light on", "phrases": ["turn on the kitchen light"],
+//!       "target": { "Mqtt": { "topic": "home/kitchen/light/set", "payload": "ON" } } },
+//!     { "name": "unlock front door", "phrases": ["unlock the front door"], "confirm": true,
+//!       "target": { "HomeAssistant": { "domain": "lock", "service": "unlock", "entity_id": "lock.front_door" } } }
+//!   ]
+//! }
+//! ```
+//!
+//! Confirm policy: a rule with `confirm: true` is only dispatched after the user answers
+//! "yes" to the kernel's clarification, within `CONFIRM_WINDOW_TICKS`. Other rules are
+//! dispatched as soon as the Command is Stable.
+//!
+//! The kernel never performs I/O. It emits `SideEffect::InvokeTool` with a
+//! `ToolCall::HomeAction`. The driver (`home_driver`, feature `home`) runs it and
+//! reports `InputContent::ToolResult`, which the kernel turns into a confirmation utterance.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::event::{Event, InputContent, InputEvent};
+use crate::kernel::time::Tick;
+
+// Config Constants
+const MAPPING_FILE: &str = "nexus_home_mapping.json";
+pub const CONFIRM_WINDOW_TICKS: u64 = 500; // 10s @ 20ms, same as the reflex yes/no window
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HomeMapping {
+    #[serde(default)]
+    pub mqtt: Option<MqttBroker>,
+    #[serde(default)]
+    pub home_assistant: Option<HomeAssistantServer>,
+    #[serde(default)]
+    pub rules: Vec<HomeRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MqttBroker {
+    pub host: String,
+    #[serde(default = "default_mqtt_port")]
+    pub port: u16,
+    #[serde(default = "default_client_id")]
+    pub client_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HomeAssistantServer {
+    pub url: String,
+    /// Environment variable holding the long-lived access token (never stored in the file).
+    #[serde(default = "default_token_env")]
+    pub token_env: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HomeRule {
+    pub name: String,
+    /// Matched as whole-word sequences, case-insensitive. Longest match wins.
+    pub phrases: Vec<String>,
+    pub target: HomeTarget,
+    #[serde(default)]
+    pub confirm: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum HomeTarget {
+    Mqtt {
+        topic: String,
+        payload: String,
+        #[serde(default)]
+        retain: bool,
+    },
+    HomeAssistant {
+        domain: String,
+        service: String,
+        entity_id: String,
+    },
+}
+
+/// A resolved rule, ready to hand to the driver.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HomeAction {
+    pub rule: String,
+    pub target: HomeTarget,
+}
+
+fn default_mqtt_port() -> u16 {
+    1883
+}
+
+fn default_client_id() -> String {
+    "nexus".to_string()
+}
+
+fn default_token_env() -> String {
+    "NEXUS_HA_TOKEN".to_string()
+}
+
+/// Mapping file location (`NEXUS_HOME_MAPPING` overrides the working-directory default).
+pub fn mapping_path() -> PathBuf {
+    std::env::var("NEXUS_HOME_MAPPING").map(PathBuf::from).unwrap_or_else(|_| PathBuf::from(MAPPING_FILE))
+}
+
+/// Driver answer to `SideEffect::InvokeTool`. Also sent when no driver is compiled in (`ok: false`).
+pub fn tool_result(call_id: String, ok: bool) -> Event {
+    Event::Input(InputEvent {
+        source: "Driver".to_string(),
+        content: InputContent::ToolResult { call_id, ok },
+        captured_at: None,
+    })
+}
+
+// " word word " so phrases only match on word boundaries
+fn normalise(text: &str) -> String {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect();
+    format!(" {} ", words.join(" "))
+}
+
+impl HomeMapping {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let raw = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&raw)?)
+    }
+
+    /// Rule whose phrase matches `text` (longest phrase wins).
+    pub fn resolve(&self, text: &str) -> Option<&HomeRule> {
+        let text = normalise(text);
+        self.rules.iter()
+            .filter_map(|rule| {
+                rule.phrases.iter()
+                    .map(|p| normalise(p))
+                    .filter(|p| !p.trim().is_empty() && text.contains(p.as_str()))
+                    .map(|p| p.len())
+                    .max()
+                    .map(|len| (len, rule))
+            })
+            .max_by_key(|(len, _)| *len)
+            .map(|(_, rule)| rule)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HomeDecision {
+    /// No confirmation needed: invoke now.
+    Dispatch(HomeAction),
+    /// Ask the user first; the action waits for a "yes".
+    AskConfirm(HomeAction),
+}
+
+/// Kernel-side state of the bridge: pending confirmation and calls in flight.
+/// Pure bookkeeping, no I/O.
+#[derive(Debug, Default)]
+pub struct HomeBridge {
+    mapping: HomeMapping,
+    pending: Option<(HomeAction, Tick)>,
+    // call_id -> rule name
+    in_flight: HashMap<String, String>,
+}
+
+impl HomeBridge {
+    pub fn new(mapping: HomeMapping) -> Self {
+        Self { mapping, ..Default::default() }
+    }
+
+    /// Production default: the mapping file if present, else no rules.
+    pub fn from_default_path() -> Self {
+        let path = mapping_path();
+        match HomeMapping::load(&path) {
+            Ok(mapping) => Self::new(mapping),
+            Err(e) => {
+                if path.exists() {
+                    tracing::warn!("Failed to load home mapping {}: {}", path.display(), e);
+                }
+                Self::default()
+            }
+        }
+    }
+
+    pub fn mapping(&self) -> &HomeMapping {
+        &self.mapping
+    }
+
+    /// A Stable Command arrived. Applies the Confirm policy.
+    pub fn on_command(&mut self, text: &str, tick: Tick) -> Option<HomeDecision> {
+        let rule = self.mapping.resolve(text)?;
+        let action = HomeAction { rule: rule.name.clone(), target: rule.target.clone() };
+        if rule.confirm {
+            self.pending = Some((action.clone(), tick));
+            Some(HomeDecision::AskConfirm(action))
+        } else {
+            Some(HomeDecision::Dispatch(action))
+        }
+    }
+
+    /// Yes/no answer to our clarification. Returns the action to invoke on a timely "yes".
+    /// Any answer clears the pending action.
+    pub fn answer(&mut self, affirmative: bool, tick: Tick) -> Option<HomeAction> {
+        let (action, asked) = self.pending.take()?;
+        let in_window = tick.frame.saturating_sub(asked.frame) <= CONFIRM_WINDOW_TICKS;
+        (affirmative && in_window).then_some(action)
+    }
+
+    pub fn awaiting_confirmation(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    pub fn dispatched(&mut self, call_id: &str, action: &HomeAction) {
+        self.in_flight.insert(call_id.to_string(), action.rule.clone());
+    }
+
+    /// Driver result arrived. Returns the rule name for calls we issued.
+    pub fn complete(&mut self, call_id: &str) -> Option<String> {
+        self.in_flight.remove(call_id)
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.len()
+    }
+}
@@ -0,0 +1,114 @@
+//! Home automation driver (feature `home`).
+//!
+//! Executes `HomeAction`s emitted by the kernel: MQTT publish (`rumqttc`) or a
+//! Home Assistant REST service call (`reqwest`). Runs outside the Reactor; the
+//! outcome goes back in as `InputContent::ToolResult`. `SideEffect::CancelTool`
+//! (sandbox timeout or interruption) aborts a running call without a result.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use rumqttc::{AsyncClient, MqttOptions, QoS};
+
+use super::home::{tool_result, HomeAction, HomeMapping, HomeTarget};
+use crate::kernel::event::Event;
+use crate::planner::tools::ToolCall;
+
+const MQTT_KEEP_ALIVE: Duration = Duration::from_secs(30);
+const HA_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct HomeDriver {
+    mqtt: Option<AsyncClient>,
+    http: reqwest::Client,
+    home_assistant: Option<(String, String)>, // (base url, token)
+    // call_id -> running task
+    running: Mutex<HashMap<String, tokio::task::AbortHandle>>,
+}
+
+impl HomeDriver {
+    /// Connects lazily: the MQTT event loop runs on its own task for the driver's lifetime.
+    /// Must be called from within a Tokio runtime.
+    pub fn new(mapping: &HomeMapping) -> Self {
+        let mqtt = mapping.mqtt.as_ref().map(|broker| {
+            let mut options = MqttOptions::new(broker.client_id.clone(), broker.host.clone(), broker.port);
+            options.set_keep_alive(MQTT_KEEP_ALIVE);
+            let (client, mut eventloop) = AsyncClient::new(options, 10);
+            tokio::spawn(async move {
+                loop {
+                    if let Err(e) = eventloop.poll().await {
+                        tracing::warn!("[Home] MQTT connection error: {}", e);
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                    }
+                }
+            });
+            client
+        });
+        let home_assistant = mapping.home_assistant.as_ref().and_then(|ha| {
+            let token = std::env::var(&ha.token_env).ok()?;
+            Some((ha.url.trim_end_matches('/').to_string(), token))
+        });
+        Self {
+            mqtt,
+            http: reqwest::Client::builder().timeout(HA_TIMEOUT).build().unwrap_or_default(),
+            home_assistant,
+            running: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub async fn execute(&self, action: &HomeAction) -> anyhow::Result<()> {
+        match &action.target {
+            HomeTarget::Mqtt { topic, payload, retain } => {
+                let client = self.mqtt.as_ref().ok_or_else(|| anyhow!("no MQTT broker configured"))?;
+                client.publish(topic.as_str(), QoS::AtLeastOnce, *retain, payload.clone().into_bytes()).await?;
+            }
+            HomeTarget::HomeAssistant { domain, service, entity_id } => {
+                let (url, token) = self.home_assistant.as_ref().ok_or_else(|| anyhow!("no Home Assistant server or token configured"))?;
+                self.http
+                    .post(format!("{}/api/services/{}/{}", url, domain, service))
+                    .bearer_auth(token)
+                    .json(&serde_json::json!({ "entity_id": entity_id }))
+                    .send()
+                    .await
+                    .context("Home Assistant request failed")?
+                    .error_for_status()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Run `call` on its own task and report the outcome as `InputContent::ToolResult`.
+    pub fn spawn(self: &Arc<Self>, call_id: String, call: ToolCall, tx: tokio::sync::mpsc::Sender<Event>) {
+        let driver = self.clone();
+        let id = call_id.clone();
+        // Held across spawn + insert so a fast task cannot finish before it is registered
+        let mut running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+        let task = tokio::spawn(async move {
+            let ok = match &call {
+                ToolCall::HomeAction(action) => match driver.execute(action).await {
+                    Ok(()) => true,
+                    Err(e) => {
+                        tracing::warn!("[Home] '{}' failed: {:#}", action.rule, e);
+                        false
+                    }
+                },
+                other => {
+                    tracing::warn!("[Home] Not an action tool: {:?}", other);
+                    false
+                }
+            };
+            driver.running.lock().unwrap_or_else(|e| e.into_inner()).remove(&call_id);
+            let _ = tx.send(tool_result(call_id, ok)).await;
+        });
+        running.insert(id, task.abort_handle());
+    }
+
+    /// Abort a running call. The kernel has already written it off, so no result is sent.
+    pub fn cancel(&self, call_id: &str) {
+        if let Some(task) = self.running.lock().unwrap_or_else(|e| e.into_inner()).remove(call_id) {
+            tracing::info!("[Home] Cancelled {}", call_id);
+            task.abort();
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! External integrations driven by kernel intents.
+//! Kernel-side mapping and policy are always compiled; drivers doing I/O are feature-gated.
+
+pub mod calendar;
+pub mod home;
+#[cfg(feature = "home")]
+pub mod home_driver;
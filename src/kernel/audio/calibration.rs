@@ -0,0 +1,198 @@
+//! Audio Calibration Profiles: core VAD tuning per (input device, output device) pair.
+//!
+//! Thresholds that suit a headset are wrong for laptop speakers next to the microphone. A
+//! calibration run (`InputContent::CalibrateAudio`, the user stays quiet) measures the noise
+//! floor and derives the speech threshold from it. On a calibrated pair, `AudioMonitor` also
+//! measures the echo tail after each playback and moves its grace window towards it.
+//!
+//! Results are kept per device pair in `nexus_calibration.json` (or the path in
+//! `NEXUS_CALIBRATION`). The store changes in memory inside the tick; each change is written
+//! back by the driver (`SideEffect::PersistCalibration`), never on the audio path. When the capture or playback device
+//! changes (`InputDevice` / `OutputDevice`), the pair's profile is loaded. A pair that was never
+//! calibrated uses the configured VAD thresholds (`kernel::audio::vad`).
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+// Config Constants
+const CALIBRATION_FILE: &str = "nexus_calibration.json";
+/// Audio a calibration run listens to (no playback audible).
+pub const NOISE_WINDOW_MS: u64 = 3000;
+/// Speech threshold = noise floor x margin, clamped.
+const NOISE_MARGIN: f32 = 3.0;
+const MIN_THRESHOLD_RMS: f32 = 0.01;
+const MAX_THRESHOLD_RMS: f32 = 0.12;
+/// Added to a measured echo tail before it becomes the grace window.
+const ECHO_MARGIN_MS: u64 = 100;
+const MIN_ECHO_TAIL_MS: u64 = 150;
+/// Energy still above threshold this long after playback is the user, not the echo.
+pub const MAX_ECHO_TAIL_MS: u64 = 1500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationProfile {
+    /// Ambient RMS measured by the last calibration run (0: never measured)
+    pub noise_floor_rms: f32,
+    /// Speech threshold while Nexus is silent
+    pub threshold_rms: f32,
+    /// Threshold multiplier while Nexus speaks and during the echo tail
+    pub speaking_factor: f32,
+    /// Echo protection after playback ends (the monitor's grace window)
+    pub echo_tail_ms: u64,
+}
+
+impl Default for CalibrationProfile {
+    fn default() -> Self {
+        Self::from_vad(&crate::kernel::audio::vad::VadConfig::default())
+    }
+}
+
+impl CalibrationProfile {
+    /// PURE FUNCTION: The uncalibrated profile: thresholds as configured, no noise floor measured.
+    pub fn from_vad(vad: &crate::kernel::audio::vad::VadConfig) -> Self {
+        Self { noise_floor_rms: 0.0, threshold_rms: vad.threshold_rms, speaking_factor: vad.adaptive_factor, echo_tail_ms: vad.grace_period_ms }
+    }
+
+    /// PURE FUNCTION: Profile after a calibration run measured `noise_floor_rms`.
+    pub fn with_noise_floor(self, noise_floor_rms: f32) -> Self {
+        Self {
+            noise_floor_rms,
+            threshold_rms: (noise_floor_rms * NOISE_MARGIN).clamp(MIN_THRESHOLD_RMS, MAX_THRESHOLD_RMS),
+            ..self
+        }
+    }
+
+    /// PURE FUNCTION: Profile after one measured echo tail. The grace window moves halfway
+    /// towards the measurement plus margin, so one odd playback does not swing it.
+    pub fn with_echo_tail(self, measured_ms: u64) -> Self {
+        let target = measured_ms + ECHO_MARGIN_MS;
+        Self { echo_tail_ms: ((self.echo_tail_ms + target) / 2).clamp(MIN_ECHO_TAIL_MS, MAX_ECHO_TAIL_MS), ..self }
+    }
+}
+
+/// Capture and playback devices in use (`None`: system default).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct DevicePair {
+    pub input: Option<String>,
+    pub output: Option<String>,
+}
+
+/// A calibration run in progress: the median chunk energy over `NOISE_WINDOW_MS`, so a cough
+/// or a word during the run does not raise the floor.
+#[derive(Debug, Clone, Default)]
+pub struct NoiseCalibration {
+    energies: Vec<f32>,
+    heard_ms: u64,
+}
+
+impl NoiseCalibration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// One chunk of audio. `Some(noise floor)` once the window is full.
+    pub fn observe(&mut self, rms: f32, duration_ms: u64) -> Option<f32> {
+        self.energies.push(rms);
+        self.heard_ms += duration_ms;
+        if self.heard_ms < NOISE_WINDOW_MS {
+            return None;
+        }
+        self.energies.sort_by(|a, b| a.total_cmp(b));
+        Some(self.energies[self.energies.len() / 2])
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CalibrationEntry {
+    devices: DevicePair,
+    profile: CalibrationProfile,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CalibrationFile {
+    profiles: Vec<CalibrationEntry>,
+}
+
+/// The whole store as it goes to disk. Drivers `save()` it off the tick.
+#[derive(Debug, Clone)]
+pub struct CalibrationSnapshot {
+    path: PathBuf,
+    profiles: Vec<CalibrationEntry>,
+}
+
+impl CalibrationSnapshot {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Blocking write.
+    pub fn save(&self) -> anyhow::Result<()> {
+        let file = CalibrationFile { profiles: self.profiles.clone() };
+        std::fs::write(&self.path, serde_json::to_string_pretty(&file)?)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CalibrationStore {
+    entries: Vec<CalibrationEntry>,
+    // None: in memory only
+    path: Option<PathBuf>,
+}
+
+/// Calibration location (`NEXUS_CALIBRATION` overrides the working-directory default).
+pub fn calibration_path() -> PathBuf {
+    std::env::var("NEXUS_CALIBRATION").map(PathBuf::from).unwrap_or_else(|_| PathBuf::from(CALIBRATION_FILE))
+}
+
+impl CalibrationStore {
+    /// In memory only (tests, ephemeral sessions).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load `path` if present; `snapshot()` writes changes back to it.
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        let mut store = Self { path: Some(path.to_path_buf()), ..Self::default() };
+        if path.exists() {
+            let file: CalibrationFile = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+            store.entries = file.profiles;
+        }
+        Ok(store)
+    }
+
+    /// Production default: the calibration file, or an empty store if it cannot be read.
+    pub fn from_default_path() -> Self {
+        let path = calibration_path();
+        Self::open(&path).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load audio calibration {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+
+    /// The pair's profile, if it was ever calibrated.
+    pub fn get(&self, devices: &DevicePair) -> Option<CalibrationProfile> {
+        self.entries.iter().find(|e| &e.devices == devices).map(|e| e.profile)
+    }
+
+    /// Store the pair's profile (in memory; see `snapshot`).
+    pub fn set(&mut self, devices: DevicePair, profile: CalibrationProfile) {
+        match self.entries.iter_mut().find(|e| e.devices == devices) {
+            Some(entry) => entry.profile = profile,
+            None => self.entries.push(CalibrationEntry { devices, profile }),
+        }
+    }
+
+    /// What to write back (`None`: in memory only).
+    pub fn snapshot(&self) -> Option<CalibrationSnapshot> {
+        self.path.as_ref().map(|path| CalibrationSnapshot { path: path.clone(), profiles: self.entries.clone() })
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
@@ -1,2 +1,5 @@
 pub mod segment;
 pub mod monitor;
+pub mod calibration;
+pub mod trim;
+pub mod vad;
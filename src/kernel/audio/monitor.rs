@@ -1,4 +1,8 @@
-use crate::kernel::event::AudioSignal;
+use std::collections::HashSet;
+
+use crate::kernel::audio::calibration::{CalibrationProfile, NoiseCalibration, MAX_ECHO_TAIL_MS};
+use crate::kernel::audio::vad::VadConfig;
+use crate::kernel::event::{AudioSignal, PlaybackId};
 
 /// Simple Energy-Based VAD (Voice Activity Detection)
 /// Phase D Requirement: Signal analysis only. No ASR.
@@ -13,6 +17,13 @@ pub struct AudioMonitor {
     adaptive_threshold_factor: f32, // Multiplier when system is speaking
     grace_period_ms: u64,           // Echo tail protection window
 
+    // Calibration (kernel::audio::calibration)
+    noise_floor_rms: f32,
+    noise_run: Option<NoiseCalibration>,
+    measure_echo: bool,            // Calibrated pair: track the echo tail after playback
+    echo_since: Option<u64>,       // Playback end being measured (ms)
+    calibration_update: Option<CalibrationProfile>,
+
     // State
     is_speaking: bool,
     consecutive_prob_speech: u64, // accumulated duration > threshold
@@ -20,26 +31,39 @@ pub struct AudioMonitor {
     
     // Phase F: Output Awareness
     system_speaking: bool,
+    playing: HashSet<PlaybackId>, // Outputs currently audible (overlap-safe)
     playback_end_tick: Option<u64>, // Monotonic MS timestamp
     current_time_ms: u64, // Monotonic MS counter (estimated from samples)
 }
 
 impl AudioMonitor {
     pub fn new(sample_rate: u32) -> Self {
+        Self::with_config(sample_rate, &VadConfig::default())
+    }
+
+    /// Tuned by `vad` (see `kernel::audio::vad`).
+    pub fn with_config(sample_rate: u32, vad: &VadConfig) -> Self {
         Self {
             sample_rate,
-            threshold_rms: 0.03, // Increased from 0.015 to reduce echo sensitivity
-            min_speech_duration_ms: 120, // Increased to 120ms (ignore short pops)
-            min_silence_duration_ms: 500, // 0.5s pause to cut
+            threshold_rms: vad.threshold_rms,
+            min_speech_duration_ms: vad.min_speech_ms,
+            min_silence_duration_ms: vad.min_silence_ms,
             
-            adaptive_threshold_factor: 3.0, // 3x threshold when Nexus is speaking
-            grace_period_ms: 300,           // 300ms tail protection
+            adaptive_threshold_factor: vad.adaptive_factor,
+            grace_period_ms: vad.grace_period_ms,
+
+            noise_floor_rms: 0.0,
+            noise_run: None,
+            measure_echo: false,
+            echo_since: None,
+            calibration_update: None,
             
             is_speaking: false,
             consecutive_prob_speech: 0,
             consecutive_prob_silence: 0,
             
             system_speaking: false,
+            playing: HashSet::new(),
             playback_end_tick: None,
             current_time_ms: 0,
         }
@@ -49,18 +73,112 @@ impl AudioMonitor {
         if self.system_speaking && !speaking {
             // Transition to Silent -> Mark end time
             self.playback_end_tick = Some(self.current_time_ms);
+            if self.measure_echo {
+                self.echo_since = Some(self.current_time_ms);
+            }
+        }
+        if speaking {
+            self.echo_since = None;
         }
         self.system_speaking = speaking;
     }
 
+    /// Rate of full-rate chunks (`process`), and so of buffered segment frames.
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
     pub fn is_system_speaking(&self) -> bool {
         self.system_speaking
     }
 
+    /// Driver reported a playback stream start.
+    pub fn playback_started(&mut self, id: PlaybackId) {
+        self.playing.insert(id);
+        self.set_system_speaking(true);
+    }
+
+    /// Driver reported a playback stream end (finished OR cancelled).
+    /// system_speaking only clears once no stream remains.
+    /// Returns true if the system is now silent.
+    pub fn playback_ended(&mut self, id: &PlaybackId) -> bool {
+        self.playing.remove(id);
+        if self.playing.is_empty() {
+            self.set_system_speaking(false);
+            return true;
+        }
+        false
+    }
+
+    pub fn playing_count(&self) -> usize {
+        self.playing.len()
+    }
+
+    /// Thresholds in effect.
+    pub fn calibration(&self) -> CalibrationProfile {
+        CalibrationProfile {
+            noise_floor_rms: self.noise_floor_rms,
+            threshold_rms: self.threshold_rms,
+            speaking_factor: self.adaptive_threshold_factor,
+            echo_tail_ms: self.grace_period_ms,
+        }
+    }
+
+    /// Use `profile` (device pair selected). `measure_echo`: the pair is calibrated, so echo
+    /// tails update it. A calibration run in progress belonged to the old pair and is dropped.
+    pub fn apply_calibration(&mut self, profile: &CalibrationProfile, measure_echo: bool) {
+        self.noise_floor_rms = profile.noise_floor_rms;
+        self.threshold_rms = profile.threshold_rms;
+        self.adaptive_threshold_factor = profile.speaking_factor;
+        self.grace_period_ms = profile.echo_tail_ms;
+        self.measure_echo = measure_echo;
+        self.noise_run = None;
+        self.echo_since = None;
+    }
+
+    /// Segment timing from `vad`. Thresholds come with the calibration profile
+    /// (`CalibrationProfile::from_vad` for a pair that was never calibrated).
+    pub fn apply_vad(&mut self, vad: &VadConfig) {
+        self.min_speech_duration_ms = vad.min_speech_ms;
+        self.min_silence_duration_ms = vad.min_silence_ms;
+    }
+
+    /// Capture restarted: speech in progress is over, and a calibration run heard the gap.
+    pub fn capture_restarted(&mut self) {
+        self.is_speaking = false;
+        self.consecutive_prob_speech = 0;
+        self.consecutive_prob_silence = 0;
+        self.noise_run = None;
+    }
+
+    /// Measure the noise floor over the next `NOISE_WINDOW_MS` of audio without playback.
+    pub fn start_noise_calibration(&mut self) {
+        self.noise_run = Some(NoiseCalibration::new());
+    }
+
+    pub fn is_calibrating(&self) -> bool {
+        self.noise_run.is_some()
+    }
+
+    /// A finished calibration run or a new echo tail measurement, to be stored for the device pair.
+    pub fn take_calibration_update(&mut self) -> Option<CalibrationProfile> {
+        self.calibration_update.take()
+    }
+
+    fn update_calibration(&mut self, profile: CalibrationProfile) {
+        self.apply_calibration(&profile, true);
+        self.calibration_update = Some(profile);
+    }
+
     /// Process a chunk of raw audio float samples.
     /// Returns Some(Signal) if a state transition occurs.
     pub fn process(&mut self, samples: &[f32]) -> Option<AudioSignal> {
-        if samples.is_empty() {
+        self.process_at(samples, self.sample_rate)
+    }
+
+    /// Process a chunk captured at a different rate (e.g. decimated push audio).
+    pub fn process_at(&mut self, samples: &[f32], sample_rate: u32) -> Option<AudioSignal> {
+        if samples.is_empty() || sample_rate == 0 {
             return None;
         }
 
@@ -68,23 +186,45 @@ impl AudioMonitor {
         let sq_sum: f32 = samples.iter().map(|&x| x * x).sum();
         let rms = (sq_sum / samples.len() as f32).sqrt();
 
+        let chunk_duration_ms = (samples.len() as u64 * 1000) / sample_rate as u64;
+        self.process_energy(rms, chunk_duration_ms)
+    }
+
+    /// Process a pre-computed energy envelope (RMS over `chunk_duration_ms`).
+    pub fn process_energy(&mut self, rms: f32, chunk_duration_ms: u64) -> Option<AudioSignal> {
         // 2. State Machine
-        let chunk_duration_ms = (samples.len() as u64 * 1000) / self.sample_rate as u64;
         self.current_time_ms += chunk_duration_ms;
 
         // Phase F: Adaptive Threshold Logic
-        let effective_threshold = if self.system_speaking {
+        let in_grace = self.playback_end_tick
+            .is_some_and(|end_tick| self.current_time_ms.saturating_sub(end_tick) < self.grace_period_ms);
+        let effective_threshold = if self.system_speaking || in_grace {
             self.threshold_rms * self.adaptive_threshold_factor
-        } else if let Some(end_tick) = self.playback_end_tick {
-             if self.current_time_ms.saturating_sub(end_tick) < self.grace_period_ms {
-                 self.threshold_rms * self.adaptive_threshold_factor
-             } else {
-                 self.threshold_rms
-             }
         } else {
             self.threshold_rms
         };
 
+        // Calibration: the echo tail ends at the first quiet chunk after playback
+        if let Some(end) = self.echo_since {
+            let tail_ms = self.current_time_ms.saturating_sub(end);
+            if rms <= self.threshold_rms {
+                self.echo_since = None;
+                let profile = self.calibration().with_echo_tail(tail_ms);
+                if profile != self.calibration() {
+                    self.update_calibration(profile);
+                }
+            } else if tail_ms > MAX_ECHO_TAIL_MS {
+                self.echo_since = None;
+            }
+        }
+        // The noise floor is measured with no playback audible
+        if !self.system_speaking && !in_grace {
+            if let Some(floor) = self.noise_run.as_mut().and_then(|run| run.observe(rms, chunk_duration_ms)) {
+                let profile = self.calibration().with_noise_floor(floor);
+                self.update_calibration(profile);
+            }
+        }
+
         if rms > effective_threshold {
             // High Energy
             self.consecutive_prob_speech += chunk_duration_ms;
@@ -19,6 +19,22 @@ pub struct AudioSegment {
     pub status: SegmentStatus,
     // Optional: Metadata for transcription
     pub transcription: Option<String>,
+    /// Silence cut when the segment was finalized (`kernel::audio::trim`)
+    #[serde(default)]
+    pub trim: Option<super::trim::SegmentTrim>,
+    /// Per-word ASR confidence (empty if the backend reports none)
+    #[serde(default)]
+    pub words: Vec<WordConfidence>,
+    /// Language the ASR backend heard (`kernel::language`)
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+/// One transcribed word and how sure the ASR backend was of it (0.0..=1.0).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordConfidence {
+    pub word: String,
+    pub confidence: f32,
 }
 
 impl AudioSegment {
@@ -30,6 +46,9 @@ impl AudioSegment {
             end_tick: None,
             status: SegmentStatus::Buffering,
             transcription: None,
+            trim: None,
+            words: Vec::new(),
+            language: None,
         }
     }
 }
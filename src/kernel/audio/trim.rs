@@ -0,0 +1,79 @@
+//! Segment Pre-Trim: cut silence out of a finalized segment before it goes to ASR.
+//!
+//! A segment ends after `min_silence_duration_ms` of quiet, and that quiet is still in its
+//! frames, as is any noise heard before the speech. When the segment is finalized, the Reactor
+//! trims it against the monitor's speech threshold:
+//! - quiet before the first and after the last voiced window is removed (`TRIM_PAD_MS` kept),
+//! - internal pauses longer than `MAX_INTERNAL_SILENCE_MS` are shortened to it.
+//!
+//! What was cut is kept on the segment (`AudioSegment::trim`) for debugging.
+
+use serde::{Deserialize, Serialize};
+
+// Config Constants
+/// Energy is measured over windows of this length.
+pub const TRIM_WINDOW_MS: u64 = 10;
+/// Kept before the first and after the last voiced window (soft onsets, trailing consonants).
+pub const TRIM_PAD_MS: u64 = 100;
+/// Longer pauses inside the speech are shortened to this, half from each end.
+pub const MAX_INTERNAL_SILENCE_MS: u64 = 300;
+
+/// Audio removed from a segment, in ms.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SegmentTrim {
+    pub leading_ms: u64,
+    pub trailing_ms: u64,
+    /// Removed from long internal pauses
+    pub compressed_ms: u64,
+}
+
+impl SegmentTrim {
+    pub fn total_ms(&self) -> u64 {
+        self.leading_ms + self.trailing_ms + self.compressed_ms
+    }
+}
+
+/// PURE FUNCTION: `frames` without the silence around and inside the speech.
+/// No voiced window at all: nothing is cut (the gate decides what happens to the segment).
+pub fn trim(frames: &[f32], sample_rate: u32, threshold_rms: f32) -> (Vec<f32>, SegmentTrim) {
+    let window = (sample_rate as u64 * TRIM_WINDOW_MS / 1000) as usize;
+    if window == 0 || frames.is_empty() {
+        return (frames.to_vec(), SegmentTrim::default());
+    }
+    let voiced: Vec<bool> = frames.chunks(window).map(|w| rms(w) > threshold_rms).collect();
+    let (Some(first), Some(last)) = (voiced.iter().position(|v| *v), voiced.iter().rposition(|v| *v)) else {
+        return (frames.to_vec(), SegmentTrim::default());
+    };
+    let samples = |ms: u64| (sample_rate as u64 * ms / 1000) as usize;
+    let start = (first * window).saturating_sub(samples(TRIM_PAD_MS));
+    let end = ((last + 1) * window + samples(TRIM_PAD_MS)).min(frames.len());
+
+    // Internal pauses: runs of quiet windows between the first and last voiced one
+    let keep = samples(MAX_INTERNAL_SILENCE_MS);
+    let mut kept = Vec::with_capacity(end - start);
+    let mut cursor = start;
+    let mut compressed = 0;
+    let mut i = first;
+    while i <= last {
+        if voiced[i] {
+            i += 1;
+            continue;
+        }
+        let run_end = (i..=last).find(|&j| voiced[j]).unwrap_or(last + 1);
+        let (quiet_start, quiet_end) = (i * window, run_end * window);
+        if quiet_end - quiet_start > keep {
+            kept.extend_from_slice(&frames[cursor..quiet_start + keep / 2]);
+            cursor = quiet_end - (keep - keep / 2);
+            compressed += quiet_end - quiet_start - keep;
+        }
+        i = run_end;
+    }
+    kept.extend_from_slice(&frames[cursor..end]);
+
+    let ms = |n: usize| n as u64 * 1000 / sample_rate as u64;
+    (kept, SegmentTrim { leading_ms: ms(start), trailing_ms: ms(frames.len() - end), compressed_ms: ms(compressed) })
+}
+
+fn rms(window: &[f32]) -> f32 {
+    (window.iter().map(|x| x * x).sum::<f32>() / window.len() as f32).sqrt()
+}
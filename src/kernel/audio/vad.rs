@@ -0,0 +1,99 @@
+//! Core VAD Tuning: the energy VAD's thresholds and timings (`AudioMonitor`).
+//!
+//! Defaults suit a typical laptop microphone. Drivers read `nexus_vad.toml` (or the path in
+//! `NEXUS_VAD_CONFIG`) at start and pass it as `ReactorConfig::vad`; keys left out keep their
+//! defaults:
+//!
+//! ```toml
+//! threshold_rms = 0.02
+//! min_speech_ms = 150
+//! min_silence_ms = 700
+//! grace_period_ms = 300
+//! adaptive_factor = 3.0
+//! ```
+//!
+//! `InputContent::ConfigUpdate` replaces it at runtime (settings). Segment timing applies at
+//! once. The thresholds are the profile of device pairs that were never calibrated: a calibrated
+//! pair keeps its measured ones (`kernel::audio::calibration`). Out-of-range values are clamped.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::audio::calibration::MAX_ECHO_TAIL_MS;
+
+// Config Constants
+const VAD_CONFIG_FILE: &str = "nexus_vad.toml";
+const MIN_THRESHOLD_RMS: f32 = 0.001;
+const MAX_THRESHOLD_RMS: f32 = 0.5;
+const MIN_SPEECH_MS: u64 = 20;
+const MAX_SPEECH_MS: u64 = 2000;
+const MIN_SILENCE_MS: u64 = 100;
+const MAX_SILENCE_MS: u64 = 5000;
+const MAX_ADAPTIVE_FACTOR: f32 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VadConfig {
+    /// RMS energy above which a chunk counts as speech while Nexus is silent
+    pub threshold_rms: f32,
+    /// Speech this long opens a segment (shorter pops are ignored)
+    pub min_speech_ms: u64,
+    /// Silence this long closes it
+    pub min_silence_ms: u64,
+    /// Echo protection after playback ends (raised threshold)
+    pub grace_period_ms: u64,
+    /// Threshold multiplier while Nexus speaks and during the grace period
+    pub adaptive_factor: f32,
+}
+
+impl Default for VadConfig {
+    fn default() -> Self {
+        Self { threshold_rms: 0.03, min_speech_ms: 120, min_silence_ms: 500, grace_period_ms: 300, adaptive_factor: 3.0 }
+    }
+}
+
+/// VAD config location (`NEXUS_VAD_CONFIG` overrides the working-directory default).
+pub fn vad_config_path() -> PathBuf {
+    std::env::var("NEXUS_VAD_CONFIG").map(PathBuf::from).unwrap_or_else(|_| PathBuf::from(VAD_CONFIG_FILE))
+}
+
+// NaN keeps the default
+fn clamp_or(value: f32, min: f32, max: f32, default: f32) -> f32 {
+    if value.is_nan() { default } else { value.clamp(min, max) }
+}
+
+impl VadConfig {
+    /// PURE FUNCTION: Every value inside its usable range (NaN: the default).
+    pub fn sanitized(self) -> Self {
+        let default = Self::default();
+        Self {
+            threshold_rms: clamp_or(self.threshold_rms, MIN_THRESHOLD_RMS, MAX_THRESHOLD_RMS, default.threshold_rms),
+            min_speech_ms: self.min_speech_ms.clamp(MIN_SPEECH_MS, MAX_SPEECH_MS),
+            min_silence_ms: self.min_silence_ms.clamp(MIN_SILENCE_MS, MAX_SILENCE_MS),
+            grace_period_ms: self.grace_period_ms.min(MAX_ECHO_TAIL_MS),
+            adaptive_factor: clamp_or(self.adaptive_factor, 1.0, MAX_ADAPTIVE_FACTOR, default.adaptive_factor),
+        }
+    }
+
+    /// A TOML document (missing keys: defaults), sanitized.
+    pub fn parse(raw: &str) -> anyhow::Result<Self> {
+        Ok(toml::from_str::<Self>(raw)?.sanitized())
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Production default: the config file, or the defaults if there is none or it cannot be read.
+    pub fn from_default_path() -> Self {
+        let path = vad_config_path();
+        if !path.exists() {
+            return Self::default();
+        }
+        Self::load(&path).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load VAD config {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+}
@@ -0,0 +1,163 @@
+//! Backchannel ("mm-hm").
+//!
+//! A listener signals that it is following a long turn without taking it. With
+//! `ReactorConfig::backchannel` on, the kernel may emit a non-lexical cue (`SideEffect::PlayCue`)
+//! in a short pause of a long user turn, when:
+//! - the turn has lasted `BACKCHANNEL_MIN_TURN_TICKS` (pauses under `TURN_GAP_TICKS` do not end it),
+//! - the pause is at least `BACKCHANNEL_PAUSE_TICKS` old (never over the user's voice),
+//! - the transcript is stable: each provisional fragment scores its confidence when it extends
+//!   the previous one (0 when it rewrites it), and the mean is at least `BACKCHANNEL_MIN_STABILITY`,
+//! - the last cue was at least `BACKCHANNEL_INTERVAL_TICKS` ago,
+//! - presence is Engaged, nothing is being said, and turn pressure is below `BACKCHANNEL_MAX_TURN_PRESSURE`.
+//!
+//! Cues have their own channel. They are not outputs: no lifecycle, no dialogue log entry, no
+//! barge-in, and `StopAudio` does not concern them. They carry no content.
+
+use serde::Serialize;
+
+use crate::kernel::event::{AudioSignal, InputContent, InputEvent};
+use crate::kernel::presence::PresenceState;
+use crate::kernel::state::SharedState;
+use crate::kernel::time::Tick;
+
+// Config Constants
+/// A turn must have lasted this long before the first cue (5s @ 20ms).
+pub const BACKCHANNEL_MIN_TURN_TICKS: u64 = 250;
+/// Minimum spacing between cues (8s @ 20ms).
+pub const BACKCHANNEL_INTERVAL_TICKS: u64 = 400;
+/// Cues fall in a pause at least this old (200ms @ 20ms)...
+pub const BACKCHANNEL_PAUSE_TICKS: u64 = 10;
+/// ...and shorter than this, which ends the turn (800ms @ 20ms).
+pub const TURN_GAP_TICKS: u64 = 40;
+pub const BACKCHANNEL_MIN_STABILITY: f32 = 0.7;
+/// Fragments needed before stability means anything
+const MIN_FRAGMENTS: u32 = 2;
+/// A contested turn (the user talking over us) is no place for a cue.
+pub const BACKCHANNEL_MAX_TURN_PRESSURE: f32 = 0.2;
+
+/// Non-lexical acknowledgement. Consecutive cues alternate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Cue {
+    MmHm,
+    Mm,
+}
+
+impl Cue {
+    pub const ALL: [Cue; 2] = [Cue::MmHm, Cue::Mm];
+
+    /// What drivers play (synthesized once, then cached like any canned phrase).
+    pub fn sound(&self) -> &'static str {
+        match self {
+            Cue::MmHm => "mm-hm",
+            Cue::Mm => "mm",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Backchannel {
+    // Start of the current user turn (None: no turn)
+    turn_start: Option<Tick>,
+    last_cue: Option<Tick>,
+    // Latest fragment of the turn: segment id and lowercased words
+    last_fragment: Option<(String, Vec<String>)>,
+    stability_sum: f32,
+    fragments: u32,
+    cues: usize,
+}
+
+impl Backchannel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Follow the turn: speech edges (capture time) and provisional fragments.
+    pub fn observe(&mut self, input: &InputEvent, now: Tick, state: &SharedState) {
+        match &input.content {
+            InputContent::Audio(AudioSignal::SpeechStart) => {
+                let heard_at = input.captured_at.unwrap_or(now);
+                // `state` is reduced already: the previous end is the pause that just closed
+                let resumed = self.turn_start.is_some()
+                    && state.last_speech_end().is_some_and(|end| heard_at.frame.saturating_sub(end.frame) < TURN_GAP_TICKS);
+                if !resumed {
+                    self.start_turn(heard_at);
+                }
+            }
+            InputContent::ProvisionalText { content, confidence, source_id, .. } => {
+                let words: Vec<String> = content.split_whitespace().map(str::to_lowercase).collect();
+                let extends = match &self.last_fragment {
+                    Some((segment, previous)) if segment == source_id => {
+                        // The last word is still being recognized
+                        let settled = &previous[..previous.len().saturating_sub(1)];
+                        words.starts_with(settled)
+                    }
+                    _ => true,
+                };
+                self.stability_sum += if extends { confidence.clamp(0.0, 1.0) } else { 0.0 };
+                self.fragments += 1;
+                self.last_fragment = Some((source_id.clone(), words));
+            }
+            InputContent::Text(_) => self.end_turn(),
+            _ => {}
+        }
+    }
+
+    /// Mean confidence-weighted stability of the turn's fragments (0 before any).
+    pub fn stability(&self) -> f32 {
+        if self.fragments == 0 { 0.0 } else { self.stability_sum / self.fragments as f32 }
+    }
+
+    /// Cues emitted so far this session.
+    pub fn cues(&self) -> usize {
+        self.cues
+    }
+
+    /// The cue to play now, if any. Ends the turn once its pause is too long.
+    pub fn due(&mut self, state: &SharedState, now: Tick) -> Option<Cue> {
+        let turn_start = self.turn_start?;
+        let pause = if state.user_speaking() {
+            None
+        } else {
+            Some(now.frame.saturating_sub(state.last_speech_end().map_or(turn_start.frame, |end| end.frame)))
+        };
+        if pause.is_some_and(|p| p >= TURN_GAP_TICKS) {
+            self.end_turn();
+            return None;
+        }
+        let since_cue = self.last_cue.map_or(u64::MAX, |at| now.frame.saturating_sub(at.frame));
+        let ready = pause.is_some_and(|p| p >= BACKCHANNEL_PAUSE_TICKS)
+            && now.frame.saturating_sub(turn_start.frame) >= BACKCHANNEL_MIN_TURN_TICKS
+            && since_cue >= BACKCHANNEL_INTERVAL_TICKS
+            && self.fragments >= MIN_FRAGMENTS
+            && self.stability() >= BACKCHANNEL_MIN_STABILITY
+            && allows_cue(state);
+        if !ready {
+            return None;
+        }
+        self.last_cue = Some(now);
+        let cue = Cue::ALL[self.cues % Cue::ALL.len()];
+        self.cues += 1;
+        Some(cue)
+    }
+
+    fn start_turn(&mut self, at: Tick) {
+        self.turn_start = Some(at);
+        self.last_fragment = None;
+        self.stability_sum = 0.0;
+        self.fragments = 0;
+    }
+
+    fn end_turn(&mut self) {
+        self.turn_start = None;
+        self.last_fragment = None;
+        self.stability_sum = 0.0;
+        self.fragments = 0;
+    }
+}
+
+/// PURE FUNCTION: Does the rest of the kernel leave room for a cue?
+pub fn allows_cue(state: &SharedState) -> bool {
+    state.presence() == PresenceState::Engaged
+        && state.active_outputs().is_empty()
+        && state.turn_pressure() < BACKCHANNEL_MAX_TURN_PRESSURE
+}
@@ -0,0 +1,253 @@
+//! Conversation Bookmarks.
+//!
+//! "Remember this point" (or "bookmark this as the budget") drops a named anchor on the
+//! conversation: the active context and topic, the long-horizon intents still open in it, the
+//! memories learned under the topic and a short excerpt of what the user just said. Later,
+//! "go back to the budget discussion" finds the anchor by its words, switches back to its
+//! context, resumes its intents and re-grounds with the excerpt.
+//!
+//! The excerpt is the user's own words, so it is the one place kernel speech quotes user text:
+//! only on explicit request, and never a sensitive turn (those are left out when the bookmark
+//! is made). Bookmarks live for the session, at most `MAX_BOOKMARKS`, oldest dropped first.
+
+use crate::kernel::context::ContextId;
+use crate::kernel::dialogue::{DialogueTurn, Speaker};
+use crate::kernel::intent::long_horizon::{IntentId, IntentStatus};
+use crate::kernel::memory::topic::TopicId;
+use crate::kernel::memory::types::MemoryId;
+use crate::kernel::state::SharedState;
+use crate::kernel::time::Tick;
+
+// Config Constants
+pub const MAX_BOOKMARKS: usize = 32;
+/// User turns quoted by a bookmark (most recent, oldest first).
+pub const EXCERPT_TURNS: usize = 3;
+/// Words of the re-grounding summary; longer excerpts are cut at the front.
+pub const MAX_SUMMARY_WORDS: usize = 24;
+/// Intents linked to one bookmark (most recently active).
+pub const MAX_LINKED_INTENTS: usize = 3;
+// Longer utterances talk about going back rather than ask for it
+const MAX_REQUEST_WORDS: usize = 12;
+// Keywords are content words: at least this long and not filler
+const MIN_KEYWORD_LEN: usize = 4;
+const NAME_KEYWORDS: usize = 2;
+
+const CREATE_VERBS: &[&str] = &["remember", "mark", "save", "keep"];
+// Only these objects, with nothing said after them but a name: "remember that place we went"
+// is a question about the past, not a bookmark
+const CREATE_OBJECTS: &[&str] = &["point", "moment"];
+// A recall names a bookmark or a past discussion ("go back to sleep" does not)
+const RECALL_MARKERS: &[&str] = &["bookmark", "discussed", "discussing", "discussion", "talking", "talked"];
+const RECALL_VERBS: &[&str] = &["return", "revisit"];
+// Never part of a name or a query ("go back to what we were discussing")
+const FILLER_WORDS: &[&str] = &[
+    "the", "our", "my", "that", "this", "what", "we", "were", "was", "discussed", "discussing",
+    "talking", "talked", "about", "discussion", "conversation", "talk", "thing", "bit", "part",
+    "point", "moment", "spot", "bookmark", "bookmarked", "earlier", "before", "please", "lets",
+    "let", "us", "get", "go", "to", "as", "it", "call", "called", "a", "an", "of", "on", "again",
+];
+// Common words that say nothing about what was discussed
+const STOP_WORDS: &[&str] = &[
+    "need", "want", "have", "with", "from", "would", "could", "should", "just", "like", "really",
+    "think", "some", "there", "them", "they", "your", "going", "know", "make", "also", "then",
+    "than", "much", "very", "will", "when", "where", "which", "into", "does",
+];
+
+/// A spoken bookmark command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BookmarkRequest {
+    /// Anchor here; without a name, one is made from what was said
+    Create { name: Option<String> },
+    /// Go back to the bookmark best matching `query` (`None`: the latest one)
+    Recall { query: Option<String> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bookmark {
+    pub name: String,
+    pub created_at: Tick,
+    pub context: ContextId,
+    pub topic: Option<TopicId>,
+    /// Open long-horizon intents at the time, most recently active first
+    pub intent_ids: Vec<IntentId>,
+    /// Long-term memories learned under the topic
+    pub memory_ids: Vec<MemoryId>,
+    /// Non-sensitive user turns just before the bookmark, at most `MAX_SUMMARY_WORDS` words
+    pub summary: String,
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+// Words of a name or query, filler removed
+fn meaningful(words: &[String]) -> Vec<String> {
+    words.iter().filter(|w| !FILLER_WORDS.contains(&w.as_str())).cloned().collect()
+}
+
+/// PURE FUNCTION: Content words of `text` ("we need the travel budget" -> ["travel", "budget"]).
+pub fn keywords(text: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for word in meaningful(&words(text)) {
+        if word.len() >= MIN_KEYWORD_LEN && !STOP_WORDS.contains(&word.as_str()) && !out.contains(&word) {
+            out.push(word);
+        }
+    }
+    out
+}
+
+// Same word, allowing a plural or other suffix ("budget" ~ "budgets")
+fn same_word(a: &str, b: &str) -> bool {
+    a == b || (a.len().min(b.len()) >= MIN_KEYWORD_LEN && (a.starts_with(b) || b.starts_with(a)))
+}
+
+/// PURE FUNCTION: Read a bookmark command ("remember this point", "bookmark this as the
+/// budget", "go back to the budget discussion", "return to what we discussed").
+/// Only explicit ones: the word "bookmark", "this point/moment" with nothing after it but a
+/// name, or going back to a bookmark or discussion. Context switches ("go back to my work
+/// context") are not bookmark commands.
+pub fn parse_bookmark_request(text: &str) -> Option<BookmarkRequest> {
+    let words = words(text);
+    if words.is_empty() || words.len() > MAX_REQUEST_WORDS || crate::kernel::context::parse_switch(text).is_some() {
+        return None;
+    }
+    let name_after = |at: usize| -> Option<String> {
+        let name = meaningful(&words[at..]);
+        (!name.is_empty()).then(|| name.join(" "))
+    };
+    // "... as <name>", "... call it <name>"
+    let named = || {
+        words.iter().position(|w| w == "as" || w == "called" || w == "call").and_then(|at| name_after(at + 1))
+    };
+
+    // "bookmark this", "bookmark that as the budget", "remember this point", "mark this moment"
+    let bookmark_verb = words.first().map(String::as_str) == Some("bookmark")
+        || words.windows(2).any(|w| w[0] == "bookmark" && (w[1] == "this" || w[1] == "that" || w[1] == "it"));
+    let create_phrase = words.windows(3).position(|w| {
+        CREATE_VERBS.contains(&w[0].as_str()) && (w[1] == "this" || w[1] == "that") && CREATE_OBJECTS.contains(&w[2].as_str())
+    }).is_some_and(|at| match &words[at + 3..] {
+        // Nothing after the object but a name ("... as taxes", "... and call it taxes")
+        [] => true,
+        [first, ..] if first == "as" || first == "call" || first == "called" => true,
+        [and, call, ..] => and == "and" && (call == "call" || call == "called"),
+        _ => false,
+    });
+    if bookmark_verb || create_phrase {
+        // "bookmark the budget" names it too
+        let name = named().or_else(|| bookmark_verb.then(|| words.iter().position(|w| w == "bookmark")).flatten().and_then(|at| name_after(at + 1)));
+        return Some(BookmarkRequest::Create { name });
+    }
+
+    // "go back to X", "back to X", "return to X", "revisit X"
+    let target = words.windows(2).position(|w| w[0] == "back" && w[1] == "to").map(|at| at + 2)
+        .or_else(|| words.iter().position(|w| RECALL_VERBS.contains(&w.as_str())).map(|at| at + 1))?;
+    if !words[target..].iter().any(|w| RECALL_MARKERS.contains(&w.as_str())) {
+        return None;
+    }
+    Some(BookmarkRequest::Recall { query: name_after(target) })
+}
+
+/// PURE FUNCTION: A bookmark of the current state. `turns`: recent dialogue turns, oldest first;
+/// sensitive turns and bookmark commands are not quoted. `ordinal` numbers unnamed bookmarks.
+pub fn capture(name: Option<String>, state: &SharedState, turns: &[DialogueTurn], now: Tick, ordinal: usize) -> Bookmark {
+    let quoted: Vec<&str> = turns.iter()
+        .filter(|t| t.speaker == Speaker::User && t.sensitivity.is_none())
+        .filter(|t| parse_bookmark_request(&t.text).is_none())
+        .map(|t| t.text.trim())
+        .collect();
+    let excerpt = quoted[quoted.len().saturating_sub(EXCERPT_TURNS)..].join(". ");
+    let excerpt_words: Vec<&str> = excerpt.split_whitespace().collect();
+    let summary = if excerpt_words.len() > MAX_SUMMARY_WORDS {
+        format!("...{}", excerpt_words[excerpt_words.len() - MAX_SUMMARY_WORDS..].join(" "))
+    } else {
+        excerpt_words.join(" ")
+    };
+
+    let mut open: Vec<_> = state.active_intents().values()
+        .filter(|i| matches!(i.status, IntentStatus::Active | IntentStatus::Suspended | IntentStatus::Dormant))
+        .filter(|i| &i.context == state.active_context())
+        .collect();
+    open.sort_by(|a, b| b.last_active_at.frame.cmp(&a.last_active_at.frame).then_with(|| a.id.cmp(&b.id)));
+    let topic = state.active_topic().cloned();
+    let mut memory_ids: Vec<MemoryId> = topic.as_ref()
+        .map(|t| state.memories_for_topic(t).into_iter().map(|r| r.id.clone()).collect())
+        .unwrap_or_default();
+    memory_ids.sort();
+
+    // Unnamed: the excerpt's first keywords, else a number
+    let name = name.unwrap_or_else(|| {
+        let words = keywords(&excerpt);
+        if words.is_empty() {
+            format!("bookmark {}", ordinal + 1)
+        } else {
+            words[..words.len().min(NAME_KEYWORDS)].join(" ")
+        }
+    });
+
+    Bookmark {
+        name,
+        created_at: now,
+        context: state.active_context().clone(),
+        topic,
+        intent_ids: open.into_iter().take(MAX_LINKED_INTENTS).map(|i| i.id.clone()).collect(),
+        memory_ids,
+        summary,
+    }
+}
+
+/// Session bookmarks, oldest first.
+#[derive(Debug, Default)]
+pub struct BookmarkStore {
+    bookmarks: Vec<Bookmark>,
+}
+
+impl BookmarkStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep `bookmark`. A bookmark of the same name is replaced; beyond `MAX_BOOKMARKS`, the
+    /// oldest goes.
+    pub fn add(&mut self, bookmark: Bookmark) {
+        self.bookmarks.retain(|b| b.name != bookmark.name);
+        self.bookmarks.push(bookmark);
+        if self.bookmarks.len() > MAX_BOOKMARKS {
+            self.bookmarks.remove(0);
+        }
+    }
+
+    /// PURE FUNCTION: The bookmark whose name and summary share the most keywords with `query`
+    /// (ties: the most recent). `None` query: the latest bookmark.
+    pub fn find(&self, query: Option<&str>) -> Option<&Bookmark> {
+        let Some(query) = query else { return self.bookmarks.last() };
+        let wanted = keywords(query);
+        let score = |b: &Bookmark| {
+            let known = keywords(&format!("{} {}", b.name, b.summary));
+            wanted.iter().filter(|w| known.iter().any(|k| same_word(w, k))).count()
+        };
+        self.bookmarks.iter().rev()
+            .map(|b| (score(b), b))
+            .filter(|(score, _)| *score > 0)
+            .fold(None::<(usize, &Bookmark)>, |best, (score, b)| match best {
+                Some((top, _)) if top >= score => best,
+                _ => Some((score, b)),
+            })
+            .map(|(_, b)| b)
+    }
+
+    pub fn list(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+
+    pub fn len(&self) -> usize {
+        self.bookmarks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bookmarks.is_empty()
+    }
+}
@@ -0,0 +1,374 @@
+//! WebSocket Bridge: the kernel for frontends other than the Tauri shell.
+//!
+//! Web dashboards and remote UIs connect over a WebSocket (`server`, feature `bridge`) and
+//! exchange JSON text frames:
+//! - client -> kernel (`ClientMessage`): typed text, transcripts from the client's own speech
+//!   recognizer (interim ones too), memory consent answers, the mic toggle, and which streams
+//!   (`Topic`) the client wants;
+//! - kernel -> client (`ServerMessage`): state snapshots (the published `StateView`), presence
+//!   transitions, and outputs (spoken text, speech requests, consent prompts).
+//!
+//! Inputs go through the driver's event channel like any other input, so the reducer, the
+//! consent rules and the onboarding allowlist apply. The stream comes from a `BridgeObserver`,
+//! a passive `TickObserver` that reads the view the tick just published: the bridge never
+//! locks the Reactor, and never misses a presence transition between two snapshots.
+//!
+//! Unlike the dashboard, a client is a frontend: it sees what was said, unredacted. Loopback is
+//! no protection on its own: any web page open in the user's browser can reach 127.0.0.1. So the
+//! server always needs a token (`NEXUS_BRIDGE_TOKEN`, generated at start when unset), and refuses
+//! upgrades from browser origins that are not allow-listed (`NEXUS_BRIDGE_ORIGINS`).
+
+#[cfg(feature = "bridge")]
+pub mod server;
+
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, mpsc};
+
+use crate::kernel::event::{Event, InputContent, InputEvent, OutputId};
+use crate::kernel::observer::{TickObserver, TickRecord};
+use crate::kernel::presence::PresenceState;
+use crate::kernel::protocol::{ConsentRequest, ConsentResponse};
+use crate::kernel::reactor::Reactor;
+use crate::kernel::scheduler::SideEffect;
+use crate::kernel::time::Tick;
+use crate::kernel::view::{StateView, StateViewReceiver};
+
+// Config Constants
+pub const BRIDGE_VERSION: u32 = 1;
+const DEFAULT_BIND: &str = "127.0.0.1:7879"; // Next to the dashboard's 7878
+/// Snapshots are streamed at most this often (1s @ 20ms); presence and outputs at once.
+pub const DEFAULT_SNAPSHOT_INTERVAL_TICKS: u64 = 50;
+/// Messages buffered per client before a slow one starts missing them.
+const OUTBOX_CAPACITY: usize = 256;
+/// Event source of client input.
+pub const BRIDGE_SOURCE: &str = "Bridge";
+
+#[derive(Debug, Clone)]
+pub struct BridgeConfig {
+    pub bind: SocketAddr,
+    /// Clients must pass `?token=...`. The server does not start without one.
+    pub token: Option<String>,
+    /// Browser origins (`Origin` header) allowed to connect; clients that send none are not browsers
+    pub allowed_origins: Vec<String>,
+    pub snapshot_interval_ticks: u64,
+}
+
+impl Default for BridgeConfig {
+    fn default() -> Self {
+        Self {
+            bind: DEFAULT_BIND.parse().expect("valid default bind address"),
+            token: None,
+            allowed_origins: Vec::new(),
+            snapshot_interval_ticks: DEFAULT_SNAPSHOT_INTERVAL_TICKS,
+        }
+    }
+}
+
+impl BridgeConfig {
+    /// From `NEXUS_BRIDGE_BIND`, `NEXUS_BRIDGE_TOKEN` (a random one when unset) and
+    /// `NEXUS_BRIDGE_ORIGINS` (comma-separated, e.g. `http://localhost:5173`).
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(bind) = std::env::var("NEXUS_BRIDGE_BIND").ok().and_then(|b| b.parse().ok()) {
+            config.bind = bind;
+        }
+        config.token = Some(Self::token_or_generated(std::env::var("NEXUS_BRIDGE_TOKEN").ok()));
+        config.allowed_origins = std::env::var("NEXUS_BRIDGE_ORIGINS")
+            .map(|list| list.split(',').map(|o| o.trim().to_string()).filter(|o| !o.is_empty()).collect())
+            .unwrap_or_default();
+        config
+    }
+
+    /// The configured token, or a random one when it is unset or empty. Never empty.
+    pub fn token_or_generated(token: Option<String>) -> String {
+        token.filter(|t| !t.is_empty()).unwrap_or_else(|| uuid::Uuid::new_v4().simple().to_string())
+    }
+
+    /// The server refuses to start without a token, on any address.
+    pub fn check(&self) -> Result<(), BridgeError> {
+        if self.token.is_none() {
+            return Err(BridgeError::TokenRequired);
+        }
+        Ok(())
+    }
+
+    /// PURE FUNCTION: May a client presenting `token` connect? Never without a configured token.
+    /// Compared in constant time, so response timing does not reveal how much of a guess matched.
+    pub fn authorized(&self, token: Option<&str>) -> bool {
+        match (self.token.as_deref(), token) {
+            (Some(expected), Some(token)) => constant_time_eq(expected.as_bytes(), token.as_bytes()),
+            _ => false,
+        }
+    }
+
+    /// PURE FUNCTION: May a connection from `origin` (its `Origin` header) upgrade? A page in
+    /// the user's browser always sends one, so only allow-listed origins get through.
+    pub fn origin_allowed(&self, origin: Option<&str>) -> bool {
+        origin.is_none_or(|origin| self.allowed_origins.iter().any(|allowed| allowed == origin))
+    }
+}
+
+/// Byte equality that looks at every byte whatever the first mismatch. Only the length may
+/// differ in timing, and a generated token's length is no secret.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    std::hint::black_box(diff) == 0
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeError {
+    /// Not a `ClientMessage` (serde error)
+    Malformed(String),
+    /// No `BridgeConfig::token` to check clients against
+    TokenRequired,
+    /// The driver gave the bridge no microphone to switch
+    MicUnavailable,
+    /// The kernel's event channel is closed (shutting down)
+    KernelClosed,
+}
+
+/// A stream a client can subscribe to. `Hello` and `Error` always reach the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Topic {
+    Snapshot,
+    Presence,
+    Output,
+}
+
+impl Topic {
+    pub const ALL: [Topic; 3] = [Topic::Snapshot, Topic::Presence, Topic::Output];
+}
+
+/// Client -> kernel.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Typed text, as from the shell's text box
+    Text { text: String },
+    /// A transcript from the client's own recognizer (e.g. a browser's streaming speech API).
+    /// Interim hypotheses are `is_final: false`; the last one for `segment_id` is final.
+    Transcript { text: String, segment_id: String, confidence: f32, is_final: bool },
+    /// Answer to a `ServerMessage::ConsentPrompt` (echoes its `prompt_id`)
+    ConsentResponse { response: ConsentResponse },
+    /// The mic toggle: start or stop capture
+    Mic { active: bool },
+    /// Replace the client's topics (all of them until it sends this)
+    Subscribe { topics: Vec<Topic> },
+}
+
+impl ClientMessage {
+    /// PURE FUNCTION: One text frame.
+    pub fn parse(frame: &str) -> Result<Self, BridgeError> {
+        serde_json::from_str(frame).map_err(|e| BridgeError::Malformed(e.to_string()))
+    }
+
+    /// PURE FUNCTION: The kernel input this message stands for (`None`: handled by the bridge).
+    pub fn input(&self) -> Option<InputEvent> {
+        let content = match self {
+            ClientMessage::Text { text } => InputContent::Text(text.clone()),
+            ClientMessage::Transcript { text, segment_id, confidence, is_final } => InputContent::ProvisionalText {
+                content: text.clone(),
+                confidence: *confidence,
+                source_id: segment_id.clone(),
+                words: Vec::new(),
+                language: None,
+                is_final: *is_final,
+            },
+            ClientMessage::ConsentResponse { response } => InputContent::MemoryConsentResponse(response.clone()),
+            ClientMessage::Mic { .. } | ClientMessage::Subscribe { .. } => return None,
+        };
+        Some(InputEvent { source: BRIDGE_SOURCE.to_string(), content, captured_at: None })
+    }
+}
+
+/// Kernel -> client.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// First message on every connection
+    Hello { version: u32 },
+    Snapshot { view: StateView },
+    Presence { tick: Tick, from: PresenceState, to: PresenceState },
+    /// Text the kernel speaks (`SideEffect::SpawnAudio`; a sentence of it with chunked speech,
+    /// see `outputs::streamer::parent_of`)
+    Output { tick: Tick, output_id: OutputId, text: String },
+    /// Speech the driver generates (`SideEffect::RequestSpeech`); described, the kernel never sees its text
+    Speech { tick: Tick, output_id: uuid::Uuid, description: String },
+    /// "May I remember that?" Answer with `ClientMessage::ConsentResponse`.
+    ConsentPrompt { request: ConsentRequest },
+    /// The open prompt was answered (by voice or another client): close it
+    ConsentDismissed { prompt_id: String },
+    /// A client message was refused
+    Error { message: String },
+}
+
+impl ServerMessage {
+    /// PURE FUNCTION: Stream this message belongs to (`None`: always delivered).
+    pub fn topic(&self) -> Option<Topic> {
+        match self {
+            ServerMessage::Snapshot { .. } => Some(Topic::Snapshot),
+            ServerMessage::Presence { .. } => Some(Topic::Presence),
+            ServerMessage::Output { .. }
+            | ServerMessage::Speech { .. }
+            | ServerMessage::ConsentPrompt { .. }
+            | ServerMessage::ConsentDismissed { .. } => Some(Topic::Output),
+            ServerMessage::Hello { .. } | ServerMessage::Error { .. } => None,
+        }
+    }
+}
+
+/// PURE FUNCTION: How an effect reaches clients (`None`: not streamed).
+pub fn effect_message(tick: Tick, effect: &SideEffect) -> Option<ServerMessage> {
+    match effect {
+        SideEffect::SpawnAudio(output_id, text) => Some(ServerMessage::Output { tick, output_id: *output_id, text: text.clone() }),
+        SideEffect::RequestSpeech { intent, output_id } => Some(ServerMessage::Speech {
+            tick,
+            output_id: *output_id,
+            description: crate::memory::summary::describe_speech(intent),
+        }),
+        SideEffect::AskMemoryConsent(request) => Some(ServerMessage::ConsentPrompt { request: request.clone() }),
+        SideEffect::DismissConsent(response) => Some(ServerMessage::ConsentDismissed { prompt_id: response.prompt_id.clone() }),
+        _ => None,
+    }
+}
+
+/// Topics one connection receives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Subscription {
+    topics: HashSet<Topic>,
+}
+
+impl Default for Subscription {
+    fn default() -> Self {
+        Self { topics: Topic::ALL.into_iter().collect() }
+    }
+}
+
+impl Subscription {
+    pub fn set_topics(&mut self, topics: &[Topic]) {
+        self.topics = topics.iter().copied().collect();
+    }
+
+    pub fn wants(&self, message: &ServerMessage) -> bool {
+        message.topic().is_none_or(|topic| self.topics.contains(&topic))
+    }
+}
+
+/// Driver side of `ClientMessage::Mic`: the microphone lives in the capture layer, not the kernel.
+pub trait MicSwitch: Send + Sync {
+    fn set_active(&self, active: bool);
+}
+
+#[cfg(feature = "vad")]
+impl MicSwitch for crate::audio::capture::CaptureController {
+    fn set_active(&self, active: bool) {
+        if active { self.start() } else { self.stop() }
+    }
+}
+
+/// Streams each tick to the bridge's clients. Registered by `Bridge::attach`.
+pub struct BridgeObserver {
+    view: StateViewReceiver,
+    outbox: broadcast::Sender<ServerMessage>,
+    presence: Option<PresenceState>,
+    last_snapshot: Option<Tick>,
+    snapshot_interval_ticks: u64,
+}
+
+impl TickObserver for BridgeObserver {
+    fn name(&self) -> &str {
+        "bridge"
+    }
+
+    fn observe(&mut self, record: &TickRecord) {
+        // Published by this tick (observers run after it)
+        let view = self.view.borrow().clone();
+        let mut messages = Vec::new();
+        if let Some(from) = self.presence.filter(|p| *p != view.presence) {
+            messages.push(ServerMessage::Presence { tick: record.tick, from, to: view.presence });
+        }
+        self.presence = Some(view.presence);
+        messages.extend(record.effects.iter().filter_map(|e| effect_message(record.tick, e)));
+        let due = self.last_snapshot.is_none_or(|at| record.tick.frame.saturating_sub(at.frame) >= self.snapshot_interval_ticks);
+        if due {
+            self.last_snapshot = Some(record.tick);
+            messages.push(ServerMessage::Snapshot { view: (*view).clone() });
+        }
+        for message in messages {
+            // No client connected: nothing to deliver
+            let _ = self.outbox.send(message);
+        }
+    }
+}
+
+/// Shared by the server's connections: input to the kernel, the stream out of it.
+pub struct Bridge {
+    config: BridgeConfig,
+    sender: mpsc::Sender<Event>,
+    view: StateViewReceiver,
+    outbox: broadcast::Sender<ServerMessage>,
+    mic: Option<Arc<dyn MicSwitch>>,
+}
+
+impl Bridge {
+    /// Registers the `BridgeObserver` on `reactor`. `sender`: the driver's event channel.
+    pub fn attach(reactor: &mut Reactor, sender: mpsc::Sender<Event>, config: BridgeConfig) -> Self {
+        let (outbox, _) = broadcast::channel(OUTBOX_CAPACITY);
+        let view = reactor.subscribe_view();
+        reactor.register_observer(Box::new(BridgeObserver {
+            view: view.clone(),
+            outbox: outbox.clone(),
+            presence: None,
+            last_snapshot: None,
+            snapshot_interval_ticks: config.snapshot_interval_ticks.max(1),
+        }));
+        Self { config, sender, view, outbox, mic: None }
+    }
+
+    /// Let clients switch capture on and off.
+    pub fn with_mic(mut self, mic: Arc<dyn MicSwitch>) -> Self {
+        self.mic = Some(mic);
+        self
+    }
+
+    pub fn config(&self) -> &BridgeConfig {
+        &self.config
+    }
+
+    /// A new connection's stream (messages from now on).
+    pub fn subscribe(&self) -> broadcast::Receiver<ServerMessage> {
+        self.outbox.subscribe()
+    }
+
+    /// What a new connection gets first: `Hello` and the current snapshot.
+    pub fn greeting(&self) -> Vec<ServerMessage> {
+        vec![
+            ServerMessage::Hello { version: BRIDGE_VERSION },
+            ServerMessage::Snapshot { view: (**self.view.borrow()).clone() },
+        ]
+    }
+
+    /// Apply one client message for the connection holding `subscription`.
+    pub async fn handle(&self, message: ClientMessage, subscription: &mut Subscription) -> Result<(), BridgeError> {
+        if let Some(input) = message.input() {
+            return self.sender.send(Event::Input(input)).await.map_err(|_| BridgeError::KernelClosed);
+        }
+        match message {
+            ClientMessage::Mic { active } => {
+                let mic = self.mic.as_ref().ok_or(BridgeError::MicUnavailable)?;
+                mic.set_active(active);
+            }
+            ClientMessage::Subscribe { topics } => subscription.set_topics(&topics),
+            ClientMessage::Text { .. } | ClientMessage::Transcript { .. } | ClientMessage::ConsentResponse { .. } => {}
+        }
+        Ok(())
+    }
+}
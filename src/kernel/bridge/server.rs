@@ -0,0 +1,100 @@
+//! Bridge WebSocket server (feature `bridge`).
+//!
+//! Route: `GET /ws?token=...` upgrades to a WebSocket. A wrong or missing token is a 401, a
+//! browser `Origin` that is not allow-listed a 403. Every connection gets `Hello` and a snapshot, then the topics it subscribed to. A client
+//! message that is refused is answered with `ServerMessage::Error`; the connection stays open.
+
+use std::sync::Arc;
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{RawQuery, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use tokio::sync::broadcast::error::RecvError;
+
+use super::{Bridge, BridgeError, ClientMessage, ServerMessage, Subscription};
+
+/// The WebSocket route over a shared Bridge.
+pub fn router(bridge: Arc<Bridge>) -> Router {
+    Router::new().route("/ws", get(upgrade)).with_state(bridge)
+}
+
+/// Serve until the listener fails. Refuses to start without a token.
+pub async fn serve(bridge: Bridge) -> anyhow::Result<()> {
+    if let Err(e) = bridge.config().check() {
+        anyhow::bail!("bridge not started: {:?}", e);
+    }
+    let listener = tokio::net::TcpListener::bind(bridge.config().bind).await?;
+    tracing::info!("[Bridge] Serving ws://{}/ws", bridge.config().bind);
+    axum::serve(listener, router(Arc::new(bridge))).await?;
+    Ok(())
+}
+
+/// PURE FUNCTION: The `token` parameter of a query string, percent-decoded.
+pub fn token_param(query: Option<&str>) -> Option<String> {
+    form_urlencoded::parse(query?.as_bytes())
+        .find(|(key, _)| key == "token")
+        .map(|(_, value)| value.into_owned())
+}
+
+async fn upgrade(ws: WebSocketUpgrade, headers: HeaderMap, RawQuery(query): RawQuery, State(bridge): State<Arc<Bridge>>) -> Response {
+    // Cross-site WebSocket hijacking: a page's script cannot choose its Origin
+    let origin = headers.get(header::ORIGIN).and_then(|o| o.to_str().ok());
+    if !bridge.config().origin_allowed(origin) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if !bridge.config().authorized(token_param(query.as_deref()).as_deref()) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+    ws.on_upgrade(move |socket| session(socket, bridge))
+}
+
+async fn session(mut socket: WebSocket, bridge: Arc<Bridge>) {
+    let mut outbox = bridge.subscribe();
+    let mut subscription = Subscription::default();
+    for message in bridge.greeting() {
+        if send(&mut socket, &message).await.is_err() {
+            return;
+        }
+    }
+    loop {
+        tokio::select! {
+            frame = socket.recv() => {
+                let text = match frame {
+                    Some(Ok(Message::Text(text))) => text,
+                    // Pings are answered by axum; binary frames are not part of the protocol
+                    Some(Ok(Message::Binary(_) | Message::Ping(_) | Message::Pong(_))) => continue,
+                    Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                };
+                let result = match ClientMessage::parse(&text) {
+                    Ok(message) => bridge.handle(message, &mut subscription).await,
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = result {
+                    let closed = e == BridgeError::KernelClosed;
+                    if send(&mut socket, &ServerMessage::Error { message: format!("{:?}", e) }).await.is_err() || closed {
+                        break;
+                    }
+                }
+            }
+            message = outbox.recv() => match message {
+                Ok(message) if subscription.wants(&message) => {
+                    if send(&mut socket, &message).await.is_err() {
+                        break;
+                    }
+                }
+                Ok(_) => {}
+                // A slow client misses messages rather than holding the others up
+                Err(RecvError::Lagged(missed)) => tracing::debug!("[Bridge] Client lagged, {} messages skipped", missed),
+                Err(RecvError::Closed) => break,
+            },
+        }
+    }
+}
+
+async fn send(socket: &mut WebSocket, message: &ServerMessage) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(message).unwrap_or_default();
+    socket.send(Message::Text(text)).await
+}
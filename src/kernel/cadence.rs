@@ -0,0 +1,134 @@
+//! Driver Cadence: fine ticks while active, a low-power wait while Dormant.
+//!
+//! Drivers step the kernel on a fixed cadence (`TICK_MS`). Nothing needs that while presence
+//! is Dormant and the driver has nothing in flight, so `DriverCadence` then blocks on the
+//! event channel instead, with a coarse timer (`LowPowerConfig::wake_ms`) as a floor for
+//! kernel timers (presence decay, maintenance, expiries). An event that arrives is stepped at
+//! once. The fine cadence resumes as soon as presence leaves Dormant or the driver has work in
+//! flight again (a stray event while Dormant costs one step, not a return to fine ticks).
+//!
+//! Kernel time keeps wall-clock pace: the ticks a low-power wait covered are reported as
+//! `Wake::skipped`, and the driver hands them to `Reactor::advance_idle` before stepping.
+
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tokio::time::{Instant, Interval, MissedTickBehavior};
+
+use crate::kernel::event::Event;
+use crate::kernel::presence::PresenceState;
+
+// Config Constants
+/// Low power: the loop still wakes this often without events.
+pub const DEFAULT_WAKE_MS: u64 = 500;
+/// Dormant and idle this long before the loop slows down (a reply or a late result may follow).
+pub const DEFAULT_ENTER_AFTER_MS: u64 = 2_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LowPowerConfig {
+    pub enabled: bool,
+    pub wake_ms: u64,
+    pub enter_after_ms: u64,
+}
+
+impl Default for LowPowerConfig {
+    fn default() -> Self {
+        Self { enabled: true, wake_ms: DEFAULT_WAKE_MS, enter_after_ms: DEFAULT_ENTER_AFTER_MS }
+    }
+}
+
+impl LowPowerConfig {
+    /// `NEXUS_LOW_POWER=0` keeps the fine cadence; `NEXUS_LOW_POWER_WAKE_MS` sets the coarse timer.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            enabled: !matches!(std::env::var("NEXUS_LOW_POWER").as_deref(), Ok("0") | Ok("false")),
+            wake_ms: std::env::var("NEXUS_LOW_POWER_WAKE_MS").ok().and_then(|v| v.parse().ok()).unwrap_or(defaults.wake_ms),
+            ..defaults
+        }
+    }
+}
+
+/// How the driver loop waits between steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CadenceMode {
+    /// Every tick
+    Fine,
+    /// Blocked on the event channel, coarse timer as a floor
+    LowPower,
+}
+
+/// What ended a wait.
+#[derive(Debug, Default)]
+pub struct Wake {
+    /// Event that ended a low-power wait (step it before draining the channel)
+    pub event: Option<Event>,
+    /// Ticks the wait covered beyond the one about to be stepped (`Reactor::advance_idle`)
+    pub skipped: u64,
+}
+
+pub struct DriverCadence {
+    config: LowPowerConfig,
+    period: Duration,
+    fine: Interval,
+    mode: CadenceMode,
+    last_step: Instant,
+    // Dormant and idle since (None: not quiet)
+    quiet_since: Option<Instant>,
+}
+
+impl DriverCadence {
+    /// `period`: one kernel step at the fine cadence.
+    pub fn new(period: Duration, config: LowPowerConfig) -> Self {
+        let mut fine = tokio::time::interval(period);
+        fine.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        Self { config, period, fine, mode: CadenceMode::Fine, last_step: Instant::now(), quiet_since: None }
+    }
+
+    pub fn mode(&self) -> CadenceMode {
+        self.mode
+    }
+
+    /// Wait for the next step. Fine: the next tick. Low power: the first event on `receiver`
+    /// or the coarse timer, whichever comes first.
+    pub async fn wait(&mut self, receiver: &mut mpsc::Receiver<Event>) -> Wake {
+        let mut wake = Wake::default();
+        match self.mode {
+            CadenceMode::Fine => {
+                self.fine.tick().await;
+            }
+            CadenceMode::LowPower => {
+                wake.event = tokio::time::timeout(Duration::from_millis(self.config.wake_ms), receiver.recv()).await.ok().flatten();
+                let periods = (self.last_step.elapsed().as_millis() / self.period.as_millis().max(1)) as u64;
+                wake.skipped = periods.saturating_sub(1);
+            }
+        }
+        self.last_step = Instant::now();
+        wake
+    }
+
+    /// After each step: low power once presence has been Dormant, with the driver `idle`, for
+    /// `enter_after_ms`; anything else resumes the fine cadence. Returns the new mode on a change.
+    pub fn follow(&mut self, presence: PresenceState, idle: bool) -> Option<CadenceMode> {
+        if !self.config.enabled || presence != PresenceState::Dormant || !idle {
+            self.quiet_since = None;
+            return self.switch(CadenceMode::Fine);
+        }
+        let since = *self.quiet_since.get_or_insert_with(Instant::now);
+        if since.elapsed() >= Duration::from_millis(self.config.enter_after_ms) {
+            return self.switch(CadenceMode::LowPower);
+        }
+        None
+    }
+
+    fn switch(&mut self, mode: CadenceMode) -> Option<CadenceMode> {
+        if self.mode == mode {
+            return None;
+        }
+        self.mode = mode;
+        // The next fine tick is one period after the step that is about to run
+        self.fine.reset();
+        Some(mode)
+    }
+}
@@ -1,6 +1,80 @@
-use super::state::StateDelta;
+use super::state::{SharedState, StateDelta};
 use super::event::{InputEvent, InputContent, AudioSignal}; // Assuming cancellation cmds come as inputs for now
 use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use super::event::OutputId;
+use super::time::Tick;
+use crate::kernel::intent::long_horizon::IntentId;
+use crate::kernel::telemetry::event::InterruptionSource;
+
+/// Max interruption records kept in state (oldest dropped first).
+pub const MAX_INTERRUPTION_RECORDS: usize = 16;
+/// Percept distance (bits) that counts as a hard context shift.
+const VISUAL_CANCEL_DISTANCE: u32 = 5;
+
+/// What was being said when the user barged in.
+/// Lengths only (no text), so it is safe to hand to the planner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InterruptionRecord {
+    pub output_id: OutputId,
+    pub spoken_chars: usize, // Estimated from playback progress
+    pub total_chars: usize,
+    pub active_intent: Option<IntentId>,
+    pub tick: Tick,
+    /// What cut it off
+    pub source: InterruptionSource,
+}
+
+/// One interruption as the user would put it, for "why was I interrupted?" reports from
+/// alpha users. Lengths and the cause only, like the record it comes from.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InterruptionReport {
+    pub output_id: OutputId,
+    pub source: InterruptionSource,
+    /// Human-readable cause ("you started speaking")
+    pub cause: String,
+    pub spoken_chars: usize,
+    pub total_chars: usize,
+    /// Ticks since the interruption
+    pub age_ticks: u64,
+}
+
+/// PURE FUNCTION: What triggered a cancellation this tick. A barge-in wins over a screen
+/// change, which wins over a typed "stop" (the most specific signal the user gave).
+pub fn interruption_source(inputs: &[InputEvent]) -> InterruptionSource {
+    let speech = inputs.iter().any(|i| matches!(i.content, InputContent::Audio(AudioSignal::SpeechStart)));
+    let screen = inputs.iter().any(|i| matches!(i.content,
+        InputContent::Visual(crate::kernel::event::VisualSignal::PerceptUpdate { distance, .. }) if distance >= VISUAL_CANCEL_DISTANCE));
+    if speech {
+        InterruptionSource::AudioSpeechStart
+    } else if screen {
+        InterruptionSource::VisualContextShift
+    } else {
+        InterruptionSource::ExplicitCancel
+    }
+}
+
+/// PURE FUNCTION: The cause in the user's words.
+pub fn describe_source(source: InterruptionSource) -> &'static str {
+    match source {
+        InterruptionSource::AudioSpeechStart => "you started speaking",
+        InterruptionSource::VisualContextShift => "your screen changed a lot",
+        InterruptionSource::ExplicitCancel => "you asked me to stop",
+        InterruptionSource::NewIntentConflict => "a newer request took over",
+    }
+}
+
+/// PURE FUNCTION: The last `limit` interruptions, newest first.
+pub fn interruption_reports(state: &SharedState, now: Tick, limit: usize) -> Vec<InterruptionReport> {
+    state.interruptions().iter().rev().take(limit).map(|record| InterruptionReport {
+        output_id: record.output_id,
+        source: record.source,
+        cause: describe_source(record.source).to_string(),
+        spoken_chars: record.spoken_chars,
+        total_chars: record.total_chars,
+        age_ticks: now.frame.saturating_sub(record.tick.frame),
+    }).collect()
+}
 
 #[derive(Debug, Default)]
 pub struct CancellationRegistry {
@@ -27,7 +101,7 @@ impl CancellationRegistry {
                 InputContent::Visual(crate::kernel::event::VisualSignal::PerceptUpdate { distance, .. }) => {
                     // Threshold logic. If distance > threshold (e.g. 5 bits), cancel.
                     // This is "Hard Interruption" by context shift.
-                    *distance >= 5
+                    *distance >= VISUAL_CANCEL_DISTANCE
                 }
                 _ => false,
             };
@@ -0,0 +1,72 @@
+//! Conversation Contexts ("work", "personal", ...).
+//!
+//! One kernel, several concurrent conversations. Each context owns its own
+//! intent state, long-horizon intents and memory. Only one is active at a time;
+//! the others are parked untouched (no decay, no retrieval) until switched back.
+//!
+//! Switching is a SystemControl intent: "switch to work context".
+
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_CONTEXT: &str = "default";
+
+// Words that can sit between the verb and the context name ("switch to my work context")
+const FILLER_WORDS: &[&str] = &["to", "the", "my", "our", "a", "into", "back"];
+const SWITCH_VERBS: &[&str] = &["switch", "change", "go", "move", "enter", "use"];
+/// Contexts known by name: "work mode" or a bare "switch to work" mean them. Any other name
+/// needs the word "context" ("use dark mode" is not a switch).
+pub const KNOWN_CONTEXTS: &[&str] = &[DEFAULT_CONTEXT, "work", "personal"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ContextId(pub String);
+
+impl ContextId {
+    pub fn new(name: &str) -> Self {
+        Self(name.trim().to_lowercase())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.0 == DEFAULT_CONTEXT
+    }
+}
+
+impl Default for ContextId {
+    fn default() -> Self {
+        Self(DEFAULT_CONTEXT.to_string())
+    }
+}
+
+impl std::fmt::Display for ContextId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Detect a context switch command. Returns the target context.
+///
+/// Grammar: `<verb> [to|the|my|...]* <name> context`, e.g. "switch to work context",
+/// "go back to my personal context". For `KNOWN_CONTEXTS` also `<verb> ... <name> mode`
+/// ("enter work mode") and, as the whole utterance, `switch [to ...] <name>` ("switch to work").
+pub fn parse_switch(text: &str) -> Option<ContextId> {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    let verb = words.iter().position(|w| SWITCH_VERBS.contains(w))?;
+    let rest = &words[verb + 1..];
+    let name_at = rest.iter().position(|w| !FILLER_WORDS.contains(w))?;
+    let name = rest[name_at];
+    let known = KNOWN_CONTEXTS.contains(&name);
+    match rest.get(name_at + 1) {
+        Some(&"context") if name != "context" && name != "mode" => Some(ContextId::new(name)),
+        Some(&"mode") if known => Some(ContextId::new(name)),
+        None if known && verb == 0 && words[0] == "switch" => Some(ContextId::new(name)),
+        _ => None,
+    }
+}
@@ -1,7 +1,8 @@
 use crate::kernel::state::SharedState;
 use crate::kernel::time::Tick;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CrystallizationDecision {
     Deny,
     Delay { ms: u64 },
@@ -23,12 +24,49 @@ pub struct SymbolicSnapshot {
     pub timestamp: Tick,
 }
 
+/// Which gate rule produced the decision (Decision Trace).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GateRule {
+    UserSpeaking,
+    QuietHours,
+    /// A memory consent prompt is open
+    ConsentPending,
+    /// Proactive speech switched off in settings
+    SubsystemDisabled,
+    DenyThreshold,
+    DelayThreshold,
+    PartialThreshold,
+    Clear,
+}
+
+/// Gate decision plus the threshold that fired. Numbers only, no content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GateExplanation {
+    pub decision: CrystallizationDecision,
+    pub rule: GateRule,
+    pub uncertainty: f32,
+    pub threshold: f32,
+}
+
 /// PURE FUNCTION: Decides if the system can crystallize thoughts into text.
 /// No side effects.
 pub fn check_gate(state: &SharedState) -> CrystallizationDecision {
+    explain_gate(state).decision
+}
+
+/// PURE FUNCTION: `check_gate` with the reason attached.
+pub fn explain_gate(state: &SharedState) -> GateExplanation {
+    // 2. Soft Latents (Uncertainty)
+    let uncertainty = state.latents().global_uncertainty();
+
     // 1. Hard Constraints
-    if state.user_speaking {
-        return CrystallizationDecision::Deny;
+    if state.user_speaking() {
+        return GateExplanation {
+            decision: CrystallizationDecision::Deny,
+            rule: GateRule::UserSpeaking,
+            uncertainty,
+            threshold: 0.0,
+        };
     }
     
     // Check if any task was recently canceled (simple heuristic)
@@ -41,33 +79,71 @@ pub fn check_gate(state: &SharedState) -> CrystallizationDecision {
         // Let's rely on `turn_pressure` or similar.
     }
     
-    // 2. Soft Latents (Uncertainty)
-    let uncertainty = state.latents.global_uncertainty();
-    
     // Thresholds (Biased by Meta-Latents)
     // Penalty reduces tolerance for uncertainty.
     let base_deny = 0.8;
-    let penalty = state.meta_latents.confidence_penalty;
+    let penalty = state.meta_latents().confidence_penalty;
     let effective_deny_threshold = base_deny - (penalty * 0.3); // max shift 0.3 (0.8 -> 0.5)
     
+    const DELAY_THRESHOLD: f32 = 0.6;
     const PARTIAL_THRESHOLD: f32 = 0.4;
     
-    if uncertainty > effective_deny_threshold {
-        return CrystallizationDecision::Deny; // Too confused (or penalized)
+    let (decision, rule, threshold) = if uncertainty > effective_deny_threshold {
+        // Too confused (or penalized)
+        (CrystallizationDecision::Deny, GateRule::DenyThreshold, effective_deny_threshold)
+    } else if uncertainty > DELAY_THRESHOLD {
+        // If somewhat uncertain, delay.
+        (CrystallizationDecision::Delay { ms: 500 }, GateRule::DelayThreshold, DELAY_THRESHOLD)
+    } else if uncertainty > PARTIAL_THRESHOLD {
+        (CrystallizationDecision::AllowPartial, GateRule::PartialThreshold, PARTIAL_THRESHOLD)
+    } else {
+        (CrystallizationDecision::AllowHard, GateRule::Clear, PARTIAL_THRESHOLD)
+    };
+
+    GateExplanation { decision, rule, uncertainty, threshold }
+}
+
+/// PURE FUNCTION: `explain_gate` for output nobody asked for. Denied during quiet hours,
+/// while proactive speech is switched off and while a consent prompt waits for an answer.
+pub fn explain_proactive_gate(state: &SharedState) -> GateExplanation {
+    if !state.subsystems().proactive_speech {
+        return GateExplanation {
+            decision: CrystallizationDecision::Deny,
+            rule: GateRule::SubsystemDisabled,
+            uncertainty: state.latents().global_uncertainty(),
+            threshold: 0.0,
+        };
     }
-    
-    // If somewhat uncertain, delay? 
-    // Or allow partial.
-    // Let's say if > 0.6, Delay.
-    if uncertainty > 0.6 {
-        return CrystallizationDecision::Delay { ms: 500 };
+    if state.quiet_hours() == crate::kernel::quiet::QuietHoursState::Active {
+        return GateExplanation {
+            decision: CrystallizationDecision::Deny,
+            rule: GateRule::QuietHours,
+            uncertainty: state.latents().global_uncertainty(),
+            threshold: 0.0,
+        };
     }
-    
-    if uncertainty > PARTIAL_THRESHOLD {
-        return CrystallizationDecision::AllowPartial;
+    // Don't talk over the question the user is deciding on
+    if state.consent_pending(state.last_tick()).is_some() {
+        return GateExplanation {
+            decision: CrystallizationDecision::Deny,
+            rule: GateRule::ConsentPending,
+            uncertainty: state.latents().global_uncertainty(),
+            threshold: 0.0,
+        };
+    }
+    explain_gate(state)
+}
+
+/// PURE FUNCTION: What the gate would decide for a planned response right now (dry run).
+/// A reply to the user goes through `explain_gate`, anything else through `explain_proactive_gate`,
+/// as when the plan is accepted. Planners read it as `StateSnapshot::gate_preview` to pick
+/// Delay or DoNothing over a response the gate would hold back.
+pub fn preview_gate(state: &SharedState) -> GateExplanation {
+    if crate::kernel::quiet::is_direct_reply(state) {
+        explain_gate(state)
+    } else {
+        explain_proactive_gate(state)
     }
-    
-    CrystallizationDecision::AllowHard
 }
 
 /// Deterministic extraction of claims from state
@@ -78,7 +154,7 @@ pub fn extract_snapshot(state: &SharedState) -> SymbolicSnapshot {
     let mut claims = Vec::new();
     
     // Scan Latents for clusters
-    for slot in &state.latents.slots {
+    for slot in &state.latents().slots {
         use crate::kernel::latent::Modality;
         match slot.modality {
             Modality::Visual => {
@@ -114,7 +190,7 @@ pub fn extract_snapshot(state: &SharedState) -> SymbolicSnapshot {
     
     SymbolicSnapshot {
         claims,
-        base_uncertainty: state.latents.global_uncertainty(),
-        timestamp: state.last_tick,
+        base_uncertainty: state.latents().global_uncertainty(),
+        timestamp: state.last_tick(),
     }
 }
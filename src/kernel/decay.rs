@@ -0,0 +1,71 @@
+//! Decay Math.
+//!
+//! Everything in the kernel that fades with time goes through here: long-horizon intent scores,
+//! long-term memory strength, latent confidence, the monitor's meta-latents, episodic entries
+//! and retrieval recency. Elapsed time is a tick count, and a driver waking from a long idle
+//! wait (`Reactor::advance_idle`) or a hydrated state can make it arbitrarily large.
+//!
+//! Every function here is total: for any input, including NaN, infinities, negative rates and
+//! `u64::MAX` ticks, the result is finite, never negative and never above the value it started
+//! from (decay does not grow a value). Results too small to matter are flushed to 0.0 rather
+//! than left as subnormals. Work is done in `f64`, so long elapsed times keep their precision.
+
+// Config Constants
+/// Results below this are 0.0 (a decayed value is never worth keeping as a subnormal).
+pub const FLUSH_TO_ZERO: f32 = 1e-12;
+
+// A value decay may start from: finite and not negative
+fn sanitize(value: f32) -> f64 {
+    if value.is_finite() {
+        value.max(0.0) as f64
+    } else if value == f32::INFINITY {
+        f32::MAX as f64
+    } else {
+        0.0
+    }
+}
+
+fn finish(value: f64, start: f64) -> f32 {
+    let value = value.clamp(0.0, start) as f32;
+    if value < FLUSH_TO_ZERO { 0.0 } else { value }
+}
+
+/// PURE FUNCTION: `value` after `elapsed` ticks of exponential decay: halved every
+/// `half_life_ticks`. A half-life of zero (or less, or NaN) decays at once; an infinite one
+/// never decays.
+pub fn exponential(value: f32, elapsed: u64, half_life_ticks: f32) -> f32 {
+    let start = sanitize(value);
+    if elapsed == 0 || half_life_ticks == f32::INFINITY {
+        return finish(start, start);
+    }
+    if half_life_ticks.is_nan() || half_life_ticks <= 0.0 {
+        return 0.0;
+    }
+    let halvings = elapsed as f64 / half_life_ticks as f64;
+    finish(start * 0.5f64.powf(halvings), start)
+}
+
+/// PURE FUNCTION: `value` after `elapsed` ticks of linear decay, `per_tick` at a time, floored
+/// at 0.0. A negative or NaN rate leaves the value as it is.
+pub fn linear(value: f32, elapsed: u64, per_tick: f32) -> f32 {
+    let start = sanitize(value);
+    let rate = if per_tick.is_nan() { 0.0 } else { per_tick.max(0.0) as f64 };
+    finish(start - rate * elapsed as f64, start)
+}
+
+/// PURE FUNCTION: The half-life (ticks) of a per-tick retention factor (0.9997 -> ~2310).
+/// A factor of 1.0 or more never decays (infinite); 0.0 or less (or NaN) decays at once (0.0).
+pub fn half_life(factor_per_tick: f32) -> f32 {
+    if factor_per_tick.is_nan() || factor_per_tick <= 0.0 {
+        return 0.0;
+    }
+    if factor_per_tick >= 1.0 {
+        return f32::INFINITY;
+    }
+    (0.5f64.ln() / (factor_per_tick as f64).ln()) as f32
+}
+
+/// PURE FUNCTION: `value` clamped to [0.0, 1.0]; NaN is 0.0.
+pub fn unit(value: f32) -> f32 {
+    if value.is_nan() { 0.0 } else { value.clamp(0.0, 1.0) }
+}
@@ -0,0 +1,103 @@
+//! Diagnostics: typed, signal-worthy conditions emitted as `SideEffect::Diagnostic`.
+//!
+//! Replaces stringly `SideEffect::Log`. Each condition has a stable `DiagnosticCode`
+//! (the string form never changes once shipped, so UIs and log filters can key on it)
+//! and a default `Severity`. Drivers map severity to a tracing level and surface
+//! `Error`s as UI toasts.
+//!
+//! `context` carries identifiers and numbers only, never user content.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Severity {
+    Debug,
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DiagnosticCode {
+    /// Planner chose to wait (`Intent::Delay`).
+    PlanDelayed,
+    /// Transcription requested for a segment that is not pending.
+    TranscriptionDenied,
+    /// A state domain crossed its footprint pressure ratio.
+    MemoryPressure,
+    /// An action tool (e.g. home automation) reported failure.
+    ToolFailed,
+    /// The OS denies (or restricts) microphone access; the kernel cannot listen.
+    CaptureBlocked,
+    /// Playback moved to another output device; speech in progress was stopped.
+    OutputRouteChanged,
+    /// A consent answer did not match the outstanding prompt (id, version, deadline); ignored.
+    ConsentRejected,
+    /// An action tool ran past its sandbox deadline and was cancelled.
+    ToolTimedOut,
+    /// An action tool was refused: the sandbox's concurrency limit was reached.
+    ToolBusy,
+    /// An action tool was reported, not run (dry run, onboarding or safe mode).
+    ToolDryRun,
+    /// Events were lost to a full kernel event queue (`kernel::queue`).
+    EventsDropped,
+    /// A manual memory candidate action was refused (unknown candidate, declined key, memory off).
+    MemoryActionRefused,
+    /// The audio capture actor was lost (thread panic, stream stopped) and restarted.
+    CaptureRestarted,
+}
+
+impl DiagnosticCode {
+    /// Stable identifier (log filters, UI).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiagnosticCode::PlanDelayed => "NX-PLAN-001",
+            DiagnosticCode::TranscriptionDenied => "NX-ASR-001",
+            DiagnosticCode::MemoryPressure => "NX-MEM-001",
+            DiagnosticCode::ToolFailed => "NX-TOOL-001",
+            DiagnosticCode::CaptureBlocked => "NX-CAP-001",
+            DiagnosticCode::OutputRouteChanged => "NX-OUT-001",
+            DiagnosticCode::ConsentRejected => "NX-MEM-002",
+            DiagnosticCode::ToolTimedOut => "NX-TOOL-002",
+            DiagnosticCode::ToolBusy => "NX-TOOL-003",
+            DiagnosticCode::ToolDryRun => "NX-TOOL-004",
+            DiagnosticCode::EventsDropped => "NX-QUEUE-001",
+            DiagnosticCode::MemoryActionRefused => "NX-MEM-003",
+            DiagnosticCode::CaptureRestarted => "NX-CAP-002",
+        }
+    }
+
+    pub fn severity(&self) -> Severity {
+        match self {
+            DiagnosticCode::PlanDelayed => Severity::Debug,
+            DiagnosticCode::OutputRouteChanged | DiagnosticCode::ToolDryRun => Severity::Info,
+            DiagnosticCode::TranscriptionDenied
+            | DiagnosticCode::MemoryPressure
+            | DiagnosticCode::CaptureBlocked
+            | DiagnosticCode::ConsentRejected
+            | DiagnosticCode::ToolTimedOut
+            | DiagnosticCode::ToolBusy
+            | DiagnosticCode::EventsDropped
+            | DiagnosticCode::MemoryActionRefused
+            | DiagnosticCode::CaptureRestarted => Severity::Warning,
+            DiagnosticCode::ToolFailed => Severity::Error,
+        }
+    }
+}
+
+impl Severity {
+    /// Drivers with a UI show these as toasts.
+    pub fn toast(&self) -> bool {
+        *self >= Severity::Error
+    }
+}
+
+/// Emit a diagnostic through `tracing` at its severity's level.
+pub fn trace(severity: Severity, code: DiagnosticCode, context: &str) {
+    match severity {
+        Severity::Debug => tracing::debug!(code = code.as_str(), "{}", context),
+        Severity::Info => tracing::info!(code = code.as_str(), "{}", context),
+        Severity::Warning => tracing::warn!(code = code.as_str(), "{}", context),
+        Severity::Error => tracing::error!(code = code.as_str(), "{}", context),
+    }
+}
@@ -0,0 +1,100 @@
+//! Dialogue Log.
+//!
+//! Bounded ring of the most recent user and system turns, kept for observation tools
+//! (the remote dashboard). Each turn is classified for sensitivity when it is recorded,
+//! and readers only ever get a redacted copy.
+//!
+//! Invariant: the log never drives arbitration, planning or memory. Its one reader in the
+//! kernel is an explicit bookmark ("remember this point"): it copies a redacted excerpt, which
+//! names the bookmark, matches it when recalled and is quoted back (`kernel::bookmark`).
+
+use std::collections::VecDeque;
+use serde::Serialize;
+
+use crate::kernel::memory::sensitivity::{classify, RedactionLevel, SensitivityClass};
+use crate::kernel::time::Tick;
+
+const DEFAULT_CAPACITY: usize = 50;
+const REDACTED: &str = "[redacted]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Speaker {
+    User,
+    System,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DialogueTurn {
+    pub tick: Tick,
+    pub speaker: Speaker,
+    pub text: String,
+    pub sensitivity: Option<SensitivityClass>,
+}
+
+impl DialogueTurn {
+    /// Observer view of this turn at `level`. `None` when the turn must not be shown.
+    pub fn redacted(&self, level: RedactionLevel) -> Option<DialogueTurn> {
+        if self.sensitivity.is_none() {
+            return Some(self.clone());
+        }
+        match level {
+            RedactionLevel::None => Some(self.clone()),
+            RedactionLevel::Omit => None,
+            RedactionLevel::Mask => Some(DialogueTurn { text: REDACTED.to_string(), ..self.clone() }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DialogueLog {
+    capacity: usize,
+    turns: VecDeque<DialogueTurn>,
+}
+
+impl DialogueLog {
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            turns: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn record(&mut self, tick: Tick, speaker: Speaker, text: &str) {
+        if text.trim().is_empty() || self.capacity == 0 {
+            return;
+        }
+        if self.turns.len() >= self.capacity {
+            self.turns.pop_front();
+        }
+        self.turns.push_back(DialogueTurn {
+            tick,
+            speaker,
+            text: text.to_string(),
+            sensitivity: classify(text),
+        });
+    }
+
+    /// Last `n` turns (oldest first), redacted at `level`. Omitted turns still count toward `n`.
+    pub fn recent(&self, n: usize, level: RedactionLevel) -> Vec<DialogueTurn> {
+        let skip = self.turns.len().saturating_sub(n);
+        self.turns.iter().skip(skip).filter_map(|t| t.redacted(level)).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.turns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.turns.is_empty()
+    }
+}
+
+impl Default for DialogueLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
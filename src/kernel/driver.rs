@@ -0,0 +1,724 @@
+//! Driver Effect Executor.
+//!
+//! The kernel only returns `SideEffect`s; drivers run them. `EffectExecutor` owns what that
+//! takes: the playing audio child, the sentence queue of a streamed reply (or the chunk queue
+//! of a chunked output), the speech generation tasks and their dedupe map, the transcription
+//! queue and ASR backend, the TTS cache, the home drivers and the store writer. The CLI driver,
+//! `Reactor::run` and the Tauri shell share it, so a new side effect is implemented here once.
+//!
+//! Per tick: `execute()` every effect of the batch with an `EffectContext` taken from the
+//! reactor, then `poll()` (chunk queue closed, generated sentences -> playback) and `pump()`
+//! (queued transcriptions).
+//! What a driver shows differently (console lines vs UI events) goes through `EffectHooks`.
+//!
+//! Not part of the kernel's pure step: this is where processes are spawned and results are
+//! sent back as `Event::Input` (source "Driver").
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::audio::transcription::{QueueDepth, TranscriptionQueue};
+use crate::kernel::backchannel::Cue;
+use crate::kernel::diagnostic::{DiagnosticCode, Severity};
+use crate::kernel::event::{AudioStatus, CaptureFidelity, Event, InputContent, InputEvent, OutputId};
+use crate::kernel::maintenance::{JobOutcome, JobResult, MaintenanceProgress};
+use crate::kernel::protocol::{ConsentRequest, ConsentResponse};
+use crate::kernel::queue::DropCounter;
+use crate::kernel::reactor::Reactor;
+use crate::kernel::scheduler::SideEffect;
+use crate::kernel::telemetry::event::{SpeechLifecycleEvent, TelemetryEvent};
+use crate::kernel::telemetry::recorder::TelemetryHandle;
+use crate::outputs::captions::Caption;
+use crate::outputs::safety::SafetyConfig;
+use crate::outputs::style::{OutputChannel, OutputStyle};
+use crate::outputs::tts_cache::{TtsCache, TtsCacheLimits};
+
+// Config Constants
+/// A repeated `RequestSpeech` for the same output within this window is ignored.
+pub const SPEECH_DEDUPE_TTL: Duration = Duration::from_secs(10);
+
+/// What a driver shows for effects it does not run itself. The defaults print to the console
+/// (CLI driver, `Reactor::run`); the shell emits UI events instead.
+pub trait EffectHooks: Send {
+    /// An output is about to play (or show, in caption-only mode)
+    fn caption(&self, caption: &Caption) {
+        println!("[CAPTION] {}", serde_json::to_string(caption).unwrap_or_default());
+    }
+
+    /// After the diagnostic was traced
+    fn diagnostic(&self, _severity: Severity, _code: DiagnosticCode) {}
+
+    fn maintenance(&self, progress: &MaintenanceProgress) {
+        println!("[MAINTENANCE] {}/{} {:?}", progress.completed, progress.total, progress.last);
+    }
+
+    /// Push-mode capture only
+    fn capture_fidelity(&self, fidelity: CaptureFidelity) {
+        debug!("[CAPTURE] Fidelity -> {:?}", fidelity);
+    }
+
+    fn ask_consent(&self, request: &ConsentRequest) {
+        println!("[CONSENT] Asking user for key: {:?} (prompt {})", request.key, request.prompt_id);
+    }
+
+    fn dismiss_consent(&self, response: &ConsentResponse) {
+        println!("[CONSENT] Answered by voice: {:?} (prompt {})", response.state, response.prompt_id);
+    }
+
+    /// Before the cue plays (not played in caption-only mode)
+    fn cue(&self, cue: Cue) {
+        println!("[CUE] {}", cue.sound());
+    }
+}
+
+/// Console output only.
+pub struct ConsoleHooks;
+
+impl EffectHooks for ConsoleHooks {}
+
+/// Reactor state the effects of one tick need, taken while the reactor is at hand (the shell
+/// runs effects outside its lock).
+#[derive(Debug, Clone)]
+pub struct EffectContext {
+    /// Generated speech: safety filter, style and channel of the active profile
+    pub filter: SafetyConfig,
+    pub style: OutputStyle,
+    pub channel: OutputChannel,
+    /// Output device (`None` = system default)
+    pub device: Option<String>,
+    /// Playback volume of the active profile (`outputs::loudness`)
+    pub volume: u8,
+    /// Reply language (speech normalization, generated speech), and the pinned transcription
+    /// language (`None`: detect), see `kernel::language`
+    pub language: String,
+    pub transcription_language: Option<String>,
+    /// Frames of the segments a `RequestTranscription` asks for
+    pub segments: HashMap<String, Vec<f32>>,
+}
+
+impl EffectContext {
+    pub fn capture(reactor: &Reactor, effects: &[SideEffect]) -> Self {
+        let segments = effects.iter()
+            .filter_map(|effect| match effect {
+                SideEffect::RequestTranscription { segment_id } => reactor.state.audio_segments().get(segment_id)
+                    .map(|seg| (segment_id.clone(), seg.frames.clone())),
+                _ => None,
+            })
+            .collect();
+        Self {
+            filter: reactor.output_filter(),
+            style: reactor.output_style(),
+            channel: reactor.output_channel(),
+            device: reactor.state.output_device().cloned(),
+            volume: reactor.output_volume(),
+            language: reactor.reply_language(),
+            transcription_language: reactor.state.language_preference().pinned().map(str::to_string),
+            segments,
+        }
+    }
+}
+
+// A blocking store write-back, with what it writes (for the log)
+type WriteJob = (&'static str, Box<dyn FnOnce() -> anyhow::Result<()> + Send>);
+
+// Store write-backs (`Persist*` effects): one writer thread, so writes land in emission order
+// and never block the tick or the async runtime
+struct FileWriter {
+    jobs: std::sync::mpsc::Sender<WriteJob>,
+}
+
+impl FileWriter {
+    fn spawn() -> Self {
+        let (jobs, queue) = std::sync::mpsc::channel::<WriteJob>();
+        let spawned = std::thread::Builder::new().name("nexus-persist".to_string()).spawn(move || {
+            // Ends when the executor (the sender) is dropped. A write that panics is logged; later writes still run.
+            for (what, write) in queue {
+                match std::panic::catch_unwind(std::panic::AssertUnwindSafe(write)) {
+                    Ok(Ok(())) => {}
+                    Ok(Err(e)) => warn!("Failed to save {}: {}", what, e),
+                    Err(_) => warn!("Saving {} panicked", what),
+                }
+            }
+        });
+        if let Err(e) = spawned {
+            warn!("Failed to start the store writer: {}", e);
+        }
+        Self { jobs }
+    }
+
+    fn write(&self, what: &'static str, write: impl FnOnce() -> anyhow::Result<()> + Send + 'static) {
+        if self.jobs.send((what, Box::new(write))).is_err() {
+            warn!("Store writer gone: {} not saved", what);
+        }
+    }
+}
+
+// Results of speech generation tasks (never reach the kernel)
+#[cfg_attr(not(feature = "llm"), allow(dead_code))]
+enum Generated {
+    // One streamed sentence, screened and restyled
+    Sentence { output_id: Uuid, text: String },
+    Done { output_id: Uuid },
+    Failed { output_id: Uuid },
+}
+
+pub struct EffectExecutor {
+    tx: mpsc::Sender<Event>,
+    drops: DropCounter,
+    telemetry: TelemetryHandle,
+    hooks: Box<dyn EffectHooks>,
+    caption_only: bool,
+    // Synthesized audio for repeated phrases
+    tts_cache: Arc<Mutex<TtsCache>>,
+    audio_child: Option<oneshot::Sender<()>>,
+    // Sentence queue of the output playing now (closed when its generation is done)
+    utterance: Option<(Uuid, mpsc::UnboundedSender<tokio::process::Command>)>,
+    // Chunk queue of the kernel output playing now (closed at the end of its batch)
+    chunks: Option<(OutputId, mpsc::UnboundedSender<(OutputId, tokio::process::Command)>)>,
+    speech_tasks: HashMap<Uuid, JoinHandle<()>>,
+    speech_dedupe: HashMap<Uuid, Instant>,
+    #[cfg_attr(not(feature = "llm"), allow(dead_code))]
+    generated_tx: mpsc::Sender<Generated>,
+    generated_rx: mpsc::Receiver<Generated>,
+    transcriptions: TranscriptionQueue,
+    last_depth: QueueDepth,
+    files: FileWriter,
+    #[cfg(feature = "asr")]
+    asr: Arc<dyn crate::audio::asr::AsrBackend>,
+    #[cfg(feature = "asr")]
+    sample_rate: u32,
+    // Pinned transcription language of the last batch (`None`: detect)
+    #[cfg(feature = "asr")]
+    asr_language: Option<String>,
+    // Wake phrases spotted in transcripts (`NEXUS_WAKE_WORDS`)
+    #[cfg(feature = "asr")]
+    wake_words: Arc<crate::audio::wake::WakeWordDetector>,
+    // Auto mic gate (shell): while it requires the wake word, other transcripts are dropped
+    #[cfg(feature = "asr")]
+    mic: Option<crate::audio::mic::MicGate>,
+    #[cfg(feature = "llm")]
+    llm: Option<crate::services::llm::client::LLMService>,
+    #[cfg(feature = "home")]
+    home: Arc<crate::integrations::home_driver::HomeDriver>,
+}
+
+impl EffectExecutor {
+    /// Results go to the reactor's channel. `driver_capacity` bounds the generated-speech
+    /// channel (`QueueConfig::driver_capacity`). No speech service: see `with_llm`.
+    pub fn new(reactor: &Reactor, driver_capacity: usize) -> Self {
+        let tts_cache = TtsCache::new(TtsCacheLimits::default())
+            .with_locale(reactor.templates.locale())
+            .with_engine(reactor.config.tts.engine());
+        let transcriptions = TranscriptionQueue::new(reactor.config.transcription_concurrency);
+        let (generated_tx, generated_rx) = mpsc::channel(driver_capacity.max(1));
+        #[cfg(feature = "asr")]
+        let asr = crate::audio::asr::from_env();
+        #[cfg(feature = "asr")]
+        info!("[TRANSCRIPTION] ASR backend: {}", asr.name());
+        Self {
+            tx: reactor._tx_clone.clone(),
+            drops: reactor.queue.drops(),
+            telemetry: reactor.telemetry.handle(),
+            hooks: Box::new(ConsoleHooks),
+            caption_only: reactor.config.caption_only,
+            tts_cache: Arc::new(Mutex::new(tts_cache)),
+            audio_child: None,
+            utterance: None,
+            chunks: None,
+            speech_tasks: HashMap::new(),
+            speech_dedupe: HashMap::new(),
+            generated_tx,
+            generated_rx,
+            last_depth: transcriptions.depth(),
+            transcriptions,
+            files: FileWriter::spawn(),
+            #[cfg(feature = "asr")]
+            asr,
+            #[cfg(feature = "asr")]
+            sample_rate: reactor.audio_monitor.sample_rate(),
+            #[cfg(feature = "asr")]
+            asr_language: reactor.state.language_preference().pinned().map(str::to_string),
+            #[cfg(feature = "asr")]
+            wake_words: Arc::new(crate::audio::wake::WakeWordDetector::from_env()),
+            #[cfg(feature = "asr")]
+            mic: None,
+            #[cfg(feature = "llm")]
+            llm: None,
+            #[cfg(feature = "home")]
+            home: Arc::new(crate::integrations::home_driver::HomeDriver::new(reactor.home.mapping())),
+        }
+    }
+
+    pub fn with_hooks(mut self, hooks: impl EffectHooks + 'static) -> Self {
+        self.hooks = Box::new(hooks);
+        self
+    }
+
+    /// Generated speech (`RequestSpeech`) and exchange summaries from the local LLM. Without
+    /// it, `RequestSpeech` is ignored and summaries fall back to the template line.
+    #[cfg(feature = "llm")]
+    pub fn with_llm(mut self, service: crate::services::llm::client::LLMService) -> Self {
+        self.llm = Some(service);
+        self
+    }
+
+    /// The auto mic gate: while Dormant, only transcripts addressed by wake word reach the kernel.
+    #[cfg(feature = "asr")]
+    pub fn with_mic_gate(mut self, gate: crate::audio::mic::MicGate) -> Self {
+        self.mic = Some(gate);
+        self
+    }
+
+    /// The TTS cache (pre-warm it with `tts_cache::prewarm`).
+    pub fn tts_cache(&self) -> Arc<Mutex<TtsCache>> {
+        self.tts_cache.clone()
+    }
+
+    /// An output is playing (or its caption-only timer running).
+    pub fn is_playing(&self) -> bool {
+        self.audio_child.as_ref().is_some_and(|stop_tx| !stop_tx.is_closed())
+    }
+
+    /// Nothing the driver has to poll for: no output playing, no speech being generated, no
+    /// transcription waiting to start (`kernel::cadence` may slow down).
+    pub fn is_idle(&self) -> bool {
+        !self.is_playing() && self.speech_tasks.is_empty() && self.transcriptions.depth().queued == 0
+    }
+
+    pub fn transcription_depth(&self) -> QueueDepth {
+        self.transcriptions.depth()
+    }
+
+    /// After each batch. Closes the chunk queue of the batch's output (it ends after its last
+    /// chunk), then plays generated sentences since the last poll: the first one starts its
+    /// output, later ones join its queue.
+    pub fn poll(&mut self, context: &EffectContext) {
+        self.chunks = None;
+        while let Ok(generated) = self.generated_rx.try_recv() {
+            match generated {
+                Generated::Sentence { output_id, text } => self.play_sentence(output_id, text, context),
+                Generated::Done { output_id } => {
+                    if self.speech_tasks.remove(&output_id).is_some() {
+                        self.telemetry.record(TelemetryEvent::SpeechLifecycle(SpeechLifecycleEvent::Generated));
+                    }
+                    // Close the queue: playback ends after the last sentence
+                    if self.utterance.as_ref().is_some_and(|(id, _)| *id == output_id) {
+                        self.utterance = None;
+                    }
+                }
+                Generated::Failed { output_id } => {
+                    self.speech_tasks.remove(&output_id);
+                    if self.utterance.as_ref().is_some_and(|(id, _)| *id == output_id) {
+                        self.utterance = None;
+                    }
+                    self.telemetry.record(TelemetryEvent::SpeechLifecycle(SpeechLifecycleEvent::Failed));
+                }
+            }
+        }
+    }
+
+    /// Run one effect. Batches are phase-ordered: execute them front to back.
+    pub fn execute(&mut self, effect: SideEffect, context: &EffectContext) {
+        match effect {
+            #[allow(deprecated)]
+            SideEffect::Log(msg) => info!("[LOG] {}", msg),
+            SideEffect::Diagnostic { severity, code, context } => {
+                crate::kernel::diagnostic::trace(severity, code, &context);
+                self.hooks.diagnostic(severity, code);
+            }
+            SideEffect::MaintenanceProgress(progress) => self.hooks.maintenance(&progress),
+            SideEffect::RunMaintenance(task) => {
+                // Behind pending store writes; the result goes back to the kernel (a panic is a failed job)
+                let tx = self.tx.clone();
+                self.files.write("maintenance result", move || {
+                    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| task.run()))
+                        .unwrap_or(JobResult { job: task.job, outcome: JobOutcome::Failed, items: 0 });
+                    tx.blocking_send(driver_input(InputContent::MaintenanceResult(result)))
+                        .map_err(|_| anyhow::anyhow!("kernel channel closed"))
+                });
+            }
+            SideEffect::PersistCalibration(snapshot) => self.files.write("audio calibration", move || snapshot.save()),
+            SideEffect::PersistCorrections(snapshot) => self.files.write("correction ledger", move || snapshot.save()),
+            SideEffect::PersistProfiles(snapshot) => self.files.write("profiles", move || snapshot.save()),
+            SideEffect::ExportTelemetry(export) => {
+                if let Err(e) = export.push() {
+                    warn!("Telemetry export at tick {} not pushed: {:?}", export.tick.frame, e);
+                }
+                self.files.write("telemetry snapshot", move || export.append().map_err(|e| anyhow::anyhow!("{:?}", e)));
+            }
+            SideEffect::SpawnAudio(id, text) => self.spawn_audio(id, text, context),
+            SideEffect::StopAudio => {
+                if let Some(stop_tx) = self.audio_child.take() {
+                    println!("[AUDIO] KILL SWITCH ACTIVATED.");
+                    let _ = stop_tx.send(());
+                }
+                // Aborting generation drops the LLM stream; queued chunks never play
+                self.utterance = None;
+                self.chunks = None;
+                for (_, task) in self.speech_tasks.drain() {
+                    task.abort();
+                }
+                self.telemetry.record(TelemetryEvent::SpeechLifecycle(SpeechLifecycleEvent::Aborted));
+            }
+            SideEffect::RequestTranscription { segment_id } => {
+                info!("[TRANSCRIPTION] Requested for Segment: {}", segment_id);
+                #[cfg(feature = "asr")]
+                {
+                    self.asr_language = context.transcription_language.clone();
+                }
+                // Queued, not spawned: the queue bounds concurrent WAV + ASR work
+                match context.segments.get(&segment_id) {
+                    Some(frames) => self.transcriptions.enqueue(segment_id, frames.clone()),
+                    None => warn!("[TRANSCRIPTION] Segment not found in state: {}", segment_id),
+                }
+            }
+            SideEffect::CancelTranscription { segment_id } => {
+                if self.transcriptions.cancel(&segment_id) {
+                    info!("[TRANSCRIPTION] Cancelled for Segment: {}", segment_id);
+                    self.telemetry.record(TelemetryEvent::TranscriptionCancelled);
+                }
+            }
+            SideEffect::SetCaptureFidelity(fidelity) => self.hooks.capture_fidelity(fidelity),
+            SideEffect::AskMemoryConsent(request) => self.hooks.ask_consent(&request),
+            SideEffect::DismissConsent(response) => self.hooks.dismiss_consent(&response),
+            SideEffect::RequestSpeech { intent, output_id } => self.request_speech(intent, output_id, context),
+            SideEffect::RequestSummary { exchange_id, exchange } => {
+                #[cfg(feature = "llm")]
+                if let Some(service) = self.llm.clone() {
+                    let tx = self.tx.clone();
+                    tokio::spawn(async move {
+                        let text = match tokio::time::timeout(Duration::from_secs(2), service.summarize_exchange(&exchange)).await {
+                            Ok(Ok(text)) => Some(text),
+                            _ => None,
+                        };
+                        let _ = tx.send(driver_input(InputContent::ExchangeSummary { exchange_id, text })).await;
+                    });
+                    return;
+                }
+                // No LLM: the kernel falls back to the template line
+                let _ = exchange;
+                self.drops.try_send(&self.tx, driver_input(InputContent::ExchangeSummary { exchange_id, text: None }));
+            }
+            SideEffect::InvokeTool { call_id, call } => {
+                #[cfg(feature = "home")]
+                self.home.spawn(call_id, call, self.tx.clone());
+                #[cfg(not(feature = "home"))]
+                {
+                    warn!("[HOME] Drivers compiled out (feature `home`). Dropping {:?}", call);
+                    self.drops.try_send(&self.tx, crate::integrations::home::tool_result(call_id, false));
+                }
+            }
+            SideEffect::CancelTool { call_id } => {
+                #[cfg(feature = "home")]
+                self.home.cancel(&call_id);
+                #[cfg(not(feature = "home"))]
+                let _ = call_id;
+            }
+            SideEffect::PlayCue(cue) => {
+                // Own channel: not tracked as speech, so StopAudio leaves it alone
+                self.hooks.cue(cue);
+                if !self.caption_only {
+                    let mut playback = {
+                        let mut cache = self.tts_cache.lock().unwrap_or_else(|e| e.into_inner());
+                        cache.set_volume(context.volume);
+                        cache.playback_command(cue.sound(), false, context.device.as_deref())
+                    };
+                    match playback.spawn() {
+                        Ok(mut child) => { tokio::spawn(async move { let _ = child.wait().await; }); }
+                        Err(e) => warn!("Failed to play cue: {}", e),
+                    }
+                }
+            }
+        }
+    }
+
+    /// End of tick: start queued transcriptions (up to the concurrency limit), report the
+    /// queue depth, forget expired speech requests.
+    pub fn pump(&mut self) {
+        #[cfg(feature = "asr")]
+        {
+            let (asr, sample_rate, language, wake, tx) = (&self.asr, self.sample_rate, &self.asr_language, &self.wake_words, &self.tx);
+            let required = self.mic.as_ref().is_some_and(|mic| mic.requires_wake_word());
+            self.transcriptions.pump(|segment_id, frames| {
+                let wake = crate::audio::wake::WakeSpotting { detector: wake.clone(), required };
+                crate::audio::transcription::spawn_transcription(asr.clone(), segment_id, frames, sample_rate, language.clone(), Some(wake), tx.clone())
+            });
+        }
+        let depth = self.transcriptions.depth();
+        if depth != self.last_depth {
+            self.last_depth = depth;
+            self.telemetry.record(TelemetryEvent::TranscriptionQueue { queued: depth.queued, in_flight: depth.in_flight });
+        }
+        self.speech_dedupe.retain(|_, requested_at| requested_at.elapsed() < SPEECH_DEDUPE_TTL);
+    }
+
+    fn spawn_audio(&mut self, id: OutputId, text: String, context: &EffectContext) {
+        if let Some((parent, _)) = crate::outputs::streamer::parent_of(id) {
+            return self.spawn_chunk(parent, id, text, context);
+        }
+        println!("[AUDIO-{:?}] Spawning 'say': '{}'", id, text);
+        self.hooks.caption(&Caption::estimate(id.into(), &text, !self.caption_only));
+        // 1. Kill existing
+        if let Some(stop_tx) = self.audio_child.take() {
+            let _ = stop_tx.send(());
+        }
+        self.utterance = None;
+        self.chunks = None;
+
+        // 2. Spawn new (cached buffer, or silent timer in caption-only mode)
+        #[cfg(feature = "tts")]
+        {
+            let mut playback = {
+                let mut cache = self.tts_cache.lock().unwrap_or_else(|e| e.into_inner());
+                cache.set_locale(&context.language);
+                cache.set_volume(context.volume);
+                cache.playback_command(&text, self.caption_only, context.device.as_deref())
+            };
+            if !self.caption_only {
+                crate::outputs::tts_cache::remember(self.tts_cache.clone(), text.clone());
+            }
+            match playback.kill_on_drop(true).spawn() {
+                Ok(mut child) => {
+                    let tx = self.tx.clone();
+                    let (stop_tx, mut stop_rx) = oneshot::channel();
+                    self.audio_child = Some(stop_tx);
+                    tokio::spawn(async move {
+                        let started_at = Instant::now();
+                        let _ = tx.send(driver_input(InputContent::AudioStatus(AudioStatus::PlaybackStarted { output_id: id.into() }))).await;
+
+                        // Race: Completion vs Kill (+ estimated 50% progress marker)
+                        let halfway = tokio::time::sleep(Duration::from_millis(crate::outputs::playback::halfway_ms(&text)));
+                        tokio::pin!(halfway);
+                        let mut halfway_sent = false;
+                        let completed = loop {
+                            tokio::select! {
+                                res = child.wait() => break res.map(|s| s.success()).unwrap_or(false),
+                                _ = &mut stop_rx => {
+                                    let _ = child.kill().await;
+                                    break false;
+                                }
+                                _ = &mut halfway, if !halfway_sent => {
+                                    halfway_sent = true;
+                                    let _ = tx.send(driver_input(InputContent::AudioStatus(AudioStatus::PlaybackProgress { output_id: id, percent: 50 }))).await;
+                                }
+                            }
+                        };
+
+                        // Completed on a natural finish only (sets committed_at); Ended always
+                        if completed {
+                            let _ = tx.send(driver_input(InputContent::AudioStatus(AudioStatus::PlaybackCompleted { output_id: id }))).await;
+                        }
+                        let elapsed_ms = started_at.elapsed().as_millis() as u64;
+                        let _ = tx.send(driver_input(InputContent::AudioStatus(AudioStatus::PlaybackEnded { output_id: id.into(), elapsed_ms }))).await;
+                    });
+                }
+                Err(e) => warn!("Failed to spawn audio: {}", e),
+            }
+        }
+
+        // TTS compiled out: text-only output. Report the full lifecycle
+        // immediately so the kernel commits and clears the output.
+        #[cfg(not(feature = "tts"))]
+        {
+            let _ = context;
+            println!("[OUTPUT-{:?}] {}", id, text);
+            let tx = self.tx.clone();
+            tokio::spawn(async move {
+                let statuses = [
+                    AudioStatus::PlaybackStarted { output_id: id.into() },
+                    AudioStatus::PlaybackCompleted { output_id: id },
+                    AudioStatus::PlaybackEnded { output_id: id.into(), elapsed_ms: 0 },
+                ];
+                for status in statuses {
+                    let _ = tx.send(driver_input(InputContent::AudioStatus(status))).await;
+                }
+            });
+        }
+    }
+
+    // One sentence of a chunked kernel output (`outputs::streamer`): the first one starts the
+    // output, later ones (same batch) join its queue
+    fn spawn_chunk(&mut self, parent: OutputId, id: OutputId, text: String, context: &EffectContext) {
+        println!("[AUDIO-{:?}] Queueing 'say': '{}'", id, text);
+        self.hooks.caption(&Caption::estimate(parent.into(), &text, !self.caption_only));
+        let playback = {
+            let mut cache = self.tts_cache.lock().unwrap_or_else(|e| e.into_inner());
+            cache.set_locale(&context.language);
+            cache.set_volume(context.volume);
+            cache.playback_command(&text, self.caption_only, context.device.as_deref())
+        };
+        if !self.caption_only {
+            crate::outputs::tts_cache::remember(self.tts_cache.clone(), text);
+        }
+
+        if let Some((_, queue)) = self.chunks.as_ref().filter(|(playing, _)| *playing == parent) {
+            let _ = queue.send((id, playback));
+            return;
+        }
+
+        if let Some(stop_tx) = self.audio_child.take() {
+            let _ = stop_tx.send(());
+        }
+        self.utterance = None;
+        let (queue, chunks) = mpsc::unbounded_channel();
+        let _ = queue.send((id, playback));
+        self.chunks = Some((parent, queue));
+        let (stop_tx, stop_rx) = oneshot::channel();
+        self.audio_child = Some(stop_tx);
+
+        let tx = self.tx.clone();
+        tokio::spawn(async move {
+            let started_at = Instant::now();
+            let _ = tx.send(driver_input(InputContent::AudioStatus(AudioStatus::PlaybackStarted { output_id: parent.into() }))).await;
+            // Completed per chunk (the kernel completes the output on the last); Ended always
+            crate::outputs::streamer::play_chunks(chunks, stop_rx, tx.clone(), |chunk| {
+                driver_input(InputContent::AudioStatus(AudioStatus::PlaybackCompleted { output_id: chunk }))
+            }).await;
+            let elapsed_ms = started_at.elapsed().as_millis() as u64;
+            let _ = tx.send(driver_input(InputContent::AudioStatus(AudioStatus::PlaybackEnded { output_id: parent.into(), elapsed_ms }))).await;
+        });
+    }
+
+    fn play_sentence(&mut self, output_id: Uuid, text: String, context: &EffectContext) {
+        // Cancelled/removed while generating?
+        if !self.speech_tasks.contains_key(&output_id) {
+            return;
+        }
+        println!("[AUDIO-{:?}] Queueing 'say': '{}'", output_id, text);
+        self.hooks.caption(&Caption::estimate(output_id.into(), &text, !self.caption_only));
+        let playback = {
+            let mut cache = self.tts_cache.lock().unwrap_or_else(|e| e.into_inner());
+            cache.set_locale(&context.language);
+            cache.set_volume(context.volume);
+            cache.playback_command(&text, self.caption_only, context.device.as_deref())
+        };
+        if !self.caption_only {
+            crate::outputs::tts_cache::remember(self.tts_cache.clone(), text.clone());
+        }
+
+        // Later sentences join the playing output's queue
+        if let Some((_, queue)) = self.utterance.as_ref().filter(|(id, _)| *id == output_id) {
+            let _ = queue.send(playback);
+            return;
+        }
+
+        // First sentence: a new output starts playing
+        if let Some(stop_tx) = self.audio_child.take() {
+            let _ = stop_tx.send(());
+        }
+        if let Some(requested_at) = self.speech_dedupe.get(&output_id) {
+            self.telemetry.record(TelemetryEvent::FirstAudio { latency_ms: requested_at.elapsed().as_millis() as u64 });
+        }
+        let (queue, players) = mpsc::unbounded_channel();
+        let _ = queue.send(playback);
+        self.utterance = Some((output_id, queue));
+        let (stop_tx, stop_rx) = oneshot::channel();
+        self.audio_child = Some(stop_tx);
+
+        let tx = self.tx.clone();
+        tokio::spawn(async move {
+            let started_at = Instant::now();
+            let _ = tx.send(driver_input(InputContent::AudioStatus(AudioStatus::PlaybackStarted { output_id: output_id.into() }))).await;
+            crate::outputs::streaming::play_in_order(players, stop_rx).await;
+            let elapsed_ms = started_at.elapsed().as_millis() as u64;
+            let _ = tx.send(driver_input(InputContent::AudioStatus(AudioStatus::PlaybackEnded { output_id: output_id.into(), elapsed_ms }))).await;
+        });
+    }
+
+    fn request_speech(&mut self, intent: crate::kernel::speech::planner::SpeechIntent, output_id: Uuid, context: &EffectContext) {
+        #[cfg(feature = "llm")]
+        if let Some(service) = self.llm.clone() {
+            // Dedupe
+            if self.speech_dedupe.get(&output_id).is_some_and(|requested_at| requested_at.elapsed() < SPEECH_DEDUPE_TTL) {
+                return;
+            }
+            self.speech_dedupe.insert(output_id, Instant::now());
+            self.telemetry.record(TelemetryEvent::SpeechLifecycle(SpeechLifecycleEvent::Requested));
+            let task = tokio::spawn(generate(
+                service,
+                intent,
+                output_id,
+                (context.filter, context.style, context.channel, context.language.clone()),
+                self.telemetry.clone(),
+                self.generated_tx.clone(),
+            ));
+            self.speech_tasks.insert(output_id, task);
+            return;
+        }
+        let _ = (intent, context);
+        info!("[DRIVER] Ignoring RequestSpeech for {} (no speech service)", output_id);
+    }
+}
+
+// Streams one reply sentence by sentence: screened, restyled, sent for playback
+#[cfg(feature = "llm")]
+async fn generate(
+    service: crate::services::llm::client::LLMService,
+    intent: crate::kernel::speech::planner::SpeechIntent,
+    output_id: Uuid,
+    (filter, style, channel, language): (SafetyConfig, OutputStyle, OutputChannel, String),
+    telemetry: TelemetryHandle,
+    tx: mpsc::Sender<Generated>,
+) {
+    use crate::outputs::safety::FilterOutcome;
+
+    let mut may_regenerate = true;
+    let mut sent = 0;
+    'generate: loop {
+        // Hard Timeout 2s to the first sentence; later ones stream while earlier ones play
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+        let mut stream = match tokio::time::timeout_at(deadline, service.stream_speech(intent.clone(), style, channel, &language)).await {
+            Ok(Ok(stream)) => stream,
+            Ok(Err(e)) => { warn!("LLM Error: {}", e); break; }
+            Err(_) => { warn!("LLM Timeout"); break; }
+        };
+        loop {
+            let next = if sent == 0 {
+                match tokio::time::timeout_at(deadline, stream.next_sentence()).await {
+                    Ok(next) => next,
+                    Err(_) => { warn!("LLM Timeout"); break 'generate; }
+                }
+            } else {
+                stream.next_sentence().await
+            };
+            let sentence = match next {
+                Ok(Some(sentence)) => sentence,
+                Ok(None) => break 'generate,
+                Err(e) => { warn!("LLM Error: {}", e); break 'generate; }
+            };
+
+            // Safety filter before playback; regenerating is only possible before anything was spoken
+            let outcome = crate::outputs::safety::screen(&sentence, &filter, may_regenerate && sent == 0);
+            if let Some(event) = outcome.telemetry() {
+                telemetry.record(event);
+            }
+            let sentence = match outcome {
+                FilterOutcome::Clean => sentence,
+                FilterOutcome::Redacted { text, .. } => text,
+                FilterOutcome::Regenerate(_) => {
+                    may_regenerate = false;
+                    continue 'generate;
+                }
+                FilterOutcome::Silenced(_) => break 'generate,
+            };
+            let text = crate::outputs::realizer::restyle(&sentence, &style, channel);
+            if tx.send(Generated::Sentence { output_id, text }).await.is_err() {
+                return;
+            }
+            sent += 1;
+        }
+    }
+    // What was already spoken stands
+    let done = if sent == 0 { Generated::Failed { output_id } } else { Generated::Done { output_id } };
+    let _ = tx.send(done).await;
+}
+
+fn driver_input(content: InputContent) -> Event {
+    Event::Input(InputEvent { source: "Driver".to_string(), content, captured_at: None })
+}
@@ -0,0 +1,145 @@
+//! Effect Batches.
+//!
+//! `tick_step` returns its side effects as an `EffectBatch`, which is always ordered by phase:
+//!
+//! 1. `Control`: stop or cancel running work and reconfigure capture.
+//! 2. `Request`: start background work (transcription, tool calls) and log.
+//! 3. `Output`: anything the user will hear or see.
+//!
+//! Within a phase, effects keep the order they were emitted in. Drivers execute a batch front
+//! to back, so old audio is always stopped before new audio starts, whatever order the kernel
+//! pushed the effects in.
+//!
+//! Embedders implement `EffectExecutor` for their platform (audio, TTS, transcription) and hand
+//! it every batch. See `examples/minimal_embedder.rs`.
+
+use std::ops::Deref;
+
+use serde::Serialize;
+
+use crate::kernel::scheduler::SideEffect;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub enum EffectPhase {
+    Control,
+    Request,
+    Output,
+}
+
+impl SideEffect {
+    pub fn phase(&self) -> EffectPhase {
+        match self {
+            SideEffect::StopAudio
+            | SideEffect::CancelTranscription { .. }
+            | SideEffect::CancelTool { .. }
+            | SideEffect::SetCaptureFidelity(_) => EffectPhase::Control,
+            SideEffect::RequestTranscription { .. }
+            | SideEffect::InvokeTool { .. }
+            | SideEffect::RequestSummary { .. }
+            | SideEffect::MaintenanceProgress(_)
+            | SideEffect::RunMaintenance(_)
+            | SideEffect::PersistCalibration(_)
+            | SideEffect::PersistCorrections(_)
+            | SideEffect::PersistProfiles(_)
+            | SideEffect::ExportTelemetry(_)
+            | SideEffect::Diagnostic { .. } => EffectPhase::Request,
+            #[allow(deprecated)]
+            SideEffect::Log(_) => EffectPhase::Request,
+            SideEffect::SpawnAudio(..)
+            | SideEffect::RequestSpeech { .. }
+            | SideEffect::AskMemoryConsent { .. }
+            | SideEffect::DismissConsent(_)
+            | SideEffect::PlayCue(_) => EffectPhase::Output,
+        }
+    }
+}
+
+/// Side effects of one tick, in phase order. Read it like a slice; build it with `push`.
+#[derive(Debug, Clone, Default)]
+pub struct EffectBatch {
+    effects: Vec<SideEffect>,
+}
+
+impl EffectBatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert after every effect of the same or an earlier phase.
+    pub fn push(&mut self, effect: SideEffect) {
+        let phase = effect.phase();
+        let at = self.effects.partition_point(|e| e.phase() <= phase);
+        self.effects.insert(at, effect);
+    }
+
+    /// Keep only the effects matching `keep`. Order is preserved.
+    pub fn retain(&mut self, keep: impl FnMut(&SideEffect) -> bool) {
+        self.effects.retain(keep);
+    }
+
+    /// Effects of a single phase, in emission order.
+    pub fn phase(&self, phase: EffectPhase) -> &[SideEffect] {
+        let start = self.effects.partition_point(|e| e.phase() < phase);
+        let end = self.effects.partition_point(|e| e.phase() <= phase);
+        &self.effects[start..end]
+    }
+
+    pub fn into_vec(self) -> Vec<SideEffect> {
+        self.effects
+    }
+}
+
+impl Deref for EffectBatch {
+    type Target = [SideEffect];
+
+    fn deref(&self) -> &[SideEffect] {
+        &self.effects
+    }
+}
+
+impl IntoIterator for EffectBatch {
+    type Item = SideEffect;
+    type IntoIter = std::vec::IntoIter<SideEffect>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.effects.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a EffectBatch {
+    type Item = &'a SideEffect;
+    type IntoIter = std::slice::Iter<'a, SideEffect>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.effects.iter()
+    }
+}
+
+impl Extend<SideEffect> for EffectBatch {
+    fn extend<I: IntoIterator<Item = SideEffect>>(&mut self, iter: I) {
+        for effect in iter {
+            self.push(effect);
+        }
+    }
+}
+
+impl FromIterator<SideEffect> for EffectBatch {
+    fn from_iter<I: IntoIterator<Item = SideEffect>>(iter: I) -> Self {
+        let mut batch = Self::new();
+        batch.extend(iter);
+        batch
+    }
+}
+
+/// Driver side of the kernel: performs side effects on a platform.
+/// The kernel never calls this itself; the embedder's loop does, once per tick.
+pub trait EffectExecutor {
+    fn execute(&mut self, effect: SideEffect);
+
+    /// Execute a whole batch front to back (phase order).
+    fn execute_batch(&mut self, batch: EffectBatch) {
+        for effect in batch {
+            self.execute(effect);
+        }
+    }
+}
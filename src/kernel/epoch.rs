@@ -0,0 +1,205 @@
+//! Planner Epoch Policy.
+//!
+//! A plan is computed against a snapshot of `SharedState` at some version (`PlanningEpoch`).
+//! By the time it comes back, the state may have moved on. The policy decides how far:
+//! a plan is accepted while `state.version - epoch.state_version <= max lag`.
+//! Plans from the future (epoch ahead of the state) are always stale.
+//!
+//! `state_version: 0` marks a manual/debug injection. It bypasses the lag check only when
+//! `allow_debug_injections` is set, which defaults to debug builds.
+//!
+//! Every mutation also belongs to a `StateDomain`, and `SharedState` keeps a version vector
+//! (`DomainVersions`) next to the global version. The lag only counts mutations in the domains the
+//! policy declares (`StaleDomains`): by default a latent decay or a presence change does not make an
+//! in-flight plan stale, and does not trigger a new one. Epochs older than the state's version
+//! history fall back to the global lag.
+
+use serde::Serialize;
+
+use crate::kernel::state::SharedState;
+use crate::planner::types::{Intent, PlanningEpoch};
+
+// Config Constants
+/// Same version, or one mutation behind (the input that triggered planning).
+pub const DEFAULT_MAX_VERSION_LAG: u64 = 1;
+/// Epoch version reserved for manual/debug plan injections.
+pub const DEBUG_EPOCH_VERSION: u64 = 0;
+
+/// What a mutation changed (`StateDelta::domain`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum StateDomain {
+    /// User input, transcripts, topic and context, interruptions
+    Dialogue,
+    /// Proposed, playing and cancelled outputs
+    Outputs,
+    /// Assessment and long-horizon intents
+    Intents,
+    /// Memory candidates, records and consent
+    Memory,
+    /// Latents, presence, devices, audio segments and the rest of the sensing state
+    Perception,
+}
+
+/// Mutations per domain (a version vector; the global version is their sum plus the start).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+pub struct DomainVersions {
+    pub dialogue: u64,
+    pub outputs: u64,
+    pub intents: u64,
+    pub memory: u64,
+    pub perception: u64,
+}
+
+impl DomainVersions {
+    pub fn get(&self, domain: StateDomain) -> u64 {
+        match domain {
+            StateDomain::Dialogue => self.dialogue,
+            StateDomain::Outputs => self.outputs,
+            StateDomain::Intents => self.intents,
+            StateDomain::Memory => self.memory,
+            StateDomain::Perception => self.perception,
+        }
+    }
+
+    pub fn bump(&mut self, domain: StateDomain) {
+        match domain {
+            StateDomain::Dialogue => self.dialogue += 1,
+            StateDomain::Outputs => self.outputs += 1,
+            StateDomain::Intents => self.intents += 1,
+            StateDomain::Memory => self.memory += 1,
+            StateDomain::Perception => self.perception += 1,
+        }
+    }
+
+    /// PURE FUNCTION: Mutations per domain between `earlier` and `self`.
+    pub fn since(&self, earlier: &DomainVersions) -> DomainVersions {
+        DomainVersions {
+            dialogue: self.dialogue.saturating_sub(earlier.dialogue),
+            outputs: self.outputs.saturating_sub(earlier.outputs),
+            intents: self.intents.saturating_sub(earlier.intents),
+            memory: self.memory.saturating_sub(earlier.memory),
+            perception: self.perception.saturating_sub(earlier.perception),
+        }
+    }
+
+    /// Mutations in the domains of `domains`.
+    pub fn count_in(&self, domains: &StaleDomains) -> u64 {
+        StateDomain::ALL.iter().filter(|d| domains.contains(**d)).map(|d| self.get(*d)).sum()
+    }
+}
+
+impl StateDomain {
+    pub const ALL: [StateDomain; 5] =
+        [StateDomain::Dialogue, StateDomain::Outputs, StateDomain::Intents, StateDomain::Memory, StateDomain::Perception];
+}
+
+/// Domains whose mutations make a plan stale. Default: all but `Perception`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaleDomains {
+    pub dialogue: bool,
+    pub outputs: bool,
+    pub intents: bool,
+    pub memory: bool,
+    pub perception: bool,
+}
+
+impl Default for StaleDomains {
+    fn default() -> Self {
+        Self { dialogue: true, outputs: true, intents: true, memory: true, perception: false }
+    }
+}
+
+impl StaleDomains {
+    /// Every mutation counts (same as the global version).
+    pub const ALL: StaleDomains = StaleDomains { dialogue: true, outputs: true, intents: true, memory: true, perception: true };
+
+    pub fn contains(&self, domain: StateDomain) -> bool {
+        match domain {
+            StateDomain::Dialogue => self.dialogue,
+            StateDomain::Outputs => self.outputs,
+            StateDomain::Intents => self.intents,
+            StateDomain::Memory => self.memory,
+            StateDomain::Perception => self.perception,
+        }
+    }
+}
+
+/// Per-intent lag overrides. `None` falls back to `EpochPolicy::max_version_lag`.
+/// `begin_response` also covers `BeginResponseWithFollowUp`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IntentLagOverrides {
+    pub begin_response: Option<u64>,
+    pub delay: Option<u64>,
+    pub ask_clarification: Option<u64>,
+    pub revise_statement: Option<u64>,
+    pub do_nothing: Option<u64>,
+}
+
+impl IntentLagOverrides {
+    pub fn lag_for(&self, intent: &Intent) -> Option<u64> {
+        match intent {
+            Intent::BeginResponse { .. } | Intent::BeginResponseWithFollowUp { .. } => self.begin_response,
+            Intent::Delay { .. } => self.delay,
+            Intent::AskClarification { .. } => self.ask_clarification,
+            Intent::ReviseStatement { .. } => self.revise_statement,
+            Intent::DoNothing => self.do_nothing,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochPolicy {
+    pub max_version_lag: u64,
+    pub allow_debug_injections: bool,
+    pub overrides: IntentLagOverrides,
+    /// Domains counted by `accepts_at` and `changed_since`
+    pub domains: StaleDomains,
+}
+
+impl Default for EpochPolicy {
+    fn default() -> Self {
+        Self {
+            max_version_lag: DEFAULT_MAX_VERSION_LAG,
+            allow_debug_injections: cfg!(debug_assertions),
+            overrides: IntentLagOverrides::default(),
+            domains: StaleDomains::default(),
+        }
+    }
+}
+
+impl EpochPolicy {
+    /// PURE FUNCTION: Is a plan for `epoch` still valid at `state_version`? (Global lag.)
+    pub fn accepts(&self, epoch: &PlanningEpoch, intent: &Intent, state_version: u64) -> bool {
+        if epoch.state_version == DEBUG_EPOCH_VERSION && self.allow_debug_injections {
+            return true;
+        }
+        state_version
+            .checked_sub(epoch.state_version)
+            .map(|lag| lag <= self.max_lag(intent))
+            .unwrap_or(false)
+    }
+
+    /// PURE FUNCTION: Is a plan for `epoch` still valid in `state`? Only mutations in `domains`
+    /// count toward the lag.
+    pub fn accepts_at(&self, epoch: &PlanningEpoch, intent: &Intent, state: &SharedState) -> bool {
+        if epoch.state_version == DEBUG_EPOCH_VERSION && self.allow_debug_injections {
+            return true;
+        }
+        self.lag_since(state, epoch.state_version).map(|lag| lag <= self.max_lag(intent)).unwrap_or(false)
+    }
+
+    /// PURE FUNCTION: Has anything in `domains` changed since `state_version`? (Re-plan guard.)
+    pub fn changed_since(&self, state: &SharedState, state_version: u64) -> bool {
+        self.lag_since(state, state_version) != Some(0)
+    }
+
+    fn max_lag(&self, intent: &Intent) -> u64 {
+        self.overrides.lag_for(intent).unwrap_or(self.max_version_lag)
+    }
+
+    // Counted mutations since `state_version`; None for a version from the future
+    fn lag_since(&self, state: &SharedState, state_version: u64) -> Option<u64> {
+        let global = state.version().checked_sub(state_version)?;
+        Some(state.domain_lag(state_version).map(|lag| lag.count_in(&self.domains)).unwrap_or(global))
+    }
+}
@@ -8,22 +8,39 @@ pub struct OutputId {
     pub ordinal: u16,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Event {
     /// External signals (Audio, Text, System Signals)
     Input(InputEvent),
     PlanProposed(crate::planner::types::PlanningEpoch, crate::planner::types::Intent),
-    // Phase N: Telemetry Injection
-    Telemetry(crate::kernel::telemetry::event::TelemetryEvent),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AudioSignal {
     SpeechStart,
     SpeechEnd,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Reduced-fidelity push audio, sent while no segment is buffering.
+/// Feeds the core VAD only; never appended to segments.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AudioSummary {
+    /// Mono chunk decimated below the capture rate (e.g. 16kHz).
+    Decimated { samples: Vec<f32>, sample_rate: u32 },
+    /// Energy only: one RMS value for the chunk window.
+    Envelope { rms: f32, duration_ms: u64 },
+}
+
+/// Push-mode capture fidelity requested by the kernel (see `SideEffect::SetCaptureFidelity`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureFidelity {
+    /// Full-rate mono chunks (`InputContent::AudioChunk`). Only while a segment is buffering.
+    Full,
+    /// `InputContent::AudioSummary` only.
+    Reduced,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum VisualSignal {
     /// Fact: A new percept has arrived.
     PerceptUpdate {
@@ -32,38 +49,162 @@ pub enum VisualSignal {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Identifies one playback stream. Kernel outputs carry an OutputId;
+/// driver-generated speech (RequestSpeech) carries the request's UUID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PlaybackId {
+    Output(OutputId),
+    Speech(uuid::Uuid),
+}
+
+impl From<OutputId> for PlaybackId {
+    fn from(id: OutputId) -> Self {
+        PlaybackId::Output(id)
+    }
+}
+
+impl From<uuid::Uuid> for PlaybackId {
+    fn from(id: uuid::Uuid) -> Self {
+        PlaybackId::Speech(id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AudioStatus {
-    PlaybackStarted,
-    PlaybackEnded, // Normalized: Finished OR Cancelled
+    PlaybackStarted { output_id: PlaybackId },
+    PlaybackEnded { output_id: PlaybackId, elapsed_ms: u64 }, // Normalized: Finished OR Cancelled
+    // Playback position reports (Driver -> Kernel), keyed by output
+    PlaybackProgress { output_id: OutputId, percent: u8 },
+    PlaybackCompleted { output_id: OutputId }, // Natural finish only. Sent BEFORE PlaybackEnded.
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InputEvent {
     pub source: String,
     pub content: InputContent,
+    /// Capture layer: tick the signal was heard (`CaptureClock`). `None`: the processing tick.
+    /// Audio timing (segments, hesitation, response gap, interruption latency) uses this.
+    #[serde(default)]
+    pub captured_at: Option<Tick>,
 }
 
-#[derive(Debug, Clone)]
+fn final_transcript() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum InputContent {
     Text(String),
     Audio(AudioSignal),
-    AudioChunk(Vec<f32>), // Raw audio frames from shell
+    AudioChunk(Vec<f32>), // Raw audio frames from shell (full rate, mono)
+    AudioSummary(AudioSummary), // Reduced-fidelity audio while the segment gate is closed
     Visual(VisualSignal),
     ProvisionalText {
         content: String,
         confidence: f32,
         source_id: String, // SegmentId
+        /// Per-word confidence from the ASR backend (empty for typed or mocked text)
+        #[serde(default)]
+        words: Vec<crate::kernel::audio::segment::WordConfidence>,
+        /// Language the ASR backend heard (`None` for typed or older text, see `kernel::language`)
+        #[serde(default)]
+        language: Option<String>,
+        /// The segment's last transcript (not a fragment that may still turn around). Spoken
+        /// commands, such as a consent answer, act on final text only. Older journals: final.
+        /// The built-in ASR, the REPL and the self-test send one final transcript per segment;
+        /// interim ones only come from external recognizers (`ClientMessage::Transcript`).
+        #[serde(default = "final_transcript")]
+        is_final: bool,
     },
     TranscriptionRequest {
         segment_id: String, // Explicit gating trigger
     },
+    /// ASR: a wake phrase opened the utterance (see `audio::wake`); sent ahead of its transcript
+    WakeWord { phrase: String },
     AudioStatus(AudioStatus),
     // Phase L: Memory Consent
-    MemoryConsentResponse {
-        key: crate::kernel::memory::types::MemoryKey,
-        state: crate::kernel::memory::consent::MemoryConsentState,
+    /// Answer to `SideEffect::AskMemoryConsent`; must echo the request's `prompt_id`
+    MemoryConsentResponse(crate::kernel::protocol::ConsentResponse),
+    /// Driver outcome of `SideEffect::InvokeTool`
+    ToolResult { call_id: String, ok: bool },
+    /// Driver clock: local minutes since midnight (quiet hours). Sent on start and on change.
+    LocalTime { minute_of_day: u16 },
+    /// Driver outcome of `SideEffect::RequestSummary` (`None`: no LLM answer, use the template)
+    ExchangeSummary { exchange_id: String, text: Option<String> },
+    /// Capture layer: OS microphone permission (probe or privacy settings)
+    CapturePermission {
+        state: crate::kernel::presence::CapturePermission,
     },
+    /// Playback layer: device speech is routed to (`None`: system default). Sent on start and on change.
+    OutputDevice { device: Option<String> },
+    /// Capture layer: microphone in use (`None`: system default). Sent when a stream opens.
+    InputDevice { device: Option<String> },
+    /// Settings: detect the spoken language or pin one (see `kernel::language`)
+    Language(crate::kernel::language::LanguagePreference),
+    /// Settings: measure the noise floor for the current device pair (see `kernel::audio::calibration`)
+    CalibrateAudio,
+    /// Settings: retune the core VAD at runtime (see `kernel::audio::vad`)
+    ConfigUpdate { vad: crate::kernel::audio::vad::VadConfig },
+    /// Capture layer: the audio actor was lost and restarted (see `audio::supervisor`)
+    CaptureRestarted,
+    /// Federation: records opened from a paired device (see `federation::Replica::apply`)
+    SyncReceived {
+        peer: crate::federation::DeviceId,
+        records: Vec<crate::federation::SyncRecord>,
+    },
+    /// Memory inspector: promote or discard a candidate by hand (see `kernel::memory::inspect`)
+    MemoryCandidateAction {
+        id: crate::kernel::memory::types::MemoryId,
+        action: crate::kernel::memory::inspect::CandidateAction,
+    },
+    /// Forget everything learned under a topic (`None`: the active one)
+    ForgetTopic { topic_id: Option<crate::kernel::memory::topic::TopicId> },
+    /// Settings: switch a subsystem on or off at runtime (see `kernel::subsystem`)
+    SubsystemControl {
+        subsystem: crate::kernel::subsystem::Subsystem,
+        enabled: bool,
+    },
+    /// CLI / settings: suspend planning and output and run `jobs` (see `kernel::maintenance`)
+    EnterMaintenance { jobs: Vec<crate::kernel::maintenance::MaintenanceJob> },
+    /// Driver outcome of `SideEffect::RunMaintenance`
+    MaintenanceResult(crate::kernel::maintenance::JobResult),
+}
+
+impl InputContent {
+    /// Onboarding allowlist: lifecycle and status signals from the platform or driver,
+    /// answers to requests the kernel made. Everything else is user content (speech, text,
+    /// vision, synced records) and is dropped in `KernelMode::Onboarding` and `KernelMode::Maintenance`.
+    /// Exhaustive on purpose: a new variant must be classified.
+    pub fn is_control(&self) -> bool {
+        match self {
+            InputContent::AudioStatus(_)
+            | InputContent::MemoryConsentResponse(_)
+            | InputContent::ToolResult { .. }
+            | InputContent::LocalTime { .. }
+            | InputContent::CapturePermission { .. }
+            | InputContent::OutputDevice { .. }
+            | InputContent::InputDevice { .. }
+            | InputContent::Language(_)
+            | InputContent::CalibrateAudio
+            | InputContent::ConfigUpdate { .. }
+            | InputContent::CaptureRestarted
+            | InputContent::SubsystemControl { .. }
+            | InputContent::EnterMaintenance { .. }
+            | InputContent::MaintenanceResult(_) => true,
+            InputContent::Text(_)
+            | InputContent::Audio(_)
+            | InputContent::AudioChunk(_)
+            | InputContent::AudioSummary(_)
+            | InputContent::Visual(_)
+            | InputContent::ProvisionalText { .. }
+            | InputContent::TranscriptionRequest { .. }
+            | InputContent::WakeWord { .. }
+            | InputContent::ExchangeSummary { .. }
+            | InputContent::SyncReceived { .. }
+            | InputContent::MemoryCandidateAction { .. }
+            | InputContent::ForgetTopic { .. } => false,
+        }
+    }
 }
 
 // Helper for legacy text compatibility
@@ -72,6 +213,16 @@ impl InputEvent {
         Self {
             source: source.to_string(),
             content: InputContent::Text(text.to_string()),
+            captured_at: None,
+        }
+    }
+
+    /// Capture-stamped input (see `CaptureClock`).
+    pub fn captured(source: &str, content: InputContent, at: Tick) -> Self {
+        Self {
+            source: source.to_string(),
+            content,
+            captured_at: Some(at),
         }
     }
 }
@@ -0,0 +1,110 @@
+//! Follow-up Offers.
+//!
+//! `Intent::BeginResponseWithFollowUp` is a two-part reply: the answer (only its first sentence
+//! with `summary_first`), then an offer to elaborate ("Want the details?"). Both are outputs; the
+//! offer is linked to the answer (`Output::parent_id`) and held here until the answer was heard.
+//!
+//! The offer is released when the answer plays to the end (`PlaybackCompleted`), and only said
+//! if the user has not taken the turn meanwhile. It is dropped when the answer is cancelled
+//! (barge-in, explicit cancel), ends without completing, or is still unheard after
+//! `FOLLOW_UP_HOLD_TICKS` (ignored: never played).
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::event::OutputId;
+use crate::kernel::time::Tick;
+
+// Config Constants
+/// An answer not heard in full within this many ticks loses its offer (60s @ 20ms).
+pub const FOLLOW_UP_HOLD_TICKS: u64 = 3_000;
+
+/// What became of an offer (Decision Trace).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FollowUpOutcome {
+    /// Answer heard in full: the offer was said
+    Offered,
+    /// Answer cancelled (barge-in, explicit cancel)
+    Canceled,
+    /// Answer stopped before its end
+    Ended,
+    /// Answer heard, but the user took the turn before the offer
+    UserSpoke,
+    /// Answer not heard within `FOLLOW_UP_HOLD_TICKS`
+    Expired,
+}
+
+/// An offer waiting on its answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingFollowUp {
+    pub answer: OutputId,
+    /// The answer was cut to its summary: offer the details
+    pub summary_first: bool,
+    pub linked_at: Tick,
+}
+
+/// Offers by answer output.
+#[derive(Debug, Default)]
+pub struct FollowUpTracker {
+    pending: HashMap<OutputId, PendingFollowUp>,
+    ready: Vec<PendingFollowUp>,
+}
+
+impl FollowUpTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `answer` was proposed; its offer waits for it to be heard.
+    pub fn link(&mut self, answer: OutputId, summary_first: bool, tick: Tick) {
+        self.pending.insert(answer, PendingFollowUp { answer, summary_first, linked_at: tick });
+    }
+
+    /// The answer played to the end: its offer is due (`take_ready`).
+    pub fn completed(&mut self, answer: &OutputId) {
+        if let Some(follow_up) = self.pending.remove(answer) {
+            self.ready.push(follow_up);
+        }
+    }
+
+    /// Barge-in or explicit cancel: no answer in flight is heard in full. Returns the answers
+    /// whose offers were dropped (waiting or just released), in order.
+    pub fn interrupted(&mut self) -> Vec<OutputId> {
+        let mut dropped: Vec<OutputId> = self.pending.drain().map(|(answer, _)| answer).collect();
+        dropped.extend(self.ready.drain(..).map(|f| f.answer));
+        dropped.sort_by_key(|a| (a.tick, a.ordinal));
+        dropped
+    }
+
+    /// The answer's playback ended. `true` if it had an offer still waiting (not completed).
+    pub fn ended(&mut self, answer: &OutputId) -> bool {
+        self.pending.remove(answer).is_some()
+    }
+
+    /// Offers whose answer was heard in full, in order.
+    pub fn take_ready(&mut self) -> Vec<PendingFollowUp> {
+        std::mem::take(&mut self.ready)
+    }
+
+    /// Offers whose answer was not heard within `FOLLOW_UP_HOLD_TICKS`.
+    pub fn expired(&mut self, now: Tick) -> Vec<PendingFollowUp> {
+        let mut due: Vec<PendingFollowUp> = self.pending.values()
+            .filter(|f| now.frame.saturating_sub(f.linked_at.frame) >= FOLLOW_UP_HOLD_TICKS)
+            .copied()
+            .collect();
+        due.sort_by_key(|f| (f.answer.tick, f.answer.ordinal));
+        for follow_up in &due {
+            self.pending.remove(&follow_up.answer);
+        }
+        due
+    }
+
+    pub fn is_pending(&self, answer: &OutputId) -> bool {
+        self.pending.contains_key(answer)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty() && self.ready.is_empty()
+    }
+}
@@ -0,0 +1,281 @@
+//! State Size Accounting & Memory Pressure.
+//!
+//! Long-running sessions accumulate audio segments, intents and memory candidates
+//! that nothing ever removes. A periodic accounting pass measures each domain,
+//! reports it via `TelemetryEvent::StateFootprint`, and runs janitor passes
+//! when a domain approaches its configured ceiling.
+//!
+//! Janitor passes are expressed as StateDeltas (state still mutates ONLY via reduce).
+//! Long-term memory and consent records are user-granted and are NEVER pruned here;
+//! they are reported only.
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::state::{SharedState, StateDelta};
+use crate::kernel::time::Tick;
+use crate::kernel::audio::segment::SegmentStatus;
+use crate::kernel::intent::long_horizon::IntentStatus;
+
+// Config Constants
+const ACCOUNTING_INTERVAL_TICKS: u64 = 500; // 10s @ 20ms
+const PRESSURE_RATIO: f32 = 0.8; // Janitor triggers at 80% of ceiling
+const JANITOR_TARGET_RATIO: f32 = 0.6; // ...and prunes down to 60%
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FootprintDomain {
+    AudioSegments,
+    Latents,
+    MemoryCandidates,
+    LongTermMemory,
+    MemoryConsent,
+    Intents,
+    ConversationLog,
+}
+
+/// Element count + approximate heap bytes for one domain.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainFootprint {
+    pub count: usize,
+    pub approx_bytes: usize,
+}
+
+/// Counts only (Telemetry privacy invariant).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateFootprint {
+    pub tick: u64,
+    pub audio_segments: DomainFootprint,
+    pub latents: DomainFootprint,
+    pub memory_candidates: DomainFootprint,
+    pub long_term_memory: DomainFootprint,
+    pub memory_consent: DomainFootprint,
+    pub intents: DomainFootprint,
+    pub conversation_log: DomainFootprint,
+}
+
+impl StateFootprint {
+    pub fn get(&self, domain: FootprintDomain) -> DomainFootprint {
+        match domain {
+            FootprintDomain::AudioSegments => self.audio_segments,
+            FootprintDomain::Latents => self.latents,
+            FootprintDomain::MemoryCandidates => self.memory_candidates,
+            FootprintDomain::LongTermMemory => self.long_term_memory,
+            FootprintDomain::MemoryConsent => self.memory_consent,
+            FootprintDomain::Intents => self.intents,
+            FootprintDomain::ConversationLog => self.conversation_log,
+        }
+    }
+
+    pub fn total_bytes(&self) -> usize {
+        self.audio_segments.approx_bytes
+            + self.latents.approx_bytes
+            + self.memory_candidates.approx_bytes
+            + self.long_term_memory.approx_bytes
+            + self.memory_consent.approx_bytes
+            + self.intents.approx_bytes
+            + self.conversation_log.approx_bytes
+    }
+}
+
+/// Element-count ceilings per domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FootprintCeilings {
+    pub audio_segments: usize,
+    pub latents: usize,
+    pub memory_candidates: usize,
+    pub long_term_memory: usize,
+    pub memory_consent: usize,
+    pub intents: usize,
+    pub conversation_log: usize,
+}
+
+impl Default for FootprintCeilings {
+    fn default() -> Self {
+        Self {
+            audio_segments: 64,
+            latents: 256,
+            memory_candidates: 512,
+            long_term_memory: 2048, // Report only
+            memory_consent: 2048,   // Report only
+            intents: 128,
+            conversation_log: 1024,
+        }
+    }
+}
+
+impl FootprintCeilings {
+    pub fn get(&self, domain: FootprintDomain) -> usize {
+        match domain {
+            FootprintDomain::AudioSegments => self.audio_segments,
+            FootprintDomain::Latents => self.latents,
+            FootprintDomain::MemoryCandidates => self.memory_candidates,
+            FootprintDomain::LongTermMemory => self.long_term_memory,
+            FootprintDomain::MemoryConsent => self.memory_consent,
+            FootprintDomain::Intents => self.intents,
+            FootprintDomain::ConversationLog => self.conversation_log,
+        }
+    }
+}
+
+const ALL_DOMAINS: [FootprintDomain; 7] = [
+    FootprintDomain::AudioSegments,
+    FootprintDomain::Latents,
+    FootprintDomain::MemoryCandidates,
+    FootprintDomain::LongTermMemory,
+    FootprintDomain::MemoryConsent,
+    FootprintDomain::Intents,
+    FootprintDomain::ConversationLog,
+];
+
+/// PURE FUNCTION: Measure state. `conversation_log_len` comes from the episodic store.
+pub fn measure(state: &SharedState, conversation_log_len: usize, tick: Tick) -> StateFootprint {
+    use std::mem::size_of;
+
+    let audio_bytes: usize = state.audio_segments().values()
+        .map(|s| size_of::<crate::kernel::audio::segment::AudioSegment>()
+            + s.frames.capacity() * size_of::<f32>()
+            + s.transcription.as_ref().map(|t| t.capacity()).unwrap_or(0))
+        .sum();
+
+    let latent_bytes: usize = state.latents().slots.iter()
+        .map(|s| size_of::<crate::kernel::latent::LatentSlot>() + s.values.capacity() * size_of::<f32>())
+        .sum();
+
+    StateFootprint {
+        tick: tick.frame,
+        audio_segments: DomainFootprint { count: state.audio_segments().len(), approx_bytes: audio_bytes },
+        latents: DomainFootprint { count: state.latents().slots.len(), approx_bytes: latent_bytes },
+        memory_candidates: DomainFootprint {
+            count: state.memory_candidates().len(),
+            approx_bytes: state.memory_candidates().len() * size_of::<crate::kernel::memory::types::MemoryCandidate>(),
+        },
+        long_term_memory: DomainFootprint {
+            count: state.long_term_memory().len(),
+            approx_bytes: state.long_term_memory().len() * size_of::<crate::kernel::memory::types::MemoryRecord>(),
+        },
+        memory_consent: DomainFootprint {
+            count: state.memory_consent().len(),
+            approx_bytes: state.memory_consent().len() * size_of::<crate::kernel::memory::consent::MemoryConsent>(),
+        },
+        intents: DomainFootprint {
+            count: state.active_intents().len(),
+            approx_bytes: state.active_intents().len() * size_of::<crate::kernel::intent::long_horizon::LongHorizonIntent>(),
+        },
+        conversation_log: DomainFootprint {
+            count: conversation_log_len,
+            approx_bytes: conversation_log_len * size_of::<crate::memory::types::EpisodicMemoryEntry>(),
+        },
+    }
+}
+
+/// Outcome of an accounting pass.
+#[derive(Debug, Clone)]
+pub struct FootprintReport {
+    pub footprint: StateFootprint,
+    /// Domains at or above PRESSURE_RATIO of their ceiling
+    pub pressured: Vec<FootprintDomain>,
+    /// Janitor deltas for prunable domains (caller reduces them)
+    pub deltas: Vec<StateDelta>,
+    /// Target length for the conversation log, if it needs pruning (not a SharedState domain)
+    pub conversation_log_target: Option<usize>,
+}
+
+/// Periodic accounting sidecar.
+pub struct FootprintMonitor {
+    pub ceilings: FootprintCeilings,
+    pub interval_ticks: u64,
+    last_run: Option<u64>,
+}
+
+impl FootprintMonitor {
+    pub fn new(ceilings: FootprintCeilings) -> Self {
+        Self {
+            ceilings,
+            interval_ticks: ACCOUNTING_INTERVAL_TICKS,
+            last_run: None,
+        }
+    }
+
+    /// Runs at most once per `interval_ticks`.
+    pub fn tick(&mut self, tick: Tick, state: &SharedState, conversation_log_len: usize) -> Option<FootprintReport> {
+        if let Some(last) = self.last_run {
+            if tick.frame.saturating_sub(last) < self.interval_ticks {
+                return None;
+            }
+        }
+        self.last_run = Some(tick.frame);
+        Some(self.account(tick, state, conversation_log_len))
+    }
+
+    /// Unconditional accounting pass.
+    pub fn account(&self, tick: Tick, state: &SharedState, conversation_log_len: usize) -> FootprintReport {
+        let footprint = measure(state, conversation_log_len, tick);
+
+        let pressured: Vec<FootprintDomain> = ALL_DOMAINS.iter()
+            .copied()
+            .filter(|d| {
+                let ceiling = self.ceilings.get(*d);
+                ceiling > 0 && footprint.get(*d).count as f32 >= ceiling as f32 * PRESSURE_RATIO
+            })
+            .collect();
+
+        let mut deltas = Vec::new();
+        let mut conversation_log_target = None;
+        for domain in &pressured {
+            let target = (self.ceilings.get(*domain) as f32 * JANITOR_TARGET_RATIO) as usize;
+            match domain {
+                FootprintDomain::AudioSegments => deltas.extend(sweep_audio_segments(state, target)),
+                FootprintDomain::Latents => deltas.push(StateDelta::LatentsTruncated { keep: target }),
+                FootprintDomain::MemoryCandidates => deltas.extend(sweep_memory_candidates(state, target)),
+                FootprintDomain::Intents => deltas.extend(sweep_intents(state, target)),
+                FootprintDomain::ConversationLog => conversation_log_target = Some(target),
+                // User-granted memory: report only
+                FootprintDomain::LongTermMemory | FootprintDomain::MemoryConsent => {}
+            }
+        }
+
+        FootprintReport { footprint, pressured, deltas, conversation_log_target }
+    }
+}
+
+/// Oldest finished segments first. Never touches the active segment or
+/// segments still awaiting transcription.
+fn sweep_audio_segments(state: &SharedState, target: usize) -> Vec<StateDelta> {
+    let excess = state.audio_segments().len().saturating_sub(target);
+    if excess == 0 {
+        return Vec::new();
+    }
+    let mut prunable: Vec<_> = state.audio_segments().values()
+        .filter(|s| matches!(s.status, SegmentStatus::Transcribed | SegmentStatus::Discarded))
+        .filter(|s| state.active_segment_id() != Some(&s.id))
+        .collect();
+    prunable.sort_by_key(|s| (s.start_tick.frame, s.id.clone()));
+
+    let ids: Vec<String> = prunable.into_iter().take(excess).map(|s| s.id.clone()).collect();
+    if ids.is_empty() { Vec::new() } else { vec![StateDelta::AudioSegmentsPurged(ids)] }
+}
+
+/// Stalest, least-reinforced candidates first.
+fn sweep_memory_candidates(state: &SharedState, target: usize) -> Vec<StateDelta> {
+    let excess = state.memory_candidates().len().saturating_sub(target);
+    let mut cands: Vec<_> = state.memory_candidates().values().collect();
+    cands.sort_by_key(|c| (c.last_reinforced_at.frame, c.reinforcement_count, c.id.clone()));
+    cands.into_iter()
+        .take(excess)
+        .map(|c| StateDelta::MemoryCandidateRemoved(c.id.clone()))
+        .collect()
+}
+
+/// Only terminal intents (Completed / Invalidated) are purged, oldest first.
+fn sweep_intents(state: &SharedState, target: usize) -> Vec<StateDelta> {
+    let excess = state.active_intents().len().saturating_sub(target);
+    if excess == 0 {
+        return Vec::new();
+    }
+    let mut terminal: Vec<_> = state.active_intents().values()
+        .filter(|i| matches!(i.status, IntentStatus::Completed | IntentStatus::Invalidated))
+        .collect();
+    terminal.sort_by_key(|i| (i.last_updated_at.frame, i.id.clone()));
+
+    let ids: Vec<_> = terminal.into_iter().take(excess).map(|i| i.id.clone()).collect();
+    if ids.is_empty() { Vec::new() } else { vec![StateDelta::LongHorizonIntentsPurged(ids)] }
+}
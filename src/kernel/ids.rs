@@ -0,0 +1,71 @@
+//! Deterministic Id Generation.
+//!
+//! Every id created inside `tick_step` (segments, intent candidates, memory candidates,
+//! topics, speech requests, tool calls, consent prompts) comes from the Reactor's
+//! `IdGenerator`. An id is a pure function of (session seed, tick, per-tick counter), so
+//! replaying the same events against the same seed reproduces the same ids.
+//!
+//! Random v4 UUIDs stay at the driver boundary only: the session seed itself
+//! (`from_entropy`, unless `ReactorConfig::id_seed` pins it) and ids minted by drivers
+//! or stores outside the tick.
+
+use uuid::Uuid;
+
+use crate::kernel::time::Tick;
+
+// splitmix64 constants
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+const MIX_1: u64 = 0xBF58_476D_1CE4_E5B9;
+const MIX_2: u64 = 0x94D0_49BB_1331_11EB;
+
+#[derive(Debug, Clone)]
+pub struct IdGenerator {
+    seed: u64,
+    tick: u64,
+    counter: u64,
+}
+
+fn splitmix64(mut z: u64) -> u64 {
+    z = z.wrapping_add(GOLDEN_GAMMA);
+    z = (z ^ (z >> 30)).wrapping_mul(MIX_1);
+    z = (z ^ (z >> 27)).wrapping_mul(MIX_2);
+    z ^ (z >> 31)
+}
+
+impl IdGenerator {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, tick: 0, counter: 0 }
+    }
+
+    /// Fresh random session seed (driver boundary).
+    pub fn from_entropy() -> Self {
+        Self::new(Uuid::new_v4().as_u64_pair().0)
+    }
+
+    /// Session seed; record it to replay a session.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Called once at the start of every tick: ids restart their counter per tick.
+    pub fn begin_tick(&mut self, tick: Tick) {
+        self.tick = tick.frame;
+        self.counter = 0;
+    }
+
+    /// Next id of this tick, formatted as a (version 4 layout) UUID.
+    pub fn next_uuid(&mut self) -> Uuid {
+        let base = splitmix64(self.seed ^ splitmix64(self.tick) ^ splitmix64(self.counter.wrapping_mul(GOLDEN_GAMMA)));
+        self.counter += 1;
+        let hi = splitmix64(base);
+        let lo = splitmix64(base ^ hi);
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&hi.to_be_bytes());
+        bytes[8..].copy_from_slice(&lo.to_be_bytes());
+        uuid::Builder::from_random_bytes(bytes).into_uuid()
+    }
+
+    pub fn next_id(&mut self) -> String {
+        self.next_uuid().to_string()
+    }
+}
@@ -1,13 +1,39 @@
 use super::types::*;
-use uuid::Uuid;
+use super::corrections::{CorrectionLedger, CorrectionsSnapshot};
+use super::clauses::{clause_symbol, split_clauses};
+use crate::kernel::ids::IdGenerator;
 use std::hash::{Hash, Hasher};
 use std::collections::hash_map::DefaultHasher;
 
-pub struct IntentArbitrator;
+// Config Constants
+// Confidence of a classification the user taught us (above every keyword heuristic but control)
+const LEARNED_CONFIDENCE: f32 = 0.92;
+// Imperatives beyond "turn on/off" and "play" (checked at the start of the utterance)
+const COMMAND_OPENERS: &[&str] = &["remind me", "set a ", "set an ", "add "];
+
+pub struct IntentArbitrator {
+    // User corrections, consulted before the keyword heuristics
+    ledger: CorrectionLedger,
+}
 
 impl IntentArbitrator {
+    /// Empty in-memory ledger.
     pub fn new() -> Self {
-        Self
+        Self::with_ledger(CorrectionLedger::new())
+    }
+
+    pub fn with_ledger(ledger: CorrectionLedger) -> Self {
+        Self { ledger }
+    }
+
+    pub fn ledger(&self) -> &CorrectionLedger {
+        &self.ledger
+    }
+
+    /// The user said `phrase_hash` meant `corrected`.
+    pub fn learn_correction(&mut self, phrase_hash: u64, corrected: IntentHypothesis) -> Option<CorrectionsSnapshot> {
+        self.ledger.record(phrase_hash, corrected);
+        self.ledger.snapshot()
     }
 
     /// Assess the incoming text and source symbol to update the IntentState.
@@ -16,11 +42,16 @@ impl IntentArbitrator {
     /// For MVP, we pass the *current state* context loosely or just logic inside.
     /// 
     /// heuristics:
+    /// - "switch to work context" -> SystemControl
+    /// - "quieter please" -> SystemControl
     /// - "?" -> Inquiry
     /// - "turn off", "play" -> Command
     /// - "um", "maybe" -> ThinkingAloud / Fragment
     /// - Short length -> Fragment
-    pub fn assess(&self, text: &str, symbol_id: &str, current_state: &IntentState) -> IntentState {
+    ///
+    /// A phrase the user corrected before skips the heuristics: the ledger's answer wins.
+    /// Several clauses that each stand as a Stable intent make a `Compound`.
+    pub fn assess(&self, text: &str, symbol_id: &str, current_state: &IntentState, ids: &mut IdGenerator) -> IntentState {
         // Refinement 1: Suspended Protection
         if let IntentState::Suspended(existing) = current_state {
             // For MVP: If new text is short/noise, keep Suspended.
@@ -31,13 +62,76 @@ impl IntentArbitrator {
             // Else, fall through to re-assessment (Reinforcement)
         }
 
+        // A learned phrase is read as a whole
+        if self.ledger.lookup(text).is_none() {
+            if let Some(compound) = self.assess_compound(text, symbol_id, ids) {
+                return compound;
+            }
+        }
+        self.assess_clause(text, symbol_id, ids)
+    }
+
+    /// Every clause Stable on its own, none of them a control command; otherwise `None`.
+    fn assess_compound(&self, text: &str, symbol_id: &str, ids: &mut IdGenerator) -> Option<IntentState> {
+        let clauses = split_clauses(text);
+        if clauses.len() < 2 {
+            return None;
+        }
+        let mut declared = Vec::with_capacity(clauses.len());
+        for (index, clause) in clauses.iter().enumerate() {
+            match self.assess_clause(clause, &clause_symbol(symbol_id, index), ids) {
+                IntentState::Stable(c) if c.hypothesis != IntentHypothesis::SystemControl => declared.push(c),
+                _ => return None,
+            }
+        }
+        Some(IntentState::Compound(declared))
+    }
+
+    // Heuristic classification of one clause (or a whole utterance)
+    fn assess_clause(&self, text: &str, symbol_id: &str, ids: &mut IdGenerator) -> IntentState {
         let text_lower = text.to_lowercase();
         let mut candidates = Vec::new();
 
+        // Learned: the user told us what this phrasing means
+        if let Some(hypothesis) = self.ledger.lookup(text) {
+            let stability = match hypothesis {
+                IntentHypothesis::ThinkingAloud | IntentHypothesis::Fragment => IntentStability::Ambiguous,
+                _ => IntentStability::Stable,
+            };
+            candidates.push(IntentCandidate {
+                id: ids.next_id(),
+                hypothesis: hypothesis.clone(),
+                confidence: LEARNED_CONFIDENCE,
+                source_symbol_ids: vec![symbol_id.to_string()],
+                semantic_hash: {
+                    let mut h = DefaultHasher::new();
+                    text.hash(&mut h);
+                    h.finish()
+                },
+                stability,
+            });
+        }
+        // 0. Detect System Control (context switch, playback volume). Handled by the kernel, not the planner.
+        else if crate::kernel::context::parse_switch(&text_lower).is_some() || crate::outputs::loudness::parse_volume_request(&text_lower).is_some() {
+            candidates.push(IntentCandidate {
+                id: ids.next_id(),
+                hypothesis: IntentHypothesis::SystemControl,
+                confidence: 0.95,
+                source_symbol_ids: vec![symbol_id.to_string()],
+                semantic_hash: {
+                    let mut h = DefaultHasher::new();
+                    text.hash(&mut h);
+                    h.finish()
+                },
+                stability: IntentStability::Stable,
+            });
+        }
         // 1. Detect Command (Action)
-        if text_lower.contains("turn on") || text_lower.contains("turn off") || text_lower.starts_with("play") {
+        else if text_lower.contains("turn on") || text_lower.contains("turn off") || text_lower.starts_with("play")
+            || COMMAND_OPENERS.iter().any(|o| text_lower.starts_with(o))
+        {
             candidates.push(IntentCandidate {
-                id: Uuid::new_v4().to_string(),
+                id: ids.next_id(),
                 hypothesis: IntentHypothesis::Command,
                 confidence: 0.9,
                 source_symbol_ids: vec![symbol_id.to_string()],
@@ -54,7 +148,7 @@ impl IntentArbitrator {
             // Check for ambiguity
             if text_lower.contains("maybe") || text_lower.len() < 10 {
                 candidates.push(IntentCandidate {
-                    id: Uuid::new_v4().to_string(),
+                    id: ids.next_id(),
                     hypothesis: IntentHypothesis::Inquiry,
                     confidence: 0.6,
                     source_symbol_ids: vec![symbol_id.to_string()],
@@ -67,7 +161,7 @@ impl IntentArbitrator {
                 });
             } else {
                 candidates.push(IntentCandidate {
-                    id: Uuid::new_v4().to_string(),
+                    id: ids.next_id(),
                     hypothesis: IntentHypothesis::Inquiry,
                     confidence: 0.85,
                     source_symbol_ids: vec![symbol_id.to_string()],
@@ -83,7 +177,7 @@ impl IntentArbitrator {
         // 3. Detect Thinking Aloud / Fragment
         else if text_lower.contains("um") || text_lower.contains("uh") || text_lower.len() < 5 {
              candidates.push(IntentCandidate {
-                id: Uuid::new_v4().to_string(),
+                id: ids.next_id(),
                 hypothesis: IntentHypothesis::ThinkingAloud,
                 confidence: 0.7,
                 source_symbol_ids: vec![symbol_id.to_string()],
@@ -98,7 +192,7 @@ impl IntentArbitrator {
         // 4. Default: Statement
         else {
              candidates.push(IntentCandidate {
-                id: Uuid::new_v4().to_string(),
+                id: ids.next_id(),
                 hypothesis: IntentHypothesis::Statement,
                 confidence: 0.5, // Low confidence by default
                 source_symbol_ids: vec![symbol_id.to_string()],
@@ -132,7 +226,7 @@ impl IntentArbitrator {
             
             IntentState::Suspended(_) => DialogueAct::StaySilent, // Silence while suspended
             
-            IntentState::Stable(_) => {
+            IntentState::Stable(_) | IntentState::Compound(_) => {
                 // Handoff to Planner. Do NOT speak.
                 DialogueAct::Wait 
             },
@@ -148,7 +242,7 @@ impl IntentArbitrator {
                         _ => {
                             if best.confidence > 0.5 && best.stability == IntentStability::Unstable {
                                 // Rule: Non-leading clarification
-                                DialogueAct::AskClarification("Do you want me to respond?".to_string())
+                                DialogueAct::AskClarification(crate::outputs::phrases::CLARIFICATION_VARIANTS[0].to_string())
                             } else {
                                 DialogueAct::StaySilent
                             }
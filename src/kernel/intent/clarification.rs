@@ -0,0 +1,108 @@
+//! Clarification Rate Limiting.
+//!
+//! The arbitrator asks a non-leading clarification whenever the leading Forming candidate is
+//! fairly confident but not Stable. Noisy transcription can keep one utterance Forming for many
+//! fragments, and each fragment would ask again: an interrogation loop.
+//!
+//! `ClarificationState` bounds the questions per forming intent: at most `per_hash` for the
+//! leading candidate's semantic hash, and at most `per_episode` while the state stays Forming
+//! on one segment (re-transcriptions change the hash). Past either limit the act becomes
+//! `after_limit`: stay silent, or `Wait` (hand off to the planner). The counts reset when the
+//! state leaves Forming, or when the leading candidate comes from another segment (a new
+//! utterance, as in `IntentHysteresis`).
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{DialogueAct, IntentState};
+
+// Config Constants
+pub const DEFAULT_CLARIFICATIONS_PER_HASH: u32 = 1;
+pub const DEFAULT_CLARIFICATIONS_PER_EPISODE: u32 = 2;
+
+/// What a clarification past the limit turns into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ClarificationFallback {
+    /// `DialogueAct::StaySilent`
+    Silence,
+    /// `DialogueAct::Wait`: the planner decides
+    #[default]
+    Planner,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClarificationLimits {
+    pub per_hash: u32,
+    pub per_episode: u32,
+    pub after_limit: ClarificationFallback,
+}
+
+impl Default for ClarificationLimits {
+    fn default() -> Self {
+        Self {
+            per_hash: DEFAULT_CLARIFICATIONS_PER_HASH,
+            per_episode: DEFAULT_CLARIFICATIONS_PER_EPISODE,
+            after_limit: ClarificationFallback::default(),
+        }
+    }
+}
+
+/// Clarifications asked in the current Forming episode.
+#[derive(Debug, Default)]
+pub struct ClarificationState {
+    asked: HashMap<u64, u32>,
+    episode: u32,
+    // Segment (symbol id) of the leading candidate the counts belong to
+    segment: Option<String>,
+    /// Clarifications replaced by the fallback this session
+    pub suppressed: u64,
+}
+
+impl ClarificationState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn reset(&mut self) {
+        self.asked.clear();
+        self.episode = 0;
+        self.segment = None;
+    }
+
+    /// Clarifications asked for this semantic hash in the current episode.
+    pub fn asked(&self, semantic_hash: u64) -> u32 {
+        self.asked.get(&semantic_hash).copied().unwrap_or(0)
+    }
+
+    /// Apply the limits to the act decided for `state`. An admitted clarification is counted.
+    pub fn admit(&mut self, act: DialogueAct, state: &IntentState, limits: &ClarificationLimits) -> DialogueAct {
+        let IntentState::Forming(candidates) = state else {
+            self.reset();
+            return act;
+        };
+        if !matches!(act, DialogueAct::AskClarification(_)) {
+            return act;
+        }
+        // The arbitrator asks about the highest-confidence candidate
+        let leading = candidates.iter()
+            .max_by(|a, b| a.confidence.partial_cmp(&b.confidence).unwrap_or(std::cmp::Ordering::Equal));
+        let segment = leading.and_then(|c| c.source_symbol_ids.first().cloned());
+        if segment != self.segment {
+            self.reset();
+            self.segment = segment;
+        }
+        let hash = leading.map(|c| c.semantic_hash).unwrap_or_default();
+        let count = self.asked.entry(hash).or_default();
+        if *count >= limits.per_hash || self.episode >= limits.per_episode {
+            self.suppressed += 1;
+            return match limits.after_limit {
+                ClarificationFallback::Silence => DialogueAct::StaySilent,
+                ClarificationFallback::Planner => DialogueAct::Wait,
+            };
+        }
+        *count += 1;
+        self.episode += 1;
+        act
+    }
+}
@@ -0,0 +1,65 @@
+//! Clause Segmentation (compound utterances).
+//!
+//! "Turn off the lights and remind me to call mom" carries two intents. The transcript is cut
+//! at a connector ("and", "then", "also", a comma or a sentence end) only when the next word
+//! opens a new clause: an imperative verb or a question word. "Salt and pepper" stays whole.
+//!
+//! Segmentation is conservative: the arbitrator only keeps the split when every clause
+//! classifies as Stable on its own (see `IntentState::Compound`).
+
+use super::types::SymbolId;
+
+// Connectors between clauses (", and then" is "and" + "then")
+const CONNECTORS: &[&str] = &["and", "then", "also"];
+// Words that open an independent clause
+const CLAUSE_OPENERS: &[&str] = &[
+    "turn", "play", "remind", "set", "add", "call", "what", "what's", "how", "when", "where", "who", "why", "can", "could",
+];
+// A clause needs at least this many words before it can be cut
+const MIN_CLAUSE_WORDS: usize = 2;
+
+fn bare(word: &str) -> String {
+    word.trim_matches(|c: char| c.is_ascii_punctuation() && c != '\'').to_lowercase()
+}
+
+/// PURE FUNCTION: Clauses of `text`, in spoken order (a single element when it does not split).
+pub fn split_clauses(text: &str) -> Vec<String> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let mut clauses = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    let mut i = 0;
+    while i < words.len() {
+        // Connector run after this point ("and", ", then", "and also")
+        let mut next = i;
+        while next < words.len() && CONNECTORS.contains(&bare(words[next]).as_str()) {
+            next += 1;
+        }
+        let sentence_end = current.last().is_some_and(|w| w.ends_with([',', ';', '.', '?', '!']));
+        let cut = (next > i || sentence_end)
+            && current.len() >= MIN_CLAUSE_WORDS
+            && words.get(next).is_some_and(|w| CLAUSE_OPENERS.contains(&bare(w).as_str()));
+        if cut {
+            clauses.push(clause(&current));
+            current.clear();
+            i = next;
+            continue;
+        }
+        current.push(words[i]);
+        i += 1;
+    }
+    if !current.is_empty() {
+        clauses.push(clause(&current));
+    }
+    clauses
+}
+
+// Words of a clause, without the comma or semicolon that ended it
+fn clause(words: &[&str]) -> String {
+    words.join(" ").trim_end_matches([',', ';']).to_string()
+}
+
+/// Symbol of clause `index` of segment `symbol_id`: the first clause keeps the segment id,
+/// later ones are suffixed, so each clause is its own long-horizon intent.
+pub fn clause_symbol(symbol_id: &str, index: usize) -> SymbolId {
+    if index == 0 { symbol_id.to_string() } else { format!("{}#{}", symbol_id, index + 1) }
+}
@@ -0,0 +1,157 @@
+//! Correction Ledger: learned intent classifications.
+//!
+//! When the user corrects a misunderstanding ("No, I was asking a question"), the kernel
+//! records phrase hash -> corrected hypothesis. The arbitrator consults the ledger before
+//! its keyword heuristics, so the same phrasing is understood next time.
+//!
+//! Only hashes of normalised phrases are stored, never the words. The ledger persists to
+//! `nexus_corrections.json` (or the path in `NEXUS_CORRECTIONS`) and keeps the newest
+//! `MAX_ENTRIES` corrections. Corrections change it in memory; the driver writes it back
+//! (`SideEffect::PersistCorrections`).
+
+use std::hash::{Hash, Hasher};
+use std::collections::hash_map::DefaultHasher;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use super::types::IntentHypothesis;
+
+// Config Constants
+const LEDGER_FILE: &str = "nexus_corrections.json";
+pub const MAX_ENTRIES: usize = 256;
+/// A correction must follow the misread phrase within this many ticks (10s @ 20ms).
+pub const CORRECTION_WINDOW_TICKS: u64 = 500;
+
+// Checked in order: negations ("not a question") before the positive forms they contain.
+const CORRECTION_PHRASES: &[(&str, IntentHypothesis)] = &[
+    ("wasn't a question", IntentHypothesis::Statement),
+    ("not a question", IntentHypothesis::Statement),
+    ("was a statement", IntentHypothesis::Statement),
+    ("just telling you", IntentHypothesis::Statement),
+    ("just saying", IntentHypothesis::Statement),
+    ("was a question", IntentHypothesis::Inquiry),
+    ("was asking", IntentHypothesis::Inquiry),
+    ("meant it as a question", IntentHypothesis::Inquiry),
+    ("was a command", IntentHypothesis::Command),
+    ("meant it as a command", IntentHypothesis::Command),
+    ("was an instruction", IntentHypothesis::Command),
+    ("thinking out loud", IntentHypothesis::ThinkingAloud),
+    ("thinking aloud", IntentHypothesis::ThinkingAloud),
+    ("talking to myself", IntentHypothesis::ThinkingAloud),
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CorrectionEntry {
+    pub phrase_hash: u64,
+    pub hypothesis: IntentHypothesis,
+}
+
+/// The whole ledger as it goes to disk. Drivers `save()` it off the tick.
+#[derive(Debug, Clone)]
+pub struct CorrectionsSnapshot {
+    path: PathBuf,
+    entries: Vec<CorrectionEntry>,
+}
+
+impl CorrectionsSnapshot {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Blocking write.
+    pub fn save(&self) -> anyhow::Result<()> {
+        std::fs::write(&self.path, serde_json::to_string_pretty(&self.entries)?)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CorrectionLedger {
+    // Oldest first
+    entries: Vec<CorrectionEntry>,
+    // None: in memory only
+    path: Option<PathBuf>,
+}
+
+/// Ledger location (`NEXUS_CORRECTIONS` overrides the working-directory default).
+pub fn ledger_path() -> PathBuf {
+    std::env::var("NEXUS_CORRECTIONS").map(PathBuf::from).unwrap_or_else(|_| PathBuf::from(LEDGER_FILE))
+}
+
+fn normalise(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// PURE FUNCTION: Ledger key for a phrase. Case and punctuation don't matter.
+pub fn phrase_hash(text: &str) -> u64 {
+    let mut h = DefaultHasher::new();
+    normalise(text).hash(&mut h);
+    h.finish()
+}
+
+/// PURE FUNCTION: The hypothesis a correction asks for ("No, I was asking" -> Inquiry).
+/// `None` for a bare "no": that rejects the answer, not the classification.
+pub fn corrected_hypothesis(text: &str) -> Option<IntentHypothesis> {
+    let text = format!(" {} ", normalise(text));
+    CORRECTION_PHRASES.iter()
+        .find(|(phrase, _)| text.contains(&format!(" {} ", phrase)))
+        .map(|(_, hypothesis)| hypothesis.clone())
+}
+
+impl CorrectionLedger {
+    /// In memory only (tests, ephemeral sessions).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load `path` if present; `snapshot()` writes changes back to it.
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        let entries = if path.exists() {
+            serde_json::from_str(&std::fs::read_to_string(path)?)?
+        } else {
+            Vec::new()
+        };
+        Ok(Self { entries, path: Some(path.to_path_buf()) })
+    }
+
+    /// Production default: the ledger file, or an empty in-memory ledger if it cannot be read.
+    pub fn from_default_path() -> Self {
+        let path = ledger_path();
+        Self::open(&path).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load correction ledger {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+
+    pub fn lookup(&self, text: &str) -> Option<&IntentHypothesis> {
+        let hash = phrase_hash(text);
+        self.entries.iter().find(|e| e.phrase_hash == hash).map(|e| &e.hypothesis)
+    }
+
+    /// Learn (or re-learn) a phrase, in memory (see `snapshot`).
+    /// The newest correction wins; the oldest is dropped when full.
+    pub fn record(&mut self, phrase_hash: u64, hypothesis: IntentHypothesis) {
+        self.entries.retain(|e| e.phrase_hash != phrase_hash);
+        if self.entries.len() >= MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.entries.push(CorrectionEntry { phrase_hash, hypothesis });
+    }
+
+    /// What to write back (`None`: in memory only).
+    pub fn snapshot(&self) -> Option<CorrectionsSnapshot> {
+        self.path.as_ref().map(|path| CorrectionsSnapshot { path: path.clone(), entries: self.entries.clone() })
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
@@ -0,0 +1,125 @@
+//! Intent Hysteresis.
+//!
+//! Successive transcription fragments of one utterance can flip the arbitrator's verdict
+//! between Forming and Stable on every assessment. Every rising edge into Stable triggers
+//! memory ingest and LHIM registration, so flapping creates duplicate intents.
+//!
+//! The filter sits between `IntentArbitration::assess` and the state update. Evidence is
+//! per segment (one utterance); a fragment from another segment is a new utterance.
+//! - Stable is declared at once when the candidate is confident (`DECLARE_CONFIDENCE`),
+//!   otherwise after `CONSISTENT_ASSESSMENTS` consecutive Stable verdicts for the same hypothesis.
+//! - Stable is revoked only after `CONSISTENT_ASSESSMENTS` consecutive non-Stable verdicts
+//!   from the segment that produced it.
+//! - A re-transcription of the current Stable intent (same segment, hypothesis and semantic
+//!   hash) is not a new edge.
+//! - A compound is declared at once (every clause is already Stable); a re-transcription of
+//!   the same clauses is not a new edge. Its first clause counts as held for revocation.
+//!
+//! Typed text is final, not a fragment: the Reactor resets the filter instead of using it.
+
+use super::types::{IntentCandidate, IntentHypothesis, IntentState, SymbolId};
+
+// Config Constants
+/// Stable verdicts at or above this confidence are declared without waiting.
+pub const DECLARE_CONFIDENCE: f32 = 0.8;
+/// Consecutive consistent assessments needed to declare (below the margin) or revoke Stable.
+pub const CONSISTENT_ASSESSMENTS: u8 = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Pending {
+    Declare { source_id: SymbolId, hypothesis: IntentHypothesis, count: u8 },
+    Revoke { source_id: SymbolId, count: u8 },
+}
+
+/// Outcome of one assessment.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Settled {
+    /// Commit this state. `became_stable` is the rising edge: ingest and register exactly once.
+    Changed { state: IntentState, became_stable: bool },
+    /// Keep the current state (held by hysteresis, or a repeat of the Stable intent).
+    Held,
+}
+
+#[derive(Debug, Default)]
+pub struct IntentHysteresis {
+    pending: Option<Pending>,
+}
+
+impl IntentHysteresis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget partial evidence (e.g. typed input, which is never a fragment).
+    pub fn reset(&mut self) {
+        self.pending = None;
+    }
+
+    /// Filter the arbitrator's verdict on a fragment of segment `source_id` against the current state.
+    pub fn settle(&mut self, current: &IntentState, assessed: IntentState, source_id: &str) -> Settled {
+        let held = match current {
+            IntentState::Stable(existing) if existing.source_symbol_ids.iter().any(|s| s == source_id) => Some(existing),
+            IntentState::Compound(clauses) => clauses.first().filter(|c| c.source_symbol_ids.iter().any(|s| s == source_id)),
+            _ => None,
+        };
+        match assessed {
+            IntentState::Stable(candidate) => self.settle_stable(held, candidate, source_id),
+            IntentState::Compound(clauses) => {
+                self.pending = None;
+                let same = |a: &[IntentCandidate]| {
+                    a.len() == clauses.len()
+                        && a.iter().zip(&clauses).all(|(x, y)| x.hypothesis == y.hypothesis && x.semantic_hash == y.semantic_hash)
+                };
+                match current {
+                    IntentState::Compound(existing) if held.is_some() && same(existing) => Settled::Held,
+                    _ => Settled::Changed { state: IntentState::Compound(clauses), became_stable: true },
+                }
+            }
+            other => {
+                if held.is_none() {
+                    self.pending = None;
+                    return Settled::Changed { state: other, became_stable: false };
+                }
+                let count = match &self.pending {
+                    Some(Pending::Revoke { source_id: s, count }) if s == source_id => count + 1,
+                    _ => 1,
+                };
+                if count >= CONSISTENT_ASSESSMENTS {
+                    self.pending = None;
+                    Settled::Changed { state: other, became_stable: false }
+                } else {
+                    self.pending = Some(Pending::Revoke { source_id: source_id.to_string(), count });
+                    Settled::Held
+                }
+            }
+        }
+    }
+
+    fn settle_stable(&mut self, held: Option<&IntentCandidate>, candidate: IntentCandidate, source_id: &str) -> Settled {
+        if let Some(existing) = held {
+            if existing.hypothesis == candidate.hypothesis && existing.semantic_hash == candidate.semantic_hash {
+                self.pending = None;
+                return Settled::Held;
+            }
+        }
+        if candidate.confidence >= DECLARE_CONFIDENCE {
+            self.pending = None;
+            return Settled::Changed { state: IntentState::Stable(candidate), became_stable: true };
+        }
+
+        let count = match &self.pending {
+            Some(Pending::Declare { source_id: s, hypothesis, count }) if s == source_id && *hypothesis == candidate.hypothesis => count + 1,
+            _ => 1,
+        };
+        if count >= CONSISTENT_ASSESSMENTS {
+            self.pending = None;
+            return Settled::Changed { state: IntentState::Stable(candidate), became_stable: true };
+        }
+        self.pending = Some(Pending::Declare { source_id: source_id.to_string(), hypothesis: candidate.hypothesis.clone(), count });
+        match held {
+            // A weak new verdict does not displace the Stable intent of the same utterance
+            Some(_) => Settled::Held,
+            None => Settled::Changed { state: IntentState::Forming(vec![candidate]), became_stable: false },
+        }
+    }
+}
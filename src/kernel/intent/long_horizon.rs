@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::kernel::time::Tick;
 use crate::kernel::state::{SharedState, StateDelta};
 use crate::kernel::intent::types::{IntentCandidate, IntentHypothesis};
+use crate::kernel::context::ContextId;
 use std::collections::HashMap;
 use crate::kernel::telemetry::recorder::TelemetryRecorder;
 use crate::kernel::telemetry::event::TelemetryEvent;
@@ -29,29 +30,117 @@ pub struct LongHorizonIntent {
     pub suspended_at: Option<Tick>,
     pub decay_score: f32,        // 1.0 -> 0.0
     pub status: IntentStatus,
+    // Conversation context the intent was formed in. Parked contexts are frozen.
+    #[serde(default)]
+    pub context: ContextId,
 }
 
 // Config Constants
-const DECAY_RATE_PER_TICK: f32 = 0.9997; // Very slow decay
+const DECAY_HALF_LIFE_TICKS: f32 = 2310.0; // Very slow decay (~0.9997 per tick, 46s @ 20ms)
 const DORMANCY_THRESHOLD: f32 = 0.3;
 const RESUME_THRESHOLD: f32 = 0.6; // Lower score, but context match boosts confidence
 const INVALIDATION_THRESHOLD: f32 = 0.1; // Hard kill line
+const LONG_DORMANCY_TICKS: u64 = 1500; // 30s @ 20ms. Beyond this, resumption is announced.
+const REGISTRATION_WINDOW_TICKS: u64 = 500; // 10s @ 20ms. Re-transcriptions arrive well within this.
+const BOARD_REPORT_INTERVAL_TICKS: u64 = 3000; // 60s @ 20ms
+/// Upper bounds of the age buckets (ticks since creation): 1 min, 10 min, 1 h. The last bucket is older.
+pub const INTENT_AGE_BUCKETS_TICKS: [u64; 3] = [3_000, 30_000, 180_000];
+
+/// Intents of one status, counted by age (`INTENT_AGE_BUCKETS_TICKS`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntentAges(pub [usize; 4]);
+
+impl IntentAges {
+    pub fn count(&self) -> usize {
+        self.0.iter().sum()
+    }
+
+    fn add(&mut self, age_ticks: u64) {
+        let i = INTENT_AGE_BUCKETS_TICKS.iter().position(|bound| age_ticks < *bound).unwrap_or(INTENT_AGE_BUCKETS_TICKS.len());
+        self.0[i] += 1;
+    }
+}
+
+/// Gauge of the intent board: live intents by status and age.
+/// A dormant set that keeps growing (and ageing) over a long session is a leak.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntentBoard {
+    pub active: IntentAges,
+    pub suspended: IntentAges,
+    pub dormant: IntentAges,
+}
+
+/// A recent registration. A later candidate from one of the same segments is the same
+/// utterance (re-delivered or re-transcribed), not a new intent. The same words in another
+/// segment are said again, and are a new intent.
+#[derive(Debug, Clone)]
+struct Registration {
+    intent_id: IntentId,
+    source_symbol_ids: Vec<String>,
+    at: Tick,
+}
+
+/// A resumption that happened after long dormancy.
+/// The user may have forgotten the thread, so the Reactor may re-ground it.
+#[derive(Debug, Clone)]
+pub struct ResumptionNotice {
+    pub intent_id: IntentId,
+    pub hypothesis: IntentHypothesis,
+    pub dormant_ticks: u64,
+    // The system was cut off while pursuing this intent
+    pub was_interrupted: bool,
+}
 
 pub struct LongHorizonIntentManager {
     pub active_intents: HashMap<IntentId, LongHorizonIntent>,
+    // Long-dormancy resumptions since last drain (see `take_resumptions`)
+    pub pending_resumptions: Vec<ResumptionNotice>,
+    // Idempotent registration: one utterance maps to at most one intent
+    recent_registrations: Vec<Registration>,
+    // Frame of the last `IntentBoard` report
+    last_board_report: Option<u64>,
 }
 
 impl LongHorizonIntentManager {
     pub fn new() -> Self {
         Self {
             active_intents: HashMap::new(),
+            pending_resumptions: Vec::new(),
+            recent_registrations: Vec::new(),
+            last_board_report: None,
+        }
+    }
+
+    /// The board as of `current_tick`. Completed and invalidated intents are not counted.
+    pub fn board(&self, current_tick: Tick) -> IntentBoard {
+        let mut board = IntentBoard::default();
+        for intent in self.active_intents.values() {
+            let ages = match intent.status {
+                IntentStatus::Active => &mut board.active,
+                IntentStatus::Suspended => &mut board.suspended,
+                IntentStatus::Dormant => &mut board.dormant,
+                IntentStatus::Completed | IntentStatus::Invalidated => continue,
+            };
+            ages.add(current_tick.frame.saturating_sub(intent.created_at.frame));
         }
+        board
+    }
+
+    /// Drain resumptions that followed long dormancy (> LONG_DORMANCY_TICKS).
+    pub fn take_resumptions(&mut self) -> Vec<ResumptionNotice> {
+        std::mem::take(&mut self.pending_resumptions)
     }
 
     /// Register a Stable Phase G intent as a Long-Horizon Intent.
     /// If an equivalent intent is Suspended/Dormant, reinforce and resume it.
+    /// A re-transcription of a recently registered utterance updates that intent instead.
     /// Else create new.
-    pub fn register_intent(&mut self, candidate: &IntentCandidate, _state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+    pub fn register_intent(&mut self, candidate: &IntentCandidate, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        self.recent_registrations.retain(|r| current_tick.frame.saturating_sub(r.at.frame) <= REGISTRATION_WINDOW_TICKS);
+        if let Some(delta) = self.reregister(candidate, current_tick, telemetry) {
+            return vec![delta];
+        }
+
         let mut deltas = Vec::new();
         // Check if we have an existing intent with same ID
         // Or if this is a "Reinforcement"
@@ -84,8 +173,14 @@ impl LongHorizonIntentManager {
                 suspended_at: None,
                 decay_score: 1.0, // Fresh
                 status: IntentStatus::Active,
+                context: state.active_context().clone(),
             };
             self.active_intents.insert(new_intent.id.clone(), new_intent.clone());
+            self.recent_registrations.push(Registration {
+                intent_id: new_intent.id.clone(),
+                source_symbol_ids: candidate.source_symbol_ids.clone(),
+                at: current_tick,
+            });
             deltas.push(StateDelta::LongHorizonIntentUpdate(new_intent.clone()));
             
             // TELEMETRY: Created
@@ -99,6 +194,39 @@ impl LongHorizonIntentManager {
         deltas
     }
 
+    /// Same utterance as a registration within the window: refresh that intent.
+    /// The latest transcription wins (a correction may change the hypothesis).
+    /// Only segment overlap decides; a matching hash from another segment is a repeat.
+    fn reregister(&mut self, candidate: &IntentCandidate, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Option<StateDelta> {
+        let registration = self.recent_registrations.iter_mut().find(|r| {
+            r.intent_id != candidate.id
+                && candidate.source_symbol_ids.iter().any(|s| r.source_symbol_ids.contains(s))
+        })?;
+        let intent = self.active_intents.get_mut(&registration.intent_id)?;
+        registration.at = current_tick;
+
+        let old_status = intent.status;
+        intent.hypothesis = candidate.hypothesis.clone();
+        for s_id in &candidate.source_symbol_ids {
+            if !intent.source_symbol_ids.contains(s_id) {
+                intent.source_symbol_ids.push(s_id.clone());
+            }
+        }
+        intent.status = IntentStatus::Active;
+        intent.suspended_at = None;
+        intent.decay_score = 1.0;
+        intent.last_active_at = current_tick;
+        intent.last_updated_at = current_tick;
+        if old_status != IntentStatus::Active {
+            telemetry.record(TelemetryEvent::IntentLifecycle {
+                intent_id: intent.id.clone(),
+                from: old_status,
+                to: IntentStatus::Active,
+            });
+        }
+        Some(StateDelta::LongHorizonIntentUpdate(intent.clone()))
+    }
+
     /// Suspend an specific intent (safe).
     pub fn suspend_intent(&mut self, id: &IntentId, _state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Option<StateDelta> {
          if let Some(intent) = self.active_intents.get_mut(id) {
@@ -153,7 +281,7 @@ impl LongHorizonIntentManager {
         // Look for "Forming" intents in current context
         // If they match a Suspended/Dormant intent, Resume it.
         
-        if let crate::kernel::intent::types::IntentState::Forming(candidates) = &state.intent_state {
+        if let crate::kernel::intent::types::IntentState::Forming(candidates) = state.intent_state() {
              // Check over candidates
              for fc in candidates {
                  // Check if any Suspended intent matches this candidate's Symbols or Semantics
@@ -161,6 +289,8 @@ impl LongHorizonIntentManager {
                  
                  for susp in self.active_intents.values() 
                      .filter(|i| i.status == IntentStatus::Suspended || i.status == IntentStatus::Dormant )
+                     // Never resume into another conversation
+                     .filter(|i| &i.context == state.active_context())
                  {
                      // Check symbol overlap
                      for s_id in &fc.source_symbol_ids {
@@ -183,9 +313,11 @@ impl LongHorizonIntentManager {
                       resumed.suspended_at = None;
                       resumed.last_active_at = current_tick;
                       // Boost score slightly?
-                      resumed.decay_score = (resumed.decay_score + 0.1).min(1.0);
+                      resumed.decay_score = crate::kernel::decay::unit(resumed.decay_score + 0.1);
                       resumed.last_updated_at = current_tick;
                       
+                      // Write back, otherwise the same intent re-resumes (and re-announces) every tick
+                      self.active_intents.insert(resumed.id.clone(), resumed.clone());
                       deltas.push(StateDelta::LongHorizonIntentUpdate(resumed.clone()));
                       
                       // TELEMETRY: Resumption
@@ -198,6 +330,16 @@ impl LongHorizonIntentManager {
                           intent_id: resumed.id.clone(),
                           dormant_ticks: dormant_duration,
                       });
+
+                      if dormant_duration > LONG_DORMANCY_TICKS {
+                          self.pending_resumptions.push(ResumptionNotice {
+                              intent_id: resumed.id.clone(),
+                              hypothesis: resumed.hypothesis.clone(),
+                              dormant_ticks: dormant_duration,
+                              was_interrupted: state.interruptions().iter()
+                                  .any(|r| r.active_intent.as_ref() == Some(&resumed.id)),
+                          });
+                      }
                  }
              }
         }
@@ -205,9 +347,37 @@ impl LongHorizonIntentManager {
         deltas
     }
 
+    /// Resume one Suspended or Dormant intent on request (a bookmark was recalled).
+    /// Unlike `try_resume` no evidence is needed, but the intent must belong to the active
+    /// context and not have decayed away.
+    pub fn resume_intent(&mut self, id: &IntentId, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Option<StateDelta> {
+        let intent = self.active_intents.get_mut(id)?;
+        if !matches!(intent.status, IntentStatus::Suspended | IntentStatus::Dormant) || &intent.context != state.active_context() {
+            return None;
+        }
+        let old_status = intent.status;
+        let dormant_ticks = intent.suspended_at.map(|t| current_tick.frame.saturating_sub(t.frame)).unwrap_or(0);
+        intent.status = IntentStatus::Active;
+        intent.suspended_at = None;
+        intent.last_active_at = current_tick;
+        intent.decay_score = crate::kernel::decay::unit(intent.decay_score + 0.1);
+        intent.last_updated_at = current_tick;
+
+        telemetry.record(TelemetryEvent::IntentLifecycle {
+            intent_id: id.clone(),
+            from: old_status,
+            to: IntentStatus::Active,
+        });
+        telemetry.record(TelemetryEvent::IntentResumption {
+            intent_id: id.clone(),
+            dormant_ticks,
+        });
+        Some(StateDelta::LongHorizonIntentUpdate(intent.clone()))
+    }
+
     /// Apply Decay (Tick).
-    /// Monotonic: score *= rate^delta
-    pub fn tick(&mut self, current_tick: Tick, _state: &SharedState, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+    /// Monotonic: score halves every `DECAY_HALF_LIFE_TICKS` (`kernel::decay`)
+    pub fn tick(&mut self, current_tick: Tick, state: &SharedState, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
         let mut deltas = Vec::new();
         
         // Apply Monotonic Decay
@@ -225,16 +395,23 @@ impl LongHorizonIntentManager {
                     continue;
                 }
 
+                // Parked context: frozen. Time spent elsewhere does not count as decay.
+                if &intent.context != state.active_context() {
+                    intent.last_updated_at = current_tick;
+                    continue;
+                }
+
                 // Delta from LAST UPDATE (Per-Intent)
-                let delta = current_tick.frame.saturating_sub(intent.last_updated_at.frame) as f32;
+                let delta = current_tick.frame.saturating_sub(intent.last_updated_at.frame);
                 
                 // Check if we should apply decay this tick.
                 // "Active" intents decay? Yes, unless reinforced.
                 // "Suspended" intents decay? Yes.
                 
                 let mut new_intent = intent.clone();
-                if delta > 0.0 {
-                    new_intent.decay_score *= DECAY_RATE_PER_TICK.powf(delta); // Apply delta-based decay
+                if delta > 0 {
+                    // Delta-based decay; any jump (idle wake, hydration) lands in [0, score]
+                    new_intent.decay_score = crate::kernel::decay::exponential(new_intent.decay_score, delta, DECAY_HALF_LIFE_TICKS);
                     new_intent.last_updated_at = current_tick;
                 }
                 
@@ -277,6 +454,12 @@ impl LongHorizonIntentManager {
                 }
             }
         }
+
+        // Aging report: periodic, from the board itself (never read back from telemetry)
+        if self.last_board_report.is_none_or(|last| current_tick.frame.saturating_sub(last) >= BOARD_REPORT_INTERVAL_TICKS) {
+            self.last_board_report = Some(current_tick.frame);
+            telemetry.record(TelemetryEvent::IntentBoard { board: self.board(current_tick) });
+        }
         
         deltas
     }
@@ -298,8 +481,11 @@ impl LongHorizonIntentManager {
         // It doesn't store original confidence explicitly (legacy did).
         // Let's use `decay_score` as the strength of presence.
         
-        let best = state.active_intents.values()
+        let best = state.active_intents().values()
             .filter(|i| i.status == IntentStatus::Active)
+            .filter(|i| &i.context == state.active_context())
+            // Privacy: unconsented sensitive intents never reach the planner
+            .filter(|i| !state.sensitive_unconsented(&i.id))
             .max_by(|a, b| a.decay_score.partial_cmp(&b.decay_score).unwrap_or(std::cmp::Ordering::Equal));
             
         if let Some(i) = best {
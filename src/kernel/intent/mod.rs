@@ -1,3 +1,7 @@
 pub mod types;
 pub mod arbitrator;
+pub mod corrections;
+pub mod clauses;
+pub mod hysteresis;
+pub mod clarification;
 pub mod long_horizon;
@@ -17,6 +17,7 @@ pub enum IntentHypothesis {
     Command,       // User instructing Action
     Fragment,      // Incomplete thought
     ThinkingAloud, // Self-talk
+    SystemControl, // Kernel-level command (e.g. context switch). Never remembered.
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -37,6 +38,20 @@ pub enum IntentState {
     Stable(IntentCandidate),
     /// Interrupted but preserved. No output until reinforced.
     Suspended(IntentCandidate),
+    /// Several independent intents in one utterance, in spoken order. Every clause is Stable
+    /// and handed off like a `Stable` intent (see `intent::clauses`).
+    Compound(Vec<IntentCandidate>),
+}
+
+impl IntentState {
+    /// Intents ready for handoff: the Stable candidate, or every clause of a compound.
+    pub fn declared(&self) -> &[IntentCandidate] {
+        match self {
+            IntentState::Stable(c) => std::slice::from_ref(c),
+            IntentState::Compound(clauses) => clauses,
+            IntentState::None | IntentState::Forming(_) | IntentState::Suspended(_) => &[],
+        }
+    }
 }
 
 impl Default for IntentState {
@@ -0,0 +1,237 @@
+//! Event Journal: an append-only, on-disk record of what the kernel was fed.
+//!
+//! Unlike a `SessionLog` (kept in memory, written on shutdown), the journal is written as the
+//! session runs, one JSON line per entry, flushed per line: a crash loses at most the line being
+//! written. Entries are the id seed (`Start`), the persisted state the session resumed from
+//! (`Hydrated`, see `Reactor::hydrate`), the events fed to each non-idle tick (`Step`,
+//! idle steps in between are implied), the ticks a low-power wait skipped (`Idle`, see
+//! `Reactor::advance_idle`) and the tick of a clean shutdown (`End`). The CLI journals when
+//! `NEXUS_JOURNAL` names a file. Like a session recording, it contains what was said.
+//!
+//! `Reactor::replay` feeds a journal to a fresh Reactor and reconstructs `SharedState`: the same
+//! events on the same ticks with the same seed give the same state. Driver results (playback,
+//! transcriptions, plans) come back as events, so they are in the journal too; effects are not
+//! executed on replay.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::kernel::event::Event;
+use crate::kernel::persist::PersistedState;
+use crate::kernel::reactor::Reactor;
+use crate::kernel::time::Tick;
+
+// Config Constants
+/// Bumped when an entry changes shape; older journals are rejected, not misread.
+pub const JOURNAL_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum JournalEntry {
+    /// First line: format and kernel id seed (`ReactorConfig::id_seed`)
+    Start { version: u32, seed: u64 },
+    /// State restored before the first step (restart after a shutdown)
+    Hydrated(PersistedState),
+    /// Events fed to this tick
+    Step { tick: u64, events: Vec<Event> },
+    /// `ticks` passed without a step after tick `after` (low-power wait)
+    Idle { after: u64, ticks: u64 },
+    /// Clean shutdown after this tick
+    End { tick: u64 },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JournalError {
+    Io(String),
+    /// No `Start` line
+    Empty,
+    /// Written by another format version
+    Version(u32),
+    /// Unreadable line (1-based), other than a torn last line
+    Malformed { line: usize },
+    /// Entry on a tick the kernel is already past (1-based line)
+    OutOfOrder { line: usize },
+    /// `Reactor::replay` needs a fresh Reactor (tick 0)
+    NotFresh { tick: u64 },
+}
+
+/// Appends entries as the driver steps the kernel.
+/// Journaling never fails the driver: after a write error the journal stops (logged once).
+pub struct JournalWriter {
+    path: PathBuf,
+    out: Option<BufWriter<File>>,
+}
+
+impl JournalWriter {
+    /// Start a journal at `path` (replacing any file there) for a kernel seeded with `seed`.
+    pub fn create(path: &Path, seed: u64) -> std::io::Result<Self> {
+        let mut writer = Self { path: path.to_path_buf(), out: Some(BufWriter::new(File::create(path)?)) };
+        writer.append(&JournalEntry::Start { version: JOURNAL_VERSION, seed });
+        Ok(writer)
+    }
+
+    /// `NEXUS_JOURNAL=session.jsonl`: journal there (None if unset or not writable).
+    pub fn from_env(seed: u64) -> Option<Self> {
+        let path = PathBuf::from(std::env::var("NEXUS_JOURNAL").ok()?);
+        match Self::create(&path, seed) {
+            Ok(writer) => Some(writer),
+            Err(e) => {
+                warn!("Journal {} not written: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Record the state the kernel is about to be hydrated with (before any step).
+    pub fn hydrated(&mut self, state: &PersistedState) {
+        self.append(&JournalEntry::Hydrated(state.clone()));
+    }
+
+    /// Record the events fed to the tick about to run. Idle ticks are not stored.
+    pub fn step(&mut self, tick: Tick, events: &[Event]) {
+        if !events.is_empty() {
+            self.append(&JournalEntry::Step { tick: tick.frame, events: events.to_vec() });
+        }
+    }
+
+    /// Record `Reactor::advance_idle(ticks)` on a kernel at `now` (call before advancing).
+    pub fn idle(&mut self, now: Tick, ticks: u64) {
+        if ticks > 0 {
+            self.append(&JournalEntry::Idle { after: now.frame, ticks });
+        }
+    }
+
+    /// Clean shutdown: replay runs up to `tick` (a journal without `End` stops at its last entry).
+    pub fn end(mut self, tick: Tick) {
+        self.append(&JournalEntry::End { tick: tick.frame });
+    }
+
+    fn append(&mut self, entry: &JournalEntry) {
+        let Some(out) = self.out.as_mut() else { return };
+        let written = serde_json::to_string(entry)
+            .map_err(std::io::Error::other)
+            .and_then(|line| writeln!(out, "{}", line))
+            .and_then(|_| out.flush());
+        if let Err(e) = written {
+            warn!("Journal {} stopped: {}", self.path.display(), e);
+            self.out = None;
+        }
+    }
+}
+
+/// A journal read back for replay.
+#[derive(Debug, Clone)]
+pub struct Journal {
+    pub seed: u64,
+    /// Entries after `Start`, in order
+    pub entries: Vec<JournalEntry>,
+    /// The last line was cut off (crash mid-write) and left out
+    pub torn: bool,
+}
+
+impl Journal {
+    pub fn load(path: &Path) -> Result<Self, JournalError> {
+        Self::parse(&std::fs::read_to_string(path).map_err(|e| JournalError::Io(e.to_string()))?)
+    }
+
+    /// PURE FUNCTION: Journal lines -> entries, checked for version and tick order.
+    pub fn parse(text: &str) -> Result<Self, JournalError> {
+        let lines: Vec<&str> = text.lines().filter(|l| !l.trim().is_empty()).collect();
+        let mut journal: Option<Journal> = None;
+        // Kernel tick after the entries so far (what replay will have reached)
+        let mut reached = 0;
+        for (index, line) in lines.iter().enumerate() {
+            let number = index + 1;
+            let entry = match serde_json::from_str::<JournalEntry>(line) {
+                Ok(entry) => entry,
+                // Only the line being written when the process died may be cut off
+                Err(_) if number == lines.len() && !text.ends_with('\n') => {
+                    if let Some(journal) = journal.as_mut() {
+                        journal.torn = true;
+                    }
+                    break;
+                }
+                Err(_) => return Err(JournalError::Malformed { line: number }),
+            };
+            let Some(journal) = journal.as_mut() else {
+                match entry {
+                    JournalEntry::Start { version, .. } if version != JOURNAL_VERSION => return Err(JournalError::Version(version)),
+                    JournalEntry::Start { seed, .. } => journal = Some(Journal { seed, entries: Vec::new(), torn: false }),
+                    _ => return Err(JournalError::Empty),
+                }
+                continue;
+            };
+            let in_order = match &entry {
+                JournalEntry::Start { .. } => false,
+                JournalEntry::Hydrated(_) => journal.entries.is_empty(),
+                JournalEntry::Step { tick, .. } => *tick > reached,
+                JournalEntry::Idle { after, .. } | JournalEntry::End { tick: after } => *after >= reached,
+            };
+            if !in_order {
+                return Err(JournalError::OutOfOrder { line: number });
+            }
+            reached = match &entry {
+                JournalEntry::Step { tick, .. } => *tick,
+                JournalEntry::Idle { after, ticks } => after + ticks,
+                JournalEntry::Hydrated(state) => state.tick.frame,
+                _ => reached,
+            };
+            journal.entries.push(entry);
+        }
+        journal.ok_or(JournalError::Empty)
+    }
+
+    /// Tick a replay of this journal ends on.
+    pub fn last_tick(&self) -> Tick {
+        let frame = self.entries.iter().fold(0, |reached, entry| match entry {
+            JournalEntry::Step { tick, .. } | JournalEntry::End { tick } => (*tick).max(reached),
+            JournalEntry::Idle { after, ticks } => after + ticks,
+            JournalEntry::Hydrated(state) => state.tick.frame.max(reached),
+            JournalEntry::Start { .. } => reached,
+        });
+        Tick { frame }
+    }
+}
+
+/// Rebuild the journaled session on `reactor` (fresh, reseeded with the journal's seed, Decision
+/// Trace on). Effects are dropped: what the drivers did came back as events.
+/// The caller provides the Reactor so stores can be isolated (see `tests/journal_tests.rs`).
+pub fn replay(reactor: &mut Reactor, journal: &Journal) -> Result<Tick, JournalError> {
+    if reactor.tick.frame != 0 {
+        return Err(JournalError::NotFresh { tick: reactor.tick.frame });
+    }
+    reactor.ids = crate::kernel::ids::IdGenerator::new(journal.seed);
+    reactor.trace.enabled = true;
+
+    for entry in &journal.entries {
+        match entry {
+            JournalEntry::Step { tick, events } => {
+                step_idle_until(reactor, tick.saturating_sub(1));
+                reactor.tick_step(events.clone());
+            }
+            JournalEntry::Idle { after, ticks } => {
+                step_idle_until(reactor, *after);
+                reactor.advance_idle(*ticks);
+            }
+            JournalEntry::End { tick } => step_idle_until(reactor, *tick),
+            JournalEntry::Hydrated(state) => {
+                reactor.hydrate(state);
+            }
+            JournalEntry::Start { .. } => {}
+        }
+    }
+    Ok(reactor.tick)
+}
+
+fn step_idle_until(reactor: &mut Reactor, frame: u64) {
+    while reactor.tick.frame < frame {
+        reactor.tick_step(Vec::new());
+    }
+}
@@ -0,0 +1,67 @@
+//! Spoken Language: what the user speaks, and what Nexus answers in.
+//!
+//! The ASR backend detects the language of every segment (`Transcript::language`) and reports
+//! it with the text (`InputContent::ProvisionalText`). The segment keeps it, an intent declared
+//! from the segment is tagged with it (`StateDelta::IntentLanguageTagged`), and the last one
+//! heard is the spoken language. Replies follow it: phrase templates (if the table has the
+//! locale), the speech normalization of the TTS cache and the LLM speech prompt.
+//!
+//! Memory keys of an intent in another language than `BASE_LANGUAGE` are scoped to it
+//! (`MemoryKey::in_language`): the same hash from two languages is not the same memory.
+//!
+//! `InputContent::Language` (settings, `NEXUS_LANGUAGE` in the CLI) pins one language: ASR
+//! transcribes in it instead of detecting, and replies use it whatever was heard.
+//! Languages are ISO 639-1 codes ("en", "de").
+
+use serde::{Deserialize, Serialize};
+
+// Config Constants
+/// Memory keys are not scoped to this language: keys from before tagging stay valid.
+pub const BASE_LANGUAGE: &str = "en";
+
+/// Transcription and reply language: detected per segment, or pinned.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LanguagePreference {
+    #[default]
+    Auto,
+    Pinned(String),
+}
+
+impl LanguagePreference {
+    /// `NEXUS_LANGUAGE=de` pins German; unset, empty or `auto` detects.
+    pub fn from_env() -> Self {
+        Self::parse(&std::env::var("NEXUS_LANGUAGE").unwrap_or_default())
+    }
+
+    /// PURE FUNCTION: `auto` (or nothing) detects, anything else pins its language.
+    pub fn parse(value: &str) -> Self {
+        match normalize(value) {
+            Some(language) => LanguagePreference::Pinned(language),
+            None => LanguagePreference::Auto,
+        }
+    }
+
+    /// The pinned language (`None`: detect).
+    pub fn pinned(&self) -> Option<&str> {
+        match self {
+            LanguagePreference::Pinned(language) => Some(language),
+            LanguagePreference::Auto => None,
+        }
+    }
+}
+
+/// PURE FUNCTION: Language code as stored: lowercase ISO 639-1, region dropped ("de-DE" -> "de").
+/// `None` for an empty code or `auto`.
+pub fn normalize(code: &str) -> Option<String> {
+    let language = code.trim().split(['-', '_']).next().unwrap_or_default().to_ascii_lowercase();
+    if language.is_empty() || language == "auto" {
+        return None;
+    }
+    Some(language)
+}
+
+/// PURE FUNCTION: Language to answer in: the pin, else the language last heard, else `fallback`
+/// (the phrase templates' configured locale).
+pub fn reply_language(preference: &LanguagePreference, spoken: Option<&str>, fallback: &str) -> String {
+    preference.pinned().or(spoken).unwrap_or(fallback).to_string()
+}
@@ -26,6 +26,15 @@ impl LatentState {
         Self::default()
     }
     
+    /// Live slots recording activity on a channel: every audio slot, visual slots only for
+    /// a changed screen (`values[1]`). Faded slots (confidence 0.5 or less) do not count.
+    pub fn active(&self, modality: &Modality) -> usize {
+        self.slots.iter()
+            .filter(|s| &s.modality == modality && s.confidence > 0.5)
+            .filter(|s| *modality != Modality::Visual || s.values.get(1).is_some_and(|v| *v > 0.0))
+            .count()
+    }
+
     pub fn global_uncertainty(&self) -> f32 {
         if self.slots.is_empty() {
              // Or 1.0 (Max uncertainty)?
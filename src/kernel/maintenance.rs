@@ -0,0 +1,341 @@
+//! Maintenance Scheduler (nightly consolidation).
+//!
+//! Heavier jobs that should not compete with a conversation run together, at most once per
+//! `MAINTENANCE_INTERVAL_TICKS`, when the kernel is idle and either:
+//! - the driver-reported local time (`InputContent::LocalTime`) is inside
+//!   `MaintenanceConfig::window`, or
+//! - `MaintenanceConfig::when_dormant` is on and presence has been Dormant for
+//!   `MAINTENANCE_DORMANT_TICKS`.
+//!
+//! Jobs, in order: episodic -> semantic promotion, semantic store compaction, telemetry spool
+//! rotation, telemetry spool export. Each result is recorded as
+//! `LifecycleEvent::Maintenance` and the run is kept as a `MaintenanceSummary`.
+//!
+//! No job touches the disk inside the tick. The tick does a job's in-memory part (promotion,
+//! compaction, counting) and emits the rest as `SideEffect::RunMaintenance`; the driver runs
+//! that `MaintenanceTask` off the tick and answers with `InputContent::MaintenanceResult`.
+//! Jobs run one at a time: the next starts once the previous result is back.
+//!
+//! Maintenance mode (`KernelMode::Maintenance`): drivers send `InputContent::EnterMaintenance`
+//! for work that should not run under a live conversation (store migration after an upgrade,
+//! compaction on request). Planning and output are suspended while the jobs run, each result is
+//! reported as `SideEffect::MaintenanceProgress`, then the kernel returns to its previous mode.
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::presence::PresenceState;
+use crate::kernel::quiet::QuietHours;
+use crate::kernel::reactor::KernelMode;
+use crate::kernel::state::SharedState;
+use crate::kernel::telemetry::event::TelemetryEvent;
+use crate::kernel::time::Tick;
+use crate::memory::store::{FileSemanticStore, SemanticSnapshot, SemanticStore};
+
+// Config Constants
+/// At most one run per this many ticks (20h @ 20ms): "nightly".
+pub const MAINTENANCE_INTERVAL_TICKS: u64 = 3_600_000;
+/// Low activity: no user input for this long (5min @ 20ms).
+pub const MAINTENANCE_IDLE_TICKS: u64 = 15_000;
+/// Dormant trigger: presence has been Dormant this long (10min @ 20ms).
+pub const MAINTENANCE_DORMANT_TICKS: u64 = 30_000;
+/// Rotated telemetry spools kept next to the live one (`<spool>.1` is the newest).
+pub const SPOOL_GENERATIONS: usize = 3;
+/// A job with the driver that has not answered in this long failed (2min @ 20ms).
+pub const MAINTENANCE_JOB_TIMEOUT_TICKS: u64 = 6_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct MaintenanceConfig {
+    /// Daily local window, e.g. 02:00-05:00 (None: no scheduled window)
+    pub window: Option<QuietHours>,
+    /// Also run during long Dormant periods
+    pub when_dormant: bool,
+}
+
+impl MaintenanceConfig {
+    /// `NEXUS_MAINTENANCE_WINDOW=02:00-05:00`, `NEXUS_MAINTENANCE_DORMANT=1`. Off when unset.
+    pub fn from_env() -> Self {
+        Self {
+            window: std::env::var("NEXUS_MAINTENANCE_WINDOW").ok().and_then(|s| QuietHours::parse(&s)),
+            when_dormant: matches!(std::env::var("NEXUS_MAINTENANCE_DORMANT").as_deref(), Ok("1") | Ok("true")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MaintenanceJob {
+    SemanticPromotion,
+    StoreCompaction,
+    LogRotation,
+    TelemetryExport,
+    /// Rewrite the semantic store in the current schema and rebuild its index. Maintenance mode only.
+    StoreMigration,
+}
+
+impl MaintenanceJob {
+    pub const ALL: [MaintenanceJob; 4] = [
+        MaintenanceJob::SemanticPromotion,
+        MaintenanceJob::StoreCompaction,
+        MaintenanceJob::LogRotation,
+        MaintenanceJob::TelemetryExport,
+    ];
+
+    /// Default maintenance-mode run after an upgrade: migrate, then compact.
+    pub const MIGRATION: [MaintenanceJob; 2] = [MaintenanceJob::StoreMigration, MaintenanceJob::StoreCompaction];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JobOutcome {
+    Done,
+    /// Not applicable (safe mode, no spool path)
+    Skipped,
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MaintenanceTrigger {
+    Window,
+    Dormant,
+    /// `Reactor::run_maintenance` called directly (blocking, outside the tick)
+    OnDemand,
+    /// `InputContent::EnterMaintenance` (maintenance mode)
+    Requested,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobResult {
+    pub job: MaintenanceJob,
+    pub outcome: JobOutcome,
+    /// Entries promoted / dropped, spools shifted, events written
+    pub items: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MaintenanceSummary {
+    pub tick: Tick,
+    pub trigger: MaintenanceTrigger,
+    pub jobs: Vec<JobResult>,
+}
+
+impl MaintenanceSummary {
+    pub fn result(&self, job: MaintenanceJob) -> Option<&JobResult> {
+        self.jobs.iter().find(|r| r.job == job)
+    }
+}
+
+/// Disk half of a job, taken from in-memory state in the tick.
+#[derive(Debug, Clone)]
+pub enum MaintenanceWork {
+    /// Write the semantic store; `items` were counted in the tick (promoted, dropped, migrated)
+    SaveSemantic { snapshot: SemanticSnapshot, items: u64 },
+    /// Load the semantic store file (defaults for fields added since) and write it back
+    MigrateSemantic { path: PathBuf },
+    RotateSpool { path: PathBuf },
+    /// Write the recorded events as the spool
+    ExportSpool { path: PathBuf, events: Vec<TelemetryEvent> },
+}
+
+/// A job for the driver (`SideEffect::RunMaintenance`).
+#[derive(Debug, Clone)]
+pub struct MaintenanceTask {
+    pub job: MaintenanceJob,
+    pub work: MaintenanceWork,
+}
+
+impl MaintenanceTask {
+    /// Blocking: do the work. Drivers run it off the tick and send the result back as
+    /// `InputContent::MaintenanceResult`.
+    pub fn run(&self) -> JobResult {
+        let items = match &self.work {
+            MaintenanceWork::SaveSemantic { snapshot, items } => snapshot.save().map(|_| *items).map_err(|e| format!("{:?}", e)),
+            MaintenanceWork::MigrateSemantic { path } => {
+                let mut store = FileSemanticStore::new(path.clone());
+                store.load()
+                    .and_then(|_| store.save())
+                    .map(|_| store.version_count() as u64)
+                    .map_err(|e| format!("{:?}", e))
+            }
+            MaintenanceWork::RotateSpool { path } => rotate(path, SPOOL_GENERATIONS).map_err(|e| e.to_string()),
+            MaintenanceWork::ExportSpool { path, events } => crate::kernel::telemetry::recorder::write_spool(path, events)
+                .map(|_| events.len() as u64)
+                .map_err(|e| e.to_string()),
+        };
+        match items {
+            Ok(items) => JobResult { job: self.job, outcome: JobOutcome::Done, items },
+            Err(e) => {
+                tracing::warn!("Maintenance job {:?} failed: {}", self.job, e);
+                JobResult { job: self.job, outcome: JobOutcome::Failed, items: 0 }
+            }
+        }
+    }
+}
+
+/// Where a maintenance-mode run stands (`SideEffect::MaintenanceProgress`), for the UI's migration screen.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MaintenanceProgress {
+    /// Jobs finished so far. Equal to `total`: the run is over and the kernel resumes.
+    pub completed: usize,
+    pub total: usize,
+    /// Job that runs next (None: nothing left)
+    pub next: Option<MaintenanceJob>,
+    /// Job that just finished (None: the run just started)
+    pub last: Option<JobResult>,
+}
+
+/// Jobs of a nightly run or queued by `InputContent::EnterMaintenance`, run one at a time.
+#[derive(Debug, Clone)]
+pub struct MaintenanceRun {
+    pub trigger: MaintenanceTrigger,
+    /// Mode to return to once the queue is empty (maintenance mode only)
+    pub resume: KernelMode,
+    pending: VecDeque<MaintenanceJob>,
+    // With the driver (`SideEffect::RunMaintenance`) since
+    running: Option<(MaintenanceJob, Tick)>,
+    results: Vec<JobResult>,
+}
+
+impl MaintenanceRun {
+    pub fn new(jobs: Vec<MaintenanceJob>, trigger: MaintenanceTrigger, resume: KernelMode) -> Self {
+        Self { trigger, resume, pending: jobs.into(), running: None, results: Vec::new() }
+    }
+
+    /// Another request while the run is going: its jobs run after the queued ones.
+    pub fn extend(&mut self, jobs: Vec<MaintenanceJob>) {
+        self.pending.extend(jobs);
+    }
+
+    pub fn next_job(&mut self) -> Option<MaintenanceJob> {
+        self.pending.pop_front()
+    }
+
+    /// `job` went to the driver; nothing else starts until its result is recorded or it times out.
+    pub fn start(&mut self, job: MaintenanceJob, now: Tick) {
+        self.running = Some((job, now));
+    }
+
+    pub fn running(&self) -> Option<MaintenanceJob> {
+        self.running.map(|(job, _)| job)
+    }
+
+    /// The running job, if the driver has not answered within `MAINTENANCE_JOB_TIMEOUT_TICKS`.
+    pub fn overdue(&self, now: Tick) -> Option<MaintenanceJob> {
+        self.running
+            .filter(|(_, since)| now.frame.saturating_sub(since.frame) >= MAINTENANCE_JOB_TIMEOUT_TICKS)
+            .map(|(job, _)| job)
+    }
+
+    pub fn record(&mut self, result: JobResult) {
+        if self.running() == Some(result.job) {
+            self.running = None;
+        }
+        self.results.push(result);
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.pending.is_empty() && self.running.is_none()
+    }
+
+    pub fn results(&self) -> &[JobResult] {
+        &self.results
+    }
+
+    pub fn progress(&self) -> MaintenanceProgress {
+        MaintenanceProgress {
+            completed: self.results.len(),
+            total: self.results.len() + self.running.iter().count() + self.pending.len(),
+            next: self.running().or(self.pending.front().copied()),
+            last: self.results.last().cloned(),
+        }
+    }
+}
+
+/// When to run. Owns no stores: the Reactor prepares the jobs, the driver finishes them.
+#[derive(Debug, Clone, Default)]
+pub struct MaintenanceScheduler {
+    /// Raw telemetry spool the export writes and rotation shifts (set by the driver; None skips both)
+    pub telemetry_spool: Option<PathBuf>,
+    minute_of_day: Option<u16>,
+    dormant_since: Option<Tick>,
+    last_run: Option<Tick>,
+    last_summary: Option<MaintenanceSummary>,
+}
+
+impl MaintenanceScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Latest local time reported by the driver.
+    pub fn observe_clock(&mut self, minute_of_day: u16) {
+        self.minute_of_day = Some(minute_of_day);
+    }
+
+    /// Track how long presence has been Dormant.
+    pub fn observe_presence(&mut self, presence: PresenceState, now: Tick) {
+        if presence == PresenceState::Dormant {
+            self.dormant_since.get_or_insert(now);
+        } else {
+            self.dormant_since = None;
+        }
+    }
+
+    /// Why a run is due now, if it is. `busy`: work in flight outside `state` (plans, tools, transcriptions).
+    pub fn due(&self, config: &MaintenanceConfig, state: &SharedState, busy: bool, now: Tick) -> Option<MaintenanceTrigger> {
+        if self.last_run.is_some_and(|at| now.frame.saturating_sub(at.frame) < MAINTENANCE_INTERVAL_TICKS) {
+            return None;
+        }
+        if busy || !is_idle(state, now) {
+            return None;
+        }
+        let in_window = config.window.zip(self.minute_of_day).is_some_and(|(window, minute)| window.contains(minute));
+        let long_dormant = config.when_dormant
+            && self.dormant_since.is_some_and(|since| now.frame.saturating_sub(since.frame) >= MAINTENANCE_DORMANT_TICKS);
+        if in_window {
+            Some(MaintenanceTrigger::Window)
+        } else if long_dormant {
+            Some(MaintenanceTrigger::Dormant)
+        } else {
+            None
+        }
+    }
+
+    pub fn finished(&mut self, summary: MaintenanceSummary) {
+        self.last_run = Some(summary.tick);
+        self.last_summary = Some(summary);
+    }
+
+    pub fn last_summary(&self) -> Option<&MaintenanceSummary> {
+        self.last_summary.as_ref()
+    }
+}
+
+/// PURE FUNCTION: Nobody is talking and nothing was said recently.
+pub fn is_idle(state: &SharedState, now: Tick) -> bool {
+    !state.user_speaking()
+        && state.active_outputs().is_empty()
+        && state.last_user_input().is_none_or(|t| now.frame.saturating_sub(t.frame) >= MAINTENANCE_IDLE_TICKS)
+}
+
+/// Shift `<path>` to `<path>.1`, `<path>.1` to `<path>.2`, ... keeping `generations` old spools.
+/// Returns how many files moved.
+pub fn rotate(path: &Path, generations: usize) -> std::io::Result<u64> {
+    let numbered = |n: usize| {
+        let mut name = path.as_os_str().to_owned();
+        name.push(format!(".{}", n));
+        PathBuf::from(name)
+    };
+    let mut moved = 0;
+    for n in (1..generations).rev() {
+        if numbered(n).exists() {
+            std::fs::rename(numbered(n), numbered(n + 1))?;
+            moved += 1;
+        }
+    }
+    if generations > 0 && path.exists() {
+        std::fs::rename(path, numbered(1))?;
+        moved += 1;
+    }
+    Ok(moved)
+}
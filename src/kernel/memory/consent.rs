@@ -1,7 +1,14 @@
+use super::topic::TopicId;
 use super::types::MemoryKey;
 use crate::kernel::time::Tick;
 use serde::{Deserialize, Serialize};
 
+// Config Constants
+/// A spoken answer to a consent prompt is short; anything longer is a new utterance.
+pub const SPOKEN_ANSWER_MAX_WORDS: usize = 4;
+const DECLINE_PHRASES: &[&str] = &["no", "nope", "don't", "do not", "dont", "forget it", "not now"];
+const GRANT_PHRASES: &[&str] = &["yes", "yeah", "yep", "sure", "okay", "ok", "go ahead", "remember it", "please do"];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MemoryConsentState {
     Unknown,
@@ -16,6 +23,14 @@ pub struct MemoryConsent {
     pub state: MemoryConsentState,
     pub asked_at: Tick,
     pub resolved_at: Option<Tick>,
+    /// Outstanding `ConsentRequest` (see `kernel::protocol`); answers must echo it
+    #[serde(default)]
+    pub prompt_id: Option<String>,
+    #[serde(default)]
+    pub expires_at: Option<Tick>,
+    /// Topic the outstanding prompt also covers; its answer resolves the topic too
+    #[serde(default)]
+    pub topic_id: Option<TopicId>,
 }
 
 impl MemoryConsent {
@@ -25,6 +40,38 @@ impl MemoryConsent {
             state: MemoryConsentState::Unknown,
             asked_at,
             resolved_at: None,
+            prompt_id: None,
+            expires_at: None,
+            topic_id: None,
         }
     }
+
+    /// A prompt is out and can still be answered: don't ask again.
+    pub fn prompt_pending(&self, now: Tick) -> bool {
+        self.resolved_at.is_none()
+            && self.prompt_id.is_some()
+            && self.expires_at.is_some_and(|deadline| now.frame <= deadline.frame)
+    }
+}
+
+/// PURE FUNCTION: Is `text` a spoken yes/no to an open consent prompt? ("yes please", "no thanks")
+/// Declines win over grants ("no, don't").
+pub fn parse_spoken_answer(text: &str) -> Option<MemoryConsentState> {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower.split(|c: char| !c.is_alphanumeric() && c != '\'').filter(|w| !w.is_empty()).collect();
+    if words.is_empty() || words.len() > SPOKEN_ANSWER_MAX_WORDS {
+        return None;
+    }
+    let normalized = format!(" {} ", words.join(" "));
+    let said = |phrases: &[&str]| phrases.iter().any(|p| normalized.contains(&format!(" {} ", p)));
+    if said(DECLINE_PHRASES) {
+        Some(MemoryConsentState::Declined)
+    } else if words.iter().any(|w| matches!(*w, "not" | "maybe")) {
+        // "not sure", "maybe": no answer
+        None
+    } else if said(GRANT_PHRASES) {
+        Some(MemoryConsentState::Granted)
+    } else {
+        None
+    }
 }
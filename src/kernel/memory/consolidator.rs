@@ -1,8 +1,8 @@
 use crate::kernel::state::{SharedState, StateDelta};
 use crate::kernel::intent::types::{IntentCandidate, IntentStability};
 use crate::kernel::memory::types::{MemoryCandidate, MemoryKey, MemoryRecord};
+use crate::kernel::memory::sensitivity::SensitivityClass;
 use crate::kernel::time::Tick;
-use uuid::Uuid;
 use crate::kernel::telemetry::recorder::TelemetryRecorder;
 use crate::kernel::telemetry::event::{TelemetryEvent, MemoryEventKind};
 // Assuming 50ms per tick
@@ -13,7 +13,7 @@ const MIN_CONSOLIDATION_WINDOW: u64 = 1200;
 const MAX_CANDIDATE_AGE: u64 = 12000;
 
 // Decay config
-const DECAY_FACTOR: f32 = 0.9995; // Slow decay per tick
+const STRENGTH_HALF_LIFE_TICKS: f32 = 1386.0; // Slow decay (~0.9995 per tick)
 const FORGET_THRESHOLD: f32 = 0.1;
 
 pub struct MemoryConsolidator;
@@ -24,19 +24,21 @@ impl MemoryConsolidator {
     }
 
     /// Process a Stable Intent to potentially create or reinforce a Memory Candidate.
-    pub fn process_intent(&self, intent: &IntentCandidate, state: &SharedState, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+    pub fn process_intent(&self, intent: &IntentCandidate, state: &SharedState, telemetry: &mut TelemetryRecorder, ids: &mut crate::kernel::ids::IdGenerator) -> Vec<StateDelta> {
         // 1. Gate: Must be Stable and High Confidence
         if intent.stability != IntentStability::Stable || intent.confidence < 0.85 {
             return vec![];
         }
 
-        let key = MemoryKey::from_intent(intent);
+        // Scoped to the active context and the spoken language: no reinforcement across
+        // conversations or languages
+        let key = MemoryKey::in_context(intent, state.active_context()).in_language(state.intent_language(&intent.id));
         let mut deltas = Vec::new();
-        let current_tick = state.last_tick;
+        let current_tick = state.last_tick();
 
         // 2. Identity Match
         // Check if we already have a candidate with this semantic key
-        let existing = state.memory_candidates.values().find(|c| c.key == key);
+        let existing = state.memory_candidates().values().find(|c| c.key == key);
 
         if let Some(cand) = existing {
             // Reinforce
@@ -48,7 +50,7 @@ impl MemoryConsolidator {
             });
         } else {
             // New Candidate
-            let id = Uuid::new_v4().to_string();
+            let id = ids.next_id();
             let new_cand = MemoryCandidate {
                 id: id.clone(),
                 key,
@@ -56,6 +58,9 @@ impl MemoryConsolidator {
                 created_at: current_tick,
                 reinforcement_count: 1, // First appearance counts as 1? Or 0? Let's say 1.
                 last_reinforced_at: current_tick,
+                topic_id: state.active_topic().cloned(),
+                sensitivity: state.sensitive_intents().get(&intent.id).copied(),
+                context: state.active_context().clone(),
             };
             deltas.push(StateDelta::MemoryCandidateCreated(new_cand));
             
@@ -74,7 +79,7 @@ impl MemoryConsolidator {
         let mut deltas = Vec::new();
 
         // 1. Decay Long Term Memory
-        for record in state.long_term_memory.values() {
+        for record in state.long_term_memory().values() {
             // Access slows decay? 
             // Model: Decay happens every tick relative to *time since last access*?
             // Or just decay continuously and access boosts strength back up?
@@ -85,7 +90,8 @@ impl MemoryConsolidator {
             
             let time_since_access = current_tick.frame.saturating_sub(record.last_accessed_at.frame);
             if time_since_access > 200 { // 10 seconds grace
-                let new_strength = record.strength * DECAY_FACTOR;
+                // One tick per pass (hydrated NaN / negative strengths end up forgotten)
+                let new_strength = crate::kernel::decay::exponential(record.strength, 1, STRENGTH_HALF_LIFE_TICKS);
                 if new_strength < FORGET_THRESHOLD {
                     deltas.push(StateDelta::MemoryForgotten(record.id.clone()));
                     telemetry.record(TelemetryEvent::MemoryEvent { 
@@ -113,18 +119,25 @@ impl MemoryConsolidator {
         }
 
         // 2. Promote Candidates
-        for cand in state.memory_candidates.values() {
+        for cand in state.memory_candidates().values() {
             let age = current_tick.frame.saturating_sub(cand.created_at.frame);
             
             let mut should_promote = false;
             let mut ask_consent = false;
+            let sensitivity = candidate_sensitivity(cand, state);
 
             // Basic Eligibility: Reinforcement >= 2 (Strict), Age >= MIN_WINDOW
             if cand.reinforcement_count >= 2 && age >= MIN_CONSOLIDATION_WINDOW {
                  // Check Consent
-                 let consent_state = state.memory_consent.get(&cand.key)
-                                     .map(|c| c.state)
-                                     .unwrap_or(crate::kernel::memory::consent::MemoryConsentState::Unknown);
+                 // Key-level consent wins. Topic-level consent ("remember things about your trip") applies otherwise.
+                 let key_consent = state.memory_consent().get(&cand.key).map(|c| c.state);
+                 let topic_consent = cand.topic_id.as_ref().and_then(|t| state.topic_consent().get(t).copied());
+                 let consent_state = match key_consent {
+                     Some(s) if s != crate::kernel::memory::consent::MemoryConsentState::Unknown => s,
+                     // Sensitive content needs explicit consent for this key; topic consent is not enough
+                     _ if sensitivity.is_some() => crate::kernel::memory::consent::MemoryConsentState::Unknown,
+                     _ => topic_consent.unwrap_or(crate::kernel::memory::consent::MemoryConsentState::Unknown),
+                 };
 
                  match consent_state {
                      crate::kernel::memory::consent::MemoryConsentState::Granted => {
@@ -133,6 +146,10 @@ impl MemoryConsolidator {
                      crate::kernel::memory::consent::MemoryConsentState::Declined | crate::kernel::memory::consent::MemoryConsentState::Ignored => {
                          // Never promote
                      }
+                     crate::kernel::memory::consent::MemoryConsentState::Unknown if sensitivity.is_some() => {
+                         // Sensitive: always ask (once), regardless of hypothesis or confidence
+                         ask_consent = key_consent.is_none();
+                     }
                      crate::kernel::memory::consent::MemoryConsentState::Unknown => {
                          // Strict Heuristic: Statement Only + Very High Confidence
                          let is_statement = matches!(cand.intent.hypothesis, crate::kernel::intent::types::IntentHypothesis::Statement); 
@@ -147,14 +164,7 @@ impl MemoryConsolidator {
 
             if should_promote {
                 // PROMOTE
-                let record = MemoryRecord {
-                    id: cand.id.clone(),
-                    intent: cand.intent.clone(),
-                    first_committed_at: current_tick,
-                    last_accessed_at: current_tick,
-                    strength: 0.5, // Initial strength
-                };
-                deltas.push(StateDelta::MemoryPromoted(record));
+                deltas.push(StateDelta::MemoryPromoted(promoted_record(cand, sensitivity, current_tick)));
                 deltas.push(StateDelta::MemoryCandidateRemoved(cand.id.clone()));
                 
                 telemetry.record(TelemetryEvent::MemoryEvent {
@@ -178,3 +188,22 @@ impl MemoryConsolidator {
         deltas
     }
 }
+
+/// Privacy class of a candidate: marked at creation, or later if the same intent turned sensitive.
+pub(crate) fn candidate_sensitivity(cand: &MemoryCandidate, state: &SharedState) -> Option<SensitivityClass> {
+    cand.sensitivity.or_else(|| state.sensitive_intents().get(&cand.intent.id).copied())
+}
+
+/// Long-term record for a candidate promoted at `tick` (by the heuristics or by hand).
+pub(crate) fn promoted_record(cand: &MemoryCandidate, sensitivity: Option<SensitivityClass>, tick: Tick) -> MemoryRecord {
+    MemoryRecord {
+        id: cand.id.clone(),
+        intent: cand.intent.clone(),
+        first_committed_at: tick,
+        last_accessed_at: tick,
+        strength: 0.5, // Initial strength
+        topic_id: cand.topic_id.clone(),
+        sensitivity,
+        context: cand.context.clone(),
+    }
+}
@@ -0,0 +1,88 @@
+//! Memory Candidate Inspection & Manual Actions.
+//!
+//! The promotion heuristics (`MemoryConsolidator::tick`) are strict, so something the user
+//! cares about may never be promoted. Drivers list the current candidates (`list_candidates`)
+//! and send `InputContent::MemoryCandidateAction` to promote or discard one. The Reactor
+//! applies it at the tick boundary through `manual_action`, so the reducer and the consent
+//! rules still apply:
+//! - Promoting is an explicit "remember this": the key's consent is resolved to `Granted`
+//!   (which also satisfies the sensitive-content rule) and the candidate is promoted now,
+//!   whatever its reinforcement count or age.
+//! - A key the user declined stays declined: promotion is refused.
+//! - Discarding removes the candidate. Saying the same thing again creates a new one.
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::memory::consent::MemoryConsentState;
+use crate::kernel::memory::consolidator::{candidate_sensitivity, promoted_record};
+use crate::kernel::memory::types::{MemoryId, MemoryKey};
+use crate::kernel::state::{SharedState, StateDelta};
+use crate::kernel::time::Tick;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CandidateAction {
+    Promote,
+    Discard,
+}
+
+/// One memory candidate, for the inspector. Key and counts only, no transcript.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CandidateSummary {
+    pub id: MemoryId,
+    pub key: MemoryKey,
+    pub reinforcement_count: u32,
+    /// Ticks since the candidate was created
+    pub age_ticks: u64,
+    /// Key-level consent, if the key was ever asked about
+    pub consent: Option<MemoryConsentState>,
+    pub sensitive: bool,
+}
+
+/// Why a manual action was not applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ManualActionRefusal {
+    /// No such candidate (already promoted, pruned or discarded)
+    UnknownCandidate,
+    /// The user declined consent for this key
+    ConsentDeclined,
+    /// Safe mode, or memory switched off (`Subsystem::Memory`)
+    MemoryOff,
+}
+
+/// PURE FUNCTION: Current candidates, oldest first.
+pub fn list_candidates(state: &SharedState, now: Tick) -> Vec<CandidateSummary> {
+    let mut candidates: Vec<_> = state.memory_candidates().values().collect();
+    candidates.sort_by(|a, b| a.created_at.frame.cmp(&b.created_at.frame).then_with(|| a.id.cmp(&b.id)));
+    candidates.into_iter().map(|cand| CandidateSummary {
+        id: cand.id.clone(),
+        key: cand.key.clone(),
+        reinforcement_count: cand.reinforcement_count,
+        age_ticks: now.frame.saturating_sub(cand.created_at.frame),
+        consent: state.memory_consent().get(&cand.key).map(|c| c.state),
+        sensitive: candidate_sensitivity(cand, state).is_some(),
+    }).collect()
+}
+
+/// PURE FUNCTION: Deltas for a manual action on candidate `id` at `now`.
+/// The caller refuses with `MemoryOff` first when memory logic is off.
+pub fn manual_action(id: &MemoryId, action: CandidateAction, state: &SharedState, now: Tick) -> Result<Vec<StateDelta>, ManualActionRefusal> {
+    let cand = state.memory_candidates().get(id).ok_or(ManualActionRefusal::UnknownCandidate)?;
+    match action {
+        CandidateAction::Discard => Ok(vec![StateDelta::MemoryCandidateRemoved(id.clone())]),
+        CandidateAction::Promote => {
+            let mut deltas = Vec::new();
+            match state.memory_consent().get(&cand.key).map(|c| c.state) {
+                Some(MemoryConsentState::Declined) => return Err(ManualActionRefusal::ConsentDeclined),
+                Some(MemoryConsentState::Granted) => {}
+                _ => deltas.push(StateDelta::MemoryConsentResolved {
+                    key: cand.key.clone(),
+                    state: MemoryConsentState::Granted,
+                    resolved_at: now,
+                }),
+            }
+            deltas.push(StateDelta::MemoryPromoted(promoted_record(cand, candidate_sensitivity(cand, state), now)));
+            deltas.push(StateDelta::MemoryCandidateRemoved(id.clone()));
+            Ok(deltas)
+        }
+    }
+}
@@ -1,3 +1,6 @@
 pub mod consolidator;
 pub mod types;
 pub mod consent;
+pub mod topic;
+pub mod sensitivity;
+pub mod inspect;
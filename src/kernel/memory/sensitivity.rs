@@ -0,0 +1,93 @@
+//! Sensitivity Classification (Privacy).
+//!
+//! Heuristic keyword classes applied to transcripts BEFORE a memory candidate exists.
+//! Sensitive intents need explicit (key-level) consent to be remembered, stay out of
+//! planner snapshots until consented, and are marked on stored records so exports
+//! can be redacted.
+
+use serde::{Serialize, Deserialize};
+use crate::kernel::memory::types::MemoryRecord;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SensitivityClass {
+    Health,
+    Finance,
+    Credentials,
+}
+
+// Checked in this order: the first class with a hit wins.
+const CREDENTIAL_TERMS: &[&str] = &[
+    "password", "passcode", "passphrase", "pin code", "pin number", "api key", "secret key",
+    "access token", "security question", "social security", "ssn", "login", "2fa", "otp",
+];
+const FINANCE_TERMS: &[&str] = &[
+    "bank", "bank account", "account number", "routing number", "iban", "credit card", "debit card",
+    "salary", "income", "debt", "loan", "mortgage", "tax", "taxes", "paycheck", "investment",
+];
+const HEALTH_TERMS: &[&str] = &[
+    "doctor", "diagnosis", "diagnosed", "medication", "medicine", "prescription", "symptom",
+    "therapy", "therapist", "depression", "anxiety", "cancer", "diabetes", "pregnant",
+    "hospital", "surgery", "blood pressure", "allergy", "allergic",
+];
+
+/// Classify a transcript. `None` means nothing sensitive was detected.
+pub fn classify(text: &str) -> Option<SensitivityClass> {
+    // Normalise to " word word word " so terms only match on word boundaries
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+    let normalised = format!(" {} ", words.join(" "));
+    let hit = |terms: &[&str]| {
+        terms.iter().any(|t| normalised.contains(&format!(" {} ", t)) || normalised.contains(&format!(" {}s ", t)))
+    };
+
+    if hit(CREDENTIAL_TERMS) {
+        Some(SensitivityClass::Credentials)
+    } else if hit(FINANCE_TERMS) {
+        Some(SensitivityClass::Finance)
+    } else if hit(HEALTH_TERMS) {
+        Some(SensitivityClass::Health)
+    } else {
+        None
+    }
+}
+
+/// How much of a sensitive record survives an export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RedactionLevel {
+    /// Export everything as stored.
+    None,
+    /// Keep sensitive records but strip anything that identifies their content.
+    #[default]
+    Mask,
+    /// Drop sensitive records entirely.
+    Omit,
+}
+
+const REDACTED: &str = "[redacted]";
+
+impl MemoryRecord {
+    /// Export view of this record at `level`. `None` when the record must not be exported.
+    pub fn redacted(&self, level: RedactionLevel) -> Option<MemoryRecord> {
+        if self.sensitivity.is_none() {
+            return Some(self.clone());
+        }
+        match level {
+            RedactionLevel::None => Some(self.clone()),
+            RedactionLevel::Omit => None,
+            RedactionLevel::Mask => {
+                let mut record = self.clone();
+                record.intent.id = REDACTED.to_string();
+                record.intent.source_symbol_ids.clear();
+                record.intent.semantic_hash = 0;
+                record.topic_id = None;
+                Some(record)
+            }
+        }
+    }
+}
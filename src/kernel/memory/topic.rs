@@ -0,0 +1,161 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::memory::types::MemoryKey;
+use crate::kernel::state::SharedState;
+use crate::kernel::time::Tick;
+
+pub type TopicId = String;
+
+// Config Constants
+const WINDOW_SIZE: usize = 6; // Recent transcripts that define the rolling topic
+const JOIN_THRESHOLD: f32 = 0.2; // Token-hash overlap needed to stay on topic
+const TOPIC_IDLE_TICKS: u64 = 3000; // 60s @ 20ms. Silence this long ends the topic.
+const MIN_TOKEN_LEN: usize = 4; // Skip short function words ("the", "and", ...)
+/// A consent prompt covers the whole topic once it holds this many candidates.
+pub const TOPIC_CONSENT_MIN_CANDIDATES: usize = 2;
+/// A spoken forget command is short; anything longer is an utterance about forgetting.
+pub const FORGET_REQUEST_MAX_WORDS: usize = 8;
+/// What may follow "forget" in a topic-forget command. "Forget it" is a consent decline, not one.
+const FORGET_OBJECTS: &[&str] = &[
+    "this topic", "that topic", "the topic",
+    "this conversation", "that conversation",
+    "what we talked about", "what we just talked about", "what we discussed", "what we just discussed",
+    "everything about this", "everything about that", "all of this", "all of that", "all that",
+];
+/// Words that may precede "forget" in a command ("can you please forget ..."); "don't" is not one.
+const LEAD_WORDS: &[&str] = &["please", "can", "could", "would", "you", "just", "now", "ok", "okay"];
+
+/// Rolling topic cluster.
+/// Stores ONLY token hashes (no text), so it is safe to keep alongside state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Topic {
+    pub id: TopicId,
+    pub started_at: Tick,
+    pub last_seen_at: Tick,
+    pub utterances: u32,
+}
+
+/// Phase H+: Lightweight conversation-topic segmentation.
+/// Clusters recent transcripts by token-hash overlap, so related facts share a topic id.
+pub struct TopicTracker {
+    pub current: Option<Topic>,
+    window: VecDeque<HashSet<u64>>,
+}
+
+impl Default for TopicTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TopicTracker {
+    pub fn new() -> Self {
+        Self {
+            current: None,
+            window: VecDeque::new(),
+        }
+    }
+
+    /// Observe a transcript. Returns the topic id it was assigned to.
+    pub fn observe(&mut self, text: &str, tick: Tick, ids: &mut crate::kernel::ids::IdGenerator) -> TopicId {
+        let tokens = Self::fingerprint(text);
+
+        // Idle gap ends the topic regardless of overlap
+        let expired = match &self.current {
+            Some(t) => tick.frame.saturating_sub(t.last_seen_at.frame) > TOPIC_IDLE_TICKS,
+            None => true,
+        };
+
+        let joins = !expired && self.overlap(&tokens) >= JOIN_THRESHOLD;
+
+        if !joins {
+            self.window.clear();
+            self.current = Some(Topic {
+                id: ids.next_id(),
+                started_at: tick,
+                last_seen_at: tick,
+                utterances: 0,
+            });
+        }
+
+        self.window.push_back(tokens);
+        while self.window.len() > WINDOW_SIZE {
+            self.window.pop_front();
+        }
+
+        let topic = self.current.as_mut().expect("topic set above");
+        topic.last_seen_at = tick;
+        topic.utterances += 1;
+        topic.id.clone()
+    }
+
+    pub fn current_id(&self) -> Option<TopicId> {
+        self.current.as_ref().map(|t| t.id.clone())
+    }
+
+    /// Fraction of the new utterance's tokens already present in the rolling window.
+    fn overlap(&self, tokens: &HashSet<u64>) -> f32 {
+        if tokens.is_empty() {
+            // Fillers ("um", "ok") never break a topic
+            return 1.0;
+        }
+        let shared = tokens.iter()
+            .filter(|t| self.window.iter().any(|w| w.contains(t)))
+            .count();
+        shared as f32 / tokens.len() as f32
+    }
+
+    fn fingerprint(text: &str) -> HashSet<u64> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|w| w.len() >= MIN_TOKEN_LEN)
+            .map(|w| {
+                let mut h = DefaultHasher::new();
+                w.to_lowercase().hash(&mut h);
+                h.finish()
+            })
+            .collect()
+    }
+}
+
+/// PURE FUNCTION: Topic a consent prompt for `key` should cover, if any.
+/// Only when the key's candidate is not sensitive (sensitive content needs key consent), its
+/// topic has no answer yet, and the topic holds at least `TOPIC_CONSENT_MIN_CANDIDATES`
+/// candidates in the active context. A single fact is asked about on its own.
+pub fn consent_scope(key: &MemoryKey, state: &SharedState) -> Option<TopicId> {
+    let cand = state.memory_candidates().values().find(|c| &c.key == key)?;
+    if crate::kernel::memory::consolidator::candidate_sensitivity(cand, state).is_some() {
+        return None;
+    }
+    let topic = cand.topic_id.as_ref()?;
+    if state.topic_consent().contains_key(topic) {
+        return None;
+    }
+    let on_topic = state.memory_candidates().values()
+        .filter(|c| c.topic_id.as_ref() == Some(topic) && &c.context == state.active_context())
+        .count();
+    (on_topic >= TOPIC_CONSENT_MIN_CANDIDATES).then(|| topic.clone())
+}
+
+/// PURE FUNCTION: Is `text` a short, explicit request to forget the current topic?
+/// ("forget what we just talked about", "please forget this topic")
+/// "Forget it" (a consent decline), "don't forget ..." and "I always forget ..." are not.
+pub fn parse_forget_request(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower.split(|c: char| !c.is_alphanumeric() && c != '\'').filter(|w| !w.is_empty()).collect();
+    if words.len() > FORGET_REQUEST_MAX_WORDS {
+        return false;
+    }
+    let Some(at) = words.iter().position(|w| *w == "forget") else { return false };
+    if !words[..at].iter().all(|w| LEAD_WORDS.contains(w)) {
+        return false;
+    }
+    let mut rest = &words[at + 1..];
+    if rest.last() == Some(&"please") {
+        rest = &rest[..rest.len() - 1];
+    }
+    FORGET_OBJECTS.contains(&rest.join(" ").as_str())
+}
@@ -1,5 +1,8 @@
 use crate::kernel::intent::types::{IntentCandidate, IntentHypothesis};
 use crate::kernel::time::Tick;
+use crate::kernel::memory::topic::TopicId;
+use crate::kernel::memory::sensitivity::SensitivityClass;
+use crate::kernel::context::ContextId;
 use serde::{Serialize, Deserialize};
 use std::hash::Hash;
 
@@ -23,6 +26,32 @@ impl MemoryKey {
             symbol_fingerprint: intent.semantic_hash, 
         }
     }
+
+    /// Key scoped to a conversation context, so "work" and "personal" never
+    /// reinforce (or share consent for) each other's candidates.
+    /// The default context keeps the plain key.
+    pub fn in_context(intent: &IntentCandidate, context: &ContextId) -> Self {
+        let mut key = Self::from_intent(intent);
+        if !context.is_default() {
+            let mut h = std::collections::hash_map::DefaultHasher::new();
+            key.symbol_fingerprint.hash(&mut h);
+            context.hash(&mut h);
+            key.symbol_fingerprint = std::hash::Hasher::finish(&h);
+        }
+        key
+    }
+
+    /// Key scoped to the language the intent was spoken in, so the same hash heard in
+    /// two languages is two memories. The base language and untagged intents keep the key.
+    pub fn in_language(mut self, language: Option<&str>) -> Self {
+        if let Some(language) = language.filter(|l| *l != crate::kernel::language::BASE_LANGUAGE) {
+            let mut h = std::collections::hash_map::DefaultHasher::new();
+            self.symbol_fingerprint.hash(&mut h);
+            language.hash(&mut h);
+            self.symbol_fingerprint = std::hash::Hasher::finish(&h);
+        }
+        self
+    }
 }
 
 // We will likely need to update IntentCandidate to support this. 
@@ -36,6 +65,15 @@ pub struct MemoryCandidate {
     pub created_at: Tick,
     pub reinforcement_count: u32,
     pub last_reinforced_at: Tick,
+    // Conversation topic this candidate was observed under (TopicTracker)
+    #[serde(default)]
+    pub topic_id: Option<TopicId>,
+    // Privacy class of the transcript that produced the intent (sensitivity::classify)
+    #[serde(default)]
+    pub sensitivity: Option<SensitivityClass>,
+    // Conversation context this candidate belongs to (never crosses contexts)
+    #[serde(default)]
+    pub context: ContextId,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,4 +83,10 @@ pub struct MemoryRecord {
     pub first_committed_at: Tick,
     pub last_accessed_at: Tick,
     pub strength: f32, // 0.0 - 1.0
+    #[serde(default)]
+    pub topic_id: Option<TopicId>,
+    #[serde(default)]
+    pub sensitivity: Option<SensitivityClass>,
+    #[serde(default)]
+    pub context: ContextId,
 }
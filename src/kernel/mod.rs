@@ -1,14 +1,47 @@
 pub mod event;
 pub mod reactor;
 pub mod scheduler;
+pub mod effects;
 pub mod cancel;
 pub mod state;
 pub mod latent;
+pub mod decay;
 pub mod crystallizer;
 pub mod time;
 pub mod presence;
 pub mod audio;
 pub mod intent;
+pub mod context;
 pub mod memory;
 pub mod telemetry;
 pub mod speech;
+pub mod trace;
+pub mod footprint;
+pub mod queue;
+pub mod sidecar;
+pub mod self_test;
+pub mod view;
+pub mod report;
+pub mod dialogue;
+pub mod bookmark;
+pub mod diagnostic;
+pub mod protocol;
+pub mod ids;
+pub mod quiet;
+pub mod subsystem;
+pub mod epoch;
+pub mod warm_start;
+pub mod follow_up;
+pub mod driver;
+pub mod cadence;
+pub mod backchannel;
+pub mod shutdown;
+pub mod maintenance;
+pub mod profile;
+pub mod replay;
+pub mod journal;
+pub mod persist;
+pub mod language;
+pub mod observer;
+pub mod repl;
+pub mod bridge;
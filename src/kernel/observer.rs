@@ -0,0 +1,86 @@
+//! Tick Observers: passive plugins on the kernel's event bus.
+//!
+//! Sidecars take part in decisions; observers only watch. An observer registered with
+//! `Reactor::register_observer` sees every `tick_step` as a `TickRecord`: the events fed to the
+//! tick, the deltas reduced into `SharedState` since the previous record, and the effects the
+//! tick emitted. Analytics or research plugins hook in here without touching decision logic.
+//!
+//! Invariants:
+//! 1. Emit-only: a record holds shared borrows, and nothing in it reaches the Reactor, the
+//!    state or the effect batch. Observers run after the tick has decided.
+//! 2. Isolated: an observer that panics is dropped (logged once); the tick goes on.
+//! 3. Free when unused: deltas are only recorded while at least one observer is registered.
+//!
+//! Records carry what the kernel was fed and decided, transcripts included: unlike Telemetry,
+//! they are not content-free. Idle ticks skipped by `advance_idle` are not observed.
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use tracing::warn;
+
+use crate::kernel::event::Event;
+use crate::kernel::scheduler::SideEffect;
+use crate::kernel::state::StateDelta;
+use crate::kernel::time::Tick;
+
+/// One tick, as an observer sees it.
+#[derive(Debug, Clone, Copy)]
+pub struct TickRecord<'a> {
+    pub tick: Tick,
+    /// Events fed to `tick_step`, in arrival order
+    pub events: &'a [Event],
+    /// Deltas reduced since the previous record, in order (the tick's own `Tick` delta first;
+    /// a hydration or restart between ticks shows up in the next record)
+    pub deltas: &'a [StateDelta],
+    /// Effects the tick returned, in phase order
+    pub effects: &'a [SideEffect],
+}
+
+/// A passive plugin. Gets every tick after the kernel has decided it.
+pub trait TickObserver: Send {
+    fn name(&self) -> &str;
+
+    fn observe(&mut self, record: &TickRecord);
+}
+
+/// Registered observers, notified in registration order.
+#[derive(Default)]
+pub struct ObserverBus {
+    observers: Vec<Box<dyn TickObserver>>,
+}
+
+impl ObserverBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, observer: Box<dyn TickObserver>) {
+        self.observers.push(observer);
+    }
+
+    pub fn len(&self) -> usize {
+        self.observers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.observers.is_empty()
+    }
+
+    /// Names of the observers still registered.
+    pub fn names(&self) -> Vec<String> {
+        self.observers.iter().map(|o| o.name().to_string()).collect()
+    }
+
+    /// Hand `record` to every observer. One that panics is unregistered.
+    pub fn notify(&mut self, record: &TickRecord) {
+        self.observers.retain_mut(|observer| {
+            match catch_unwind(AssertUnwindSafe(|| observer.observe(record))) {
+                Ok(()) => true,
+                Err(_) => {
+                    warn!("Tick observer {} panicked at tick {} and was removed", observer.name(), record.tick.frame);
+                    false
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,133 @@
+//! State Persistence: what the kernel remembers across a process restart.
+//!
+//! A `PersistedState` is the part of `SharedState` worth keeping: long-term memories, live
+//! long-horizon intents (with their sensitivity marks), resolved consent (per memory key and
+//! per topic), the active context and presence, and the tick they were taken at. Everything
+//! else (outputs, audio, latents, the forming intent) belongs to the session that ended, and
+//! so does an exchange in progress: `Engaged` comes back as `Attentive`.
+//!
+//! `SharedState::serialize` writes it as JSON with a schema version; `SharedState::deserialize`
+//! and `Reactor::hydrate_from_disk` read it back by reducing the deltas it stands for, so a
+//! restored state went through `reduce` like any other. A file from another schema version is
+//! rejected, not misread. Unlike the shutdown checkpoint, it contains memory content.
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::context::ContextId;
+use crate::kernel::intent::long_horizon::{IntentId, IntentStatus, LongHorizonIntent};
+use crate::kernel::memory::consent::{MemoryConsent, MemoryConsentState};
+use crate::kernel::memory::sensitivity::SensitivityClass;
+use crate::kernel::memory::topic::TopicId;
+use crate::kernel::memory::types::MemoryRecord;
+use crate::kernel::presence::PresenceState;
+use crate::kernel::state::{SharedState, StateDelta};
+use crate::kernel::time::Tick;
+
+// Config Constants
+/// Bumped when `PersistedState` changes shape.
+pub const STATE_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PersistError {
+    Io(String),
+    /// Not a state file (or a damaged one)
+    Malformed(String),
+    /// Written by another schema version
+    Version(u32),
+}
+
+/// What `Reactor::hydrate_from_disk` restored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Hydrated {
+    /// Tick the state was persisted at (kernel time continues from it)
+    pub tick: Tick,
+    pub memories: usize,
+    pub intents: usize,
+}
+
+/// The persisted part of `SharedState`. Collections are sorted, so equal states write equal files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedState {
+    pub version: u32,
+    pub tick: Tick,
+    pub presence: PresenceState,
+    pub active_context: ContextId,
+    pub long_term_memory: Vec<MemoryRecord>,
+    /// Active, suspended and dormant intents (completed and invalidated ones are done)
+    pub intents: Vec<LongHorizonIntent>,
+    pub sensitive_intents: Vec<(IntentId, SensitivityClass)>,
+    /// Answered consent only: an open prompt ends with the session that showed it
+    pub memory_consent: Vec<MemoryConsent>,
+    pub topic_consent: Vec<(TopicId, MemoryConsentState)>,
+}
+
+impl PersistedState {
+    pub fn capture(state: &SharedState) -> Self {
+        let mut long_term_memory: Vec<MemoryRecord> = state.long_term_memory().values().cloned().collect();
+        long_term_memory.sort_by(|a, b| a.id.cmp(&b.id));
+        let mut intents: Vec<LongHorizonIntent> = state.active_intents().values()
+            .filter(|i| !matches!(i.status, IntentStatus::Completed | IntentStatus::Invalidated))
+            .cloned()
+            .collect();
+        intents.sort_by(|a, b| a.id.cmp(&b.id));
+        let mut sensitive_intents: Vec<(IntentId, SensitivityClass)> = state.sensitive_intents().iter()
+            .filter(|(id, _)| intents.iter().any(|i| &i.id == *id))
+            .map(|(id, class)| (id.clone(), *class))
+            .collect();
+        sensitive_intents.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut memory_consent: Vec<MemoryConsent> = state.memory_consent().values()
+            .filter(|c| c.state != MemoryConsentState::Unknown && c.resolved_at.is_some())
+            .cloned()
+            .collect();
+        memory_consent.sort_by_key(|c| (c.memory_key.symbol_fingerprint, format!("{:?}", c.memory_key.hypothesis)));
+        let mut topic_consent: Vec<(TopicId, MemoryConsentState)> = state.topic_consent().iter()
+            .map(|(topic, consent)| (topic.clone(), *consent))
+            .collect();
+        topic_consent.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Self {
+            version: STATE_SCHEMA_VERSION,
+            tick: state.last_tick(),
+            presence: state.presence(),
+            active_context: state.active_context().clone(),
+            long_term_memory,
+            intents,
+            sensitive_intents,
+            memory_consent,
+            topic_consent,
+        }
+    }
+
+    pub fn load(path: &std::path::Path) -> Result<Self, PersistError> {
+        Self::parse(&std::fs::read_to_string(path).map_err(|e| PersistError::Io(e.to_string()))?)
+    }
+
+    /// PURE FUNCTION: Version check, then the fields.
+    pub fn parse(text: &str) -> Result<Self, PersistError> {
+        let value: serde_json::Value = serde_json::from_str(text).map_err(|e| PersistError::Malformed(e.to_string()))?;
+        let version = value.get("version").and_then(|v| v.as_u64()).ok_or_else(|| PersistError::Malformed("no version".to_string()))?;
+        if version != STATE_SCHEMA_VERSION as u64 {
+            return Err(PersistError::Version(version as u32));
+        }
+        serde_json::from_value(value).map_err(|e| PersistError::Malformed(e.to_string()))
+    }
+
+    /// Deltas that rebuild this on a fresh state (intents as `LongHorizonIntentUpdate`).
+    pub fn deltas(&self) -> Vec<StateDelta> {
+        let mut deltas = vec![StateDelta::ContextSwitched(self.active_context.clone())];
+        deltas.extend(self.long_term_memory.iter().cloned().map(StateDelta::MemoryPromoted));
+        deltas.extend(self.intents.iter().cloned().map(StateDelta::LongHorizonIntentUpdate));
+        deltas.extend(self.sensitive_intents.iter().map(|(intent_id, class)| StateDelta::IntentSensitivityMarked { intent_id: intent_id.clone(), class: *class }));
+        for consent in &self.memory_consent {
+            deltas.push(StateDelta::MemoryConsentAsked(consent.memory_key.clone(), consent.asked_at));
+            if let Some(resolved_at) = consent.resolved_at {
+                deltas.push(StateDelta::MemoryConsentResolved { key: consent.memory_key.clone(), state: consent.state, resolved_at });
+            }
+        }
+        deltas.extend(self.topic_consent.iter().map(|(topic_id, state)| StateDelta::TopicConsentResolved { topic_id: topic_id.clone(), state: *state }));
+        // Nobody is mid-exchange with a process that just started
+        let presence = if self.presence == PresenceState::Engaged { PresenceState::Attentive } else { self.presence };
+        deltas.push(StateDelta::PresenceUpdate(presence));
+        deltas
+    }
+}
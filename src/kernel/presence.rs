@@ -1,5 +1,15 @@
 use serde::{Serialize, Deserialize};
 
+use crate::kernel::latent::{LatentState, Modality};
+
+// Config Constants
+/// Hamming distance between percepts that counts as the screen changing (noise stays below).
+pub const SCREEN_CHANGE_DISTANCE: u32 = 2;
+/// Live changed-screen latents that mean the user is working at the machine (~1s of change at 5 FPS).
+pub const SCREEN_ACTIVE_FRAMES: usize = 3;
+/// Audio and screen both quiet this long: presence decays one step toward Dormant (60s @ 20ms).
+pub const PRESENCE_QUIET_TICKS: u64 = 3000;
+
 /// The explicit lifecycle states of Nexus on a laptop.
 /// Defined in Phase A (presence.md).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -24,9 +34,20 @@ impl Default for PresenceState {
     }
 }
 
+impl PresenceState {
+    /// Every state, for exhaustive checks of the graph.
+    pub const ALL: [PresenceState; 5] = [
+        PresenceState::Dormant,
+        PresenceState::Attentive,
+        PresenceState::Engaged,
+        PresenceState::QuietlyHolding,
+        PresenceState::Suspended,
+    ];
+}
+
 /// Commands that request a presence state transition.
 /// These are REQUESTS, not forces. The State Machine validates them.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PresenceRequest {
     SystemBoot,
     WakeWordDetected,
@@ -39,6 +60,119 @@ pub enum PresenceRequest {
     UserResume,
     Timeout,
     AudioActivity,
+    /// Capture layer: microphone access became available
+    CaptureGranted,
+    /// Capture layer: microphone access is missing or was revoked
+    CaptureBlocked,
+    /// Vision: the screen keeps changing, the user is working at the machine
+    UserActivityDetected,
+}
+
+/// Where a presence request comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RequestOrigin {
+    /// Kernel observations: input, output lifecycle, intents, timers
+    Kernel,
+    /// Capture layer: wake word, voice and screen activity, microphone access
+    Capture,
+    /// Explicit user commands (pause, resume)
+    User,
+    /// The UI surface's own lifecycle (window attached, closed, focused). The UI is a view
+    /// of the core (presence.md §2, §4): these never change presence.
+    Ui,
+}
+
+impl PresenceRequest {
+    /// Every request, for exhaustive checks of the graph.
+    pub const ALL: [PresenceRequest; 14] = [
+        PresenceRequest::SystemBoot,
+        PresenceRequest::WakeWordDetected,
+        PresenceRequest::InputActivity,
+        PresenceRequest::OutputDrafted,
+        PresenceRequest::OutputCompleted,
+        PresenceRequest::LongTermIntentDetected,
+        PresenceRequest::IntentResolved,
+        PresenceRequest::UserSuspend,
+        PresenceRequest::UserResume,
+        PresenceRequest::Timeout,
+        PresenceRequest::AudioActivity,
+        PresenceRequest::CaptureGranted,
+        PresenceRequest::CaptureBlocked,
+        PresenceRequest::UserActivityDetected,
+    ];
+
+    /// Exhaustive on purpose: a new request must be classified.
+    pub fn origin(&self) -> RequestOrigin {
+        match self {
+            PresenceRequest::SystemBoot
+            | PresenceRequest::InputActivity
+            | PresenceRequest::OutputDrafted
+            | PresenceRequest::OutputCompleted
+            | PresenceRequest::LongTermIntentDetected
+            | PresenceRequest::IntentResolved
+            | PresenceRequest::Timeout => RequestOrigin::Kernel,
+            PresenceRequest::WakeWordDetected
+            | PresenceRequest::AudioActivity
+            | PresenceRequest::CaptureGranted
+            | PresenceRequest::CaptureBlocked
+            | PresenceRequest::UserActivityDetected => RequestOrigin::Capture,
+            PresenceRequest::UserSuspend | PresenceRequest::UserResume => RequestOrigin::User,
+        }
+    }
+}
+
+/// Microphone access as reported by the capture layer (OS privacy state).
+/// Without access the OS delivers silence, so the kernel must not claim to be listening.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum CapturePermission {
+    /// No report (no probe on this platform, or text-only drivers)
+    #[default]
+    Unknown,
+    /// The user has not been asked yet
+    NotDetermined,
+    Granted,
+    Denied,
+    /// Blocked by policy (MDM, parental controls); the user cannot grant it
+    Restricted,
+}
+
+impl CapturePermission {
+    pub const ALL: [CapturePermission; 5] = [
+        CapturePermission::Unknown,
+        CapturePermission::NotDetermined,
+        CapturePermission::Granted,
+        CapturePermission::Denied,
+        CapturePermission::Restricted,
+    ];
+
+    pub fn allows_listening(&self) -> bool {
+        matches!(self, CapturePermission::Unknown | CapturePermission::Granted)
+    }
+}
+
+/// Whether Nexus listens while it speaks (user setting, switchable at runtime).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ConversationMode {
+    /// Listens while speaking: the user can barge in.
+    #[default]
+    FullDuplex,
+    /// Never listens while speaking (privacy, echo-prone setups). No barge-in by voice.
+    HalfDuplex,
+}
+
+impl ConversationMode {
+    /// From `NEXUS_CONVERSATION_MODE` (`half` or `full`; default full duplex).
+    pub fn from_env() -> Self {
+        match std::env::var("NEXUS_CONVERSATION_MODE").as_deref() {
+            Ok("half") | Ok("half-duplex") => ConversationMode::HalfDuplex,
+            _ => ConversationMode::FullDuplex,
+        }
+    }
+
+    /// PURE FUNCTION: Does the kernel process captured audio right now?
+    pub fn listening(&self, system_speaking: bool) -> bool {
+        *self == ConversationMode::FullDuplex || !system_speaking
+    }
 }
 
 /// The state machine that governs presence transitions.
@@ -52,17 +186,26 @@ impl PresenceGraph {
         use PresenceState::*;
         use PresenceRequest::*;
 
+        // Background Continuity > UI Lifecycle
+        if request.origin() == RequestOrigin::Ui {
+            return None;
+        }
+
         match (current, request) {
             // --- From Dormant ---
             (Dormant, SystemBoot) => Some(Attentive), // or stays Dormant until UI attach? Let's say Attentive implies listening.
             // If boot implies "Ready to listen", then Attentive.
+            (Dormant, CaptureGranted) => Some(Attentive),
+            (Dormant, UserActivityDetected) => Some(Attentive), // Back at the machine
+            (Dormant, WakeWordDetected) => Some(Attentive), // Called by name: listening again
             
             // --- From Attentive ---
             (Attentive, WakeWordDetected) => Some(Engaged),
             (Attentive, InputActivity) => Some(Engaged), // Any typing/speech wakes it
             (Attentive, AudioActivity) => Some(Engaged),
             (Attentive, UserSuspend) => Some(Suspended),
-            // Timeout in Attentive -> Dormant (Energy saving?) - Optional, let's keep it simple.
+            (Attentive, CaptureBlocked) => Some(Dormant), // Not listening, so not Attentive
+            (Attentive, Timeout) => Some(Dormant), // Audio and screen both quiet
 
             // --- From Engaged ---
             (Engaged, OutputCompleted) => Some(Attentive), // Back to listening
@@ -85,4 +228,33 @@ impl PresenceGraph {
             _ => None,
         }
     }
+
+    /// `transition`, except that nothing becomes Attentive without microphone access.
+    /// A resume still ends the pause: without access it lands in Dormant (awake, not listening).
+    pub fn transition_gated(current: PresenceState, request: PresenceRequest, permission: CapturePermission) -> Option<PresenceState> {
+        match Self::transition(current, request) {
+            Some(PresenceState::Attentive) if !permission.allows_listening() => {
+                (current == PresenceState::Suspended).then_some(PresenceState::Dormant)
+            }
+            next => next,
+        }
+    }
+}
+
+/// PURE FUNCTION: Is the user working at the machine?
+/// Sustained change, not one frame: enough live visual latents that recorded a changed screen.
+pub fn screen_active(latents: &LatentState) -> bool {
+    latents.active(&Modality::Visual) >= SCREEN_ACTIVE_FRAMES
+}
+
+/// PURE FUNCTION: Presence request from channel activity.
+/// An active screen keeps the user present; `quiet_ticks` without audio or screen activity decays presence.
+pub fn activity_request(screen_active: bool, quiet_ticks: u64) -> Option<PresenceRequest> {
+    if screen_active {
+        Some(PresenceRequest::UserActivityDetected)
+    } else if quiet_ticks >= PRESENCE_QUIET_TICKS {
+        Some(PresenceRequest::Timeout)
+    } else {
+        None
+    }
 }
@@ -0,0 +1,169 @@
+//! User Profiles: preferences that outlive a session.
+//!
+//! A profile holds the output style (`outputs::style`) and the playback volume
+//! (`outputs::loudness`). One profile is active; switching
+//! profiles switches both with it. Profiles persist to `nexus_profiles.json` (or the
+//! path in `NEXUS_PROFILES`). Changes are made in memory; the driver writes the store back
+//! (`SideEffect::PersistProfiles`).
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::outputs::loudness::DEFAULT_VOLUME;
+use crate::outputs::style::OutputStyle;
+
+// Config Constants
+const PROFILES_FILE: &str = "nexus_profiles.json";
+pub const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    #[serde(default)]
+    pub style: OutputStyle,
+    /// Percent of the normalized loudness
+    #[serde(default = "default_volume")]
+    pub volume: u8,
+}
+
+fn default_volume() -> u8 {
+    DEFAULT_VOLUME
+}
+
+impl Profile {
+    fn named(name: &str) -> Self {
+        Self { name: name.to_string(), style: OutputStyle::default(), volume: DEFAULT_VOLUME }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ProfileFile {
+    active: String,
+    profiles: Vec<Profile>,
+}
+
+/// The whole store as it goes to disk. Drivers `save()` it off the tick.
+#[derive(Debug, Clone)]
+pub struct ProfilesSnapshot {
+    path: PathBuf,
+    file: ProfileFile,
+}
+
+impl ProfilesSnapshot {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Blocking write.
+    pub fn save(&self) -> anyhow::Result<()> {
+        std::fs::write(&self.path, serde_json::to_string_pretty(&self.file)?)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct ProfileStore {
+    active: String,
+    // Never empty; always contains `active`
+    profiles: Vec<Profile>,
+    // None: in memory only
+    path: Option<PathBuf>,
+}
+
+impl Default for ProfileStore {
+    fn default() -> Self {
+        Self { active: DEFAULT_PROFILE.to_string(), profiles: vec![Profile::named(DEFAULT_PROFILE)], path: None }
+    }
+}
+
+/// Profiles location (`NEXUS_PROFILES` overrides the working-directory default).
+pub fn profiles_path() -> PathBuf {
+    std::env::var("NEXUS_PROFILES").map(PathBuf::from).unwrap_or_else(|_| PathBuf::from(PROFILES_FILE))
+}
+
+impl ProfileStore {
+    /// In memory only (tests, ephemeral sessions).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load `path` if present; `snapshot()` writes changes back to it.
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        let mut store = Self { path: Some(path.to_path_buf()), ..Self::default() };
+        if path.exists() {
+            let file: ProfileFile = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+            store.profiles = file.profiles;
+            store.active = file.active;
+            if store.active().is_none() {
+                let active = store.active.clone();
+                store.profiles.push(Profile::named(&active));
+            }
+        }
+        Ok(store)
+    }
+
+    /// Production default: the profiles file, or the default profile in memory if it cannot be read.
+    pub fn from_default_path() -> Self {
+        let path = profiles_path();
+        Self::open(&path).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load profiles {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+
+    fn active(&self) -> Option<&Profile> {
+        self.profiles.iter().find(|p| p.name == self.active)
+    }
+
+    pub fn active_profile(&self) -> &Profile {
+        self.active().unwrap_or(&self.profiles[0])
+    }
+
+    pub fn style(&self) -> OutputStyle {
+        self.active_profile().style
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.profiles.iter().map(|p| p.name.as_str()).collect()
+    }
+
+    /// Change the active profile's style (in memory; see `snapshot`).
+    pub fn set_style(&mut self, style: OutputStyle) {
+        let active = self.active.clone();
+        match self.profiles.iter_mut().find(|p| p.name == active) {
+            Some(profile) => profile.style = style,
+            None => self.profiles.push(Profile { style, ..Profile::named(&active) }),
+        }
+    }
+
+    pub fn volume(&self) -> u8 {
+        self.active_profile().volume
+    }
+
+    /// Change the active profile's volume (in memory; see `snapshot`).
+    pub fn set_volume(&mut self, volume: u8) {
+        let active = self.active.clone();
+        match self.profiles.iter_mut().find(|p| p.name == active) {
+            Some(profile) => profile.volume = volume,
+            None => self.profiles.push(Profile { volume, ..Profile::named(&active) }),
+        }
+    }
+
+    /// Make `name` the active profile, creating it with the default style and volume if new
+    /// (in memory; see `snapshot`).
+    pub fn select(&mut self, name: &str) {
+        if !self.profiles.iter().any(|p| p.name == name) {
+            self.profiles.push(Profile::named(name));
+        }
+        self.active = name.to_string();
+    }
+
+    /// What to write back (`None`: in memory only).
+    pub fn snapshot(&self) -> Option<ProfilesSnapshot> {
+        self.path.as_ref().map(|path| ProfilesSnapshot {
+            path: path.clone(),
+            file: ProfileFile { active: self.active.clone(), profiles: self.profiles.clone() },
+        })
+    }
+}
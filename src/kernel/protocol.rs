@@ -0,0 +1,103 @@
+//! Wire Protocol: typed, versioned messages exchanged with the driver/UI.
+//!
+//! Round trips the kernel starts (e.g. asking for memory consent) carry a `prompt_id`.
+//! The answer must echo it: a response to a prompt the kernel did not issue, issued for
+//! another key, already answered, or past its deadline is rejected, never applied.
+//!
+//! Bump `PROTOCOL_VERSION` on any breaking change to these shapes.
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::memory::consent::{MemoryConsent, MemoryConsentState};
+use crate::kernel::memory::topic::TopicId;
+use crate::kernel::memory::types::MemoryKey;
+use crate::kernel::time::Tick;
+
+// Config Constants
+pub const PROTOCOL_VERSION: u32 = 1;
+/// A consent prompt can be answered for this many ticks (30s at 20ms).
+pub const CONSENT_PROMPT_TTL_TICKS: u64 = 1500;
+
+/// Kernel -> UI: "May I remember that?"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConsentRequest {
+    pub version: u32,
+    pub prompt_id: String,
+    pub key: MemoryKey,
+    pub asked_at: Tick,
+    /// Last tick an answer is accepted
+    pub expires_at: Tick,
+    /// Set when the answer also covers this conversation topic ("remember things about this?")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topic_id: Option<TopicId>,
+}
+
+/// UI -> Kernel: the user's answer to one `ConsentRequest`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConsentResponse {
+    pub version: u32,
+    pub prompt_id: String,
+    pub key: MemoryKey,
+    pub state: MemoryConsentState,
+}
+
+impl ConsentRequest {
+    pub fn new(prompt_id: String, key: MemoryKey, asked_at: Tick) -> Self {
+        Self {
+            version: PROTOCOL_VERSION,
+            prompt_id,
+            key,
+            asked_at,
+            expires_at: Tick { frame: asked_at.frame + CONSENT_PROMPT_TTL_TICKS },
+            topic_id: None,
+        }
+    }
+
+    /// Widen the prompt to a whole topic (see `topic::consent_scope`).
+    pub fn for_topic(mut self, topic_id: Option<TopicId>) -> Self {
+        self.topic_id = topic_id;
+        self
+    }
+
+    pub fn answer(&self, state: MemoryConsentState) -> ConsentResponse {
+        ConsentResponse {
+            version: self.version,
+            prompt_id: self.prompt_id.clone(),
+            key: self.key.clone(),
+            state,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConsentRejection {
+    /// Sender speaks another protocol version
+    Version,
+    /// No prompt is outstanding for this key
+    NotAsked,
+    /// A prompt is outstanding, but with another id (stale or forged answer)
+    PromptMismatch,
+    /// Answered after `expires_at`
+    Expired,
+    /// This prompt was already answered
+    AlreadyResolved,
+}
+
+/// PURE FUNCTION: may `response` resolve `consent` (the kernel's record for `response.key`) at `now`?
+pub fn validate_consent(response: &ConsentResponse, consent: Option<&MemoryConsent>, now: Tick) -> Result<(), ConsentRejection> {
+    if response.version != PROTOCOL_VERSION {
+        return Err(ConsentRejection::Version);
+    }
+    let consent = consent.ok_or(ConsentRejection::NotAsked)?;
+    let prompt_id = consent.prompt_id.as_deref().ok_or(ConsentRejection::NotAsked)?;
+    if prompt_id != response.prompt_id {
+        return Err(ConsentRejection::PromptMismatch);
+    }
+    if consent.resolved_at.is_some() {
+        return Err(ConsentRejection::AlreadyResolved);
+    }
+    if consent.expires_at.is_some_and(|deadline| now.frame > deadline.frame) {
+        return Err(ConsentRejection::Expired);
+    }
+    Ok(())
+}
@@ -0,0 +1,156 @@
+//! Kernel Event Queue Accounting.
+//!
+//! Everything reaches the kernel through one bounded `mpsc` channel. Producers that must not
+//! block (audio callbacks, the kernel's own driver loop) `try_send` and lose the event when the
+//! channel is full. This module sizes the channels (`QueueConfig`, from the environment) and
+//! makes the queue visible: depth at each tick boundary, high-water mark and drop count, in the
+//! State View and as periodic `TelemetryEvent::EventQueue` reports.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+use crate::kernel::event::Event;
+use crate::kernel::time::Tick;
+
+// Config Constants
+pub const DEFAULT_EVENT_QUEUE_CAPACITY: usize = 100;
+pub const DEFAULT_DRIVER_QUEUE_CAPACITY: usize = 100;
+const QUEUE_REPORT_INTERVAL_TICKS: u64 = 500; // 10s @ 20ms
+const MIN_QUEUE_CAPACITY: usize = 8;
+
+/// Channel capacities. Deployments with heavy vision/audio streams raise the event queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueConfig {
+    /// Kernel event channel (inputs, plans, driver results)
+    pub event_capacity: usize,
+    /// Driver-internal channel (generated speech, playback results)
+    pub driver_capacity: usize,
+}
+
+impl Default for QueueConfig {
+    fn default() -> Self {
+        Self { event_capacity: DEFAULT_EVENT_QUEUE_CAPACITY, driver_capacity: DEFAULT_DRIVER_QUEUE_CAPACITY }
+    }
+}
+
+impl QueueConfig {
+    /// `NEXUS_EVENT_QUEUE` and `NEXUS_DRIVER_QUEUE` (slots, at least `MIN_QUEUE_CAPACITY`).
+    pub fn from_env() -> Self {
+        let slots = |var: &str, default: usize| {
+            std::env::var(var).ok().and_then(|v| v.trim().parse::<usize>().ok()).map(|n| n.max(MIN_QUEUE_CAPACITY)).unwrap_or(default)
+        };
+        Self {
+            event_capacity: slots("NEXUS_EVENT_QUEUE", DEFAULT_EVENT_QUEUE_CAPACITY),
+            driver_capacity: slots("NEXUS_DRIVER_QUEUE", DEFAULT_DRIVER_QUEUE_CAPACITY),
+        }
+    }
+
+    /// The kernel event channel, sized by `event_capacity`.
+    pub fn event_channel(&self) -> (mpsc::Sender<Event>, mpsc::Receiver<Event>) {
+        mpsc::channel(self.event_capacity)
+    }
+}
+
+/// Events lost to a full queue. Shared by every non-blocking producer; cheap to clone.
+#[derive(Debug, Clone, Default)]
+pub struct DropCounter(Arc<AtomicU64>);
+
+impl DropCounter {
+    pub fn record(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// `try_send`, counting the event if the queue is full. A closed channel is not a drop.
+    pub fn try_send(&self, tx: &mpsc::Sender<Event>, event: Event) -> bool {
+        match tx.try_send(event) {
+            Ok(()) => true,
+            Err(mpsc::error::TrySendError::Full(_)) => {
+                self.record();
+                false
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => false,
+        }
+    }
+}
+
+/// Live queue figures (counts only).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueueStats {
+    /// Events waiting at the last tick boundary (drained into the tick plus still queued)
+    pub depth: usize,
+    pub capacity: usize,
+    /// Deepest queue seen this session
+    pub high_water: usize,
+    /// Events lost to a full queue this session
+    pub dropped: u64,
+}
+
+/// Outcome of `QueueMonitor::tick`, when a report is due.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueReport {
+    pub stats: QueueStats,
+    /// Drops since the previous report (a diagnostic if non-zero)
+    pub new_drops: u64,
+}
+
+/// Per-tick queue accounting sidecar.
+#[derive(Debug)]
+pub struct QueueMonitor {
+    capacity: usize,
+    depth: usize,
+    high_water: usize,
+    drops: DropCounter,
+    reported_drops: u64,
+    last_report: Option<u64>,
+    pub interval_ticks: u64,
+}
+
+impl QueueMonitor {
+    /// `capacity`: the channel's `max_capacity()`.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            depth: 0,
+            high_water: 0,
+            drops: DropCounter::default(),
+            reported_drops: 0,
+            last_report: None,
+            interval_ticks: QUEUE_REPORT_INTERVAL_TICKS,
+        }
+    }
+
+    /// Counter to hand to non-blocking producers (`DropCounter::try_send`).
+    pub fn drops(&self) -> DropCounter {
+        self.drops.clone()
+    }
+
+    /// Record the depth at this tick boundary.
+    pub fn observe(&mut self, depth: usize) {
+        self.depth = depth;
+        self.high_water = self.high_water.max(depth);
+    }
+
+    pub fn stats(&self) -> QueueStats {
+        QueueStats { depth: self.depth, capacity: self.capacity, high_water: self.high_water, dropped: self.drops.count() }
+    }
+
+    /// A report once per `interval_ticks`, or at once when events were dropped since the last one.
+    pub fn tick(&mut self, tick: Tick) -> Option<QueueReport> {
+        let dropped = self.drops.count();
+        let new_drops = dropped.saturating_sub(self.reported_drops);
+        let due = self.last_report.is_none_or(|last| tick.frame.saturating_sub(last) >= self.interval_ticks);
+        if !due && new_drops == 0 {
+            return None;
+        }
+        self.last_report = Some(tick.frame);
+        self.reported_drops = dropped;
+        Some(QueueReport { stats: self.stats(), new_drops })
+    }
+}
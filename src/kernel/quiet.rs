@@ -0,0 +1,100 @@
+//! Quiet Hours.
+//!
+//! A daily window (e.g. 22:00–08:00) during which the kernel does not speak unprompted.
+//! Proactive output (resumption offers, planner output nobody asked for) is denied at the
+//! gate (`explain_proactive_gate`); offers are deferred until the window ends. Direct answers
+//! to explicit user input still go through.
+//!
+//! The kernel has no wall clock: drivers report local time as `InputContent::LocalTime`.
+//! The user can lift the window until it ends by voice ("it's fine, you can talk").
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::state::SharedState;
+
+// Config Constants
+const MINUTES_PER_DAY: u16 = 24 * 60;
+/// Planner output within this many ticks of explicit user input counts as a direct answer (10s @ 20ms).
+pub const DIRECT_REPLY_WINDOW_TICKS: u64 = 500;
+const OVERRIDE_PHRASES: &[&str] = &[
+    "you can talk",
+    "you can speak",
+    "you may talk",
+    "you may speak",
+    "feel free to talk",
+    "end quiet hours",
+];
+
+/// Daily window in local minutes since midnight. `start > end` wraps past midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuietHours {
+    pub start_minute: u16,
+    pub end_minute: u16,
+}
+
+impl QuietHours {
+    pub fn new(start_minute: u16, end_minute: u16) -> Self {
+        Self {
+            start_minute: start_minute % MINUTES_PER_DAY,
+            end_minute: end_minute % MINUTES_PER_DAY,
+        }
+    }
+
+    /// Parse `"HH:MM-HH:MM"` (also accepts an en dash), e.g. `"22:00-08:00"`.
+    pub fn parse(spec: &str) -> Option<Self> {
+        let (start, end) = spec.split_once(['-', '–'])?;
+        Some(Self::new(parse_clock(start)?, parse_clock(end)?))
+    }
+
+    /// Start inclusive, end exclusive. An empty window (`start == end`) is never quiet.
+    pub fn contains(&self, minute_of_day: u16) -> bool {
+        let m = minute_of_day % MINUTES_PER_DAY;
+        if self.start_minute <= self.end_minute {
+            (self.start_minute..self.end_minute).contains(&m)
+        } else {
+            m >= self.start_minute || m < self.end_minute
+        }
+    }
+}
+
+fn parse_clock(text: &str) -> Option<u16> {
+    let (h, m) = text.trim().split_once(':')?;
+    let (h, m): (u16, u16) = (h.parse().ok()?, m.parse().ok()?);
+    (h < 24 && m < 60).then_some(h * 60 + m)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum QuietHoursState {
+    /// Outside the window (or no schedule / no clock yet)
+    #[default]
+    Off,
+    Active,
+    /// Inside the window, lifted by the user until it ends
+    Overridden,
+}
+
+/// PURE FUNCTION: State for the reported local time. An override lasts until the window ends.
+pub fn next_state(schedule: Option<QuietHours>, minute_of_day: u16, current: QuietHoursState) -> QuietHoursState {
+    match schedule {
+        Some(hours) if hours.contains(minute_of_day) => match current {
+            QuietHoursState::Overridden => QuietHoursState::Overridden,
+            _ => QuietHoursState::Active,
+        },
+        _ => QuietHoursState::Off,
+    }
+}
+
+/// Voice override: "it's fine, you can talk".
+pub fn parse_override(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).collect();
+    let normalized = words.join(" ");
+    OVERRIDE_PHRASES.iter().any(|p| normalized.contains(p))
+}
+
+/// PURE FUNCTION: Did the user just ask for something? Otherwise output is proactive.
+pub fn is_direct_reply(state: &SharedState) -> bool {
+    state.last_user_input()
+        .map(|t| state.last_tick().frame.saturating_sub(t.frame) <= DIRECT_REPLY_WINDOW_TICKS)
+        .unwrap_or(false)
+}
@@ -1,6 +1,6 @@
 use tokio::sync::mpsc;
-use tokio::time::{interval, Duration}; // Only for the loop driver
-use tracing::{info, warn};
+use tokio::time::Duration; // Only for the loop driver
+use tracing::{debug, info, warn};
 use std::path::PathBuf;
 
 use super::event::Event;
@@ -12,7 +12,6 @@ use super::cancel::CancellationRegistry;
 // use crate::planner::stub::plan;
 
 use crate::planner::async_planner::AsyncPlanner;
-use uuid::Uuid;
 use super::audio::segment::AudioSegment;
 
 // Memory System
@@ -23,67 +22,378 @@ use crate::memory::{
 use crate::kernel::memory::consolidator::MemoryConsolidator;
 use crate::monitor::monitor::SelfObservationMonitor; // Monitor
 use crate::kernel::intent::long_horizon::LongHorizonIntentManager;
+use crate::kernel::intent::arbitrator::IntentArbitrator;
+use super::sidecar::{MemorySensor, MemoryConsolidation, SelfMonitor, SentimentEstimator, IntentManager, IntentArbitration};
 use crate::kernel::telemetry::recorder::TelemetryRecorder;
-use crate::kernel::telemetry::event::{TelemetryEvent, OutputEventKind, InterruptionSource};
+use crate::kernel::telemetry::event::{TelemetryEvent, OutputEventKind};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
 pub enum KernelMode {
     Onboarding,
     Active,
+    /// Planning and output suspended while maintenance jobs run (`InputContent::EnterMaintenance`)
+    Maintenance,
 }
 
 
 #[derive(Debug, Clone, Copy)]
 pub struct ReactorConfig {
     pub safe_mode: bool,
+    // Announce intents resumed after long dormancy ("Earlier you mentioned...")
+    pub resumption_offers: bool,
+    // On waking after a long gap, say what is still on hold ("While you were away...")
+    pub warm_start: bool,
+    // Listening cues ("mm-hm") in the pauses of a long user turn
+    pub backchannel: bool,
+    // Record per-tick DecisionTrace (debug tooling; read-only)
+    pub decision_trace: bool,
+    // Memory pressure ceilings (element counts per state domain)
+    pub footprint: crate::kernel::footprint::FootprintCeilings,
+    // Dispatch to the Tier 2 LLM planner (off for offline self-tests; reflex still runs)
+    pub llm_planning: bool,
+    // Driver: max transcriptions running at once (audio::transcription::TranscriptionQueue)
+    pub transcription_concurrency: usize,
+    // Memory retrieval ranking (recency vs confidence, supersession), shared by both stores
+    pub retrieval: crate::memory::retriever::RankingPolicy,
+    // Accessibility: show captions instead of speaking (drivers swap TTS for a silent timer)
+    pub caption_only: bool,
+    // Speak kernel outputs a sentence at a time (outputs::streamer): StopAudio drops the unspoken rest
+    pub chunked_speech: bool,
+    // Speech engine drivers spawn for SpawnAudio (say, SAPI, espeak-ng, piper; default per platform)
+    pub tts: crate::outputs::tts::TtsEngineKind,
+    // Core VAD thresholds and timings (nexus_vad.toml; InputContent::ConfigUpdate at runtime)
+    pub vad: crate::kernel::audio::vad::VadConfig,
+    // Session seed for in-kernel ids (None: random per session). Pin it to replay a session.
+    pub id_seed: Option<u64>,
+    // One-line episodic summary per completed exchange (template, driver LLM, or off)
+    pub exchange_summaries: crate::memory::summary::SummaryMode,
+    // No unprompted speech inside this daily window (None: never quiet)
+    pub quiet_hours: Option<crate::kernel::quiet::QuietHours>,
+    // Plan staleness: max version lag, per-intent overrides, debug (version 0) injections
+    pub epoch: crate::kernel::epoch::EpochPolicy,
+    // Half duplex: captured audio is dropped while the system speaks (no barge-in by voice)
+    pub conversation_mode: crate::kernel::presence::ConversationMode,
+    // Safety filter on LLM text before it is spoken (profanity, PII, injection echoes)
+    pub output_filter: crate::outputs::safety::SafetyConfig,
+    // Action tools: per-tool timeouts, concurrency cap, dry run
+    pub tool_sandbox: crate::planner::sandbox::SandboxConfig,
+    // Nightly jobs (promotion, compaction, spool rotation/export): local window and/or Dormant periods
+    pub maintenance: crate::kernel::maintenance::MaintenanceConfig,
+    // Clarifications per forming intent (per semantic hash, per episode) and what replaces the rest
+    pub clarification: crate::kernel::intent::clarification::ClarificationLimits,
+    // Periodic telemetry snapshots: cadence, JSONL file and/or OTLP push (targets set on `Reactor::exporter`)
+    pub telemetry_export: crate::kernel::telemetry::exporter::ExportConfig,
+}
+
+impl Default for ReactorConfig {
+    fn default() -> Self {
+        Self {
+            safe_mode: false,
+            resumption_offers: true,
+            warm_start: true,
+            backchannel: false,
+            decision_trace: false,
+            footprint: crate::kernel::footprint::FootprintCeilings::default(),
+            llm_planning: true,
+            transcription_concurrency: crate::audio::transcription::DEFAULT_MAX_CONCURRENT,
+            retrieval: crate::memory::retriever::RankingPolicy::default(),
+            caption_only: false,
+            chunked_speech: false,
+            tts: crate::outputs::tts::TtsEngineKind::default(),
+            vad: crate::kernel::audio::vad::VadConfig::default(),
+            id_seed: None,
+            exchange_summaries: crate::memory::summary::SummaryMode::default(),
+            quiet_hours: None,
+            epoch: crate::kernel::epoch::EpochPolicy::default(),
+            conversation_mode: crate::kernel::presence::ConversationMode::default(),
+            output_filter: crate::outputs::safety::SafetyConfig::default(),
+            tool_sandbox: crate::planner::sandbox::SandboxConfig::default(),
+            maintenance: crate::kernel::maintenance::MaintenanceConfig::default(),
+            clarification: crate::kernel::intent::clarification::ClarificationLimits::default(),
+            telemetry_export: crate::kernel::telemetry::exporter::ExportConfig::default(),
+        }
+    }
 }
 
 pub struct Reactor {
     pub receiver: mpsc::Receiver<Event>,
     // We need a sender clone for the planner
-    _tx_clone: mpsc::Sender<Event>,
+    pub(crate) _tx_clone: mpsc::Sender<Event>,
     pub state: SharedState,
     pub scheduler: Scheduler,
     pub cancel_registry: CancellationRegistry,
     pub tick: Tick,
     pub planner: AsyncPlanner,
+    // Two-tier planning: synchronous reflex micro-planner (trivial cases)
+    pub reflex: crate::planner::reflex::ReflexPlanner,
     // Track the last state version we requested a plan for, to prevent loops
     last_planned_version: Option<u64>,
+    // The plan in flight is a safety-filter retry (one retry, then silence)
+    filter_retrying: bool,
+    // Last classified phrase (ledger hash, hypothesis, tick): what a user correction refers to
+    last_assessment: Option<(u64, crate::kernel::intent::types::IntentHypothesis, Tick)>,
 
     // Memory Components (Sidecars)
-    pub observer: MemoryObserver,
-    pub consolidator: MemoryConsolidator,
-    pub episodic: InMemoryEpisodicStore,
-    pub semantic: FileSemanticStore,
+    pub observer: Box<dyn MemorySensor>,
+    pub consolidator: Box<dyn MemoryConsolidation>,
+    pub episodic: Box<dyn EpisodicStore + Send>,
+    pub semantic: Box<dyn SemanticStore + Send>,
     
     // Self-Observation Monitor
-    pub monitor: SelfObservationMonitor,
+    pub monitor: Box<dyn SelfMonitor>,
+    // Turn-level sentiment (feeds the monitor)
+    pub sentiment: Box<dyn SentimentEstimator>,
+    // Last segment whose sentiment was reported (its refinements are not re-counted)
+    sentiment_segment: Option<String>,
 
     // Phase D: Audio Monitor (VAD)
     pub audio_monitor: crate::kernel::audio::monitor::AudioMonitor,
+    // Last capture fidelity requested from push-mode drivers
+    pub capture_fidelity: crate::kernel::event::CaptureFidelity,
+    // Segments handed to the driver for transcription and not yet transcribed
+    pub transcriptions_in_flight: std::collections::HashSet<String>,
+
+    // Thinking indicator for the State View (UI only, never read by decisions)
+    cognition: crate::kernel::view::Cognition,
+    // Published State View (lock-free UI reads)
+    view_tx: tokio::sync::watch::Sender<std::sync::Arc<crate::kernel::view::StateView>>,
     
     // Part IX: Long-Horizon Intent Manager
-    pub lhim: LongHorizonIntentManager,
+    pub lhim: Box<dyn IntentManager>,
+
+    // Topic Segmentation (labels memory candidates)
+    pub topic_tracker: crate::kernel::memory::topic::TopicTracker,
 
     // Phase G: Intent Arbitrator
-    pub arbitrator: crate::kernel::intent::arbitrator::IntentArbitrator,
+    pub arbitrator: Box<dyn IntentArbitration>,
+    // Transcription fragments: Stable is declared/revoked only on consistent evidence
+    pub hysteresis: crate::kernel::intent::hysteresis::IntentHysteresis,
+    // Bounded clarifications per forming intent (no interrogation loops on noisy fragments)
+    pub clarifications: crate::kernel::intent::clarification::ClarificationState,
     
     // Phase J: Telemetry
     pub telemetry: TelemetryRecorder,
+    // Telemetry bookkeeping: silence context and turn gaps (never read by decisions)
+    // Periodic snapshot export (file path and collector endpoint set by the driver)
+    pub exporter: crate::kernel::telemetry::exporter::TelemetryExporter,
+    silence: crate::kernel::telemetry::silence::SilenceClassifier,
+    response_gap: crate::kernel::telemetry::silence::ResponseGapTracker,
+    // Current tick for the capture layer (audio is stamped when heard)
+    capture_clock: crate::kernel::time::CaptureClock,
     
     // Phase K: Onboarding Lock
     pub mode: KernelMode,
 
     // Phase N: Speech Planner
     pub speech_planner: crate::kernel::speech::planner::SpeechPlanner,
+    // Recent realizations (consecutive utterances use different variants)
+    phrases: crate::outputs::realizer::PhraseMemory,
+
+    // Proactive Speech Budget (Unprompted utterances, e.g. Resumption Offers)
+    pub proactive_budget: crate::kernel::speech::budget::ProactiveBudget,
+    // Resumption offer held back by quiet hours (latest only)
+    deferred_resumption: Option<crate::kernel::intent::long_horizon::ResumptionNotice>,
+    // Last tick presence was Engaged (wake detection)
+    last_engaged: Tick,
+    // Last tick with audio, input or screen activity (presence decay)
+    last_activity: Tick,
+    // Warm-start summary waiting for the gate, with the tick of the wake
+    pending_warm_start: Option<(crate::kernel::warm_start::WarmStart, Tick)>,
+    // Segment consumed by a voice control (consent answer, style request); its later fragments are the same request
+    control_segment: Option<String>,
+    // Listening cues: the current user turn and its transcript stability
+    pub backchannel: crate::kernel::backchannel::Backchannel,
+    // Nightly maintenance: when to run, last run's summary, spool path (driver)
+    pub maintenance: crate::kernel::maintenance::MaintenanceScheduler,
+    // Nightly or maintenance-mode run: jobs still to run, the one with the driver, the mode to return to
+    pub maintenance_run: Option<crate::kernel::maintenance::MaintenanceRun>,
+
+    // Decision Trace (Why did the gate deny? Which candidate won?)
+    pub trace: crate::kernel::trace::DecisionTraceRing,
+
+    // State Size Accounting (Memory Pressure + Janitor)
+    pub footprint: crate::kernel::footprint::FootprintMonitor,
+    // Event queue depth, high-water mark and drops (capacity from the channel)
+    pub queue: crate::kernel::queue::QueueMonitor,
+
+    // Last dialogue turns for observers (dashboard); redacted on read
+    pub dialogue: crate::kernel::dialogue::DialogueLog,
+    // Named anchors on the conversation ("remember this point")
+    pub bookmarks: crate::kernel::bookmark::BookmarkStore,
+    // Re-grounding summary of a just-recalled bookmark, with its lead intent
+    pending_reground: Option<(String, Option<String>)>,
+
+    // Turn-level summaries: Stable intent -> reply -> acknowledged playback -> episodic entry
+    pub exchanges: crate::memory::summary::ExchangeTracker,
+    // Two-part replies: follow-up offers waiting for their answer to be heard
+    pub follow_ups: crate::kernel::follow_up::FollowUpTracker,
+    // Outputs spoken in sentence chunks (ReactorConfig::chunked_speech) and how far they got
+    pub streamer: crate::outputs::streamer::SpeechStreamer,
+
+    // Home automation bridge (Command -> MQTT / Home Assistant via InvokeTool)
+    pub home: crate::integrations::home::HomeBridge,
+    // Action tool calls in flight (deadlines; aborted on interruption)
+    pub tool_sandbox: crate::planner::sandbox::ToolSandbox,
+
+    // Federation: replicated memories / long-horizon intents, per-device consent
+    pub federation: crate::federation::Replica,
+
+    // Deterministic ids for everything created inside tick_step
+    pub ids: crate::kernel::ids::IdGenerator,
+
+    // Planner tools: read-only calendar + per-tool consent flags
+    pub calendar: crate::integrations::calendar::Calendar,
+    pub tool_consent: crate::planner::tools::ToolConsent,
+
+    // User profiles (output style), persisted
+    pub profiles: crate::kernel::profile::ProfileStore,
+
+    // Core VAD calibration per (input, output) device pair, persisted
+    pub calibration: crate::kernel::audio::calibration::CalibrationStore,
+
+    // Dialogue act phrasing (per locale), from the template file
+    pub templates: crate::outputs::phrases::PhraseTemplates,
+
+    // Passive plugins: see every tick after it has decided (`register_observer`)
+    observers: crate::kernel::observer::ObserverBus,
 
     // New config field
     pub config: ReactorConfig,
 }
 
-impl Reactor {
-    pub fn new(receiver: mpsc::Receiver<Event>, sender: mpsc::Sender<Event>, config: ReactorConfig) -> Self {
-        let _tx_clone = sender.clone(); 
+/// Reactor construction with injectable sidecars and stores.
+/// Anything not provided falls back to the production default.
+/// Use `kernel::sidecar::Disabled` to switch a subsystem off.
+pub struct ReactorBuilder {
+    receiver: mpsc::Receiver<Event>,
+    sender: mpsc::Sender<Event>,
+    config: ReactorConfig,
+    observer: Option<Box<dyn MemorySensor>>,
+    consolidator: Option<Box<dyn MemoryConsolidation>>,
+    monitor: Option<Box<dyn SelfMonitor>>,
+    sentiment: Option<Box<dyn SentimentEstimator>>,
+    lhim: Option<Box<dyn IntentManager>>,
+    arbitrator: Option<Box<dyn IntentArbitration>>,
+    episodic: Option<Box<dyn EpisodicStore + Send>>,
+    semantic: Option<Box<dyn SemanticStore + Send>>,
+    home: Option<crate::integrations::home::HomeBridge>,
+    calendar: Option<crate::integrations::calendar::Calendar>,
+    federation: Option<crate::federation::Replica>,
+    profiles: Option<crate::kernel::profile::ProfileStore>,
+    calibration: Option<crate::kernel::audio::calibration::CalibrationStore>,
+    templates: Option<crate::outputs::phrases::PhraseTemplates>,
+}
+
+impl ReactorBuilder {
+    pub fn new(receiver: mpsc::Receiver<Event>, sender: mpsc::Sender<Event>) -> Self {
+        Self {
+            receiver,
+            sender,
+            config: ReactorConfig::default(),
+            observer: None,
+            consolidator: None,
+            monitor: None,
+            sentiment: None,
+            lhim: None,
+            arbitrator: None,
+            episodic: None,
+            semantic: None,
+            home: None,
+            calendar: None,
+            federation: None,
+            profiles: None,
+            calibration: None,
+            templates: None,
+        }
+    }
+
+    pub fn config(mut self, config: ReactorConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn observer(mut self, observer: Box<dyn MemorySensor>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    pub fn consolidator(mut self, consolidator: Box<dyn MemoryConsolidation>) -> Self {
+        self.consolidator = Some(consolidator);
+        self
+    }
+
+    pub fn monitor(mut self, monitor: Box<dyn SelfMonitor>) -> Self {
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// Sentiment estimator. Default: `monitor::sentiment::LexiconSentiment`.
+    pub fn sentiment(mut self, sentiment: Box<dyn SentimentEstimator>) -> Self {
+        self.sentiment = Some(sentiment);
+        self
+    }
+
+    pub fn lhim(mut self, lhim: Box<dyn IntentManager>) -> Self {
+        self.lhim = Some(lhim);
+        self
+    }
+
+    pub fn arbitrator(mut self, arbitrator: Box<dyn IntentArbitration>) -> Self {
+        self.arbitrator = Some(arbitrator);
+        self
+    }
+
+    pub fn episodic_store(mut self, store: Box<dyn EpisodicStore + Send>) -> Self {
+        self.episodic = Some(store);
+        self
+    }
+
+    /// Provided stores are used as-is (NOT loaded). The default store is loaded from disk.
+    pub fn semantic_store(mut self, store: Box<dyn SemanticStore + Send>) -> Self {
+        self.semantic = Some(store);
+        self
+    }
+
+    /// Home automation rules. Default: loaded from `integrations::home::mapping_path()` if present.
+    pub fn home_mapping(mut self, mapping: crate::integrations::home::HomeMapping) -> Self {
+        self.home = Some(crate::integrations::home::HomeBridge::new(mapping));
+        self
+    }
+
+    /// Calendar for the `calendar_events` tool. Default: `Calendar::from_env()`.
+    pub fn calendar(mut self, calendar: crate::integrations::calendar::Calendar) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    /// Federation replica (device id + per-peer consent). Default: `Replica::from_env()`, no peers.
+    pub fn federation(mut self, replica: crate::federation::Replica) -> Self {
+        self.federation = Some(replica);
+        self
+    }
+
+    /// User profiles (output style). Default: `ProfileStore::from_default_path()`.
+    pub fn profiles(mut self, profiles: crate::kernel::profile::ProfileStore) -> Self {
+        self.profiles = Some(profiles);
+        self
+    }
+
+    /// Audio calibration profiles. Default: `CalibrationStore::from_default_path()`.
+    pub fn calibration(mut self, calibration: crate::kernel::audio::calibration::CalibrationStore) -> Self {
+        self.calibration = Some(calibration);
+        self
+    }
+
+    /// Dialogue act phrase templates. Default: `PhraseTemplates::from_default_path()`.
+    pub fn templates(mut self, templates: crate::outputs::phrases::PhraseTemplates) -> Self {
+        self.templates = Some(templates);
+        self
+    }
+
+    pub fn build(self) -> Reactor {
+        let mut config = self.config;
+        config.vad = config.vad.sanitized();
+        let sender = self.sender;
         let mut telemetry = TelemetryRecorder::new();
         
         if config.safe_mode {
@@ -92,42 +402,632 @@ impl Reactor {
 
         // Initialize Semantic Store
         // For now, store in the current directory or a known location.
-        let semantic_path = PathBuf::from("nexus_semantic_memory.json");
-        let mut semantic = FileSemanticStore::new(semantic_path);
-        if let Err(e) = semantic.load() {
-            warn!("Failed to load semantic memory: {:?}", e);
+        let semantic = self.semantic.unwrap_or_else(|| {
+            let semantic_path = PathBuf::from("nexus_semantic_memory.json");
+            let mut semantic = FileSemanticStore::new(semantic_path);
+            if let Err(e) = semantic.load() {
+                warn!("Failed to load semantic memory: {:?}", e);
+            }
+            Box::new(semantic)
+        });
+
+        // Devices start as the system defaults until drivers report otherwise
+        let calibration = self.calibration.unwrap_or_else(crate::kernel::audio::calibration::CalibrationStore::from_default_path);
+        let mut audio_monitor = crate::kernel::audio::monitor::AudioMonitor::with_config(48000, &config.vad);
+        if let Some(profile) = calibration.get(&crate::kernel::audio::calibration::DevicePair::default()) {
+            audio_monitor.apply_calibration(&profile, true);
         }
 
-        Self {
-            receiver,
+        Reactor {
+            receiver: self.receiver,
             _tx_clone: sender.clone(), // Use the provided sender
             state: SharedState::new(),
             scheduler: Scheduler,
             cancel_registry: CancellationRegistry::new(),
             tick: Tick::new(),
-            planner: AsyncPlanner::new(sender.clone()), // Use the provided sender
+            planner: AsyncPlanner::new(sender.clone(), telemetry.handle()), // Use the provided sender
+            reflex: crate::planner::reflex::ReflexPlanner::new(),
             last_planned_version: None,
+            filter_retrying: false,
+            last_assessment: None,
             
-            observer: MemoryObserver::new(),
-            consolidator: MemoryConsolidator::new(),
-            episodic: InMemoryEpisodicStore::new(),
+            observer: self.observer.unwrap_or_else(|| Box::new(MemoryObserver::new())),
+            consolidator: self.consolidator.unwrap_or_else(|| Box::new(MemoryConsolidator::new())),
+            episodic: self.episodic.unwrap_or_else(|| Box::new(InMemoryEpisodicStore::new())),
             semantic,
             
-            monitor: SelfObservationMonitor::new(),
-            audio_monitor: crate::kernel::audio::monitor::AudioMonitor::new(48000),
-            lhim: LongHorizonIntentManager::new(),
-            arbitrator: crate::kernel::intent::arbitrator::IntentArbitrator::new(),
+            monitor: self.monitor.unwrap_or_else(|| Box::new(SelfObservationMonitor::new())),
+            sentiment: self.sentiment.unwrap_or_else(|| Box::new(crate::monitor::sentiment::LexiconSentiment::new())),
+            sentiment_segment: None,
+            audio_monitor,
+            capture_fidelity: crate::kernel::event::CaptureFidelity::Reduced,
+            transcriptions_in_flight: std::collections::HashSet::new(),
+            cognition: crate::kernel::view::Cognition::Idle,
+            view_tx: tokio::sync::watch::channel(std::sync::Arc::new(
+                crate::kernel::view::StateView::capture(Tick { frame: 0 }, KernelMode::Active, false, config.conversation_mode, crate::kernel::view::Cognition::Idle, crate::kernel::queue::QueueStats::default(), &SharedState::new())
+            )).0,
+            lhim: self.lhim.unwrap_or_else(|| Box::new(LongHorizonIntentManager::new())),
+            topic_tracker: crate::kernel::memory::topic::TopicTracker::new(),
+            arbitrator: self.arbitrator.unwrap_or_else(|| Box::new(IntentArbitrator::with_ledger(
+                crate::kernel::intent::corrections::CorrectionLedger::from_default_path(),
+            ))),
+            hysteresis: crate::kernel::intent::hysteresis::IntentHysteresis::new(),
+            clarifications: crate::kernel::intent::clarification::ClarificationState::new(),
             telemetry, // Use the telemetry created above
+            exporter: crate::kernel::telemetry::exporter::TelemetryExporter::new(config.telemetry_export),
+            silence: crate::kernel::telemetry::silence::SilenceClassifier::default(),
+            response_gap: crate::kernel::telemetry::silence::ResponseGapTracker::new(),
+            capture_clock: crate::kernel::time::CaptureClock::new(),
             mode: KernelMode::Active, // Default to Active (Safe for Tests), Driver will override if needed.
             speech_planner: crate::kernel::speech::planner::SpeechPlanner::new(),
+            phrases: crate::outputs::realizer::PhraseMemory::new(),
+            proactive_budget: crate::kernel::speech::budget::ProactiveBudget::new(),
+            deferred_resumption: None,
+            last_engaged: Tick::new(),
+            last_activity: Tick::new(),
+            pending_warm_start: None,
+            control_segment: None,
+            backchannel: crate::kernel::backchannel::Backchannel::new(),
+            maintenance: crate::kernel::maintenance::MaintenanceScheduler::new(),
+            maintenance_run: None,
+            trace: crate::kernel::trace::DecisionTraceRing::new(config.decision_trace),
+            footprint: crate::kernel::footprint::FootprintMonitor::new(config.footprint),
+            queue: crate::kernel::queue::QueueMonitor::new(sender.max_capacity()),
+            dialogue: crate::kernel::dialogue::DialogueLog::new(),
+            bookmarks: crate::kernel::bookmark::BookmarkStore::new(),
+            pending_reground: None,
+            exchanges: crate::memory::summary::ExchangeTracker::new(),
+            follow_ups: crate::kernel::follow_up::FollowUpTracker::new(),
+            streamer: crate::outputs::streamer::SpeechStreamer::new(),
+            home: self.home.unwrap_or_else(crate::integrations::home::HomeBridge::from_default_path),
+            tool_sandbox: crate::planner::sandbox::ToolSandbox::new(),
+            calendar: self.calendar.unwrap_or_else(crate::integrations::calendar::Calendar::from_env),
+            tool_consent: crate::planner::tools::ToolConsent::default(),
+            federation: self.federation.unwrap_or_else(crate::federation::Replica::from_env),
+            profiles: self.profiles.unwrap_or_else(crate::kernel::profile::ProfileStore::from_default_path),
+            calibration,
+            templates: self.templates.unwrap_or_else(crate::outputs::phrases::PhraseTemplates::from_default_path),
+            observers: crate::kernel::observer::ObserverBus::new(),
+            ids: config.id_seed.map(crate::kernel::ids::IdGenerator::new).unwrap_or_else(crate::kernel::ids::IdGenerator::from_entropy),
             config, // Add the config field
         }
     }
+}
+
+impl Reactor {
+    /// Production defaults. Use `Reactor::builder` to inject sidecars.
+    pub fn new(receiver: mpsc::Receiver<Event>, sender: mpsc::Sender<Event>, config: ReactorConfig) -> Self {
+        ReactorBuilder::new(receiver, sender).config(config).build()
+    }
+
+    pub fn builder(receiver: mpsc::Receiver<Event>, sender: mpsc::Sender<Event>) -> ReactorBuilder {
+        ReactorBuilder::new(receiver, sender)
+    }
 
     /// Set the kernel mode (Encapsulated)
     pub fn set_mode(&mut self, mode: KernelMode) {
         info!("Kernel Mode changed to: {:?}", mode);
         self.mode = mode;
+        self.publish_view();
+    }
+
+    /// Settings: switch between full and half duplex. Takes effect on the next audio chunk.
+    pub fn set_conversation_mode(&mut self, mode: crate::kernel::presence::ConversationMode) {
+        info!("Conversation Mode changed to: {:?}", mode);
+        self.config.conversation_mode = mode;
+        self.publish_view();
+    }
+
+    /// Language replies are in (`kernel::language::reply_language`).
+    pub fn reply_language(&self) -> String {
+        crate::kernel::language::reply_language(self.state.language_preference(), self.state.spoken_language(), self.templates.configured_locale())
+    }
+
+    /// Phrase templates follow the reply language (when the table has it).
+    fn follow_language(&mut self) {
+        let language = self.reply_language();
+        self.templates.speak_in(&language);
+    }
+
+    /// Capture and playback devices in use.
+    pub fn device_pair(&self) -> crate::kernel::audio::calibration::DevicePair {
+        crate::kernel::audio::calibration::DevicePair {
+            input: self.state.input_device().cloned(),
+            output: self.state.output_device().cloned(),
+        }
+    }
+
+    /// Load the current device pair's calibration, or the defaults for a pair never calibrated.
+    fn load_calibration(&mut self) {
+        let profile = self.calibration.get(&self.device_pair());
+        let uncalibrated = crate::kernel::audio::calibration::CalibrationProfile::from_vad(&self.config.vad);
+        self.audio_monitor.apply_calibration(&profile.unwrap_or(uncalibrated), profile.is_some());
+    }
+
+    /// The active profile's output style.
+    pub fn output_style(&self) -> crate::outputs::style::OutputStyle {
+        self.profiles.style()
+    }
+
+    /// Settings: change the active profile's output style. Returns the profiles for the caller
+    /// to write back off the tick (`None`: in memory only).
+    pub fn set_output_style(&mut self, style: crate::outputs::style::OutputStyle) -> Option<crate::kernel::profile::ProfilesSnapshot> {
+        info!("Output style changed to: {:?}", style);
+        self.profiles.set_style(style);
+        self.profiles.snapshot()
+    }
+
+    /// The active profile's playback volume (percent of the normalized loudness).
+    pub fn output_volume(&self) -> u8 {
+        self.profiles.volume()
+    }
+
+    /// Settings: change the active profile's playback volume, within `MIN_VOLUME..=MAX_VOLUME`.
+    /// Returns the profiles for the caller to write back off the tick (`None`: in memory only).
+    pub fn set_output_volume(&mut self, volume: u8) -> Option<crate::kernel::profile::ProfilesSnapshot> {
+        let volume = volume.clamp(crate::outputs::loudness::MIN_VOLUME, crate::outputs::loudness::MAX_VOLUME);
+        info!("Output volume changed to: {}%", volume);
+        self.profiles.set_volume(volume);
+        self.profiles.snapshot()
+    }
+
+    /// Where replies are shown: text in caption-only mode, else voice.
+    pub fn output_channel(&self) -> crate::outputs::style::OutputChannel {
+        crate::outputs::style::OutputChannel::from_caption_only(self.config.caption_only)
+    }
+
+    /// The output safety filter with the active style's profanity tolerance applied.
+    pub fn output_filter(&self) -> crate::outputs::safety::SafetyConfig {
+        self.output_style().screening(self.config.output_filter)
+    }
+
+    /// Federation: records `peer` may receive (empty without consent). Sealing and transport
+    /// are the driver's job (`federation::channel`, feature `federation`).
+    pub fn sync_outgoing(&mut self, peer: &str) -> Vec<crate::federation::SyncRecord> {
+        self.federation.outgoing(peer, &self.state)
+    }
+
+    /// UI indicator: is the LLM planner working on a plan?
+    pub fn cognition(&self) -> crate::kernel::view::Cognition {
+        self.cognition
+    }
+
+    /// Subscribe to the per-tick State View. Readers never take the Reactor lock.
+    pub fn subscribe_view(&self) -> crate::kernel::view::StateViewReceiver {
+        self.view_tx.subscribe()
+    }
+
+    /// Handle for capture threads: stamps audio with the tick it was heard (`InputEvent::captured_at`).
+    pub fn capture_clock(&self) -> crate::kernel::time::CaptureClock {
+        self.capture_clock.clone()
+    }
+
+    fn publish_view(&self) {
+        let view = crate::kernel::view::StateView::capture(self.tick, self.mode, self.audio_monitor.is_system_speaking(), self.config.conversation_mode, self.cognition, self.queue.stats(), &self.state)
+            .with_volume(self.output_volume());
+        self.view_tx.send_replace(std::sync::Arc::new(view));
+    }
+
+    /// Doctor: exercise every subsystem on an isolated copy of the current state.
+    /// Read-only with respect to this Reactor.
+    pub fn run_self_test(&self) -> crate::kernel::self_test::SelfTestReport {
+        crate::kernel::self_test::run(&self.state, self.tick, self.config, self.episodic.len())
+    }
+
+    /// Time-indexed episodic recall (planner tool `recall_episodes`, UI "what did we talk about").
+    pub fn recall_episodes(&self, query: &crate::memory::store::EpisodicQuery) -> Vec<crate::planner::tools::RecalledEpisode> {
+        crate::planner::tools::recall_episodes(&*self.episodic, query)
+    }
+
+    /// Run a read-only planner tool under the current consent flags.
+    pub fn run_tool(&self, call: &crate::planner::tools::ToolCall, now_ms: u64) -> serde_json::Value {
+        let ctx = crate::planner::tools::ToolContext {
+            episodic: &*self.episodic,
+            calendar: &self.calendar,
+            consent: &self.tool_consent,
+            now_ms,
+        };
+        crate::planner::tools::execute_with(call, &ctx)
+    }
+
+    /// Ranked evidence for a (subject + predicate) query from both stores (`config.retrieval`).
+    pub fn retrieve_memory(&self, query_hash: u64) -> Vec<crate::memory::retriever::RetrievalHit> {
+        crate::memory::retriever::MemoryRetriever::retrieve_with(query_hash, &*self.episodic, &*self.semantic, &self.config.retrieval)
+    }
+
+    /// Memory inspector: current candidates, oldest first (key, reinforcement, age).
+    pub fn memory_candidates(&self) -> Vec<crate::kernel::memory::inspect::CandidateSummary> {
+        crate::kernel::memory::inspect::list_candidates(&self.state, self.tick)
+    }
+
+    /// Offers held back by the gate: a deferred resumption offer, a warm start not said yet.
+    pub fn deferred_outputs(&self) -> usize {
+        self.deferred_resumption.is_some() as usize + self.pending_warm_start.is_some() as usize
+    }
+
+    /// "Why was I interrupted?": the last `limit` interruptions with their causes, newest first.
+    pub fn recent_interruptions(&self, limit: usize) -> Vec<crate::kernel::cancel::InterruptionReport> {
+        crate::kernel::cancel::interruption_reports(&self.state, self.tick, limit)
+    }
+
+    /// Portable bundle of semantic memory and resolved consent ("nexus memory export").
+    pub fn export_memory(&self) -> Result<crate::memory::bundle::MemoryBundle, crate::memory::store::MemoryError> {
+        crate::memory::bundle::MemoryBundle::collect(&*self.semantic, &self.state)
+    }
+
+    /// Merge a bundle into semantic memory and consent ("nexus memory import").
+    pub fn import_memory(&mut self, bundle: &crate::memory::bundle::MemoryBundle, policy: crate::memory::bundle::ConflictPolicy)
+        -> Result<crate::memory::bundle::ImportReport, crate::memory::store::MemoryError>
+    {
+        let (report, deltas) = bundle.import(&mut *self.semantic, &self.state, policy, self.tick, &mut self.ids)?;
+        for delta in deltas {
+            self.state.reduce(delta);
+        }
+        Ok(report)
+    }
+
+    /// Bug report contents (trace, state summary, telemetry, redacted config, platform).
+    /// Collecting writes nothing; see `ReportBundle::write_zip`.
+    pub fn report_bundle(&self) -> crate::kernel::report::ReportBundle {
+        crate::kernel::report::ReportBundle::collect(self)
+    }
+
+    /// Graceful shutdown: drop work in flight, flush stores, write a checkpoint.
+    /// The returned Control effects stop the driver's side of the dropped work.
+    pub fn shutdown(&mut self, options: &crate::kernel::shutdown::ShutdownOptions)
+        -> (crate::kernel::shutdown::ShutdownReport, crate::kernel::effects::EffectBatch)
+    {
+        use crate::kernel::shutdown::{ShutdownReport, StoreFlush};
+        let mut effects = crate::kernel::effects::EffectBatch::new();
+
+        // 1. Work in flight
+        let mut events_dropped = 0;
+        while self.receiver.try_recv().is_ok() {
+            events_dropped += 1;
+        }
+        let playing = self.state.active_outputs().values()
+            .filter(|o| !matches!(o.status, crate::kernel::event::OutputStatus::Canceled | crate::kernel::event::OutputStatus::Committed))
+            .count();
+        // Unconditional: requested speech is the driver's until playback starts
+        effects.push(SideEffect::StopAudio);
+        self.state.reduce(StateDelta::AllOutputsCleared);
+        let mut dropped = playing;
+        for invocation in self.tool_sandbox.abort_all() {
+            self.home.complete(&invocation.call_id);
+            effects.push(SideEffect::CancelTool { call_id: invocation.call_id });
+            dropped += 1;
+        }
+        let mut transcriptions: Vec<String> = self.transcriptions_in_flight.drain().collect();
+        transcriptions.sort();
+        for segment_id in transcriptions {
+            effects.push(SideEffect::CancelTranscription { segment_id });
+            dropped += 1;
+        }
+        if self.planner.is_in_flight() {
+            dropped += 1;
+        }
+        self.planner.abort();
+
+        // 2. Stores
+        let mut flushed = vec![StoreFlush { store: "semantic", error: self.semantic.save().err().map(|e| format!("{:?}", e)) }];
+        self.telemetry.drain();
+        let summary = self.telemetry.aggregate_session(self.tick.frame);
+        self.telemetry.record(summary);
+        if let Some(path) = &options.telemetry_spool {
+            flushed.push(StoreFlush { store: "telemetry", error: self.telemetry.save_spool(path).err().map(|e| e.to_string()) });
+        }
+        if self.exporter.config().is_enabled() && self.state.subsystems().telemetry_export {
+            let error = self.exporter.flush(self.tick, &self.telemetry.snapshot()).err().map(|e| format!("{:?}", e));
+            flushed.push(StoreFlush { store: "telemetry_export", error });
+        }
+
+        // 3. Checkpoint
+        self.publish_view();
+        let mut checkpoint_path = None;
+        if let Some(path) = &options.checkpoint {
+            let written = serde_json::to_string_pretty(&crate::kernel::report::StateCheckpoint::collect(self))
+                .map_err(|e| e.to_string())
+                .and_then(|json| std::fs::write(path, json).map_err(|e| e.to_string()));
+            if written.is_ok() {
+                checkpoint_path = Some(path.clone());
+            }
+            flushed.push(StoreFlush { store: "checkpoint", error: written.err() });
+        }
+        if let Some(path) = &options.state {
+            let written = self.state.serialize()
+                .map_err(|e| format!("{:?}", e))
+                .and_then(|json| std::fs::write(path, json).map_err(|e| e.to_string()));
+            flushed.push(StoreFlush { store: "state", error: written.err() });
+        }
+
+        let report = ShutdownReport { tick: self.tick, flushed, checkpoint_path, pending_effects_dropped: dropped, events_dropped };
+        info!("Shutdown at tick {}: {} effects and {} events dropped, clean: {}", self.tick.frame, dropped, events_dropped, report.is_clean());
+        (report, effects)
+    }
+
+    /// Run every maintenance job now, in order, and record each result in lifecycle telemetry.
+    /// Blocking (the jobs write to disk): for drivers and tools outside the tick (`OnDemand`).
+    /// The scheduler's nightly runs go through `SideEffect::RunMaintenance` instead.
+    pub fn run_maintenance(&mut self, trigger: crate::kernel::maintenance::MaintenanceTrigger) -> crate::kernel::maintenance::MaintenanceSummary {
+        use crate::kernel::maintenance::{MaintenanceJob, MaintenanceSummary};
+        let jobs = MaintenanceJob::ALL.into_iter()
+            .map(|job| {
+                let result = self.prepare_maintenance_job(job).unwrap_or_else(|task| task.run());
+                self.record_maintenance_job(&result);
+                result
+            })
+            .collect();
+        let summary = MaintenanceSummary { tick: self.tick, trigger, jobs };
+        info!("Maintenance ({:?}) at tick {}: {:?}", trigger, self.tick.frame, summary.jobs);
+        self.maintenance.finished(summary.clone());
+        summary
+    }
+
+    // In-memory half of a job. `Ok`: settled without touching the disk (skipped, nothing on
+    // disk, failed in memory); `Err`: the disk work is left for the driver.
+    fn prepare_maintenance_job(&mut self, job: crate::kernel::maintenance::MaintenanceJob)
+        -> Result<crate::kernel::maintenance::JobResult, crate::kernel::maintenance::MaintenanceTask>
+    {
+        use crate::kernel::maintenance::{JobOutcome, JobResult, MaintenanceJob, MaintenanceTask, MaintenanceWork};
+        let memory_off = self.config.safe_mode || !self.state.subsystems().memory;
+        let settled = |outcome, items| Ok(JobResult { job, outcome, items });
+        // Counted in memory: the store goes to disk as a snapshot
+        let save_semantic = |counted: Result<u64, crate::memory::store::MemoryError>, semantic: &dyn crate::memory::store::SemanticStore| {
+            match counted {
+                Err(e) => {
+                    warn!("Maintenance job {:?} failed: {:?}", job, e);
+                    Ok(JobResult { job, outcome: JobOutcome::Failed, items: 0 })
+                }
+                Ok(items) => match semantic.snapshot() {
+                    Some(snapshot) => Err(MaintenanceTask { job, work: MaintenanceWork::SaveSemantic { snapshot, items } }),
+                    None => Ok(JobResult { job, outcome: JobOutcome::Done, items }),
+                },
+            }
+        };
+        match job {
+            // Safe mode or memory switched off: no memory logic
+            MaintenanceJob::SemanticPromotion | MaintenanceJob::StoreCompaction | MaintenanceJob::StoreMigration if memory_off => {
+                settled(JobOutcome::Skipped, 0)
+            }
+            MaintenanceJob::SemanticPromotion => {
+                let promoted = crate::memory::consolidator::promote_semantic(&*self.episodic, &mut *self.semantic, self.tick.frame);
+                save_semantic(promoted.map(|n| n as u64), &*self.semantic)
+            }
+            MaintenanceJob::StoreCompaction => {
+                let dropped = self.semantic.compact();
+                save_semantic(dropped.map(|n| n as u64), &*self.semantic)
+            }
+            // The file, not the live store: fields added since it was written get their defaults on load
+            MaintenanceJob::StoreMigration => match self.semantic.snapshot() {
+                Some(snapshot) => Err(MaintenanceTask { job, work: MaintenanceWork::MigrateSemantic { path: snapshot.path().to_path_buf() } }),
+                None => settled(JobOutcome::Skipped, 0),
+            },
+            MaintenanceJob::LogRotation => match self.maintenance.telemetry_spool.clone() {
+                Some(path) => Err(MaintenanceTask { job, work: MaintenanceWork::RotateSpool { path } }),
+                None => settled(JobOutcome::Skipped, 0),
+            },
+            MaintenanceJob::TelemetryExport if !self.state.subsystems().telemetry_export => settled(JobOutcome::Skipped, 0),
+            MaintenanceJob::TelemetryExport => match self.maintenance.telemetry_spool.clone() {
+                Some(path) => {
+                    self.telemetry.drain();
+                    let events = self.telemetry.events().cloned().collect();
+                    Err(MaintenanceTask { job, work: MaintenanceWork::ExportSpool { path, events } })
+                }
+                None => settled(JobOutcome::Skipped, 0),
+            },
+        }
+    }
+
+    // A finished job, recorded as `LifecycleEvent::Maintenance`
+    fn record_maintenance_job(&mut self, result: &crate::kernel::maintenance::JobResult) {
+        let crate::kernel::maintenance::JobResult { job, outcome, items } = *result;
+        self.telemetry.record(TelemetryEvent::Lifecycle(crate::kernel::telemetry::event::LifecycleEvent::Maintenance { job, outcome, items }));
+    }
+
+    /// Planner dispatch guard: no plan yet, or a domain the epoch policy counts changed since.
+    fn needs_plan(&self) -> bool {
+        self.last_planned_version.is_none_or(|v| self.config.epoch.changed_since(&self.state, v))
+    }
+
+    /// Speech ended: close the segment and cut the silence ASR would otherwise be sent.
+    fn finalize_segment(&mut self, segment_id: &str, end_tick: Tick) {
+        self.state.reduce(StateDelta::AudioSegmentFinalized { segment_id: segment_id.to_string(), end_tick });
+        let Some(segment) = self.state.audio_segments().get(segment_id) else {
+            return;
+        };
+        let (frames, trim) = crate::kernel::audio::trim::trim(&segment.frames, self.audio_monitor.sample_rate(), self.audio_monitor.calibration().threshold_rms);
+        if trim.total_ms() > 0 {
+            info!("Segment {} trimmed: {:?}", segment_id, trim);
+        }
+        self.state.reduce(StateDelta::AudioSegmentTrimmed { segment_id: segment_id.to_string(), frames, trim });
+    }
+
+    /// Maintenance mode: queue `jobs`, stop speaking and suspend planning and output until they have run.
+    /// A request during a run (nightly or requested) queues its jobs behind the current ones.
+    fn enter_maintenance(&mut self, jobs: Vec<crate::kernel::maintenance::MaintenanceJob>, effects: &mut crate::kernel::effects::EffectBatch) {
+        use crate::kernel::maintenance::{MaintenanceRun, MaintenanceTrigger};
+        // Mode set by hand without a run: come back Active
+        let resume = if self.mode == KernelMode::Maintenance { KernelMode::Active } else { self.mode };
+        let run = self.maintenance_run.get_or_insert_with(|| MaintenanceRun::new(Vec::new(), MaintenanceTrigger::Requested, resume));
+        run.extend(jobs);
+        if self.mode != KernelMode::Maintenance {
+            run.trigger = MaintenanceTrigger::Requested;
+            run.resume = resume;
+            info!("Entering maintenance: {:?}", run.progress());
+            if self.audio_monitor.is_system_speaking() {
+                effects.push(SideEffect::StopAudio);
+            }
+            self.set_mode(KernelMode::Maintenance);
+        }
+        if let Some(run) = &self.maintenance_run {
+            effects.push(SideEffect::MaintenanceProgress(run.progress()));
+        }
+    }
+
+    /// The driver finished the running job: record it (maintenance mode reports it).
+    fn maintenance_result(&mut self, result: crate::kernel::maintenance::JobResult, effects: &mut crate::kernel::effects::EffectBatch) {
+        use crate::kernel::maintenance::MaintenanceTrigger;
+        let Some(run) = self.maintenance_run.as_mut().filter(|run| run.running() == Some(result.job)) else {
+            warn!("Maintenance result for a job that is not running: {:?}", result);
+            return;
+        };
+        run.record(result.clone());
+        if run.trigger == MaintenanceTrigger::Requested {
+            effects.push(SideEffect::MaintenanceProgress(run.progress()));
+        }
+        self.record_maintenance_job(&result);
+    }
+
+    /// Move the run on: start the next job unless one is with the driver. A job the driver has
+    /// not answered within `MAINTENANCE_JOB_TIMEOUT_TICKS` fails (a late result is dropped).
+    /// Once the queue is empty, keep the run as the last `MaintenanceSummary`; a maintenance-mode
+    /// run returns to the mode it interrupted.
+    fn maintenance_step(&mut self, effects: &mut crate::kernel::effects::EffectBatch) {
+        use crate::kernel::maintenance::{JobOutcome, JobResult, MaintenanceSummary, MaintenanceTrigger};
+        if let Some(job) = self.maintenance_run.as_ref().and_then(|run| run.overdue(self.tick)) {
+            warn!("Maintenance job {:?} got no result from the driver; marking it failed", job);
+            self.maintenance_result(JobResult { job, outcome: JobOutcome::Failed, items: 0 }, effects);
+        }
+        let Some(mut run) = self.maintenance_run.take() else {
+            return;
+        };
+        // Jobs settled in memory (skipped, nothing to write) don't cost a tick each; stop at the first one the driver runs
+        while run.running().is_none() {
+            let Some(job) = run.next_job() else { break };
+            match self.prepare_maintenance_job(job) {
+                Ok(result) => {
+                    run.record(result.clone());
+                    if run.trigger == MaintenanceTrigger::Requested {
+                        effects.push(SideEffect::MaintenanceProgress(run.progress()));
+                    }
+                    self.record_maintenance_job(&result);
+                }
+                Err(task) => {
+                    run.start(job, self.tick);
+                    effects.push(SideEffect::RunMaintenance(task));
+                }
+            }
+        }
+        if !run.is_finished() {
+            self.maintenance_run = Some(run);
+            return;
+        }
+        let summary = MaintenanceSummary { tick: self.tick, trigger: run.trigger, jobs: run.results().to_vec() };
+        info!("Maintenance ({:?}) at tick {}: {:?}", run.trigger, self.tick.frame, summary.jobs);
+        self.maintenance.finished(summary);
+        if run.trigger == MaintenanceTrigger::Requested {
+            // Whatever the planner was answering was dropped: plan again for the current state
+            self.last_planned_version = None;
+            self.set_mode(run.resume);
+        }
+    }
+
+    /// "Restart core": graceful shutdown, then a fresh kernel with the same config.
+    /// Like an app restart, state, sidecars and episodic memory start over; with `options.state`,
+    /// the fresh kernel hydrates what shutdown persisted there (memories, intents, consent). What drivers hold
+    /// keeps working: the event channel and its queue accounting, State View subscriptions, the
+    /// capture clock, the telemetry recorder and its exporter. So do the semantic store (just flushed) and what drivers report
+    /// only once per session: kernel mode (and a maintenance run in progress), calendar, tool consent, home mapping, federation peers, profiles and audio calibration.
+    /// Embedders that inject sidecars through the builder rebuild the Reactor instead.
+    pub fn restart(&mut self, options: &crate::kernel::shutdown::ShutdownOptions)
+        -> (crate::kernel::shutdown::ShutdownReport, crate::kernel::effects::EffectBatch)
+    {
+        let (report, effects) = self.shutdown(options);
+        let (_, placeholder) = mpsc::channel(1);
+        let fresh = Reactor::new(placeholder, self._tx_clone.clone(), self.config);
+        let old = std::mem::replace(self, fresh);
+        self.receiver = old.receiver;
+        self.view_tx = old.view_tx;
+        self.capture_clock = old.capture_clock;
+        self.telemetry = old.telemetry;
+        self.exporter = old.exporter;
+        self.planner = AsyncPlanner::new(self._tx_clone.clone(), self.telemetry.handle());
+        self.semantic = old.semantic;
+        self.mode = old.mode;
+        self.maintenance_run = old.maintenance_run;
+        self.calendar = old.calendar;
+        self.tool_consent = old.tool_consent;
+        self.home = crate::integrations::home::HomeBridge::new(old.home.mapping().clone());
+        self.federation = old.federation;
+        self.profiles = old.profiles;
+        self.calibration = old.calibration;
+        self.load_calibration();
+        self.templates = old.templates;
+        self.observers = old.observers;
+        self.state.record_deltas(!self.observers.is_empty());
+        self.maintenance.telemetry_spool = old.maintenance.telemetry_spool;
+        self.queue = old.queue;
+        for subsystem in crate::kernel::subsystem::Subsystem::ALL {
+            self.state.reduce(StateDelta::SubsystemToggled { subsystem, enabled: old.state.subsystems().is_enabled(subsystem) });
+        }
+        self.state.reduce(StateDelta::LanguagePreferenceChanged(old.state.language_preference().clone()));
+        self.follow_language();
+        if let Some(path) = options.state.as_ref().filter(|_| report.flushed.iter().any(|f| f.store == "state" && f.error.is_none())) {
+            if let Err(e) = self.hydrate_from_disk(path) {
+                warn!("Restarted without persisted state: {:?}", e);
+            }
+        }
+        self.publish_view();
+        info!("Kernel restarted");
+        (report, effects)
+    }
+
+    /// Resume from a state persisted at shutdown (`kernel::persist`). Memories, consent and
+    /// presence are reduced back in; long-horizon intents go through the intent manager, as
+    /// replicated ones do (`adopt_intent`). Kernel time continues from the persisted tick, so
+    /// ages and decay stay meaningful. Call before the first step.
+    pub fn hydrate_from_disk(&mut self, path: &std::path::Path) -> Result<crate::kernel::persist::Hydrated, crate::kernel::persist::PersistError> {
+        let persisted = crate::kernel::persist::PersistedState::load(path)?;
+        let hydrated = self.hydrate(&persisted);
+        info!("Hydrated from {}: {} memories, {} intents (tick {})", path.display(), hydrated.memories, hydrated.intents, hydrated.tick.frame);
+        Ok(hydrated)
+    }
+
+    /// `hydrate_from_disk` from a state already read. A journaled session records it
+    /// (`JournalWriter::hydrated`) so replay starts from the same state.
+    pub fn hydrate(&mut self, persisted: &crate::kernel::persist::PersistedState) -> crate::kernel::persist::Hydrated {
+        if self.tick < persisted.tick {
+            self.advance_idle(persisted.tick.frame - self.tick.frame);
+            self.state.reduce(StateDelta::Tick(self.tick));
+        }
+        for delta in persisted.deltas() {
+            match delta {
+                StateDelta::LongHorizonIntentUpdate(intent) => {
+                    if let Some(delta) = self.lhim.adopt_intent(intent) {
+                        self.state.reduce(delta);
+                    }
+                }
+                delta => self.state.reduce(delta),
+            }
+        }
+        self.publish_view();
+        crate::kernel::persist::Hydrated { tick: persisted.tick, memories: persisted.long_term_memory.len(), intents: persisted.intents.len() }
+    }
+
+    /// Low-power driver (`kernel::cadence`): `ticks` passed without a step. The next
+    /// `tick_step` lands on wall-clock time, so tick-based timers (presence decay, maintenance,
+    /// expiries) keep their pace; per-step work runs once for the whole wait.
+    pub fn advance_idle(&mut self, ticks: u64) {
+        self.tick = Tick { frame: self.tick.frame + ticks };
+    }
+
+    /// Rebuild a journaled session on this fresh Reactor (`kernel::journal`): same events, same
+    /// ticks, same seed, same `SharedState`. Returns the tick the replay ended on.
+    pub fn replay(&mut self, journal: &crate::kernel::journal::Journal) -> Result<Tick, crate::kernel::journal::JournalError> {
+        crate::kernel::journal::replay(self, journal)
+    }
+
+    /// Add a passive plugin (`kernel::observer`): from the next tick on it sees the events, the
+    /// deltas applied and the effects emitted. Observers cannot change what the kernel decides.
+    pub fn register_observer(&mut self, observer: Box<dyn crate::kernel::observer::TickObserver>) {
+        info!("Tick observer registered: {}", observer.name());
+        self.observers.register(observer);
+        self.state.record_deltas(true);
+    }
+
+    /// Names of the registered tick observers (a panicking one is removed).
+    pub fn observers(&self) -> Vec<String> {
+        self.observers.names()
     }
 
     /// Pure Tick Step: Advances State. Returns SideEffects to be executed by the driver.
@@ -135,82 +1035,298 @@ impl Reactor {
     /// 
     /// **KERNEL LAW**: The Tick is advanced at the VERY START of this step. 
     /// All reductions and planning occur in the context of the *new* tick.
-    pub fn tick_step(&mut self, events: Vec<Event>) -> Vec<SideEffect> {
+    pub fn tick_step(&mut self, events: Vec<Event>) -> crate::kernel::effects::EffectBatch {
+        if self.observers.is_empty() {
+            return self.step(events);
+        }
+        let observed = events.clone();
+        let effects = self.step(events);
+        let deltas = self.state.take_applied();
+        self.observers.notify(&crate::kernel::observer::TickRecord { tick: self.tick, events: &observed, deltas: &deltas, effects: &effects });
+        self.state.record_deltas(!self.observers.is_empty());
+        effects
+    }
+
+    fn step(&mut self, events: Vec<Event>) -> crate::kernel::effects::EffectBatch {
         self.tick = self.tick.next();
         let _frame_start = self.tick.frame;
-        let old_presence = self.state.presence; // Capture old presence for transition check
+        let old_presence = self.state.presence(); // Capture old presence for transition check
         
         self.state.reduce(StateDelta::Tick(self.tick)); // Sync Time
-        let mut effects = Vec::new();
+        self.capture_clock.publish(self.tick);
+        self.trace.begin(self.tick);
+        self.ids.begin_tick(self.tick);
+        // Drained into this tick plus what arrived since
+        self.queue.observe(events.len() + self._tx_clone.max_capacity() - self._tx_clone.capacity());
+        // Phase-ordered (control -> requests -> outputs) regardless of push order
+        let mut effects = crate::kernel::effects::EffectBatch::new();
+
+        // Telemetry recorded by drivers/sidecars since the last tick
+        self.telemetry.drain();
 
         // Separate inputs and plans
         let mut inputs = Vec::new();
         let mut plans = Vec::new();
+        // Home bridge asked for confirmation this tick (suppresses the reflex command ack)
+        let mut home_asked = false;
+        // Maintenance requested this tick: the first job waits so the UI can show the screen first
+        let mut maintenance_entered = false;
 
         for event in events {
             match event {
-                Event::Input(inp) => {
-                     // Phase K Invariant: While in Onboarding, ALL user input is ignored.
-                     // This is intentional and must not be relaxed.
-                     if self.mode == KernelMode::Onboarding {
+                Event::Input(mut inp) => {
+                     // Audio timing runs on capture time. A stamp is never later than this tick.
+                     inp.captured_at = inp.captured_at.map(|at| at.min(self.tick));
+                     let heard_at = inp.captured_at.unwrap_or(self.tick);
+
+                     // Microphone permission is a platform signal, not user input: the
+                     // onboarding step that requests it must see the answer.
+                     if let super::event::InputContent::CapturePermission { state } = inp.content {
+                         if state != self.state.capture_permission() {
+                             self.state.reduce(StateDelta::CapturePermissionChanged(state));
+                             if !state.allows_listening() && state != crate::kernel::presence::CapturePermission::NotDetermined {
+                                 effects.push(SideEffect::diagnostic(
+                                     crate::kernel::diagnostic::DiagnosticCode::CaptureBlocked,
+                                     format!("permission={:?}", state),
+                                 ));
+                             }
+                         }
+                         continue;
+                     }
+                     // Playback route: a platform signal too. Speech in progress does not
+                     // continue on another device (e.g. headset unplugged mid-utterance).
+                     if let super::event::InputContent::OutputDevice { ref device } = inp.content {
+                         if device.as_ref() != self.state.output_device() {
+                             self.state.reduce(StateDelta::OutputDeviceChanged(device.clone()));
+                             self.load_calibration();
+                             if self.audio_monitor.is_system_speaking() {
+                                 effects.push(SideEffect::StopAudio);
+                                 effects.push(SideEffect::diagnostic(
+                                     crate::kernel::diagnostic::DiagnosticCode::OutputRouteChanged,
+                                     format!("device={}", if device.is_some() { "selected" } else { "default" }),
+                                 ));
+                             }
+                         }
+                         continue;
+                     }
+                     // Capture device: the pair's calibration follows it
+                     if let super::event::InputContent::InputDevice { ref device } = inp.content {
+                         if device.as_ref() != self.state.input_device() {
+                             self.state.reduce(StateDelta::InputDeviceChanged(device.clone()));
+                             self.load_calibration();
+                         }
+                         continue;
+                     }
+                     // Capture actor restarted: a segment spanning the gap is unusable
+                     if let super::event::InputContent::CaptureRestarted = inp.content {
+                         let segment = self.state.active_segment_id().cloned();
+                         self.state.reduce(StateDelta::CaptureRestarted);
+                         self.audio_monitor.capture_restarted();
+                         effects.push(SideEffect::diagnostic(
+                             crate::kernel::diagnostic::DiagnosticCode::CaptureRestarted,
+                             format!("segment={}", segment.as_deref().unwrap_or("none")),
+                         ));
+                         continue;
+                     }
+                     if let super::event::InputContent::CalibrateAudio = inp.content {
+                         info!("Audio calibration started");
+                         self.audio_monitor.start_noise_calibration();
+                         continue;
+                     }
+                     // Settings: VAD retuned; a calibrated device pair keeps its measured thresholds
+                     if let super::event::InputContent::ConfigUpdate { vad } = inp.content {
+                         let vad = vad.sanitized();
+                         if vad != self.config.vad {
+                             info!("VAD config: {:?}", vad);
+                             self.config.vad = vad;
+                             self.audio_monitor.apply_vad(&vad);
+                             if self.calibration.get(&self.device_pair()).is_none() {
+                                 self.load_calibration();
+                             }
+                         }
+                         continue;
+                     }
+                     // Maintenance mode: planning and output pause until the jobs have run
+                     if let super::event::InputContent::EnterMaintenance { ref jobs } = inp.content {
+                         self.enter_maintenance(jobs.clone(), &mut effects);
+                         maintenance_entered = true;
+                         continue;
+                     }
+                     if let super::event::InputContent::MaintenanceResult(ref result) = inp.content {
+                         self.maintenance_result(result.clone(), &mut effects);
+                         continue;
+                     }
+                     // Settings switch: applies from this tick on
+                     if let super::event::InputContent::SubsystemControl { subsystem, enabled } = inp.content {
+                         if self.state.subsystems().is_enabled(subsystem) != enabled {
+                             info!("Subsystem {:?} {}", subsystem, if enabled { "enabled" } else { "disabled" });
+                             self.state.reduce(StateDelta::SubsystemToggled { subsystem, enabled });
+                             self.telemetry.record(TelemetryEvent::SubsystemToggled { subsystem, enabled });
+                         }
+                         continue;
+                     }
+                     // Settings: pin a language (ASR and replies) or go back to detecting it
+                     if let super::event::InputContent::Language(ref preference) = inp.content {
+                         if preference != self.state.language_preference() {
+                             info!("Language preference: {:?}", preference);
+                             self.state.reduce(StateDelta::LanguagePreferenceChanged(preference.clone()));
+                             self.follow_language();
+                         }
+                         continue;
+                     }
+
+                     // Phase K Invariant: While in Onboarding, ALL user content is ignored.
+                     // This is intentional and must not be relaxed. Control inputs still pass
+                     // (`InputContent::is_control`): a dropped PlaybackEnded would leave the
+                     // kernel believing it is still speaking after onboarding.
+                     // Maintenance mode drops it too: nothing would plan an answer.
+                     if self.mode != KernelMode::Active && !inp.content.is_control() {
                          // We drop the input entirely.
-                         // Do we log it? Maybe trace.
                          // tracing::trace!("Input dropped due to Onboarding Mode");
                          continue;
                      }
+                     // Vision switched off: percepts are dropped on arrival
+                     if matches!(inp.content, super::event::InputContent::Visual(_)) && !self.state.subsystems().vision {
+                         continue;
+                     }
+                     // Wake word: Dormant -> Attentive -> Engaged, a step at a time through the graph
+                     if let super::event::InputContent::WakeWord { ref phrase } = inp.content {
+                         info!("Wake word {:?} heard", phrase);
+                         for _ in 0..2 {
+                             let before = self.state.presence();
+                             self.state.reduce(StateDelta::PresenceTransition(crate::kernel::presence::PresenceRequest::WakeWordDetected));
+                             if self.state.presence() == before {
+                                 break;
+                             }
+                         }
+                         self.last_activity = self.tick;
+                         continue;
+                     }
 
                      // 0. Pre-Process: Lifecycle Updates (AudioStatus)
                      if let super::event::InputContent::AudioStatus(ref status) = inp.content {
                           match status {
-                               super::event::AudioStatus::PlaybackStarted => {
-                                    self.audio_monitor.set_system_speaking(true);
+                               super::event::AudioStatus::PlaybackStarted { output_id } => {
+                                    self.audio_monitor.playback_started(*output_id);
+                                    if let Some(gap_ticks) = self.response_gap.playback_started(self.tick) {
+                                        self.telemetry.record(TelemetryEvent::ResponseGap { gap_ticks });
+                                    }
+                               }
+                               super::event::AudioStatus::PlaybackEnded { output_id, elapsed_ms } => {
+                                    debug!("[REACTOR] Playback {:?} ended after {}ms", output_id, elapsed_ms);
+                                    // Driver speech has no PlaybackCompleted: barge-in already dropped its exchange
+                                    match output_id {
+                                        super::event::PlaybackId::Speech(_) => {
+                                            if let Some(exchange) = self.exchanges.acknowledged(output_id) {
+                                                self.complete_exchange(exchange, &mut effects);
+                                            }
+                                        }
+                                        super::event::PlaybackId::Output(id) => {
+                                            self.streamer.ended(*id);
+                                            self.exchanges.dropped(output_id);
+                                            // Completed answers released their offer already
+                                            if self.follow_ups.ended(id) {
+                                                self.note_follow_up(*id, crate::kernel::follow_up::FollowUpOutcome::Ended);
+                                            }
+                                        }
+                                    }
+                                    // Overlapping outputs: only the last stream to end clears system_speaking
+                                    if self.audio_monitor.playback_ended(output_id) {
+                                        // CRITICAL FIX: Clear active outputs so planning can resume
+                                        self.state.reduce(StateDelta::AllOutputsCleared); 
+                                    }
                                }
-                               super::event::AudioStatus::PlaybackEnded => {
-                                    self.audio_monitor.set_system_speaking(false);
-                                    // CRITICAL FIX: Clear active outputs so planning can resume
-                                    self.state.reduce(StateDelta::AllOutputsCleared); 
+                               super::event::AudioStatus::PlaybackProgress { output_id, percent } => {
+                                    self.state.reduce(StateDelta::OutputPlaybackProgress { id: *output_id, percent: *percent });
+                               }
+                               super::event::AudioStatus::PlaybackCompleted { output_id } => {
+                                    // A sentence of a chunked output is progress; the last one completes the output
+                                    let completed = match self.streamer.completed(*output_id) {
+                                        crate::outputs::streamer::ChunkCompletion::Whole => Some(*output_id),
+                                        crate::outputs::streamer::ChunkCompletion::Last(parent) => Some(parent),
+                                        crate::outputs::streamer::ChunkCompletion::Partial { parent, percent } => {
+                                            self.state.reduce(StateDelta::OutputPlaybackProgress { id: parent, percent });
+                                            None
+                                        }
+                                    };
+                                    if let Some(output_id) = completed {
+                                        // Spoken in full -> Committed (sets committed_at)
+                                        let latency_ticks = self.state.active_outputs().get(&output_id)
+                                            .map(|o| self.tick.frame.saturating_sub(o.proposed_at.frame))
+                                            .unwrap_or(0);
+                                        self.state.reduce(StateDelta::OutputCommitted(output_id));
+                                        self.telemetry.record(TelemetryEvent::OutputLifecycle {
+                                            output_id,
+                                            event: OutputEventKind::PlaybackCompleted,
+                                            latency_ticks,
+                                        });
+                                        if let Some(exchange) = self.exchanges.acknowledged(&output_id.into()) {
+                                            self.complete_exchange(exchange, &mut effects);
+                                        }
+                                        self.follow_ups.completed(&output_id);
+                                    }
                                }
                           }
                      }
 
                      match &inp.content {
-                         super::event::InputContent::AudioChunk(samples) => {
-                             // Phase D: Core-side VAD
-                             if let Some(signal) = self.audio_monitor.process(samples) {
+                         super::event::InputContent::AudioChunk(_) | super::event::InputContent::AudioSummary(_) => {
+                             // Half duplex: not listening while speaking (no VAD, no buffering, no barge-in)
+                             if !self.config.conversation_mode.listening(self.audio_monitor.is_system_speaking()) {
+                                 continue;
+                             }
+                             // Phase D: Core-side VAD (any fidelity)
+                             let vad_signal = match &inp.content {
+                                 super::event::InputContent::AudioChunk(samples) => self.audio_monitor.process(samples),
+                                 super::event::InputContent::AudioSummary(super::event::AudioSummary::Decimated { samples, sample_rate }) => {
+                                     self.audio_monitor.process_at(samples, *sample_rate)
+                                 }
+                                 super::event::InputContent::AudioSummary(super::event::AudioSummary::Envelope { rms, duration_ms }) => {
+                                     self.audio_monitor.process_energy(*rms, *duration_ms)
+                                 }
+                                 _ => None,
+                             };
+                             if let Some(profile) = self.audio_monitor.take_calibration_update() {
+                                 info!("Audio calibration updated: {:?}", profile);
+                                 self.calibration.set(self.device_pair(), profile);
+                                 // Written back by the driver: no file I/O on the audio path
+                                 if let Some(snapshot) = self.calibration.snapshot() {
+                                     effects.push(SideEffect::PersistCalibration(snapshot));
+                                 }
+                             }
+                             if let Some(signal) = vad_signal {
                                   // Synthetic Event: VAD Signal
                                   let sig_evt = super::event::InputEvent {
                                       source: "CoreVAD".to_string(),
-                                      content: super::event::InputContent::Audio(signal.clone())
+                                      content: super::event::InputContent::Audio(signal.clone()),
+                                      captured_at: inp.captured_at,
                                   };
                                   inputs.push(sig_evt); 
 
                                   // Phase E: Buffer Cleanup on Signal
                                   match signal {
                                       super::event::AudioSignal::SpeechStart => {
-                                          let new_id = Uuid::new_v4().to_string();
-                                          let seg = AudioSegment::new(new_id, self.tick);
+                                          let new_id = self.ids.next_id();
+                                          let seg = AudioSegment::new(new_id, heard_at);
                                           self.state.reduce(StateDelta::AudioSegmentCreated(seg));
                                           
                                           // Phase G: Interruption Supremacy (Suspend Intent)
-                                          if let crate::kernel::intent::types::IntentState::Forming(cands) = &self.state.intent_state {
+                                          if let crate::kernel::intent::types::IntentState::Forming(cands) = self.state.intent_state() {
                                               // Suspend the best candidate or just the set?
                                               // For MVP, if Forming, we suspend the *most confident* one to preserve context.
                                               if let Some(best) = cands.iter().max_by(|a, b| a.confidence.partial_cmp(&b.confidence).unwrap()) {
                                                   let susp = crate::kernel::intent::types::IntentState::Suspended(best.clone());
                                                   self.state.reduce(StateDelta::AssessmentUpdate(susp));
                                               }
-                                          } else if let crate::kernel::intent::types::IntentState::Stable(cand) = &self.state.intent_state {
+                                          } else if let crate::kernel::intent::types::IntentState::Stable(cand) = self.state.intent_state() {
                                                let susp = crate::kernel::intent::types::IntentState::Suspended(cand.clone());
                                                self.state.reduce(StateDelta::AssessmentUpdate(susp));
                                           }
                                           // Note: If Suspended already, stay Suspended.
                                       }
                                       super::event::AudioSignal::SpeechEnd => {
-                                          if let Some(id) = self.state.active_segment_id.clone() {
-                                              self.state.reduce(StateDelta::AudioSegmentFinalized { 
-                                                  segment_id: id.clone(), 
-                                                  end_tick: self.tick 
-                                              });
+                                          if let Some(id) = self.state.active_segment_id().cloned() {
+                                              self.finalize_segment(&id, heard_at);
                                               effects.push(SideEffect::RequestTranscription { segment_id: id });
                                           }
                                       }
@@ -218,41 +1334,43 @@ impl Reactor {
                              }
                              
                              // Phase E: Audio Buffering (Append Frame)
-                             if let Some(id) = &self.state.active_segment_id {
-                                 let id_clone = id.clone(); // Clone ID to avoid borrow issues
+                             // Full-rate chunks only: summaries would corrupt the segment's sample rate.
+                             if let (Some(id), super::event::InputContent::AudioChunk(samples)) = (self.state.active_segment_id().cloned(), &inp.content) {
                                  self.state.reduce(StateDelta::AudioFrameAppended { 
-                                     segment_id: id_clone, 
+                                     segment_id: id, 
                                      frames: samples.clone() 
                                  });
                              }
                          },
                          // IMPORTANT: Handle explicit Audio signals (e.g. from Tests or External VAD)
                          super::event::InputContent::Audio(ref signal) => {
+                             // Half duplex: a capture-side VAD hears our own playback too (no segment, no barge-in)
+                             if *signal == super::event::AudioSignal::SpeechStart
+                                 && !self.config.conversation_mode.listening(self.audio_monitor.is_system_speaking()) {
+                                 continue;
+                             }
                              match signal {
                                  super::event::AudioSignal::SpeechStart => {
-                                      if self.state.active_segment_id.is_none() {
-                                          let new_id = Uuid::new_v4().to_string();
-                                          let seg = AudioSegment::new(new_id, self.tick);
+                                      if self.state.active_segment_id().is_none() {
+                                          let new_id = self.ids.next_id();
+                                          let seg = AudioSegment::new(new_id, heard_at);
                                           self.state.reduce(StateDelta::AudioSegmentCreated(seg));
                                           
                                           // Phase G: Interruption Supremacy (Suspend Intent)
-                                          if let crate::kernel::intent::types::IntentState::Forming(cands) = &self.state.intent_state {
+                                          if let crate::kernel::intent::types::IntentState::Forming(cands) = self.state.intent_state() {
                                               if let Some(best) = cands.iter().max_by(|a, b| a.confidence.partial_cmp(&b.confidence).unwrap()) {
                                                   let susp = crate::kernel::intent::types::IntentState::Suspended(best.clone());
                                                   self.state.reduce(StateDelta::AssessmentUpdate(susp));
                                               }
-                                          } else if let crate::kernel::intent::types::IntentState::Stable(cand) = &self.state.intent_state {
+                                          } else if let crate::kernel::intent::types::IntentState::Stable(cand) = self.state.intent_state() {
                                                let susp = crate::kernel::intent::types::IntentState::Suspended(cand.clone());
                                                self.state.reduce(StateDelta::AssessmentUpdate(susp));
                                           }
                                       }
                                  }
                                  super::event::AudioSignal::SpeechEnd => {
-                                      if let Some(id) = self.state.active_segment_id.clone() {
-                                          self.state.reduce(StateDelta::AudioSegmentFinalized { 
-                                              segment_id: id.clone(), 
-                                              end_tick: self.tick 
-                                          });
+                                      if let Some(id) = self.state.active_segment_id().cloned() {
+                                          self.finalize_segment(&id, heard_at);
                                           effects.push(SideEffect::RequestTranscription { segment_id: id });
                                       }
                                  }
@@ -261,7 +1379,7 @@ impl Reactor {
                          },
                          super::event::InputContent::Visual(super::event::VisualSignal::PerceptUpdate { hash, distance }) => {
                              // Stability logic
-                             let current_stability = self.state.visual.stability_score;
+                             let current_stability = self.state.visual().stability_score;
                              let distance_val = *distance;
                              
                              let new_stability = if distance_val < 5 {
@@ -282,7 +1400,7 @@ impl Reactor {
                          super::event::InputContent::TranscriptionRequest { segment_id } => {
                              // Gating Logic: Check availability
                              let mut accepted = false;
-                             if let Some(seg) = self.state.audio_segments.get(segment_id) {
+                             if let Some(seg) = self.state.audio_segments().get(segment_id) {
                                  if seg.status == crate::kernel::audio::segment::SegmentStatus::Pending {
                                      accepted = true;
                                  }
@@ -292,45 +1410,84 @@ impl Reactor {
                                  self.state.reduce(StateDelta::AudioSegmentTranscribing(segment_id.clone()));
                                  effects.push(SideEffect::RequestTranscription { segment_id: segment_id.clone() });
                              } else {
-                                 warn!("Transcription Request DENIED for segment: {}", segment_id);
+                                 effects.push(SideEffect::diagnostic(
+                                     crate::kernel::diagnostic::DiagnosticCode::TranscriptionDenied,
+                                     format!("segment={}", segment_id),
+                                 ));
                              }
                          },
-                          super::event::InputContent::ProvisionalText { content, confidence: _, source_id } => {
+                          super::event::InputContent::ProvisionalText { content, confidence: _, source_id, words, language, is_final } => {
                               self.state.reduce(StateDelta::AudioSegmentTranscribed { 
                                   segment_id: source_id.clone(), 
-                                  text: content.clone() 
+                                  text: content.clone(),
+                                  words: words.clone(),
+                                  language: language.as_deref().and_then(crate::kernel::language::normalize),
                               });
+                              self.follow_language();
+                              // Interim hypotheses of the segment are not turns of their own
+                              if *is_final {
+                                  self.dialogue.record(self.tick, crate::kernel::dialogue::Speaker::User, content);
+                              }
+                              if self.lift_quiet_hours(content, &mut effects) {
+                                  continue;
+                              }
+                              if self.control_segment.as_ref() == Some(source_id) {
+                                  continue;
+                              }
+                              // A fragment can still turn around ("yes... actually no"): consent, style, bookmarks and forgetting wait for the final text
+                              if *is_final && (self.answer_consent_by_voice(content, &mut effects)
+                                  || self.adjust_style_by_voice(content, &mut effects)
+                                  || self.bookmark_by_voice(content, &mut effects)
+                                  || self.forget_topic_by_voice(content, &mut effects)) {
+                                  self.control_segment = Some(source_id.clone());
+                                  continue;
+                              }
                               
                               // Phase G: Assess & Decide
-                              let new_intent_state = self.arbitrator.assess(content, source_id, &self.state.intent_state);
-                              self.state.reduce(StateDelta::AssessmentUpdate(new_intent_state.clone()));
+                              let assessed = self.arbitrator.assess(content, source_id, self.state.intent_state(), &mut self.ids);
+                              self.note_assessment(content, &assessed);
+                              // Contexts: a switch command belongs to no conversation (no memory, no intent, no reply)
+                              if self.apply_context_switch(&assessed, content) {
+                                  continue;
+                              }
+                              // Refined transcripts of the same request must not step the volume again
+                              if self.apply_volume_request(&assessed, content, &mut effects) {
+                                  self.control_segment = Some(source_id.clone());
+                                  continue;
+                              }
+                              // Hysteresis: fragments must not flap Forming <-> Stable
+                              let settled = self.hysteresis.settle(self.state.intent_state(), assessed, source_id);
+                              if let crate::kernel::intent::hysteresis::Settled::Changed { state, .. } = &settled {
+                                  self.state.reduce(StateDelta::AssessmentUpdate(state.clone()));
+                              }
+
+                              // Topic Segmentation (before Memory Ingest, so candidates get labelled)
+                              let topic_id = self.topic_tracker.observe(content, self.tick, &mut self.ids);
+                              if self.state.active_topic() != Some(&topic_id) {
+                                  self.state.reduce(StateDelta::TopicChanged(topic_id));
+                              }
                               
                               // Phase H: Memory Ingest (Edge Triggered)
-                              if let crate::kernel::intent::types::IntentState::Stable(cand) = &new_intent_state {
-                                  // Memory
-                                  let memory_deltas = self.consolidator.process_intent(cand, &self.state, &mut self.telemetry);
-                                  for d in memory_deltas {
-                                      self.state.reduce(d);
-                                  }
-                                  
-                  // Phase I: Long-Horizon Intent Registration
-                                  // This is the primary entry point for Intent Creation
-                                  let intent_deltas = self.lhim.register_intent(cand, &self.state, self.tick, &mut self.telemetry);
-                                  for d in intent_deltas {
-                                      self.state.reduce(d);
-                                  }
+                              if let crate::kernel::intent::hysteresis::Settled::Changed { state, became_stable: true } = &settled {
+                                  self.ingest_declared(state.declared(), content);
                               }
 
                               // Decide
-                              let dialogue_act = self.arbitrator.decide(&self.state.intent_state); 
-                              // (Using state.intent_state which is now updated)
+                              let dialogue_act = self.arbitrator.decide(self.state.intent_state()); 
+                              // (Using state.intent_state() which is now updated)
+                              let dialogue_act = self.clarifications.admit(dialogue_act, self.state.intent_state(), &self.config.clarification);
+                              self.trace_arbitration(&dialogue_act);
+                              let dialogue_act = self.phrases.vary_in(&self.templates, dialogue_act);
                               
                                if let Some(speech_intent) = self.speech_planner.plan(&dialogue_act, self.config.safe_mode) {
                                     info!("SpeechPlanner produced intent: {:?}", speech_intent);
-                                    let output_id = Uuid::new_v4();
+                                    let output_id = self.ids.next_uuid();
                                     
                                     // Telemetry: Log the cognitive decision to speak
                                     self.telemetry.record(TelemetryEvent::DialogueAct { act: (&dialogue_act).into() });
+                                    if let crate::kernel::intent::types::DialogueAct::AskClarification(_) = dialogue_act {
+                                        self.reflex.note_clarification_asked(self.tick);
+                                    }
 
                                     effects.push(SideEffect::RequestSpeech { 
                                         intent: speech_intent, 
@@ -343,6 +1500,7 @@ impl Reactor {
                                        // No-op
                                    }
                                }
+                               home_asked |= self.route_home_command(content, &mut effects);
 
                                inputs.push(inp); // Propagate text to other systems
                           },
@@ -350,51 +1508,120 @@ impl Reactor {
                           super::event::InputContent::Text(text) => {
                               // Treat direct text input exactly like high-confidence transcription
                               self.state.reduce(StateDelta::InputReceived(inp.clone()));
+                              self.dialogue.record(self.tick, crate::kernel::dialogue::Speaker::User, text);
+                              if self.lift_quiet_hours(text, &mut effects) || self.answer_consent_by_voice(text, &mut effects) || self.adjust_style_by_voice(text, &mut effects)
+                                  || self.bookmark_by_voice(text, &mut effects) || self.forget_topic_by_voice(text, &mut effects)
+                              {
+                                  continue;
+                              }
                               
                               // Phase G: Assess & Decide
-                              let new_intent_state = self.arbitrator.assess(text, &inp.source, &self.state.intent_state);
+                              let new_intent_state = self.arbitrator.assess(text, &inp.source, self.state.intent_state(), &mut self.ids);
+                              self.note_assessment(text, &new_intent_state);
+                              if self.apply_context_switch(&new_intent_state, text) || self.apply_volume_request(&new_intent_state, text, &mut effects) {
+                                  continue;
+                              }
+                              // Typed text is final: no fragment evidence carries over
+                              self.hysteresis.reset();
                               self.state.reduce(StateDelta::AssessmentUpdate(new_intent_state.clone()));
+
+                              // Topic Segmentation
+                              let topic_id = self.topic_tracker.observe(text, self.tick, &mut self.ids);
+                              if self.state.active_topic() != Some(&topic_id) {
+                                  self.state.reduce(StateDelta::TopicChanged(topic_id));
+                              }
                               
                               // Phase H: Memory Ingest
-                              if let crate::kernel::intent::types::IntentState::Stable(cand) = &new_intent_state {
-                                  let memory_deltas = self.consolidator.process_intent(cand, &self.state, &mut self.telemetry);
-                                  for d in memory_deltas { self.state.reduce(d); }
-                                  
-                                  // Phase I: LHIM
-                                  let intent_deltas = self.lhim.register_intent(cand, &self.state, self.tick, &mut self.telemetry);
-                                  for d in intent_deltas { self.state.reduce(d); }
-                              }
+                              self.ingest_declared(new_intent_state.declared(), text);
                               
                               // Decide (Immediate Reaction)
-                              let dialogue_act = self.arbitrator.decide(&self.state.intent_state);
+                              let dialogue_act = self.arbitrator.decide(self.state.intent_state());
+                              let dialogue_act = self.clarifications.admit(dialogue_act, self.state.intent_state(), &self.config.clarification);
+                              self.trace_arbitration(&dialogue_act);
+                              let dialogue_act = self.phrases.vary_in(&self.templates, dialogue_act);
                                if let Some(speech_intent) = self.speech_planner.plan(&dialogue_act, self.config.safe_mode) {
-                                    let output_id = Uuid::new_v4();
+                                    let output_id = self.ids.next_uuid();
                                     self.telemetry.record(TelemetryEvent::DialogueAct { act: (&dialogue_act).into() });
+                                    if let crate::kernel::intent::types::DialogueAct::AskClarification(_) = dialogue_act {
+                                        self.reflex.note_clarification_asked(self.tick);
+                                    }
                                     effects.push(SideEffect::RequestSpeech { intent: speech_intent, output_id });
                                }
+                              home_asked |= self.route_home_command(text, &mut effects);
 
                               inputs.push(inp);
                           },
 
-                         super::event::InputContent::MemoryConsentResponse { key, state } => {
-                             self.state.reduce(StateDelta::MemoryConsentResolved {
-                                 key: key.clone(),
-                                 state: state.clone(),
-                                 resolved_at: self.tick,
-                             });
-                             // Telemetry
-                             self.telemetry.record(TelemetryEvent::MemoryEvent {
-                                 kind: crate::kernel::telemetry::event::MemoryEventKind::AttributesUpdated, // Or new ConsentResolved kind? Use AttributesUpdated for now.
-                                 memory_id: "consent_update".to_string(), // Metadata
-                             });
+                         super::event::InputContent::MemoryConsentResponse(response) => {
+                             // Only the answer to the outstanding prompt counts
+                             let consent = self.state.memory_consent().get(&response.key);
+                             match crate::kernel::protocol::validate_consent(response, consent, self.tick) {
+                                 Ok(()) => self.resolve_consent(&response.key, response.state),
+                                 Err(reason) => effects.push(SideEffect::diagnostic(
+                                     crate::kernel::diagnostic::DiagnosticCode::ConsentRejected,
+                                     format!("prompt_id={} reason={:?}", response.prompt_id, reason),
+                                 )),
+                             }
+                         },
+                         super::event::InputContent::MemoryCandidateAction { id, action } => {
+                             self.apply_candidate_action(id, *action, &mut effects);
+                         },
+                         super::event::InputContent::ForgetTopic { topic_id } => {
+                             self.forget_topic(topic_id.clone(), &mut effects);
+                         },
+                         super::event::InputContent::ToolResult { call_id, ok } => {
+                             // Home bridge: say how it went (only for calls still in flight)
+                             self.tool_sandbox.complete(call_id);
+                             if let Some(rule) = self.home.complete(call_id) {
+                                 if !*ok {
+                                     effects.push(SideEffect::diagnostic(
+                                         crate::kernel::diagnostic::DiagnosticCode::ToolFailed,
+                                         format!("call_id={}", call_id),
+                                     ));
+                                 }
+                                 let seed = if *ok { "home_action_done" } else { "home_action_failed" };
+                                 let act = crate::kernel::intent::types::DialogueAct::Confirm(format!("{}: {}", seed, rule));
+                                 self.speak(&act, &mut effects);
+                             }
+                         },
+                         super::event::InputContent::SyncReceived { peer, records } => {
+                             // Federation: merge, then reduce like any local change
+                             for delta in self.federation.apply(peer, records.clone(), &self.state, self.tick) {
+                                 let delta = match delta {
+                                     StateDelta::LongHorizonIntentUpdate(intent) => self.lhim.adopt_intent(intent),
+                                     other => Some(other),
+                                 };
+                                 if let Some(delta) = delta {
+                                     self.state.reduce(delta);
+                                 }
+                             }
+                         },
+                         super::event::InputContent::LocalTime { minute_of_day } => {
+                             self.maintenance.observe_clock(*minute_of_day);
+                             let quiet = crate::kernel::quiet::next_state(self.config.quiet_hours, *minute_of_day, self.state.quiet_hours());
+                             if quiet != self.state.quiet_hours() {
+                                 info!("[REACTOR] Quiet hours: {:?} -> {:?}", self.state.quiet_hours(), quiet);
+                                 self.state.reduce(StateDelta::QuietHoursChanged(quiet));
+                             }
+                         },
+                         super::event::InputContent::ExchangeSummary { exchange_id, text } => {
+                             if let Some(exchange) = self.exchanges.summary_arrived(exchange_id) {
+                                 let entry = match text.as_deref().and_then(crate::memory::summary::clean_llm_summary) {
+                                     Some(line) => exchange.entry(line, crate::memory::types::Modality::Inferred, self.tick),
+                                     None => exchange.entry(crate::memory::summary::template_summary(&exchange), crate::memory::types::Modality::Observed, self.tick),
+                                 };
+                                 self.episodic.insert(entry);
+                             }
                          },
                          _ => {
                              inputs.push(inp);
                          }
                      }
                 },
-                Event::PlanProposed(epoch, intent) => plans.push((epoch, intent)),
-                Event::Telemetry(evt) => self.telemetry.record(evt),
+                Event::PlanProposed(epoch, intent) => {
+                    self.cognition = crate::kernel::view::Cognition::Idle;
+                    plans.push((epoch, intent));
+                }
             }
         }
         
@@ -403,33 +1630,86 @@ impl Reactor {
         let mut monitor_obs = Vec::new();
         for inp in &inputs {
             let user_obs = self.monitor.observe_raw(inp, &self.state);
+            if user_obs.iter().any(|o| matches!(o, crate::monitor::types::SelfObservation::UserCorrection { .. })) {
+                self.learn_correction(inp, &mut effects);
+            }
             monitor_obs.extend(user_obs);
+            monitor_obs.extend(self.observe_sentiment(inp));
         }
 
         // TELEMETRY: Check Presence Transition
-        if self.state.presence != old_presence {
+        if self.state.presence() != old_presence {
             self.telemetry.record(TelemetryEvent::PresenceTransition {
                 from: old_presence,
-                to: self.state.presence,
+                to: self.state.presence(),
                 tick: self.tick,
             });
         }
         
         // TELEMETRY: Silence Tracking
-        // Definition: No user speech, no system speech.
-        // We check state flags.
-        // AudioMonitor tracks system_speaking.
-        // SharedState tracks user_speaking.
-        if !self.state.user_speaking && !self.audio_monitor.is_system_speaking() {
-             self.telemetry.record(TelemetryEvent::SilencePeriod { duration_ticks: 1 });
+        // Definition: No user speech, no system speech, no user input this tick.
+        // Silence near activity is InConversation, the rest is Idle.
+        let mut user_input = false;
+        for inp in &inputs {
+            match inp.content {
+                super::event::InputContent::Audio(super::event::AudioSignal::SpeechStart) => self.response_gap.user_speech_started(),
+                // The user stopped when the silence began, not when VAD confirmed it
+                super::event::InputContent::Audio(super::event::AudioSignal::SpeechEnd) => {
+                    self.response_gap.user_speech_ended(inp.captured_at.unwrap_or(self.tick))
+                }
+                _ => {}
+            }
+            if let (super::event::InputContent::Audio(_), Some(at)) = (&inp.content, inp.captured_at) {
+                self.telemetry.record(TelemetryEvent::CaptureLag { lag_ticks: self.tick.frame - at.frame });
+            }
+            user_input |= matches!(inp.content,
+                super::event::InputContent::Text(_) | super::event::InputContent::ProvisionalText { .. } | super::event::InputContent::Audio(_));
+        }
+        let active = user_input || self.state.user_speaking() || self.audio_monitor.is_system_speaking();
+        if let Some(context) = self.silence.observe(self.tick, active) {
+             self.telemetry.record(TelemetryEvent::SilencePeriod { duration_ticks: 1, context });
         }
 
         // === 2. CANCEL (Pure Decision) ===
         let cancel_deltas = self.cancel_registry.process(&inputs);
         let has_cancellation = !cancel_deltas.is_empty();
+        let interruption_source = crate::kernel::cancel::interruption_source(&inputs);
+
+        if has_cancellation {
+            effects.push(SideEffect::StopAudio);
+            self.exchanges.interrupted();
+            for answer in self.follow_ups.interrupted() {
+                self.note_follow_up(answer, crate::kernel::follow_up::FollowUpOutcome::Canceled);
+            }
+            for invocation in self.tool_sandbox.abort_all() {
+                info!("[Sandbox] Interrupted: aborting {} ({})", invocation.tool, invocation.call_id);
+                self.home.complete(&invocation.call_id);
+                effects.push(SideEffect::CancelTool { call_id: invocation.call_id });
+            }
 
-        if has_cancellation {
-            effects.push(SideEffect::StopAudio);
+            // Interruption Context: Capture what was being said BEFORE outputs are canceled.
+            let active_intent = self.state.active_intents().values()
+                .filter(|i| i.status == crate::kernel::intent::long_horizon::IntentStatus::Active)
+                .max_by(|a, b| a.decay_score.partial_cmp(&b.decay_score).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|i| i.id.clone());
+            let records: Vec<_> = self.state.active_outputs().values()
+                .filter(|o| !matches!(o.status, crate::kernel::event::OutputStatus::Canceled | crate::kernel::event::OutputStatus::Committed))
+                .map(|o| {
+                    let total_chars = o.content.chars().count();
+                    let percent = self.state.playback_progress().get(&o.id).copied().unwrap_or(0) as usize;
+                    crate::kernel::cancel::InterruptionRecord {
+                        output_id: o.id,
+                        spoken_chars: total_chars * percent / 100,
+                        total_chars,
+                        active_intent: active_intent.clone(),
+                        tick: self.tick,
+                        source: interruption_source,
+                    }
+                })
+                .collect();
+            for record in records {
+                self.state.reduce(StateDelta::InterruptionRecorded(record));
+            }
         }
 
         // === 3. REDUCE (Causality) ===
@@ -449,11 +1729,16 @@ impl Reactor {
         }
         
         // TELEMETRY: Interruption
+        // Barge-in latency runs from when the user started speaking (capture time);
+        // explicit cancels are handled the tick they arrive.
         if has_cancellation {
-            // Latency = 0 (Same tick processing)
-             self.telemetry.record(TelemetryEvent::Interruption {
-                source: InterruptionSource::ExplicitCancel, // Default/Inferred
-                cancel_latency_ticks: 0,
+            let barge_in = inputs.iter()
+                .filter(|i| matches!(i.content, super::event::InputContent::Audio(super::event::AudioSignal::SpeechStart)))
+                .map(|i| i.captured_at.unwrap_or(self.tick))
+                .min();
+            self.telemetry.record(TelemetryEvent::Interruption {
+                source: interruption_source,
+                cancel_latency_ticks: barge_in.map(|at| self.tick.frame - at.frame).unwrap_or(0),
             });
         }
         
@@ -494,11 +1779,14 @@ impl Reactor {
                  self.state.reduce(d);
              }
         }
+        let resumptions = self.lhim.take_resumptions();
         
         if !inputs.is_empty() {
              // CRITICAL: Input invalidates current planning context. 
              // Stop the thinker.
              self.planner.abort();
+             self.cognition = crate::kernel::view::Cognition::Idle;
+             self.filter_retrying = false;
              // We reset planned version because we interrupted the thought process
              // although the state version mismatch will handle it naturally.
         }
@@ -516,7 +1804,7 @@ impl Reactor {
                  crate::kernel::event::InputContent::Audio(crate::kernel::event::AudioSignal::SpeechStart) => {
                      // High Energy / Uncertainty -> Presence Request
                      if let Some(new_state) = crate::kernel::presence::PresenceGraph::transition(
-                         self.state.presence, 
+                         self.state.presence(), 
                          crate::kernel::presence::PresenceRequest::AudioActivity
                      ) {
                          self.state.reduce(StateDelta::PresenceUpdate(new_state));
@@ -532,13 +1820,14 @@ impl Reactor {
                          }
                      });
                  }
-                 super::event::InputContent::Visual(super::event::VisualSignal::PerceptUpdate { .. }) => {
+                 super::event::InputContent::Visual(super::event::VisualSignal::PerceptUpdate { distance, .. }) => {
                      // Concept: Vision is an Anchor. 
                      // Low Uncertainty if stable.
-                     // We just record the presence of visual ground.
+                     // We just record the presence of visual ground, and whether the screen changed.
+                     let changed = if *distance >= crate::kernel::presence::SCREEN_CHANGE_DISTANCE { 1.0 } else { 0.0 };
                       self.state.reduce(StateDelta::LatentUpdate {
                          slot: crate::kernel::latent::LatentSlot {
-                             values: vec![0.5], // "Stability" vector
+                             values: vec![0.5, changed], // "Stability" vector, screen activity
                              confidence: 0.8,
                              created_at: self.tick,
                              modality: crate::kernel::latent::Modality::Visual,
@@ -550,59 +1839,128 @@ impl Reactor {
              }
         }
 
+        // Presence from activity: a busy screen keeps the user present, quiet channels decay toward Dormant
+        let screen_active = crate::kernel::presence::screen_active(self.state.latents());
+        if screen_active || user_input || self.state.latents().active(&crate::kernel::latent::Modality::Audio) > 0
+            || self.state.user_speaking() || self.audio_monitor.is_system_speaking() {
+            self.last_activity = self.tick;
+        }
+        if let Some(request) = crate::kernel::presence::activity_request(screen_active, self.tick.frame.saturating_sub(self.last_activity.frame)) {
+            self.state.reduce(StateDelta::PresenceTransition(request));
+            // One step per quiet period
+            self.last_activity = self.tick;
+        }
+
         // === MEMORY OBSERVATION (LATENTS) ===
         // Observe current latent state for candidates
-        for slot in &self.state.latents.slots {
+        for slot in &self.state.latents().slots {
             self.observer.observe_latent(slot, self.tick.frame);
         }
 
+        // === MAINTENANCE MODE (Planning and output suspended) ===
+        // Jobs run on the driver, one at a time. Plans that arrived are dropped; control answers may not speak.
+        if self.mode == KernelMode::Maintenance {
+            if !maintenance_entered {
+                self.maintenance_step(&mut effects);
+            }
+            effects.retain(|effect| effect.phase() != crate::kernel::effects::EffectPhase::Output);
+            self.publish_view();
+            self.trace.end();
+            return effects;
+        }
+
         // === 4. PLAN (Async Integration) ===
         // A) Apply VALID Proposed Plans
         let mut intents = Vec::new();
         for (epoch, intent) in plans {
-            // STALE REJECTION
-            // Allow version 0 for manual/debug injections
-            if epoch.state_version == 0 || epoch.state_version == self.state.version || epoch.state_version + 1 == self.state.version {
+            // STALE REJECTION (EpochPolicy: version lag in the declared domains, debug injections)
+            if self.config.epoch.accepts_at(&epoch, &intent, &self.state) {
                  println!("[Reactor] Accepted Plan: {:?}", intent);
+                 self.trace.note(crate::kernel::trace::TraceEntry::PlanAccepted {
+                     epoch_version: epoch.state_version,
+                     state_version: self.state.version(),
+                 });
                  intents.push(intent);
             } else {
-                println!("[Reactor] Discarded Stale Plan: Epoch {:?} vs State {}", epoch, self.state.version);
+                println!("[Reactor] Discarded Stale Plan: Epoch {:?} vs State {}", epoch, self.state.version());
+                self.trace.note(crate::kernel::trace::TraceEntry::PlanStale {
+                    epoch_version: epoch.state_version,
+                    state_version: self.state.version(),
+                });
+            }
+        }
+
+        // === 4.5 REFLEX (Synchronous micro-planner) ===
+        // Priority Rule: never override an LLM plan for the same epoch
+        // (accepted this tick, or still in flight for the current state version).
+        let llm_owns_epoch = !intents.is_empty()
+            || (self.planner.is_in_flight() && !self.needs_plan());
+        if !llm_owns_epoch && !home_asked {
+            if let Some(plan) = self.reflex.plan_speech(&inputs, &self.state, self.tick) {
+                use crate::planner::reflex::ReflexPlan;
+                use crate::kernel::intent::types::DialogueAct;
+                // Home bridge Confirm policy: a timely "yes" releases the pending action
+                if let ReflexPlan::ConfirmAnswer { affirmative } = plan {
+                    if let Some(action) = self.home.answer(affirmative, self.tick) {
+                        self.invoke_home(action, &mut effects);
+                    }
+                }
+                let act = match plan {
+                    ReflexPlan::ConfirmAnswer { affirmative: true } => DialogueAct::Confirm("affirmed".to_string()),
+                    ReflexPlan::ConfirmAnswer { affirmative: false } => DialogueAct::StaySilent,
+                    ReflexPlan::AcknowledgeCommand => DialogueAct::Confirm("command_ack".to_string()),
+                    ReflexPlan::Transcribe { .. } => DialogueAct::Wait,
+                };
+                if let Some(speech_intent) = self.speech_planner.plan(&act, self.config.safe_mode) {
+                    info!("Reflex micro-plan: {:?}", plan);
+                    self.telemetry.record(TelemetryEvent::DialogueAct { act: (&act).into() });
+                    effects.push(SideEffect::RequestSpeech { intent: speech_intent, output_id: self.ids.next_uuid() });
+                }
+            }
+        }
+        for plan in self.reflex.plan_maintenance(&self.state, self.tick) {
+            if let crate::planner::reflex::ReflexPlan::Transcribe { segment_id } = plan {
+                effects.push(SideEffect::RequestTranscription { segment_id });
             }
         }
 
         // B) Check Opportunity -> Speculate
         // If state is quiescent, ask LLM.
-        // GUARD: Only plan if we haven't already planned for this state version
-        if self.state.active_outputs().is_empty() {
-             let needs_plan = match self.last_planned_version {
-                 Some(v) => v != self.state.version,
-                 None => true,
-             };
-
-             if needs_plan {
-                 println!("[Reactor] Opportunity Detected. Dispatching to Planner (State Ver: {})", self.state.version);
+        // GUARD: Only plan if nothing the epoch policy counts changed since the last dispatch
+        if self.config.llm_planning && self.state.active_outputs().is_empty() {
+             if self.needs_plan() {
+                 println!("[Reactor] Opportunity Detected. Dispatching to Planner (State Ver: {})", self.state.version());
                  let context = self.lhim.get_context(&self.state);
-                 let snapshot = self.state.snapshot(self.tick, context);
+                 let mut snapshot = self.state.snapshot(self.tick, context);
+                 snapshot.deferred_outputs = self.deferred_outputs();
                  // Future: Inject Memory Retrieval into Snapshot here?
                  // Or does planner query it via tool?
                  // Plan says: "Planner Query -> Memory Retriever".
                  // So we don't inject passively yet.
                  self.planner.dispatch(snapshot);
-                 self.last_planned_version = Some(self.state.version);
+                 self.last_planned_version = Some(self.state.version());
+                 // Without the `llm` feature nothing was dispatched
+                 if self.planner.is_in_flight() {
+                     self.cognition = crate::kernel::view::Cognition::Planning;
+                 }
              } else {
                 // println!("[Reactor] No Plan Needed (Version Match)");
              }
         }
         
         // === 5. EMIT & 6. SCHEDULE === 
+        let plan_count = intents.len();
         for (ordinal, intent) in intents.into_iter().enumerate() {
             // PHASE 6: Crystallization Gate
-            // Intercept BeginResponse
-            if let crate::planner::types::Intent::BeginResponse { .. } = &intent {
-                 use crate::kernel::crystallizer::{check_gate, extract_snapshot, CrystallizationDecision};
-                 use crate::outputs::realizer::realize;
+            // Intercept BeginResponse (and the answer of a two-part reply)
+            if let crate::planner::types::Intent::BeginResponse { .. } | crate::planner::types::Intent::BeginResponseWithFollowUp { .. } = &intent {
+                 use crate::kernel::crystallizer::{preview_gate, extract_snapshot, CrystallizationDecision};
+                 use crate::outputs::realizer::realize_variants;
                  
-                 let decision = check_gate(&self.state);
+                 // Planner output nobody asked for is proactive (quiet hours)
+                 let explanation = preview_gate(&self.state);
+                 let decision = explanation.decision.clone();
+                 self.trace.note(crate::kernel::trace::TraceEntry::Gate(explanation));
                  println!("[Reactor] Crystallization Decision: {:?}", decision);
                  match decision {
                      CrystallizationDecision::Deny => {
@@ -625,7 +1983,14 @@ impl Reactor {
                      CrystallizationDecision::AllowPartial | CrystallizationDecision::AllowHard => {
                          // Realize Text
                          let snapshot = extract_snapshot(&self.state);
-                         let text = realize(&snapshot, &decision);
+                         let mut text = self.realized(realize_variants(&snapshot, &decision));
+                         let follow_up = match &intent {
+                             crate::planner::types::Intent::BeginResponseWithFollowUp { summary_first } => Some(*summary_first),
+                             _ => None,
+                         };
+                         if follow_up == Some(true) {
+                             text = crate::outputs::realizer::summary_sentence(&text);
+                         }
                          let status = match decision {
                              CrystallizationDecision::AllowHard => crate::kernel::event::OutputStatus::HardCommit,
                              _ => crate::kernel::event::OutputStatus::SoftCommit,
@@ -665,6 +2030,10 @@ impl Reactor {
                          
                          let effect = SideEffect::SpawnAudio(output_id, text.clone()); 
                          effects.push(effect);
+                         // The offer waits until this answer was heard (6.4)
+                         if let Some(summary_first) = follow_up {
+                             self.follow_ups.link(output_id, summary_first, self.tick);
+                         }
 
                          // === MEMORY OBSERVATION (OUTPUT) ===
                          self.observer.observe_crystallization(&output_obj, &snapshot, self.tick.frame);
@@ -674,14 +2043,186 @@ impl Reactor {
                  }
             }
         
-            let (delta_opt, effect_opt) = self.scheduler.schedule(intent, self.tick, ordinal as u16);
+            let (mut delta_opt, mut effect_opt) = self.scheduler.schedule_in(&self.templates, intent, self.tick, ordinal as u16);
+
+            // OUTPUT SAFETY: planner text is LLM text; screen it before it is spoken
+            let screened = match &effect_opt {
+                Some(SideEffect::SpawnAudio(_, text)) => {
+                    Some(crate::outputs::safety::screen(text, &self.output_filter(), !self.filter_retrying))
+                }
+                _ => None,
+            };
+            if let Some(outcome) = screened {
+                use crate::outputs::safety::FilterOutcome;
+                if let Some(event) = outcome.telemetry() {
+                    self.telemetry.record(event);
+                }
+                self.filter_retrying = matches!(outcome, FilterOutcome::Regenerate(_));
+                match outcome {
+                    FilterOutcome::Clean => {}
+                    FilterOutcome::Redacted { text, .. } => {
+                        if let Some(StateDelta::OutputProposed(output)) = &mut delta_opt {
+                            output.content = text.clone();
+                        }
+                        if let Some(SideEffect::SpawnAudio(_, spoken)) = &mut effect_opt {
+                            *spoken = text;
+                        }
+                    }
+                    FilterOutcome::Regenerate(_) => {
+                        // Plan again at the next opportunity
+                        self.last_planned_version = None;
+                        delta_opt = None;
+                        effect_opt = None;
+                    }
+                    FilterOutcome::Silenced(_) => {
+                        delta_opt = None;
+                        effect_opt = None;
+                    }
+                }
+            }
+
             if let Some(delta) = delta_opt { self.state.reduce(delta); }
             if let Some(effect) = effect_opt { effects.push(effect); }
         }
 
+        // === 6.3 BOOKMARK RE-GROUNDING (The user asked to go back) ===
+        // A reply, not proactive speech: no gate or budget. Follow-ups never speak on a tick
+        // with user input, so their first ordinal is free.
+        if let Some((text, parent_id)) = self.pending_reground.take() {
+            if !self.config.safe_mode {
+                let act = crate::kernel::intent::types::DialogueAct::Offer("bookmark_recall".to_string());
+                self.propose_proactive(text, parent_id, &act, plan_count as u16 + 2, &mut effects);
+            }
+        }
+
+        // === 6.4 FOLLOW-UP OFFERS (Second part of a two-part reply) ===
+        // Only once the answer was heard in full, and only if the user did not take the turn.
+        {
+            use crate::kernel::follow_up::FollowUpOutcome;
+
+            for follow_up in self.follow_ups.expired(self.tick) {
+                self.note_follow_up(follow_up.answer, FollowUpOutcome::Expired);
+            }
+            for (n, follow_up) in self.follow_ups.take_ready().into_iter().enumerate() {
+                if self.state.user_speaking() || user_input {
+                    self.note_follow_up(follow_up.answer, FollowUpOutcome::UserSpoke);
+                    continue;
+                }
+                let text = self.realized(self.templates.follow_up(follow_up.summary_first));
+                // After plan outputs, the resumption offer and the warm start
+                let ordinal = plan_count as u16 + 2 + n as u16;
+                let (delta, effect) = self.scheduler.follow_up(follow_up.answer, text, self.tick, ordinal);
+                self.state.reduce(delta);
+                let act = crate::kernel::intent::types::DialogueAct::Offer("follow_up".to_string());
+                self.telemetry.record(TelemetryEvent::DialogueAct { act: (&act).into() });
+                self.telemetry.record(TelemetryEvent::OutputLifecycle {
+                    output_id: crate::kernel::event::OutputId { tick: self.tick.frame, ordinal },
+                    event: OutputEventKind::SoftCommit,
+                    latency_ticks: 0,
+                });
+                effects.push(effect);
+                self.note_follow_up(follow_up.answer, FollowUpOutcome::Offered);
+            }
+        }
+
+        // === 6.5 RESUMPTION OFFER (Re-grounding after long dormancy) ===
+        // Proactive speech: Gate + Budget apply. Never talk over an active output.
+        // Quiet hours defer the latest offer until the window ends.
+        let notice = match resumptions.last() {
+            Some(notice) => Some(notice.clone()),
+            None if self.state.quiet_hours() != crate::kernel::quiet::QuietHoursState::Active
+                && self.state.consent_pending(self.tick).is_none() => self.deferred_resumption.take(),
+            None => None,
+        };
+        if let Some(notice) = notice {
+            if self.config.resumption_offers && !self.config.safe_mode && self.state.active_outputs().is_empty() {
+                use crate::kernel::crystallizer::{explain_proactive_gate, CrystallizationDecision, GateRule};
+
+                let explanation = explain_proactive_gate(&self.state);
+                let decision = explanation.decision.clone();
+                if matches!(explanation.rule, GateRule::QuietHours | GateRule::ConsentPending) {
+                    self.deferred_resumption = Some(notice.clone());
+                }
+                self.trace.note(crate::kernel::trace::TraceEntry::Gate(explanation));
+                let allowed = matches!(decision, CrystallizationDecision::AllowPartial | CrystallizationDecision::AllowHard);
+
+                if allowed && self.proactive_budget.try_spend(self.tick) {
+                    let text = self.realized(self.templates.resumption(&notice.hypothesis, notice.was_interrupted, &decision));
+                    let act = crate::kernel::intent::types::DialogueAct::Offer("resumption".to_string());
+                    info!("Resumption Offer for intent {} after {} dormant ticks", notice.intent_id, notice.dormant_ticks);
+                    // After any plan outputs this tick
+                    self.propose_proactive(text, Some(notice.intent_id.clone()), &act, plan_count as u16, &mut effects);
+                }
+            }
+        }
+
+        // === 6.6 WARM START (What is still on hold after a long gap) ===
+        let engaged = self.state.presence() == crate::kernel::presence::PresenceState::Engaged;
+        let was_engaged = old_presence == crate::kernel::presence::PresenceState::Engaged;
+        if self.config.warm_start && !self.config.safe_mode
+            && crate::kernel::warm_start::is_wake(was_engaged, engaged, self.last_engaged, self.tick)
+        {
+            let summary = crate::kernel::warm_start::summarize(&self.state, self.deferred_resumption.is_some());
+            self.pending_warm_start = summary.map(|summary| (summary, self.tick));
+        }
+        if engaged {
+            self.last_engaged = self.tick;
+        }
+        if let Some((summary, woke_at)) = self.pending_warm_start.clone() {
+            use crate::kernel::crystallizer::{explain_proactive_gate, CrystallizationDecision};
+
+            if self.tick.frame.saturating_sub(woke_at.frame) > crate::kernel::warm_start::WARM_START_HOLD_TICKS {
+                self.pending_warm_start = None;
+            } else if self.state.active_outputs().is_empty() {
+                let explanation = explain_proactive_gate(&self.state);
+                let decision = explanation.decision.clone();
+                self.trace.note(crate::kernel::trace::TraceEntry::Gate(explanation));
+                let allowed = matches!(decision, CrystallizationDecision::AllowPartial | CrystallizationDecision::AllowHard);
+
+                if allowed && self.proactive_budget.try_spend(self.tick) {
+                    self.pending_warm_start = None;
+                    let text = self.realized(self.templates.warm_start(&summary, &decision));
+                    let act = crate::kernel::intent::types::DialogueAct::Offer("warm_start".to_string());
+                    info!("Warm start: {} held, deferred offer: {}", summary.held, summary.deferred_offer);
+                    let parent_id = summary.lead.map(|(id, _)| id);
+                    self.propose_proactive(text, parent_id, &act, plan_count as u16 + 1, &mut effects);
+                }
+            }
+        }
+
+        // === 6.7 BACKCHANNEL (Listening cues in a long user turn) ===
+        // Not an output: no lifecycle, not logged, never competes for the turn
+        if self.config.backchannel && !self.config.safe_mode {
+            for inp in &inputs {
+                self.backchannel.observe(inp, self.tick, &self.state);
+            }
+            if let Some(cue) = self.backchannel.due(&self.state, self.tick) {
+                debug!("Backchannel cue {:?} (stability {:.2})", cue, self.backchannel.stability());
+                effects.push(SideEffect::PlayCue(cue));
+            }
+        }
+
+        // === 6.8 MAINTENANCE (Nightly jobs while idle) ===
+        self.maintenance.observe_presence(self.state.presence(), self.tick);
+        let busy = self.planner.is_in_flight() || !self.tool_sandbox.in_flight().is_empty() || !self.transcriptions_in_flight.is_empty();
+        if self.maintenance_run.is_none() {
+            if let Some(trigger) = self.maintenance.due(&self.config.maintenance, &self.state, busy, self.tick) {
+                info!("Maintenance due ({:?})", trigger);
+                let jobs = crate::kernel::maintenance::MaintenanceJob::ALL.to_vec();
+                self.maintenance_run = Some(crate::kernel::maintenance::MaintenanceRun::new(jobs, trigger, self.mode));
+            }
+        }
+        // The disk work goes to the driver, one job at a time
+        self.maintenance_step(&mut effects);
+
         // === MEMORY CONSOLIDATION ===
         // Drive Memory Lifecycle
         self.episodic.tick(self.tick.frame); // Decay
+        // LLM summaries that never came back: keep the template line
+        for exchange in self.exchanges.expired(self.tick) {
+            let summary = crate::memory::summary::template_summary(&exchange);
+            self.episodic.insert(exchange.entry(summary, crate::memory::types::Modality::Observed, self.tick));
+        }
         
         // let candidates = self.observer.flush();
         // if !candidates.is_empty() {
@@ -703,8 +2244,8 @@ impl Reactor {
         }
 
         // === PHASE H: MEMORY TICK ===
-        // SAFE MODE CHECK: Block memory consolidation
-        let mem_tick_deltas = if self.config.safe_mode {
+        // SAFE MODE CHECK: Block memory consolidation (also when memory is switched off)
+        let mem_tick_deltas = if self.config.safe_mode || !self.state.subsystems().memory {
             Vec::new() // No memory logic in safe mode
         } else {
             self.consolidator.tick(self.tick, &self.state, &mut self.telemetry)
@@ -714,160 +2255,705 @@ impl Reactor {
             if let StateDelta::MemoryConsentAsked(key, _) = &d {
                  // Double check safe mode (redundant but safe)
                  if self.config.safe_mode { continue; }
+                 // One prompt at a time per key: re-asking would invalidate the prompt id the UI holds
+                 if self.state.memory_consent().get(key).is_some_and(|c| c.prompt_pending(self.tick)) { continue; }
+                 // One prompt on screen at all: the rest wait (the consolidator asks again)
+                 if self.state.consent_pending(self.tick).is_some() { continue; }
 
-                 effects.push(SideEffect::AskMemoryConsent { 
-                     key: key.clone(), 
-                     prompt_id: Uuid::new_v4().to_string() 
+                 // Several facts on one topic: the answer covers the topic too
+                 let topic_id = crate::kernel::memory::topic::consent_scope(key, &self.state);
+                 let request = crate::kernel::protocol::ConsentRequest::new(self.ids.next_id(), key.clone(), self.tick)
+                     .for_topic(topic_id);
+                 self.state.reduce(d);
+                 self.state.reduce(StateDelta::MemoryConsentPrompted {
+                     key: request.key.clone(),
+                     prompt_id: request.prompt_id.clone(),
+                     expires_at: request.expires_at,
+                     topic_id: request.topic_id.clone(),
                  });
+                 effects.push(SideEffect::AskMemoryConsent(request));
+                 continue;
             }
             self.state.reduce(d);
         }
 
+        // === STATE FOOTPRINT (Accounting + Janitor) ===
+        if let Some(report) = self.footprint.tick(self.tick, &self.state, self.episodic.len()) {
+            if !report.pressured.is_empty() {
+                effects.push(SideEffect::diagnostic(
+                    crate::kernel::diagnostic::DiagnosticCode::MemoryPressure,
+                    format!("domains={:?}", report.pressured),
+                ));
+            }
+            for d in report.deltas {
+                self.state.reduce(d);
+            }
+            if let Some(keep) = report.conversation_log_target {
+                self.episodic.prune_oldest(keep);
+            }
+            self.telemetry.record(TelemetryEvent::StateFootprint {
+                footprint: report.footprint,
+                pressured: report.pressured,
+            });
+        }
+
+        // === EVENT QUEUE ===
+        if let Some(report) = self.queue.tick(self.tick) {
+            if report.new_drops > 0 {
+                effects.push(SideEffect::diagnostic(
+                    crate::kernel::diagnostic::DiagnosticCode::EventsDropped,
+                    format!("dropped={} capacity={} high_water={}", report.new_drops, report.stats.capacity, report.stats.high_water),
+                ));
+            }
+            self.telemetry.record(TelemetryEvent::EventQueue { stats: report.stats });
+        }
+
+        // === CAPTURE FIDELITY ===
+        // Push-mode capture only needs full-rate chunks while a segment is buffering.
+        let wanted = if self.state.active_segment_id().is_some() {
+            crate::kernel::event::CaptureFidelity::Full
+        } else {
+            crate::kernel::event::CaptureFidelity::Reduced
+        };
+        if wanted != self.capture_fidelity {
+            self.capture_fidelity = wanted;
+            effects.push(SideEffect::SetCaptureFidelity(wanted));
+        }
+
+        // === FEATURE DEGRADATION ===
+        // ASR compiled out: nothing will ever transcribe a segment. Discard it
+        // instead of leaving it Pending (the gate would stay closed forever).
+        #[cfg(not(feature = "asr"))]
+        effects.retain(|effect| match effect {
+            SideEffect::RequestTranscription { segment_id } => {
+                self.state.reduce(StateDelta::AudioSegmentDiscarded(segment_id.clone()));
+                false
+            }
+            _ => true,
+        });
+
+        // === TRANSCRIPTION CANCELLATION ===
+        // Track what the driver is working on; cancel anything the kernel no longer wants.
+        for effect in &effects {
+            if let SideEffect::RequestTranscription { segment_id } = effect {
+                self.transcriptions_in_flight.insert(segment_id.clone());
+            }
+        }
+        let mut cancelled = Vec::new();
+        self.transcriptions_in_flight.retain(|segment_id| {
+            use crate::kernel::audio::segment::SegmentStatus;
+            let status = self.state.audio_segments().get(segment_id).map(|s| s.status.clone());
+            if status == Some(SegmentStatus::Transcribed) {
+                return false; // Done
+            }
+            let intent_invalidated = self.state.active_intents().values().any(|i| {
+                i.status == crate::kernel::intent::long_horizon::IntentStatus::Invalidated
+                    && i.source_symbol_ids.contains(segment_id)
+            });
+            let wanted = matches!(status, Some(SegmentStatus::Pending) | Some(SegmentStatus::Transcribing));
+            if !wanted || intent_invalidated {
+                cancelled.push(segment_id.clone());
+                return false;
+            }
+            true
+        });
+        for segment_id in cancelled {
+            effects.push(SideEffect::CancelTranscription { segment_id });
+        }
+
+        // === TOOL TIMEOUTS ===
+        for invocation in self.tool_sandbox.expired(self.tick) {
+            effects.push(SideEffect::CancelTool { call_id: invocation.call_id.clone() });
+            effects.push(SideEffect::diagnostic(
+                crate::kernel::diagnostic::DiagnosticCode::ToolTimedOut,
+                format!("call_id={} tool={} deadline={}", invocation.call_id, invocation.tool, invocation.deadline.frame),
+            ));
+            if let Some(rule) = self.home.complete(&invocation.call_id) {
+                let act = crate::kernel::intent::types::DialogueAct::Confirm(format!("home_action_failed: {}", rule));
+                self.speak(&act, &mut effects);
+            }
+        }
+
+        // === DIALOGUE LOG (observers only) + EXCHANGE REPLIES ===
+        for effect in effects.phase(crate::kernel::effects::EffectPhase::Output) {
+            match effect {
+                SideEffect::SpawnAudio(output_id, text) => {
+                    self.dialogue.record(self.tick, crate::kernel::dialogue::Speaker::System, text);
+                    self.exchanges.attach((*output_id).into(), text);
+                }
+                SideEffect::RequestSpeech { intent, output_id } => {
+                    self.exchanges.attach((*output_id).into(), &crate::memory::summary::describe_speech(intent));
+                }
+                _ => {}
+            }
+        }
+
+        // === CHUNKED SPEECH === (after the log: observers and exchanges see whole replies)
+        if self.config.chunked_speech {
+            effects = effects.into_iter().flat_map(|effect| match effect {
+                SideEffect::SpawnAudio(id, text) => self.streamer.split(id, text),
+                other => vec![other],
+            }).collect();
+        }
+
+        // === TELEMETRY EXPORT (read-only) ===
+        // Written by the driver: no file append or network push inside the tick
+        if self.exporter.is_due(self.tick) && self.state.subsystems().telemetry_export {
+            effects.push(SideEffect::ExportTelemetry(self.exporter.export(self.tick, self.telemetry.snapshot())));
+        }
+
+        // === PUBLISH VIEW ===
+        self.publish_view();
+
+        self.trace.end();
         effects
     }
 
+    /// Voice override during quiet hours ("it's fine, you can talk"). Lasts until the window ends.
+    fn lift_quiet_hours(&mut self, text: &str, effects: &mut crate::kernel::effects::EffectBatch) -> bool {
+        if self.state.quiet_hours() != crate::kernel::quiet::QuietHoursState::Active || !crate::kernel::quiet::parse_override(text) {
+            return false;
+        }
+        info!("[REACTOR] Quiet hours lifted by the user");
+        self.state.reduce(StateDelta::QuietHoursChanged(crate::kernel::quiet::QuietHoursState::Overridden));
+        let act = crate::kernel::intent::types::DialogueAct::Confirm("quiet_hours_lifted".to_string());
+        self.speak(&act, effects);
+        true
+    }
+
+    /// Spoken "yes" / "no" while a consent prompt is open: resolves it like the UI would, and
+    /// tells the UI to close the prompt. Not an utterance for the arbitrator.
+    fn answer_consent_by_voice(&mut self, text: &str, effects: &mut crate::kernel::effects::EffectBatch) -> bool {
+        let Some(consent) = self.state.consent_pending(self.tick) else {
+            return false;
+        };
+        let Some(answer) = crate::kernel::memory::consent::parse_spoken_answer(text) else {
+            return false;
+        };
+        let response = crate::kernel::protocol::ConsentResponse {
+            version: crate::kernel::protocol::PROTOCOL_VERSION,
+            prompt_id: consent.prompt_id.clone().unwrap_or_default(),
+            key: consent.memory_key.clone(),
+            state: answer,
+        };
+        info!("[REACTOR] Consent prompt {} answered by voice: {:?}", response.prompt_id, answer);
+        self.resolve_consent(&response.key, answer);
+        effects.push(SideEffect::DismissConsent(response));
+        true
+    }
+
+    /// Apply a validated answer to the prompt open for `key`. A prompt that covered a topic
+    /// resolves the topic too, unless the answer is neither yes nor no.
+    fn resolve_consent(&mut self, key: &crate::kernel::memory::types::MemoryKey, state: crate::kernel::memory::consent::MemoryConsentState) {
+        use crate::kernel::memory::consent::MemoryConsentState;
+
+        let topic_id = self.state.memory_consent().get(key).and_then(|c| c.topic_id.clone());
+        self.state.reduce(StateDelta::MemoryConsentResolved { key: key.clone(), state, resolved_at: self.tick });
+        if let Some(topic_id) = topic_id.filter(|_| matches!(state, MemoryConsentState::Granted | MemoryConsentState::Declined)) {
+            info!("[REACTOR] Topic {} consent: {:?}", topic_id, state);
+            self.state.reduce(StateDelta::TopicConsentResolved { topic_id, state });
+        }
+        // Telemetry
+        self.telemetry.record(TelemetryEvent::MemoryEvent {
+            kind: crate::kernel::telemetry::event::MemoryEventKind::AttributesUpdated, // Or new ConsentResolved kind? Use AttributesUpdated for now.
+            memory_id: "consent_update".to_string(), // Metadata
+        });
+    }
+
+    /// Spoken "forget what we just talked about": forgets the active topic. Not an utterance
+    /// for the arbitrator.
+    fn forget_topic_by_voice(&mut self, text: &str, effects: &mut crate::kernel::effects::EffectBatch) -> bool {
+        if !crate::kernel::memory::topic::parse_forget_request(text) {
+            return false;
+        }
+        self.forget_topic(None, effects);
+        true
+    }
+
+    /// Forget every candidate and record learned under `topic_id` (the active topic when `None`)
+    /// and confirm. Works with memory switched off too: forgetting never needs permission.
+    fn forget_topic(&mut self, topic_id: Option<crate::kernel::memory::topic::TopicId>, effects: &mut crate::kernel::effects::EffectBatch) {
+        use crate::kernel::intent::types::DialogueAct;
+
+        let Some(topic_id) = topic_id.or_else(|| self.state.active_topic().cloned()) else {
+            self.speak(&DialogueAct::Confirm("nothing_to_forget".to_string()), effects);
+            return;
+        };
+        info!("[REACTOR] Topic {} forgotten", topic_id);
+        self.state.reduce(StateDelta::MemoryTopicForgotten(topic_id));
+        self.telemetry.record(TelemetryEvent::MemoryEvent {
+            kind: crate::kernel::telemetry::event::MemoryEventKind::Forgotten,
+            memory_id: "topic_forgotten".to_string(),
+        });
+        self.speak(&DialogueAct::Confirm("topic_forgotten".to_string()), effects);
+    }
+
+    /// Memory inspector: promote or discard a candidate by hand. Refusals are diagnostics.
+    /// A consent prompt open for the promoted key is answered and closed.
+    fn apply_candidate_action(&mut self, id: &crate::kernel::memory::types::MemoryId, action: crate::kernel::memory::inspect::CandidateAction, effects: &mut crate::kernel::effects::EffectBatch) {
+        use crate::kernel::memory::inspect::{manual_action, CandidateAction, ManualActionRefusal};
+        use crate::kernel::telemetry::event::MemoryEventKind;
+
+        let result = if self.config.safe_mode || !self.state.subsystems().memory {
+            Err(ManualActionRefusal::MemoryOff)
+        } else {
+            manual_action(id, action, &self.state, self.tick)
+        };
+        let deltas = match result {
+            Ok(deltas) => deltas,
+            Err(reason) => {
+                effects.push(SideEffect::diagnostic(
+                    crate::kernel::diagnostic::DiagnosticCode::MemoryActionRefused,
+                    format!("memory_id={} action={:?} reason={:?}", id, action, reason),
+                ));
+                return;
+            }
+        };
+        let prompt = self.state.consent_pending(self.tick)
+            .filter(|c| self.state.memory_candidates().get(id).is_some_and(|cand| cand.key == c.memory_key))
+            .cloned();
+        for delta in deltas {
+            self.state.reduce(delta);
+        }
+        let kind = match action {
+            CandidateAction::Promote => MemoryEventKind::Promoted,
+            CandidateAction::Discard => MemoryEventKind::CandidateDiscarded,
+        };
+        self.telemetry.record(TelemetryEvent::MemoryEvent { kind, memory_id: id.clone() });
+        info!("[REACTOR] Memory candidate {} {:?} by hand", id, action);
+        if let (CandidateAction::Promote, Some(consent)) = (action, prompt) {
+            effects.push(SideEffect::DismissConsent(crate::kernel::protocol::ConsentResponse {
+                version: crate::kernel::protocol::PROTOCOL_VERSION,
+                prompt_id: consent.prompt_id.unwrap_or_default(),
+                key: consent.memory_key,
+                state: crate::kernel::memory::consent::MemoryConsentState::Granted,
+            }));
+        }
+    }
+
+    /// Spoken style request ("be more formal", "no emoji"): updates the active profile and
+    /// confirms. Not an utterance for the arbitrator.
+    fn adjust_style_by_voice(&mut self, text: &str, effects: &mut crate::kernel::effects::EffectBatch) -> bool {
+        let Some(style) = crate::outputs::style::parse_style_request(text, &self.output_style()) else {
+            return false;
+        };
+        if let Some(snapshot) = self.set_output_style(style) {
+            effects.push(SideEffect::PersistProfiles(snapshot));
+        }
+        let act = crate::kernel::intent::types::DialogueAct::Confirm("output_style_updated".to_string());
+        self.speak(&act, effects);
+        true
+    }
+
+    /// Spoken bookmark command. "Remember this point" anchors the conversation here and confirms.
+    /// "Go back to the budget discussion" switches to the bookmark's context, parks whatever is
+    /// active there and resumes the bookmark's intents; the re-grounding summary is spoken after
+    /// this tick's plan outputs. Not an utterance for the arbitrator.
+    fn bookmark_by_voice(&mut self, text: &str, effects: &mut crate::kernel::effects::EffectBatch) -> bool {
+        use crate::kernel::bookmark::{capture, parse_bookmark_request, BookmarkRequest, EXCERPT_TURNS};
+        use crate::kernel::intent::types::DialogueAct;
+
+        let Some(request) = parse_bookmark_request(text) else { return false };
+        let query = match request {
+            BookmarkRequest::Create { name } => {
+                // Sensitive turns never reach a bookmark; the command's fragments may precede it
+                let turns = self.dialogue.recent(EXCERPT_TURNS * 3, crate::kernel::memory::sensitivity::RedactionLevel::Omit);
+                let bookmark = capture(name, &self.state, &turns, self.tick, self.bookmarks.len());
+                info!("Bookmark '{}': {} intents, {} memories", bookmark.name, bookmark.intent_ids.len(), bookmark.memory_ids.len());
+                self.bookmarks.add(bookmark);
+                self.speak(&DialogueAct::Confirm("bookmark_created".to_string()), effects);
+                return true;
+            }
+            BookmarkRequest::Recall { query } => query,
+        };
+        let Some(bookmark) = self.bookmarks.find(query.as_deref()).cloned() else {
+            self.speak(&DialogueAct::Confirm("bookmark_not_found".to_string()), effects);
+            return true;
+        };
+        info!("Bookmark '{}' recalled", bookmark.name);
+        self.switch_context(bookmark.context.clone());
+
+        // The bookmark's thread takes over: other active intents are parked
+        let parked: Vec<_> = self.state.active_intents().values()
+            .filter(|i| i.status == crate::kernel::intent::long_horizon::IntentStatus::Active)
+            .filter(|i| &i.context == self.state.active_context() && !bookmark.intent_ids.contains(&i.id))
+            .map(|i| i.id.clone())
+            .collect();
+        for id in parked {
+            if let Some(d) = self.lhim.suspend_intent(&id, &self.state, self.tick, &mut self.telemetry) {
+                self.state.reduce(d);
+            }
+        }
+        for id in &bookmark.intent_ids {
+            if let Some(d) = self.lhim.resume_intent(id, &self.state, self.tick, &mut self.telemetry) {
+                self.state.reduce(d);
+            }
+        }
+
+        let lead = bookmark.intent_ids.iter().find_map(|id| self.state.active_intents().get(id));
+        let hypothesis = lead.map(|i| i.hypothesis.clone());
+        let parent_id = lead.map(|i| i.id.clone());
+        let text = self.realized(self.templates.bookmark_recall(&bookmark.name, &bookmark.summary, hypothesis.as_ref()));
+        self.pending_reground = Some((text, parent_id));
+        true
+    }
+
+    /// Realizer output in the active style, avoiding recently said variants.
+    fn realized(&mut self, variants: Vec<String>) -> String {
+        let (style, channel) = (self.output_style(), self.output_channel());
+        self.phrases.choose(variants.iter().map(|v| crate::outputs::realizer::restyle(v, &style, channel)).collect())
+    }
+
+    /// Start a summarizable exchange. Sensitive and kernel-control turns are never summarized.
+    /// Rising edge into Stable (or a compound): sensitivity, memory ingest and LHIM registration
+    /// for each declared intent, in spoken order. One utterance opens one exchange.
+    fn ingest_declared(&mut self, declared: &[crate::kernel::intent::types::IntentCandidate], text: &str) {
+        for cand in declared {
+            // Privacy: classify before any candidate exists (the whole utterance, for every clause)
+            if let Some(class) = crate::kernel::memory::sensitivity::classify(text) {
+                self.state.reduce(StateDelta::IntentSensitivityMarked { intent_id: cand.id.clone(), class });
+            }
+            // Language of the segment it was heard in (memory keys are scoped to it)
+            let language = cand.source_symbol_ids.iter()
+                .find_map(|id| self.state.audio_segments().get(id).and_then(|seg| seg.language.clone()));
+            if let Some(language) = language {
+                self.state.reduce(StateDelta::IntentLanguageTagged { intent_id: cand.id.clone(), language });
+            }
+
+            // Memory
+            let memory_deltas = self.consolidator.process_intent(cand, &self.state, &mut self.telemetry, &mut self.ids);
+            for d in memory_deltas {
+                self.state.reduce(d);
+            }
+
+            // Phase I: Long-Horizon Intent Registration
+            // This is the primary entry point for Intent Creation
+            let intent_deltas = self.lhim.register_intent(cand, &self.state, self.tick, &mut self.telemetry);
+            for d in intent_deltas {
+                self.state.reduce(d);
+            }
+        }
+        if let Some(first) = declared.first() {
+            self.open_exchange(first, text);
+        }
+    }
+
+    fn open_exchange(&mut self, cand: &crate::kernel::intent::types::IntentCandidate, text: &str) {
+        if self.config.exchange_summaries == crate::memory::summary::SummaryMode::Off
+            || self.config.safe_mode
+            || cand.hypothesis == crate::kernel::intent::types::IntentHypothesis::SystemControl
+            || crate::kernel::memory::sensitivity::classify(text).is_some()
+        {
+            return;
+        }
+        self.exchanges.open(&cand.id, cand.hypothesis.clone(), text, self.state.active_topic().cloned(), self.tick);
+    }
+
+    /// Acknowledged exchange: write its summary now (template) or ask the driver (LLM).
+    fn complete_exchange(&mut self, exchange: crate::memory::summary::Exchange, effects: &mut crate::kernel::effects::EffectBatch) {
+        match self.config.exchange_summaries {
+            crate::memory::summary::SummaryMode::Off => {}
+            crate::memory::summary::SummaryMode::Template => {
+                let summary = crate::memory::summary::template_summary(&exchange);
+                self.episodic.insert(exchange.entry(summary, crate::memory::types::Modality::Observed, self.tick));
+            }
+            crate::memory::summary::SummaryMode::Llm => {
+                effects.push(SideEffect::RequestSummary { exchange_id: exchange.id.clone(), exchange: exchange.clone() });
+                self.exchanges.await_summary(exchange, self.tick);
+            }
+        }
+    }
+
+    /// Home bridge: route a Stable Command through the mapping and the Confirm policy.
+    /// The command clauses of a compound are routed in spoken order, and the utterance gets at
+    /// most one spoken reply: a confirmation question stops the routing, later clauses wait.
+    /// Returns true when the kernel asked for confirmation instead of acting.
+    fn route_home_command(&mut self, text: &str, effects: &mut crate::kernel::effects::EffectBatch) -> bool {
+        use crate::integrations::home::HomeDecision;
+        use crate::kernel::intent::types::{DialogueAct, IntentHypothesis, IntentState};
+
+        let commands: Vec<String> = match self.state.intent_state() {
+            IntentState::Stable(c) if c.hypothesis == IntentHypothesis::Command => vec![text.to_string()],
+            IntentState::Compound(clauses) => clauses.iter()
+                .zip(crate::kernel::intent::clauses::split_clauses(text))
+                .filter(|(c, _)| c.hypothesis == IntentHypothesis::Command)
+                .map(|(_, clause)| clause)
+                .collect(),
+            _ => return false,
+        };
+        let mut spoke = false;
+        for command in commands {
+            match self.home.on_command(&command, self.tick) {
+                Some(HomeDecision::Dispatch(action)) => {
+                    let mut dispatched = crate::kernel::effects::EffectBatch::new();
+                    self.invoke_home(action, &mut dispatched);
+                    for effect in dispatched.into_vec() {
+                        let speech = matches!(effect, SideEffect::RequestSpeech { .. });
+                        if !(speech && spoke) {
+                            effects.push(effect);
+                        }
+                        spoke |= speech;
+                    }
+                }
+                Some(HomeDecision::AskConfirm(action)) => {
+                    if !spoke {
+                        self.speak(&DialogueAct::AskClarification(format!("confirm_home_action: {}", action.rule)), effects);
+                    }
+                    self.reflex.note_clarification_asked(self.tick);
+                    return true;
+                }
+                None => {}
+            }
+        }
+        false
+    }
+
+    /// A follow-up offer was said or dropped: log and trace it.
+    fn note_follow_up(&mut self, answer: crate::kernel::event::OutputId, outcome: crate::kernel::follow_up::FollowUpOutcome) {
+        info!("Follow-up offer for {:?}: {:?}", answer, outcome);
+        self.trace.note(crate::kernel::trace::TraceEntry::FollowUp { answer, outcome });
+    }
+
+    /// Kernel-phrased output (proactive ones already past gate and budget): propose it and speak it.
+    fn propose_proactive(
+        &mut self,
+        text: String,
+        parent_id: Option<String>,
+        act: &crate::kernel::intent::types::DialogueAct,
+        ordinal: u16,
+        effects: &mut crate::kernel::effects::EffectBatch,
+    ) {
+        let output_id = crate::kernel::event::OutputId { tick: self.tick.frame, ordinal };
+        let output_obj = crate::kernel::event::Output {
+            id: output_id,
+            content: text.clone(),
+            status: crate::kernel::event::OutputStatus::SoftCommit,
+            proposed_at: self.tick,
+            committed_at: None,
+            parent_id,
+        };
+        self.state.reduce(StateDelta::OutputProposed(output_obj));
+
+        self.telemetry.record(TelemetryEvent::DialogueAct { act: act.into() });
+        self.telemetry.record(TelemetryEvent::OutputLifecycle {
+            output_id,
+            event: OutputEventKind::SoftCommit,
+            latency_ticks: 0,
+        });
+
+        effects.push(SideEffect::SpawnAudio(output_id, text));
+    }
+
+    /// Correction learning: remember what was just classified, unless it is itself a correction.
+    fn note_assessment(&mut self, text: &str, assessed: &crate::kernel::intent::types::IntentState) {
+        use crate::kernel::intent::corrections::{corrected_hypothesis, phrase_hash};
+        use crate::kernel::intent::types::IntentState;
+
+        if corrected_hypothesis(text).is_some() {
+            return;
+        }
+        let hypothesis = match assessed {
+            IntentState::Stable(c) => Some(c.hypothesis.clone()),
+            IntentState::Forming(cands) => cands.iter()
+                .max_by(|a, b| a.confidence.partial_cmp(&b.confidence).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|c| c.hypothesis.clone()),
+            // A correction names one reading; a compound has several
+            IntentState::Compound(_) | IntentState::Suspended(_) | IntentState::None => None,
+        };
+        self.last_assessment = hypothesis.map(|h| (phrase_hash(text), h, self.tick));
+    }
+
+    /// The monitor saw a UserCorrection. If it names the intended reading of the last phrase
+    /// ("No, I was asking") and that differs from ours, teach the arbitrator.
+    fn learn_correction(&mut self, inp: &crate::kernel::event::InputEvent, effects: &mut crate::kernel::effects::EffectBatch) {
+        let (super::event::InputContent::Text(text) | super::event::InputContent::ProvisionalText { content: text, .. }) = &inp.content else {
+            return;
+        };
+        let Some(corrected) = crate::kernel::intent::corrections::corrected_hypothesis(text) else {
+            return;
+        };
+        let Some((phrase_hash, assessed, at)) = self.last_assessment.take() else {
+            return;
+        };
+        let in_window = self.tick.frame.saturating_sub(at.frame) <= crate::kernel::intent::corrections::CORRECTION_WINDOW_TICKS;
+        if in_window && corrected != assessed {
+            info!("[Arbitrator] Learned correction: {:?} -> {:?}", assessed, corrected);
+            // Written back by the driver, like calibration
+            if let Some(snapshot) = self.arbitrator.learn_correction(phrase_hash, corrected) {
+                effects.push(SideEffect::PersistCorrections(snapshot));
+            }
+        }
+    }
+
+    /// Sentiment of a user turn for the monitor. Typed text counts per line; a spoken segment
+    /// counts once, with its first confident transcript. Low-confidence estimates are dropped.
+    fn observe_sentiment(&mut self, inp: &crate::kernel::event::InputEvent) -> Option<crate::monitor::types::SelfObservation> {
+        let (estimate, segment) = match &inp.content {
+            super::event::InputContent::Text(text) => (self.sentiment.estimate(text, None)?, None),
+            super::event::InputContent::ProvisionalText { content, source_id, language, .. } => {
+                if self.sentiment_segment.as_ref() == Some(source_id) {
+                    return None;
+                }
+                (self.sentiment.estimate(content, language.as_deref())?, Some(source_id))
+            }
+            _ => return None,
+        };
+        if estimate.confidence < crate::monitor::sentiment::MIN_SENTIMENT_CONFIDENCE {
+            return None;
+        }
+        if let Some(segment) = segment {
+            self.sentiment_segment = Some(segment.clone());
+        }
+        debug!("[Monitor] User sentiment {:.2} ({:.2})", estimate.valence, estimate.confidence);
+        Some(crate::monitor::types::SelfObservation::UserSentiment { valence: estimate.valence, confidence: estimate.confidence })
+    }
+
+    /// Dispatch through the tool sandbox: run, report (dry run) or refuse (busy).
+    fn invoke_home(&mut self, action: crate::integrations::home::HomeAction, effects: &mut crate::kernel::effects::EffectBatch) {
+        use crate::kernel::diagnostic::DiagnosticCode;
+        use crate::kernel::intent::types::DialogueAct;
+        use crate::planner::sandbox::Admission;
+
+        let call_id = self.ids.next_id();
+        let call = crate::planner::tools::ToolCall::HomeAction(action.clone());
+        let dry_run = self.config.tool_sandbox.dry_run || self.config.safe_mode || self.mode == KernelMode::Onboarding;
+        match self.tool_sandbox.admit(&call_id, &call, &self.config.tool_sandbox, dry_run, self.tick) {
+            Admission::Run => {
+                info!("[Home] Invoking '{}' ({})", action.rule, call_id);
+                self.home.dispatched(&call_id, &action);
+                effects.push(SideEffect::InvokeTool { call_id, call });
+            }
+            Admission::DryRun => {
+                effects.push(SideEffect::diagnostic(DiagnosticCode::ToolDryRun, format!("call_id={} tool={}", call_id, call.name())));
+                self.speak(&DialogueAct::Confirm(format!("home_action_dry_run: {}", action.rule)), effects);
+            }
+            Admission::Busy => {
+                effects.push(SideEffect::diagnostic(
+                    DiagnosticCode::ToolBusy,
+                    format!("call_id={} tool={} in_flight={}", call_id, call.name(), self.tool_sandbox.in_flight().len()),
+                ));
+                self.speak(&DialogueAct::Confirm(format!("home_action_failed: {}", action.rule)), effects);
+            }
+        }
+    }
+
+    /// Speak a dialogue act (no-op in safe mode), with telemetry.
+    fn speak(&mut self, act: &crate::kernel::intent::types::DialogueAct, effects: &mut crate::kernel::effects::EffectBatch) {
+        if let Some(speech_intent) = self.speech_planner.plan(act, self.config.safe_mode) {
+            self.telemetry.record(TelemetryEvent::DialogueAct { act: act.into() });
+            effects.push(SideEffect::RequestSpeech { intent: speech_intent, output_id: self.ids.next_uuid() });
+        }
+    }
+
+    /// Decision Trace: Record arbitration outcome (winner = highest-confidence candidate).
+    fn trace_arbitration(&mut self, act: &crate::kernel::intent::types::DialogueAct) {
+        if !self.trace.enabled { return; }
+        use crate::kernel::intent::types::IntentState;
+        let (winner, candidates) = match self.state.intent_state() {
+            IntentState::Forming(cands) | IntentState::Compound(cands) => (
+                cands.iter().max_by(|a, b| a.confidence.partial_cmp(&b.confidence).unwrap_or(std::cmp::Ordering::Equal)),
+                cands.len(),
+            ),
+            IntentState::Stable(c) | IntentState::Suspended(c) => (Some(c), 1),
+            IntentState::None => (None, 0),
+        };
+        self.trace.note(crate::kernel::trace::TraceEntry::Arbitration {
+            winner_id: winner.map(|c| c.id.clone()),
+            hypothesis: winner.map(|c| c.hypothesis.clone()),
+            stability: winner.map(|c| c.stability.clone()),
+            confidence: winner.map(|c| c.confidence).unwrap_or(0.0),
+            candidates,
+            act: act.into(),
+        });
+    }
+
+    /// Contexts: a Stable SystemControl intent naming a context switches to it.
+    /// Active intents of the old context are suspended (parked), the target's intent state restored.
+    /// Returns true if the utterance was consumed as a control command.
+    fn apply_context_switch(&mut self, assessed: &crate::kernel::intent::types::IntentState, text: &str) -> bool {
+        let crate::kernel::intent::types::IntentState::Stable(cand) = assessed else { return false };
+        if cand.hypothesis != crate::kernel::intent::types::IntentHypothesis::SystemControl {
+            return false;
+        }
+        let Some(target) = crate::kernel::context::parse_switch(text) else { return false };
+        self.switch_context(target);
+        true
+    }
+
+    // Park the active context's intents and make `target` active (no-op if it already is)
+    fn switch_context(&mut self, target: crate::kernel::context::ContextId) {
+        if &target == self.state.active_context() {
+            return;
+        }
+        info!("[REACTOR] Context switch: {} -> {}", self.state.active_context(), target);
+        let intent_deltas = self.lhim.handle_interruption(&self.state, self.tick, &mut self.telemetry);
+        for d in intent_deltas {
+            self.state.reduce(d);
+        }
+        self.state.reduce(StateDelta::ContextSwitched(target));
+        // Topics never span contexts
+        self.topic_tracker = crate::kernel::memory::topic::TopicTracker::new();
+        self.hysteresis.reset();
+    }
+
+    /// Playback volume: a Stable SystemControl intent asking for it ("quieter please") steps the
+    /// active profile's volume and confirms. Returns true if the utterance was consumed.
+    fn apply_volume_request(&mut self, assessed: &crate::kernel::intent::types::IntentState, text: &str, effects: &mut crate::kernel::effects::EffectBatch) -> bool {
+        let crate::kernel::intent::types::IntentState::Stable(cand) = assessed else { return false };
+        if cand.hypothesis != crate::kernel::intent::types::IntentHypothesis::SystemControl {
+            return false;
+        }
+        let Some(request) = crate::outputs::loudness::parse_volume_request(text) else { return false };
+        if let Some(snapshot) = self.set_output_volume(request.apply(self.output_volume())) {
+            effects.push(SideEffect::PersistProfiles(snapshot));
+        }
+        let act = crate::kernel::intent::types::DialogueAct::Confirm("output_volume_updated".to_string());
+        self.speak(&act, effects);
+        true
+    }
+
     /// Async Driver Loop
     pub async fn run(&mut self) {
         info!("Reactor Pipeline Started. Tick: {}ms", TICK_MS);
 
-        let mut cadence = interval(Duration::from_millis(TICK_MS));
-        cadence.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        // Fine cadence while active; Dormant and idle: blocked on the channel (NEXUS_LOW_POWER)
+        let mut cadence = crate::kernel::cadence::DriverCadence::new(Duration::from_millis(TICK_MS), crate::kernel::cadence::LowPowerConfig::from_env());
 
-        let mut audio_child: Option<tokio::sync::oneshot::Sender<()>> = None;
+        // No speech service in this loop: RequestSpeech is ignored, summaries use the template line
+        let mut executor = crate::kernel::driver::EffectExecutor::new(self, crate::kernel::queue::QueueConfig::default().driver_capacity);
+        // NEXUS_JOURNAL: every step on disk as it runs (kernel::journal)
+        let mut journal = crate::kernel::journal::JournalWriter::from_env(self.ids.seed());
 
         loop {
-            // Driver: Wait for physical time boundary
-            cadence.tick().await;
+            // Driver: Wait for physical time boundary (or the first event, in low power)
+            let wake = cadence.wait(&mut self.receiver).await;
+            if let Some(journal) = journal.as_mut() {
+                journal.idle(self.tick, wake.skipped);
+            }
+            self.advance_idle(wake.skipped);
 
             // Driver: Drain Events (Inputs + Plans)
-            let mut events: Vec<Event> = Vec::new();
+            let mut events: Vec<Event> = wake.event.into_iter().collect();
             while let Ok(event) = self.receiver.try_recv() {
                 events.push(event);
             }
 
             // Core: Execute Step
+            if let Some(journal) = journal.as_mut() {
+                journal.step(self.tick.next(), &events);
+            }
             let effects = self.tick_step(events);
 
-            // Driver: Execute Side Effects
+            // Driver: Execute Side Effects (in batch order: control before outputs)
+            let context = crate::kernel::driver::EffectContext::capture(self, &effects);
             for effect in effects {
-                match effect {
-                    SideEffect::Log(msg) => println!("[LOG] {}", msg),
-                    SideEffect::SpawnAudio(id, text) => {
-                        println!("[AUDIO-{:?}] Spawning 'say': '{}'", id, text);
-                        // [Temporary Phase D Output Harness]
-                        // 1. Kill existing
-                        if let Some(stop_tx) = audio_child.take() {
-                             let _ = stop_tx.send(()); 
-                        }
-                        // 2. Spawn new (macOS only for Phase D)
-                        // Use "say" command
-                        match tokio::process::Command::new("say")
-                            .arg(&text)
-                            .kill_on_drop(true) // Ensure it dies if we drop handle
-                            .spawn() 
-                        {
-                            Ok(mut child) => {
-                                let tx_clone = self._tx_clone.clone();
-                                let (stop_tx, mut stop_rx) = tokio::sync::oneshot::channel();
-                                
-                                tokio::spawn(async move {
-                                    // Signal Started
-                                    let _ = tx_clone.send(Event::Input(crate::kernel::event::InputEvent {
-                                        source: "Driver".to_string(),
-                                        content: crate::kernel::event::InputContent::AudioStatus(
-                                            crate::kernel::event::AudioStatus::PlaybackStarted
-                                        )
-                                    })).await;
-
-                                    // Race: Completion vs Kill
-                                    tokio::select! {
-                                        _ = child.wait() => { 
-                                            // Natural Finish or Error
-                                        }
-                                        _ = &mut stop_rx => {
-                                            // Kill Signal
-                                            let _ = child.kill().await;
-                                        }
-                                    }
-                                    
-                                    // Signal Ended (Normalized)
-                                    let _ = tx_clone.send(Event::Input(crate::kernel::event::InputEvent {
-                                        source: "Driver".to_string(),
-                                        content: crate::kernel::event::InputContent::AudioStatus(
-                                            crate::kernel::event::AudioStatus::PlaybackEnded
-                                        )
-                                    })).await;
-                                });
-                                
-                                audio_child = Some(stop_tx);
-                            },
-                            Err(e) => warn!("Failed to spawn audio: {}", e),
-                        }
-                    },
-                    SideEffect::StopAudio => {
-                         if let Some(stop_tx) = audio_child.take() {
-                            println!("[AUDIO] KILL SWITCH ACTIVATED.");
-                            let _ = stop_tx.send(());
-                        }
-                    },
-                    SideEffect::RequestTranscription { segment_id } => {
-                        info!("[TRANSCRIPTION] Requested for Segment: {}", segment_id);
-                        
-                        // 1. Retrieve Audio from SharedState
-                        let audio_data_opt = self.state.audio_segments.get(&segment_id).map(|seg| seg.frames.clone());
-                        let tx = self._tx_clone.clone();
-
-                        if let Some(frames) = audio_data_opt {
-                            tokio::spawn(async move {
-                                // 2. Write to WAV (Temp)
-                                let file_path = format!("/tmp/nexus_seg_{}.wav", segment_id);
-                                let spec = hound::WavSpec {
-                                    channels: 1,
-                                    sample_rate: 48000,
-                                    bits_per_sample: 32,
-                                    sample_format: hound::SampleFormat::Float,
-                                };
-                                
-                                if let Ok(mut writer) = hound::WavWriter::create(&file_path, spec) {
-                                    for &sample in &frames {
-                                        writer.write_sample(sample).unwrap();
-                                    }
-                                    writer.finalize().unwrap();
-                                    info!("[TRANSCRIPTION] Saved WAV to {}", file_path);
+                executor.execute(effect, &context);
+            }
+            executor.poll(&context);
+            executor.pump();
 
-                                    // 3. Spawn ASR (Mocked)
-                                    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                                    let mock_text = "Phase E verification successful. Gate is working.";
-                                    
-                                    let _ = tx.send(Event::Input(crate::kernel::event::InputEvent {
-                                        source: "ASR".to_string(),
-                                        content: crate::kernel::event::InputContent::ProvisionalText {
-                                            content: mock_text.to_string(),
-                                            confidence: 0.9,
-                                            source_id: segment_id.clone(),
-                                        }
-                                    })).await;
-                                } else {
-                                    tracing::error!("[TRANSCRIPTION] Failed to write WAV file");
-                                }
-                            });
-                        } else {
-                            warn!("[TRANSCRIPTION] Segment not found in state: {}", segment_id);
-                        }
-                    },
-                    
-                    // Legacy Reactor Loop Stub for Phase N
-                    SideEffect::RequestSpeech { .. } => {
-                        // In Phase N, main.rs drives this. Reactor::run is legacy/GUI.
-                        info!("[REACTOR] Ignoring RequestSpeech (Legacy Helper Loop)");
-                    },
-
-                    SideEffect::AskMemoryConsent { key, prompt_id: _ } => {
-                        // In Reactor test driver, we just log it. 
-                        // Real driver handles it in main.rs
-                        println!("[REACTOR-LOG] Ask Consent for key: {:?}", key);
-                    }
-                }
+            if let Some(mode) = cadence.follow(self.state.presence(), executor.is_idle()) {
+                info!("[DRIVER] Cadence -> {:?}", mode);
             }
         }
     }
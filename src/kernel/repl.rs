@@ -0,0 +1,263 @@
+//! Text REPL Driver: the kernel without audio hardware.
+//!
+//! `nexus-cli` reads stdin lines and hands them to a `ReplDriver`, which steps the Reactor
+//! synchronously and stands in for every driver the kernel talks to:
+//! - a plain line is typed text (`InputContent::Text`);
+//! - `:say TEXT` is a spoken turn: SpeechStart, SpeechEnd, then `TEXT` as the segment's
+//!   transcript (`ProvisionalText`), so the audio gate, hysteresis and arbitration all run;
+//! - `:tick [N]` lets N ticks pass, `:state` prints a summary, `:quit` ends the session.
+//!
+//! Outputs are "played" instantly: the driver reports the playback lifecycle on the next tick,
+//! as the text-only executor does. Summaries get the template line, tools fail (no tools here).
+//! After every command the driver keeps stepping for `SETTLE_TICKS`, and reports each tick
+//! that produced output or changed presence or the intent.
+
+use std::time::Duration;
+
+use crate::kernel::event::{AudioSignal, AudioStatus, Event, InputContent, InputEvent, OutputId, PlaybackId};
+use crate::kernel::intent::types::IntentState;
+use crate::kernel::presence::PresenceState;
+use crate::kernel::reactor::Reactor;
+use crate::kernel::scheduler::SideEffect;
+use crate::kernel::time::Tick;
+
+// Config Constants
+/// Ticks stepped after each command so delayed outputs land (1s @ 20ms).
+pub const SETTLE_TICKS: u64 = 50;
+/// ASR confidence of a `:say` transcript.
+pub const SAY_CONFIDENCE: f32 = 0.9;
+/// Event source of everything the REPL feeds.
+pub const REPL_SOURCE: &str = "Repl";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplCommand {
+    /// Typed text
+    Text(String),
+    /// Spoken turn with this transcript
+    Say(String),
+    /// Let ticks pass
+    Tick(u64),
+    State,
+    Quit,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplError {
+    UnknownCommand(String),
+    BadArgument(String),
+}
+
+impl ReplCommand {
+    /// PURE FUNCTION: One input line. `None` for a blank line.
+    pub fn parse(line: &str) -> Result<Option<Self>, ReplError> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(None);
+        }
+        let Some(command) = line.strip_prefix(':') else {
+            return Ok(Some(ReplCommand::Text(line.to_string())));
+        };
+        let (name, argument) = command.split_once(char::is_whitespace).unwrap_or((command, ""));
+        let argument = argument.trim();
+        match name {
+            "say" if !argument.is_empty() => Ok(Some(ReplCommand::Say(argument.to_string()))),
+            "say" => Err(ReplError::BadArgument("say needs a transcript".to_string())),
+            "tick" if argument.is_empty() => Ok(Some(ReplCommand::Tick(1))),
+            "tick" => argument.parse().map(|n| Some(ReplCommand::Tick(n))).map_err(|_| ReplError::BadArgument(argument.to_string())),
+            "state" => Ok(Some(ReplCommand::State)),
+            "quit" | "q" => Ok(Some(ReplCommand::Quit)),
+            _ => Err(ReplError::UnknownCommand(name.to_string())),
+        }
+    }
+}
+
+/// One reported tick: what the kernel said or asked, and where presence and the intent stand.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickLine {
+    pub tick: Tick,
+    pub presence: PresenceState,
+    pub intent: String,
+    /// One entry per output-like effect ("say: ...", "speech: ...", "consent: ...")
+    pub outputs: Vec<String>,
+}
+
+impl TickLine {
+    pub fn render(&self) -> String {
+        let mut text = format!("[{:>5}] {:?} | intent: {}", self.tick.frame, self.presence, self.intent);
+        for output in &self.outputs {
+            text.push_str("\n        ");
+            text.push_str(output);
+        }
+        text
+    }
+}
+
+/// PURE FUNCTION: Short form of the intent state ("stable Inquiry", "forming (2)").
+pub fn intent_summary(intent: &IntentState) -> String {
+    match intent {
+        IntentState::None => "none".to_string(),
+        IntentState::Forming(candidates) => format!("forming ({})", candidates.len()),
+        IntentState::Stable(c) => format!("stable {:?}", c.hypothesis),
+        IntentState::Suspended(c) => format!("suspended {:?}", c.hypothesis),
+        IntentState::Compound(clauses) => {
+            format!("compound {}", clauses.iter().map(|c| format!("{:?}", c.hypothesis)).collect::<Vec<_>>().join(" + "))
+        }
+    }
+}
+
+/// PURE FUNCTION: How an effect shows in the REPL (`None`: not shown).
+pub fn describe_effect(effect: &SideEffect) -> Option<String> {
+    match effect {
+        SideEffect::SpawnAudio(_, text) => Some(format!("say: {}", text)),
+        SideEffect::RequestSpeech { intent, .. } => Some(format!("speech: {}", crate::memory::summary::describe_speech(intent))),
+        SideEffect::AskMemoryConsent(request) => Some(format!("consent: remember {:?}? (prompt {})", request.key, request.prompt_id)),
+        SideEffect::InvokeTool { call, .. } => Some(format!("tool: {:?}", call)),
+        SideEffect::PlayCue(cue) => Some(format!("cue: {}", cue.sound())),
+        _ => None,
+    }
+}
+
+/// Steps a Reactor from REPL commands and answers its effects in-process.
+pub struct ReplDriver {
+    pub reactor: Reactor,
+    // Driver answers for the next tick (playback lifecycle, summaries, tool results)
+    pending: Vec<Event>,
+    // Presence and intent of the last reported tick
+    shown: Option<(PresenceState, String)>,
+    // Real-time pacing per tick (LLM planning: answers arrive over the event channel)
+    pace: Option<Duration>,
+}
+
+impl ReplDriver {
+    pub fn new(reactor: Reactor) -> Self {
+        Self { reactor, pending: Vec::new(), shown: None, pace: None }
+    }
+
+    /// Sleep `tick` between steps, so HTTP planner answers can arrive while settling.
+    pub fn paced(mut self, tick: Duration) -> Self {
+        self.pace = Some(tick);
+        self
+    }
+
+    /// Run one command. Returns the ticks worth reporting (none for `State` and `Quit`).
+    pub fn run(&mut self, command: ReplCommand) -> Vec<TickLine> {
+        let mut lines = Vec::new();
+        match command {
+            ReplCommand::Text(text) => lines.extend(self.step(vec![input(InputContent::Text(text))])),
+            ReplCommand::Say(text) => {
+                lines.extend(self.step(vec![input(InputContent::Audio(AudioSignal::SpeechStart))]));
+                let segment_id = self.reactor.state.active_segment_id().cloned();
+                lines.extend(self.step(vec![input(InputContent::Audio(AudioSignal::SpeechEnd))]));
+                if let Some(segment_id) = segment_id {
+                    lines.extend(self.step(vec![input(InputContent::ProvisionalText {
+                        content: text,
+                        confidence: SAY_CONFIDENCE,
+                        source_id: segment_id,
+                        words: Vec::new(),
+                        language: None,
+                        is_final: true,
+                    })]));
+                }
+            }
+            ReplCommand::Tick(n) => {
+                for _ in 0..n {
+                    lines.extend(self.step(Vec::new()));
+                }
+                return lines;
+            }
+            ReplCommand::State | ReplCommand::Quit => return lines,
+        }
+        for _ in 0..SETTLE_TICKS {
+            lines.extend(self.step(Vec::new()));
+        }
+        lines
+    }
+
+    /// Kernel state in a few lines (`:state`).
+    pub fn summary(&self) -> Vec<String> {
+        let state = &self.reactor.state;
+        vec![
+            format!("tick {} | mode {:?} | presence {:?}", self.reactor.tick.frame, self.reactor.mode, state.presence()),
+            format!("intent: {} | long-horizon intents: {}", intent_summary(state.intent_state()), state.active_intents().len()),
+            format!("memory: {} candidates, {} long-term", state.memory_candidates().len(), state.long_term_memory().len()),
+        ]
+    }
+
+    fn step(&mut self, mut events: Vec<Event>) -> Option<TickLine> {
+        if let Some(pace) = self.pace {
+            std::thread::sleep(pace);
+        }
+        // Whatever reached the channel (planner answers), after the driver's own answers
+        let mut inbound = std::mem::take(&mut self.pending);
+        while let Ok(event) = self.reactor.receiver.try_recv() {
+            inbound.push(event);
+        }
+        inbound.append(&mut events);
+
+        let effects = self.reactor.tick_step(inbound);
+        let mut outputs = Vec::new();
+        for effect in effects.iter() {
+            outputs.extend(describe_effect(effect));
+            self.answer(effect);
+        }
+        // Chunked outputs end after their last sentence
+        let mut chunked: Vec<OutputId> = effects.iter()
+            .filter_map(|effect| match effect {
+                SideEffect::SpawnAudio(id, _) => crate::outputs::streamer::parent_of(*id).map(|(parent, _)| parent),
+                _ => None,
+            })
+            .collect();
+        chunked.dedup();
+        for parent in chunked {
+            self.pending.push(input(InputContent::AudioStatus(AudioStatus::PlaybackEnded { output_id: parent.into(), elapsed_ms: 0 })));
+        }
+
+        let presence = self.reactor.state.presence();
+        let intent = intent_summary(self.reactor.state.intent_state());
+        let changed = self.shown.as_ref() != Some(&(presence, intent.clone()));
+        if outputs.is_empty() && !changed {
+            return None;
+        }
+        self.shown = Some((presence, intent.clone()));
+        Some(TickLine { tick: self.reactor.tick, presence, intent, outputs })
+    }
+
+    // What the drivers would report back for `effect`
+    fn answer(&mut self, effect: &SideEffect) {
+        match effect {
+            SideEffect::SpawnAudio(id, _) => match crate::outputs::streamer::parent_of(*id) {
+                // A sentence of a chunked output: the first starts it, each one completes
+                Some((parent, index)) => {
+                    if index == 0 {
+                        self.pending.push(input(InputContent::AudioStatus(AudioStatus::PlaybackStarted { output_id: parent.into() })));
+                    }
+                    self.pending.push(input(InputContent::AudioStatus(AudioStatus::PlaybackCompleted { output_id: *id })));
+                }
+                None => {
+                    self.pending.push(input(InputContent::AudioStatus(AudioStatus::PlaybackStarted { output_id: (*id).into() })));
+                    self.pending.push(input(InputContent::AudioStatus(AudioStatus::PlaybackCompleted { output_id: *id })));
+                    self.pending.push(input(InputContent::AudioStatus(AudioStatus::PlaybackEnded { output_id: (*id).into(), elapsed_ms: 0 })));
+                }
+            },
+            SideEffect::RequestSpeech { output_id, .. } => {
+                let id = PlaybackId::Speech(*output_id);
+                self.pending.push(input(InputContent::AudioStatus(AudioStatus::PlaybackStarted { output_id: id })));
+                self.pending.push(input(InputContent::AudioStatus(AudioStatus::PlaybackEnded { output_id: id, elapsed_ms: 0 })));
+            }
+            SideEffect::RequestSummary { exchange_id, .. } => {
+                self.pending.push(input(InputContent::ExchangeSummary { exchange_id: exchange_id.clone(), text: None }));
+            }
+            SideEffect::InvokeTool { call_id, .. } => {
+                self.pending.push(input(InputContent::ToolResult { call_id: call_id.clone(), ok: false }));
+            }
+            SideEffect::RunMaintenance(task) => {
+                self.pending.push(input(InputContent::MaintenanceResult(task.run())));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn input(content: InputContent) -> Event {
+    Event::Input(InputEvent { source: REPL_SOURCE.to_string(), content, captured_at: None })
+}
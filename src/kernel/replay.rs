@@ -0,0 +1,145 @@
+//! Session Replay: recorded event logs and the checkpoints golden tests compare.
+//!
+//! A `SessionLog` is what the kernel was fed: the id seed and, per tick, the events passed to
+//! `tick_step` (idle ticks are implied). The CLI records one when `NEXUS_RECORD_SESSION`
+//! names a file; canonical sessions live in `tests/fixtures/sessions/`.
+//!
+//! `replay` feeds a log to a Reactor tick by tick (idle ticks included) and returns one
+//! `ReplayCheckpoint` per tick where something happened: gate decisions, arbitration outcomes,
+//! memories promoted to long-term, and the effects emitted (variant names only). Checkpoints
+//! are content-free and deterministic, so they can be compared against golden JSON.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::crystallizer::{CrystallizationDecision, GateRule};
+use crate::kernel::event::Event;
+use crate::kernel::intent::types::{IntentHypothesis, IntentStability};
+use crate::kernel::reactor::Reactor;
+use crate::kernel::telemetry::event::DialogueActKind;
+use crate::kernel::time::Tick;
+use crate::kernel::trace::TraceEntry;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayStep {
+    pub tick: u64,
+    pub events: Vec<Event>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionLog {
+    /// Kernel id seed of the recorded session (`ReactorConfig::id_seed`)
+    pub seed: u64,
+    pub steps: Vec<ReplayStep>,
+}
+
+impl SessionLog {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, steps: Vec::new() }
+    }
+
+    /// Record the events fed to the tick about to run. Idle ticks are not stored.
+    pub fn record(&mut self, tick: Tick, events: &[Event]) {
+        if !events.is_empty() {
+            self.steps.push(ReplayStep { tick: tick.frame, events: events.to_vec() });
+        }
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GateCheckpoint {
+    pub decision: CrystallizationDecision,
+    pub rule: GateRule,
+}
+
+/// Arbitration outcome (confidence and candidate ids left out: tuning noise, not behavior).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntentCheckpoint {
+    pub hypothesis: Option<IntentHypothesis>,
+    pub stability: Option<IntentStability>,
+    pub candidates: usize,
+    pub act: DialogueActKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayCheckpoint {
+    pub tick: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gates: Vec<GateCheckpoint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub intents: Vec<IntentCheckpoint>,
+    /// Hypotheses of memories promoted to long-term this tick (sorted)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub promoted: Vec<IntentHypothesis>,
+    /// `SideEffect` variant names, in emission order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub effects: Vec<String>,
+}
+
+impl ReplayCheckpoint {
+    fn is_empty(&self) -> bool {
+        self.gates.is_empty() && self.intents.is_empty() && self.promoted.is_empty() && self.effects.is_empty()
+    }
+}
+
+// "RequestSpeech { intent: .. }" -> "RequestSpeech"
+fn variant_name(debug: &str) -> String {
+    debug.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect()
+}
+
+/// Replay `log` on `reactor` (reseeded with the log's seed, Decision Trace on).
+/// The caller provides the Reactor so stores can be isolated (see `tests/golden_replay_tests.rs`).
+pub fn replay(reactor: &mut Reactor, log: &SessionLog) -> Vec<ReplayCheckpoint> {
+    reactor.ids = crate::kernel::ids::IdGenerator::new(log.seed);
+    reactor.trace.enabled = true;
+
+    let mut checkpoints = Vec::new();
+    for step in &log.steps {
+        while reactor.tick.frame + 1 < step.tick {
+            checkpoints.extend(replay_tick(reactor, Vec::new()));
+        }
+        checkpoints.extend(replay_tick(reactor, step.events.clone()));
+    }
+    checkpoints
+}
+
+fn replay_tick(reactor: &mut Reactor, events: Vec<Event>) -> Option<ReplayCheckpoint> {
+    let remembered: std::collections::HashSet<_> = reactor.state.long_term_memory().keys().cloned().collect();
+    let effects = reactor.tick_step(events);
+
+    let mut checkpoint = ReplayCheckpoint {
+        tick: reactor.tick.frame,
+        gates: Vec::new(),
+        intents: Vec::new(),
+        promoted: Vec::new(),
+        effects: effects.iter().map(|e| variant_name(&format!("{:?}", e))).collect(),
+    };
+    let traced = reactor.trace.recent(1).into_iter().filter(|t| t.tick == reactor.tick);
+    for entry in traced.flat_map(|t| t.entries) {
+        match entry {
+            TraceEntry::Gate(explanation) => checkpoint.gates.push(GateCheckpoint { decision: explanation.decision, rule: explanation.rule }),
+            TraceEntry::Arbitration { hypothesis, stability, candidates, act, .. } => {
+                checkpoint.intents.push(IntentCheckpoint { hypothesis, stability, candidates, act });
+            }
+            TraceEntry::PlanAccepted { .. } | TraceEntry::PlanStale { .. } | TraceEntry::FollowUp { .. } => {}
+        }
+    }
+    let mut promoted: Vec<IntentHypothesis> = reactor.state.long_term_memory().iter()
+        .filter(|(id, _)| !remembered.contains(*id))
+        .map(|(_, record)| record.intent.hypothesis.clone())
+        .collect();
+    promoted.sort_by_key(|h| format!("{:?}", h));
+    checkpoint.promoted = promoted;
+
+    (!checkpoint.is_empty()).then_some(checkpoint)
+}
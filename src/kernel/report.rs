@@ -0,0 +1,150 @@
+//! Bug Report Bundle ("nexus bundle-report").
+//!
+//! Collects what a maintainer needs to reproduce a problem into one zip:
+//! the recent decision trace, a state checkpoint (StateView + footprint), the
+//! telemetry snapshot, the config (NEXUS_* values redacted) and platform info.
+//! All parts follow the Telemetry PRIVACY INVARIANT: no transcripts, audio or memory content.
+//!
+//! Nothing is written until the user has seen `manifest()` and confirmed.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::kernel::footprint::{FootprintMonitor, StateFootprint};
+use crate::kernel::reactor::Reactor;
+use crate::kernel::telemetry::metrics::TelemetrySnapshot;
+use crate::kernel::time::Tick;
+use crate::kernel::trace::DecisionTrace;
+use crate::kernel::view::StateView;
+
+// Last N ticks of decision trace in the bundle
+const TRACE_SEGMENT_TICKS: usize = 200;
+const REDACTED: &str = "[redacted]";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StateCheckpoint {
+    pub view: StateView,
+    pub footprint: StateFootprint,
+}
+
+impl StateCheckpoint {
+    /// State summary and per-domain sizes (bug reports, graceful shutdown).
+    pub fn collect(reactor: &Reactor) -> Self {
+        let footprint = FootprintMonitor::new(reactor.config.footprint)
+            .account(reactor.tick, &reactor.state, reactor.episodic.len())
+            .footprint;
+        let view = (**reactor.subscribe_view().borrow()).clone();
+        Self { view, footprint }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigSummary {
+    pub reactor: String,
+    pub features: Vec<&'static str>,
+    /// NEXUS_* variables that are set. Values are never included.
+    pub env: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlatformInfo {
+    pub os: &'static str,
+    pub arch: &'static str,
+    pub family: &'static str,
+    pub nexus_version: &'static str,
+}
+
+impl PlatformInfo {
+    pub fn current() -> Self {
+        Self {
+            os: std::env::consts::OS,
+            arch: std::env::consts::ARCH,
+            family: std::env::consts::FAMILY,
+            nexus_version: env!("CARGO_PKG_VERSION"),
+        }
+    }
+}
+
+/// One file in the bundle, as shown to the user before confirming.
+#[derive(Debug, Clone, Serialize)]
+pub struct BundleEntry {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub bytes: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportBundle {
+    pub tick: Tick,
+    pub trace: Vec<DecisionTrace>,
+    pub checkpoint: StateCheckpoint,
+    pub telemetry: TelemetrySnapshot,
+    pub config: ConfigSummary,
+    pub platform: PlatformInfo,
+}
+
+impl ReportBundle {
+    pub fn collect(reactor: &Reactor) -> Self {
+        Self {
+            tick: reactor.tick,
+            trace: reactor.trace.recent(TRACE_SEGMENT_TICKS),
+            checkpoint: StateCheckpoint::collect(reactor),
+            telemetry: reactor.telemetry.snapshot(),
+            config: ConfigSummary {
+                reactor: format!("{:?}", reactor.config),
+                features: enabled_features(),
+                env: std::env::vars()
+                    .filter(|(k, _)| k.starts_with("NEXUS_"))
+                    .map(|(k, _)| (k, REDACTED.to_string()))
+                    .collect(),
+            },
+            platform: PlatformInfo::current(),
+        }
+    }
+
+    fn files(&self) -> anyhow::Result<Vec<(&'static str, &'static str, String)>> {
+        Ok(vec![
+            ("trace.json", "Decision trace of recent ticks (IDs, enums, numbers)", serde_json::to_string_pretty(&self.trace)?),
+            ("state.json", "State summary and per-domain sizes (no content)", serde_json::to_string_pretty(&self.checkpoint)?),
+            ("telemetry.json", "Aggregated telemetry counters", serde_json::to_string_pretty(&self.telemetry)?),
+            ("config.json", "Kernel config and compiled features (env values redacted)", serde_json::to_string_pretty(&self.config)?),
+            ("platform.json", "OS, architecture and Nexus version", serde_json::to_string_pretty(&self.platform)?),
+        ])
+    }
+
+    /// What the zip will contain. Show this to the user before `write_zip`.
+    pub fn manifest(&self) -> anyhow::Result<Vec<BundleEntry>> {
+        Ok(self.files()?
+            .into_iter()
+            .map(|(name, description, body)| BundleEntry { name, description, bytes: body.len() })
+            .collect())
+    }
+
+    /// Write the zip. Refuses unless the user confirmed the manifest.
+    pub fn write_zip(&self, path: &Path, confirmed: bool) -> anyhow::Result<()> {
+        if !confirmed {
+            anyhow::bail!("report bundle not confirmed by the user");
+        }
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(path)?);
+        let options = zip::write::SimpleFileOptions::default();
+        for (name, _, body) in self.files()? {
+            zip.start_file(name, options)?;
+            zip.write_all(body.as_bytes())?;
+        }
+        zip.finish()?;
+        Ok(())
+    }
+}
+
+fn enabled_features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+    if cfg!(feature = "vision") { features.push("vision"); }
+    if cfg!(feature = "vad") { features.push("vad"); }
+    if cfg!(feature = "asr") { features.push("asr"); }
+    if cfg!(feature = "tts") { features.push("tts"); }
+    if cfg!(feature = "llm") { features.push("llm"); }
+    features
+}
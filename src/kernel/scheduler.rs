@@ -1,37 +1,85 @@
 use super::event::{Output, OutputId, OutputStatus};
 use super::state::StateDelta;
 use super::time::Tick;
+use super::diagnostic::DiagnosticCode;
 use crate::planner::types::Intent;
 
 pub struct Scheduler;
 
 #[derive(Debug, Clone)]
 pub enum SideEffect {
+    #[deprecated(note = "emit SideEffect::Diagnostic with a DiagnosticCode instead")]
     Log(String),
+    /// Signal-worthy condition. Drivers log it at `severity` and may toast `Error`s.
+    Diagnostic {
+        severity: super::diagnostic::Severity,
+        code: super::diagnostic::DiagnosticCode,
+        context: String,
+    },
     SpawnAudio(OutputId, String),
     StopAudio,
     RequestTranscription { segment_id: String },
+    /// Segment was discarded/purged or its intent invalidated: drop queued or running work.
+    CancelTranscription { segment_id: String },
+    /// Push-mode capture: full fidelity only while a segment is buffering.
+    SetCaptureFidelity(super::event::CaptureFidelity),
     // Phase L
-    AskMemoryConsent { key: crate::kernel::memory::types::MemoryKey, prompt_id: String },
+    AskMemoryConsent(crate::kernel::protocol::ConsentRequest),
+    /// The open prompt was answered by voice: the UI closes it (the response echoes its `prompt_id`).
+    DismissConsent(crate::kernel::protocol::ConsentResponse),
     RequestSpeech {
         intent: crate::kernel::speech::planner::SpeechIntent,
         output_id: uuid::Uuid,
     },
+    /// Run an action tool (e.g. home automation). The driver answers with `InputContent::ToolResult`.
+    InvokeTool { call_id: String, call: crate::planner::tools::ToolCall },
+    /// Abort a running tool call (timed out or interrupted). No `ToolResult` is expected.
+    CancelTool { call_id: String },
+    /// One-line LLM summary of a completed exchange. The driver answers with `InputContent::ExchangeSummary`.
+    RequestSummary { exchange_id: String, exchange: crate::memory::summary::Exchange },
+    /// Listening cue ("mm-hm") on its own channel: not an output, never stopped by `StopAudio`.
+    PlayCue(crate::kernel::backchannel::Cue),
+    /// Maintenance mode: the run started or a job finished. Drivers show it so the UI does not look frozen.
+    MaintenanceProgress(crate::kernel::maintenance::MaintenanceProgress),
+    /// Run a maintenance job's disk work off the tick. The driver answers with `InputContent::MaintenanceResult`.
+    RunMaintenance(crate::kernel::maintenance::MaintenanceTask),
+    /// Audio calibration changed: write the store back (off the tick, in emission order).
+    PersistCalibration(crate::kernel::audio::calibration::CalibrationSnapshot),
+    /// The user corrected an intent reading: write the correction ledger back (off the tick).
+    PersistCorrections(crate::kernel::intent::corrections::CorrectionsSnapshot),
+    /// A profile preference changed: write the profile store back (off the tick).
+    PersistProfiles(crate::kernel::profile::ProfilesSnapshot),
+    /// Periodic telemetry export is due: append the snapshot and push it (off the tick).
+    ExportTelemetry(crate::kernel::telemetry::exporter::SnapshotExport),
+}
+
+impl SideEffect {
+    /// Diagnostic at the code's default severity.
+    pub fn diagnostic(code: super::diagnostic::DiagnosticCode, context: impl Into<String>) -> Self {
+        SideEffect::Diagnostic { severity: code.severity(), code, context: context.into() }
+    }
 }
 
 impl Scheduler {
-    /// Pure Projection: Intent + Context -> (StateDelta, SideEffect)
+    /// Pure Projection: Intent + Context -> (StateDelta, SideEffect), phrased with the built-in templates
     pub fn schedule(&self, intent: Intent, tick: Tick, ordinal: u16) -> (Option<StateDelta>, Option<SideEffect>) {
+        self.schedule_in(crate::outputs::phrases::PhraseTemplates::builtin(), intent, tick, ordinal)
+    }
+
+    /// Pure Projection with the given phrase templates (clarifications, corrections)
+    pub fn schedule_in(&self, templates: &crate::outputs::phrases::PhraseTemplates, intent: Intent, tick: Tick, ordinal: u16)
+        -> (Option<StateDelta>, Option<SideEffect>)
+    {
         let output_id = OutputId { tick: tick.frame, ordinal };
 
         match intent {
             Intent::DoNothing => (None, None),
-            Intent::Delay { ticks: _ } => {
+            Intent::Delay { ticks } => {
                 // In Phase 1: Delay is effective by NOT emitting output.
-                (None, Some(SideEffect::Log("Planner decided to Delay".to_string())))
+                (None, Some(SideEffect::diagnostic(DiagnosticCode::PlanDelayed, format!("ticks={}", ticks))))
             }
             Intent::AskClarification { context } => {
-                 let text = format!("Could you clarify? Context: {}", context);
+                 let text = templates.plan_clarification(&context);
                   let output = Output {
                     id: output_id,
                     content: text.clone(),
@@ -43,7 +91,7 @@ impl Scheduler {
                 (Some(StateDelta::OutputProposed(output)), Some(SideEffect::SpawnAudio(output_id, text)))
             }
             Intent::ReviseStatement { ref_id: _, correction } => {
-                let text = format!("Correction: {}", correction);
+                let text = templates.correction(&correction);
                 let output = Output {
                     id: output_id,
                     content: text.clone(),
@@ -54,10 +102,14 @@ impl Scheduler {
                 };
                 (Some(StateDelta::OutputProposed(output)), Some(SideEffect::SpawnAudio(output_id, text)))
             }
-            Intent::BeginResponse { confidence: _ } => {
+            Intent::BeginResponse { .. } | Intent::BeginResponseWithFollowUp { .. } => {
                 // Phase 1 Stub: We don't generate text yet.
                 // Longer text for Phase D Interruption Verification
-                let text = "This is a long verification message to test the interruptibility of the Nexus system. Please speak now to test the kill switch.".to_string();
+                let mut text = "This is a long verification message to test the interruptibility of the Nexus system. Please speak now to test the kill switch.".to_string();
+                // The offer itself is linked by the caller (`follow_up_in` once this was heard)
+                if let Intent::BeginResponseWithFollowUp { summary_first: true } = intent {
+                    text = crate::outputs::realizer::summary_sentence(&text);
+                }
                 
                 let output = Output {
                     id: output_id,
@@ -75,4 +127,19 @@ impl Scheduler {
             }
         }
     }
+
+    /// Pure Projection: the follow-up offer of a `BeginResponseWithFollowUp` answer, linked to it
+    /// through `parent_id`. `text`: one of `PhraseTemplates::follow_up` (restyled by the caller).
+    pub fn follow_up(&self, answer: OutputId, text: String, tick: Tick, ordinal: u16) -> (StateDelta, SideEffect) {
+        let output_id = OutputId { tick: tick.frame, ordinal };
+        let output = Output {
+            id: output_id,
+            content: text.clone(),
+            status: OutputStatus::SoftCommit,
+            proposed_at: tick,
+            committed_at: None,
+            parent_id: Some(format!("output:{}:{}", answer.tick, answer.ordinal)),
+        };
+        (StateDelta::OutputProposed(output), SideEffect::SpawnAudio(output_id, text))
+    }
 }
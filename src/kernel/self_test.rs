@@ -0,0 +1,171 @@
+//! Kernel Self-Test ("nexus doctor").
+//!
+//! Exercises each subsystem with synthetic inputs on an ISOLATED copy of the
+//! live state: scratch sidecars, in-memory stores, no LLM dispatch. Side effects
+//! are inspected, never executed. The live Reactor is never touched.
+
+use serde::Serialize;
+
+use crate::kernel::event::{AudioSignal, Event, InputContent, InputEvent, VisualSignal};
+use crate::kernel::footprint::FootprintMonitor;
+use crate::kernel::intent::types::IntentState;
+use crate::kernel::reactor::{KernelMode, Reactor, ReactorConfig};
+use crate::kernel::state::SharedState;
+use crate::kernel::time::Tick;
+use crate::memory::{FileSemanticStore, InMemoryEpisodicStore};
+
+// Synthetic inputs (recognisable in logs)
+const SELF_TEST_SOURCE: &str = "SelfTest";
+const SELF_TEST_HASH: u64 = 0x5E1F_7E57;
+const SELF_TEST_COMMAND: &str = "Turn off the lights";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Subsystem {
+    AudioGate,
+    Vision,
+    IntentArbitration,
+    LongHorizonIntents,
+    Footprint,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SubsystemCheck {
+    pub subsystem: Subsystem,
+    pub passed: bool,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfTestReport {
+    pub tick: Tick,
+    pub checks: Vec<SubsystemCheck>,
+}
+
+impl SelfTestReport {
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|c| c.passed)
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &SubsystemCheck> {
+        self.checks.iter().filter(|c| !c.passed)
+    }
+}
+
+/// Run every check. Each check gets its own scratch Reactor so failures can't cascade.
+pub fn run(state: &SharedState, tick: Tick, config: ReactorConfig, conversation_log_len: usize) -> SelfTestReport {
+    let (arbitration, long_horizon) = check_intents(state, tick, config);
+    SelfTestReport {
+        tick,
+        checks: vec![
+            check_audio_gate(state, tick, config),
+            check_vision(state, tick, config),
+            arbitration,
+            long_horizon,
+            check_footprint(state, tick, config, conversation_log_len),
+        ],
+    }
+}
+
+/// Scratch Reactor over a copy of `state`. Nothing it does leaves this module.
+fn scratch(state: &SharedState, tick: Tick, config: ReactorConfig) -> Reactor {
+    let (tx, rx) = tokio::sync::mpsc::channel(16);
+    let config = ReactorConfig { llm_planning: false, decision_trace: false, ..config };
+    // Never loaded, never saved (the kernel does not write semantic memory on tick)
+    let semantic = FileSemanticStore::new(std::env::temp_dir().join("nexus_self_test_semantic.json"));
+    let mut reactor = Reactor::builder(rx, tx)
+        .config(config)
+        .episodic_store(Box::new(InMemoryEpisodicStore::new()))
+        .semantic_store(Box::new(semantic))
+        .build();
+    reactor.state = state.clone();
+    reactor.tick = tick;
+    reactor.set_mode(KernelMode::Active);
+    reactor
+}
+
+fn input(content: InputContent) -> Event {
+    Event::Input(InputEvent { source: SELF_TEST_SOURCE.to_string(), content, captured_at: None })
+}
+
+fn check(subsystem: Subsystem, passed: bool, detail: String) -> SubsystemCheck {
+    SubsystemCheck { subsystem, passed, detail }
+}
+
+// === 1. AUDIO GATE ===
+// SpeechStart opens a segment; SpeechEnd finalizes it.
+fn check_audio_gate(state: &SharedState, tick: Tick, config: ReactorConfig) -> SubsystemCheck {
+    let mut reactor = scratch(state, tick, config);
+
+    reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechStart))]);
+    let Some(segment_id) = reactor.state.active_segment_id().cloned() else {
+        return check(Subsystem::AudioGate, false, "SpeechStart did not open a segment".to_string());
+    };
+
+    reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechEnd))]);
+    let finalized = reactor.state.active_segment_id().is_none()
+        && reactor.state.audio_segments().get(&segment_id).map(|s| s.end_tick.is_some()).unwrap_or(false);
+    if finalized {
+        check(Subsystem::AudioGate, true, "segment opened and finalized".to_string())
+    } else {
+        check(Subsystem::AudioGate, false, "SpeechEnd did not finalize the segment".to_string())
+    }
+}
+
+// === 2. VISION ===
+// A stable percept updates the visual hash.
+fn check_vision(state: &SharedState, tick: Tick, config: ReactorConfig) -> SubsystemCheck {
+    let mut reactor = scratch(state, tick, config);
+    let percept = || input(InputContent::Visual(VisualSignal::PerceptUpdate { hash: SELF_TEST_HASH, distance: 0 }));
+
+    reactor.tick_step(vec![percept()]);
+    reactor.tick_step(vec![percept()]);
+
+    let visual = reactor.state.visual();
+    if visual.hash == SELF_TEST_HASH {
+        check(Subsystem::Vision, true, format!("percept reduced (stability {:.2})", visual.stability_score))
+    } else {
+        check(Subsystem::Vision, false, "percept did not reach visual state".to_string())
+    }
+}
+
+// === 3. INTENTS ===
+// A clear command must be assessed (Phase G) and registered with the LHIM (Part IX).
+fn check_intents(state: &SharedState, tick: Tick, config: ReactorConfig) -> (SubsystemCheck, SubsystemCheck) {
+    let mut reactor = scratch(state, tick, config);
+
+    reactor.tick_step(vec![input(InputContent::ProvisionalText {
+        content: SELF_TEST_COMMAND.to_string(),
+        confidence: 0.9,
+        source_id: "self_test_seg".to_string(),
+        words: Vec::new(),
+        language: None,
+        is_final: true,
+    })]);
+
+    let arbitration = match reactor.state.intent_state() {
+        IntentState::Stable(c) => check(Subsystem::IntentArbitration, true, format!("stable {:?} ({:.2})", c.hypothesis, c.confidence)),
+        IntentState::None => check(Subsystem::IntentArbitration, false, "command produced no intent".to_string()),
+        other => check(Subsystem::IntentArbitration, false, format!("command did not stabilise: {:?}", other)),
+    };
+
+    let now = reactor.tick;
+    let registered = reactor.state.active_intents().values().any(|i| i.last_active_at == now);
+    let long_horizon = if registered {
+        check(Subsystem::LongHorizonIntents, true, "stable intent registered".to_string())
+    } else {
+        check(Subsystem::LongHorizonIntents, false, "stable intent was not registered".to_string())
+    };
+
+    (arbitration, long_horizon)
+}
+
+// === 4. FOOTPRINT ===
+// Live state must be below the pressure ceilings.
+fn check_footprint(state: &SharedState, tick: Tick, config: ReactorConfig, conversation_log_len: usize) -> SubsystemCheck {
+    let report = FootprintMonitor::new(config.footprint).account(tick, state, conversation_log_len);
+    if report.pressured.is_empty() {
+        check(Subsystem::Footprint, true, format!("{} bytes", report.footprint.total_bytes()))
+    } else {
+        check(Subsystem::Footprint, false, format!("under pressure: {:?}", report.pressured))
+    }
+}
@@ -0,0 +1,56 @@
+//! Graceful Shutdown.
+//!
+//! `Reactor::shutdown` is how a session ends on purpose (Ctrl+C in the CLI, quit or
+//! "Restart core" in the shell). In order:
+//! 1. Work in flight is dropped. Undelivered events are drained unprocessed. Playing outputs,
+//!    tool calls, transcriptions and the planner request are cancelled; the returned Control
+//!    effects (`StopAudio`, `CancelTool`, `CancelTranscription`) stop the driver's side of them.
+//! 2. Stores are flushed: the semantic store is saved, and the telemetry session summary is
+//!    recorded and spooled.
+//! 3. A checkpoint is written: `StateCheckpoint` (State View + footprint), content-free like
+//!    the bug report's `state.json`. With `ShutdownOptions::state`, so is the persisted state
+//!    (`kernel::persist`: memories, long-horizon intents, consent), for the next start to hydrate.
+//!
+//! `Reactor::restart` follows it with a fresh kernel (see its doc).
+
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use crate::kernel::time::Tick;
+
+/// Where shutdown writes. `None` skips that step.
+#[derive(Debug, Clone, Default)]
+pub struct ShutdownOptions {
+    pub checkpoint: Option<PathBuf>,
+    pub telemetry_spool: Option<PathBuf>,
+    /// Persisted state (`Reactor::hydrate_from_disk`). Contains memory content.
+    pub state: Option<PathBuf>,
+}
+
+/// One store written on the way out.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StoreFlush {
+    pub store: &'static str,
+    /// `None`: written
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShutdownReport {
+    pub tick: Tick,
+    pub flushed: Vec<StoreFlush>,
+    /// Set only when the checkpoint was written
+    pub checkpoint_path: Option<PathBuf>,
+    /// Outputs, tool calls, transcriptions and plans that will not complete
+    pub pending_effects_dropped: usize,
+    /// Events that arrived after the last tick and were never processed
+    pub events_dropped: usize,
+}
+
+impl ShutdownReport {
+    /// Every store and the checkpoint were written.
+    pub fn is_clean(&self) -> bool {
+        self.flushed.iter().all(|f| f.error.is_none())
+    }
+}
@@ -0,0 +1,209 @@
+//! Sidecar Seams.
+//!
+//! The Reactor talks to its sidecars (observer, consolidator, monitor, sentiment, LHIM, arbitrator)
+//! only through these traits, so tests can inject spies and products can swap
+//! or disable subsystems via `ReactorBuilder`.
+//!
+//! Invariant: Sidecars never mutate SharedState directly. They return StateDeltas
+//! (or observations) and the Reactor reduces them.
+
+use crate::kernel::event::{InputEvent, Output};
+use crate::kernel::state::{SharedState, StateDelta};
+use crate::kernel::time::Tick;
+use crate::kernel::latent::LatentSlot;
+use crate::kernel::crystallizer::SymbolicSnapshot;
+use crate::kernel::intent::types::{IntentCandidate, IntentHypothesis, IntentState, DialogueAct};
+use crate::kernel::intent::long_horizon::{
+    LongHorizonIntentManager, LongHorizonIntent, IntentId, IntentContext, ResumptionNotice,
+};
+use crate::kernel::intent::arbitrator::IntentArbitrator;
+use crate::kernel::intent::corrections::CorrectionsSnapshot;
+use crate::kernel::memory::consolidator::MemoryConsolidator;
+use crate::kernel::telemetry::recorder::TelemetryRecorder;
+use crate::kernel::ids::IdGenerator;
+use crate::memory::MemoryObserver;
+use crate::memory::types::MemoryCandidate;
+use crate::monitor::monitor::SelfObservationMonitor;
+use crate::monitor::types::SelfObservation;
+use crate::monitor::sentiment::{LexiconSentiment, Sentiment};
+
+/// Memory Observer seam (Sensor). Captures candidates, never decides promotion.
+pub trait MemorySensor: Send {
+    fn observe_crystallization(&mut self, output: &Output, snapshot: &SymbolicSnapshot, current_tick: u64);
+    fn observe_latent(&mut self, slot: &LatentSlot, current_tick: u64);
+    fn flush(&mut self) -> Vec<MemoryCandidate>;
+}
+
+/// Kernel Memory Consolidator seam (Phase H).
+pub trait MemoryConsolidation: Send {
+    fn process_intent(&self, intent: &IntentCandidate, state: &SharedState, telemetry: &mut TelemetryRecorder, ids: &mut IdGenerator) -> Vec<StateDelta>;
+    fn tick(&self, current_tick: Tick, state: &SharedState, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta>;
+}
+
+/// Self-Observation Monitor seam (Meta-Latents).
+pub trait SelfMonitor: Send {
+    fn observe_raw(&self, input: &InputEvent, state: &SharedState) -> Vec<SelfObservation>;
+    fn tick(&mut self, current_tick: u64, incoming_events: &[SelfObservation]) -> Option<StateDelta>;
+}
+
+/// Turn-level sentiment seam. Lexicon by default; a model-backed estimator plugs in here.
+pub trait SentimentEstimator: Send {
+    /// Valence of one user turn (`language`: as heard, `None` for typed text). `None`: no estimate.
+    fn estimate(&mut self, transcript: &str, language: Option<&str>) -> Option<Sentiment>;
+}
+
+/// Long-Horizon Intent Manager seam (Part IX).
+pub trait IntentManager: Send {
+    fn register_intent(&mut self, candidate: &IntentCandidate, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta>;
+    fn suspend_intent(&mut self, id: &IntentId, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Option<StateDelta>;
+    fn handle_interruption(&mut self, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta>;
+    fn try_resume(&mut self, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta>;
+    fn tick(&mut self, current_tick: Tick, state: &SharedState, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta>;
+    fn take_resumptions(&mut self) -> Vec<ResumptionNotice>;
+    fn get_context(&self, state: &SharedState) -> IntentContext;
+    /// Take over an intent replicated from another device (federation).
+    fn adopt_intent(&mut self, intent: LongHorizonIntent) -> Option<StateDelta> {
+        Some(StateDelta::LongHorizonIntentUpdate(intent))
+    }
+    /// Resume a parked intent on request (bookmark recall). `None`: it cannot be resumed.
+    fn resume_intent(&mut self, _id: &IntentId, _state: &SharedState, _current_tick: Tick, _telemetry: &mut TelemetryRecorder) -> Option<StateDelta> {
+        None
+    }
+}
+
+/// Intent Arbitrator seam (Phase G).
+pub trait IntentArbitration: Send {
+    fn assess(&self, text: &str, symbol_id: &str, current_state: &IntentState, ids: &mut IdGenerator) -> IntentState;
+    fn decide(&self, state: &IntentState) -> DialogueAct;
+    /// The user corrected the classification of a phrase (`corrections::phrase_hash`).
+    /// Returns the ledger to write back, if it is persisted.
+    fn learn_correction(&mut self, _phrase_hash: u64, _corrected: IntentHypothesis) -> Option<CorrectionsSnapshot> {
+        None
+    }
+}
+
+// === DEFAULT IMPLEMENTATIONS ===
+
+impl MemorySensor for MemoryObserver {
+    fn observe_crystallization(&mut self, output: &Output, snapshot: &SymbolicSnapshot, current_tick: u64) {
+        MemoryObserver::observe_crystallization(self, output, snapshot, current_tick)
+    }
+    fn observe_latent(&mut self, slot: &LatentSlot, current_tick: u64) {
+        MemoryObserver::observe_latent(self, slot, current_tick)
+    }
+    fn flush(&mut self) -> Vec<MemoryCandidate> {
+        MemoryObserver::flush(self)
+    }
+}
+
+impl MemoryConsolidation for MemoryConsolidator {
+    fn process_intent(&self, intent: &IntentCandidate, state: &SharedState, telemetry: &mut TelemetryRecorder, ids: &mut IdGenerator) -> Vec<StateDelta> {
+        MemoryConsolidator::process_intent(self, intent, state, telemetry, ids)
+    }
+    fn tick(&self, current_tick: Tick, state: &SharedState, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        MemoryConsolidator::tick(self, current_tick, state, telemetry)
+    }
+}
+
+impl SelfMonitor for SelfObservationMonitor {
+    fn observe_raw(&self, input: &InputEvent, state: &SharedState) -> Vec<SelfObservation> {
+        SelfObservationMonitor::observe_raw(self, input, state)
+    }
+    fn tick(&mut self, current_tick: u64, incoming_events: &[SelfObservation]) -> Option<StateDelta> {
+        SelfObservationMonitor::tick(self, current_tick, incoming_events)
+    }
+}
+
+impl SentimentEstimator for LexiconSentiment {
+    fn estimate(&mut self, transcript: &str, language: Option<&str>) -> Option<Sentiment> {
+        LexiconSentiment::score(self, transcript, language)
+    }
+}
+
+impl IntentManager for LongHorizonIntentManager {
+    fn register_intent(&mut self, candidate: &IntentCandidate, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        LongHorizonIntentManager::register_intent(self, candidate, state, current_tick, telemetry)
+    }
+    fn suspend_intent(&mut self, id: &IntentId, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Option<StateDelta> {
+        LongHorizonIntentManager::suspend_intent(self, id, state, current_tick, telemetry)
+    }
+    fn handle_interruption(&mut self, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        LongHorizonIntentManager::handle_interruption(self, state, current_tick, telemetry)
+    }
+    fn try_resume(&mut self, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        LongHorizonIntentManager::try_resume(self, state, current_tick, telemetry)
+    }
+    fn tick(&mut self, current_tick: Tick, state: &SharedState, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        LongHorizonIntentManager::tick(self, current_tick, state, telemetry)
+    }
+    fn take_resumptions(&mut self) -> Vec<ResumptionNotice> {
+        LongHorizonIntentManager::take_resumptions(self)
+    }
+    fn get_context(&self, state: &SharedState) -> IntentContext {
+        LongHorizonIntentManager::get_context(self, state)
+    }
+    fn adopt_intent(&mut self, intent: LongHorizonIntent) -> Option<StateDelta> {
+        self.active_intents.insert(intent.id.clone(), intent.clone());
+        Some(StateDelta::LongHorizonIntentUpdate(intent))
+    }
+    fn resume_intent(&mut self, id: &IntentId, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Option<StateDelta> {
+        LongHorizonIntentManager::resume_intent(self, id, state, current_tick, telemetry)
+    }
+}
+
+impl IntentArbitration for IntentArbitrator {
+    fn assess(&self, text: &str, symbol_id: &str, current_state: &IntentState, ids: &mut IdGenerator) -> IntentState {
+        IntentArbitrator::assess(self, text, symbol_id, current_state, ids)
+    }
+    fn decide(&self, state: &IntentState) -> DialogueAct {
+        IntentArbitrator::decide(self, state)
+    }
+    fn learn_correction(&mut self, phrase_hash: u64, corrected: IntentHypothesis) -> Option<CorrectionsSnapshot> {
+        IntentArbitrator::learn_correction(self, phrase_hash, corrected)
+    }
+}
+
+// === DISABLED SUBSYSTEM ===
+
+/// No-op sidecar. Install via `ReactorBuilder` to switch a subsystem off cleanly:
+/// it observes nothing, emits no deltas and never speaks.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Disabled;
+
+impl MemorySensor for Disabled {
+    fn observe_crystallization(&mut self, _output: &Output, _snapshot: &SymbolicSnapshot, _current_tick: u64) {}
+    fn observe_latent(&mut self, _slot: &LatentSlot, _current_tick: u64) {}
+    fn flush(&mut self) -> Vec<MemoryCandidate> { Vec::new() }
+}
+
+impl MemoryConsolidation for Disabled {
+    fn process_intent(&self, _intent: &IntentCandidate, _state: &SharedState, _telemetry: &mut TelemetryRecorder, _ids: &mut IdGenerator) -> Vec<StateDelta> { Vec::new() }
+    fn tick(&self, _current_tick: Tick, _state: &SharedState, _telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> { Vec::new() }
+}
+
+impl SelfMonitor for Disabled {
+    fn observe_raw(&self, _input: &InputEvent, _state: &SharedState) -> Vec<SelfObservation> { Vec::new() }
+    fn tick(&mut self, _current_tick: u64, _incoming_events: &[SelfObservation]) -> Option<StateDelta> { None }
+}
+
+impl SentimentEstimator for Disabled {
+    fn estimate(&mut self, _transcript: &str, _language: Option<&str>) -> Option<Sentiment> { None }
+}
+
+impl IntentManager for Disabled {
+    fn register_intent(&mut self, _candidate: &IntentCandidate, _state: &SharedState, _current_tick: Tick, _telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> { Vec::new() }
+    fn suspend_intent(&mut self, _id: &IntentId, _state: &SharedState, _current_tick: Tick, _telemetry: &mut TelemetryRecorder) -> Option<StateDelta> { None }
+    fn handle_interruption(&mut self, _state: &SharedState, _current_tick: Tick, _telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> { Vec::new() }
+    fn try_resume(&mut self, _state: &SharedState, _current_tick: Tick, _telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> { Vec::new() }
+    fn tick(&mut self, _current_tick: Tick, _state: &SharedState, _telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> { Vec::new() }
+    fn take_resumptions(&mut self) -> Vec<ResumptionNotice> { Vec::new() }
+    fn get_context(&self, _state: &SharedState) -> IntentContext {
+        IntentContext { active_focus: None, strength: 0.0 }
+    }
+    fn adopt_intent(&mut self, _intent: LongHorizonIntent) -> Option<StateDelta> { None }
+}
+
+impl IntentArbitration for Disabled {
+    fn assess(&self, _text: &str, _symbol_id: &str, current_state: &IntentState, _ids: &mut IdGenerator) -> IntentState { current_state.clone() }
+    fn decide(&self, _state: &IntentState) -> DialogueAct { DialogueAct::StaySilent }
+}
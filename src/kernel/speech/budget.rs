@@ -0,0 +1,63 @@
+use std::collections::VecDeque;
+
+use crate::kernel::time::Tick;
+
+/// Proactive Speech Budget.
+/// Limits how often the system may speak WITHOUT being prompted by the user
+/// (e.g. resumption offers). Sliding window over ticks.
+#[derive(Debug, Clone)]
+pub struct ProactiveBudget {
+    pub max_per_window: usize,
+    pub window_ticks: u64,
+    spent: VecDeque<u64>,
+}
+
+// Defaults: at most 1 proactive utterance per minute (3000 ticks @ 20ms).
+const DEFAULT_MAX_PER_WINDOW: usize = 1;
+const DEFAULT_WINDOW_TICKS: u64 = 3000;
+
+impl Default for ProactiveBudget {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProactiveBudget {
+    pub fn new() -> Self {
+        Self::with_limits(DEFAULT_MAX_PER_WINDOW, DEFAULT_WINDOW_TICKS)
+    }
+
+    pub fn with_limits(max_per_window: usize, window_ticks: u64) -> Self {
+        Self {
+            max_per_window,
+            window_ticks,
+            spent: VecDeque::new(),
+        }
+    }
+
+    /// Check without spending.
+    pub fn available(&self, now: Tick) -> bool {
+        let in_window = self.spent.iter()
+            .filter(|t| now.frame.saturating_sub(**t) < self.window_ticks)
+            .count();
+        in_window < self.max_per_window
+    }
+
+    /// Spend one unit if available. Returns false if the budget is exhausted.
+    pub fn try_spend(&mut self, now: Tick) -> bool {
+        // Drop expired entries
+        while let Some(&t) = self.spent.front() {
+            if now.frame.saturating_sub(t) >= self.window_ticks {
+                self.spent.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if self.spent.len() >= self.max_per_window {
+            return false;
+        }
+        self.spent.push_back(now.frame);
+        true
+    }
+}
@@ -1 +1,2 @@
 pub mod planner;
+pub mod budget;
@@ -1,5 +1,5 @@
 use super::event::{InputEvent, Output, OutputId, OutputStatus, InputContent, AudioSignal};
-use super::presence::{PresenceState, PresenceRequest, PresenceGraph};
+use super::presence::{CapturePermission, PresenceState, PresenceRequest, PresenceGraph};
 use std::collections::{HashMap, HashSet};
 use crate::kernel::time::Tick;
 use crate::kernel::intent::long_horizon::{LongHorizonIntent, IntentId};
@@ -7,6 +7,14 @@ use crate::kernel::audio::segment::{AudioSegment, SegmentStatus};
 use crate::kernel::intent::types::IntentState;
 use crate::kernel::memory::types::{MemoryCandidate, MemoryRecord, MemoryId, MemoryKey};
 use crate::kernel::memory::consent::{MemoryConsent, MemoryConsentState};
+use crate::kernel::memory::topic::TopicId;
+use crate::kernel::memory::sensitivity::SensitivityClass;
+use crate::kernel::context::ContextId;
+use crate::kernel::epoch::{DomainVersions, StateDomain};
+
+// Config Constants
+/// Version vectors kept for `SharedState::domain_lag` (older epochs use the global lag).
+pub const VERSION_HISTORY: usize = 256;
 
 #[derive(Debug, Clone)]
 pub struct MetaLatents {
@@ -39,6 +47,8 @@ pub enum StateDelta {
     OutputProposed(Output),
     OutputCommitted(OutputId),
     OutputCanceled(OutputId),
+    OutputPlaybackProgress { id: OutputId, percent: u8 },
+    InterruptionRecorded(crate::kernel::cancel::InterruptionRecord),
     TaskCanceled(String),
     VisualStateUpdate { hash: u64, stability: f32 },
     LatentUpdate { slot: crate::kernel::latent::LatentSlot },
@@ -46,12 +56,25 @@ pub enum StateDelta {
     LongHorizonIntentUpdate(LongHorizonIntent),
     PresenceTransition(PresenceRequest),
     PresenceUpdate(PresenceState),
+    CapturePermissionChanged(CapturePermission),
+    OutputDeviceChanged(Option<String>),
+    InputDeviceChanged(Option<String>),
+    SubsystemToggled { subsystem: crate::kernel::subsystem::Subsystem, enabled: bool },
+    QuietHoursChanged(crate::kernel::quiet::QuietHoursState),
+    LanguagePreferenceChanged(crate::kernel::language::LanguagePreference),
     // Audio Buffering Deltas
     AudioSegmentCreated(AudioSegment),
     AudioFrameAppended { segment_id: String, frames: Vec<f32> },
     AudioSegmentFinalized { segment_id: String, end_tick: Tick },
+    /// Finalized segment with its silence cut (`kernel::audio::trim`)
+    AudioSegmentTrimmed { segment_id: String, frames: Vec<f32>, trim: crate::kernel::audio::trim::SegmentTrim },
     AudioSegmentTranscribing(String),
-    AudioSegmentTranscribed { segment_id: String, text: String },
+    /// `language`: what the ASR backend heard (also the spoken language from now on)
+    AudioSegmentTranscribed { segment_id: String, text: String, words: Vec<crate::kernel::audio::segment::WordConfidence>, language: Option<String> },
+    /// Segment will never be transcribed (e.g. ASR compiled out)
+    AudioSegmentDiscarded(String),
+    /// Capture restarted: the open segment has a hole, and no SpeechEnd will close it
+    CaptureRestarted,
     /// Phase G: Intent Assessment
     AssessmentUpdate(IntentState),
     Tick(Tick),
@@ -65,9 +88,82 @@ pub enum StateDelta {
     MemoryAccessed { id: MemoryId, time: Tick },
     // Phase L: Memory Consent
     MemoryConsentAsked(MemoryKey, Tick),
+    /// The `ConsentRequest` actually sent for an asked key
+    MemoryConsentPrompted { key: MemoryKey, prompt_id: String, expires_at: Tick, topic_id: Option<TopicId> },
     MemoryConsentResolved { key: MemoryKey, state: MemoryConsentState, resolved_at: Tick },
+    // Topic Segmentation (Topic-scoped consent & forgetting)
+    TopicChanged(TopicId),
+    TopicConsentResolved { topic_id: TopicId, state: MemoryConsentState },
+    MemoryTopicForgotten(TopicId),
+    // Privacy: transcript behind this intent was classified sensitive
+    IntentSensitivityMarked { intent_id: IntentId, class: SensitivityClass },
+    // Language the intent was spoken in (from its source segment, see kernel::language)
+    IntentLanguageTagged { intent_id: IntentId, language: String },
+    // Contexts: park the current conversation, resume (or start) the target one
+    ContextSwitched(ContextId),
     // Clearing Outputs (Phase Q Fix)
     AllOutputsCleared, 
+    // Janitor (Memory Pressure, see kernel::footprint)
+    AudioSegmentsPurged(Vec<String>),
+    LatentsTruncated { keep: usize },
+    LongHorizonIntentsPurged(Vec<IntentId>),
+}
+
+impl StateDelta {
+    /// What the delta changes. `None`: not versioned (ticks and buffered audio frames, so slow
+    /// planning is not invalidated by the clock or the microphone).
+    pub fn domain(&self) -> Option<StateDomain> {
+        match self {
+            StateDelta::Tick(_) | StateDelta::AudioFrameAppended { .. } => None,
+            StateDelta::InputReceived(_)
+            | StateDelta::InterruptionRecorded(_)
+            | StateDelta::AudioSegmentTranscribed { .. }
+            | StateDelta::TopicChanged(_)
+            | StateDelta::ContextSwitched(_) => Some(StateDomain::Dialogue),
+            StateDelta::OutputProposed(_)
+            | StateDelta::OutputCommitted(_)
+            | StateDelta::OutputCanceled(_)
+            | StateDelta::OutputPlaybackProgress { .. }
+            | StateDelta::TaskCanceled(_)
+            | StateDelta::AllOutputsCleared => Some(StateDomain::Outputs),
+            StateDelta::LongHorizonIntentUpdate(_)
+            | StateDelta::AssessmentUpdate(_)
+            | StateDelta::IntentSensitivityMarked { .. }
+            | StateDelta::IntentLanguageTagged { .. }
+            | StateDelta::LongHorizonIntentsPurged(_) => Some(StateDomain::Intents),
+            StateDelta::MemoryCandidateCreated(_)
+            | StateDelta::MemoryCandidateReinforced(..)
+            | StateDelta::MemoryPromoted(_)
+            | StateDelta::MemoryDecayed { .. }
+            | StateDelta::MemoryForgotten(_)
+            | StateDelta::MemoryCandidateRemoved(_)
+            | StateDelta::MemoryAccessed { .. }
+            | StateDelta::MemoryConsentAsked(..)
+            | StateDelta::MemoryConsentPrompted { .. }
+            | StateDelta::MemoryConsentResolved { .. }
+            | StateDelta::TopicConsentResolved { .. }
+            | StateDelta::MemoryTopicForgotten(_) => Some(StateDomain::Memory),
+            StateDelta::VisualStateUpdate { .. }
+            | StateDelta::LatentUpdate { .. }
+            | StateDelta::MetaLatentUpdate { .. }
+            | StateDelta::PresenceTransition(_)
+            | StateDelta::PresenceUpdate(_)
+            | StateDelta::CapturePermissionChanged(_)
+            | StateDelta::OutputDeviceChanged(_)
+            | StateDelta::InputDeviceChanged(_)
+            | StateDelta::SubsystemToggled { .. }
+            | StateDelta::QuietHoursChanged(_)
+            | StateDelta::LanguagePreferenceChanged(_)
+            | StateDelta::AudioSegmentCreated(_)
+            | StateDelta::AudioSegmentFinalized { .. }
+            | StateDelta::AudioSegmentTrimmed { .. }
+            | StateDelta::AudioSegmentTranscribing(_)
+            | StateDelta::AudioSegmentDiscarded(_)
+            | StateDelta::CaptureRestarted
+            | StateDelta::AudioSegmentsPurged(_)
+            | StateDelta::LatentsTruncated { .. } => Some(StateDomain::Perception),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -93,47 +189,84 @@ pub struct SharedState {
     // In strict model, we might track canceled task IDs or just effects
     canceled_tasks: HashSet<String>,
     // Monotonic version for Epoch validation
-    pub version: u64,
+    version: u64,
+    // Per-domain mutation counts, and the vector at each recent version (oldest first)
+    domain_versions: DomainVersions,
+    version_history: std::collections::VecDeque<(u64, DomainVersions)>,
+    // Deltas reduced since the last `take_applied` (only while tick observers are registered)
+    applied: Option<Vec<StateDelta>>,
     
     // Audio / Control State
-    pub last_tick: Tick,
-    pub user_speaking: bool,
-    pub turn_pressure: f32, // 0.0 - 1.0
-    pub last_speech_start: Option<Tick>,
-    pub last_speech_end: Option<Tick>,
-    pub hesitation_detected: bool,
+    last_tick: Tick,
+    user_speaking: bool,
+    turn_pressure: f32, // 0.0 - 1.0
+    last_speech_start: Option<Tick>,
+    last_speech_end: Option<Tick>,
+    hesitation_detected: bool,
+    // Last explicit user input (text or transcript). Separates direct answers from proactive output.
+    last_user_input: Option<Tick>,
+    // Quiet hours (schedule in ReactorConfig, local clock from the driver)
+    quiet_hours: crate::kernel::quiet::QuietHoursState,
     
     // Vision State
-    pub visual: VisualState,
+    visual: VisualState,
     
     // Latent Field (Sidecar)
-    pub latents: crate::kernel::latent::LatentState,
+    latents: crate::kernel::latent::LatentState,
     
     // Meta-Latents (Self-Observation)
-    pub meta_latents: MetaLatents,
+    meta_latents: MetaLatents,
     
     // Long-Horizon Intents (Part IX)
     // Long-Horizon Intents (Part IX)
-    pub active_intents: HashMap<IntentId, LongHorizonIntent>,
+    active_intents: HashMap<IntentId, LongHorizonIntent>,
 
     // Phase B: Presence State (Authoritative)
     // Phase B: Presence State (Authoritative)
-    pub presence: PresenceState,
+    presence: PresenceState,
+    // Microphone access (capture layer). Gates Attentive.
+    capture_permission: CapturePermission,
+    // Playback route reported by the driver (None = system default)
+    output_device: Option<String>,
+    // Capture device reported by the driver (None = system default)
+    input_device: Option<String>,
+    // Runtime switches (settings)
+    subsystems: crate::kernel::subsystem::SubsystemFlags,
+    // Transcription and reply language (settings), and the language last heard
+    language_preference: crate::kernel::language::LanguagePreference,
+    spoken_language: Option<String>,
 
     // Phase E: Audio Storage (Cognition)
     // Phase E: Audio Storage (Cognition)
-    pub audio_segments: HashMap<String, AudioSegment>,
-    pub active_segment_id: Option<String>,
+    audio_segments: HashMap<String, AudioSegment>,
+    active_segment_id: Option<String>,
+
+    // Playback Position (percent spoken, per output). Enables truncation telemetry.
+    playback_progress: HashMap<OutputId, u8>,
+    // Interruption Context (capped, oldest first)
+    interruptions: std::collections::VecDeque<crate::kernel::cancel::InterruptionRecord>,
 
     // Phase G: Intent Arbitration
     // Phase G: Intent Arbitration
-    pub intent_state: IntentState,
+    intent_state: IntentState,
 
     // Phase H: Memory Consolidation
-    pub memory_candidates: HashMap<MemoryId, MemoryCandidate>,
-    pub long_term_memory: HashMap<MemoryId, MemoryRecord>,
+    memory_candidates: HashMap<MemoryId, MemoryCandidate>,
+    long_term_memory: HashMap<MemoryId, MemoryRecord>,
     // Phase L: Consent State (Human-Aligned)
-    pub memory_consent: HashMap<MemoryKey, MemoryConsent>,
+    memory_consent: HashMap<MemoryKey, MemoryConsent>,
+    // Key of the prompt the UI is showing (one at a time)
+    consent_pending: Option<MemoryKey>,
+    // Topic Segmentation
+    active_topic: Option<TopicId>,
+    topic_consent: HashMap<TopicId, MemoryConsentState>,
+    // Privacy: intents whose transcript was classified sensitive (sticky per intent)
+    sensitive_intents: HashMap<IntentId, SensitivityClass>,
+    // Language each spoken intent was declared in
+    intent_languages: HashMap<IntentId, String>,
+    // Contexts: the conversation currently in focus, and the intent state of every parked one
+    active_context: ContextId,
+    parked_intent_states: HashMap<ContextId, IntentState>,
 }
 
 impl Default for SharedState {
@@ -143,23 +276,43 @@ impl Default for SharedState {
             active_outputs: HashMap::new(),
             canceled_tasks: HashSet::new(),
             version: 0,
+            domain_versions: DomainVersions::default(),
+            version_history: std::collections::VecDeque::from([(0, DomainVersions::default())]),
+            applied: None,
             last_tick: Tick { frame: 0 },
             user_speaking: false,
             turn_pressure: 0.0,
             last_speech_start: None,
             last_speech_end: None,
             hesitation_detected: false,
+            last_user_input: None,
+            quiet_hours: crate::kernel::quiet::QuietHoursState::default(),
             visual: VisualState::default(), 
             latents: crate::kernel::latent::LatentState::default(),
             meta_latents: MetaLatents::default(),
             active_intents: HashMap::new(),
             presence: PresenceState::default(),
+            capture_permission: CapturePermission::default(),
+            output_device: None,
+            input_device: None,
+            subsystems: crate::kernel::subsystem::SubsystemFlags::default(),
+            language_preference: crate::kernel::language::LanguagePreference::default(),
+            spoken_language: None,
             audio_segments: HashMap::new(),
             active_segment_id: None,
+            playback_progress: HashMap::new(),
+            interruptions: std::collections::VecDeque::new(),
             intent_state: IntentState::default(),
             memory_candidates: HashMap::new(),
             long_term_memory: HashMap::new(),
             memory_consent: HashMap::new(),
+            consent_pending: None,
+            active_topic: None,
+            topic_consent: HashMap::new(),
+            sensitive_intents: HashMap::new(),
+            intent_languages: HashMap::new(),
+            active_context: ContextId::default(),
+            parked_intent_states: HashMap::new(),
         }
     }
 }
@@ -169,6 +322,42 @@ impl SharedState {
         Self::default()
     }
 
+    /// Fresh state at a given epoch version (tests and replay). Stale-plan checks compare against it.
+    pub fn at_version(version: u64) -> Self {
+        Self { version, version_history: std::collections::VecDeque::from([(version, DomainVersions::default())]), ..Self::default() }
+    }
+
+    /// The persisted part of this state (`kernel::persist`), as versioned JSON.
+    pub fn serialize(&self) -> Result<String, crate::kernel::persist::PersistError> {
+        serde_json::to_string_pretty(&crate::kernel::persist::PersistedState::capture(self))
+            .map_err(|e| crate::kernel::persist::PersistError::Malformed(e.to_string()))
+    }
+
+    /// A fresh state with what `serialize` wrote, reduced back in at its tick.
+    pub fn deserialize(text: &str) -> Result<Self, crate::kernel::persist::PersistError> {
+        let persisted = crate::kernel::persist::PersistedState::parse(text)?;
+        let mut state = Self::new();
+        state.reduce(StateDelta::Tick(persisted.tick));
+        for delta in persisted.deltas() {
+            state.reduce(delta);
+        }
+        Ok(state)
+    }
+
+    /// True if `intent_id` was classified sensitive and the user has not consented to it yet.
+    /// Consent is key-level only: a Granted consent on one of its candidates, or a promoted record.
+    pub fn sensitive_unconsented(&self, intent_id: &IntentId) -> bool {
+        if !self.sensitive_intents.contains_key(intent_id) {
+            return false;
+        }
+        let granted = |key: &MemoryKey| {
+            self.memory_consent.get(key).map(|c| c.state == MemoryConsentState::Granted).unwrap_or(false)
+        };
+        let consented = self.memory_candidates.values().any(|c| &c.intent.id == intent_id && granted(&c.key))
+            || self.long_term_memory.values().any(|r| &r.intent.id == intent_id);
+        !consented
+    }
+
     pub fn snapshot(&self, tick: Tick, intent_context: crate::kernel::intent::long_horizon::IntentContext) -> crate::planner::types::StateSnapshot {
         crate::planner::types::StateSnapshot {
             epoch: crate::planner::types::PlanningEpoch {
@@ -179,6 +368,16 @@ impl SharedState {
             user_active: self.user_speaking,
             active_outputs: self.active_outputs.len(),
             recent_interruptions: self.canceled_tasks.len(),
+            pending_consent: self.consent_pending(tick).is_some(),
+            deferred_outputs: 0, // Held by the Reactor; it fills this in
+            gate_preview: crate::kernel::crystallizer::preview_gate(self),
+            last_interruption: self.interruptions.back().cloned().map(|mut record| {
+                // Privacy: don't leak an unconsented sensitive intent through the interruption context
+                if record.active_intent.as_ref().map(|id| self.sensitive_unconsented(id)).unwrap_or(false) {
+                    record.active_intent = None;
+                }
+                record
+            }),
             latent_summary: {
                 // Textual Firewall: Summarize slots to natural language
                 let mut summary = String::new();
@@ -210,10 +409,17 @@ impl SharedState {
 
     /// Pure reduction: State + Delta -> Mutated State
     pub fn reduce(&mut self, delta: StateDelta) {
-        // Version increments on mutation (except Tick to allow slow planning)
+        if let Some(applied) = self.applied.as_mut() {
+            applied.push(delta.clone());
+        }
         // Version increments on mutation (except Tick and High-Freq Audio to allow slow planning)
-        if !matches!(delta, StateDelta::Tick(_) | StateDelta::AudioFrameAppended { .. }) {
+        if let Some(domain) = delta.domain() {
             self.version += 1;
+            self.domain_versions.bump(domain);
+            if self.version_history.len() == VERSION_HISTORY {
+                self.version_history.pop_front();
+            }
+            self.version_history.push_back((self.version, self.domain_versions));
         }
         
         match delta {
@@ -222,7 +428,7 @@ impl SharedState {
                 // Turn Pressure Dynamics
                 // Decay if not speaking
                 if !self.user_speaking {
-                    self.turn_pressure = (self.turn_pressure - 0.01).max(0.0);
+                    self.turn_pressure = crate::kernel::decay::linear(self.turn_pressure, 1, 0.01);
                 } else {
                     // If speaking and system has active outputs (interruption)
                     if !self.active_outputs.is_empty() {
@@ -232,38 +438,44 @@ impl SharedState {
                 
                 // Visual Stability Decay (Physics)
                 // If no update received this tick, decay slightly
-                self.visual.stability_score = (self.visual.stability_score - 0.01).max(0.0);
+                self.visual.stability_score = crate::kernel::decay::linear(self.visual.stability_score, 1, 0.01);
                 
                 // Latent Decay (Physics)
                 // Decay constant lambda ~ 0.1 for fast decay (Audio), 0.01 for slow (Vision)
-                // confidence_new = confidence * exp(-rate) ~ confidence * (1.0 - rate)
+                // confidence_new = confidence * (1.0 - rate), clamped (a rate above 1.0 is a drop, below 0.0 no decay)
                 self.latents.slots.retain_mut(|slot| {
-                    slot.confidence *= 1.0 - slot.decay_rate;
+                    let half_life = crate::kernel::decay::half_life(1.0 - slot.decay_rate);
+                    slot.confidence = crate::kernel::decay::exponential(slot.confidence, 1, half_life);
                     slot.confidence > 0.05 // Prune dead slots
                 });
             }
             StateDelta::InputReceived(input) => {
+                // Speech edges are timed by when they were heard (capture stamp), not processed
+                let heard_at = input.captured_at.unwrap_or(self.last_tick);
                 match input.content {
                     InputContent::Audio(AudioSignal::SpeechStart) => {
                         self.user_speaking = true;
-                        self.last_speech_start = Some(self.last_tick);
+                        self.last_speech_start = Some(heard_at);
                         self.hesitation_detected = false; 
                     }
                     InputContent::Audio(AudioSignal::SpeechEnd) => {
                         self.user_speaking = false;
-                        self.last_speech_end = Some(self.last_tick);
+                        self.last_speech_end = Some(heard_at);
                         
                         // Check Hesitation (Short burst < 10 ticks = 200ms)
                         if let Some(start) = self.last_speech_start {
                             // Tick should support subtraction or frame diff
-                            if self.last_tick.frame >= start.frame {
-                                let duration = self.last_tick.frame - start.frame;
+                            if heard_at.frame >= start.frame {
+                                let duration = heard_at.frame - start.frame;
                                 if duration < 10 && duration > 0 {
                                     self.hesitation_detected = true;
                                 }
                             }
                         }
                     }
+                    InputContent::Text(_) | InputContent::ProvisionalText { .. } => {
+                        self.last_user_input = Some(self.last_tick);
+                    }
                     _ => {}
                 }
             }
@@ -273,7 +485,20 @@ impl SharedState {
             StateDelta::OutputCommitted(id) => {
                 if let Some(out) = self.active_outputs.get_mut(&id) {
                     out.status = OutputStatus::Committed;
+                    out.committed_at = Some(self.last_tick);
+                }
+                self.playback_progress.insert(id, 100);
+            }
+            StateDelta::InterruptionRecorded(record) => {
+                if self.interruptions.len() >= crate::kernel::cancel::MAX_INTERRUPTION_RECORDS {
+                    self.interruptions.pop_front();
                 }
+                self.interruptions.push_back(record);
+            }
+            StateDelta::OutputPlaybackProgress { id, percent } => {
+                // Monotonic: late/out-of-order reports never move playback backwards
+                let entry = self.playback_progress.entry(id).or_insert(0);
+                *entry = (*entry).max(percent.min(100));
             }
             StateDelta::OutputCanceled(id) => {
                 if let Some(out) = self.active_outputs.get_mut(&id) {
@@ -306,14 +531,39 @@ impl SharedState {
                 self.active_intents.insert(intent.id.clone(), intent);
             }
             StateDelta::PresenceTransition(request) => {
-                if let Some(new_state) = PresenceGraph::transition(self.presence, request) {
+                if let Some(new_state) = PresenceGraph::transition_gated(self.presence, request, self.capture_permission) {
                     // TODO: Emit event? For now just mutate.
                     self.presence = new_state;
                 }
                 // If None, the transition was rejected by the Core (Authority).
             }
             StateDelta::PresenceUpdate(new_state) => {
-                self.presence = new_state;
+                // Microphone gate: never Attentive without capture access
+                if new_state != PresenceState::Attentive || self.capture_permission.allows_listening() {
+                    self.presence = new_state;
+                }
+            }
+            StateDelta::CapturePermissionChanged(permission) => {
+                self.capture_permission = permission;
+                let request = if permission.allows_listening() { PresenceRequest::CaptureGranted } else { PresenceRequest::CaptureBlocked };
+                if let Some(new_state) = PresenceGraph::transition(self.presence, request) {
+                    self.presence = new_state;
+                }
+            }
+            StateDelta::OutputDeviceChanged(device) => {
+                self.output_device = device;
+            }
+            StateDelta::InputDeviceChanged(device) => {
+                self.input_device = device;
+            }
+            StateDelta::SubsystemToggled { subsystem, enabled } => {
+                self.subsystems.set(subsystem, enabled);
+            }
+            StateDelta::LanguagePreferenceChanged(preference) => {
+                self.language_preference = preference;
+            }
+            StateDelta::QuietHoursChanged(quiet) => {
+                self.quiet_hours = quiet;
             }
             StateDelta::AudioSegmentCreated(seg) => {
                 self.active_segment_id = Some(seg.id.clone());
@@ -333,22 +583,69 @@ impl SharedState {
                     self.active_segment_id = None;
                 }
             }
+            StateDelta::AudioSegmentTrimmed { segment_id, frames, trim } => {
+                if let Some(seg) = self.audio_segments.get_mut(&segment_id) {
+                    seg.frames = frames;
+                    seg.trim = Some(trim);
+                }
+            }
             StateDelta::AudioSegmentTranscribing(segment_id) => {
                 if let Some(seg) = self.audio_segments.get_mut(&segment_id) {
                     seg.status = SegmentStatus::Transcribing;
                 }
             }
-            StateDelta::AudioSegmentTranscribed { segment_id, text } => {
+            StateDelta::AudioSegmentTranscribed { segment_id, text, words, language } => {
                 if let Some(seg) = self.audio_segments.get_mut(&segment_id) {
                     seg.status = SegmentStatus::Transcribed;
                     seg.transcription = Some(text);
+                    seg.words = words;
+                    seg.language = language.clone();
                 }
+                if language.is_some() {
+                    self.spoken_language = language;
+                }
+            }
+            StateDelta::AudioSegmentDiscarded(segment_id) => {
+                if let Some(seg) = self.audio_segments.get_mut(&segment_id) {
+                    seg.status = SegmentStatus::Discarded;
+                }
+            }
+            StateDelta::CaptureRestarted => {
+                if let Some(id) = self.active_segment_id.take() {
+                    if let Some(seg) = self.audio_segments.get_mut(&id) {
+                        seg.status = SegmentStatus::Discarded;
+                    }
+                }
+                self.user_speaking = false;
             }
             StateDelta::AssessmentUpdate(new_state) => {
                 self.intent_state = new_state;
             }
             StateDelta::AllOutputsCleared => {
                 self.active_outputs.clear();
+                self.playback_progress.clear();
+            }
+            StateDelta::AudioSegmentsPurged(ids) => {
+                for id in ids {
+                    // Never drop the segment currently being captured
+                    if self.active_segment_id.as_ref() != Some(&id) {
+                        self.audio_segments.remove(&id);
+                    }
+                }
+            }
+            StateDelta::LatentsTruncated { keep } => {
+                // Slots are appended in arrival order: keep the newest
+                let len = self.latents.slots.len();
+                if len > keep {
+                    self.latents.slots.drain(..len - keep);
+                }
+            }
+            StateDelta::LongHorizonIntentsPurged(ids) => {
+                for id in ids {
+                    self.active_intents.remove(&id);
+                    self.sensitive_intents.remove(&id);
+                    self.intent_languages.remove(&id);
+                }
             }
             // Phase H: Memory Reduction
             StateDelta::MemoryCandidateCreated(candidate) => {
@@ -389,7 +686,18 @@ impl SharedState {
                 let consent = MemoryConsent::new(key.clone(), tick);
                 self.memory_consent.insert(key, consent);
             }
+            StateDelta::MemoryConsentPrompted { key, prompt_id, expires_at, topic_id } => {
+                if let Some(consent) = self.memory_consent.get_mut(&key) {
+                    consent.prompt_id = Some(prompt_id);
+                    consent.expires_at = Some(expires_at);
+                    consent.topic_id = topic_id;
+                    self.consent_pending = Some(key);
+                }
+            }
             StateDelta::MemoryConsentResolved { key, state, resolved_at } => {
+                if self.consent_pending.as_ref() == Some(&key) {
+                    self.consent_pending = None;
+                }
                 if let Some(consent) = self.memory_consent.get_mut(&key) {
                     consent.state = state;
                     consent.resolved_at = Some(resolved_at);
@@ -404,10 +712,216 @@ impl SharedState {
                      self.memory_consent.insert(key, consent);
                 }
             }
+            StateDelta::TopicChanged(topic_id) => {
+                self.active_topic = Some(topic_id);
+            }
+            StateDelta::TopicConsentResolved { topic_id, state } => {
+                self.topic_consent.insert(topic_id, state);
+            }
+            StateDelta::MemoryTopicForgotten(topic_id) => {
+                // Forget everything learned under this topic (candidates AND records)
+                self.memory_candidates.retain(|_, c| c.topic_id.as_ref() != Some(&topic_id));
+                self.long_term_memory.retain(|_, r| r.topic_id.as_ref() != Some(&topic_id));
+            }
+            StateDelta::IntentSensitivityMarked { intent_id, class } => {
+                self.sensitive_intents.insert(intent_id, class);
+            }
+            StateDelta::IntentLanguageTagged { intent_id, language } => {
+                self.intent_languages.insert(intent_id, language);
+            }
+            StateDelta::ContextSwitched(target) => {
+                if target != self.active_context {
+                    let parked = std::mem::take(&mut self.intent_state);
+                    if parked != IntentState::None {
+                        self.parked_intent_states.insert(self.active_context.clone(), parked);
+                    }
+                    self.intent_state = self.parked_intent_states.remove(&target).unwrap_or_default();
+                    self.active_context = target;
+                }
+            }
         }
     }
+
+    /// Long-term memory for export, with sensitive records redacted at `level`.
+    pub fn export_memories(&self, level: crate::kernel::memory::sensitivity::RedactionLevel) -> Vec<MemoryRecord> {
+        self.long_term_memory.values().filter_map(|r| r.redacted(level)).collect()
+    }
     
-    // Read-only accessors for Planner
+    // === READ ACCESSORS ===
+    // Fields are private: `reduce` is the only way SharedState mutates.
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Keep a copy of every reduced delta for `take_applied` (see `kernel::observer`).
+    pub fn record_deltas(&mut self, enabled: bool) {
+        match (enabled, self.applied.is_some()) {
+            (true, false) => self.applied = Some(Vec::new()),
+            (false, true) => self.applied = None,
+            _ => {}
+        }
+    }
+
+    /// Deltas reduced since the last call, in order (empty unless recording).
+    pub fn take_applied(&mut self) -> Vec<StateDelta> {
+        self.applied.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    pub fn domain_versions(&self) -> DomainVersions {
+        self.domain_versions
+    }
+
+    /// Mutations per domain since `version`. `None` if it is older than the kept history
+    /// (`VERSION_HISTORY`) or ahead of the state.
+    pub fn domain_lag(&self, version: u64) -> Option<DomainVersions> {
+        // Versions in the history are consecutive
+        let oldest = self.version_history.front()?.0;
+        let (_, then) = self.version_history.get(version.checked_sub(oldest)? as usize)?;
+        Some(self.domain_versions.since(then))
+    }
+
+    pub fn last_tick(&self) -> Tick {
+        self.last_tick
+    }
+
+    pub fn user_speaking(&self) -> bool {
+        self.user_speaking
+    }
+
+    pub fn turn_pressure(&self) -> f32 {
+        self.turn_pressure
+    }
+
+    pub fn last_speech_start(&self) -> Option<Tick> {
+        self.last_speech_start
+    }
+
+    pub fn last_speech_end(&self) -> Option<Tick> {
+        self.last_speech_end
+    }
+
+    pub fn hesitation_detected(&self) -> bool {
+        self.hesitation_detected
+    }
+
+    pub fn last_user_input(&self) -> Option<Tick> {
+        self.last_user_input
+    }
+
+    pub fn quiet_hours(&self) -> crate::kernel::quiet::QuietHoursState {
+        self.quiet_hours
+    }
+
+    pub fn presence(&self) -> PresenceState {
+        self.presence
+    }
+
+    pub fn capture_permission(&self) -> CapturePermission {
+        self.capture_permission
+    }
+
+    pub fn output_device(&self) -> Option<&String> {
+        self.output_device.as_ref()
+    }
+
+    pub fn input_device(&self) -> Option<&String> {
+        self.input_device.as_ref()
+    }
+
+    pub fn subsystems(&self) -> crate::kernel::subsystem::SubsystemFlags {
+        self.subsystems
+    }
+
+    pub fn active_segment_id(&self) -> Option<&String> {
+        self.active_segment_id.as_ref()
+    }
+
+    pub fn active_topic(&self) -> Option<&TopicId> {
+        self.active_topic.as_ref()
+    }
+
+    pub fn visual(&self) -> &VisualState {
+        &self.visual
+    }
+
+    pub fn latents(&self) -> &crate::kernel::latent::LatentState {
+        &self.latents
+    }
+
+    pub fn meta_latents(&self) -> &MetaLatents {
+        &self.meta_latents
+    }
+
+    pub fn active_intents(&self) -> &HashMap<IntentId, LongHorizonIntent> {
+        &self.active_intents
+    }
+
+    pub fn audio_segments(&self) -> &HashMap<String, AudioSegment> {
+        &self.audio_segments
+    }
+
+    pub fn playback_progress(&self) -> &HashMap<OutputId, u8> {
+        &self.playback_progress
+    }
+
+    pub fn interruptions(&self) -> &std::collections::VecDeque<crate::kernel::cancel::InterruptionRecord> {
+        &self.interruptions
+    }
+
+    pub fn intent_state(&self) -> &IntentState {
+        &self.intent_state
+    }
+
+    pub fn memory_candidates(&self) -> &HashMap<MemoryId, MemoryCandidate> {
+        &self.memory_candidates
+    }
+
+    pub fn long_term_memory(&self) -> &HashMap<MemoryId, MemoryRecord> {
+        &self.long_term_memory
+    }
+
+    pub fn memory_consent(&self) -> &HashMap<MemoryKey, MemoryConsent> {
+        &self.memory_consent
+    }
+
+    /// The consent prompt on screen, while it can still be answered. No other prompt is asked meanwhile.
+    pub fn consent_pending(&self, now: Tick) -> Option<&MemoryConsent> {
+        self.consent_pending.as_ref()
+            .and_then(|key| self.memory_consent.get(key))
+            .filter(|consent| consent.prompt_pending(now))
+    }
+
+    pub fn topic_consent(&self) -> &HashMap<TopicId, MemoryConsentState> {
+        &self.topic_consent
+    }
+
+    pub fn sensitive_intents(&self) -> &HashMap<IntentId, SensitivityClass> {
+        &self.sensitive_intents
+    }
+
+    /// Language `intent_id` was spoken in (`None`: typed, or not reported by the ASR backend).
+    pub fn intent_language(&self, intent_id: &IntentId) -> Option<&str> {
+        self.intent_languages.get(intent_id).map(String::as_str)
+    }
+
+    pub fn language_preference(&self) -> &crate::kernel::language::LanguagePreference {
+        &self.language_preference
+    }
+
+    /// Language of the last transcribed segment that reported one.
+    pub fn spoken_language(&self) -> Option<&str> {
+        self.spoken_language.as_deref()
+    }
+
+    pub fn active_context(&self) -> &ContextId {
+        &self.active_context
+    }
+
+    pub fn parked_intent_states(&self) -> &HashMap<ContextId, IntentState> {
+        &self.parked_intent_states
+    }
+
     pub fn active_outputs(&self) -> &HashMap<OutputId, Output> {
         &self.active_outputs
     }
@@ -415,4 +929,19 @@ impl SharedState {
     pub fn canceled_tasks(&self) -> &std::collections::HashSet<String> {
         &self.canceled_tasks
     }
+
+    /// Topic-scoped retrieval: Long-term memories learned under `topic_id` (active context only).
+    pub fn memories_for_topic(&self, topic_id: &TopicId) -> Vec<&MemoryRecord> {
+        self.long_term_memory.values()
+            .filter(|r| r.context == self.active_context)
+            .filter(|r| r.topic_id.as_ref() == Some(topic_id))
+            .collect()
+    }
+
+    /// Context-scoped retrieval: Long-term memories learned in `context`.
+    pub fn memories_in_context(&self, context: &ContextId) -> Vec<&MemoryRecord> {
+        self.long_term_memory.values()
+            .filter(|r| &r.context == context)
+            .collect()
+    }
 }
@@ -0,0 +1,64 @@
+//! Runtime Subsystem Switches.
+//!
+//! Alpha users can switch off the parts they distrust without restarting in safe mode.
+//! Drivers send `InputContent::SubsystemControl`; the Reactor applies it at the tick boundary
+//! (`StateDelta::SubsystemToggled`) and the flags are published in the State View.
+//!
+//! - `Vision`: visual percepts are dropped on arrival.
+//! - `Memory`: no consolidation, consent prompts, promotion or compaction (as in safe mode).
+//! - `ProactiveSpeech`: output nobody asked for is denied at the gate (`GateRule::SubsystemDisabled`).
+//! - `TelemetryExport`: the nightly spool export is skipped. Recording continues locally.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Subsystem {
+    Vision,
+    Memory,
+    ProactiveSpeech,
+    TelemetryExport,
+}
+
+impl Subsystem {
+    pub const ALL: [Subsystem; 4] = [
+        Subsystem::Vision,
+        Subsystem::Memory,
+        Subsystem::ProactiveSpeech,
+        Subsystem::TelemetryExport,
+    ];
+}
+
+/// Which subsystems run. Everything is on by default; restart keeps the flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubsystemFlags {
+    pub vision: bool,
+    pub memory: bool,
+    pub proactive_speech: bool,
+    pub telemetry_export: bool,
+}
+
+impl Default for SubsystemFlags {
+    fn default() -> Self {
+        Self { vision: true, memory: true, proactive_speech: true, telemetry_export: true }
+    }
+}
+
+impl SubsystemFlags {
+    pub fn is_enabled(&self, subsystem: Subsystem) -> bool {
+        match subsystem {
+            Subsystem::Vision => self.vision,
+            Subsystem::Memory => self.memory,
+            Subsystem::ProactiveSpeech => self.proactive_speech,
+            Subsystem::TelemetryExport => self.telemetry_export,
+        }
+    }
+
+    pub fn set(&mut self, subsystem: Subsystem, enabled: bool) {
+        match subsystem {
+            Subsystem::Vision => self.vision = enabled,
+            Subsystem::Memory => self.memory = enabled,
+            Subsystem::ProactiveSpeech => self.proactive_speech = enabled,
+            Subsystem::TelemetryExport => self.telemetry_export = enabled,
+        }
+    }
+}
@@ -3,7 +3,7 @@ use crate::kernel::time::Tick;
 use crate::kernel::presence::PresenceState;
 use crate::kernel::event::OutputId;
 use crate::kernel::intent::types::DialogueAct; // We'll map to a sanitized kind
-use crate::kernel::intent::long_horizon::{IntentId, IntentStatus};
+use crate::kernel::intent::long_horizon::{IntentBoard, IntentId, IntentStatus};
 use crate::kernel::memory::types::MemoryId;
 
 // Allowed: IDs, Timestamps, Durations, Counts, Enums
@@ -29,6 +29,24 @@ pub enum TelemetryEvent {
 
     SilencePeriod {
         duration_ticks: u64,
+        // Spools written before the split read as Idle
+        #[serde(default)]
+        context: crate::kernel::telemetry::silence::SilenceContext,
+    },
+
+    // Turn gap: user SpeechEnd -> system PlaybackStarted
+    ResponseGap {
+        gap_ticks: u64,
+    },
+
+    // Driver speech: RequestSpeech -> first generated sentence playing (outputs::streaming)
+    FirstAudio {
+        latency_ms: u64,
+    },
+
+    // Audio edge heard (capture stamp) -> processed by the kernel
+    CaptureLag {
+        lag_ticks: u64,
     },
 
     OutputLifecycle {
@@ -53,6 +71,11 @@ pub enum TelemetryEvent {
         dormant_ticks: u64,
     },
 
+    // Periodic intent board gauge (LHIM tick): counts by status and age
+    IntentBoard {
+        board: IntentBoard,
+    },
+
     MemoryEvent {
         kind: MemoryEventKind,
         memory_id: MemoryId,
@@ -66,6 +89,41 @@ pub enum TelemetryEvent {
 
     SafeModeActive,
 
+    // Settings switch (kernel::subsystem): which parts users turn off
+    SubsystemToggled {
+        subsystem: crate::kernel::subsystem::Subsystem,
+        enabled: bool,
+    },
+
+    // Planner output parsing (strict / repaired / failed -> DoNothing)
+    PlanParse {
+        outcome: PlanParseKind,
+    },
+
+    // Periodic state size accounting (counts + approximate bytes only)
+    StateFootprint {
+        footprint: crate::kernel::footprint::StateFootprint,
+        pressured: Vec<crate::kernel::footprint::FootprintDomain>,
+    },
+
+    // Kernel event queue (kernel::queue): periodic, and at once after drops
+    EventQueue {
+        stats: crate::kernel::queue::QueueStats,
+    },
+
+    // Driver transcription queue (audio::transcription), recorded when the depth changes
+    TranscriptionQueue {
+        queued: usize,
+        in_flight: usize,
+    },
+    TranscriptionCancelled,
+
+    // Output safety filter hit on LLM text (outputs::safety); reason + action only, never the text
+    OutputFiltered {
+        reason: crate::outputs::safety::FilterReason,
+        action: crate::outputs::safety::FilterAction,
+    },
+
     SessionSummary {
         duration_ticks: u64,
         silence_ratio: f32,
@@ -81,6 +139,7 @@ pub enum OutputEventKind {
     HardCommit,
     SoftCommit,
     Cancelled,
+    PlaybackCompleted,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -88,6 +147,8 @@ pub enum InterruptionSource {
     AudioSpeechStart,
     ExplicitCancel,
     NewIntentConflict,
+    // Screen percept jumped (hard context shift)
+    VisualContextShift,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -98,6 +159,8 @@ pub enum MemoryEventKind {
     Decayed,
     Forgotten,
     AttributesUpdated,
+    // Candidate removed by hand (memory inspector)
+    CandidateDiscarded,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -109,9 +172,25 @@ pub enum DialogueActKind {
     StaySilent,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlanParseKind {
+    Parsed,
+    Repaired,
+    NoJson,
+    InvalidJson,
+    UnknownIntent,
+    InvalidData,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LifecycleEvent {
     OnboardingCompleted,
+    // One job of a maintenance run (kernel::maintenance); items: entries / files / events
+    Maintenance {
+        job: crate::kernel::maintenance::MaintenanceJob,
+        outcome: crate::kernel::maintenance::JobOutcome,
+        items: u64,
+    },
 }
 
 impl From<&DialogueAct> for DialogueActKind {
@@ -0,0 +1,244 @@
+//! Content-Free Telemetry Export.
+//!
+//! Everything that leaves the machine goes through here. On top of the PRIVACY
+//! INVARIANT (no user content in events), the export:
+//! - replaces every internal ID with a salted hash (salt never leaves the machine),
+//! - buckets every count, duration and timestamp to a power of two,
+//! - rounds ratios to one decimal.
+//!
+//! `nexus telemetry preview` prints exactly this payload so users can review it.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use serde::Serialize;
+
+use super::event::{
+    DialogueActKind, InterruptionSource, LifecycleEvent, MemoryEventKind, OutputEventKind, PlanParseKind,
+    SpeechLifecycleEvent, TelemetryEvent,
+};
+use crate::kernel::event::OutputId;
+use crate::kernel::footprint::{DomainFootprint, FootprintDomain, StateFootprint};
+use crate::kernel::queue::QueueStats;
+use crate::kernel::intent::long_horizon::{IntentAges, IntentBoard, IntentStatus};
+use crate::kernel::presence::PresenceState;
+use crate::kernel::subsystem::Subsystem;
+use super::silence::SilenceContext;
+use crate::outputs::safety::{FilterAction, FilterReason};
+
+// Bump when the exported shape changes
+pub const EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Per-export salt. Hashes from different salts can't be correlated.
+#[derive(Debug, Clone, Copy)]
+pub struct ExportSalt(u64);
+
+impl ExportSalt {
+    pub fn new(salt: u64) -> Self {
+        Self(salt)
+    }
+
+    pub fn random() -> Self {
+        Self(uuid::Uuid::new_v4().as_u64_pair().0)
+    }
+
+    pub fn hash_id(&self, id: &str) -> String {
+        let mut hasher = DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        id.hash(&mut hasher);
+        format!("{:016x}", hasher.finish())
+    }
+}
+
+/// Lower bound of the power-of-two bucket `n` falls in (0, 1, 2, 4, 8, ...).
+pub fn bucket(n: u64) -> u64 {
+    if n == 0 { 0 } else { 1 << (63 - n.leading_zeros()) }
+}
+
+fn bucket_ratio(r: f32) -> f32 {
+    (r.clamp(0.0, 1.0) * 10.0).round() / 10.0
+}
+
+fn bucket_domain(d: DomainFootprint) -> DomainFootprint {
+    DomainFootprint {
+        count: bucket(d.count as u64) as usize,
+        approx_bytes: bucket(d.approx_bytes as u64) as usize,
+    }
+}
+
+fn bucket_ages(ages: IntentAges) -> IntentAges {
+    IntentAges(ages.0.map(|n| bucket(n as u64) as usize))
+}
+
+fn output_key(id: &OutputId) -> String {
+    format!("{}:{}", id.tick, id.ordinal)
+}
+
+/// Export-side mirror of `TelemetryEvent`: hashed IDs, bucketed numbers.
+#[derive(Debug, Clone, Serialize)]
+pub enum ExportEvent {
+    SpeechLifecycle(SpeechLifecycleEvent),
+    PresenceTransition { from: PresenceState, to: PresenceState, tick_bucket: u64 },
+    SilencePeriod { duration_bucket: u64, context: SilenceContext },
+    ResponseGap { gap_bucket: u64 },
+    FirstAudio { latency_bucket: u64 },
+    CaptureLag { lag_bucket: u64 },
+    OutputLifecycle { output: String, event: OutputEventKind, latency_bucket: u64 },
+    Interruption { source: InterruptionSource, cancel_latency_bucket: u64 },
+    IntentLifecycle { intent: String, from: IntentStatus, to: IntentStatus },
+    IntentResumption { intent: String, dormant_bucket: u64 },
+    IntentBoard { board: IntentBoard },
+    MemoryEvent { kind: MemoryEventKind, memory: String },
+    DialogueAct { act: DialogueActKind },
+    Lifecycle(LifecycleEvent),
+    SafeModeActive,
+    SubsystemToggled { subsystem: Subsystem, enabled: bool },
+    PlanParse { outcome: PlanParseKind },
+    StateFootprint { footprint: StateFootprint, pressured: Vec<FootprintDomain> },
+    EventQueue { stats: QueueStats },
+    TranscriptionQueue { queued_bucket: u64, in_flight_bucket: u64 },
+    TranscriptionCancelled,
+    OutputFiltered { reason: FilterReason, action: FilterAction },
+    SessionSummary {
+        duration_bucket: u64,
+        silence_ratio: f32,
+        interruptions_bucket: u64,
+        resumed_intents_bucket: u64,
+        memory_consents_bucket: u64,
+    },
+}
+
+impl ExportEvent {
+    pub fn from_event(event: &TelemetryEvent, salt: &ExportSalt) -> Self {
+        match event {
+            TelemetryEvent::SpeechLifecycle(e) => ExportEvent::SpeechLifecycle(e.clone()),
+            TelemetryEvent::PresenceTransition { from, to, tick } => ExportEvent::PresenceTransition {
+                from: *from,
+                to: *to,
+                tick_bucket: bucket(tick.frame),
+            },
+            TelemetryEvent::SilencePeriod { duration_ticks, context } => ExportEvent::SilencePeriod {
+                duration_bucket: bucket(*duration_ticks),
+                context: *context,
+            },
+            TelemetryEvent::ResponseGap { gap_ticks } => ExportEvent::ResponseGap {
+                gap_bucket: bucket(*gap_ticks),
+            },
+            TelemetryEvent::FirstAudio { latency_ms } => ExportEvent::FirstAudio {
+                latency_bucket: bucket(*latency_ms),
+            },
+            TelemetryEvent::CaptureLag { lag_ticks } => ExportEvent::CaptureLag {
+                lag_bucket: bucket(*lag_ticks),
+            },
+            TelemetryEvent::OutputLifecycle { output_id, event, latency_ticks } => ExportEvent::OutputLifecycle {
+                output: salt.hash_id(&output_key(output_id)),
+                event: *event,
+                latency_bucket: bucket(*latency_ticks),
+            },
+            TelemetryEvent::Interruption { source, cancel_latency_ticks } => ExportEvent::Interruption {
+                source: *source,
+                cancel_latency_bucket: bucket(*cancel_latency_ticks),
+            },
+            TelemetryEvent::IntentLifecycle { intent_id, from, to } => ExportEvent::IntentLifecycle {
+                intent: salt.hash_id(intent_id),
+                from: *from,
+                to: *to,
+            },
+            TelemetryEvent::IntentResumption { intent_id, dormant_ticks } => ExportEvent::IntentResumption {
+                intent: salt.hash_id(intent_id),
+                dormant_bucket: bucket(*dormant_ticks),
+            },
+            TelemetryEvent::IntentBoard { board } => ExportEvent::IntentBoard {
+                board: IntentBoard {
+                    active: bucket_ages(board.active),
+                    suspended: bucket_ages(board.suspended),
+                    dormant: bucket_ages(board.dormant),
+                },
+            },
+            TelemetryEvent::MemoryEvent { kind, memory_id } => ExportEvent::MemoryEvent {
+                kind: *kind,
+                memory: salt.hash_id(memory_id),
+            },
+            TelemetryEvent::DialogueAct { act } => ExportEvent::DialogueAct { act: *act },
+            TelemetryEvent::Lifecycle(LifecycleEvent::Maintenance { job, outcome, items }) => {
+                ExportEvent::Lifecycle(LifecycleEvent::Maintenance { job: *job, outcome: *outcome, items: bucket(*items) })
+            }
+            TelemetryEvent::Lifecycle(e) => ExportEvent::Lifecycle(*e),
+            TelemetryEvent::SafeModeActive => ExportEvent::SafeModeActive,
+            TelemetryEvent::SubsystemToggled { subsystem, enabled } => ExportEvent::SubsystemToggled { subsystem: *subsystem, enabled: *enabled },
+            TelemetryEvent::PlanParse { outcome } => ExportEvent::PlanParse { outcome: *outcome },
+            TelemetryEvent::StateFootprint { footprint, pressured } => ExportEvent::StateFootprint {
+                footprint: StateFootprint {
+                    tick: bucket(footprint.tick),
+                    audio_segments: bucket_domain(footprint.audio_segments),
+                    latents: bucket_domain(footprint.latents),
+                    memory_candidates: bucket_domain(footprint.memory_candidates),
+                    long_term_memory: bucket_domain(footprint.long_term_memory),
+                    memory_consent: bucket_domain(footprint.memory_consent),
+                    intents: bucket_domain(footprint.intents),
+                    conversation_log: bucket_domain(footprint.conversation_log),
+                },
+                pressured: pressured.clone(),
+            },
+            TelemetryEvent::EventQueue { stats } => ExportEvent::EventQueue {
+                stats: QueueStats {
+                    depth: bucket(stats.depth as u64) as usize,
+                    capacity: stats.capacity,
+                    high_water: bucket(stats.high_water as u64) as usize,
+                    dropped: bucket(stats.dropped),
+                },
+            },
+            TelemetryEvent::TranscriptionQueue { queued, in_flight } => ExportEvent::TranscriptionQueue {
+                queued_bucket: bucket(*queued as u64),
+                in_flight_bucket: bucket(*in_flight as u64),
+            },
+            TelemetryEvent::TranscriptionCancelled => ExportEvent::TranscriptionCancelled,
+            TelemetryEvent::OutputFiltered { reason, action } => ExportEvent::OutputFiltered { reason: *reason, action: *action },
+            TelemetryEvent::SessionSummary { duration_ticks, silence_ratio, interruptions, resumed_intents, memory_consents } => {
+                ExportEvent::SessionSummary {
+                    duration_bucket: bucket(*duration_ticks),
+                    silence_ratio: bucket_ratio(*silence_ratio),
+                    interruptions_bucket: bucket(*interruptions),
+                    resumed_intents_bucket: bucket(*resumed_intents),
+                    memory_consents_bucket: bucket(*memory_consents),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TelemetryExport {
+    pub schema: u32,
+    pub events: Vec<ExportEvent>,
+}
+
+impl TelemetryExport {
+    pub fn content_free<'a>(events: impl IntoIterator<Item = &'a TelemetryEvent>, salt: &ExportSalt) -> Self {
+        Self {
+            schema: EXPORT_SCHEMA_VERSION,
+            events: events.into_iter().map(|e| ExportEvent::from_event(e, salt)).collect(),
+        }
+    }
+
+    /// Raw IDs from `source` that still appear anywhere in the serialized export. Must be empty.
+    pub fn leaked_ids<'a>(&self, source: impl IntoIterator<Item = &'a TelemetryEvent>) -> Vec<String> {
+        let payload = serde_json::to_string(self).unwrap_or_default();
+        let mut leaked: Vec<String> = source.into_iter()
+            .filter_map(raw_id)
+            .filter(|id| !id.is_empty() && payload.contains(id.as_str()))
+            .collect();
+        leaked.sort();
+        leaked.dedup();
+        leaked
+    }
+}
+
+fn raw_id(event: &TelemetryEvent) -> Option<String> {
+    match event {
+        TelemetryEvent::OutputLifecycle { output_id, .. } => Some(output_key(output_id)),
+        TelemetryEvent::IntentLifecycle { intent_id, .. } | TelemetryEvent::IntentResumption { intent_id, .. } => Some(intent_id.clone()),
+        TelemetryEvent::MemoryEvent { memory_id, .. } => Some(memory_id.clone()),
+        _ => None,
+    }
+}
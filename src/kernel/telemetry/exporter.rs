@@ -0,0 +1,280 @@
+//! Periodic Telemetry Export (snapshots to a JSONL file and/or an OTLP collector).
+//!
+//! `TelemetryRecorder` only keeps its ring in memory. With `ReactorConfig::telemetry_export`,
+//! the Reactor takes a `TelemetrySnapshot` of that ring every `interval_ticks` and hands it to
+//! the driver as `SideEffect::ExportTelemetry` (a tick does no I/O); shutdown, outside the
+//! tick, flushes once more itself. Each export goes to:
+//! - a local JSONL file (`TelemetryExporter::file`), one `SnapshotLine` per flush;
+//! - an OpenTelemetry collector over OTLP/HTTP JSON (`TelemetryExporter::otlp_endpoint`,
+//!   feature `otlp`), one gauge per numeric snapshot field.
+//!
+//! Both carry aggregates only: a snapshot holds counts, durations and ratios, never an ID or
+//! user content. What leaves the machine (OTLP) is further bucketed like `export` (powers of
+//! two). The `telemetry_export` subsystem switch pauses both.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::export::{bucket, EXPORT_SCHEMA_VERSION};
+use super::metrics::TelemetrySnapshot;
+use crate::kernel::time::{Tick, TICK_MS};
+
+// Config Constants
+/// Default flush cadence when export is enabled from the environment (60s @ 20ms).
+pub const DEFAULT_EXPORT_INTERVAL_TICKS: u64 = 3_000;
+/// OTLP/HTTP collector default (`OTEL_EXPORTER_OTLP_ENDPOINT` overrides).
+pub const DEFAULT_OTLP_ENDPOINT: &str = "http://localhost:4318";
+/// Prefix of every exported metric name.
+pub const METRIC_PREFIX: &str = "nexus";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ExportConfig {
+    /// Ticks between two flushes (0: export off)
+    pub interval_ticks: u64,
+    /// Append snapshots to `TelemetryExporter::file`
+    pub file: bool,
+    /// Push bucketed metrics to `TelemetryExporter::otlp_endpoint` (feature `otlp`)
+    pub otlp: bool,
+}
+
+impl ExportConfig {
+    /// `NEXUS_TELEMETRY_EXPORT=file,otlp`, `NEXUS_TELEMETRY_EXPORT_SECS=60`. Off when unset.
+    pub fn from_env() -> Self {
+        let targets = std::env::var("NEXUS_TELEMETRY_EXPORT").unwrap_or_default();
+        let secs: Option<u64> = std::env::var("NEXUS_TELEMETRY_EXPORT_SECS").ok().and_then(|s| s.parse().ok());
+        Self::parse(&targets, secs)
+    }
+
+    /// PURE FUNCTION: Targets as a comma list (`file`, `otlp`), cadence in seconds (None: default).
+    pub fn parse(targets: &str, secs: Option<u64>) -> Self {
+        let targets: Vec<String> = targets.split(',').map(|t| t.trim().to_ascii_lowercase()).collect();
+        let file = targets.iter().any(|t| t == "file");
+        let otlp = targets.iter().any(|t| t == "otlp");
+        let interval_ticks = match secs {
+            _ if !file && !otlp => 0,
+            Some(secs) => secs * 1000 / TICK_MS,
+            None => DEFAULT_EXPORT_INTERVAL_TICKS,
+        };
+        Self { interval_ticks, file, otlp }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.interval_ticks > 0 && (self.file || self.otlp)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportError {
+    /// Snapshot file could not be opened or appended to
+    Io(String),
+    /// OTLP asked for without the `otlp` feature, or outside a Tokio runtime
+    OtlpUnavailable,
+}
+
+/// One line of the snapshot file.
+#[derive(Debug, Clone, Serialize)]
+pub struct SnapshotLine<'a> {
+    pub schema: u32,
+    pub tick: u64,
+    pub snapshot: &'a TelemetrySnapshot,
+}
+
+/// One OTLP gauge: dotted snapshot field path, bucketed value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MetricPoint {
+    pub name: String,
+    pub value: u64,
+}
+
+/// PURE FUNCTION: Every numeric field of `snapshot` as a bucketed gauge, in field order
+/// (`nexus.silence_stats.total_ticks`, `nexus.intent_stats.latest_board.active.0`, ...).
+/// Only numbers are emitted; absent optionals are skipped.
+pub fn metric_points(snapshot: &TelemetrySnapshot) -> Vec<MetricPoint> {
+    let mut points = Vec::new();
+    if let Ok(value) = serde_json::to_value(snapshot) {
+        flatten(METRIC_PREFIX, &value, &mut points);
+    }
+    points
+}
+
+fn flatten(name: &str, value: &serde_json::Value, points: &mut Vec<MetricPoint>) {
+    match value {
+        serde_json::Value::Number(n) => {
+            let value = n.as_u64().unwrap_or_else(|| n.as_f64().unwrap_or(0.0).max(0.0).round() as u64);
+            points.push(MetricPoint { name: name.to_string(), value: bucket(value) });
+        }
+        serde_json::Value::Object(fields) => {
+            for (field, value) in fields {
+                flatten(&format!("{}.{}", name, field), value, points);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (i, value) in items.iter().enumerate() {
+                flatten(&format!("{}.{}", name, i), value, points);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// PURE FUNCTION: OTLP/JSON `ExportMetricsServiceRequest` with one gauge per point.
+pub fn otlp_payload(points: &[MetricPoint], time_unix_nano: u64) -> serde_json::Value {
+    let metrics: Vec<serde_json::Value> = points.iter().map(|point| serde_json::json!({
+        "name": point.name,
+        "gauge": { "dataPoints": [{ "asInt": point.value.to_string(), "timeUnixNano": time_unix_nano.to_string() }] },
+    })).collect();
+    serde_json::json!({
+        "resourceMetrics": [{
+            "resource": { "attributes": [{ "key": "service.name", "value": { "stringValue": METRIC_PREFIX } }] },
+            "scopeMetrics": [{
+                "scope": { "name": "nexus.telemetry", "version": EXPORT_SCHEMA_VERSION.to_string() },
+                "metrics": metrics,
+            }],
+        }],
+    })
+}
+
+/// One due export, as the driver runs it (`SideEffect::ExportTelemetry`): the snapshot and
+/// the targets configured when it was taken.
+#[derive(Debug, Clone)]
+pub struct SnapshotExport {
+    pub tick: Tick,
+    pub snapshot: TelemetrySnapshot,
+    file: Option<PathBuf>,
+    otlp_endpoint: Option<String>,
+    #[cfg(feature = "otlp")]
+    client: reqwest::Client,
+}
+
+impl SnapshotExport {
+    /// Blocking append to the snapshot file (no file target: nothing to do).
+    pub fn append(&self) -> Result<(), ExportError> {
+        match &self.file {
+            Some(path) => append_line(path, &SnapshotLine { schema: EXPORT_SCHEMA_VERSION, tick: self.tick.frame, snapshot: &self.snapshot }),
+            None => Ok(()),
+        }
+    }
+
+    /// Start the OTLP push in the background (no OTLP target: nothing to do). Needs a Tokio
+    /// runtime; failures of the push itself are logged, not returned.
+    pub fn push(&self) -> Result<(), ExportError> {
+        match &self.otlp_endpoint {
+            Some(endpoint) => self.push_otlp(endpoint, metric_points(&self.snapshot)),
+            None => Ok(()),
+        }
+    }
+
+    #[cfg(feature = "otlp")]
+    fn push_otlp(&self, endpoint: &str, points: Vec<MetricPoint>) -> Result<(), ExportError> {
+        let runtime = tokio::runtime::Handle::try_current().map_err(|_| ExportError::OtlpUnavailable)?;
+        let time_unix_nano = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        let request = self.client
+            .post(format!("{}/v1/metrics", endpoint.trim_end_matches('/')))
+            .json(&otlp_payload(&points, time_unix_nano));
+        runtime.spawn(async move {
+            match request.send().await.and_then(|r| r.error_for_status()) {
+                Ok(_) => {}
+                Err(e) => warn!("OTLP telemetry push failed: {}", e),
+            }
+        });
+        Ok(())
+    }
+
+    #[cfg(not(feature = "otlp"))]
+    fn push_otlp(&self, _endpoint: &str, _points: Vec<MetricPoint>) -> Result<(), ExportError> {
+        Err(ExportError::OtlpUnavailable)
+    }
+}
+
+/// Flush cadence and targets. Config comes from `ReactorConfig`; the file path and collector
+/// endpoint are set by the driver (like `MaintenanceScheduler::telemetry_spool`).
+#[derive(Debug)]
+pub struct TelemetryExporter {
+    config: ExportConfig,
+    /// Snapshot file (None: the file target is skipped)
+    pub file: Option<PathBuf>,
+    /// OTLP/HTTP collector base URL (metrics go to `<endpoint>/v1/metrics`)
+    pub otlp_endpoint: String,
+    last_flush: Tick,
+    flushes: u64,
+    #[cfg(feature = "otlp")]
+    client: reqwest::Client,
+}
+
+impl TelemetryExporter {
+    pub fn new(config: ExportConfig) -> Self {
+        if config.otlp && cfg!(not(feature = "otlp")) {
+            warn!("OTLP telemetry export requested but compiled out (feature `otlp`)");
+        }
+        Self {
+            config,
+            file: None,
+            otlp_endpoint: DEFAULT_OTLP_ENDPOINT.to_string(),
+            last_flush: Tick::new(),
+            flushes: 0,
+            #[cfg(feature = "otlp")]
+            client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(5))
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> ExportConfig {
+        self.config
+    }
+
+    /// Snapshots flushed so far (periodic and final).
+    pub fn flushes(&self) -> u64 {
+        self.flushes
+    }
+
+    pub fn last_flush(&self) -> Tick {
+        self.last_flush
+    }
+
+    /// Enabled and `interval_ticks` passed since the last flush.
+    pub fn is_due(&self, now: Tick) -> bool {
+        self.config.is_enabled() && now.frame.saturating_sub(self.last_flush.frame) >= self.config.interval_ticks
+    }
+
+    /// Take a due export: counts it as a flush and returns what the driver writes. Pure; the
+    /// Reactor emits it as `SideEffect::ExportTelemetry`.
+    pub fn export(&mut self, now: Tick, snapshot: TelemetrySnapshot) -> SnapshotExport {
+        self.last_flush = now;
+        self.flushes += 1;
+        SnapshotExport {
+            tick: now,
+            snapshot,
+            file: self.file.clone().filter(|_| self.config.file),
+            otlp_endpoint: self.config.otlp.then(|| self.otlp_endpoint.clone()),
+            #[cfg(feature = "otlp")]
+            client: self.client.clone(),
+        }
+    }
+
+    /// Export `snapshot` to every configured target right away (shutdown, outside the tick).
+    /// The OTLP push runs in the background; its failures are logged, not returned.
+    pub fn flush(&mut self, now: Tick, snapshot: &TelemetrySnapshot) -> Result<(), ExportError> {
+        let export = self.export(now, snapshot.clone());
+        let appended = export.append();
+        appended.and(export.push())
+    }
+}
+
+fn append_line(path: &std::path::Path, line: &SnapshotLine) -> Result<(), ExportError> {
+    let json = serde_json::to_string(line).map_err(|e| ExportError::Io(e.to_string()))?;
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut out| writeln!(out, "{}", json))
+        .map_err(|e| ExportError::Io(e.to_string()))
+}
@@ -1,32 +1,70 @@
 use std::collections::VecDeque;
-use super::event::{TelemetryEvent, MemoryEventKind, DialogueActKind};
-use crate::kernel::intent::long_horizon::IntentStatus;
+use serde::Serialize;
+use super::event::{TelemetryEvent, MemoryEventKind, DialogueActKind, PlanParseKind};
+use super::silence::SilenceContext;
+use crate::kernel::intent::long_horizon::{IntentBoard, IntentStatus};
+use crate::outputs::safety::FilterAction;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct TelemetrySnapshot {
     pub silence_stats: SilenceStats,
+    pub response_gap_stats: ResponseGapStats,
+    pub capture_lag_stats: CaptureLagStats,
+    pub first_audio_stats: FirstAudioStats,
     pub interruption_stats: InterruptionStats,
     pub intent_stats: IntentStats,
     pub memory_stats: MemoryStats,
     pub dialogue_stats: DialogueStats,
+    pub planner_stats: PlannerStats,
+    pub footprint_stats: FootprintStats,
+    pub transcription_stats: TranscriptionStats,
+    pub event_queue_stats: EventQueueStats,
+    pub filter_stats: FilterStats,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct SilenceStats {
     pub total_periods: u64,
     pub total_ticks: u64,
     pub avg_silence_ticks: f64,
     pub max_silence_ticks: u64,
+    // Split of total_ticks: pauses near activity vs idle time
+    pub in_conversation_ticks: u64,
+    pub idle_ticks: u64,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ResponseGapStats {
+    pub count: u64,
+    pub total_gap_ticks: u64,
+    pub avg_gap_ticks: f64,
+    pub max_gap_ticks: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CaptureLagStats {
+    pub count: u64,
+    pub total_lag_ticks: u64,
+    pub avg_lag_ticks: f64,
+    pub max_lag_ticks: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FirstAudioStats {
+    pub count: u64,
+    pub total_latency_ms: u64,
+    pub avg_latency_ms: f64,
+    pub max_latency_ms: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct InterruptionStats {
     pub count: u64,
     pub total_latency_ticks: u64,
     pub avg_cancel_latency_ticks: f64,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct IntentStats {
     pub created: u64,
     pub suspended: u64,
@@ -34,9 +72,11 @@ pub struct IntentStats {
     pub invalidated: u64,
     pub total_dormant_ticks: u64,
     pub avg_dormancy_ticks: f64,
+    pub latest_board: Option<IntentBoard>,
+    pub peak_dormant: usize, // Largest dormant set reported
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct MemoryStats {
     pub candidates_created: u64,
     pub reinforced: u64,
@@ -45,7 +85,7 @@ pub struct MemoryStats {
     pub forgotten: u64,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DialogueStats {
     pub clarifications: u64,
     pub confirmations: u64,
@@ -53,6 +93,42 @@ pub struct DialogueStats {
     pub silent_waits: u64,
 }
 
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PlannerStats {
+    pub parsed: u64,
+    pub repaired: u64,
+    pub failed: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FootprintStats {
+    pub latest: Option<crate::kernel::footprint::StateFootprint>,
+    pub pressure_passes: u64, // Accounting passes that found a domain near its ceiling
+    pub peak_bytes: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TranscriptionStats {
+    pub peak_queued: usize,
+    pub peak_in_flight: usize,
+    pub cancelled: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EventQueueStats {
+    pub capacity: usize,
+    pub peak_depth: usize,
+    pub dropped: u64, // Session total at the latest report
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FilterStats {
+    pub filtered: u64, // Outputs the safety filter caught (sum of the three below)
+    pub redacted: u64,
+    pub regenerated: u64,
+    pub silenced: u64,
+}
+
 pub fn compute_snapshot(events: &VecDeque<TelemetryEvent>) -> TelemetrySnapshot {
     let mut snap = TelemetrySnapshot::default();
     
@@ -61,14 +137,33 @@ pub fn compute_snapshot(events: &VecDeque<TelemetryEvent>) -> TelemetrySnapshot
     
     for event in events {
         match event {
-            TelemetryEvent::SilencePeriod { duration_ticks } => {
+            TelemetryEvent::SilencePeriod { duration_ticks, context } => {
                 snap.silence_stats.total_periods += 1;
                 snap.silence_stats.total_ticks += duration_ticks;
                 if *duration_ticks > snap.silence_stats.max_silence_ticks {
                     snap.silence_stats.max_silence_ticks = *duration_ticks;
                 }
+                match context {
+                    SilenceContext::InConversation => snap.silence_stats.in_conversation_ticks += duration_ticks,
+                    SilenceContext::Idle => snap.silence_stats.idle_ticks += duration_ticks,
+                }
                 silence_accum_count += 1;
             }
+            TelemetryEvent::ResponseGap { gap_ticks } => {
+                snap.response_gap_stats.count += 1;
+                snap.response_gap_stats.total_gap_ticks += gap_ticks;
+                snap.response_gap_stats.max_gap_ticks = snap.response_gap_stats.max_gap_ticks.max(*gap_ticks);
+            }
+            TelemetryEvent::CaptureLag { lag_ticks } => {
+                snap.capture_lag_stats.count += 1;
+                snap.capture_lag_stats.total_lag_ticks += lag_ticks;
+                snap.capture_lag_stats.max_lag_ticks = snap.capture_lag_stats.max_lag_ticks.max(*lag_ticks);
+            }
+            TelemetryEvent::FirstAudio { latency_ms } => {
+                snap.first_audio_stats.count += 1;
+                snap.first_audio_stats.total_latency_ms += latency_ms;
+                snap.first_audio_stats.max_latency_ms = snap.first_audio_stats.max_latency_ms.max(*latency_ms);
+            }
             TelemetryEvent::Interruption { source: _, cancel_latency_ticks } => {
                 snap.interruption_stats.count += 1;
                 snap.interruption_stats.total_latency_ticks += cancel_latency_ticks;
@@ -89,6 +184,10 @@ pub fn compute_snapshot(events: &VecDeque<TelemetryEvent>) -> TelemetrySnapshot
                 snap.intent_stats.total_dormant_ticks += dormant_ticks;
                 resumption_count += 1;
             }
+            TelemetryEvent::IntentBoard { board } => {
+                snap.intent_stats.peak_dormant = snap.intent_stats.peak_dormant.max(board.dormant.count());
+                snap.intent_stats.latest_board = Some(*board);
+            }
             TelemetryEvent::MemoryEvent { kind, .. } => {
                 match kind {
                     MemoryEventKind::CandidateCreated => snap.memory_stats.candidates_created += 1,
@@ -96,7 +195,7 @@ pub fn compute_snapshot(events: &VecDeque<TelemetryEvent>) -> TelemetrySnapshot
                     MemoryEventKind::Promoted => snap.memory_stats.promoted += 1,
                     MemoryEventKind::Decayed => snap.memory_stats.decayed += 1,
                     MemoryEventKind::Forgotten => snap.memory_stats.forgotten += 1,
-                    MemoryEventKind::AttributesUpdated => {} // Tracking only
+                    MemoryEventKind::AttributesUpdated | MemoryEventKind::CandidateDiscarded => {} // Tracking only
                 }
             }
             TelemetryEvent::DialogueAct { act } => {
@@ -107,6 +206,40 @@ pub fn compute_snapshot(events: &VecDeque<TelemetryEvent>) -> TelemetrySnapshot
                     DialogueActKind::Wait | DialogueActKind::StaySilent => snap.dialogue_stats.silent_waits += 1,
                 }
             }
+            TelemetryEvent::PlanParse { outcome } => {
+                match outcome {
+                    PlanParseKind::Parsed => snap.planner_stats.parsed += 1,
+                    PlanParseKind::Repaired => snap.planner_stats.repaired += 1,
+                    _ => snap.planner_stats.failed += 1,
+                }
+            }
+            TelemetryEvent::StateFootprint { footprint, pressured } => {
+                if !pressured.is_empty() {
+                    snap.footprint_stats.pressure_passes += 1;
+                }
+                snap.footprint_stats.peak_bytes = snap.footprint_stats.peak_bytes.max(footprint.total_bytes());
+                snap.footprint_stats.latest = Some(footprint.clone());
+            }
+            TelemetryEvent::TranscriptionQueue { queued, in_flight } => {
+                snap.transcription_stats.peak_queued = snap.transcription_stats.peak_queued.max(*queued);
+                snap.transcription_stats.peak_in_flight = snap.transcription_stats.peak_in_flight.max(*in_flight);
+            }
+            TelemetryEvent::EventQueue { stats } => {
+                snap.event_queue_stats.capacity = stats.capacity;
+                snap.event_queue_stats.peak_depth = snap.event_queue_stats.peak_depth.max(stats.high_water);
+                snap.event_queue_stats.dropped = snap.event_queue_stats.dropped.max(stats.dropped);
+            }
+            TelemetryEvent::TranscriptionCancelled => {
+                snap.transcription_stats.cancelled += 1;
+            }
+            TelemetryEvent::OutputFiltered { action, .. } => {
+                snap.filter_stats.filtered += 1;
+                match action {
+                    FilterAction::Redacted => snap.filter_stats.redacted += 1,
+                    FilterAction::Regenerated => snap.filter_stats.regenerated += 1,
+                    FilterAction::Silenced => snap.filter_stats.silenced += 1,
+                }
+            }
             _ => {}
         }
     }
@@ -116,6 +249,18 @@ pub fn compute_snapshot(events: &VecDeque<TelemetryEvent>) -> TelemetrySnapshot
         snap.silence_stats.avg_silence_ticks = snap.silence_stats.total_ticks as f64 / silence_accum_count as f64;
     }
     
+    if snap.response_gap_stats.count > 0 {
+        snap.response_gap_stats.avg_gap_ticks = snap.response_gap_stats.total_gap_ticks as f64 / snap.response_gap_stats.count as f64;
+    }
+
+    if snap.capture_lag_stats.count > 0 {
+        snap.capture_lag_stats.avg_lag_ticks = snap.capture_lag_stats.total_lag_ticks as f64 / snap.capture_lag_stats.count as f64;
+    }
+
+    if snap.first_audio_stats.count > 0 {
+        snap.first_audio_stats.avg_latency_ms = snap.first_audio_stats.total_latency_ms as f64 / snap.first_audio_stats.count as f64;
+    }
+
     if snap.interruption_stats.count > 0 {
         snap.interruption_stats.avg_cancel_latency_ticks = snap.interruption_stats.total_latency_ticks as f64 / snap.interruption_stats.count as f64;
     }
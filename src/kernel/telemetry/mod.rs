@@ -8,7 +8,12 @@
 //! # PRIVACY INVARIANT
 //! Telemetry events must **NEVER** contain user content (Text, Audio, Embeddings).
 //! Only internal IDs (IntentId, MemoryId, OutputId) and metrics (Duration, Counts) are allowed.
+//! Anything leaving the machine is further stripped by `export` (salted IDs, bucketed numbers).
+//! Periodic snapshots (`exporter`) carry aggregates only; their OTLP metrics are bucketed too.
 
 pub mod event;
 pub mod metrics;
 pub mod recorder;
+pub mod export;
+pub mod exporter;
+pub mod silence;
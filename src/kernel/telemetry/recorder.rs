@@ -1,21 +1,49 @@
 use std::collections::VecDeque;
+use std::sync::mpsc::{channel, Receiver, Sender};
 use super::event::TelemetryEvent;
 use super::metrics::{TelemetrySnapshot, compute_snapshot};
+use super::export::{ExportSalt, TelemetryExport};
 
 const MAX_EVENTS: usize = 10_000;
 
+/// Cloneable, lock-free way into a `TelemetryRecorder` for drivers, sidecars and
+/// background tasks. Recording never blocks; the Reactor drains the handle every tick.
+/// (std `mpsc` is crossbeam's channel under the hood, so no extra dependency.)
+#[derive(Debug, Clone)]
+pub struct TelemetryHandle {
+    tx: Sender<TelemetryEvent>,
+}
+
+impl TelemetryHandle {
+    /// Dropped silently if the recorder is gone (telemetry never fails the caller).
+    pub fn record(&self, event: TelemetryEvent) {
+        let _ = self.tx.send(event);
+    }
+}
+
 #[derive(Debug)]
 pub struct TelemetryRecorder {
     buffer: VecDeque<TelemetryEvent>,
+    // Events recorded through handles, not yet drained into `buffer`
+    inbox: Receiver<TelemetryEvent>,
+    handle: TelemetryHandle,
 }
 
 impl TelemetryRecorder {
     pub fn new() -> Self {
+        let (tx, inbox) = channel();
         Self {
             buffer: VecDeque::with_capacity(MAX_EVENTS),
+            inbox,
+            handle: TelemetryHandle { tx },
         }
     }
 
+    /// Shared handle for code that does not own the recorder.
+    pub fn handle(&self) -> TelemetryHandle {
+        self.handle.clone()
+    }
+
     pub fn record(&mut self, event: TelemetryEvent) {
         if self.buffer.len() >= MAX_EVENTS {
             self.buffer.pop_front();
@@ -23,6 +51,16 @@ impl TelemetryRecorder {
         self.buffer.push_back(event);
     }
 
+    /// Move everything recorded through handles into the buffer. Returns how many events.
+    pub fn drain(&mut self) -> usize {
+        let mut drained = 0;
+        while let Ok(event) = self.inbox.try_recv() {
+            self.record(event);
+            drained += 1;
+        }
+        drained
+    }
+
     pub fn snapshot(&self) -> TelemetrySnapshot {
         // Delegate to pure functional metrics module
         compute_snapshot(&self.buffer)
@@ -31,6 +69,28 @@ impl TelemetryRecorder {
     pub fn clear(&mut self) {
         self.buffer.clear();
     }
+
+    pub fn events(&self) -> impl Iterator<Item = &TelemetryEvent> {
+        self.buffer.iter()
+    }
+
+    /// The only shape telemetry may take when leaving the machine.
+    pub fn export_content_free(&self, salt: &ExportSalt) -> TelemetryExport {
+        TelemetryExport::content_free(&self.buffer, salt)
+    }
+
+    // Local spool (JSON lines, raw events). Stays on this machine; `telemetry preview` reads it.
+    pub fn save_spool(&self, path: &std::path::Path) -> std::io::Result<()> {
+        write_spool(path, &self.buffer)
+    }
+
+    pub fn load_spool(path: &std::path::Path) -> std::io::Result<Self> {
+        let mut recorder = Self::new();
+        for line in std::fs::read_to_string(path)?.lines().filter(|l| !l.trim().is_empty()) {
+            recorder.record(serde_json::from_str(line)?);
+        }
+        Ok(recorder)
+    }
     
     // Phase M: Session Aggregation (Called on Shutdown)
     pub fn aggregate_session(&self, duration_ticks: u64) -> TelemetryEvent {
@@ -58,3 +118,13 @@ impl TelemetryRecorder {
          }
     }
 }
+
+/// Write `events` as a spool (JSON lines). Blocking: maintenance runs it off the tick.
+pub fn write_spool<'a>(path: &std::path::Path, events: impl IntoIterator<Item = &'a TelemetryEvent>) -> std::io::Result<()> {
+    let mut out = String::new();
+    for event in events {
+        out.push_str(&serde_json::to_string(event)?);
+        out.push('\n');
+    }
+    std::fs::write(path, out)
+}
@@ -0,0 +1,80 @@
+//! Silence & Response Timing.
+//!
+//! A tick where nobody speaks is not one kind of silence. Right after someone spoke it is a
+//! pause in the conversation. Long after, it is just idle time, which would dominate any
+//! aggregate. `SilenceClassifier` labels each silent tick by its distance from the last
+//! activity. `ResponseGapTracker` measures the turn gap: user SpeechEnd -> system PlaybackStarted.
+//!
+//! Telemetry bookkeeping only: never read by decision logic.
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::time::Tick;
+
+// Config Constants
+/// Silence within this many ticks of activity is part of the conversation (5s at 20ms).
+pub const CONVERSATION_WINDOW_TICKS: u64 = 250;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SilenceContext {
+    /// Within `CONVERSATION_WINDOW_TICKS` of speech or input (a pause, a turn gap)
+    InConversation,
+    /// Nothing happened recently
+    #[default]
+    Idle,
+}
+
+#[derive(Debug, Clone)]
+pub struct SilenceClassifier {
+    window_ticks: u64,
+    last_activity: Option<Tick>,
+}
+
+impl Default for SilenceClassifier {
+    fn default() -> Self {
+        Self::new(CONVERSATION_WINDOW_TICKS)
+    }
+}
+
+impl SilenceClassifier {
+    pub fn new(window_ticks: u64) -> Self {
+        Self { window_ticks, last_activity: None }
+    }
+
+    /// Once per tick. `active`: someone spoke or typed this tick. `None` = not silent.
+    pub fn observe(&mut self, tick: Tick, active: bool) -> Option<SilenceContext> {
+        if active {
+            self.last_activity = Some(tick);
+            return None;
+        }
+        let recent = self.last_activity
+            .map(|last| tick.frame.saturating_sub(last.frame) <= self.window_ticks)
+            .unwrap_or(false);
+        Some(if recent { SilenceContext::InConversation } else { SilenceContext::Idle })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ResponseGapTracker {
+    user_done_at: Option<Tick>,
+}
+
+impl ResponseGapTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The user resumed speaking: the turn is not over yet.
+    pub fn user_speech_started(&mut self) {
+        self.user_done_at = None;
+    }
+
+    pub fn user_speech_ended(&mut self, tick: Tick) {
+        self.user_done_at = Some(tick);
+    }
+
+    /// Ticks since the user stopped speaking, once per turn.
+    pub fn playback_started(&mut self, tick: Tick) -> Option<u64> {
+        self.user_done_at.take().map(|done| tick.frame.saturating_sub(done.frame))
+    }
+}
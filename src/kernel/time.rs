@@ -16,3 +16,29 @@ impl Tick {
         Tick { frame: self.frame + 1 }
     }
 }
+
+/// The kernel's current tick, shared with the capture layer so audio is stamped when it
+/// was heard rather than when a later tick got around to it (VAD debounce, channel and
+/// transcription delays). The reactor publishes every tick; capture threads only read.
+/// Lock-free: the audio thread must never block.
+#[derive(Debug, Clone, Default)]
+pub struct CaptureClock(std::sync::Arc<std::sync::atomic::AtomicU64>);
+
+impl CaptureClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn publish(&self, tick: Tick) {
+        self.0.store(tick.frame, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    pub fn now(&self) -> Tick {
+        Tick { frame: self.0.load(std::sync::atomic::Ordering::Relaxed) }
+    }
+
+    /// The tick `ms` ago (e.g. the first frame of a debounced VAD edge).
+    pub fn ago_ms(&self, ms: u64) -> Tick {
+        Tick { frame: self.now().frame.saturating_sub(ms / TICK_MS) }
+    }
+}
@@ -0,0 +1,101 @@
+//! Decision Trace: per-tick explanation records for arbitration, gating and planning.
+//!
+//! Invariants (same as Telemetry):
+//! 1. Read-only: recording never influences decisions.
+//! 2. No user content: IDs, enums and numbers only.
+
+use std::collections::VecDeque;
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::time::Tick;
+use crate::kernel::crystallizer::GateExplanation;
+use crate::kernel::intent::types::{IntentHypothesis, IntentStability};
+use crate::kernel::telemetry::event::DialogueActKind;
+
+const DEFAULT_CAPACITY: usize = 512; // Ticks with at least one decision
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TraceEntry {
+    /// Crystallization Gate fired (BeginResponse or proactive speech)
+    Gate(GateExplanation),
+    /// Arbitration winner (highest-confidence candidate) and resulting act
+    Arbitration {
+        winner_id: Option<String>,
+        hypothesis: Option<IntentHypothesis>,
+        stability: Option<IntentStability>,
+        confidence: f32,
+        candidates: usize,
+        act: DialogueActKind,
+    },
+    PlanAccepted { epoch_version: u64, state_version: u64 },
+    /// Plan discarded by the staleness rule
+    PlanStale { epoch_version: u64, state_version: u64 },
+    /// Follow-up offer of a two-part reply: said or dropped
+    FollowUp { answer: crate::kernel::event::OutputId, outcome: crate::kernel::follow_up::FollowUpOutcome },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionTrace {
+    pub tick: Tick,
+    pub entries: Vec<TraceEntry>,
+}
+
+/// Bounded ring of per-tick decision traces.
+/// Idle ticks (no entries) are not stored.
+#[derive(Debug)]
+pub struct DecisionTraceRing {
+    pub enabled: bool,
+    capacity: usize,
+    buffer: VecDeque<DecisionTrace>,
+    current: Option<DecisionTrace>,
+}
+
+impl DecisionTraceRing {
+    pub fn new(enabled: bool) -> Self {
+        Self::with_capacity(enabled, DEFAULT_CAPACITY)
+    }
+
+    pub fn with_capacity(enabled: bool, capacity: usize) -> Self {
+        Self {
+            enabled,
+            capacity,
+            buffer: VecDeque::with_capacity(capacity),
+            current: None,
+        }
+    }
+
+    /// Open the trace for this tick (flushes any unfinished one).
+    pub fn begin(&mut self, tick: Tick) {
+        if !self.enabled { return; }
+        self.end();
+        self.current = Some(DecisionTrace { tick, entries: Vec::new() });
+    }
+
+    pub fn note(&mut self, entry: TraceEntry) {
+        if let Some(trace) = self.current.as_mut() {
+            trace.entries.push(entry);
+        }
+    }
+
+    /// Close the trace for this tick.
+    pub fn end(&mut self) {
+        if let Some(trace) = self.current.take() {
+            if trace.entries.is_empty() { return; }
+            if self.buffer.len() >= self.capacity {
+                self.buffer.pop_front();
+            }
+            self.buffer.push_back(trace);
+        }
+    }
+
+    /// Most recent `limit` traces, oldest first.
+    pub fn recent(&self, limit: usize) -> Vec<DecisionTrace> {
+        let skip = self.buffer.len().saturating_sub(limit);
+        self.buffer.iter().skip(skip).cloned().collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.current = None;
+    }
+}
@@ -0,0 +1,111 @@
+//! Published State View.
+//!
+//! After every tick the Reactor publishes a small, immutable summary of its state
+//! on a `watch` channel. UI readers (Tauri commands) borrow the latest view
+//! without locking the Reactor, so reading never delays a tick.
+//!
+//! Invariant: the view is a projection. Nothing flows back into the kernel.
+
+use std::sync::Arc;
+use serde::Serialize;
+
+use crate::kernel::intent::types::IntentState;
+use crate::kernel::language::LanguagePreference;
+use crate::kernel::presence::{CapturePermission, ConversationMode, PresenceState};
+use crate::kernel::queue::QueueStats;
+use crate::kernel::quiet::QuietHoursState;
+use crate::kernel::reactor::KernelMode;
+use crate::kernel::state::SharedState;
+use crate::kernel::subsystem::SubsystemFlags;
+use crate::kernel::time::Tick;
+
+/// What the kernel is working on, for UI indicators only. Never read by decision logic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum Cognition {
+    #[default]
+    Idle,
+    /// A plan was dispatched to the LLM planner and has not come back (or been aborted)
+    Planning,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StateView {
+    pub tick: Tick,
+    pub version: u64,
+    pub mode: KernelMode,
+    pub presence: PresenceState,
+    pub capture_permission: CapturePermission,
+    // Playback route (None = system default)
+    pub output_device: Option<String>,
+    pub quiet_hours: QuietHoursState,
+    pub user_speaking: bool,
+    pub system_speaking: bool,
+    pub conversation_mode: ConversationMode,
+    // Captured audio is processed (microphone access, and not muted by half duplex)
+    pub listening: bool,
+    pub cognition: Cognition,
+    // Audio gate: a segment is currently buffering
+    pub buffering: bool,
+    pub active_outputs: usize,
+    // Hypothesis of the current arbitration winner (Stable or Suspended)
+    pub intent_focus: Option<String>,
+    pub active_intents: usize,
+    pub active_topic: Option<String>,
+    pub pending_consents: usize,
+    // A consent prompt is on screen (voice "yes" / "no" answers it)
+    pub consent_pending: bool,
+    // Kernel event channel: depth, capacity, high-water mark, drops
+    pub event_queue: QueueStats,
+    // Runtime switches (settings toggles)
+    pub subsystems: SubsystemFlags,
+    // Pinned or detected language, and the language last heard
+    pub language: LanguagePreference,
+    pub spoken_language: Option<String>,
+    // Playback volume of the active profile, percent of the normalized loudness
+    pub volume: u8,
+}
+
+impl StateView {
+    pub fn capture(tick: Tick, mode: KernelMode, system_speaking: bool, conversation_mode: ConversationMode, cognition: Cognition, event_queue: QueueStats, state: &SharedState) -> Self {
+        let intent_focus = match state.intent_state() {
+            IntentState::Stable(c) | IntentState::Suspended(c) => Some(format!("{:?}", c.hypothesis)),
+            IntentState::Compound(clauses) => Some(clauses.iter().map(|c| format!("{:?}", c.hypothesis)).collect::<Vec<_>>().join(" + ")),
+            IntentState::Forming(_) | IntentState::None => None,
+        };
+        Self {
+            tick,
+            version: state.version(),
+            mode,
+            presence: state.presence(),
+            capture_permission: state.capture_permission(),
+            output_device: state.output_device().cloned(),
+            quiet_hours: state.quiet_hours(),
+            user_speaking: state.user_speaking(),
+            system_speaking,
+            conversation_mode,
+            listening: state.capture_permission().allows_listening() && conversation_mode.listening(system_speaking),
+            cognition,
+            buffering: state.active_segment_id().is_some(),
+            active_outputs: state.active_outputs().len(),
+            intent_focus,
+            active_intents: state.active_intents().len(),
+            active_topic: state.active_topic().cloned(),
+            pending_consents: state.memory_consent().values().filter(|c| c.resolved_at.is_none()).count(),
+            consent_pending: state.consent_pending(tick).is_some(),
+            event_queue,
+            subsystems: state.subsystems(),
+            language: state.language_preference().clone(),
+            spoken_language: state.spoken_language().map(str::to_string),
+            volume: crate::outputs::loudness::DEFAULT_VOLUME,
+        }
+    }
+
+    /// The active profile's volume: profiles are the Reactor's, not `SharedState`'s.
+    pub fn with_volume(mut self, volume: u8) -> Self {
+        self.volume = volume;
+        self
+    }
+}
+
+/// Reader handle. Cheap to clone; `borrow()` never blocks the Reactor.
+pub type StateViewReceiver = tokio::sync::watch::Receiver<Arc<StateView>>;
@@ -0,0 +1,53 @@
+//! Warm Start.
+//!
+//! Waking up after a long gap (presence back to Engaged after at least `WARM_START_GAP_TICKS`
+//! out of it), the kernel may say what it is still holding: suspended or dormant long-horizon
+//! intents in the active context, and a resumption offer deferred by quiet hours.
+//! "Welcome back. While you were away I kept one thing on hold: you were asking about something."
+//!
+//! The summary is proactive speech: it passes the proactive gate and the `ProactiveBudget`.
+//! While the gate says no (typically because the user is still speaking the words that woke
+//! us), it waits up to `WARM_START_HOLD_TICKS`, then is dropped. Content-free like resumption
+//! offers: only hypothesis classes, never user text. Toggle: `ReactorConfig::warm_start`.
+
+use crate::kernel::intent::long_horizon::{IntentId, IntentStatus};
+use crate::kernel::intent::types::IntentHypothesis;
+use crate::kernel::state::SharedState;
+use crate::kernel::time::Tick;
+
+// Config Constants
+/// A wake counts as a warm start after this long out of Engaged (15 min @ 20ms).
+pub const WARM_START_GAP_TICKS: u64 = 45_000;
+/// A summary the gate keeps denying is dropped after this many ticks (10s @ 20ms).
+pub const WARM_START_HOLD_TICKS: u64 = 500;
+
+/// What survived the gap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WarmStart {
+    /// Suspended or dormant intents in the active context
+    pub held: usize,
+    /// The most salient of them (highest decay score)
+    pub lead: Option<(IntentId, IntentHypothesis)>,
+    /// A resumption offer is waiting for quiet hours to end
+    pub deferred_offer: bool,
+}
+
+/// PURE FUNCTION: Was this tick's transition a wake after a long gap?
+pub fn is_wake(was_engaged: bool, engaged: bool, last_engaged: Tick, now: Tick) -> bool {
+    !was_engaged && engaged && now.frame.saturating_sub(last_engaged.frame) >= WARM_START_GAP_TICKS
+}
+
+/// PURE FUNCTION: Summary of what is on hold (`None`: nothing worth mentioning).
+pub fn summarize(state: &SharedState, deferred_offer: bool) -> Option<WarmStart> {
+    let held: Vec<_> = state.active_intents().values()
+        .filter(|i| matches!(i.status, IntentStatus::Suspended | IntentStatus::Dormant))
+        .filter(|i| &i.context == state.active_context())
+        .collect();
+    if held.is_empty() && !deferred_offer {
+        return None;
+    }
+    let lead = held.iter()
+        .max_by(|a, b| a.decay_score.partial_cmp(&b.decay_score).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|i| (i.id.clone(), i.hypothesis.clone()));
+    Some(WarmStart { held: held.len(), lead, deferred_offer })
+}
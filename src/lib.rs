@@ -2,9 +2,14 @@ pub mod kernel;
 pub mod planner;
 pub mod audio;
 pub mod outputs;
+#[cfg(feature = "vision")]
 pub mod vision;
 pub mod memory;
 pub mod monitor;
+pub mod dashboard;
+pub mod integrations;
+pub mod federation;
+#[cfg(feature = "llm")]
 pub mod services;
 // pub mod intent; // Legacy - Removed in Phase I
 
@@ -1,214 +1,428 @@
 use nexus::kernel::reactor::Reactor;
 use tokio::sync::mpsc;
-use uuid::Uuid;
 use nexus::kernel::event::Event;
 use nexus::kernel::scheduler::SideEffect;
-use std::collections::HashMap;
-use tokio::task::JoinHandle;
 use std::time::{Duration, Instant};
 
-// Internal Driver Events (Never touch Kernel)
-enum DriverEvent {
-    GeneratedSpeech { output_id: Uuid, text: String },
-    SpeechFailed { output_id: Uuid },
+// Raw telemetry spool (local only). Override with NEXUS_TELEMETRY_SPOOL.
+const TELEMETRY_SPOOL: &str = "nexus_telemetry_spool.jsonl";
+
+fn telemetry_spool_path() -> std::path::PathBuf {
+    std::env::var("NEXUS_TELEMETRY_SPOOL").map(Into::into).unwrap_or_else(|_| TELEMETRY_SPOOL.into())
+}
+
+// Periodic telemetry snapshots (counts only), when NEXUS_TELEMETRY_EXPORT includes `file`. Override with NEXUS_TELEMETRY_EXPORT_FILE.
+const TELEMETRY_EXPORT: &str = "nexus_telemetry_snapshots.jsonl";
+
+fn telemetry_export_path() -> std::path::PathBuf {
+    std::env::var("NEXUS_TELEMETRY_EXPORT_FILE").map(Into::into).unwrap_or_else(|_| TELEMETRY_EXPORT.into())
+}
+
+// State checkpoint written at shutdown (content-free). Override with NEXUS_CHECKPOINT.
+const CHECKPOINT: &str = "nexus_checkpoint.json";
+
+fn checkpoint_path() -> std::path::PathBuf {
+    std::env::var("NEXUS_CHECKPOINT").map(Into::into).unwrap_or_else(|_| CHECKPOINT.into())
+}
+
+// Persisted state (memories, long-horizon intents, consent): written at shutdown, hydrated at start.
+// Contains memory content. Override with NEXUS_STATE.
+const STATE: &str = "nexus_state.json";
+
+fn state_path() -> std::path::PathBuf {
+    std::env::var("NEXUS_STATE").map(Into::into).unwrap_or_else(|_| STATE.into())
+}
+
+/// Local minutes since midnight for quiet hours. `date` honours TZ; UTC if it is unavailable.
+fn local_minute_of_day() -> u16 {
+    let local = std::process::Command::new("date").arg("+%H:%M").output().ok()
+        .and_then(|out| {
+            let text = String::from_utf8_lossy(&out.stdout).to_string();
+            let (h, m) = text.trim().split_once(':')?;
+            Some(h.parse::<u16>().ok()? * 60 + m.parse::<u16>().ok()?)
+        });
+    local.unwrap_or_else(|| {
+        let secs = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        ((secs / 60) % (24 * 60)) as u16
+    })
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize logging/tracing
     tracing_subscriber::fmt::init();
-    tracing::info!("Nexus Kernel Booting...");
 
-    // Kernel Channel
-    let (tx, rx) = mpsc::channel(100);
+    // `nexus doctor`: run the kernel self-test and exit
+    if std::env::args().nth(1).as_deref() == Some("doctor") {
+        let (tx, rx) = mpsc::channel(1);
+        let reactor = Reactor::new(rx, tx, nexus::kernel::reactor::ReactorConfig::default());
+        let report = reactor.run_self_test();
+        for check in &report.checks {
+            println!("[{}] {:?}: {}", if check.passed { " OK " } else { "FAIL" }, check.subsystem, check.detail);
+        }
+        std::process::exit(if report.passed() { 0 } else { 1 });
+    }
+
+    // `nexus telemetry preview [SPOOL]`: print exactly what a telemetry export would send
+    if std::env::args().nth(1).as_deref() == Some("telemetry") {
+        if std::env::args().nth(2).as_deref() != Some("preview") {
+            eprintln!("usage: nexus telemetry preview [SPOOL]");
+            std::process::exit(2);
+        }
+        let path = std::env::args().nth(3).map(Into::into).unwrap_or_else(telemetry_spool_path);
+        let recorder = nexus::kernel::telemetry::recorder::TelemetryRecorder::load_spool(&path)
+            .map_err(|e| format!("cannot read telemetry spool {}: {}", path.display(), e))?;
+        let export = recorder.export_content_free(&nexus::kernel::telemetry::export::ExportSalt::random());
+        println!("{}", serde_json::to_string_pretty(&export)?);
+
+        let leaked = export.leaked_ids(recorder.events());
+        if leaked.is_empty() {
+            eprintln!("[PRIVACY] OK: {} events, no raw identifiers", export.events.len());
+        } else {
+            eprintln!("[PRIVACY] VIOLATION: {} raw identifiers in export", leaked.len());
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // `nexus memory export [OUT.json]` / `nexus memory import FILE [--prefer-imported]`: portable memory bundle
+    if std::env::args().nth(1).as_deref() == Some("memory") {
+        let args: Vec<String> = std::env::args().skip(2).collect();
+        let (tx, rx) = mpsc::channel(1);
+        let mut reactor = Reactor::new(rx, tx, nexus::kernel::reactor::ReactorConfig::default());
+        match args.first().map(String::as_str) {
+            Some("export") => {
+                let out = args.get(1).cloned().unwrap_or_else(|| "nexus_memory.json".to_string());
+                let bundle = reactor.export_memory().map_err(|e| format!("cannot read memory: {:?}", e))?;
+                std::fs::write(&out, bundle.to_json().map_err(|e| format!("{:?}", e))?)?;
+                println!("Wrote {} ({} semantic entries)", out, bundle.semantic.len());
+            }
+            Some("import") if args.len() >= 2 => {
+                let policy = if args.iter().any(|a| a == "--prefer-imported") {
+                    nexus::memory::bundle::ConflictPolicy::PreferImported
+                } else {
+                    nexus::memory::bundle::ConflictPolicy::KeepExisting
+                };
+                let bundle = nexus::memory::bundle::MemoryBundle::from_json(&std::fs::read_to_string(&args[1])?)
+                    .map_err(|e| format!("cannot read bundle {}: {:?}", args[1], e))?;
+                let report = reactor.import_memory(&bundle, policy).map_err(|e| format!("import failed: {:?}", e))?;
+                println!(
+                    "Imported {} entries ({} under new ids), {} already present, {} conflicts ({} chains kept out)",
+                    report.added, report.remapped, report.duplicates, report.conflicts, report.skipped
+                );
+                // Consent lives in kernel state, which this one-shot command does not keep
+                if !bundle.consent.is_empty() || !bundle.topic_consent.is_empty() {
+                    println!("Consent decisions are applied when a running kernel imports the bundle (Reactor::import_memory)");
+                }
+            }
+            _ => {
+                eprintln!("usage: nexus memory export [OUT.json] | nexus memory import FILE [--prefer-imported]");
+                std::process::exit(2);
+            }
+        }
+        return Ok(());
+    }
+
+    // `nexus maintenance [JOB...]`: run jobs in maintenance mode (default: StoreMigration StoreCompaction)
+    if std::env::args().nth(1).as_deref() == Some("maintenance") {
+        let mut jobs = Vec::new();
+        for arg in std::env::args().skip(2) {
+            match serde_json::from_value::<nexus::kernel::maintenance::MaintenanceJob>(serde_json::Value::String(arg.clone())) {
+                Ok(job) => jobs.push(job),
+                Err(_) => {
+                    eprintln!("usage: nexus maintenance [JOB...] (unknown job {})", arg);
+                    std::process::exit(2);
+                }
+            }
+        }
+        if jobs.is_empty() {
+            jobs = nexus::kernel::maintenance::MaintenanceJob::MIGRATION.to_vec();
+        }
+        let (tx, rx) = mpsc::channel(1);
+        let mut reactor = Reactor::new(rx, tx, nexus::kernel::reactor::ReactorConfig::default());
+        // Same path as the drivers: a control event, then one job at a time
+        let mut events = vec![Event::Input(nexus::kernel::event::InputEvent {
+            source: "CLI".to_string(),
+            content: nexus::kernel::event::InputContent::EnterMaintenance { jobs },
+            captured_at: None,
+        })];
+        loop {
+            for effect in reactor.tick_step(std::mem::take(&mut events)) {
+                match effect {
+                    SideEffect::MaintenanceProgress(progress) => {
+                        if let Some(result) = progress.last {
+                            println!("[{}/{}] {:?}: {:?} ({} items)", progress.completed, progress.total, result.job, result.outcome, result.items);
+                        }
+                    }
+                    // This loop is the driver: run the job here and answer next tick
+                    SideEffect::RunMaintenance(task) => events.push(Event::Input(nexus::kernel::event::InputEvent {
+                        source: "CLI".to_string(),
+                        content: nexus::kernel::event::InputContent::MaintenanceResult(task.run()),
+                        captured_at: None,
+                    })),
+                    _ => {}
+                }
+            }
+            if reactor.mode != nexus::kernel::reactor::KernelMode::Maintenance {
+                break;
+            }
+        }
+        let failed = reactor.maintenance.last_summary()
+            .is_some_and(|summary| summary.jobs.iter().any(|r| r.outcome == nexus::kernel::maintenance::JobOutcome::Failed));
+        std::process::exit(if failed { 1 } else { 0 });
+    }
+
+    // `nexus bundle-report [OUT.zip] [--yes]`: bug report bundle, written only after confirmation
+    if std::env::args().nth(1).as_deref() == Some("bundle-report") {
+        let args: Vec<String> = std::env::args().skip(2).collect();
+        let assume_yes = args.iter().any(|a| a == "--yes");
+        let out = args.iter().find(|a| !a.starts_with("--")).cloned().unwrap_or_else(|| "nexus_report.zip".to_string());
+
+        let (tx, rx) = mpsc::channel(1);
+        let mut reactor = Reactor::new(rx, tx, nexus::kernel::reactor::ReactorConfig::default());
+        // Offline: the last session's telemetry comes from the local spool (if any)
+        if let Ok(spooled) = nexus::kernel::telemetry::recorder::TelemetryRecorder::load_spool(&telemetry_spool_path()) {
+            // Merge rather than replace: the reactor's own handles stay connected
+            for event in spooled.events() {
+                reactor.telemetry.record(event.clone());
+            }
+        }
+        let bundle = reactor.report_bundle();
+
+        println!("The report bundle will contain:");
+        for entry in bundle.manifest()? {
+            println!("  {:<15} {:>7} bytes  {}", entry.name, entry.bytes, entry.description);
+        }
+        let confirmed = assume_yes || {
+            print!("Write {}? [y/N] ", out);
+            std::io::Write::flush(&mut std::io::stdout())?;
+            let mut answer = String::new();
+            std::io::stdin().read_line(&mut answer)?;
+            matches!(answer.trim(), "y" | "Y" | "yes")
+        };
+        if !confirmed {
+            println!("Nothing written.");
+            return Ok(());
+        }
+        bundle.write_zip(std::path::Path::new(&out), true)?;
+        println!("Wrote {}", out);
+        return Ok(());
+    }
+
+    tracing::info!("Nexus Kernel Booting...");
 
-    // Driver Internal Channel
-    let (driver_tx, mut driver_rx) = mpsc::channel(100);
+    // Kernel Channel (NEXUS_EVENT_QUEUE / NEXUS_DRIVER_QUEUE: capacities for heavy streams)
+    let queues = nexus::kernel::queue::QueueConfig::from_env();
+    let (tx, rx) = queues.event_channel();
 
     // Setup Reactor
-    let config = nexus::kernel::reactor::ReactorConfig { safe_mode: false };
+    let caption_only = std::env::var("NEXUS_CAPTION_ONLY").is_ok();
+    let id_seed = std::env::var("NEXUS_ID_SEED").ok().and_then(|s| s.parse().ok());
+    // Exchange summaries from the local LLM (template line when it does not answer)
+    let exchange_summaries = nexus::memory::summary::SummaryMode::Llm;
+    // e.g. NEXUS_QUIET_HOURS=22:00-08:00
+    let quiet_hours = std::env::var("NEXUS_QUIET_HOURS").ok().and_then(|s| nexus::kernel::quiet::QuietHours::parse(&s));
+    // NEXUS_CONVERSATION_MODE=half: never listen while speaking
+    let conversation_mode = nexus::kernel::presence::ConversationMode::from_env();
+    // NEXUS_OUTPUT_FILTER=off|redact|regenerate|silence (LLM text safety filter)
+    let output_filter = nexus::outputs::safety::SafetyConfig::from_env();
+    let tool_sandbox = nexus::planner::sandbox::SandboxConfig::from_env();
+    // NEXUS_BACKCHANNEL=1: "mm-hm" in the pauses of a long turn
+    let backchannel = std::env::var("NEXUS_BACKCHANNEL").is_ok_and(|v| v != "0");
+    // NEXUS_CHUNKED_SPEECH=1: speak replies a sentence at a time (an interruption drops only the rest)
+    let chunked_speech = std::env::var("NEXUS_CHUNKED_SPEECH").is_ok_and(|v| v != "0");
+    // NEXUS_MAINTENANCE_WINDOW=02:00-05:00 and/or NEXUS_MAINTENANCE_DORMANT=1: nightly jobs
+    let maintenance = nexus::kernel::maintenance::MaintenanceConfig::from_env();
+    // NEXUS_TTS=say|sapi|espeak|piper (default per platform)
+    let tts = nexus::outputs::tts::TtsEngineKind::from_env();
+    // nexus_vad.toml (or NEXUS_VAD_CONFIG): core VAD thresholds and timings
+    let vad = nexus::kernel::audio::vad::VadConfig::from_default_path();
+    // NEXUS_TELEMETRY_EXPORT=file,otlp (+ NEXUS_TELEMETRY_EXPORT_SECS): periodic snapshots; OTLP to OTEL_EXPORTER_OTLP_ENDPOINT
+    let telemetry_export = nexus::kernel::telemetry::exporter::ExportConfig::from_env();
+    let config = nexus::kernel::reactor::ReactorConfig { safe_mode: false, caption_only, id_seed, exchange_summaries, quiet_hours, conversation_mode, output_filter, tool_sandbox, backchannel, maintenance, tts, vad, chunked_speech, telemetry_export, ..Default::default() };
     let mut reactor = Reactor::new(rx, tx.clone(), config);
+    reactor.maintenance.telemetry_spool = Some(telemetry_spool_path());
+    reactor.exporter.file = Some(telemetry_export_path());
+    if let Ok(endpoint) = std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT") {
+        reactor.exporter.otlp_endpoint = endpoint;
+    }
+    println!("[MAIN] Id seed: {} (NEXUS_ID_SEED to replay)", reactor.ids.seed());
+    // NEXUS_RECORD_SESSION=session.json: record what the kernel is fed (see kernel::replay). Contains what was said.
+    let mut session = std::env::var("NEXUS_RECORD_SESSION").ok()
+        .map(|path| (std::path::PathBuf::from(path), nexus::kernel::replay::SessionLog::new(reactor.ids.seed())));
+    // NEXUS_JOURNAL=session.jsonl: append every step as it runs (see kernel::journal). Contains what was said.
+    let mut journal = nexus::kernel::journal::JournalWriter::from_env(reactor.ids.seed());
+    if state_path().exists() {
+        match nexus::kernel::persist::PersistedState::load(&state_path()) {
+            Ok(persisted) => {
+                // Journaled first: a replay must start from the same state
+                if let Some(journal) = journal.as_mut() {
+                    journal.hydrated(&persisted);
+                }
+                let hydrated = reactor.hydrate(&persisted);
+                println!("[MAIN] Resumed {} memories and {} intents", hydrated.memories, hydrated.intents);
+            }
+            Err(e) => tracing::warn!("Starting without persisted state: {:?}", e),
+        }
+    }
 
     // Initialize Services
     let llm_service = nexus::services::llm::client::LLMService::new();
-    
-    // Driver State
-    let mut speech_tasks: HashMap<Uuid, JoinHandle<()>> = HashMap::new();
-    let mut speech_dedupe: HashMap<Uuid, Instant> = HashMap::new();
-    let mut audio_child: Option<tokio::sync::oneshot::Sender<()>> = None;
 
-    // Clone tx for audio status reporting check
-    let status_tx = tx.clone();
+    // Microphone (VAD signals -> Kernel), restarted if the actor is lost. Keep the controller alive for the process lifetime.
+    #[cfg(feature = "vad")]
+    let _capture = {
+        let capture = nexus::audio::capture::CaptureActor::spawn(
+            nexus::audio::capture::CaptureConfig::from_env(),
+            tx.clone(),
+            reactor.capture_clock(),
+        );
+        capture.start();
+        std::sync::Arc::new(capture)
+    };
+    // NEXUS_BRIDGE=1: WebSocket bridge for web dashboards and remote UIs (NEXUS_BRIDGE_BIND, NEXUS_BRIDGE_TOKEN, NEXUS_BRIDGE_ORIGINS)
+    #[cfg(feature = "bridge")]
+    if std::env::var("NEXUS_BRIDGE").as_deref() == Ok("1") {
+        let config = nexus::kernel::bridge::BridgeConfig::from_env();
+        if std::env::var("NEXUS_BRIDGE_TOKEN").is_err() {
+            // Generated for this run: the user needs it to connect
+            println!("Bridge token: {}", config.token.as_deref().unwrap_or_default());
+        }
+        let bridge = nexus::kernel::bridge::Bridge::attach(&mut reactor, tx.clone(), config);
+        #[cfg(feature = "vad")]
+        let bridge = bridge.with_mic(_capture.clone());
+        tokio::spawn(async move {
+            if let Err(e) = nexus::kernel::bridge::server::serve(bridge).await {
+                tracing::warn!("[Bridge] Stopped: {}", e);
+            }
+        });
+    }
     
-    let mut cadence = tokio::time::interval(Duration::from_millis(100));
-    cadence.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    // Side effects: playback, generated speech, transcription, tools
+    let mut executor = nexus::kernel::driver::EffectExecutor::new(&reactor, queues.driver_capacity).with_llm(llm_service);
+    if !caption_only {
+        // Synthesized audio for repeated phrases (pre-warmed with the canned ones)
+        let canned = nexus::outputs::tts_cache::canned_phrases_in(&reactor.templates);
+        tokio::spawn(nexus::outputs::tts_cache::prewarm(executor.tts_cache(), canned));
+    }
+    // Local clock for quiet hours (checked every 30s, reported on change)
+    let mut clock_checked: Option<Instant> = None;
+    let mut last_minute: Option<u16> = None;
+    // Output route (NEXUS_OUTPUT_DEVICE fallback chain), re-resolved as devices come and go
+    let mut router = nexus::outputs::device::OutputRouter::new(nexus::outputs::device::OutputDeviceConfig::from_env());
+    let mut route_checked: Option<Instant> = None;
+    // NEXUS_LANGUAGE=de: transcribe and answer in German (default: the language heard), sent with the first step
+    let mut language = Some(nexus::kernel::language::LanguagePreference::from_env());
+
+    // 100ms steps while active; Dormant and idle: blocked on the channel (NEXUS_LOW_POWER)
+    let mut cadence = nexus::kernel::cadence::DriverCadence::new(Duration::from_millis(100), nexus::kernel::cadence::LowPowerConfig::from_env());
 
     tracing::info!("Nexus Kernel Active. Press Ctrl+C to stop.");
 
+    // One listener for the whole session (a fresh one per tick could miss the signal)
+    let shutdown = tokio::signal::ctrl_c();
+    tokio::pin!(shutdown);
+
     loop {
-         cadence.tick().await;
+         let wake = tokio::select! {
+             wake = cadence.wait(&mut reactor.receiver) => wake,
+             _ = &mut shutdown => break,
+         };
+         if let Some(journal) = journal.as_mut() {
+             journal.idle(reactor.tick, wake.skipped);
+         }
+         reactor.advance_idle(wake.skipped);
 
-         // 1. Drain Kernel Events
-         let mut events = Vec::new();
+         // 1. Drain Kernel Events (after the one that ended a low-power wait)
+         let mut events: Vec<Event> = wake.event.into_iter().collect();
          while let Ok(event) = reactor.receiver.try_recv() {
              events.push(event);
          }
+         if clock_checked.is_none_or(|t| t.elapsed() >= Duration::from_secs(30)) {
+             clock_checked = Some(Instant::now());
+             let minute = local_minute_of_day();
+             if last_minute != Some(minute) {
+                 last_minute = Some(minute);
+                 events.push(Event::Input(nexus::kernel::event::InputEvent {
+                     source: "Driver".to_string(),
+                     content: nexus::kernel::event::InputContent::LocalTime { minute_of_day: minute },
+                     captured_at: None,
+                 }));
+             }
+         }
 
-         // 2. Drain Driver Events (Async Results)
-         while let Ok(evt) = driver_rx.try_recv() {
-             match evt {
-                 DriverEvent::GeneratedSpeech { output_id, text } => {
-                     // Check if task was cancelled/removed?
-                     if speech_tasks.contains_key(&output_id) {
-                         speech_tasks.remove(&output_id); // Completed
-                         
-                         // Telemetry: Generated
-                         let _ = status_tx.send(Event::Telemetry(
-                             nexus::kernel::telemetry::event::TelemetryEvent::SpeechLifecycle(
-                                 nexus::kernel::telemetry::event::SpeechLifecycleEvent::Generated
-                             )
-                         )).await;
-
-                         // PLAY AUDIO (The "Harness" Logic)
-                         println!("[AUDIO-{:?}] Spawning 'say': '{}'", output_id, text);
-                         if let Some(stop_tx) = audio_child.take() {
-                             let _ = stop_tx.send(());
-                         }
-                         
-                         match tokio::process::Command::new("say")
-                             .arg(&text)
-                             .kill_on_drop(true)
-                             .spawn() 
-                         {
-                             Ok(mut child) => {
-                                 let tx_clone = status_tx.clone();
-                                 let (stop_tx, mut stop_rx) = tokio::sync::oneshot::channel();
-                                 audio_child = Some(stop_tx);
-                                 
-                                 tokio::spawn(async move {
-                                     let _ = tx_clone.send(Event::Input(nexus::kernel::event::InputEvent {
-                                         source: "Driver".to_string(),
-                                         content: nexus::kernel::event::InputContent::AudioStatus(
-                                             nexus::kernel::event::AudioStatus::PlaybackStarted
-                                         )
-                                     })).await;
-
-                                     tokio::select! {
-                                         _ = child.wait() => {}
-                                         _ = &mut stop_rx => { let _ = child.kill().await; }
-                                     }
-                                     
-                                     let _ = tx_clone.send(Event::Input(nexus::kernel::event::InputEvent {
-                                         source: "Driver".to_string(),
-                                         content: nexus::kernel::event::InputContent::AudioStatus(
-                                             nexus::kernel::event::AudioStatus::PlaybackEnded
-                                         )
-                                     })).await;
-                                 });
-                             }
-                             Err(e) => tracing::warn!("Failed to spawn 'say': {}", e),
-                         }
-                     }
-                 },
-                 DriverEvent::SpeechFailed { output_id } => {
-                     speech_tasks.remove(&output_id);
-                     let _ = status_tx.send(Event::Telemetry(
-                         nexus::kernel::telemetry::event::TelemetryEvent::SpeechLifecycle(
-                             nexus::kernel::telemetry::event::SpeechLifecycleEvent::Failed
-                         )
-                     )).await;
-                 }
+         if route_checked.is_none_or(|t| t.elapsed() >= Duration::from_secs(nexus::outputs::device::ROUTE_POLL_SECS)) {
+             let first = route_checked.is_none();
+             route_checked = Some(Instant::now());
+             if router.refresh(&nexus::outputs::device::output_devices()) || first {
+                 events.push(Event::Input(nexus::kernel::event::InputEvent {
+                     source: "Driver".to_string(),
+                     content: nexus::kernel::event::InputContent::OutputDevice { device: router.current().map(str::to_string) },
+                     captured_at: None,
+                 }));
              }
          }
 
-         // 3. Kernel Step
+         if let Some(preference) = language.take() {
+             events.push(Event::Input(nexus::kernel::event::InputEvent {
+                 source: "Driver".to_string(),
+                 content: nexus::kernel::event::InputContent::Language(preference),
+                 captured_at: None,
+             }));
+         }
+
+         // 2. Kernel Step
+         if let Some((_, log)) = session.as_mut() {
+             log.record(reactor.tick.next(), &events);
+         }
+         if let Some(journal) = journal.as_mut() {
+             journal.step(reactor.tick.next(), &events);
+         }
          let effects = reactor.tick_step(events);
 
-         // 4. Handle Side Effects
+         // 3. Handle Side Effects, then play generated sentences
+         let context = nexus::kernel::driver::EffectContext::capture(&reactor, &effects);
          for effect in effects {
-             match effect {
-                 SideEffect::Log(msg) => println!("[LOG] {}", msg),
-                 
-                 SideEffect::SpawnAudio(_output_id_legacy, text) => {
-                     // Legacy Harness (direct spawn)
-                     // Re-use logic or duplicate? Duplicate for minimal friction now.
-                     // Mock ID for tracking audio handle
-                     println!("[AUDIO-LEGACY] Spawning 'say': '{}'", text);
-                     if let Some(stop_tx) = audio_child.take() { let _ = stop_tx.send(()); }
-                     
-                     match tokio::process::Command::new("say").arg(&text).kill_on_drop(true).spawn() {
-                         Ok(mut child) => {
-                             let tx_clone = status_tx.clone();
-                             let (stop_tx, mut stop_rx) = tokio::sync::oneshot::channel();
-                             audio_child = Some(stop_tx);
-                             tokio::spawn(async move {
-                                 let _ = tx_clone.send(Event::Input(nexus::kernel::event::InputEvent {
-                                     source: "Driver".to_string(),
-                                     content: nexus::kernel::event::InputContent::AudioStatus(nexus::kernel::event::AudioStatus::PlaybackStarted)
-                                 })).await;
-                                 tokio::select! { _ = child.wait() => {}, _ = &mut stop_rx => { let _ = child.kill().await; } }
-                             });
-                         }
-                         Err(_) => {}
-                     }
-                 },
-                 
-                 SideEffect::StopAudio => {
-                     if let Some(stop_tx) = audio_child.take() { let _ = stop_tx.send(()); }
-                     for (_, task) in speech_tasks.drain() { task.abort(); }
-                     let _ = status_tx.send(Event::Telemetry(
-                         nexus::kernel::telemetry::event::TelemetryEvent::SpeechLifecycle(
-                             nexus::kernel::telemetry::event::SpeechLifecycleEvent::Aborted
-                         )
-                     )).await;
-                 },
-
-                 SideEffect::RequestSpeech { intent, output_id } => {
-                     // Dedupe
-                     if let Some(inst) = speech_dedupe.get(&output_id) {
-                         if inst.elapsed() < Duration::from_secs(10) { continue; }
-                     }
-                     speech_dedupe.insert(output_id, Instant::now());
-                     
-                     // Telemetry: Requested
-                     let _ = status_tx.send(Event::Telemetry(
-                         nexus::kernel::telemetry::event::TelemetryEvent::SpeechLifecycle(
-                             nexus::kernel::telemetry::event::SpeechLifecycleEvent::Requested
-                         )
-                     )).await;
-
-                     // Spawn Task
-                     let service = llm_service.clone();
-                     let dr_tx = driver_tx.clone();
-                     let oid = output_id;
-                     
-                     let task = tokio::spawn(async move {
-                         // Hard Timeout 2s
-                         let result = tokio::time::timeout(Duration::from_secs(2), service.generate_speech(intent)).await;
-                         
-                         match result {
-                             Ok(Ok(text)) => {
-                                 let _ = dr_tx.send(DriverEvent::GeneratedSpeech { output_id: oid, text }).await;
-                             },
-                             Ok(Err(e)) => {
-                                 tracing::warn!("LLM Error: {}", e);
-                                 let _ = dr_tx.send(DriverEvent::SpeechFailed { output_id: oid }).await;
-                             },
-                             Err(_) => { // Timeout
-                                 tracing::warn!("LLM Timeout");
-                                 let _ = dr_tx.send(DriverEvent::SpeechFailed { output_id: oid }).await;
-                             }
-                         }
-                     });
-                     
-                     speech_tasks.insert(output_id, task);
-                 },
-                 
-                 _ => {}
-             }
+             executor.execute(effect, &context);
+         }
+         executor.poll(&context);
+         executor.pump();
+
+         if let Some(mode) = cadence.follow(reactor.state.presence(), executor.is_idle()) {
+             tracing::info!("Cadence -> {:?}", mode);
          }
-         
-         // Cleanup Dedupe (TTL)
-         speech_dedupe.retain(|_, time| time.elapsed() < Duration::from_secs(10));
     }
+
+    if let Some(journal) = journal {
+        tracing::info!("Session journaled to {} (tick {})", journal.path().display(), reactor.tick.frame);
+        journal.end(reactor.tick);
+    }
+
+    // Graceful shutdown: flush stores and checkpoint, then stop what is still running.
+    // Raw telemetry is spooled locally; `nexus telemetry preview` shows what would leave the machine
+    let (report, effects) = reactor.shutdown(&nexus::kernel::shutdown::ShutdownOptions {
+        checkpoint: Some(checkpoint_path()),
+        telemetry_spool: Some(telemetry_spool_path()),
+        state: Some(state_path()),
+    });
+    let context = nexus::kernel::driver::EffectContext::capture(&reactor, &effects);
+    for effect in effects {
+        executor.execute(effect, &context);
+    }
+    for flush in &report.flushed {
+        match &flush.error {
+            None => tracing::info!("Flushed {}", flush.store),
+            Some(e) => tracing::warn!("Failed to flush {}: {}", flush.store, e),
+        }
+    }
+    if let Some((path, log)) = session {
+        match log.save(&path) {
+            Ok(()) => tracing::info!("Session recorded to {} ({} steps)", path.display(), log.steps.len()),
+            Err(e) => tracing::warn!("Failed to record session: {}", e),
+        }
+    }
+    tracing::info!("Shutdown at tick {}: {} pending effects and {} events dropped", report.tick.frame, report.pending_effects_dropped, report.events_dropped);
+    let queue = reactor.queue.stats();
+    tracing::info!("Event queue: high-water {}/{}, {} dropped when full", queue.high_water, queue.capacity, queue.dropped);
+    Ok(())
 }
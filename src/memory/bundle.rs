@@ -0,0 +1,227 @@
+//! Portable Memory Bundle ("nexus memory export" / "nexus memory import").
+//!
+//! A versioned JSON document with everything Nexus knows about the user that outlives a
+//! session: every version of every semantic chain (oldest first) and the resolved consent
+//! decisions, per memory key and per topic. Pending prompts are session state and are left out.
+//!
+//! Import merges into an existing store instead of replacing it:
+//! - An entry already present (same id, same content) is skipped, so re-importing is a no-op.
+//! - An id that is taken by different content gets a fresh id; links inside the bundle follow it.
+//! - A chain whose root states something else about an existing (subject + predicate) is a
+//!   conflict, settled by `ConflictPolicy`. Preferring the import appends it as a new version
+//!   of the existing chain, so the old value stays in `history`.
+//! - A declined consent is never turned into a grant by an import.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::ids::IdGenerator;
+use crate::kernel::memory::consent::MemoryConsentState;
+use crate::kernel::memory::topic::TopicId;
+use crate::kernel::memory::types::MemoryKey;
+use crate::kernel::state::{SharedState, StateDelta};
+use crate::kernel::time::Tick;
+use crate::memory::store::{MemoryError, SemanticStore};
+use crate::memory::types::SemanticMemoryEntry;
+
+// Config Constants
+pub const BUNDLE_FORMAT: &str = "nexus-memory";
+/// Bumped on any change an older reader would misread. Readers accept this version and older.
+pub const BUNDLE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyConsent {
+    pub key: MemoryKey,
+    pub state: MemoryConsentState,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopicConsent {
+    pub topic_id: TopicId,
+    pub state: MemoryConsentState,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryBundle {
+    pub format: String,
+    pub version: u32,
+    /// Every stored version, each chain oldest first
+    pub semantic: Vec<SemanticMemoryEntry>,
+    #[serde(default)]
+    pub consent: Vec<KeyConsent>,
+    #[serde(default)]
+    pub topic_consent: Vec<TopicConsent>,
+}
+
+/// Which side wins when the bundle and the store disagree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictPolicy {
+    /// The store is left as it is; the conflicting part of the bundle is dropped
+    #[default]
+    KeepExisting,
+    /// The bundle's value becomes the latest version (consent: except over a decline)
+    PreferImported,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    pub added: usize,
+    /// Already in the store
+    pub duplicates: usize,
+    /// Added under a fresh id
+    pub remapped: usize,
+    pub conflicts: usize,
+    /// Dropped by `ConflictPolicy::KeepExisting` (whole chains)
+    pub skipped: usize,
+    pub consent_applied: usize,
+    pub consent_kept: usize,
+}
+
+impl MemoryBundle {
+    /// Snapshot of the semantic store and the resolved consent in `state`.
+    pub fn collect(semantic: &dyn SemanticStore, state: &SharedState) -> Result<Self, MemoryError> {
+        let entries = semantic.entries()?;
+        let mut chains = Vec::new();
+        let mut seen = HashSet::new();
+        for entry in &entries {
+            if seen.contains(&entry.id) {
+                continue;
+            }
+            for version in semantic.history(&entry.id)? {
+                if seen.insert(version.id.clone()) {
+                    chains.push(version);
+                }
+            }
+        }
+
+        let mut consent: Vec<KeyConsent> = state.memory_consent().values()
+            .filter(|c| c.resolved_at.is_some())
+            .map(|c| KeyConsent { key: c.memory_key.clone(), state: c.state })
+            .collect();
+        consent.sort_by_key(|c| (c.key.symbol_fingerprint, format!("{:?}", c.key.hypothesis)));
+        let mut topic_consent: Vec<TopicConsent> = state.topic_consent().iter()
+            .map(|(topic_id, state)| TopicConsent { topic_id: topic_id.clone(), state: *state })
+            .collect();
+        topic_consent.sort_by(|a, b| a.topic_id.cmp(&b.topic_id));
+
+        Ok(Self { format: BUNDLE_FORMAT.to_string(), version: BUNDLE_VERSION, semantic: chains, consent, topic_consent })
+    }
+
+    pub fn to_json(&self) -> Result<String, MemoryError> {
+        serde_json::to_string_pretty(self).map_err(|e| MemoryError::SerializationError(e.to_string()))
+    }
+
+    /// Parses and checks the header. Bundles from a newer version are refused, not guessed at.
+    pub fn from_json(json: &str) -> Result<Self, MemoryError> {
+        let bundle: Self = serde_json::from_str(json).map_err(|e| MemoryError::SerializationError(e.to_string()))?;
+        if bundle.format != BUNDLE_FORMAT {
+            return Err(MemoryError::SerializationError(format!("not a memory bundle: format {:?}", bundle.format)));
+        }
+        if bundle.version > BUNDLE_VERSION {
+            return Err(MemoryError::SerializationError(format!(
+                "bundle version {} is newer than this build ({})", bundle.version, BUNDLE_VERSION
+            )));
+        }
+        Ok(bundle)
+    }
+
+    /// Merges the semantic chains into `semantic`. Consent is returned as deltas for the caller to reduce.
+    /// Remapped entries take their new id from `ids`, so a replayed import lands on the same ids.
+    pub fn import(&self, semantic: &mut dyn SemanticStore, state: &SharedState, policy: ConflictPolicy, now: Tick, ids: &mut IdGenerator)
+        -> Result<(ImportReport, Vec<StateDelta>), MemoryError>
+    {
+        let mut report = ImportReport::default();
+        let existing = semantic.entries()?;
+        let mut by_id: HashMap<String, SemanticMemoryEntry> = existing.iter().map(|e| (e.id.clone(), e.clone())).collect();
+        let superseded: HashSet<String> = existing.iter().filter_map(|e| e.previous_version_id.clone()).collect();
+        let mut heads: HashMap<u64, SemanticMemoryEntry> = existing.into_iter()
+            .filter(|e| !superseded.contains(&e.id))
+            .map(|e| (e.claim.key_hash(), e))
+            .collect();
+
+        // Bundle id -> id in the store (None: the chain was dropped)
+        let mut store_ids: HashMap<String, Option<String>> = HashMap::new();
+        for entry in &self.semantic {
+            if by_id.get(&entry.id) == Some(entry) {
+                report.duplicates += 1;
+                store_ids.insert(entry.id.clone(), Some(entry.id.clone()));
+                continue;
+            }
+            let mut next = entry.clone();
+            match entry.previous_version_id.as_ref().and_then(|p| store_ids.get(p)) {
+                Some(None) => {
+                    store_ids.insert(entry.id.clone(), None);
+                    continue;
+                }
+                Some(Some(previous)) => next.previous_version_id = Some(previous.clone()),
+                // Chain root (or its predecessor was compacted away before export)
+                None => {
+                    next.previous_version_id = None;
+                    if let Some(head) = heads.get(&entry.claim.key_hash()) {
+                        if head.claim == entry.claim {
+                            report.duplicates += 1;
+                            store_ids.insert(entry.id.clone(), Some(head.id.clone()));
+                            continue;
+                        }
+                        report.conflicts += 1;
+                        match policy {
+                            ConflictPolicy::KeepExisting => {
+                                report.skipped += 1;
+                                store_ids.insert(entry.id.clone(), None);
+                                continue;
+                            }
+                            ConflictPolicy::PreferImported => {
+                                next.previous_version_id = Some(head.id.clone());
+                                next.version = head.version + 1;
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(previous) = next.previous_version_id.as_ref().and_then(|p| by_id.get(p)) {
+                next.version = next.version.max(previous.version + 1);
+            }
+            if by_id.contains_key(&next.id) {
+                next.id = ids.next_uuid().to_string();
+                report.remapped += 1;
+            }
+            store_ids.insert(entry.id.clone(), Some(next.id.clone()));
+            by_id.insert(next.id.clone(), next.clone());
+            heads.insert(next.claim.key_hash(), next.clone());
+            semantic.insert(next)?;
+            report.added += 1;
+        }
+
+        let mut deltas = Vec::new();
+        for consent in &self.consent {
+            let current = state.memory_consent().get(&consent.key).filter(|c| c.resolved_at.is_some()).map(|c| c.state);
+            if keeps(current, consent.state, policy) {
+                report.consent_kept += 1;
+            } else {
+                report.consent_applied += 1;
+                deltas.push(StateDelta::MemoryConsentResolved { key: consent.key.clone(), state: consent.state, resolved_at: now });
+            }
+        }
+        for consent in &self.topic_consent {
+            let current = state.topic_consent().get(&consent.topic_id).copied();
+            if keeps(current, consent.state, policy) {
+                report.consent_kept += 1;
+            } else {
+                report.consent_applied += 1;
+                deltas.push(StateDelta::TopicConsentResolved { topic_id: consent.topic_id.clone(), state: consent.state });
+            }
+        }
+        Ok((report, deltas))
+    }
+}
+
+// Does the current decision stand against an imported one?
+fn keeps(current: Option<MemoryConsentState>, imported: MemoryConsentState, policy: ConflictPolicy) -> bool {
+    match current {
+        None => false,
+        Some(current) if current == imported => true,
+        Some(MemoryConsentState::Declined) => true,
+        Some(_) => policy == ConflictPolicy::KeepExisting,
+    }
+}
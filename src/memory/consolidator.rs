@@ -88,6 +88,10 @@ impl MemoryConsolidator {
                     created_at_tick: current_tick,
                     last_reinforced_tick: current_tick,
                     decay_rate: 0.01, // Default decay
+                    created_at_ms: std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .ok()
+                        .map(|d| d.as_millis() as u64),
                 };
                 store.insert(entry);
                 promoted_keys.push(*key);
@@ -108,59 +112,39 @@ impl MemoryConsolidator {
     }
 
     fn run_semantic_promotion<E: EpisodicStore, S: SemanticStore>(&mut self, episodic: &mut E, semantic: &mut S, current_tick: u64) {
-        // Scan Episodic Memory for high-confidence, stable facts
-        // This is "Sleep Consolidation"
-        
-        let _candidates = episodic.retrieve(0); // We need "all" or iterator.
-        // The trait currently has `all()`.
-        
-        // We can't borrow mutable episodic while iterating?
-        // Actually `all()` returns Vec references. 
-        // We'll collect candidates first.
-        
-        let all_episodic = episodic.all();
-        let mut to_promote = Vec::new();
+        let _ = promote_semantic(&*episodic, &mut *semantic, current_tick);
+    }
+}
 
-        for entry in all_episodic {
-            // Strict Rules for Semantic
-            // 1. High Confidence
-            // 2. Modality is Asserted (Text) or specific types
-            // 3. Repeated reinforcement? (Implicit in high confidence due to episodic reinforcement?)
-            
-            if entry.confidence > 0.9 && matches!(entry.claim.modality, Modality::Asserted) {
-                // Check if already exists in Semantic
-                // Note: key_hash() only checks Subject+Predicate
-                let key = entry.claim.key_hash();
-                
-                // We need to check if we should OVERWRITE/VERSION.
-                // Retrieve existing
-                 let existing = semantic.retrieve(key).unwrap_or_default();
-                 // If any existing matches Subject+Predicate...
-                 
-                 let duplicate = existing.iter().any(|e| e.claim.object == entry.claim.object);
-                 
-                 if !duplicate {
-                     to_promote.push(entry.clone());
-                 } else {
-                     // Reinforce existing? (Not implemented yet)
-                 }
+/// "Sleep Consolidation": Episodic -> Semantic. High-confidence asserted claims not already
+/// known (same Subject+Predicate and Object) become semantic entries. Returns how many.
+pub fn promote_semantic(episodic: &dyn EpisodicStore, semantic: &mut dyn SemanticStore, current_tick: u64) -> Result<usize, crate::memory::store::MemoryError> {
+    let mut to_promote = Vec::new();
+    for entry in episodic.all() {
+        // Strict Rules for Semantic: High Confidence, Asserted (Text)
+        if entry.confidence > 0.9 && matches!(entry.claim.modality, Modality::Asserted) {
+            // Note: key_hash() only checks Subject+Predicate
+            let existing = semantic.retrieve(entry.claim.key_hash())?;
+            let duplicate = existing.iter().any(|e| e.claim.object == entry.claim.object)
+                || to_promote.iter().any(|p: &EpisodicMemoryEntry| p.claim == entry.claim);
+            if !duplicate {
+                to_promote.push(entry.clone());
             }
         }
+    }
 
-        for p in to_promote {
-             // Create Semantic Entry
-             let sem_entry = SemanticMemoryEntry {
-                 id: uuid::Uuid::new_v4().to_string(),
-                 claim: p.claim.clone(),
-                 confidence: p.confidence,
-                 provenance: Provenance::System, // Promoted from System experience
-                 created_at_tick: current_tick,
-                 last_accessed_tick: current_tick,
-                 version: 1,
-                 previous_version_id: None,
-             };
-             
-             let _ = semantic.insert(sem_entry);
-        }
+    let promoted = to_promote.len();
+    for p in to_promote {
+        semantic.insert(SemanticMemoryEntry {
+            id: uuid::Uuid::new_v4().to_string(),
+            claim: p.claim,
+            confidence: p.confidence,
+            provenance: Provenance::System, // Promoted from System experience
+            created_at_tick: current_tick,
+            last_accessed_tick: current_tick,
+            version: 1,
+            previous_version_id: None,
+        })?;
     }
+    Ok(promoted)
 }
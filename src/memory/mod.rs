@@ -3,6 +3,8 @@ pub mod store;
 pub mod observer;
 pub mod consolidator;
 pub mod retriever;
+pub mod summary;
+pub mod bundle;
 
 pub use types::*;
 pub use store::*;
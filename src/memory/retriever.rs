@@ -1,18 +1,58 @@
 use crate::memory::types::Claim;
 use crate::memory::store::{EpisodicStore, SemanticStore};
+use serde::Serialize;
 use std::cmp::Ordering;
 
+/// Ranking policy for retrieval, shared by both stores.
+/// Configured centrally via `ReactorConfig::retrieval`.
+///
+/// score = (confidence_weight * confidence + recency_weight * recency) * relevance,
+/// times `superseded_penalty` when a newer hit contradicts this one.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct RankingPolicy {
+    pub confidence_weight: f32,
+    pub recency_weight: f32,
+    /// Ticks behind the newest hit at which the recency term halves.
+    pub recency_half_life_ticks: u64,
+    /// Multiplier for hits contradicted by a newer hit (same subject/predicate, different object).
+    pub superseded_penalty: f32,
+}
+
+impl Default for RankingPolicy {
+    fn default() -> Self {
+        Self {
+            confidence_weight: 0.5,
+            recency_weight: 0.5,
+            recency_half_life_ticks: 6000, // ~2 min @ 20ms
+            superseded_penalty: 0.5,
+        }
+    }
+}
+
+/// Why a hit ranked where it did.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct RankingRationale {
+    pub confidence_term: f32,
+    pub recency_term: f32,
+    /// A newer hit states something different about the same subject/predicate.
+    pub superseded: bool,
+    /// Number of hits that contradict this one (either direction).
+    pub conflicts: usize,
+}
+
 /// Evidence retrieved from memory for the Planner.
-#[derive(Debug, Clone, PartialEq)]
-pub struct MemoryEvidence {
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RetrievalHit {
     pub content: Claim,
     pub confidence: f32,
     pub source: RetrievalSource,
     pub relevance: f32,
     pub recency_tick: u64,
+    pub score: f32,
+    pub rationale: RankingRationale,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
 pub enum RetrievalSource {
     Episodic,
     Semantic,
@@ -23,44 +63,79 @@ pub enum RetrievalSource {
 pub struct MemoryRetriever;
 
 impl MemoryRetriever {
-    /// Retrieve evidence relevant to a query.
+    /// Retrieve evidence relevant to a query, ranked with the default policy.
     /// Query hash is currently (Subject + Predicate).
-    pub fn retrieve<E: EpisodicStore, S: SemanticStore>(
+    pub fn retrieve<E: EpisodicStore + ?Sized, S: SemanticStore + ?Sized>(
         query_hash: u64,
         episodic: &E,
         semantic: &S,
-    ) -> Vec<MemoryEvidence> {
+    ) -> Vec<RetrievalHit> {
+        Self::retrieve_with(query_hash, episodic, semantic, &RankingPolicy::default())
+    }
+
+    /// Retrieve evidence from both stores and rank it with `policy` (best first).
+    pub fn retrieve_with<E: EpisodicStore + ?Sized, S: SemanticStore + ?Sized>(
+        query_hash: u64,
+        episodic: &E,
+        semantic: &S,
+        policy: &RankingPolicy,
+    ) -> Vec<RetrievalHit> {
         let mut results = Vec::new();
 
         // 1. Check Episodic (Recent context)
         let episodic_hits = episodic.retrieve(query_hash);
         for entry in episodic_hits {
-             results.push(MemoryEvidence {
-                 content: entry.claim.clone(),
-                 confidence: entry.confidence,
-                 source: RetrievalSource::Episodic,
-                 relevance: 1.0, // Exact match on hash
-                 recency_tick: entry.last_reinforced_tick,
-             });
+             results.push(Self::hit(entry.claim.clone(), entry.confidence, RetrievalSource::Episodic, entry.last_reinforced_tick));
         }
 
-        // 2. Check Semantic (Long term facts)
+        // 2. Check Semantic (Long term facts, latest version per chain)
         if let Ok(semantic_hits) = semantic.retrieve(query_hash) {
              for entry in semantic_hits {
-                 results.push(MemoryEvidence {
-                     content: entry.claim.clone(),
-                     confidence: entry.confidence,
-                     source: RetrievalSource::Semantic,
-                     relevance: 1.0,
-                     recency_tick: entry.last_accessed_tick,
-                 });
+                 results.push(Self::hit(entry.claim.clone(), entry.confidence, RetrievalSource::Semantic, entry.last_accessed_tick));
              }
         }
 
-        // 3. Sort by Score (Confidence * Relevance * Decay?)
-        // For now, strict sort by Confidence.
-        results.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(Ordering::Equal));
-        
+        // 3. Rank: conflicts first (needs every hit), then score
+        Self::rank(&mut results, policy);
+        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+
         results
     }
+
+    fn hit(content: Claim, confidence: f32, source: RetrievalSource, recency_tick: u64) -> RetrievalHit {
+        RetrievalHit {
+            content,
+            confidence,
+            source,
+            relevance: 1.0, // Exact match on hash
+            recency_tick,
+            score: 0.0,
+            rationale: RankingRationale { confidence_term: 0.0, recency_term: 0.0, superseded: false, conflicts: 0 },
+        }
+    }
+
+    fn rank(hits: &mut [RetrievalHit], policy: &RankingPolicy) {
+        // Recency is relative to the newest hit, so it means the same for both stores
+        let newest = hits.iter().map(|h| h.recency_tick).max().unwrap_or(0);
+        let half_life = policy.recency_half_life_ticks.max(1) as f32;
+
+        for i in 0..hits.len() {
+            let contradicting = |other: &RetrievalHit| {
+                other.content.subject == hits[i].content.subject
+                    && other.content.predicate == hits[i].content.predicate
+                    && other.content.object != hits[i].content.object
+            };
+            let conflicts = hits.iter().filter(|o| contradicting(o)).count();
+            let superseded = hits.iter().any(|o| contradicting(o) && o.recency_tick > hits[i].recency_tick);
+
+            let age = newest.saturating_sub(hits[i].recency_tick);
+            let confidence_term = policy.confidence_weight * hits[i].confidence;
+            let recency_term = policy.recency_weight * crate::kernel::decay::exponential(1.0, age, half_life);
+            let penalty = if superseded { policy.superseded_penalty } else { 1.0 };
+
+            let hit = &mut hits[i];
+            hit.score = (confidence_term + recency_term) * hit.relevance * penalty;
+            hit.rationale = RankingRationale { confidence_term, recency_term, superseded, conflicts };
+        }
+    }
 }
@@ -1,6 +1,8 @@
-use crate::memory::types::{EpisodicMemoryEntry, SemanticMemoryEntry};
-use std::collections::{HashMap, VecDeque};
-use std::path::PathBuf;
+use crate::kernel::ids::IdGenerator;
+use crate::memory::types::{EntityId, EpisodicMemoryEntry, SemanticMemoryEntry};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
 use std::fs;
 
 // Generic error type for memory operations
@@ -17,21 +19,111 @@ impl From<std::io::Error> for MemoryError {
     }
 }
 
+/// Time bounds for episodic recall (inclusive on both ends).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TimeRange {
+    /// Kernel ticks (`created_at_tick`)
+    Ticks { from: u64, to: u64 },
+    /// Unix milliseconds (`created_at_ms`). Entries without a wall-clock stamp never match.
+    WallClock { from_ms: u64, to_ms: u64 },
+}
+
+/// "What did we talk about this morning": entries created inside `range`,
+/// optionally only those about `topic` (`EntityId::Topic`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EpisodicQuery {
+    pub range: TimeRange,
+    #[serde(default)]
+    pub topic: Option<String>,
+}
+
+impl EpisodicQuery {
+    pub fn matches(&self, entry: &EpisodicMemoryEntry) -> bool {
+        let in_range = match self.range {
+            TimeRange::Ticks { from, to } => (from..=to).contains(&entry.created_at_tick),
+            TimeRange::WallClock { from_ms, to_ms } => entry.created_at_ms.is_some_and(|t| (from_ms..=to_ms).contains(&t)),
+        };
+        let on_topic = match &self.topic {
+            Some(topic) => entry.claim.subject == EntityId::Topic(topic.clone()),
+            None => true,
+        };
+        in_range && on_topic
+    }
+}
+
 /// Trait for the Episodic Memory Store (Short-term/Session).
 pub trait EpisodicStore {
     fn insert(&mut self, entry: EpisodicMemoryEntry);
     fn retrieve(&self, query_hash: u64) -> Vec<&EpisodicMemoryEntry>;
     fn tick(&mut self, current_tick: u64); // Handles decay
     fn all(&self) -> Vec<&EpisodicMemoryEntry>;
+    fn len(&self) -> usize {
+        self.all().len()
+    }
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Memory pressure: drop oldest entries until at most `keep` remain.
+    fn prune_oldest(&mut self, keep: usize);
+    /// Time-indexed recall, oldest first.
+    fn in_range(&self, query: &EpisodicQuery) -> Vec<&EpisodicMemoryEntry> {
+        let mut hits: Vec<&EpisodicMemoryEntry> = self.all().into_iter().filter(|e| query.matches(e)).collect();
+        hits.sort_by_key(|e| e.created_at_tick);
+        hits
+    }
 }
 
 /// Trait for the Semantic Memory Store (Long-term).
+/// Append-only: entries are never modified. An update appends a new version linked
+/// to the one it replaces via `previous_version_id`.
 pub trait SemanticStore {
     fn insert(&mut self, entry: SemanticMemoryEntry) -> Result<(), MemoryError>;
+    /// Latest version of every chain matching `query_hash`. Superseded versions are never returned.
     fn retrieve(&self, query_hash: u64) -> Result<Vec<SemanticMemoryEntry>, MemoryError>;
-    fn update_confidence(&mut self, id: &str, new_confidence: f32) -> Result<(), MemoryError>;
+    /// Append a new version of `id`'s chain with `new_confidence`. Returns the new version's id,
+    /// taken from `ids` so that replays version the same way.
+    /// Updating a superseded version versions the chain's latest entry.
+    fn update_confidence(&mut self, id: &str, new_confidence: f32, ids: &mut IdGenerator) -> Result<String, MemoryError>;
+    /// Every version of the chain containing `id`, oldest first.
+    fn history(&self, id: &str) -> Result<Vec<SemanticMemoryEntry>, MemoryError>;
+    /// Maintenance: drop superseded versions, keeping only chain heads. Returns how many were dropped.
+    /// The only operation that removes entries. Never run implicitly. In memory: the caller saves.
+    fn compact(&mut self) -> Result<usize, MemoryError>;
+    /// Every stored version, superseded ones included, in insertion order (export).
+    fn entries(&self) -> Result<Vec<SemanticMemoryEntry>, MemoryError>;
     fn save(&self) -> Result<(), MemoryError>;
     fn load(&mut self) -> Result<(), MemoryError>;
+    /// What `save` would write, to be written off the tick (None: nothing on disk).
+    fn snapshot(&self) -> Option<SemanticSnapshot>;
+}
+
+/// A semantic store's contents as they go to disk (`SemanticStore::snapshot`).
+#[derive(Debug, Clone)]
+pub struct SemanticSnapshot {
+    path: PathBuf,
+    entries: Vec<SemanticMemoryEntry>,
+}
+
+impl SemanticSnapshot {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Blocking write.
+    pub fn save(&self) -> Result<(), MemoryError> {
+        let json = serde_json::to_string_pretty(&self.entries)
+            .map_err(|e| MemoryError::SerializationError(e.to_string()))?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
 }
 
 /// In-memory implementation of the Episodic Store.
@@ -81,8 +173,8 @@ impl EpisodicStore for InMemoryEpisodicStore {
         // Assuming threshold is, say, confidence goes below 0.1
         let mut active = VecDeque::new();
         for entry in self.entries.iter() {
-            let age = current_tick.saturating_sub(entry.last_reinforced_tick) as f32;
-            let current_strength = entry.confidence - (age * entry.decay_rate);
+            let age = current_tick.saturating_sub(entry.last_reinforced_tick);
+            let current_strength = crate::kernel::decay::linear(entry.confidence, age, entry.decay_rate);
             
             if current_strength > 0.1 {
                 active.push_back(entry.clone());
@@ -95,6 +187,19 @@ impl EpisodicStore for InMemoryEpisodicStore {
     fn all(&self) -> Vec<&EpisodicMemoryEntry> {
         self.entries.iter().collect()
     }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn prune_oldest(&mut self, keep: usize) {
+        if self.entries.len() <= keep {
+            return;
+        }
+        let excess = self.entries.len() - keep;
+        self.entries.drain(..excess);
+        self.rebuild_index();
+    }
 }
 
 /// File-based implementation of the Semantic Store.
@@ -102,6 +207,8 @@ pub struct FileSemanticStore {
     path: PathBuf,
     entries: Vec<SemanticMemoryEntry>,
     index: HashMap<u64, Vec<usize>>,
+    // Ids that a newer version points back to
+    superseded: HashSet<String>,
 }
 
 impl FileSemanticStore {
@@ -110,15 +217,38 @@ impl FileSemanticStore {
             path,
             entries: Vec::new(),
             index: HashMap::new(),
+            superseded: HashSet::new(),
         }
     }
 
     fn rebuild_index(&mut self) {
         self.index.clear();
+        self.superseded.clear();
         for (i, entry) in self.entries.iter().enumerate() {
             let key = entry.claim.key_hash();
             self.index.entry(key).or_insert_with(Vec::new).push(i);
+            if let Some(prev) = &entry.previous_version_id {
+                self.superseded.insert(prev.clone());
+            }
+        }
+    }
+
+    fn get(&self, id: &str) -> Option<&SemanticMemoryEntry> {
+        self.entries.iter().find(|e| e.id == id)
+    }
+
+    /// Follow `previous_version_id` links forward from `id` to the chain's latest version.
+    fn head_of(&self, id: &str) -> Option<&SemanticMemoryEntry> {
+        let mut current = self.get(id)?;
+        while let Some(next) = self.entries.iter().find(|e| e.previous_version_id.as_deref() == Some(current.id.as_str())) {
+            current = next;
         }
+        Some(current)
+    }
+
+    /// Number of stored versions, superseded ones included.
+    pub fn version_count(&self) -> usize {
+        self.entries.len()
     }
 }
 
@@ -126,51 +256,63 @@ impl SemanticStore for FileSemanticStore {
     fn insert(&mut self, entry: SemanticMemoryEntry) -> Result<(), MemoryError> {
         self.entries.push(entry);
         self.rebuild_index();
-        // Auto-save on insert? Or manual logic? The plan implies append-only log.
-        // We'll save the whole snapshot for now as JSON for simplicity.
+        // Snapshot of the append-only log (entries are only ever added, see `compact`)
         self.save()
     }
 
     fn retrieve(&self, query_hash: u64) -> Result<Vec<SemanticMemoryEntry>, MemoryError> {
         if let Some(indices) = self.index.get(&query_hash) {
-            Ok(indices.iter().filter_map(|&i| self.entries.get(i).cloned()).collect())
+            Ok(indices.iter()
+                .filter_map(|&i| self.entries.get(i))
+                .filter(|e| !self.superseded.contains(&e.id))
+                .cloned()
+                .collect())
         } else {
             Ok(Vec::new())
         }
     }
 
-    fn update_confidence(&mut self, id: &str, new_confidence: f32) -> Result<(), MemoryError> {
-        if let Some(entry) = self.entries.iter_mut().find(|e| e.id == id) {
-             // In an append-only system, we would insert a NEW entry with updated confidence / version.
-             // But for the in-memory vec representation, we mutate then save.
-             // To strictly follow "append-only" on disk, we'd just append the new JSON line.
-             // But managing strict append-only logs is complex for retrieval (need to collapse).
-             // We will simulate it: the caller should probably create a new entry with version + 1
-             // and call insert() instead of update_confidence mutating.
-             // However, for simplicity here, we'll allow mutation of the *latest* state in memory,
-             // but if we were strictly logging, we'd write a new record.
-             
-             // Let's stick to the invariant: "Updates create new versions"
-             // So this method might be implementing the mutation logic by pushing a new entry.
-             // But `update_confidence` usually implies modifying *an* entry.
-             // Let's change the signature or implementation to match the plan:
-             // "Semantic memory must be append-only with versioning, not mutable overwrite."
-             
-             entry.confidence = new_confidence; // This violates the invariant if we overwrite.
-             // Correct approach:
-             // We'll leave this mutation here for the *in-memory cache* but
-             // when we Serialize to disk, we should ideally support versioning.
-             // Given the complexity constraint, let's keep mutation in memory, 
-             // but understand that `insert` is the preferred way to "update" by adding v2.
+    fn update_confidence(&mut self, id: &str, new_confidence: f32, ids: &mut IdGenerator) -> Result<String, MemoryError> {
+        let head = self.head_of(id).ok_or(MemoryError::NotFound)?;
+        let next = SemanticMemoryEntry {
+            id: ids.next_uuid().to_string(),
+            confidence: new_confidence,
+            version: head.version + 1,
+            previous_version_id: Some(head.id.clone()),
+            ..head.clone()
+        };
+        let next_id = next.id.clone();
+        self.insert(next)?;
+        Ok(next_id)
+    }
+
+    fn history(&self, id: &str) -> Result<Vec<SemanticMemoryEntry>, MemoryError> {
+        let mut chain = vec![self.head_of(id).ok_or(MemoryError::NotFound)?.clone()];
+        while let Some(prev) = chain.last().and_then(|e| e.previous_version_id.as_deref()).and_then(|p| self.get(p)) {
+            chain.push(prev.clone());
+        }
+        chain.reverse();
+        Ok(chain)
+    }
+
+    fn compact(&mut self) -> Result<usize, MemoryError> {
+        let before = self.entries.len();
+        let superseded = std::mem::take(&mut self.superseded);
+        self.entries.retain(|e| !superseded.contains(&e.id));
+        // Heads keep their version number; the link now points at nothing
+        for entry in self.entries.iter_mut() {
+            entry.previous_version_id = None;
         }
-        self.save()
+        self.rebuild_index();
+        Ok(before - self.entries.len())
+    }
+
+    fn entries(&self) -> Result<Vec<SemanticMemoryEntry>, MemoryError> {
+        Ok(self.entries.clone())
     }
 
     fn save(&self) -> Result<(), MemoryError> {
-        let json = serde_json::to_string_pretty(&self.entries)
-            .map_err(|e| MemoryError::SerializationError(e.to_string()))?;
-        fs::write(&self.path, json)?;
-        Ok(())
+        SemanticSnapshot { path: self.path.clone(), entries: self.entries.clone() }.save()
     }
 
     fn load(&mut self) -> Result<(), MemoryError> {
@@ -183,4 +325,8 @@ impl SemanticStore for FileSemanticStore {
         self.rebuild_index();
         Ok(())
     }
+
+    fn snapshot(&self) -> Option<SemanticSnapshot> {
+        Some(SemanticSnapshot { path: self.path.clone(), entries: self.entries.clone() })
+    }
 }
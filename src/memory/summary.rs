@@ -0,0 +1,176 @@
+//! Turn-level Exchange Summaries.
+//!
+//! Long sessions should leave a compact, searchable history instead of raw transcripts.
+//! An exchange completes when a Stable intent got a reply and that reply was delivered in
+//! full (acknowledged by the driver). Each completed exchange becomes one episodic entry
+//! holding a one-line summary, subject `EntityId::Topic` so topic recall finds it.
+//!
+//! Lifecycle (driven by the Reactor):
+//! 1. `open`: a Stable intent. Replaces any earlier exchange that never got a reply.
+//! 2. `attach`: the next reply (`SpawnAudio` / `RequestSpeech`) binds the exchange to its playback.
+//! 3. `acknowledged`: natural end of that playback. `dropped` / `interrupted` discard it.
+//!
+//! Summaries are a template line by default. In `SummaryMode::Llm` the driver is asked for a
+//! one-liner (`SideEffect::RequestSummary`); no answer in time falls back to the template.
+//! Sensitive exchanges are never summarized.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::event::PlaybackId;
+use crate::kernel::intent::types::IntentHypothesis;
+use crate::kernel::speech::planner::SpeechIntent;
+use crate::kernel::time::Tick;
+use crate::memory::types::{Claim, ClaimValue, EntityId, EpisodicMemoryEntry, Modality, Predicate};
+
+// Config Constants
+const MAX_SIDE_CHARS: usize = 80;
+const MAX_SUMMARY_CHARS: usize = 200;
+/// Ticks to wait for an LLM summary before writing the template line.
+pub const SUMMARY_TIMEOUT_TICKS: u64 = 100;
+pub const EXCHANGE_PREDICATE: &str = "exchange";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SummaryMode {
+    Off,
+    #[default]
+    Template,
+    /// Ask the driver's LLM for the line (`SideEffect::RequestSummary`)
+    Llm,
+}
+
+/// One completed (or in-flight) user turn and the system's reply.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Exchange {
+    /// Id of the Stable intent that opened it
+    pub id: String,
+    pub hypothesis: IntentHypothesis,
+    pub user_text: String,
+    /// Spoken text, or a description of driver-generated speech
+    pub reply: String,
+    pub topic: Option<String>,
+    pub opened_at: Tick,
+}
+
+impl Exchange {
+    /// The episodic entry for this exchange, holding `summary`.
+    pub fn entry(&self, summary: String, modality: Modality, tick: Tick) -> EpisodicMemoryEntry {
+        let subject = match &self.topic {
+            Some(topic) => EntityId::Topic(topic.clone()),
+            None => EntityId::User,
+        };
+        EpisodicMemoryEntry {
+            claim: Claim::new(subject, Predicate::Custom(EXCHANGE_PREDICATE.to_string()), ClaimValue::Text(summary), modality),
+            confidence: 1.0,
+            created_at_tick: tick.frame,
+            last_reinforced_tick: tick.frame,
+            // Session history: only memory pressure prunes it
+            decay_rate: 0.0,
+            created_at_ms: None,
+        }
+    }
+}
+
+/// PURE FUNCTION: One-line template summary.
+pub fn template_summary(exchange: &Exchange) -> String {
+    let verb = match exchange.hypothesis {
+        IntentHypothesis::Inquiry => "User asked",
+        IntentHypothesis::Command => "User requested",
+        _ => "User said",
+    };
+    format!("{} \"{}\"; replied \"{}\"", verb, clip(&exchange.user_text, MAX_SIDE_CHARS), clip(&exchange.reply, MAX_SIDE_CHARS))
+}
+
+/// PURE FUNCTION: Accept an LLM answer as a summary line (first line, trimmed, capped).
+pub fn clean_llm_summary(text: &str) -> Option<String> {
+    let line = text.lines().map(str::trim).find(|l| !l.is_empty())?;
+    Some(clip(line, MAX_SUMMARY_CHARS))
+}
+
+/// Reply description for driver-generated speech (the kernel never sees its text).
+pub fn describe_speech(intent: &SpeechIntent) -> String {
+    match intent {
+        SpeechIntent::Clarification(seed) => format!("(asked to clarify: {})", seed),
+        SpeechIntent::Confirmation(seed) => format!("(confirmed: {})", seed),
+        SpeechIntent::Offer(seed) => format!("(offered: {})", seed),
+    }
+}
+
+fn clip(text: &str, max: usize) -> String {
+    let text = text.trim();
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut clipped: String = text.chars().take(max.saturating_sub(3)).collect();
+    clipped.push_str("...");
+    clipped
+}
+
+/// Tracks exchanges from Stable intent to acknowledged reply.
+#[derive(Debug, Default)]
+pub struct ExchangeTracker {
+    open: Option<Exchange>,
+    awaiting_playback: HashMap<PlaybackId, Exchange>,
+    awaiting_summary: HashMap<String, (Exchange, Tick)>,
+}
+
+impl ExchangeTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A Stable intent starts an exchange. `reply` is filled in by `attach`.
+    pub fn open(&mut self, id: &str, hypothesis: IntentHypothesis, user_text: &str, topic: Option<String>, tick: Tick) {
+        self.open = Some(Exchange {
+            id: id.to_string(),
+            hypothesis,
+            user_text: user_text.to_string(),
+            reply: String::new(),
+            topic,
+            opened_at: tick,
+        });
+    }
+
+    /// The next reply belongs to the open exchange. No-op when none is open.
+    pub fn attach(&mut self, playback: PlaybackId, reply: &str) {
+        if let Some(mut exchange) = self.open.take() {
+            exchange.reply = reply.to_string();
+            self.awaiting_playback.insert(playback, exchange);
+        }
+    }
+
+    /// The reply was delivered in full: the exchange is complete.
+    pub fn acknowledged(&mut self, playback: &PlaybackId) -> Option<Exchange> {
+        self.awaiting_playback.remove(playback)
+    }
+
+    /// The reply ended without being delivered in full.
+    pub fn dropped(&mut self, playback: &PlaybackId) {
+        self.awaiting_playback.remove(playback);
+    }
+
+    /// Barge-in: nothing in flight was heard in full.
+    pub fn interrupted(&mut self) {
+        self.awaiting_playback.clear();
+    }
+
+    /// Hold a completed exchange until the driver's summary arrives.
+    pub fn await_summary(&mut self, exchange: Exchange, tick: Tick) {
+        self.awaiting_summary.insert(exchange.id.clone(), (exchange, tick));
+    }
+
+    pub fn summary_arrived(&mut self, exchange_id: &str) -> Option<Exchange> {
+        self.awaiting_summary.remove(exchange_id).map(|(exchange, _)| exchange)
+    }
+
+    /// Exchanges whose summary did not arrive within `SUMMARY_TIMEOUT_TICKS`.
+    pub fn expired(&mut self, now: Tick) -> Vec<Exchange> {
+        let mut due: Vec<(Tick, String)> = self.awaiting_summary.iter()
+            .filter(|(_, (_, since))| now.frame.saturating_sub(since.frame) >= SUMMARY_TIMEOUT_TICKS)
+            .map(|(id, (_, since))| (*since, id.clone()))
+            .collect();
+        due.sort_by(|a, b| (a.0.frame, &a.1).cmp(&(b.0.frame, &b.1)));
+        due.into_iter().filter_map(|(_, id)| self.summary_arrived(&id)).collect()
+    }
+}
@@ -112,4 +112,7 @@ pub struct EpisodicMemoryEntry {
     pub last_reinforced_tick: u64,
     /// Decays over time. If < 0, it is removed.
     pub decay_rate: f32, 
+    /// Wall clock (Unix ms) at creation, for temporal recall. None in older stores.
+    #[serde(default)]
+    pub created_at_ms: Option<u64>,
 }
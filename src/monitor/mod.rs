@@ -1,5 +1,7 @@
 pub mod types;
 pub mod monitor;
+pub mod sentiment;
 
 pub use types::*;
 pub use monitor::*;
+pub use sentiment::*;
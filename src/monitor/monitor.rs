@@ -47,6 +47,18 @@ impl SelfObservationMonitor {
                 SelfObservation::ConfidenceMismatch { .. } => {
                     self.confidence_penalty = (self.confidence_penalty + 0.2).min(1.0);
                 }
+                SelfObservation::UserSentiment { valence, confidence } if *valence < 0.0 => {
+                    // Frustrated user: keep it short and only speak when sure
+                    let frustration = -valence * confidence;
+                    self.interruption_score = (self.interruption_score + 0.3 * frustration).min(1.0);
+                    self.confidence_penalty = (self.confidence_penalty + 0.2 * frustration).min(1.0);
+                }
+                SelfObservation::UserSentiment { valence, confidence } => {
+                    // Pleased user: heal like a mild confirmation
+                    let relief = valence * confidence;
+                    self.confidence_penalty = (self.confidence_penalty - 0.05 * relief).max(0.0);
+                    self.interruption_score = (self.interruption_score - 0.05 * relief).max(0.0);
+                }
                 SelfObservation::StableAlignment | SelfObservation::Confirmation => {
                     // Verify recovery test?
                     // System heals faster on success
@@ -60,11 +72,10 @@ impl SelfObservationMonitor {
         // Decay rate depends on the parameter.
         // Interruption sensitivity decays fast (1s?). Confidence penalty decays slow.
         // Assuming Tick = 100ms.
-        let decay_factor = 0.01 * (elapsed as f32); 
-        
-        self.interruption_score = (self.interruption_score - decay_factor).max(0.0);
-        self.confidence_penalty = (self.confidence_penalty - (decay_factor * 0.5)).max(0.0); // Slower decay
-        self.correction_score = (self.correction_score - decay_factor).max(0.0);
+        // Linear per tick, so a long gap heals fully (never below 0.0, never NaN)
+        self.interruption_score = crate::kernel::decay::linear(self.interruption_score, elapsed, 0.01);
+        self.confidence_penalty = crate::kernel::decay::linear(self.confidence_penalty, elapsed, 0.005); // Slower decay
+        self.correction_score = crate::kernel::decay::linear(self.correction_score, elapsed, 0.01);
 
         // 3. Emit Delta
         // We always emit the current state so the kernel is in sync.
@@ -0,0 +1,114 @@
+//! Turn-level Sentiment (valence) over user transcripts.
+//!
+//! The Reactor hands each user turn (typed text, or the first transcript of a segment) to its
+//! `SentimentEstimator` sidecar. A confident estimate becomes a
+//! `SelfObservation::UserSentiment`, and the Monitor folds it into `MetaLatents`: a frustrated
+//! user raises interruption sensitivity and the confidence penalty, a pleased one helps them
+//! heal. The estimate itself never reaches state or the planner.
+//!
+//! `LexiconSentiment` is the default: a small English word list with negation ("not good")
+//! and intensifiers ("really annoying"). A model-backed estimator plugs into the same seam.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+// Config Constants
+/// Below this confidence an estimate is not reported to the Monitor.
+pub const MIN_SENTIMENT_CONFIDENCE: f32 = 0.5;
+/// Valence at or below this reads as frustration.
+pub const FRUSTRATION_VALENCE: f32 = -0.3;
+/// Sentiment words that make a fully confident estimate.
+const CONFIDENT_HITS: f32 = 2.0;
+/// Negators flip a word's score (damped) within this many preceding words.
+const NEGATION_WINDOW: usize = 2;
+const NEGATION_FACTOR: f32 = -0.5;
+const INTENSIFIER_FACTOR: f32 = 1.5;
+/// Normalization: valence = sum / sqrt(sum^2 + ALPHA), in (-1, 1).
+const NORMALIZATION_ALPHA: f32 = 4.0;
+
+const LEXICON: &[(&str, f32)] = &[
+    // Negative
+    ("annoying", -0.8), ("annoyed", -0.8), ("frustrating", -0.9), ("frustrated", -0.9),
+    ("useless", -0.9), ("stupid", -0.8), ("terrible", -0.9), ("awful", -0.9), ("hate", -0.9),
+    ("ugh", -0.6), ("bad", -0.6), ("broken", -0.6), ("wrong", -0.5), ("worse", -0.7),
+    ("ridiculous", -0.8), ("confusing", -0.5), ("slow", -0.4), ("pointless", -0.8),
+    // Positive
+    ("thanks", 0.6), ("thank", 0.6), ("great", 0.8), ("good", 0.5), ("perfect", 0.9),
+    ("awesome", 0.9), ("nice", 0.6), ("love", 0.8), ("helpful", 0.7), ("excellent", 0.9),
+    ("cool", 0.5), ("brilliant", 0.9),
+];
+
+const NEGATORS: &[&str] = &["not", "no", "never", "don't", "isn't", "doesn't", "didn't", "wasn't", "can't", "won't"];
+const INTENSIFIERS: &[&str] = &["very", "really", "so", "too", "extremely", "totally", "completely"];
+
+/// Valence of one turn: -1.0 (negative) ..= 1.0 (positive), and how sure the estimate is.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Sentiment {
+    pub valence: f32,
+    pub confidence: f32,
+}
+
+impl Sentiment {
+    pub fn is_frustrated(&self) -> bool {
+        self.valence <= FRUSTRATION_VALENCE && self.confidence >= MIN_SENTIMENT_CONFIDENCE
+    }
+}
+
+/// Lexicon-based estimator (English only; other languages get no estimate).
+#[derive(Debug, Clone)]
+pub struct LexiconSentiment {
+    lexicon: HashMap<String, f32>,
+}
+
+impl Default for LexiconSentiment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LexiconSentiment {
+    pub fn new() -> Self {
+        Self { lexicon: LEXICON.iter().map(|(word, score)| (word.to_string(), *score)).collect() }
+    }
+
+    /// Add or override words (lowercase), e.g. domain jargon.
+    pub fn with_words(mut self, words: &[(&str, f32)]) -> Self {
+        self.lexicon.extend(words.iter().map(|(word, score)| (word.to_lowercase(), score.clamp(-1.0, 1.0))));
+        self
+    }
+
+    /// PURE FUNCTION: Sentiment of `transcript` in `language` (`None`: unknown, read as English).
+    /// `None` without any sentiment word, or for a language the lexicon does not cover.
+    pub fn score(&self, transcript: &str, language: Option<&str>) -> Option<Sentiment> {
+        if language.is_some_and(|l| l != crate::kernel::language::BASE_LANGUAGE) {
+            return None;
+        }
+        let words: Vec<String> = transcript
+            .split(|c: char| !(c.is_alphanumeric() || c == '\''))
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        let mut sum = 0.0;
+        let mut hits = 0;
+        for (i, word) in words.iter().enumerate() {
+            let Some(&base) = self.lexicon.get(word) else { continue };
+            let mut score = base;
+            if i > 0 && INTENSIFIERS.contains(&words[i - 1].as_str()) {
+                score *= INTENSIFIER_FACTOR;
+            }
+            if words[i.saturating_sub(NEGATION_WINDOW)..i].iter().any(|w| NEGATORS.contains(&w.as_str())) {
+                score *= NEGATION_FACTOR;
+            }
+            sum += score;
+            hits += 1;
+        }
+        if hits == 0 {
+            return None;
+        }
+        Some(Sentiment {
+            valence: sum / (sum * sum + NORMALIZATION_ALPHA).sqrt(),
+            confidence: (hits as f32 / CONFIDENT_HITS).min(1.0),
+        })
+    }
+}
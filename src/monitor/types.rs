@@ -21,6 +21,9 @@ pub enum SelfObservation {
     
     /// User confirmed the statement (e.g. "Yes", "Exactly").
     Confirmation,
+
+    /// Estimated valence of a user turn (`monitor::sentiment`), -1.0 ..= 1.0.
+    UserSentiment { valence: f32, confidence: f32 },
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,117 @@
+//! Speech Captions (Accessibility).
+//!
+//! Every spoken output is also published as a `Caption`: the full text plus per-word timing
+//! relative to playback start, so a UI can highlight words in sync with the audio. Timing comes
+//! from TTS word offsets when the backend reports them (`from_word_offsets`); otherwise it is
+//! estimated from word length and punctuation over `playback::estimate_duration_ms`.
+//!
+//! Caption-only mode (`ReactorConfig::caption_only`): drivers show the caption and replace the
+//! TTS process with a silent timer of the same estimated length (`playback_command_with`), so the
+//! playback lifecycle the kernel sees (start, progress, end, interruption) is unchanged.
+
+use serde::Serialize;
+
+use crate::kernel::event::PlaybackId;
+use crate::outputs::playback::estimate_duration_ms;
+
+// Relative word weights: characters, plus a pause after clause and sentence punctuation
+const MIN_WORD_WEIGHT: u64 = 2;
+const CLAUSE_PAUSE_WEIGHT: u64 = 2;
+const SENTENCE_PAUSE_WEIGHT: u64 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CaptionTiming {
+    /// Word offsets reported by the TTS backend.
+    Tts,
+    /// Length heuristic (backends without metadata, e.g. `say`).
+    Estimated,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CaptionWord {
+    pub text: String,
+    pub start_ms: u64,
+    pub end_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Caption {
+    pub playback_id: PlaybackId,
+    pub text: String,
+    pub words: Vec<CaptionWord>,
+    pub duration_ms: u64,
+    pub timing: CaptionTiming,
+    /// False in caption-only mode (nothing is spoken).
+    pub audible: bool,
+}
+
+impl Caption {
+    /// Heuristic timing: the estimated duration split across words by weight.
+    pub fn estimate(playback_id: PlaybackId, text: &str, audible: bool) -> Self {
+        let duration_ms = estimate_duration_ms(text);
+        let weights: Vec<u64> = text.split_whitespace().map(word_weight).collect();
+        let total: u64 = weights.iter().sum::<u64>().max(1);
+
+        let mut elapsed = 0;
+        let mut words = Vec::with_capacity(weights.len());
+        for (word, weight) in text.split_whitespace().zip(&weights) {
+            let start_ms = duration_ms * elapsed / total;
+            elapsed += weight;
+            words.push(CaptionWord { text: word.to_string(), start_ms, end_ms: duration_ms * elapsed / total });
+        }
+        Self { playback_id, text: text.to_string(), words, duration_ms, timing: CaptionTiming::Estimated, audible }
+    }
+
+    /// TTS metadata timing: `offsets_ms[i]` is when word `i` starts. Falls back to the
+    /// estimate if the offsets do not line up with the words.
+    pub fn from_word_offsets(playback_id: PlaybackId, text: &str, offsets_ms: &[u64], duration_ms: u64, audible: bool) -> Self {
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        let sorted = offsets_ms.windows(2).all(|w| w[0] <= w[1]);
+        if tokens.len() != offsets_ms.len() || !sorted || offsets_ms.last().is_some_and(|last| *last > duration_ms) {
+            return Self::estimate(playback_id, text, audible);
+        }
+        let words = tokens.iter().enumerate()
+            .map(|(i, word)| CaptionWord {
+                text: word.to_string(),
+                start_ms: offsets_ms[i],
+                end_ms: offsets_ms.get(i + 1).copied().unwrap_or(duration_ms),
+            })
+            .collect();
+        Self { playback_id, text: text.to_string(), words, duration_ms, timing: CaptionTiming::Tts, audible }
+    }
+
+    /// Index of the word being spoken `elapsed_ms` after playback start.
+    pub fn word_at(&self, elapsed_ms: u64) -> Option<usize> {
+        self.words.iter().position(|w| elapsed_ms >= w.start_ms && elapsed_ms < w.end_ms)
+    }
+}
+
+fn word_weight(word: &str) -> u64 {
+    let letters = word.chars().filter(|c| c.is_alphanumeric()).count() as u64;
+    let pause = match word.chars().last() {
+        Some('.' | '?' | '!') => SENTENCE_PAUSE_WEIGHT,
+        Some(',' | ';' | ':') => CLAUSE_PAUSE_WEIGHT,
+        _ => 0,
+    };
+    letters.max(MIN_WORD_WEIGHT) + pause
+}
+
+/// Playback process for a driver: `say <text>`, or in caption-only mode a silent
+/// `sleep` lasting the caption's estimated duration.
+pub fn playback_command(text: &str, caption_only: bool) -> tokio::process::Command {
+    playback_command_on(text, caption_only, None)
+}
+
+/// `playback_command` routed to an output device (`say -a`). `None` = system default.
+pub fn playback_command_on(text: &str, caption_only: bool, device: Option<&str>) -> tokio::process::Command {
+    playback_command_with(&crate::outputs::tts::SayEngine, text, caption_only, device)
+}
+
+/// `playback_command_on` with any TTS engine (`ReactorConfig::tts`).
+pub fn playback_command_with(engine: &dyn crate::outputs::tts::TtsEngine, text: &str, caption_only: bool, device: Option<&str>) -> tokio::process::Command {
+    if caption_only {
+        engine.silence(estimate_duration_ms(text))
+    } else {
+        engine.speak(text, device)
+    }
+}
@@ -0,0 +1,86 @@
+//! Playback Device Routing.
+//!
+//! Like capture (`CaptureConfig::device_name`), playback can be pinned to an output device
+//! (headset vs speakers). The user sets a fallback chain of device names (substring match),
+//! most preferred first; the first one present wins, otherwise the system default plays.
+//!
+//! The driver polls the device list (`OutputRouter::refresh`) and reports every route change
+//! to the kernel as `InputContent::OutputDevice`. If the route changes mid-utterance (headset
+//! unplugged), the kernel stops playback rather than continue on another device.
+
+use cpal::traits::{DeviceTrait, HostTrait};
+
+// Config Constants
+/// Device list poll interval for drivers.
+pub const ROUTE_POLL_SECS: u64 = 5;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutputDeviceConfig {
+    /// Fallback chain (substring match, most preferred first). Empty = system default.
+    pub preferred: Vec<String>,
+}
+
+impl OutputDeviceConfig {
+    /// From `NEXUS_OUTPUT_DEVICE`, e.g. `"Headset,MacBook Pro Speakers"`.
+    pub fn from_env() -> Self {
+        Self::parse(&std::env::var("NEXUS_OUTPUT_DEVICE").unwrap_or_default())
+    }
+
+    pub fn parse(chain: &str) -> Self {
+        Self {
+            preferred: chain.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect(),
+        }
+    }
+}
+
+/// Output device names known to the host (empty if enumeration fails).
+pub fn output_devices() -> Vec<String> {
+    cpal::default_host()
+        .output_devices()
+        .map(|devices| devices.filter_map(|d| d.name().ok()).collect())
+        .unwrap_or_default()
+}
+
+/// PURE FUNCTION: First available device matching the chain. `None` = system default.
+pub fn resolve(preferred: &[String], available: &[String]) -> Option<String> {
+    preferred.iter().find_map(|wanted| {
+        available.iter().find(|name| name.contains(wanted.as_str())).cloned()
+    })
+}
+
+/// Driver-side routing state.
+#[derive(Debug, Default)]
+pub struct OutputRouter {
+    config: OutputDeviceConfig,
+    current: Option<String>,
+}
+
+impl OutputRouter {
+    pub fn new(config: OutputDeviceConfig) -> Self {
+        Self { config, current: None }
+    }
+
+    /// Device playback goes to (`None` = system default).
+    pub fn current(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    pub fn config(&self) -> &OutputDeviceConfig {
+        &self.config
+    }
+
+    /// Settings: replace the chain. Takes effect on the next `refresh`.
+    pub fn set_config(&mut self, config: OutputDeviceConfig) {
+        self.config = config;
+    }
+
+    /// Re-resolve against the current device list. True if the route changed.
+    pub fn refresh(&mut self, available: &[String]) -> bool {
+        let route = resolve(&self.config.preferred, available);
+        if route == self.current {
+            return false;
+        }
+        self.current = route;
+        true
+    }
+}
@@ -0,0 +1,237 @@
+//! Playback Loudness.
+//!
+//! TTS engines speak at very different levels (espeak-ng is loud, SAPI and piper voices
+//! quiet), so synthesized buffers are normalized to `TARGET_LUFS` before they are cached.
+//! Loudness is integrated loudness after ITU-R BS.1770: K-weighting, 400 ms blocks with 75%
+//! overlap, an absolute gate at -70 LUFS and a relative gate 10 LU below the ungated level.
+//!
+//! On top of that sits the user's volume (`Profile::volume`, percent of the normalized level),
+//! adjusted by voice ("quieter please", "louder") through SystemControl intents. Only 16-bit
+//! PCM WAV buffers are rewritten; other playback gets the gain through the engine's own volume
+//! control (`TtsEngine::speak_at`).
+
+// Config Constants
+pub const TARGET_LUFS: f64 = -18.0;
+/// Normalization never boosts or cuts by more than this (quiet noise is not speech).
+pub const MAX_GAIN_DB: f64 = 12.0;
+pub const DEFAULT_VOLUME: u8 = 100;
+pub const MIN_VOLUME: u8 = 10;
+pub const MAX_VOLUME: u8 = 150;
+pub const VOLUME_STEP: u8 = 20;
+const BLOCK_MS: u32 = 400;
+const BLOCK_STEP_MS: u32 = 100;
+const ABSOLUTE_GATE_LUFS: f64 = -70.0;
+const RELATIVE_GATE_LU: f64 = -10.0;
+
+const QUIETER_WORDS: &[&str] = &["quieter", "softer", "lower", "down"];
+const LOUDER_WORDS: &[&str] = &["louder", "up", "higher"];
+// Longer utterances mention loudness rather than ask for it
+const MAX_REQUEST_WORDS: usize = 8;
+const VOLUME_WORDS: &[&str] = &["volume", "quieter", "louder", "softer"];
+
+/// Decoded 16-bit PCM WAV, samples interleaved in [-1, 1].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WavPcm {
+    pub sample_rate: u32,
+    pub channels: usize,
+    pub samples: Vec<f32>,
+    // Byte range of the `data` chunk in the source buffer
+    data: std::ops::Range<usize>,
+}
+
+/// PURE FUNCTION: Parse a RIFF/WAVE buffer. `None` unless it is 16-bit integer PCM.
+pub fn decode_wav(bytes: &[u8]) -> Option<WavPcm> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return None;
+    }
+    let u16_at = |at: usize| bytes.get(at..at + 2).map(|b| u16::from_le_bytes([b[0], b[1]]));
+    let u32_at = |at: usize| bytes.get(at..at + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]));
+    let (mut format, mut at) = (None, 12);
+    while at + 8 <= bytes.len() {
+        let id = &bytes[at..at + 4];
+        let size = u32_at(at + 4)? as usize;
+        let body = at + 8;
+        match id {
+            b"fmt " => {
+                // PCM (1), 16 bits per sample
+                if u16_at(body)? != 1 || u16_at(body + 14)? != 16 {
+                    return None;
+                }
+                format = Some((u16_at(body + 2)? as usize, u32_at(body + 4)?));
+            }
+            b"data" => {
+                let (channels, sample_rate) = format?;
+                // Streaming writers leave the size unset; the data runs to the end
+                let end = body.saturating_add(size).min(bytes.len());
+                let end = end - (end - body) % 2;
+                let samples = bytes[body..end].chunks_exact(2)
+                    .map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / 32768.0)
+                    .collect();
+                return (channels > 0 && sample_rate > 0).then_some(WavPcm { sample_rate, channels, samples, data: body..end });
+            }
+            _ => {}
+        }
+        // Chunks are word aligned
+        at = body.checked_add(size + size % 2)?;
+    }
+    None
+}
+
+// One biquad section (direct form I)
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 2],
+    x: [f64; 2],
+    y: [f64; 2],
+}
+
+impl Biquad {
+    fn new(b: [f64; 3], a: [f64; 3]) -> Self {
+        Self { b: b.map(|v| v / a[0]), a: [a[1] / a[0], a[2] / a[0]], x: [0.0; 2], y: [0.0; 2] }
+    }
+
+    // K-weighting stage 1: high shelf, +4 dB above ~1.5 kHz (head diffraction)
+    fn shelf(sample_rate: u32) -> Self {
+        let (gain_db, f0, q) = (4.0f64, 1500.0, std::f64::consts::FRAC_1_SQRT_2);
+        let a = 10f64.powf(gain_db / 40.0);
+        let w0 = 2.0 * std::f64::consts::PI * f0 / sample_rate as f64;
+        let (cos, alpha) = (w0.cos(), w0.sin() / (2.0 * q));
+        let root = 2.0 * a.sqrt() * alpha;
+        Self::new(
+            [a * ((a + 1.0) + (a - 1.0) * cos + root), -2.0 * a * ((a - 1.0) + (a + 1.0) * cos), a * ((a + 1.0) + (a - 1.0) * cos - root)],
+            [(a + 1.0) - (a - 1.0) * cos + root, 2.0 * ((a - 1.0) - (a + 1.0) * cos), (a + 1.0) - (a - 1.0) * cos - root],
+        )
+    }
+
+    // K-weighting stage 2: high pass at 38 Hz
+    fn high_pass(sample_rate: u32) -> Self {
+        let (f0, q) = (38.0f64, 0.5);
+        let w0 = 2.0 * std::f64::consts::PI * f0 / sample_rate as f64;
+        let (cos, alpha) = (w0.cos(), w0.sin() / (2.0 * q));
+        Self::new([(1.0 + cos) / 2.0, -(1.0 + cos), (1.0 + cos) / 2.0], [1.0 + alpha, -2.0 * cos, 1.0 - alpha])
+    }
+
+    fn process(&mut self, x: f64) -> f64 {
+        let y = self.b[0] * x + self.b[1] * self.x[0] + self.b[2] * self.x[1] - self.a[0] * self.y[0] - self.a[1] * self.y[1];
+        self.x = [x, self.x[0]];
+        self.y = [y, self.y[0]];
+        y
+    }
+}
+
+fn lufs(power: f64) -> f64 {
+    -0.691 + 10.0 * power.log10()
+}
+
+/// PURE FUNCTION: Integrated loudness (LUFS) of interleaved samples. `None` for silence
+/// (nothing above the absolute gate). Audio shorter than one block is measured as one block.
+pub fn integrated_loudness(samples: &[f32], channels: usize, sample_rate: u32) -> Option<f64> {
+    if channels == 0 || sample_rate == 0 || samples.len() < channels {
+        return None;
+    }
+    let frames = samples.len() / channels;
+    // K-weighted squares, summed over channels (all weighted 1: speech is mono or L/R)
+    let mut squares = vec![0.0f64; frames];
+    for channel in 0..channels {
+        let (mut shelf, mut high_pass) = (Biquad::shelf(sample_rate), Biquad::high_pass(sample_rate));
+        for (frame, square) in squares.iter_mut().enumerate() {
+            let y = high_pass.process(shelf.process(samples[frame * channels + channel] as f64));
+            *square += y * y;
+        }
+    }
+    let block = ((sample_rate * BLOCK_MS / 1000) as usize).clamp(1, frames);
+    let step = ((sample_rate * BLOCK_STEP_MS / 1000) as usize).max(1);
+    let powers: Vec<f64> = (0..=(frames - block) / step)
+        .map(|i| squares[i * step..i * step + block].iter().sum::<f64>() / block as f64)
+        .filter(|&p| p > 0.0 && lufs(p) > ABSOLUTE_GATE_LUFS)
+        .collect();
+    if powers.is_empty() {
+        return None;
+    }
+    let relative_gate = lufs(powers.iter().sum::<f64>() / powers.len() as f64) + RELATIVE_GATE_LU;
+    let gated: Vec<f64> = powers.into_iter().filter(|&p| lufs(p) > relative_gate).collect();
+    Some(lufs(gated.iter().sum::<f64>() / gated.len() as f64))
+}
+
+/// PURE FUNCTION: Linear gain taking `measured` LUFS to `target`, within ±`MAX_GAIN_DB`.
+pub fn normalization_gain(measured: f64, target: f64) -> f32 {
+    10f64.powf((target - measured).clamp(-MAX_GAIN_DB, MAX_GAIN_DB) / 20.0) as f32
+}
+
+/// PURE FUNCTION: Linear gain of a volume preference (percent of the normalized level).
+pub fn volume_gain(volume: u8) -> f32 {
+    volume.clamp(MIN_VOLUME, MAX_VOLUME) as f32 / 100.0
+}
+
+/// PURE FUNCTION: `bytes` (16-bit PCM WAV) with every sample scaled by `gain`, clipped to full
+/// scale. Headers and other chunks are kept as they are.
+pub fn apply_gain(bytes: &[u8], gain: f32) -> Option<Vec<u8>> {
+    let wav = decode_wav(bytes)?;
+    let mut out = bytes.to_vec();
+    for (sample, dst) in wav.samples.iter().zip(out[wav.data].chunks_exact_mut(2)) {
+        let scaled = (sample * gain * 32768.0).round().clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+        dst.copy_from_slice(&scaled.to_le_bytes());
+    }
+    Some(out)
+}
+
+/// PURE FUNCTION: `bytes` normalized to `target` LUFS, with the loudness measured before.
+/// `None` if the buffer is not 16-bit PCM WAV or is silent.
+pub fn normalize(bytes: &[u8], target: f64) -> Option<(Vec<u8>, f64)> {
+    let wav = decode_wav(bytes)?;
+    let measured = integrated_loudness(&wav.samples, wav.channels, wav.sample_rate)?;
+    Some((apply_gain(bytes, normalization_gain(measured, target))?, measured))
+}
+
+/// A spoken volume change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeRequest {
+    Quieter,
+    Louder,
+    /// Back to the normalized level ("normal volume")
+    Reset,
+}
+
+impl VolumeRequest {
+    /// PURE FUNCTION: The volume after this request, one step at a time, within bounds.
+    pub fn apply(self, volume: u8) -> u8 {
+        match self {
+            VolumeRequest::Quieter => volume.saturating_sub(VOLUME_STEP).max(MIN_VOLUME),
+            VolumeRequest::Louder => volume.saturating_add(VOLUME_STEP).min(MAX_VOLUME),
+            VolumeRequest::Reset => DEFAULT_VOLUME,
+        }
+    }
+}
+
+/// PURE FUNCTION: Read a volume request ("quieter please", "speak up", "turn the volume
+/// down", "normal volume"). English keywords; anything else is not a request.
+pub fn parse_volume_request(text: &str) -> Option<VolumeRequest> {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).collect();
+    if words.len() > MAX_REQUEST_WORDS {
+        return None;
+    }
+    let has = |list: &[&str]| words.iter().any(|w| list.contains(w));
+    // "turn it down", "turn yourself up": only a pronoun object means the voice ("turn down the lights" does not)
+    let turn = |direction: &str| words.windows(3).any(|w| w[0] == "turn" && matches!(w[1], "it" | "yourself") && w[2] == direction);
+
+    // "speak up" and "turn it up/down" need no volume word; anything else does
+    if words.windows(2).any(|w| w == ["speak", "up"]) || turn("up") {
+        return Some(VolumeRequest::Louder);
+    }
+    if turn("down") {
+        return Some(VolumeRequest::Quieter);
+    }
+    if !has(VOLUME_WORDS) {
+        return None;
+    }
+    if words.contains(&"louder") || (!has(QUIETER_WORDS) && has(LOUDER_WORDS)) {
+        Some(VolumeRequest::Louder)
+    } else if has(QUIETER_WORDS) {
+        Some(VolumeRequest::Quieter)
+    } else if has(&["normal", "default", "reset", "usual"]) {
+        Some(VolumeRequest::Reset)
+    } else {
+        None
+    }
+}
@@ -1,3 +1,15 @@
 pub mod text;
 pub mod mock_audio;
 pub mod realizer;
+pub mod phrases;
+pub mod playback;
+pub mod captions;
+pub mod tts;
+pub mod tts_cache;
+pub mod loudness;
+pub mod device;
+pub mod safety;
+pub mod style;
+pub mod streaming;
+pub mod streamer;
+pub mod speakable;
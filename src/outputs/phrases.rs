@@ -0,0 +1,356 @@
+//! Dialogue Act Phrase Templates.
+//!
+//! Every sentence the kernel phrases itself (clarifications, resumption offers, the warm start,
+//! follow-up offers, bookmark recalls, planner clarifications and corrections) comes from a template table: per locale, per act,
+//! one or more variants. `PhraseMemory` rotates through the variants. Templates take variables
+//! in braces: `{topic}` (what the intent was about, content-free: "you were asking about
+//! something"), `{intent}` (the suspended intent's name: "your question"), `{held}` and
+//! `{count}` (warm start), `{name}` and `{summary}` (bookmarks), `{context}` and `{correction}`
+//! (planner text).
+//!
+//! The built-in table is English. `nexus_phrases.json` (or the path in `NEXUS_PHRASES`; the
+//! shell reads `phrases.json` from its config dir) selects a locale and overrides or adds
+//! templates:
+//! `{ "locale": "de", "templates": { "de": { "clarification": ["Meinst du mich?"], .. } } }`.
+//! A table is only used if every act has at least one variant in every locale and every
+//! variable is one its act provides. The kernel phrases in the reply language instead
+//! (`speak_in`, see `kernel::language`) when the table has templates for it.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::crystallizer::CrystallizationDecision;
+use crate::kernel::intent::types::IntentHypothesis;
+use crate::kernel::warm_start::WarmStart;
+
+// Config Constants
+const PHRASES_FILE: &str = "nexus_phrases.json";
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Non-leading clarifications (arbitrator). The first is the seed the arbitrator emits.
+pub const CLARIFICATION_VARIANTS: &[&str] = &[
+    "Do you want me to respond?",
+    "Should I answer that?",
+    "Was that meant for me?",
+];
+
+/// One row of the template table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PhraseAct {
+    /// Non-leading clarification (arbitrator)
+    Clarification,
+    /// Planner `AskClarification`
+    PlanClarification,
+    /// Planner `ReviseStatement`
+    Correction,
+    /// Resumption offer: `AllowPartial` (asks) and `AllowHard` (announces)
+    ResumptionOffer,
+    Resumption,
+    /// Resumption offer for an intent that was cut off
+    InterruptedResumptionOffer,
+    InterruptedResumption,
+    /// Wake-up summary: `AllowPartial` (asks) and `AllowHard` (announces)
+    WarmStartOffer,
+    WarmStart,
+    /// Follow-up after a summarized answer (`BeginResponseWithFollowUp`)
+    FollowUpDetails,
+    /// Follow-up after a full answer
+    FollowUpMore,
+    /// Re-grounding after a bookmark is recalled
+    BookmarkRecall,
+    // `{topic}` per hypothesis class; Unknown: no lead intent
+    TopicInquiry,
+    TopicCommand,
+    TopicStatement,
+    TopicThinking,
+    TopicUnknown,
+    // `{intent}` per hypothesis class
+    NameInquiry,
+    NameCommand,
+    NameStatement,
+    NameThinking,
+    NameUnknown,
+    // `{held}`
+    HeldOne,
+    HeldMany,
+}
+
+impl PhraseAct {
+    pub const ALL: [PhraseAct; 24] = [
+        PhraseAct::Clarification, PhraseAct::PlanClarification, PhraseAct::Correction,
+        PhraseAct::ResumptionOffer, PhraseAct::Resumption, PhraseAct::InterruptedResumptionOffer, PhraseAct::InterruptedResumption,
+        PhraseAct::WarmStartOffer, PhraseAct::WarmStart, PhraseAct::FollowUpDetails, PhraseAct::FollowUpMore, PhraseAct::BookmarkRecall,
+        PhraseAct::TopicInquiry, PhraseAct::TopicCommand, PhraseAct::TopicStatement, PhraseAct::TopicThinking, PhraseAct::TopicUnknown,
+        PhraseAct::NameInquiry, PhraseAct::NameCommand, PhraseAct::NameStatement, PhraseAct::NameThinking, PhraseAct::NameUnknown,
+        PhraseAct::HeldOne, PhraseAct::HeldMany,
+    ];
+
+    /// Variables this act's templates may use.
+    pub fn variables(&self) -> &'static [&'static str] {
+        match self {
+            PhraseAct::PlanClarification => &["context"],
+            PhraseAct::Correction => &["correction"],
+            PhraseAct::ResumptionOffer | PhraseAct::Resumption
+            | PhraseAct::InterruptedResumptionOffer | PhraseAct::InterruptedResumption => &["topic", "intent"],
+            PhraseAct::WarmStartOffer | PhraseAct::WarmStart => &["held", "topic", "intent"],
+            PhraseAct::HeldMany => &["count"],
+            PhraseAct::BookmarkRecall => &["name", "summary"],
+            _ => &[],
+        }
+    }
+}
+
+const BUILTIN: &[(PhraseAct, &[&str])] = &[
+    (PhraseAct::Clarification, CLARIFICATION_VARIANTS),
+    (PhraseAct::PlanClarification, &["Could you clarify? Context: {context}"]),
+    (PhraseAct::Correction, &["Correction: {correction}"]),
+    (PhraseAct::ResumptionOffer, &[
+        "Earlier {topic}... shall we pick that back up?",
+        "A while ago {topic}... shall we pick that back up?",
+    ]),
+    (PhraseAct::Resumption, &["Earlier {topic}. Picking that back up.", "A while ago {topic}. Picking that back up."]),
+    (PhraseAct::InterruptedResumptionOffer, &[
+        "We got cut off earlier while {topic}... shall we pick that back up?",
+        "We were interrupted earlier while {topic}... shall we pick that back up?",
+    ]),
+    (PhraseAct::InterruptedResumption, &[
+        "We got cut off earlier while {topic}. Picking that back up.",
+        "We were interrupted earlier while {topic}. Picking that back up.",
+    ]),
+    (PhraseAct::WarmStartOffer, &[
+        "Welcome back... while you were away I kept {held} on hold: {topic}. Shall we pick it back up?",
+        "Good to have you back... I kept {held} on hold while you were away: {topic}. Shall we pick it back up?",
+    ]),
+    (PhraseAct::WarmStart, &[
+        "Welcome back. While you were away I kept {held} on hold: {topic}.",
+        "Good to have you back. I kept {held} on hold while you were away: {topic}.",
+    ]),
+    (PhraseAct::FollowUpDetails, &["Want the details?", "Should I go into more detail?"]),
+    (PhraseAct::FollowUpMore, &["Anything else you'd like to know about that?", "Want to hear more about that?"]),
+    (PhraseAct::BookmarkRecall, &["Back to {name}. Where we left it: {summary}.", "Picking up {name} again. We were at: {summary}."]),
+    (PhraseAct::TopicInquiry, &["you were asking about something"]),
+    (PhraseAct::TopicCommand, &["you wanted something done"]),
+    (PhraseAct::TopicStatement, &["you mentioned something"]),
+    (PhraseAct::TopicThinking, &["you were thinking something through"]),
+    (PhraseAct::TopicUnknown, &["something I wanted to bring up"]),
+    (PhraseAct::NameInquiry, &["your question"]),
+    (PhraseAct::NameCommand, &["your request"]),
+    (PhraseAct::NameStatement, &["what you mentioned"]),
+    (PhraseAct::NameThinking, &["your train of thought"]),
+    (PhraseAct::NameUnknown, &["what I wanted to bring up"]),
+    (PhraseAct::HeldOne, &["one thing"]),
+    (PhraseAct::HeldMany, &["{count} things"]),
+];
+
+type LocaleTable = BTreeMap<PhraseAct, Vec<String>>;
+
+#[derive(Debug, Clone, Deserialize)]
+struct PhraseFile {
+    #[serde(default)]
+    locale: Option<String>,
+    #[serde(default)]
+    templates: BTreeMap<String, LocaleTable>,
+}
+
+/// Validated template table with the locale in use.
+#[derive(Debug, Clone)]
+pub struct PhraseTemplates {
+    locale: String,
+    // Reply language the table has templates for, if not `locale` (`speak_in`)
+    speaking: Option<String>,
+    // Every locale has at least one variant for every act
+    table: BTreeMap<String, LocaleTable>,
+}
+
+impl Default for PhraseTemplates {
+    fn default() -> Self {
+        let english = BUILTIN.iter().map(|(act, variants)| (*act, variants.iter().map(|v| v.to_string()).collect())).collect();
+        Self { locale: DEFAULT_LOCALE.to_string(), speaking: None, table: BTreeMap::from([(DEFAULT_LOCALE.to_string(), english)]) }
+    }
+}
+
+/// Template file location (`NEXUS_PHRASES` overrides the working-directory default).
+pub fn phrases_path() -> PathBuf {
+    std::env::var("NEXUS_PHRASES").map(PathBuf::from).unwrap_or_else(|_| PathBuf::from(PHRASES_FILE))
+}
+
+// `{name}` placeholders in a template
+fn placeholders(template: &str) -> impl Iterator<Item = &str> {
+    template.split('{').skip(1).filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+}
+
+/// PURE FUNCTION: `template` with each `{name}` replaced by its value.
+pub fn fill(template: &str, vars: &[(&str, &str)]) -> String {
+    vars.iter().fold(template.to_string(), |text, (name, value)| text.replace(&format!("{{{}}}", name), value))
+}
+
+impl PhraseTemplates {
+    /// The built-in English table, shared.
+    pub fn builtin() -> &'static PhraseTemplates {
+        static BUILTIN_TEMPLATES: OnceLock<PhraseTemplates> = OnceLock::new();
+        BUILTIN_TEMPLATES.get_or_init(PhraseTemplates::default)
+    }
+
+    /// The built-in table with `json` (the template file format) applied, validated.
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        let file: PhraseFile = serde_json::from_str(json)?;
+        let mut templates = Self::default();
+        for (locale, acts) in file.templates {
+            templates.table.entry(locale).or_default().extend(acts);
+        }
+        if let Some(locale) = file.locale {
+            templates.locale = locale;
+        }
+        templates.validate()?;
+        Ok(templates)
+    }
+
+    /// Load `path` if present; the built-in table otherwise.
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Production default: the template file, or the built-in table if it cannot be read or is invalid.
+    pub fn from_default_path() -> Self {
+        let path = phrases_path();
+        Self::open(&path).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load phrase templates {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+
+    /// Every act has a variant in every locale, every variable is one its act provides, and the
+    /// selected locale exists.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if !self.table.contains_key(&self.locale) {
+            anyhow::bail!("no templates for locale {}", self.locale);
+        }
+        for (locale, acts) in &self.table {
+            for act in PhraseAct::ALL {
+                let variants = acts.get(&act).map(Vec::as_slice).unwrap_or_default();
+                if variants.iter().all(|v| v.trim().is_empty()) {
+                    anyhow::bail!("locale {}: no variant for {:?}", locale, act);
+                }
+                for name in variants.iter().flat_map(|v| placeholders(v)) {
+                    if !act.variables().contains(&name) {
+                        anyhow::bail!("locale {}: {:?} has no variable {{{}}}", locale, act, name);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Locale phrases are rendered in: the reply language if the table has it, else the
+    /// configured one.
+    pub fn locale(&self) -> &str {
+        self.speaking.as_deref().unwrap_or(&self.locale)
+    }
+
+    /// Locale selected by the template file (`DEFAULT_LOCALE` without one).
+    pub fn configured_locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Phrase in `language` (the reply language, see `kernel::language`) if the table has
+    /// templates for it; in the configured locale otherwise. Returns whether `language` is used.
+    pub fn speak_in(&mut self, language: &str) -> bool {
+        self.speaking = Some(language.to_string()).filter(|l| *l != self.locale && self.table.contains_key(l));
+        self.locale() == language
+    }
+
+    pub fn locales(&self) -> Vec<&str> {
+        self.table.keys().map(String::as_str).collect()
+    }
+
+    /// The act's templates in the selected locale, canonical first.
+    pub fn variants(&self, act: PhraseAct) -> &[String] {
+        self.table.get(self.locale()).and_then(|acts| acts.get(&act)).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// PURE FUNCTION: Every phrasing of `act` with `vars` filled in, canonical first.
+    pub fn render(&self, act: PhraseAct, vars: &[(&str, &str)]) -> Vec<String> {
+        self.variants(act).iter().filter(|v| !v.trim().is_empty()).map(|v| fill(v, vars)).collect()
+    }
+
+    // Canonical phrasing of a lookup act (topics, names, counts)
+    fn first(&self, act: PhraseAct, vars: &[(&str, &str)]) -> String {
+        self.render(act, vars).into_iter().next().unwrap_or_default()
+    }
+
+    // `{topic}` and `{intent}` for an intent's hypothesis class (None: no lead intent)
+    fn describe(&self, hypothesis: Option<&IntentHypothesis>) -> (String, String) {
+        let (topic, name) = match hypothesis {
+            Some(IntentHypothesis::Inquiry) => (PhraseAct::TopicInquiry, PhraseAct::NameInquiry),
+            Some(IntentHypothesis::Command | IntentHypothesis::SystemControl) => (PhraseAct::TopicCommand, PhraseAct::NameCommand),
+            Some(IntentHypothesis::Statement) => (PhraseAct::TopicStatement, PhraseAct::NameStatement),
+            Some(IntentHypothesis::Fragment | IntentHypothesis::ThinkingAloud) => (PhraseAct::TopicThinking, PhraseAct::NameThinking),
+            None => (PhraseAct::TopicUnknown, PhraseAct::NameUnknown),
+        };
+        (self.first(topic, &[]), self.first(name, &[]))
+    }
+
+    /// Non-leading clarifications.
+    pub fn clarifications(&self) -> Vec<String> {
+        self.render(PhraseAct::Clarification, &[])
+    }
+
+    /// Re-grounding offer for an intent resumed after long dormancy. Empty unless the gate allows.
+    pub fn resumption(&self, hypothesis: &IntentHypothesis, was_interrupted: bool, decision: &CrystallizationDecision) -> Vec<String> {
+        let act = match (decision, was_interrupted) {
+            (CrystallizationDecision::AllowPartial, true) => PhraseAct::InterruptedResumptionOffer,
+            (CrystallizationDecision::AllowHard, true) => PhraseAct::InterruptedResumption,
+            (CrystallizationDecision::AllowPartial, false) => PhraseAct::ResumptionOffer,
+            (CrystallizationDecision::AllowHard, false) => PhraseAct::Resumption,
+            _ => return Vec::new(),
+        };
+        let (topic, intent) = self.describe(Some(hypothesis));
+        self.render(act, &[("topic", &topic), ("intent", &intent)])
+    }
+
+    /// Wake-up summary of what is still on hold. Empty unless the gate allows.
+    pub fn warm_start(&self, summary: &WarmStart, decision: &CrystallizationDecision) -> Vec<String> {
+        let act = match decision {
+            CrystallizationDecision::AllowPartial => PhraseAct::WarmStartOffer,
+            CrystallizationDecision::AllowHard => PhraseAct::WarmStart,
+            _ => return Vec::new(),
+        };
+        let count = summary.held + summary.deferred_offer as usize;
+        let held = if count == 1 {
+            self.first(PhraseAct::HeldOne, &[])
+        } else {
+            self.first(PhraseAct::HeldMany, &[("count", &count.to_string())])
+        };
+        let (topic, intent) = self.describe(summary.lead.as_ref().map(|(_, hypothesis)| hypothesis));
+        self.render(act, &[("held", &held), ("topic", &topic), ("intent", &intent)])
+    }
+
+    /// Offer to elaborate after a `BeginResponseWithFollowUp` answer.
+    pub fn follow_up(&self, summary_first: bool) -> Vec<String> {
+        self.render(if summary_first { PhraseAct::FollowUpDetails } else { PhraseAct::FollowUpMore }, &[])
+    }
+
+    /// Re-grounding for a recalled bookmark: its `summary`, or (nothing quotable) what its lead
+    /// intent was about.
+    pub fn bookmark_recall(&self, name: &str, summary: &str, hypothesis: Option<&IntentHypothesis>) -> Vec<String> {
+        let summary = if summary.is_empty() { self.describe(hypothesis).0 } else { summary.trim_end_matches(['.', '?', '!']).to_string() };
+        self.render(PhraseAct::BookmarkRecall, &[("name", name), ("summary", &summary)])
+    }
+
+    /// Planner clarification around the planner's context text.
+    pub fn plan_clarification(&self, context: &str) -> String {
+        self.first(PhraseAct::PlanClarification, &[("context", context)])
+    }
+
+    /// Planner correction of an earlier statement.
+    pub fn correction(&self, correction: &str) -> String {
+        self.first(PhraseAct::Correction, &[("correction", correction)])
+    }
+}
@@ -0,0 +1,15 @@
+/// Playback position estimation for drivers whose TTS backend reports no progress (e.g. `say`).
+/// ~150 words per minute => ~400ms per word.
+const MS_PER_WORD: u64 = 400;
+const MIN_DURATION_MS: u64 = 300;
+
+/// PURE FUNCTION: Estimated speaking duration of `text`.
+pub fn estimate_duration_ms(text: &str) -> u64 {
+    let words = text.split_whitespace().count() as u64;
+    (words * MS_PER_WORD).max(MIN_DURATION_MS)
+}
+
+/// Midpoint marker for `AudioStatus::PlaybackProgress`.
+pub fn halfway_ms(text: &str) -> u64 {
+    estimate_duration_ms(text) / 2
+}
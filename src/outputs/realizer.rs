@@ -1,7 +1,78 @@
+use std::collections::VecDeque;
+
 use crate::kernel::crystallizer::{SymbolicSnapshot, CrystallizationDecision};
+use crate::kernel::intent::types::{DialogueAct, IntentHypothesis};
+use crate::kernel::warm_start::WarmStart;
+use crate::outputs::phrases::PhraseTemplates;
+use crate::outputs::style::{Formality, OutputChannel, OutputStyle};
+
+// Config Constants
+/// Realizations remembered by `PhraseMemory` (a variant said within the last few is avoided).
+pub const PHRASE_MEMORY_SIZE: usize = 6;
+
+pub use crate::outputs::phrases::CLARIFICATION_VARIANTS;
+
+const HEDGES: &[&str] = &["It seems that", "It looks like", "As far as I can tell,"];
+
+// Formal expands, Casual contracts (lowercase; a leading capital is kept).
+const CONTRACTIONS: &[(&str, &str)] = &[
+    ("i'm", "i am"), ("i'll", "i will"), ("i've", "i have"), ("you're", "you are"), ("we're", "we are"),
+    ("it's", "it is"), ("that's", "that is"), ("there's", "there is"), ("let's", "let us"),
+    ("don't", "do not"), ("doesn't", "does not"), ("didn't", "did not"), ("can't", "cannot"),
+    ("won't", "will not"), ("isn't", "is not"), ("aren't", "are not"), ("wasn't", "was not"),
+];
+
+/// Short-term anti-repetition memory: the last `PHRASE_MEMORY_SIZE` realizations chosen.
+/// Deterministic (no randomness), so replays and the TTS pre-warm see the same phrases.
+#[derive(Debug, Clone, Default)]
+pub struct PhraseMemory {
+    recent: VecDeque<String>,
+}
+
+impl PhraseMemory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Picks the first variant not said recently (else the one said longest ago) and remembers it.
+    /// Empty pool: empty string.
+    pub fn choose(&mut self, variants: Vec<String>) -> String {
+        let pick = variants.into_iter()
+            .min_by_key(|v| self.recent.iter().rposition(|r| r == v).map_or(0, |i| i + 1))
+            .unwrap_or_default();
+        if !pick.is_empty() {
+            self.recent.push_back(pick.clone());
+            if self.recent.len() > PHRASE_MEMORY_SIZE {
+                self.recent.pop_front();
+            }
+        }
+        pick
+    }
+
+    /// `vary_in` with the built-in templates.
+    pub fn vary(&mut self, act: DialogueAct) -> DialogueAct {
+        self.vary_in(PhraseTemplates::builtin(), act)
+    }
+
+    /// Re-phrases an act whose seed comes from a variant pool (the built-in clarification seeds)
+    /// with a variant from `templates`; other acts pass through.
+    pub fn vary_in(&mut self, templates: &PhraseTemplates, act: DialogueAct) -> DialogueAct {
+        match act {
+            DialogueAct::AskClarification(seed) if CLARIFICATION_VARIANTS.contains(&seed.as_str()) => {
+                DialogueAct::AskClarification(self.choose(templates.clarifications()))
+            }
+            act => act,
+        }
+    }
+}
 
 /// PURE FUNCTION: Converts a symbolic snapshot into text based on the decision.
 pub fn realize(snapshot: &SymbolicSnapshot, decision: &CrystallizationDecision) -> String {
+    realize_variants(snapshot, decision).into_iter().next().unwrap_or_default()
+}
+
+/// PURE FUNCTION: Every phrasing `realize` may take, canonical first.
+pub fn realize_variants(snapshot: &SymbolicSnapshot, decision: &CrystallizationDecision) -> Vec<String> {
     // Phase 6: Template-based realization
     
     // 1. Concat claims
@@ -12,18 +83,100 @@ pub fn realize(snapshot: &SymbolicSnapshot, decision: &CrystallizationDecision)
         
     if content.is_empty() {
         // Phase D Verification: Default speech
-        return "This is a long verification message to test the interruptibility of the Nexus system. Please speak now to test the kill switch.".to_string(); 
+        return vec!["This is a long verification message to test the interruptibility of the Nexus system. Please speak now to test the kill switch.".to_string()];
     }
     
     match decision {
         CrystallizationDecision::AllowPartial => {
             // Hedge
-            format!("It seems that {}...", content)
+            HEDGES.iter().map(|h| format!("{} {}...", h, content)).collect()
         }
         CrystallizationDecision::AllowHard => {
             // Direct
-            format!("{}.", content)
+            vec![format!("{}.", content)]
+        }
+        _ => Vec::new(), // Should not happen if called correctly
+    }
+}
+
+/// PURE FUNCTION: The first sentence of `text` (all of it if it is one sentence). The short
+/// answer of `Intent::BeginResponseWithFollowUp { summary_first: true }`.
+pub fn summary_sentence(text: &str) -> String {
+    let mut splitter = crate::outputs::streaming::SentenceSplitter::new();
+    splitter.push(text).into_iter().next().or_else(|| splitter.finish()).unwrap_or_default()
+}
+
+/// PURE FUNCTION: Re-grounding sentence for an intent resumed after long dormancy.
+/// Content-free by design: only the hypothesis class is referenced, never user text.
+pub fn realize_resumption(hypothesis: &IntentHypothesis, was_interrupted: bool, decision: &CrystallizationDecision) -> String {
+    realize_resumption_variants(hypothesis, was_interrupted, decision).into_iter().next().unwrap_or_default()
+}
+
+/// PURE FUNCTION: Every phrasing `realize_resumption` may take, canonical first (built-in templates).
+pub fn realize_resumption_variants(hypothesis: &IntentHypothesis, was_interrupted: bool, decision: &CrystallizationDecision) -> Vec<String> {
+    PhraseTemplates::builtin().resumption(hypothesis, was_interrupted, decision)
+}
+
+/// PURE FUNCTION: Wake-up summary of what is still on hold. Content-free like `realize_resumption`.
+pub fn realize_warm_start(summary: &WarmStart, decision: &CrystallizationDecision) -> String {
+    realize_warm_start_variants(summary, decision).into_iter().next().unwrap_or_default()
+}
+
+/// PURE FUNCTION: Every phrasing `realize_warm_start` may take, canonical first (built-in templates).
+pub fn realize_warm_start_variants(summary: &WarmStart, decision: &CrystallizationDecision) -> Vec<String> {
+    PhraseTemplates::builtin().warm_start(summary, decision)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '\''
+}
+
+// Whole-word, ASCII case-insensitive replacement. `mid_clause`: only where another word follows
+// ("I am here" contracts, "that is what it is." keeps its final "it is").
+fn replace_phrase(text: &str, from: &str, to: &str, mid_clause: bool) -> String {
+    let lower = text.to_ascii_lowercase();
+    let mut out = String::with_capacity(text.len());
+    let (mut copied, mut search) = (0, 0);
+    while let Some(found) = lower[search..].find(from) {
+        let start = search + found;
+        let end = start + from.len();
+        let starts_word = !lower[..start].chars().next_back().is_some_and(is_word_char);
+        let next = lower[end..].chars().next();
+        let ends_word = if mid_clause { next == Some(' ') } else { !next.is_some_and(is_word_char) };
+        if starts_word && ends_word {
+            out.push_str(&text[copied..start]);
+            if text[start..].starts_with(|c: char| c.is_uppercase()) {
+                let mut chars = to.chars();
+                out.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+                out.push_str(chars.as_str());
+            } else {
+                out.push_str(to);
+            }
+            copied = end;
         }
-        _ => String::new(), // Should not happen if called correctly
+        search = end;
+    }
+    out.push_str(&text[copied..]);
+    out
+}
+
+fn is_emoji(c: char) -> bool {
+    matches!(c as u32, 0x1F000..=0x1FAFF | 0x2600..=0x27BF | 0x2B00..=0x2BFF | 0xFE0F | 0x200D | 0x20E3)
+}
+
+/// PURE FUNCTION: Applies an output style to finished text: formality (contractions), and emoji,
+/// which only survive in the text channel when the style allows them.
+pub fn restyle(text: &str, style: &OutputStyle, channel: OutputChannel) -> String {
+    let mut text = text.to_string();
+    for (short, long) in CONTRACTIONS {
+        text = match style.formality {
+            Formality::Formal => replace_phrase(&text, short, long, false),
+            Formality::Casual => replace_phrase(&text, long, short, true),
+            Formality::Neutral => break,
+        };
+    }
+    if (style.emoji && channel == OutputChannel::Text) || !text.chars().any(is_emoji) {
+        return text;
     }
+    text.chars().filter(|c| !is_emoji(*c)).collect::<String>().split_whitespace().collect::<Vec<_>>().join(" ")
 }
@@ -0,0 +1,204 @@
+//! Output Safety Filter.
+//!
+//! Last check on LLM-generated text before it is spoken: planner text in the kernel
+//! (before `SpawnAudio`) and generated speech in drivers (before playback). Three heuristic
+//! checks, each switchable: profanity, PII (email addresses, phone/card-like digit runs) and
+//! prompt-injection echoes (the model repeating injected instructions or its system prompt).
+//!
+//! On a hit the `FilterPolicy` decides: regenerate once, redact the offending words, or stay
+//! silent. An injection echo cannot be redacted into something safe and is always dropped.
+//! Kernel-authored text (realizer templates) is not filtered.
+
+use serde::{Deserialize, Serialize};
+
+use crate::kernel::telemetry::event::TelemetryEvent;
+
+// Config Constants
+/// Spoken (and captioned) in place of redacted words.
+pub const SPOKEN_REDACTION: &str = "(redacted)";
+/// Digit runs at least this long read as a phone, card or account number.
+const PII_MIN_DIGITS: usize = 7;
+
+const PROFANITY_TERMS: &[&str] = &[
+    "fuck", "fucking", "fucker", "shit", "bullshit", "bitch", "asshole", "bastard", "cunt", "motherfucker", "dickhead",
+];
+// Matched on normalised words, so punctuation and case don't matter.
+const INJECTION_PHRASES: &[&str] = &[
+    "ignore previous instructions", "ignore all previous instructions", "ignore the previous instructions",
+    "ignore the above", "disregard previous instructions", "disregard all previous instructions",
+    "system prompt", "my instructions are", "developer mode", "you are now",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FilterReason {
+    Profanity,
+    Pii,
+    InjectionEcho,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FilterPolicy {
+    /// Ask for new text once; still unsafe -> silence
+    Regenerate,
+    /// Replace offending words with `SPOKEN_REDACTION`
+    #[default]
+    Redact,
+    /// Say nothing
+    Silence,
+}
+
+/// What happened to a filtered output (telemetry).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FilterAction {
+    Redacted,
+    Regenerated,
+    Silenced,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SafetyConfig {
+    pub profanity: bool,
+    pub pii: bool,
+    pub injection_echo: bool,
+    pub policy: FilterPolicy,
+}
+
+impl Default for SafetyConfig {
+    fn default() -> Self {
+        Self { profanity: true, pii: true, injection_echo: true, policy: FilterPolicy::default() }
+    }
+}
+
+impl SafetyConfig {
+    /// All checks off.
+    pub const OFF: SafetyConfig = SafetyConfig { profanity: false, pii: false, injection_echo: false, policy: FilterPolicy::Redact };
+
+    /// From `NEXUS_OUTPUT_FILTER`: `off`, `redact` (default), `regenerate` or `silence`.
+    pub fn from_env() -> Self {
+        match std::env::var("NEXUS_OUTPUT_FILTER").as_deref() {
+            Ok("off") => Self::OFF,
+            Ok("regenerate") => Self { policy: FilterPolicy::Regenerate, ..Self::default() },
+            Ok("silence") => Self { policy: FilterPolicy::Silence, ..Self::default() },
+            _ => Self::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterOutcome {
+    Clean,
+    /// Speak this instead
+    Redacted { text: String, reason: FilterReason },
+    /// Drop this text and ask the generator again
+    Regenerate(FilterReason),
+    /// Drop this text, say nothing
+    Silenced(FilterReason),
+}
+
+impl FilterOutcome {
+    /// Content-free record of a filtered output (`None` when clean).
+    pub fn telemetry(&self) -> Option<TelemetryEvent> {
+        let (reason, action) = match self {
+            FilterOutcome::Clean => return None,
+            FilterOutcome::Redacted { reason, .. } => (*reason, FilterAction::Redacted),
+            FilterOutcome::Regenerate(reason) => (*reason, FilterAction::Regenerated),
+            FilterOutcome::Silenced(reason) => (*reason, FilterAction::Silenced),
+        };
+        Some(TelemetryEvent::OutputFiltered { reason, action })
+    }
+}
+
+fn normalise(word: &str) -> String {
+    word.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+fn is_profane(word: &str) -> bool {
+    let w = normalise(word);
+    PROFANITY_TERMS.iter().any(|t| w == *t || w.strip_suffix('s') == Some(*t))
+}
+
+fn is_email(word: &str) -> bool {
+    let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+    match word.split_once('@') {
+        Some((user, domain)) => !user.is_empty() && domain.contains('.') && !domain.starts_with('.'),
+        None => false,
+    }
+}
+
+/// Part of a number as people write it: digits and separators only ("555-0100", "(415)", "+1").
+fn is_numeric(word: &str) -> bool {
+    let word = word.trim_end_matches(['.', ',', ';', ':', '!', '?']);
+    word.chars().any(|c| c.is_ascii_digit()) && word.chars().all(|c| c.is_ascii_digit() || "+-().".contains(c))
+}
+
+fn digits(words: &[&str]) -> usize {
+    words.iter().flat_map(|w| w.chars()).filter(char::is_ascii_digit).count()
+}
+
+/// Word spans to redact: `(start, end)` into `words`, with the reason.
+fn offending_spans(words: &[&str], config: &SafetyConfig) -> Vec<(usize, usize, FilterReason)> {
+    let mut spans = Vec::new();
+    let mut i = 0;
+    while i < words.len() {
+        if config.pii && is_numeric(words[i]) {
+            // Numbers are often spoken in groups: "555 123 4567"
+            let end = (i..words.len()).find(|&j| !is_numeric(words[j])).unwrap_or(words.len());
+            if digits(&words[i..end]) >= PII_MIN_DIGITS {
+                spans.push((i, end, FilterReason::Pii));
+            }
+            i = end;
+            continue;
+        }
+        if config.pii && is_email(words[i]) {
+            spans.push((i, i + 1, FilterReason::Pii));
+        } else if config.profanity && is_profane(words[i]) {
+            spans.push((i, i + 1, FilterReason::Profanity));
+        }
+        i += 1;
+    }
+    spans
+}
+
+fn echoes_injection(text: &str) -> bool {
+    let words: Vec<String> = text.split_whitespace().map(normalise).filter(|w| !w.is_empty()).collect();
+    let normalised = format!(" {} ", words.join(" "));
+    INJECTION_PHRASES.iter().any(|p| normalised.contains(&format!(" {} ", p)))
+}
+
+/// PURE FUNCTION: First reason `text` must not be spoken as is (`None`: safe).
+pub fn check(text: &str, config: &SafetyConfig) -> Option<FilterReason> {
+    if config.injection_echo && echoes_injection(text) {
+        return Some(FilterReason::InjectionEcho);
+    }
+    let words: Vec<&str> = text.split_whitespace().collect();
+    offending_spans(&words, config).first().map(|(_, _, reason)| *reason)
+}
+
+/// PURE FUNCTION: `text` with offending words replaced by `SPOKEN_REDACTION`.
+pub fn redact(text: &str, config: &SafetyConfig) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let spans = offending_spans(&words, config);
+    let mut out: Vec<&str> = Vec::with_capacity(words.len());
+    let mut i = 0;
+    for (start, end, _) in spans {
+        out.extend_from_slice(&words[i..start]);
+        out.push(SPOKEN_REDACTION);
+        i = end;
+    }
+    out.extend_from_slice(&words[i..]);
+    out.join(" ")
+}
+
+/// PURE FUNCTION: Apply the policy. `may_regenerate` is false once the text is already a retry.
+pub fn screen(text: &str, config: &SafetyConfig, may_regenerate: bool) -> FilterOutcome {
+    let Some(reason) = check(text, config) else {
+        return FilterOutcome::Clean;
+    };
+    match config.policy {
+        FilterPolicy::Regenerate if may_regenerate => FilterOutcome::Regenerate(reason),
+        FilterPolicy::Redact if reason != FilterReason::InjectionEcho => {
+            FilterOutcome::Redacted { text: redact(text, config), reason }
+        }
+        _ => FilterOutcome::Silenced(reason),
+    }
+}
@@ -0,0 +1,273 @@
+//! Speakable Text (audio channel only).
+//!
+//! Realized text is written to be read: "2025-11-03", "42km", "e.g.", `max_retries`. Spoken
+//! verbatim, TTS reads these digit by digit, as letters or not at all. `speakable` rewrites a
+//! reply for the voice right before synthesis, per locale (the phrase templates' locale):
+//! - ISO dates become "November 3, 2025" / "3. November 2025",
+//! - numbers lose their grouping separators, decimals are read with the locale's word,
+//! - units and percent after a number are spelled out ("42 kilometers"),
+//! - abbreviations are expanded ("for example"),
+//! - code identifiers are split into words (`max_retries`, `maxRetries` -> "max retries").
+//!
+//! Captions and the text channel keep the original text. Locales without a lexicon only get
+//! identifier splitting.
+
+// Config Constants
+// Punctuation kept around a token (backticks are dropped: "`max_retries`" is an identifier)
+const LEADING_PUNCTUATION: &[char] = &['(', '[', '"', '\'', '`'];
+const TRAILING_PUNCTUATION: &[char] = &[',', ';', ':', '!', '?', ')', ']', '"', '\'', '`'];
+
+/// Words for one locale. Units are (symbol, singular, plural).
+struct SpeechLexicon {
+    months: [&'static str; 12],
+    // "{month} {day}, {year}" or "{day}. {month} {year}"
+    day_first: bool,
+    decimal_separator: char,
+    group_separator: char,
+    decimal_word: &'static str,
+    minus_word: &'static str,
+    units: &'static [(&'static str, &'static str, &'static str)],
+    abbreviations: &'static [(&'static str, &'static str)],
+}
+
+const ENGLISH: SpeechLexicon = SpeechLexicon {
+    months: ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"],
+    day_first: false,
+    decimal_separator: '.',
+    group_separator: ',',
+    decimal_word: "point",
+    minus_word: "minus",
+    units: &[
+        ("%", "percent", "percent"),
+        ("km", "kilometer", "kilometers"),
+        ("m", "meter", "meters"),
+        ("cm", "centimeter", "centimeters"),
+        ("mm", "millimeter", "millimeters"),
+        ("kg", "kilogram", "kilograms"),
+        ("g", "gram", "grams"),
+        ("mg", "milligram", "milligrams"),
+        ("l", "liter", "liters"),
+        ("ml", "milliliter", "milliliters"),
+        ("km/h", "kilometer per hour", "kilometers per hour"),
+        ("mph", "mile per hour", "miles per hour"),
+        ("°C", "degree Celsius", "degrees Celsius"),
+        ("°F", "degree Fahrenheit", "degrees Fahrenheit"),
+        ("GB", "gigabyte", "gigabytes"),
+        ("MB", "megabyte", "megabytes"),
+        ("KB", "kilobyte", "kilobytes"),
+        ("ms", "millisecond", "milliseconds"),
+        ("s", "second", "seconds"),
+        ("min", "minute", "minutes"),
+        ("h", "hour", "hours"),
+    ],
+    abbreviations: &[
+        ("e.g.", "for example"),
+        ("i.e.", "that is"),
+        ("etc.", "et cetera"),
+        ("vs.", "versus"),
+        ("approx.", "approximately"),
+        ("Dr.", "Doctor"),
+        ("Mr.", "Mister"),
+        ("Mrs.", "Missus"),
+    ],
+};
+
+const GERMAN: SpeechLexicon = SpeechLexicon {
+    months: ["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"],
+    day_first: true,
+    decimal_separator: ',',
+    group_separator: '.',
+    decimal_word: "Komma",
+    minus_word: "minus",
+    units: &[
+        ("%", "Prozent", "Prozent"),
+        ("km", "Kilometer", "Kilometer"),
+        ("m", "Meter", "Meter"),
+        ("cm", "Zentimeter", "Zentimeter"),
+        ("mm", "Millimeter", "Millimeter"),
+        ("kg", "Kilogramm", "Kilogramm"),
+        ("g", "Gramm", "Gramm"),
+        ("mg", "Milligramm", "Milligramm"),
+        ("l", "Liter", "Liter"),
+        ("ml", "Milliliter", "Milliliter"),
+        ("km/h", "Kilometer pro Stunde", "Kilometer pro Stunde"),
+        ("mph", "Meile pro Stunde", "Meilen pro Stunde"),
+        ("°C", "Grad Celsius", "Grad Celsius"),
+        ("°F", "Grad Fahrenheit", "Grad Fahrenheit"),
+        ("GB", "Gigabyte", "Gigabyte"),
+        ("MB", "Megabyte", "Megabyte"),
+        ("KB", "Kilobyte", "Kilobyte"),
+        ("ms", "Millisekunde", "Millisekunden"),
+        ("s", "Sekunde", "Sekunden"),
+        ("min", "Minute", "Minuten"),
+        ("h", "Stunde", "Stunden"),
+    ],
+    abbreviations: &[
+        ("z.B.", "zum Beispiel"),
+        ("d.h.", "das heißt"),
+        ("usw.", "und so weiter"),
+        ("bzw.", "beziehungsweise"),
+        ("ca.", "circa"),
+        ("Dr.", "Doktor"),
+    ],
+};
+
+// "en-US" and "en_GB" speak like "en"
+fn lexicon(locale: &str) -> Option<&'static SpeechLexicon> {
+    match locale.split(['-', '_']).next().unwrap_or_default().to_lowercase().as_str() {
+        "en" => Some(&ENGLISH),
+        "de" => Some(&GERMAN),
+        _ => None,
+    }
+}
+
+/// `text` as it should be spoken in `locale` (a `PhraseTemplates::locale()`).
+/// PURE FUNCTION: the text channel keeps `text` as is.
+pub fn speakable(text: &str, locale: &str) -> String {
+    let lex = lexicon(locale);
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let mut spoken = Vec::with_capacity(tokens.len());
+    // Some(singular) right after a bare number: "42 km"
+    let mut after_number = None;
+    for (i, token) in tokens.iter().enumerate() {
+        let (word, number) = speak_token(token, lex, after_number, i + 1 == tokens.len());
+        after_number = number;
+        spoken.push(word);
+    }
+    spoken.join(" ")
+}
+
+// One whitespace-separated token, surrounding punctuation kept
+fn speak_token(token: &str, lex: Option<&SpeechLexicon>, after_number: Option<bool>, last: bool) -> (String, Option<bool>) {
+    // "run()" is said "run"
+    let token = token.replace("()", "");
+    let token = token.as_str();
+    let core = token.trim_start_matches(LEADING_PUNCTUATION);
+    let lead = token[..token.len() - core.len()].replace('`', "");
+    let body = core.trim_end_matches(TRAILING_PUNCTUATION);
+    let trail = core[body.len()..].replace('`', "");
+
+    // Abbreviations keep their dots; a sentence-final one keeps the full stop
+    if let Some((_, expansion)) = lex.and_then(|lex| lex.abbreviations.iter().find(|(abbr, _)| abbr.eq_ignore_ascii_case(body))) {
+        let stop = if last && trail.is_empty() { "." } else { "" };
+        return (format!("{}{}{}{}", lead, expansion, stop, trail), None);
+    }
+    // Otherwise a full stop after the word is punctuation
+    let word = body.trim_end_matches('.');
+    let (spoken, number) = speak_word(word, lex, after_number);
+    (format!("{}{}{}{}", lead, spoken, &body[word.len()..], trail), number)
+}
+
+fn speak_word(word: &str, lex: Option<&SpeechLexicon>, after_number: Option<bool>) -> (String, Option<bool>) {
+    if let Some(lex) = lex {
+        if let Some(date) = speak_date(word, lex) {
+            return (date, None);
+        }
+        if let Some((number, singular)) = speak_number(word, lex) {
+            return (number, Some(singular));
+        }
+        if let Some(measure) = speak_measure(word, lex) {
+            return (measure, None);
+        }
+        if let Some(unit) = after_number.and_then(|singular| unit_word(word, lex, singular)) {
+            return (unit.to_string(), None);
+        }
+    }
+    (split_identifier(word).unwrap_or_else(|| word.to_string()), None)
+}
+
+fn unit_word(symbol: &str, lex: &SpeechLexicon, singular: bool) -> Option<&'static str> {
+    lex.units.iter()
+        .find(|(s, _, _)| *s == symbol)
+        .map(|(_, one, many)| if singular { *one } else { *many })
+}
+
+// "2025-11-03"
+fn speak_date(word: &str, lex: &SpeechLexicon) -> Option<String> {
+    let mut parts = word.split('-');
+    let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+    let digits = |s: &str, n: usize| s.len() == n && s.bytes().all(|b| b.is_ascii_digit());
+    if parts.next().is_some() || !digits(year, 4) || !digits(month, 2) || !digits(day, 2) {
+        return None;
+    }
+    let (month, day): (usize, u32) = (month.parse().ok()?, day.parse().ok()?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let month = lex.months[month - 1];
+    Some(if lex.day_first {
+        format!("{}. {} {}", day, month, year)
+    } else {
+        format!("{} {}, {}", month, day, year)
+    })
+}
+
+// Bare number in the locale's notation: (spoken, is exactly one)
+fn speak_number(word: &str, lex: &SpeechLexicon) -> Option<(String, bool)> {
+    let (minus, digits) = match word.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, word),
+    };
+    let (whole, fraction) = match digits.split_once(lex.decimal_separator) {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (digits, None),
+    };
+    let all_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if fraction.is_some_and(|f| !all_digits(f)) {
+        return None;
+    }
+    // Grouped: 1-3 leading digits, then groups of exactly three
+    let groups: Vec<&str> = whole.split(lex.group_separator).collect();
+    let grouped = groups.len() > 1;
+    if !groups.iter().all(|g| all_digits(g)) || (grouped && (groups[0].len() > 3 || groups[1..].iter().any(|g| g.len() != 3))) {
+        return None;
+    }
+    let whole = groups.concat();
+    let singular = !minus && fraction.is_none() && whole == "1";
+    let mut spoken = if minus { format!("{} {}", lex.minus_word, whole) } else { whole };
+    if let Some(fraction) = fraction {
+        spoken = format!("{} {} {}", spoken, lex.decimal_word, fraction);
+    }
+    Some((spoken, singular))
+}
+
+// "42km", "5%", "21.5°C"
+fn speak_measure(word: &str, lex: &SpeechLexicon) -> Option<String> {
+    let split = word.char_indices()
+        .find(|(i, c)| !(c.is_ascii_digit() || *c == lex.decimal_separator || *c == lex.group_separator || (*i == 0 && *c == '-')))
+        .map(|(i, _)| i)?;
+    let (number, symbol) = word.split_at(split);
+    let (number, singular) = speak_number(number, lex)?;
+    Some(format!("{} {}", number, unit_word(symbol, lex, singular)?))
+}
+
+// `max_retries`, `maxRetries`, `std::fs` -> words. None for ordinary words.
+fn split_identifier(word: &str) -> Option<String> {
+    let parts: Vec<&str> = word.split("::").collect();
+    if parts.iter().any(|p| p.is_empty() || !p.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')) {
+        return None;
+    }
+    let words: Vec<String> = parts.iter()
+        .flat_map(|p| p.split('_'))
+        .filter(|p| !p.is_empty())
+        .flat_map(split_camel)
+        .collect();
+    let code = parts.len() > 1 || word.contains('_') || words.len() > 1;
+    (code && !words.is_empty()).then(|| words.join(" "))
+}
+
+// Split before an uppercase letter that follows two or more lowercase ones ("iPhone" stays whole)
+fn split_camel(piece: &str) -> Vec<String> {
+    let mut words = vec![String::new()];
+    let mut lower_run = 0;
+    for c in piece.chars() {
+        if c.is_ascii_uppercase() && lower_run >= 2 {
+            words.push(String::new());
+        }
+        lower_run = if c.is_ascii_lowercase() { lower_run + 1 } else { 0 };
+        if let Some(current) = words.last_mut() {
+            current.push(c);
+        }
+    }
+    words
+}
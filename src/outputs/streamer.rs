@@ -0,0 +1,175 @@
+//! Chunked Speech: kernel outputs spoken a sentence at a time.
+//!
+//! A kernel output used to reach the driver as one `SpawnAudio` carrying the whole text: one
+//! player ran to the end, and an interruption could only cut it "somewhere". With
+//! `ReactorConfig::chunked_speech`, `SpeechStreamer::split` cuts the output into sentences
+//! (`streaming::SentenceSplitter`) and emits one `SpawnAudio` per sentence under a child
+//! `OutputId` (`chunk_id`). The output in state stays the parent:
+//! - the driver plays the chunks back to back (`play_chunks`), reporting `PlaybackStarted` and
+//!   `PlaybackEnded` for the parent and `PlaybackCompleted` for each child as it finishes;
+//! - the kernel turns each completed child into playback progress on the parent, and the last
+//!   one completes the parent.
+//!
+//! `StopAudio` kills the chunk playing and drops the queued ones. Chunks already heard stay
+//! heard: the parent's progress (and `InterruptionRecord::spoken_chars`) ends on a sentence.
+//! Child ordinals live above `CHUNK_ORDINAL_BASE`, clear of the ordinals a tick gives its outputs.
+
+use std::collections::HashMap;
+
+use tokio::sync::{mpsc, oneshot};
+
+use crate::kernel::event::{Event, OutputId};
+use crate::kernel::scheduler::SideEffect;
+use crate::outputs::streaming::SentenceSplitter;
+
+// Config Constants
+pub const CHUNK_ORDINAL_BASE: u16 = 0x8000;
+/// Sentences per output; the rest is spoken with the last one.
+pub const MAX_CHUNKS: u16 = 64;
+/// Outputs split while their playback is still outstanding (the oldest is forgotten).
+const MAX_STREAMS: usize = 16;
+
+/// PURE FUNCTION: Child id of sentence `index` of `parent`. `None` if `parent` is itself a chunk
+/// or its ordinal is beyond what the chunk range holds.
+pub fn chunk_id(parent: OutputId, index: u16) -> Option<OutputId> {
+    if parent.ordinal >= CHUNK_ORDINAL_BASE / MAX_CHUNKS || index >= MAX_CHUNKS {
+        return None;
+    }
+    Some(OutputId { tick: parent.tick, ordinal: CHUNK_ORDINAL_BASE + parent.ordinal * MAX_CHUNKS + index })
+}
+
+/// PURE FUNCTION: The parent and sentence index of a child id (`None` for a whole output).
+pub fn parent_of(id: OutputId) -> Option<(OutputId, u16)> {
+    let offset = id.ordinal.checked_sub(CHUNK_ORDINAL_BASE)?;
+    Some((OutputId { tick: id.tick, ordinal: offset / MAX_CHUNKS }, offset % MAX_CHUNKS))
+}
+
+/// PURE FUNCTION: `text` as spoken sentences, at most `MAX_CHUNKS`.
+pub fn sentence_chunks(text: &str) -> Vec<String> {
+    let mut splitter = SentenceSplitter::new();
+    let mut chunks = splitter.push(text);
+    chunks.extend(splitter.finish());
+    if chunks.len() > MAX_CHUNKS as usize {
+        let rest = chunks.split_off(MAX_CHUNKS as usize - 1).join(" ");
+        chunks.push(rest);
+    }
+    chunks
+}
+
+/// What a `PlaybackCompleted` means for the output it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkCompletion {
+    /// Not a chunk: the output itself finished
+    Whole,
+    /// A sentence finished; `percent` of the parent's text has been heard
+    Partial { parent: OutputId, percent: u8 },
+    /// The last sentence finished: the parent is complete
+    Last(OutputId),
+}
+
+#[derive(Debug)]
+struct Stream {
+    // Characters per chunk, in order
+    chars: Vec<usize>,
+    completed: usize,
+}
+
+/// Kernel side: which outputs were split, and how far their playback got.
+#[derive(Debug, Default)]
+pub struct SpeechStreamer {
+    streams: HashMap<OutputId, Stream>,
+}
+
+impl SpeechStreamer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The effects speaking `text` as output `id`: one `SpawnAudio` per sentence, or the
+    /// output as it is when it is a single sentence (or cannot have chunk ids).
+    pub fn split(&mut self, id: OutputId, text: String) -> Vec<SideEffect> {
+        let chunks = sentence_chunks(&text);
+        let ids: Option<Vec<OutputId>> = (0..chunks.len() as u16).map(|i| chunk_id(id, i)).collect();
+        let Some(ids) = ids.filter(|_| chunks.len() > 1) else {
+            return vec![SideEffect::SpawnAudio(id, text)];
+        };
+        if self.streams.len() >= MAX_STREAMS {
+            if let Some(oldest) = self.streams.keys().min_by_key(|k| (k.tick, k.ordinal)).copied() {
+                self.streams.remove(&oldest);
+            }
+        }
+        self.streams.insert(id, Stream { chars: chunks.iter().map(|c| c.chars().count()).collect(), completed: 0 });
+        ids.into_iter().zip(chunks).map(|(chunk, text)| SideEffect::SpawnAudio(chunk, text)).collect()
+    }
+
+    /// A `PlaybackCompleted` for `id` arrived.
+    pub fn completed(&mut self, id: OutputId) -> ChunkCompletion {
+        let Some((parent, index)) = parent_of(id) else {
+            return ChunkCompletion::Whole;
+        };
+        let Some(stream) = self.streams.get_mut(&parent) else {
+            // Forgotten (or never split here): nothing left to wait for
+            return ChunkCompletion::Last(parent);
+        };
+        stream.completed = stream.completed.max(index as usize + 1);
+        if stream.completed >= stream.chars.len() {
+            self.streams.remove(&parent);
+            return ChunkCompletion::Last(parent);
+        }
+        let total: usize = stream.chars.iter().sum::<usize>().max(1);
+        let heard: usize = stream.chars[..stream.completed].iter().sum();
+        ChunkCompletion::Partial { parent, percent: (heard * 100 / total) as u8 }
+    }
+
+    /// The parent's playback ended (finished or stopped): remaining chunks will not play.
+    pub fn ended(&mut self, parent: OutputId) {
+        self.streams.remove(&parent);
+    }
+
+    /// Outputs still playing in chunks.
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+}
+
+/// Driver: play queued chunks one after another until the queue closes (the output's batch is
+/// done) or `stop` fires (also when its sender is dropped). Each chunk that plays to the end is
+/// reported through `report`. `true` if every chunk played to the end.
+pub async fn play_chunks(
+    mut chunks: mpsc::UnboundedReceiver<(OutputId, tokio::process::Command)>,
+    mut stop: oneshot::Receiver<()>,
+    tx: mpsc::Sender<Event>,
+    report: impl Fn(OutputId) -> Event,
+) -> bool {
+    loop {
+        let next = tokio::select! {
+            next = chunks.recv() => next,
+            _ = &mut stop => return false,
+        };
+        let Some((id, mut command)) = next else {
+            return true;
+        };
+        let mut child = match command.kill_on_drop(true).spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                tracing::warn!("Failed to spawn chunk playback: {}", e);
+                return false;
+            }
+        };
+        let finished = tokio::select! {
+            res = child.wait() => res.map(|s| s.success()).unwrap_or(false),
+            _ = &mut stop => {
+                let _ = child.kill().await;
+                return false;
+            }
+        };
+        if !finished {
+            return false;
+        }
+        let _ = tx.send(report(id)).await;
+    }
+}
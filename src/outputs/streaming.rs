@@ -0,0 +1,91 @@
+//! Streamed Speech: sentence-at-a-time generation and playback.
+//!
+//! Drivers do not wait for the whole generated reply. `SentenceSplitter` cuts the LLM token
+//! stream into sentences; each one is screened and restyled on its own, and the first starts
+//! playing while the rest are still generating. `play_in_order` plays the queued sentences
+//! back to back. Cancelling the output (StopAudio) aborts the generation task, which drops the
+//! HTTP stream (llama-server stops generating), and stops the player mid-sentence.
+//! `TelemetryEvent::FirstAudio` records RequestSpeech -> first sentence playing.
+
+use tokio::sync::{mpsc, oneshot};
+
+// Config Constants
+/// Shorter "sentences" are held and joined to the next one ("Dr. Smith", "Yes. Which one?").
+const MIN_SENTENCE_CHARS: usize = 12;
+
+/// Sentence boundaries in streamed text. A terminator only ends a sentence once the next
+/// character is known to be whitespace ("3.5" arrives as "3." then "5").
+#[derive(Debug, Clone, Default)]
+pub struct SentenceSplitter {
+    buffer: String,
+}
+
+impl SentenceSplitter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append streamed text; returns the sentences it completed (trimmed, in order).
+    pub fn push(&mut self, text: &str) -> Vec<String> {
+        self.buffer.push_str(text);
+        let mut sentences = Vec::new();
+        while let Some(end) = boundary(&self.buffer) {
+            sentences.push(self.buffer[..end].trim().to_string());
+            self.buffer.drain(..end);
+        }
+        sentences
+    }
+
+    /// End of the stream: whatever is left, if anything.
+    pub fn finish(&mut self) -> Option<String> {
+        let rest = std::mem::take(&mut self.buffer);
+        let rest = rest.trim();
+        (!rest.is_empty()).then(|| rest.to_string())
+    }
+}
+
+// Byte offset just past the first sentence end in `text`
+fn boundary(text: &str) -> Option<usize> {
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let end = i + c.len_utf8();
+        let ends = c == '\n'
+            || (matches!(c, '.' | '!' | '?' | '…') && chars.peek().is_some_and(|(_, next)| next.is_whitespace()));
+        if ends && text[..end].trim().chars().count() >= MIN_SENTENCE_CHARS {
+            return Some(end);
+        }
+    }
+    None
+}
+
+/// Driver: run queued sentence players one after another until the queue closes (generation
+/// done) or `stop` fires (also when its sender is dropped). `true` if every sentence played to
+/// the end.
+pub async fn play_in_order(mut players: mpsc::UnboundedReceiver<tokio::process::Command>, mut stop: oneshot::Receiver<()>) -> bool {
+    loop {
+        let next = tokio::select! {
+            next = players.recv() => next,
+            _ = &mut stop => return false,
+        };
+        let Some(mut command) = next else {
+            return true;
+        };
+        let mut child = match command.kill_on_drop(true).spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                tracing::warn!("Failed to spawn sentence playback: {}", e);
+                return false;
+            }
+        };
+        let finished = tokio::select! {
+            res = child.wait() => res.map(|s| s.success()).unwrap_or(false),
+            _ = &mut stop => {
+                let _ = child.kill().await;
+                return false;
+            }
+        };
+        if !finished {
+            return false;
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Output Style Preferences.
+//!
+//! How replies are phrased, kept per profile (`kernel::profile`): formality, emoji in the text
+//! channel, and profanity tolerance. Consumers:
+//! - the realizer applies formality and emoji to the text it hands out (`realizer::restyle`),
+//! - the LLM speech prompt carries all three (`prompt_guidance`),
+//! - profanity tolerance relaxes the output safety filter's profanity check (`screening`).
+//!
+//! Changed from the settings panel or by voice ("be more formal", "no emoji").
+
+use serde::{Deserialize, Serialize};
+
+use crate::outputs::safety::SafetyConfig;
+
+// Config Constants
+/// A style request is a short command, not a sentence that happens to mention formality.
+pub const STYLE_REQUEST_MAX_WORDS: usize = 8;
+
+// Checked in order: negations ("no swearing") before the positive forms they contain.
+const MORE_FORMAL_PHRASES: &[&str] = &["more formal", "less casual", "be formal", "more professional"];
+const MORE_CASUAL_PHRASES: &[&str] = &["more casual", "less formal", "be casual", "more relaxed", "less stiff"];
+const EMOJI_OFF_PHRASES: &[&str] = &["no emoji", "without emoji", "stop using emoji", "don t use emoji", "fewer emoji"];
+const EMOJI_ON_PHRASES: &[&str] = &["use emoji", "with emoji", "add emoji", "more emoji"];
+const PROFANITY_NONE_PHRASES: &[&str] = &[
+    "no swearing", "don t swear", "stop swearing", "no cursing", "don t curse", "watch your language", "keep it clean",
+];
+const PROFANITY_MILD_PHRASES: &[&str] = &["mild language", "a little swearing", "bit of swearing"];
+const PROFANITY_ANY_PHRASES: &[&str] = &["you can swear", "swearing is fine", "swearing is ok", "you can curse", "cursing is fine"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub enum Formality {
+    /// Contractions, relaxed phrasing
+    Casual,
+    /// Templates as written
+    #[default]
+    Neutral,
+    /// No contractions
+    Formal,
+}
+
+impl Formality {
+    fn more(self) -> Self {
+        match self {
+            Formality::Casual => Formality::Neutral,
+            _ => Formality::Formal,
+        }
+    }
+
+    fn less(self) -> Self {
+        match self {
+            Formality::Formal => Formality::Neutral,
+            _ => Formality::Casual,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ProfanityTolerance {
+    /// Never swear; the safety filter catches what slips through
+    #[default]
+    None,
+    /// Mild language in generated text; strong terms are still filtered
+    Mild,
+    /// Whatever the user uses; the safety filter's profanity check is off
+    Any,
+}
+
+/// Where a reply is shown. Emoji only ever reach the text channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutputChannel {
+    /// Spoken (captions mirror the spoken words)
+    Voice,
+    /// Read, not heard (caption-only mode)
+    Text,
+}
+
+impl OutputChannel {
+    pub fn from_caption_only(caption_only: bool) -> Self {
+        if caption_only { OutputChannel::Text } else { OutputChannel::Voice }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct OutputStyle {
+    pub formality: Formality,
+    /// Emoji in the text channel (never spoken)
+    pub emoji: bool,
+    pub profanity: ProfanityTolerance,
+}
+
+impl OutputStyle {
+    /// Style instructions for the LLM speech prompt (appended to the system prompt).
+    pub fn prompt_guidance(&self, channel: OutputChannel) -> String {
+        let formality = match self.formality {
+            Formality::Casual => "Use a casual, friendly register with contractions.",
+            Formality::Neutral => "Use a plain, neutral register.",
+            Formality::Formal => "Use a formal register without contractions or slang.",
+        };
+        let emoji = if self.emoji && channel == OutputChannel::Text {
+            "An occasional emoji is welcome."
+        } else {
+            "Never use emoji."
+        };
+        let profanity = match self.profanity {
+            ProfanityTolerance::None => "Never use profanity.",
+            ProfanityTolerance::Mild => "Mild language is acceptable; avoid strong profanity.",
+            ProfanityTolerance::Any => "Profanity is acceptable if it fits.",
+        };
+        format!("{} {} {}", formality, emoji, profanity)
+    }
+
+    /// The safety filter for this style: tolerating any profanity turns the profanity check off.
+    /// PII and injection-echo checks are never relaxed.
+    pub fn screening(&self, base: SafetyConfig) -> SafetyConfig {
+        match self.profanity {
+            ProfanityTolerance::Any => SafetyConfig { profanity: false, ..base },
+            _ => base,
+        }
+    }
+}
+
+fn normalise(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// PURE FUNCTION: The style a spoken request asks for ("be more formal" -> one step more formal).
+/// `None` if `text` is not a style request. Formality moves one step per request; the others are set.
+pub fn parse_style_request(text: &str, current: &OutputStyle) -> Option<OutputStyle> {
+    let normalised = normalise(text);
+    if normalised.is_empty() || normalised.split(' ').count() > STYLE_REQUEST_MAX_WORDS {
+        return None;
+    }
+    let padded = format!(" {} ", normalised);
+    let says = |phrases: &[&str]| phrases.iter().any(|p| padded.contains(&format!(" {}", p)));
+
+    let mut style = *current;
+    let mut matched = false;
+    if says(MORE_FORMAL_PHRASES) {
+        style.formality = current.formality.more();
+        matched = true;
+    } else if says(MORE_CASUAL_PHRASES) {
+        style.formality = current.formality.less();
+        matched = true;
+    }
+    if says(EMOJI_OFF_PHRASES) {
+        style.emoji = false;
+        matched = true;
+    } else if says(EMOJI_ON_PHRASES) {
+        style.emoji = true;
+        matched = true;
+    }
+    if says(PROFANITY_NONE_PHRASES) {
+        style.profanity = ProfanityTolerance::None;
+        matched = true;
+    } else if says(PROFANITY_MILD_PHRASES) {
+        style.profanity = ProfanityTolerance::Mild;
+        matched = true;
+    } else if says(PROFANITY_ANY_PHRASES) {
+        style.profanity = ProfanityTolerance::Any;
+        matched = true;
+    }
+    matched.then_some(style)
+}
@@ -0,0 +1,317 @@
+//! TTS Engines (driver side).
+//!
+//! Drivers turn `SideEffect::SpawnAudio` into a playback process. That process comes from a
+//! `TtsEngine`, picked by `ReactorConfig::tts` (`TtsEngineKind`: per platform by default, or
+//! `NEXUS_TTS`):
+//! - `Say`: macOS `say` (`-a` routes to a device); cached buffers via `say -o` + `afplay`.
+//! - `Sapi`: Windows System.Speech through PowerShell. Text is passed in the environment, never
+//!   spliced into the script.
+//! - `Espeak`: espeak-ng (Linux); `aplay` plays to a device and the cached buffers.
+//! - `Piper`: piper neural voices (Linux), streamed: raw audio goes to `aplay` while the rest
+//!   of the sentence is still being synthesized. Voice from `NEXUS_PIPER_MODEL`.
+//!
+//! Engines with a volume control also speak at a gain (`speak_at`): loudness normalization
+//! and the user's volume (`outputs::loudness`). Piper speaks at its own level.
+//!
+//! Engines only build commands. Drivers spawn, time and kill them, so the playback lifecycle the
+//! kernel sees is the same for every engine.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tokio::process::Command;
+
+// Config Constants
+pub const DEFAULT_PIPER_MODEL: &str = "en_US-lessac-medium.onnx";
+/// Output rate of piper's medium and high quality voices.
+pub const DEFAULT_PIPER_SAMPLE_RATE: u32 = 22_050;
+// Environment the SAPI scripts read
+const SAPI_TEXT_VAR: &str = "NEXUS_TTS_TEXT";
+const SAPI_FILE_VAR: &str = "NEXUS_TTS_FILE";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TtsEngineKind {
+    Say,
+    Sapi,
+    Espeak,
+    Piper,
+}
+
+impl Default for TtsEngineKind {
+    /// The platform's own voice: `say` on macOS, SAPI on Windows, espeak-ng elsewhere.
+    fn default() -> Self {
+        if cfg!(target_os = "macos") {
+            TtsEngineKind::Say
+        } else if cfg!(target_os = "windows") {
+            TtsEngineKind::Sapi
+        } else {
+            TtsEngineKind::Espeak
+        }
+    }
+}
+
+impl TtsEngineKind {
+    /// From `NEXUS_TTS` (`say`, `sapi`, `espeak` or `piper`; default per platform).
+    pub fn from_env() -> Self {
+        Self::parse(&std::env::var("NEXUS_TTS").unwrap_or_default()).unwrap_or_default()
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "say" => Some(TtsEngineKind::Say),
+            "sapi" => Some(TtsEngineKind::Sapi),
+            "espeak" | "espeak-ng" => Some(TtsEngineKind::Espeak),
+            "piper" => Some(TtsEngineKind::Piper),
+            _ => None,
+        }
+    }
+
+    pub fn engine(&self) -> Arc<dyn TtsEngine> {
+        match self {
+            TtsEngineKind::Say => Arc::new(SayEngine),
+            TtsEngineKind::Sapi => Arc::new(SapiEngine),
+            TtsEngineKind::Espeak => Arc::new(EspeakEngine),
+            TtsEngineKind::Piper => Arc::new(PiperEngine::from_env()),
+        }
+    }
+}
+
+pub trait TtsEngine: Send + Sync {
+    fn kind(&self) -> TtsEngineKind;
+
+    /// Speak `text` now. `device`: output device name (`None` = system default).
+    fn speak(&self, text: &str, device: Option<&str>) -> Command;
+
+    /// Write `text` as audio to `path` (speech cache).
+    fn synthesize_to(&self, text: &str, path: &Path) -> Command;
+
+    /// Play a file written by `synthesize_to` on the system default device.
+    fn play_file(&self, path: &Path) -> Command;
+
+    /// `speak` at `gain` (linear, 1.0 = the engine's own level; see `outputs::loudness`).
+    /// Engines without a volume control speak at their own level.
+    fn speak_at(&self, text: &str, device: Option<&str>, gain: f32) -> Command {
+        let _ = gain;
+        self.speak(text, device)
+    }
+
+    /// `play_file` at `gain`, for buffers the cache cannot rewrite (not 16-bit PCM WAV).
+    fn play_file_at(&self, path: &Path, gain: f32) -> Command {
+        let _ = gain;
+        self.play_file(path)
+    }
+
+    /// Extension of the files `synthesize_to` writes.
+    fn extension(&self) -> &'static str {
+        "wav"
+    }
+
+    /// Silent stand-in for a playback (caption-only mode).
+    fn silence(&self, duration_ms: u64) -> Command {
+        let mut command = Command::new("sleep");
+        command.arg(format!("{:.3}", duration_ms as f64 / 1000.0));
+        command
+    }
+}
+
+/// macOS `say`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SayEngine;
+
+impl TtsEngine for SayEngine {
+    fn kind(&self) -> TtsEngineKind {
+        TtsEngineKind::Say
+    }
+
+    fn speak(&self, text: &str, device: Option<&str>) -> Command {
+        let mut command = Command::new("say");
+        if let Some(device) = device {
+            command.arg("-a").arg(device);
+        }
+        command.arg(text);
+        command
+    }
+
+    fn speak_at(&self, text: &str, device: Option<&str>, gain: f32) -> Command {
+        // Embedded volume command; `say` does not go above its own level
+        self.speak(&format!("[[volm {:.2}]] {}", gain.clamp(0.0, 1.0), text), device)
+    }
+
+    fn synthesize_to(&self, text: &str, path: &Path) -> Command {
+        let mut command = Command::new("say");
+        command.arg("-o").arg(path).arg(text);
+        command
+    }
+
+    fn play_file(&self, path: &Path) -> Command {
+        let mut command = Command::new("afplay");
+        command.arg(path);
+        command
+    }
+
+    fn play_file_at(&self, path: &Path, gain: f32) -> Command {
+        let mut command = Command::new("afplay");
+        command.arg("-v").arg(format!("{:.2}", gain.max(0.0))).arg(path);
+        command
+    }
+
+    fn extension(&self) -> &'static str {
+        "aiff"
+    }
+}
+
+/// Windows SAPI (System.Speech) through PowerShell. Plays on the default device only.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SapiEngine;
+
+fn powershell(script: &str) -> Command {
+    let mut command = Command::new("powershell");
+    command.args(["-NoProfile", "-NonInteractive", "-Command", script]);
+    command
+}
+
+impl TtsEngine for SapiEngine {
+    fn kind(&self) -> TtsEngineKind {
+        TtsEngineKind::Sapi
+    }
+
+    fn speak(&self, text: &str, _device: Option<&str>) -> Command {
+        let mut command = powershell(
+            "Add-Type -AssemblyName System.Speech; (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak($env:NEXUS_TTS_TEXT)",
+        );
+        command.env(SAPI_TEXT_VAR, text);
+        command
+    }
+
+    fn speak_at(&self, text: &str, _device: Option<&str>, gain: f32) -> Command {
+        // SpeechSynthesizer.Volume: 0-100, 100 being the voice's own level
+        let mut command = powershell(&format!(
+            "Add-Type -AssemblyName System.Speech; $s = New-Object System.Speech.Synthesis.SpeechSynthesizer; \
+             $s.Volume = {}; $s.Speak($env:NEXUS_TTS_TEXT)",
+            (gain * 100.0).round().clamp(0.0, 100.0) as u32,
+        ));
+        command.env(SAPI_TEXT_VAR, text);
+        command
+    }
+
+    fn synthesize_to(&self, text: &str, path: &Path) -> Command {
+        let mut command = powershell(
+            "Add-Type -AssemblyName System.Speech; $s = New-Object System.Speech.Synthesis.SpeechSynthesizer; \
+             $s.SetOutputToWaveFile($env:NEXUS_TTS_FILE); $s.Speak($env:NEXUS_TTS_TEXT); $s.Dispose()",
+        );
+        command.env(SAPI_TEXT_VAR, text).env(SAPI_FILE_VAR, path);
+        command
+    }
+
+    fn play_file(&self, path: &Path) -> Command {
+        let mut command = powershell("(New-Object Media.SoundPlayer $env:NEXUS_TTS_FILE).PlaySync()");
+        command.env(SAPI_FILE_VAR, path);
+        command
+    }
+
+    fn silence(&self, duration_ms: u64) -> Command {
+        powershell(&format!("Start-Sleep -Milliseconds {}", duration_ms))
+    }
+}
+
+/// espeak-ng. A named device is reached through `aplay -D` (ALSA names, as cpal lists them).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EspeakEngine;
+
+impl TtsEngine for EspeakEngine {
+    fn kind(&self) -> TtsEngineKind {
+        TtsEngineKind::Espeak
+    }
+
+    fn speak(&self, text: &str, device: Option<&str>) -> Command {
+        let Some(device) = device else {
+            let mut command = Command::new("espeak-ng");
+            command.arg(text);
+            return command;
+        };
+        let mut command = Command::new("sh");
+        command.args(["-c", r#"espeak-ng --stdout "$0" | aplay -q -D "$1""#, text, device]);
+        command
+    }
+
+    fn speak_at(&self, text: &str, device: Option<&str>, gain: f32) -> Command {
+        // Amplitude 0-200, 100 being espeak's own level
+        let amplitude = (gain * 100.0).round().clamp(0.0, 200.0) as u32;
+        let Some(device) = device else {
+            let mut command = Command::new("espeak-ng");
+            command.arg("-a").arg(amplitude.to_string()).arg(text);
+            return command;
+        };
+        let mut command = Command::new("sh");
+        command.args(["-c", r#"espeak-ng -a "$2" --stdout "$0" | aplay -q -D "$1""#, text, device, &amplitude.to_string()]);
+        command
+    }
+
+    fn synthesize_to(&self, text: &str, path: &Path) -> Command {
+        let mut command = Command::new("espeak-ng");
+        command.arg("-w").arg(path).arg(text);
+        command
+    }
+
+    fn play_file(&self, path: &Path) -> Command {
+        let mut command = Command::new("aplay");
+        command.arg("-q").arg(path);
+        command
+    }
+}
+
+/// piper, streaming raw samples into `aplay` as they are synthesized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiperEngine {
+    pub model: String,
+    pub sample_rate: u32,
+}
+
+impl Default for PiperEngine {
+    fn default() -> Self {
+        Self { model: DEFAULT_PIPER_MODEL.to_string(), sample_rate: DEFAULT_PIPER_SAMPLE_RATE }
+    }
+}
+
+impl PiperEngine {
+    /// Voice from `NEXUS_PIPER_MODEL`, its output rate from `NEXUS_PIPER_RATE` (16000 for
+    /// low quality voices).
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            model: std::env::var("NEXUS_PIPER_MODEL").ok().filter(|s| !s.is_empty()).unwrap_or(defaults.model),
+            sample_rate: std::env::var("NEXUS_PIPER_RATE").ok().and_then(|s| s.trim().parse().ok()).unwrap_or(defaults.sample_rate),
+        }
+    }
+}
+
+impl TtsEngine for PiperEngine {
+    fn kind(&self) -> TtsEngineKind {
+        TtsEngineKind::Piper
+    }
+
+    fn speak(&self, text: &str, device: Option<&str>) -> Command {
+        // "$3": the device, or "default"
+        let mut command = Command::new("sh");
+        command.args([
+            "-c",
+            r#"printf '%s' "$0" | piper --model "$1" --output-raw | aplay -q -D "$3" -r "$2" -f S16_LE -t raw -c 1 -"#,
+            text,
+            &self.model,
+            &self.sample_rate.to_string(),
+            device.unwrap_or("default"),
+        ]);
+        command
+    }
+
+    fn synthesize_to(&self, text: &str, path: &Path) -> Command {
+        let mut command = Command::new("sh");
+        command.args(["-c", r#"printf '%s' "$0" | piper --model "$1" --output_file "$2""#, text, &self.model]).arg(path);
+        command
+    }
+
+    fn play_file(&self, path: &Path) -> Command {
+        EspeakEngine.play_file(path)
+    }
+}
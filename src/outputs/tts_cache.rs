@@ -0,0 +1,332 @@
+//! Speech Synthesis Cache.
+//!
+//! Clarifications, confirmations and resumption offers reuse a small set of phrases.
+//! Synthesizing them on every utterance adds latency before playback starts, so drivers keep
+//! synthesized audio per phrase (`phrase_key` -> buffer) and play the buffer on a hit.
+//!
+//! Bounded by entry count and total bytes (least recently used first) and by a TTL.
+//! Pre-warmed at boot with `canned_phrases_in()`, the kernel's fixed realizations.
+//! Short phrases that miss are synthesized in the background after playback (`remember`).
+//!
+//! Driver-side only: the kernel never sees audio. Buffers are synthesized and played by the
+//! cache's `TtsEngine` (`with_engine`; default per platform). Entries are keyed by the text as
+//! written; what gets synthesized is its `speakable` form in the cache's locale.
+//!
+//! Loudness (`outputs::loudness`): WAV buffers are normalized to `TARGET_LUFS` as they are
+//! stored, and their measured loudness estimates the engine's level, which sets the gain of
+//! uncached speech. Every playback is then scaled by the active profile's volume (`set_volume`).
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::kernel::backchannel::Cue;
+use crate::kernel::crystallizer::CrystallizationDecision;
+use crate::kernel::intent::types::IntentHypothesis;
+use crate::outputs::loudness::{self, DEFAULT_VOLUME, TARGET_LUFS};
+use crate::outputs::phrases::{PhraseTemplates, DEFAULT_LOCALE};
+use crate::outputs::speakable::speakable;
+use crate::outputs::tts::{TtsEngine, TtsEngineKind};
+
+// Config Constants
+pub const DEFAULT_MAX_ENTRIES: usize = 64;
+pub const DEFAULT_MAX_BYTES: usize = 16 * 1024 * 1024;
+pub const DEFAULT_TTL: Duration = Duration::from_secs(6 * 60 * 60);
+/// Longer phrases are unlikely to repeat verbatim; they are not cached on a miss.
+pub const CACHEABLE_MAX_WORDS: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TtsCacheLimits {
+    pub max_entries: usize,
+    pub max_bytes: usize,
+    pub ttl: Duration,
+}
+
+impl Default for TtsCacheLimits {
+    fn default() -> Self {
+        Self { max_entries: DEFAULT_MAX_ENTRIES, max_bytes: DEFAULT_MAX_BYTES, ttl: DEFAULT_TTL }
+    }
+}
+
+struct CachedAudio {
+    audio: Arc<Vec<u8>>,
+    inserted_at: Instant,
+    last_used: Instant,
+}
+
+pub struct TtsCache {
+    limits: TtsCacheLimits,
+    entries: HashMap<u64, CachedAudio>,
+    bytes: usize,
+    // Speech normalization (`outputs::speakable`)
+    locale: String,
+    engine: Arc<dyn TtsEngine>,
+    // Percent of the normalized level (`Profile::volume`)
+    volume: u8,
+    // Sum and count of the engine's measured loudness (LUFS), from the buffers it synthesized
+    measured: (f64, u32),
+}
+
+/// Cache key: case and whitespace do not change what is spoken.
+pub fn phrase_key(text: &str) -> u64 {
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    let mut h = DefaultHasher::new();
+    normalized.hash(&mut h);
+    h.finish()
+}
+
+/// `canned_phrases_in` with the built-in templates.
+pub fn canned_phrases() -> Vec<String> {
+    canned_phrases_in(PhraseTemplates::builtin())
+}
+
+/// Phrases the kernel speaks verbatim: every variant of the clarification, of the resumption
+/// and of the follow-up offers in the templates' locale, and the listening cues.
+pub fn canned_phrases_in(templates: &PhraseTemplates) -> Vec<String> {
+    let hypotheses = [IntentHypothesis::Inquiry, IntentHypothesis::Command, IntentHypothesis::Statement, IntentHypothesis::Fragment];
+    let mut phrases = templates.clarifications();
+    for hypothesis in &hypotheses {
+        for was_interrupted in [false, true] {
+            for decision in [CrystallizationDecision::AllowPartial, CrystallizationDecision::AllowHard] {
+                phrases.extend(templates.resumption(hypothesis, was_interrupted, &decision));
+            }
+        }
+    }
+    for summary_first in [true, false] {
+        phrases.extend(templates.follow_up(summary_first));
+    }
+    phrases.extend(Cue::ALL.iter().map(|cue| cue.sound().to_string()));
+    phrases
+}
+
+impl TtsCache {
+    pub fn new(limits: TtsCacheLimits) -> Self {
+        Self {
+            limits,
+            entries: HashMap::new(),
+            bytes: 0,
+            locale: DEFAULT_LOCALE.to_string(),
+            engine: TtsEngineKind::default().engine(),
+            volume: DEFAULT_VOLUME,
+            measured: (0.0, 0),
+        }
+    }
+
+    /// Synthesize and play with `engine` (`ReactorConfig::tts`). Cached buffers are engine-specific,
+    /// so this starts empty.
+    pub fn with_engine(mut self, engine: Arc<dyn TtsEngine>) -> Self {
+        self.entries.clear();
+        self.bytes = 0;
+        self.measured = (0.0, 0);
+        self.engine = engine;
+        self
+    }
+
+    pub fn engine(&self) -> Arc<dyn TtsEngine> {
+        self.engine.clone()
+    }
+
+    /// Speak in `locale` (the phrase templates' locale).
+    pub fn with_locale(mut self, locale: &str) -> Self {
+        self.locale = locale.to_string();
+        self
+    }
+
+    /// Follow the reply language (`kernel::language`). Buffers were synthesized from another
+    /// locale's speakable text, so a change starts the cache empty.
+    pub fn set_locale(&mut self, locale: &str) {
+        if self.locale != locale {
+            self.locale = locale.to_string();
+            self.entries.clear();
+            self.bytes = 0;
+        }
+    }
+
+    /// Play at `volume` percent of the normalized level (the active profile's volume).
+    pub fn set_volume(&mut self, volume: u8) {
+        self.volume = volume;
+    }
+
+    pub fn volume(&self) -> u8 {
+        self.volume
+    }
+
+    /// The engine's average loudness (LUFS) over the WAV buffers it synthesized so far.
+    pub fn engine_loudness(&self) -> Option<f64> {
+        let (sum, count) = self.measured;
+        (count > 0).then(|| sum / count as f64)
+    }
+
+    /// Gain of uncached speech: the engine's level normalized (once measured), times the volume.
+    pub fn speech_gain(&self) -> f32 {
+        let normalization = self.engine_loudness().map(|lufs| loudness::normalization_gain(lufs, TARGET_LUFS)).unwrap_or(1.0);
+        normalization * loudness::volume_gain(self.volume)
+    }
+
+    /// What the voice says for `text`.
+    pub fn spoken(&self, text: &str) -> String {
+        speakable(text, &self.locale)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    pub fn contains(&self, text: &str, now: Instant) -> bool {
+        self.entries.get(&phrase_key(text))
+            .map(|e| now.saturating_duration_since(e.inserted_at) < self.limits.ttl)
+            .unwrap_or(false)
+    }
+
+    /// Cached audio for `text`, if present and not expired.
+    pub fn get(&mut self, text: &str, now: Instant) -> Option<Arc<Vec<u8>>> {
+        let key = phrase_key(text);
+        let expired = now.saturating_duration_since(self.entries.get(&key)?.inserted_at) >= self.limits.ttl;
+        if expired {
+            self.remove(key);
+            return None;
+        }
+        let entry = self.entries.get_mut(&key)?;
+        entry.last_used = now;
+        Some(entry.audio.clone())
+    }
+
+    /// Store synthesized audio, normalized to `TARGET_LUFS` if it is 16-bit PCM WAV. Buffers
+    /// larger than `max_bytes` are not cached.
+    pub fn insert(&mut self, text: &str, audio: Vec<u8>, now: Instant) {
+        if audio.is_empty() || audio.len() > self.limits.max_bytes || self.limits.max_entries == 0 {
+            return;
+        }
+        let audio = match loudness::normalize(&audio, TARGET_LUFS) {
+            Some((normalized, lufs)) => {
+                self.measured = (self.measured.0 + lufs, self.measured.1 + 1);
+                normalized
+            }
+            None => audio,
+        };
+        let key = phrase_key(text);
+        self.remove(key);
+        self.entries.retain(|_, e| now.saturating_duration_since(e.inserted_at) < self.limits.ttl);
+        self.bytes = self.entries.values().map(|e| e.audio.len()).sum();
+        while self.entries.len() >= self.limits.max_entries || self.bytes + audio.len() > self.limits.max_bytes {
+            let Some(lru) = self.entries.iter().min_by_key(|(_, e)| e.last_used).map(|(k, _)| *k) else { break };
+            self.remove(lru);
+        }
+        self.bytes += audio.len();
+        self.entries.insert(key, CachedAudio { audio: Arc::new(audio), inserted_at: now, last_used: now });
+    }
+
+    fn remove(&mut self, key: u64) {
+        if let Some(e) = self.entries.remove(&key) {
+            self.bytes -= e.audio.len();
+        }
+    }
+
+    /// Driver: playback for `text` on `device` (`None` = system default). A cached buffer
+    /// plays via the engine's file player; otherwise the engine speaks at `speech_gain`, or in
+    /// caption-only mode this is `captions::playback_command_with` (a silent timer). A selected
+    /// device always gets live speech: the file players do not route to one.
+    pub fn playback_command(&mut self, text: &str, caption_only: bool, device: Option<&str>) -> tokio::process::Command {
+        if !caption_only && device.is_none() {
+            if let Some(audio) = self.get(text, Instant::now()) {
+                // Normalized when stored: only the volume is left to apply
+                let gain = loudness::volume_gain(self.volume);
+                let scaled = if self.volume == DEFAULT_VOLUME { None } else { loudness::apply_gain(&audio, gain) };
+                let file = match &scaled {
+                    Some(scaled) => buffer_file(&format!("{:016x}-{}", phrase_key(text), self.volume), scaled, self.engine.extension()),
+                    None => buffer_file(&format!("{:016x}", phrase_key(text)), &audio, self.engine.extension()),
+                };
+                match file {
+                    // Not a WAV buffer: the player applies the volume
+                    Ok(path) if scaled.is_none() && self.volume != DEFAULT_VOLUME => return self.engine.play_file_at(&path, gain),
+                    Ok(path) => return self.engine.play_file(&path),
+                    Err(e) => tracing::warn!("TTS cache file unavailable: {}", e),
+                }
+            }
+        }
+        if caption_only {
+            // A caption-only timer is sized from the caption text
+            return crate::outputs::captions::playback_command_with(self.engine.as_ref(), text, true, device);
+        }
+        // At the engine's own level (nothing measured, default volume): the plain command
+        let gain = self.speech_gain();
+        if gain == 1.0 {
+            self.engine.speak(&self.spoken(text), device)
+        } else {
+            self.engine.speak_at(&self.spoken(text), device, gain)
+        }
+    }
+}
+
+/// The player needs a file: buffers are written once per phrase (and volume) to a temp directory.
+fn buffer_file(name: &str, audio: &[u8], extension: &str) -> std::io::Result<PathBuf> {
+    let dir = std::env::temp_dir().join("nexus-tts");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{}.{}", name, extension));
+    if std::fs::metadata(&path).map(|m| m.len() != audio.len() as u64).unwrap_or(true) {
+        std::fs::write(&path, audio)?;
+    }
+    Ok(path)
+}
+
+/// Driver: synthesize `text` to an audio buffer (`TtsEngine::synthesize_to`).
+pub async fn synthesize(engine: &dyn TtsEngine, text: &str) -> std::io::Result<Vec<u8>> {
+    let path = std::env::temp_dir().join(format!("nexus-tts-{:016x}-{}.{}", phrase_key(text), std::process::id(), engine.extension()));
+    let status = engine.synthesize_to(text, &path).status().await?;
+    if !status.success() {
+        return Err(std::io::Error::other(format!("{:?} exited with {}", engine.kind(), status)));
+    }
+    let audio = tokio::fs::read(&path).await;
+    let _ = tokio::fs::remove_file(&path).await;
+    audio
+}
+
+/// Driver: cache a short phrase after a miss (background; never delays playback).
+pub fn remember(cache: Arc<Mutex<TtsCache>>, text: String) {
+    if text.split_whitespace().count() > CACHEABLE_MAX_WORDS {
+        return;
+    }
+    // Unknown phrases only (a poisoned lock counts as known)
+    let Some((spoken, engine)) = cache.lock().ok().filter(|c| !c.contains(&text, Instant::now())).map(|c| (c.spoken(&text), c.engine())) else {
+        return;
+    };
+    tokio::spawn(async move {
+        if let Ok(audio) = synthesize(engine.as_ref(), &spoken).await {
+            if let Ok(mut cache) = cache.lock() {
+                cache.insert(&text, audio, Instant::now());
+            }
+        }
+    });
+}
+
+/// Driver: synthesize the canned phrases (`canned_phrases_in`) at boot.
+pub async fn prewarm(cache: Arc<Mutex<TtsCache>>, phrases: Vec<String>) {
+    let mut warmed = 0;
+    for phrase in phrases {
+        let Ok((spoken, engine)) = cache.lock().map(|c| (c.spoken(&phrase), c.engine())) else { break };
+        match synthesize(engine.as_ref(), &spoken).await {
+            Ok(audio) => {
+                if let Ok(mut cache) = cache.lock() {
+                    cache.insert(&phrase, audio, Instant::now());
+                    warmed += 1;
+                }
+            }
+            Err(e) => {
+                tracing::warn!("TTS pre-warm stopped: {}", e);
+                break;
+            }
+        }
+    }
+    tracing::info!("TTS cache pre-warmed with {} phrases", warmed);
+}
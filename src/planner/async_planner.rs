@@ -1,93 +1,136 @@
 use tokio::sync::mpsc;
-use tracing::warn;
-use serde_json::json;
 use crate::kernel::event::Event;
-use crate::planner::types::{StateSnapshot, Intent};
+use crate::planner::endpoint::PlannerEndpoint;
+use crate::planner::types::StateSnapshot;
+#[cfg(feature = "llm")]
+use tracing::warn;
+#[cfg(feature = "llm")]
+use crate::planner::types::Intent;
+#[cfg(feature = "llm")]
+use crate::planner::grammar::PlanParseOutcome;
+#[cfg(feature = "llm")]
+use crate::kernel::telemetry::event::TelemetryEvent;
+use crate::kernel::telemetry::recorder::TelemetryHandle;
 
-const LLM_URL: &str = "http://localhost:8080/completion";
-const DEFAULT_TIMEOUT_MS: u64 = 200;
+/// PURE FUNCTION: The planner prompt for `snapshot`.
+pub fn plan_prompt(snapshot: &StateSnapshot) -> String {
+    format!(
+        "STATE: {}\nAVAILABLE INTENTS: BeginResponse(confidence), BeginResponseWithFollowUp(summary_first), Delay(ticks), AskClarification, DoNothing.\nSTATE.gate_preview is what a response would get now: on Deny choose DoNothing, on Delay choose Delay.\nReturn ONLY valid JSON.",
+        serde_json::to_string(snapshot).unwrap_or_default()
+    )
+}
 
+/// Tier 2 Planner (LLM over HTTP, `PlannerEndpoint`).
+/// Without the `llm` feature, `dispatch` is a no-op: nothing is ever in flight,
+/// so `tick_step` falls back to the reflex micro-planner alone.
+/// Every dispatch ends in exactly one `PlanProposed` for its epoch (`DoNothing` on any failure),
+/// unless it is aborted first. Whether the plan is still fresh is the kernel's call (`EpochPolicy`).
 pub struct AsyncPlanner {
+    #[cfg(feature = "llm")]
     client: reqwest::Client,
+    #[cfg_attr(not(feature = "llm"), allow(dead_code))]
     tx: mpsc::Sender<Event>,
+    #[cfg_attr(not(feature = "llm"), allow(dead_code))]
+    telemetry: TelemetryHandle,
     current_task: Option<tokio::task::JoinHandle<()>>,
+    endpoint: PlannerEndpoint,
 }
 
 impl AsyncPlanner {
-    pub fn new(tx: mpsc::Sender<Event>) -> Self {
-        let timeout_ms = std::env::var("NEXUS_PLANNER_TIMEOUT_MS")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(DEFAULT_TIMEOUT_MS);
+    /// Endpoint from the environment (`PlannerEndpoint::from_env`).
+    pub fn new(tx: mpsc::Sender<Event>, telemetry: TelemetryHandle) -> Self {
+        Self::with_endpoint(tx, telemetry, PlannerEndpoint::from_env())
+    }
 
+    #[cfg(feature = "llm")]
+    pub fn with_endpoint(tx: mpsc::Sender<Event>, telemetry: TelemetryHandle, endpoint: PlannerEndpoint) -> Self {
         Self {
             client: reqwest::Client::builder()
-                .timeout(std::time::Duration::from_millis(timeout_ms))
+                .timeout(std::time::Duration::from_millis(endpoint.timeout_ms))
                 .build()
                 .unwrap_or_else(|_| reqwest::Client::new()),
             tx,
+            telemetry,
+            current_task: None,
+            endpoint,
+        }
+    }
+
+    #[cfg(not(feature = "llm"))]
+    pub fn with_endpoint(tx: mpsc::Sender<Event>, telemetry: TelemetryHandle, endpoint: PlannerEndpoint) -> Self {
+        Self {
+            tx,
+            telemetry,
             current_task: None,
+            endpoint,
         }
     }
 
+    pub fn endpoint(&self) -> &PlannerEndpoint {
+        &self.endpoint
+    }
+
     pub fn abort(&mut self) {
         if let Some(task) = self.current_task.take() {
             task.abort();
         }
     }
 
+    /// True while a dispatched plan has not yet been proposed (or aborted).
+    pub fn is_in_flight(&self) -> bool {
+        self.current_task.as_ref().map(|t| !t.is_finished()).unwrap_or(false)
+    }
+
+    #[cfg(not(feature = "llm"))]
+    pub fn dispatch(&mut self, snapshot: StateSnapshot) {
+        tracing::debug!("[AsyncPlanner] LLM compiled out (feature `llm`). Skipping epoch {:?}", snapshot.epoch);
+    }
+
+    #[cfg(feature = "llm")]
     pub fn dispatch(&mut self, snapshot: StateSnapshot) {
         // Abort any existing in-flight plan
         self.abort();
 
         let client = self.client.clone();
         let tx = self.tx.clone();
+        let telemetry = self.telemetry.clone();
+        let endpoint = self.endpoint.clone();
         let epoch = snapshot.epoch;
 
         let handle = tokio::spawn(async move {
-            let prompt = format!(
-                "STATE: {}\nAVAILABLE INTENTS: BeginResponse(confidence), Delay(ticks), AskClarification, DoNothing.\nReturn ONLY valid JSON.",
-                serde_json::to_string(&snapshot).unwrap_or_default()
-            );
-
-            let body = json!({
-                "prompt": prompt,
-                "n_predict": 64,
-                "json_schema": {
-                    "type": "object",
-                    "properties": {
-                        "intent": { "type": "string", "enum": ["BeginResponse", "Delay", "AskClarification", "DoNothing"] },
-                        "data": { "type": "object" }
-                    }
-                }
-            });
-            
-
-            
-            println!("[AsyncPlanner] Sending Request to LLM...");
-            match client.post(LLM_URL).json(&body).send().await {
-                Ok(resp) => {
-                    if let Ok(text) = resp.text().await {
-                        // Debug Log Raw Response for Config Verification
-                        println!("[AsyncPlanner] Raw LLM Response (Epoch {:?}): {}", epoch, text);
-
-                        // Parse the wrapper JSON from llama-server
-                        let json_resp: serde_json::Value = serde_json::from_str(&text).unwrap_or_default();
-                        let content = json_resp["content"].as_str().unwrap_or(text.as_str()); // Fallback to raw if logic changes
+            // Constrained decoding per backend: GBNF grammar or JSON schema
+            let body = endpoint.request_body(&plan_prompt(&snapshot));
+            let mut request = client.post(&endpoint.url).json(&body);
+            if let Some(key) = &endpoint.api_key {
+                request = request.bearer_auth(key);
+            }
 
-                        println!("[AsyncPlanner] Extracted Content: {}", content);
+            println!("[AsyncPlanner] Sending Request to LLM ({:?})...", endpoint.backend);
+            let reply = match request.send().await {
+                Ok(resp) if resp.status().is_success() => resp.text().await.map_err(|e| e.to_string()),
+                Ok(resp) => Err(format!("HTTP {}", resp.status())),
+                Err(e) => Err(e.to_string()),
+            };
+            let parsed = match reply {
+                Ok(text) => {
+                    // Debug Log Raw Response for Config Verification
+                    println!("[AsyncPlanner] Raw LLM Response (Epoch {:?}): {}", epoch, text);
 
-                        let intent: Option<Intent> = serde_json::from_str(content).ok();
-                         let parsed = intent.unwrap_or(Intent::DoNothing);
-                         println!("[AsyncPlanner] Parsed Intent: {:?}", parsed);
-                         let _ = tx.send(Event::PlanProposed(epoch, parsed)).await;
+                    // Backend wrapper -> Strict -> Repair -> DoNothing
+                    let outcome = endpoint.parse_reply(&text);
+                    if let PlanParseOutcome::Failed(e) = &outcome {
+                        warn!("[AsyncPlanner] Unparseable plan ({:?}). Falling back to DoNothing.", e);
                     }
+                    telemetry.record(TelemetryEvent::PlanParse { outcome: (&outcome).into() });
+                    outcome.into_intent()
                 }
                 Err(e) => {
                     println!("[AsyncPlanner] LLM Plan Failed/Timeout: {}", e);
-                    let _ = tx.send(Event::PlanProposed(epoch, Intent::DoNothing)).await;
+                    Intent::DoNothing
                 }
-            }
+            };
+            println!("[AsyncPlanner] Parsed Intent: {:?}", parsed);
+            let _ = tx.send(Event::PlanProposed(epoch, parsed)).await;
         });
         
         self.current_task = Some(handle);
@@ -0,0 +1,151 @@
+//! Planner LLM endpoint.
+//!
+//! The Tier 2 planner talks to one of three server APIs (`PlannerBackend`), picked with
+//! `NEXUS_PLANNER_BACKEND`:
+//! - `llama` (default): llama-server `/completion`, constrained by `json_schema` or `INTENT_GBNF`.
+//! - `openai`: any OpenAI-compatible `/v1/chat/completions` (vLLM, LM Studio, llama-server's
+//!   own route, hosted APIs), asked for a JSON object. The schema goes in the prompt.
+//! - `ollama`: Ollama `/api/chat`, constrained by passing the schema as `format`.
+//!
+//! Request bodies and reply extraction are pure; `AsyncPlanner` only does the HTTP. Whatever the
+//! backend, the extracted text goes through `grammar::parse_intent` (strict, repair, DoNothing).
+
+use serde_json::{json, Value};
+
+use crate::planner::grammar::{intent_json_schema, parse_intent, PlanParseError, PlanParseOutcome, INTENT_GBNF};
+
+// Config Constants
+pub const DEFAULT_LLAMA_URL: &str = "http://localhost:8080/completion";
+pub const DEFAULT_OPENAI_URL: &str = "http://localhost:8080/v1/chat/completions";
+pub const DEFAULT_OLLAMA_URL: &str = "http://localhost:11434/api/chat";
+pub const DEFAULT_OLLAMA_MODEL: &str = "llama3.2";
+pub const DEFAULT_TIMEOUT_MS: u64 = 200;
+/// An Intent is a few dozen tokens.
+pub const MAX_PLAN_TOKENS: u32 = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlannerBackend {
+    #[default]
+    LlamaServer,
+    OpenAi,
+    Ollama,
+}
+
+impl PlannerBackend {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "llama" | "llama-server" | "llamacpp" => Some(PlannerBackend::LlamaServer),
+            "openai" | "openai-compatible" => Some(PlannerBackend::OpenAi),
+            "ollama" => Some(PlannerBackend::Ollama),
+            _ => None,
+        }
+    }
+
+    pub fn default_url(&self) -> &'static str {
+        match self {
+            PlannerBackend::LlamaServer => DEFAULT_LLAMA_URL,
+            PlannerBackend::OpenAi => DEFAULT_OPENAI_URL,
+            PlannerBackend::Ollama => DEFAULT_OLLAMA_URL,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannerEndpoint {
+    pub backend: PlannerBackend,
+    pub url: String,
+    /// Required by the chat APIs (Ollama falls back to `DEFAULT_OLLAMA_MODEL`); llama-server
+    /// serves the one model it loaded
+    pub model: Option<String>,
+    /// Sent as a bearer token
+    pub api_key: Option<String>,
+    pub timeout_ms: u64,
+    /// llama-server only: GBNF grammar instead of the JSON schema
+    pub use_gbnf: bool,
+}
+
+impl Default for PlannerEndpoint {
+    fn default() -> Self {
+        Self::for_backend(PlannerBackend::default())
+    }
+}
+
+impl PlannerEndpoint {
+    /// `backend` at its default local URL.
+    pub fn for_backend(backend: PlannerBackend) -> Self {
+        Self {
+            backend,
+            url: backend.default_url().to_string(),
+            model: (backend == PlannerBackend::Ollama).then(|| DEFAULT_OLLAMA_MODEL.to_string()),
+            api_key: None,
+            timeout_ms: DEFAULT_TIMEOUT_MS,
+            use_gbnf: false,
+        }
+    }
+
+    /// From `NEXUS_PLANNER_BACKEND`, `NEXUS_PLANNER_URL`, `NEXUS_PLANNER_MODEL`,
+    /// `NEXUS_PLANNER_API_KEY`, `NEXUS_PLANNER_TIMEOUT_MS` and `NEXUS_PLANNER_GBNF`.
+    pub fn from_env() -> Self {
+        let var = |name: &str| std::env::var(name).ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+        let backend = var("NEXUS_PLANNER_BACKEND").and_then(|s| PlannerBackend::parse(&s)).unwrap_or_default();
+        let defaults = Self::for_backend(backend);
+        Self {
+            backend,
+            url: var("NEXUS_PLANNER_URL").unwrap_or(defaults.url),
+            model: var("NEXUS_PLANNER_MODEL").or(defaults.model),
+            api_key: var("NEXUS_PLANNER_API_KEY"),
+            timeout_ms: var("NEXUS_PLANNER_TIMEOUT_MS").and_then(|s| s.parse().ok()).unwrap_or(defaults.timeout_ms),
+            use_gbnf: std::env::var("NEXUS_PLANNER_GBNF").is_ok(),
+        }
+    }
+
+    /// PURE FUNCTION: The request body carrying `prompt`, constrained to the Intent format.
+    pub fn request_body(&self, prompt: &str) -> Value {
+        match self.backend {
+            PlannerBackend::LlamaServer if self.use_gbnf => json!({
+                "prompt": prompt,
+                "n_predict": MAX_PLAN_TOKENS,
+                "grammar": INTENT_GBNF,
+            }),
+            PlannerBackend::LlamaServer => json!({
+                "prompt": prompt,
+                "n_predict": MAX_PLAN_TOKENS,
+                "json_schema": intent_json_schema(),
+            }),
+            PlannerBackend::OpenAi => json!({
+                "model": self.model.clone().unwrap_or_default(),
+                "messages": [{ "role": "user", "content": format!("{}\nSCHEMA: {}", prompt, intent_json_schema()) }],
+                "max_tokens": MAX_PLAN_TOKENS,
+                "temperature": 0.0,
+                "response_format": { "type": "json_object" },
+            }),
+            PlannerBackend::Ollama => json!({
+                "model": self.model.clone().unwrap_or_else(|| DEFAULT_OLLAMA_MODEL.to_string()),
+                "messages": [{ "role": "user", "content": prompt }],
+                "stream": false,
+                "format": intent_json_schema(),
+                "options": { "num_predict": MAX_PLAN_TOKENS, "temperature": 0.0 },
+            }),
+        }
+    }
+
+    /// PURE FUNCTION: The model's text in a reply body. llama-server's raw text is taken as is
+    /// when it is not its usual wrapper; the chat APIs must answer in their own format.
+    pub fn extract_content(&self, body: &str) -> Option<String> {
+        let reply: Option<Value> = serde_json::from_str(body).ok();
+        let content = match self.backend {
+            PlannerBackend::LlamaServer => return Some(reply.as_ref().and_then(|r| r["content"].as_str()).unwrap_or(body).to_string()),
+            PlannerBackend::OpenAi => reply?["choices"][0]["message"]["content"].as_str()?.to_string(),
+            PlannerBackend::Ollama => reply?["message"]["content"].as_str()?.to_string(),
+        };
+        Some(content)
+    }
+
+    /// PURE FUNCTION: A reply body to a parsed (or repaired) Intent, or the reason it failed.
+    pub fn parse_reply(&self, body: &str) -> PlanParseOutcome {
+        match self.extract_content(body) {
+            Some(content) => parse_intent(&content),
+            None => PlanParseOutcome::Failed(PlanParseError::NoJson),
+        }
+    }
+}
@@ -0,0 +1,327 @@
+//! Constrained output layer for the LLM planner.
+//!
+//! Local models frequently deviate from strict JSON. This module:
+//! 1. Describes the Intent wire format (JSON schema + optional GBNF grammar for llama-server).
+//! 2. Parses raw completions strictly, then attempts automatic repair of common deviations.
+//! 3. Reports the outcome so the caller can record parse telemetry and fall back to DoNothing.
+
+use serde_json::{json, Map, Value};
+use crate::planner::types::Intent;
+use crate::kernel::telemetry::event::PlanParseKind;
+
+/// GBNF grammar (llama.cpp) restricting output to the adjacently tagged Intent format.
+/// ReviseStatement is intentionally excluded: the planner never proposes it.
+pub const INTENT_GBNF: &str = r#"root ::= "{" ws "\"intent\"" ws ":" ws intent ws "}"
+intent ::= begin | follow | delay | clarify | nothing
+begin ::= "\"BeginResponse\"" ws "," ws "\"data\"" ws ":" ws "{" ws "\"confidence\"" ws ":" ws number ws "}"
+follow ::= "\"BeginResponseWithFollowUp\"" ws "," ws "\"data\"" ws ":" ws "{" ws "\"summary_first\"" ws ":" ws boolean ws "}"
+delay ::= "\"Delay\"" ws "," ws "\"data\"" ws ":" ws "{" ws "\"ticks\"" ws ":" ws integer ws "}"
+clarify ::= "\"AskClarification\"" ws "," ws "\"data\"" ws ":" ws "{" ws "\"context\"" ws ":" ws string ws "}"
+nothing ::= "\"DoNothing\""
+number ::= "0" ("." [0-9]+)? | "1" (".0")?
+integer ::= [0-9]+
+boolean ::= "true" | "false"
+string ::= "\"" [^"\\]* "\""
+ws ::= [ \t\n]*
+"#;
+
+/// JSON schema for llama-server `json_schema` constrained decoding.
+pub fn intent_json_schema() -> Value {
+    json!({
+        "oneOf": [
+            {
+                "type": "object",
+                "properties": {
+                    "intent": { "const": "BeginResponse" },
+                    "data": {
+                        "type": "object",
+                        "properties": { "confidence": { "type": "number", "minimum": 0.0, "maximum": 1.0 } },
+                        "required": ["confidence"]
+                    }
+                },
+                "required": ["intent", "data"]
+            },
+            {
+                "type": "object",
+                "properties": {
+                    "intent": { "const": "BeginResponseWithFollowUp" },
+                    "data": {
+                        "type": "object",
+                        "properties": { "summary_first": { "type": "boolean" } },
+                        "required": ["summary_first"]
+                    }
+                },
+                "required": ["intent", "data"]
+            },
+            {
+                "type": "object",
+                "properties": {
+                    "intent": { "const": "Delay" },
+                    "data": {
+                        "type": "object",
+                        "properties": { "ticks": { "type": "integer", "minimum": 0 } },
+                        "required": ["ticks"]
+                    }
+                },
+                "required": ["intent", "data"]
+            },
+            {
+                "type": "object",
+                "properties": {
+                    "intent": { "const": "AskClarification" },
+                    "data": {
+                        "type": "object",
+                        "properties": { "context": { "type": "string" } },
+                        "required": ["context"]
+                    }
+                },
+                "required": ["intent", "data"]
+            },
+            {
+                "type": "object",
+                "properties": { "intent": { "const": "DoNothing" } },
+                "required": ["intent"]
+            }
+        ]
+    })
+}
+
+/// Why a completion could not be turned into an Intent (no content carried).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanParseError {
+    NoJson,
+    InvalidJson,
+    UnknownIntent,
+    InvalidData,
+}
+
+#[derive(Debug, Clone)]
+pub enum PlanParseOutcome {
+    /// Strict parse succeeded
+    Parsed(Intent),
+    /// Parsed after automatic repair
+    Repaired(Intent),
+    Failed(PlanParseError),
+}
+
+impl PlanParseOutcome {
+    /// Intent to propose. Failures degrade to DoNothing (Silence is not an error).
+    pub fn into_intent(self) -> Intent {
+        match self {
+            PlanParseOutcome::Parsed(i) | PlanParseOutcome::Repaired(i) => i,
+            PlanParseOutcome::Failed(_) => Intent::DoNothing,
+        }
+    }
+}
+
+impl From<&PlanParseOutcome> for PlanParseKind {
+    fn from(outcome: &PlanParseOutcome) -> Self {
+        match outcome {
+            PlanParseOutcome::Parsed(_) => PlanParseKind::Parsed,
+            PlanParseOutcome::Repaired(_) => PlanParseKind::Repaired,
+            PlanParseOutcome::Failed(PlanParseError::NoJson) => PlanParseKind::NoJson,
+            PlanParseOutcome::Failed(PlanParseError::InvalidJson) => PlanParseKind::InvalidJson,
+            PlanParseOutcome::Failed(PlanParseError::UnknownIntent) => PlanParseKind::UnknownIntent,
+            PlanParseOutcome::Failed(PlanParseError::InvalidData) => PlanParseKind::InvalidData,
+        }
+    }
+}
+
+/// PURE FUNCTION: Raw completion -> Intent (strict, then repaired).
+pub fn parse_intent(raw: &str) -> PlanParseOutcome {
+    let trimmed = raw.trim();
+
+    // 1. Strict
+    if let Ok(intent) = serde_json::from_str::<Intent>(trimmed) {
+        return PlanParseOutcome::Parsed(intent);
+    }
+
+    // 2. Repair
+    match repair(trimmed) {
+        Ok(intent) => PlanParseOutcome::Repaired(intent),
+        Err(e) => PlanParseOutcome::Failed(e),
+    }
+}
+
+fn repair(raw: &str) -> Result<Intent, PlanParseError> {
+    // Bare intent name, e.g. `DoNothing` or `"DoNothing"`
+    let bare = raw.trim_matches(|c: char| c == '"' || c == '\'' || c.is_whitespace());
+    if !bare.is_empty() && bare.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return build_intent(bare, Map::new());
+    }
+
+    // Extract the first balanced object (drops code fences and surrounding prose)
+    let object = extract_object(raw).ok_or(PlanParseError::NoJson)?;
+    let value = parse_lenient(&object).ok_or(PlanParseError::InvalidJson)?;
+    let map = match value {
+        Value::Object(m) => m,
+        _ => return Err(PlanParseError::InvalidJson),
+    };
+
+    // Adjacently tagged (possibly with flattened data): {"intent": "...", ...}
+    let tag = map.get("intent").or_else(|| map.get("type")).and_then(|v| v.as_str()).map(|s| s.to_string());
+    if let Some(tag) = tag {
+        let mut data = match map.get("data") {
+            Some(Value::Object(d)) => d.clone(),
+            _ => Map::new(),
+        };
+        // Flattened fields: {"intent": "Delay", "ticks": 5}
+        for (k, v) in map.iter() {
+            if k != "intent" && k != "type" && k != "data" {
+                data.entry(k.clone()).or_insert_with(|| v.clone());
+            }
+        }
+        return build_intent(&tag, data);
+    }
+
+    // Externally tagged: {"BeginResponse": {"confidence": 0.9}}
+    if map.len() == 1 {
+        let (tag, inner) = map.into_iter().next().expect("len checked");
+        let data = match inner {
+            Value::Object(d) => d,
+            _ => Map::new(),
+        };
+        return build_intent(&tag, data);
+    }
+
+    Err(PlanParseError::UnknownIntent)
+}
+
+/// Normalize tag spelling ("begin_response", "beginresponse", "BEGIN-RESPONSE") and coerce field types.
+fn build_intent(tag: &str, data: Map<String, Value>) -> Result<Intent, PlanParseError> {
+    let normalized: String = tag.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase();
+
+    match normalized.as_str() {
+        "beginresponse" | "respond" => {
+            let confidence = number(data.get("confidence")).unwrap_or(0.5) as f32;
+            Ok(Intent::BeginResponse { confidence: confidence.clamp(0.0, 1.0) })
+        }
+        "beginresponsewithfollowup" | "followup" => {
+            let summary_first = boolean(data.get("summary_first")).unwrap_or(true);
+            Ok(Intent::BeginResponseWithFollowUp { summary_first })
+        }
+        "delay" | "wait" => {
+            let ticks = number(data.get("ticks")).ok_or(PlanParseError::InvalidData)?;
+            if ticks < 0.0 { return Err(PlanParseError::InvalidData); }
+            Ok(Intent::Delay { ticks: ticks.round() as u64 })
+        }
+        "askclarification" | "clarify" => {
+            let context = data.get("context").and_then(|v| v.as_str()).unwrap_or("").to_string();
+            Ok(Intent::AskClarification { context })
+        }
+        "donothing" | "nothing" | "none" => Ok(Intent::DoNothing),
+        _ => Err(PlanParseError::UnknownIntent),
+    }
+}
+
+fn number(v: Option<&Value>) -> Option<f64> {
+    match v? {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn boolean(v: Option<&Value>) -> Option<bool> {
+    match v? {
+        Value::Bool(b) => Some(*b),
+        Value::String(s) => s.trim().to_lowercase().parse().ok(),
+        Value::Number(n) => n.as_f64().map(|n| n != 0.0),
+        _ => None,
+    }
+}
+
+/// First balanced `{...}` span, string-aware.
+fn extract_object(raw: &str) -> Option<String> {
+    let start = raw.find('{')?;
+    let mut depth = 0usize;
+    let mut in_string: Option<char> = None;
+    let mut escaped = false;
+
+    for (i, c) in raw[start..].char_indices() {
+        if let Some(q) = in_string {
+            if escaped { escaped = false; }
+            else if c == '\\' { escaped = true; }
+            else if c == q { in_string = None; }
+            continue;
+        }
+        match c {
+            '"' | '\'' => in_string = Some(c),
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(raw[start..start + i + 1].to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Truncated completion (n_predict hit): close what is open
+    let mut s = raw[start..].to_string();
+    if in_string.is_some() { s.push('"'); }
+    for _ in 0..depth { s.push('}'); }
+    Some(s)
+}
+
+/// Strict JSON, then common deviations: single quotes, trailing commas, unquoted keys.
+fn parse_lenient(object: &str) -> Option<Value> {
+    if let Ok(v) = serde_json::from_str(object) {
+        return Some(v);
+    }
+
+    let mut fixed = object.to_string();
+    if !fixed.contains('"') {
+        fixed = fixed.replace('\'', "\"");
+    }
+    fixed = strip_trailing_commas(&fixed);
+    if let Ok(v) = serde_json::from_str(&fixed) {
+        return Some(v);
+    }
+
+    serde_json::from_str(&quote_keys(&fixed)).ok()
+}
+
+fn strip_trailing_commas(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let chars: Vec<char> = s.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == ',' {
+            let next = chars[i + 1..].iter().find(|c| !c.is_whitespace());
+            if matches!(next, Some('}') | Some(']')) {
+                continue;
+            }
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// `{intent: "Delay"}` -> `{"intent": "Delay"}`
+fn quote_keys(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 8);
+    let chars: Vec<char> = s.chars().collect();
+    let mut i = 0;
+    let mut in_string = false;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '"' && (i == 0 || chars[i - 1] != '\\') {
+            in_string = !in_string;
+        }
+        if !in_string && (c.is_alphabetic() || c == '_') {
+            let prev = out.trim_end().chars().last();
+            if matches!(prev, Some('{') | Some(',')) {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') { i += 1; }
+                out.push('"');
+                out.extend(&chars[start..i]);
+                out.push('"');
+                continue;
+            }
+        }
+        out.push(c);
+        i += 1;
+    }
+    out
+}
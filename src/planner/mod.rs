@@ -1,3 +1,8 @@
 pub mod stub;
 pub mod types;
 pub mod async_planner;
+pub mod endpoint;
+pub mod grammar;
+pub mod reflex;
+pub mod tools;
+pub mod sandbox;
@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use crate::kernel::event::{InputContent, InputEvent};
+use crate::kernel::state::SharedState;
+use crate::kernel::time::Tick;
+use crate::kernel::audio::segment::SegmentStatus;
+use crate::kernel::intent::types::{IntentHypothesis, IntentId};
+
+// Config Constants
+const CONFIRMATION_WINDOW_TICKS: u64 = 500; // 10s @ 20ms to answer our clarification
+const TRANSCRIBE_STALL_TICKS: u64 = 250; // 5s Pending without ASR pickup
+
+const AFFIRMATIVE: &[&str] = &["yes", "yeah", "yep", "sure", "please", "ok", "okay", "go ahead"];
+const NEGATIVE: &[&str] = &["no", "nope", "nah", "not now", "don't", "never mind"];
+
+/// Deterministic micro-plans for trivial cases.
+/// Open-ended responses are left to the async LLM planner.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReflexPlan {
+    /// User answered our pending yes/no clarification
+    ConfirmAnswer { affirmative: bool },
+    /// Stable Command: short acknowledgement
+    AcknowledgeCommand,
+    /// Finalized segment never picked up by ASR
+    Transcribe { segment_id: String },
+}
+
+/// Tier 1 Planner: Runs synchronously inside `tick_step`. No I/O, no awaits.
+/// Priority Rule: micro-plans never override an in-flight LLM plan for the same epoch
+/// (enforced by the Reactor, which skips speech micro-plans in that case).
+pub struct ReflexPlanner {
+    pending_clarification_at: Option<Tick>,
+    retried_segments: HashSet<String>,
+    // Last acknowledged Stable command: a held Stable intent is acknowledged once
+    acknowledged: Option<IntentId>,
+}
+
+impl Default for ReflexPlanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReflexPlanner {
+    pub fn new() -> Self {
+        Self {
+            pending_clarification_at: None,
+            retried_segments: HashSet::new(),
+            acknowledged: None,
+        }
+    }
+
+    /// Called when the system asks a yes/no clarification.
+    pub fn note_clarification_asked(&mut self, tick: Tick) {
+        self.pending_clarification_at = Some(tick);
+    }
+
+    /// Speech micro-plans (suppressed while an LLM plan for the same epoch is in flight).
+    pub fn plan_speech(&mut self, inputs: &[InputEvent], state: &SharedState, tick: Tick) -> Option<ReflexPlan> {
+        let text = inputs.iter().rev().find_map(|inp| match &inp.content {
+            InputContent::Text(t) => Some(t.as_str()),
+            InputContent::ProvisionalText { content, .. } => Some(content.as_str()),
+            _ => None,
+        })?;
+
+        // 1. Yes/No answer to our own clarification
+        if let Some(asked) = self.pending_clarification_at {
+            if tick.frame.saturating_sub(asked.frame) <= CONFIRMATION_WINDOW_TICKS {
+                if let Some(affirmative) = Self::yes_no(text) {
+                    self.pending_clarification_at = None;
+                    return Some(ReflexPlan::ConfirmAnswer { affirmative });
+                }
+            } else {
+                self.pending_clarification_at = None;
+            }
+        }
+
+        // 2. Acknowledge Stable Commands (a compound with several commands once, not per clause)
+        if let Some(cand) = state.intent_state().declared().iter().find(|c| c.hypothesis == IntentHypothesis::Command) {
+            if self.acknowledged.as_ref() != Some(&cand.id) {
+                self.acknowledged = Some(cand.id.clone());
+                return Some(ReflexPlan::AcknowledgeCommand);
+            }
+        }
+
+        None
+    }
+
+    /// Housekeeping micro-plans (never conflict with LLM plans).
+    pub fn plan_maintenance(&mut self, state: &SharedState, tick: Tick) -> Vec<ReflexPlan> {
+        let mut plans = Vec::new();
+
+        for seg in state.audio_segments().values() {
+            if seg.status != SegmentStatus::Pending || self.retried_segments.contains(&seg.id) {
+                continue;
+            }
+            let stalled_for = seg.end_tick
+                .map(|t| tick.frame.saturating_sub(t.frame))
+                .unwrap_or(0);
+            if stalled_for >= TRANSCRIBE_STALL_TICKS {
+                self.retried_segments.insert(seg.id.clone());
+                plans.push(ReflexPlan::Transcribe { segment_id: seg.id.clone() });
+            }
+        }
+
+        // Forget segments that no longer exist
+        self.retried_segments.retain(|id| state.audio_segments().contains_key(id));
+
+        plans
+    }
+
+    fn yes_no(text: &str) -> Option<bool> {
+        let t = text.trim().trim_end_matches(|c: char| c.is_ascii_punctuation()).to_lowercase();
+        // Only short, bare answers. "yes but what about..." is open-ended (LLM).
+        if t.split_whitespace().count() > 3 {
+            return None;
+        }
+        if NEGATIVE.iter().any(|n| t == *n || t.starts_with(&format!("{} ", n))) {
+            return Some(false);
+        }
+        if AFFIRMATIVE.iter().any(|a| t == *a || t.starts_with(&format!("{} ", a))) {
+            return Some(true);
+        }
+        None
+    }
+}
@@ -0,0 +1,130 @@
+//! Tool Invocation Sandbox.
+//!
+//! Kernel-side limits on action tools (`SideEffect::InvokeTool`):
+//!
+//! - **Timeouts**: every invocation gets a per-tool deadline. A call still running past it is
+//!   cancelled (`SideEffect::CancelTool`) and reported as failed; a late `ToolResult` is ignored.
+//! - **Concurrency**: at most `max_concurrent` calls run at once. Extra calls are refused, not queued
+//!   (a queued "turn on the light" that runs ten seconds later is worse than a clear failure).
+//! - **Dry run**: report what would be done instead of acting. Forced during onboarding and in
+//!   safe mode.
+//!
+//! The invocation records are kernel state, so an interruption can abort everything in flight.
+//! Pure bookkeeping, no I/O: the driver does the work and honours `CancelTool`.
+
+use serde::Serialize;
+
+use crate::kernel::time::Tick;
+use crate::planner::tools::ToolCall;
+
+// Config Constants
+/// Home actions: the Home Assistant client gives up after 5s; allow 1s of slack (20ms ticks).
+pub const HOME_ACTION_TIMEOUT_TICKS: u64 = 300;
+pub const DEFAULT_TIMEOUT_TICKS: u64 = 250;
+pub const DEFAULT_MAX_CONCURRENT: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SandboxConfig {
+    pub home_action_timeout_ticks: u64,
+    /// Any other tool
+    pub default_timeout_ticks: u64,
+    pub max_concurrent: usize,
+    /// Never act; report what would have been done
+    pub dry_run: bool,
+}
+
+impl Default for SandboxConfig {
+    fn default() -> Self {
+        Self {
+            home_action_timeout_ticks: HOME_ACTION_TIMEOUT_TICKS,
+            default_timeout_ticks: DEFAULT_TIMEOUT_TICKS,
+            max_concurrent: DEFAULT_MAX_CONCURRENT,
+            dry_run: false,
+        }
+    }
+}
+
+impl SandboxConfig {
+    /// Default limits; `NEXUS_TOOL_DRY_RUN=1` turns on dry run.
+    pub fn from_env() -> Self {
+        let dry_run = matches!(std::env::var("NEXUS_TOOL_DRY_RUN").as_deref(), Ok("1") | Ok("true"));
+        Self { dry_run, ..Self::default() }
+    }
+
+    pub fn timeout_ticks(&self, call: &ToolCall) -> u64 {
+        match call {
+            ToolCall::HomeAction(_) => self.home_action_timeout_ticks,
+            _ => self.default_timeout_ticks,
+        }
+    }
+}
+
+/// One running tool call, as the kernel sees it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ToolInvocation {
+    pub call_id: String,
+    pub tool: &'static str,
+    pub started_at: Tick,
+    /// Last tick a result is accepted
+    pub deadline: Tick,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Admission {
+    /// Invoke it (now recorded as in flight)
+    Run,
+    /// Report it, do not invoke
+    DryRun,
+    /// `max_concurrent` calls already running
+    Busy,
+}
+
+#[derive(Debug, Default)]
+pub struct ToolSandbox {
+    in_flight: Vec<ToolInvocation>,
+}
+
+impl ToolSandbox {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gate one call. `dry_run` is the effective flag (config, onboarding or safe mode).
+    pub fn admit(&mut self, call_id: &str, call: &ToolCall, config: &SandboxConfig, dry_run: bool, now: Tick) -> Admission {
+        if dry_run {
+            return Admission::DryRun;
+        }
+        if self.in_flight.len() >= config.max_concurrent {
+            return Admission::Busy;
+        }
+        self.in_flight.push(ToolInvocation {
+            call_id: call_id.to_string(),
+            tool: call.name(),
+            started_at: now,
+            deadline: Tick { frame: now.frame + config.timeout_ticks(call) },
+        });
+        Admission::Run
+    }
+
+    /// A result arrived. Returns the record for calls still in flight (`None`: unknown, timed out or aborted).
+    pub fn complete(&mut self, call_id: &str) -> Option<ToolInvocation> {
+        let index = self.in_flight.iter().position(|i| i.call_id == call_id)?;
+        Some(self.in_flight.remove(index))
+    }
+
+    /// Remove and return calls past their deadline.
+    pub fn expired(&mut self, now: Tick) -> Vec<ToolInvocation> {
+        let (expired, running) = std::mem::take(&mut self.in_flight).into_iter().partition(|i| now.frame > i.deadline.frame);
+        self.in_flight = running;
+        expired
+    }
+
+    /// Interruption: remove and return everything in flight.
+    pub fn abort_all(&mut self) -> Vec<ToolInvocation> {
+        std::mem::take(&mut self.in_flight)
+    }
+
+    pub fn in_flight(&self) -> &[ToolInvocation] {
+        &self.in_flight
+    }
+}
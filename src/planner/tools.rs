@@ -0,0 +1,201 @@
+//! Read-only planner tools.
+//!
+//! A tool lets the planner ground an answer in stored data instead of guessing it.
+//! Each tool has a name, a JSON schema for its arguments (same constrained-decoding
+//! format as `grammar::intent_json_schema`) and an executor over a store.
+//! Tools never mutate memory.
+//!
+//! Each tool is gated by a per-tool consent flag (`ToolConsent`). Only consented tools are
+//! offered in the prompt, and a call to any other tool returns an error instead of data.
+//! `recall_episodes` is granted by default (memory has its own consent); `calendar_events`
+//! must be granted by the user.
+//!
+//! Action tools (`HomeAction`) are not offered to the planner and never run here:
+//! the kernel gates them (Confirm policy) and emits `SideEffect::InvokeTool` for the driver.
+
+use std::collections::HashSet;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::integrations::calendar::{Calendar, CalendarQuery};
+use crate::integrations::home::HomeAction;
+use crate::memory::store::{EpisodicQuery, EpisodicStore};
+use crate::memory::types::Claim;
+
+pub const RECALL_EPISODES: &str = "recall_episodes";
+pub const CALENDAR_EVENTS: &str = "calendar_events";
+
+// Newest N entries per recall (keeps the planner prompt bounded)
+const MAX_RECALLED: usize = 20;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToolCall {
+    RecallEpisodes(EpisodicQuery),
+    CalendarEvents(CalendarQuery),
+    HomeAction(HomeAction),
+}
+
+impl ToolCall {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ToolCall::RecallEpisodes(_) => RECALL_EPISODES,
+            ToolCall::CalendarEvents(_) => CALENDAR_EVENTS,
+            ToolCall::HomeAction(_) => "home_action",
+        }
+    }
+}
+
+/// Per-tool consent flags.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolConsent {
+    granted: HashSet<String>,
+}
+
+impl Default for ToolConsent {
+    fn default() -> Self {
+        Self { granted: HashSet::from([RECALL_EPISODES.to_string()]) }
+    }
+}
+
+impl ToolConsent {
+    pub fn set(&mut self, tool: &str, granted: bool) {
+        if granted {
+            self.granted.insert(tool.to_string());
+        } else {
+            self.granted.remove(tool);
+        }
+    }
+
+    pub fn allows(&self, tool: &str) -> bool {
+        self.granted.contains(tool)
+    }
+}
+
+/// Everything a tool may read. `now_ms` anchors relative queries ("next meeting").
+pub struct ToolContext<'a> {
+    pub episodic: &'a dyn EpisodicStore,
+    pub calendar: &'a Calendar,
+    pub consent: &'a ToolConsent,
+    pub now_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RecalledEpisode {
+    pub claim: Claim,
+    pub confidence: f32,
+    pub created_at_tick: u64,
+    pub created_at_ms: Option<u64>,
+}
+
+/// Tool descriptions for the planner prompt, with default consent.
+pub fn tool_specs() -> Value {
+    tool_specs_for(&ToolConsent::default())
+}
+
+/// Tool descriptions for the planner prompt: consented tools only.
+pub fn tool_specs_for(consent: &ToolConsent) -> Value {
+    let specs = json!([
+        {
+            "name": RECALL_EPISODES,
+            "description": "Episodic memories created within a time range (ticks or Unix ms), optionally about one topic. Oldest first.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "range": {
+                        "oneOf": [
+                            {
+                                "type": "object",
+                                "properties": {
+                                    "Ticks": {
+                                        "type": "object",
+                                        "properties": {
+                                            "from": { "type": "integer", "minimum": 0 },
+                                            "to": { "type": "integer", "minimum": 0 }
+                                        },
+                                        "required": ["from", "to"]
+                                    }
+                                },
+                                "required": ["Ticks"]
+                            },
+                            {
+                                "type": "object",
+                                "properties": {
+                                    "WallClock": {
+                                        "type": "object",
+                                        "properties": {
+                                            "from_ms": { "type": "integer", "minimum": 0 },
+                                            "to_ms": { "type": "integer", "minimum": 0 }
+                                        },
+                                        "required": ["from_ms", "to_ms"]
+                                    }
+                                },
+                                "required": ["WallClock"]
+                            }
+                        ]
+                    },
+                    "topic": { "type": "string" }
+                },
+                "required": ["range"]
+            }
+        },
+        {
+            "name": CALENDAR_EVENTS,
+            "description": "Upcoming calendar events (title, start/end as Unix ms, all-day flag), soonest first. Defaults to events from now on.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "from_ms": { "type": "integer", "minimum": 0 },
+                    "to_ms": { "type": "integer", "minimum": 0 },
+                    "limit": { "type": "integer", "minimum": 1, "maximum": 10 }
+                }
+            }
+        }
+    ]);
+    Value::Array(
+        specs.as_array().into_iter().flatten()
+            .filter(|spec| spec["name"].as_str().is_some_and(|name| consent.allows(name)))
+            .cloned()
+            .collect(),
+    )
+}
+
+/// Decode a tool call by name. `None` for unknown tools or malformed arguments.
+pub fn parse_tool_call(name: &str, args: &Value) -> Option<ToolCall> {
+    match name {
+        RECALL_EPISODES => serde_json::from_value(args.clone()).ok().map(ToolCall::RecallEpisodes),
+        CALENDAR_EVENTS => serde_json::from_value(args.clone()).ok().map(ToolCall::CalendarEvents),
+        _ => None,
+    }
+}
+
+/// Time-indexed episodic recall, capped to the newest `MAX_RECALLED` entries (oldest first).
+pub fn recall_episodes(episodic: &dyn EpisodicStore, query: &EpisodicQuery) -> Vec<RecalledEpisode> {
+    let hits = episodic.in_range(query);
+    let skip = hits.len().saturating_sub(MAX_RECALLED);
+    hits.into_iter()
+        .skip(skip)
+        .map(|e| RecalledEpisode {
+            claim: e.claim.clone(),
+            confidence: e.confidence,
+            created_at_tick: e.created_at_tick,
+            created_at_ms: e.created_at_ms,
+        })
+        .collect()
+}
+
+/// Run a tool call with default consent and no calendar.
+pub fn execute(call: &ToolCall, episodic: &dyn EpisodicStore) -> Value {
+    let calendar = Calendar::default();
+    let consent = ToolConsent::default();
+    execute_with(call, &ToolContext { episodic, calendar: &calendar, consent: &consent, now_ms: 0 })
+}
+
+/// Run a tool call. The result is JSON for the planner prompt.
+pub fn execute_with(call: &ToolCall, ctx: &ToolContext) -> Value {
+    match call {
+        ToolCall::HomeAction(_) => json!({ "error": "action tools run through the kernel" }),
+        _ if !ctx.consent.allows(call.name()) => json!({ "error": "consent required", "tool": call.name() }),
+        ToolCall::RecallEpisodes(query) => json!(recall_episodes(ctx.episodic, query)),
+        ToolCall::CalendarEvents(query) => json!(ctx.calendar.query(query, ctx.now_ms)),
+    }
+}
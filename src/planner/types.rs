@@ -13,6 +13,9 @@ pub struct PlanningEpoch {
 #[serde(tag = "intent", content = "data")]
 pub enum Intent {
     BeginResponse { confidence: f32 },
+    /// Answer, then offer to elaborate ("Want the details?"). `summary_first`: the answer is cut
+    /// to its first sentence. The offer is only said once the answer was heard in full.
+    BeginResponseWithFollowUp { summary_first: bool },
     Delay { ticks: u64 }, // Logical time, not wall clock
     AskClarification { context: String },
     ReviseStatement { ref_id: OutputId, correction: String },
@@ -29,4 +32,13 @@ pub struct StateSnapshot {
     pub latent_summary: String, // Textual firewall for planner
     pub meta_mood: String, // "Cautious", "Confident", etc.
     pub intent_context: crate::kernel::intent::long_horizon::IntentContext,
+    // "You cut me off while I was explaining X" (lengths + intent id only)
+    pub last_interruption: Option<crate::kernel::cancel::InterruptionRecord>,
+    // A memory consent prompt is waiting for the user: proactive output is held until it is answered
+    pub pending_consent: bool,
+    // Offers held back by the gate (quiet hours, pending consent); they are said later
+    pub deferred_outputs: usize,
+    // Gate dry run: what a BeginResponse would get right now (Deny/Delay: plan Delay or DoNothing).
+    // The gate still decides again when the plan is accepted.
+    pub gate_preview: crate::kernel::crystallizer::GateExplanation,
 }
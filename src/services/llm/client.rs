@@ -2,8 +2,34 @@ use anyhow::{Result, anyhow};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use crate::kernel::speech::planner::SpeechIntent;
+use crate::memory::summary::Exchange;
+use crate::outputs::streaming::SentenceSplitter;
+use crate::outputs::style::{OutputChannel, OutputStyle};
+use std::collections::VecDeque;
 use std::time::Duration;
 
+// Config Constants
+/// Whole streamed reply; drivers hold the first sentence to the usual 2s.
+const STREAM_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Prompt for `generate_speech`: the companion persona plus the profile's output style, in
+/// `language` (the reply language, see `kernel::language`).
+pub fn speech_prompt(intent: &SpeechIntent, style: &OutputStyle, channel: OutputChannel, language: &str) -> String {
+    let system_prompt = "You are a quiet, thinking cognitive companion. You respond briefly, neutrally, and precisely. You do not offer advice unless asked. You are calm.";
+
+    let user_prompt = match intent {
+        SpeechIntent::Clarification(context) => format!("The user's intent is ambiguous. Ask a neutral clarification question. Context: {}", context),
+        SpeechIntent::Confirmation(details) => format!("Confirm this action briefly: {}", details),
+        SpeechIntent::Offer(details) => format!("Offer these options neutrally: {}", details),
+    };
+
+    let language_guidance = match language {
+        crate::kernel::language::BASE_LANGUAGE => String::new(),
+        other => format!(" Reply in the language with ISO 639-1 code \"{}\".", other),
+    };
+    format!("System: {} {}{}\nUser: {}\nAssistant:", system_prompt, style.prompt_guidance(channel), language_guidance, user_prompt)
+}
+
 #[derive(Clone)]
 pub struct LLMService {
     client: Client,
@@ -24,6 +50,94 @@ struct CompletionResponse {
     content: String,
 }
 
+// One `data:` line of a streamed completion
+#[derive(Deserialize)]
+struct StreamChunk {
+    #[serde(default)]
+    content: String,
+    #[serde(default)]
+    stop: bool,
+}
+
+/// PURE: streamed `/completion` body (`data: {"content": .., "stop": ..}` lines, split across
+/// network chunks anywhere, even inside a UTF-8 character) -> completed sentences.
+#[derive(Debug, Default)]
+pub struct StreamDecoder {
+    line: Vec<u8>,
+    splitter: SentenceSplitter,
+    stopped: bool,
+}
+
+impl StreamDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed body bytes; returns the sentences they completed. The `stop` chunk also flushes the tail.
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        let mut sentences = Vec::new();
+        for &byte in bytes {
+            if self.stopped {
+                break;
+            }
+            if byte != b'\n' {
+                self.line.push(byte);
+                continue;
+            }
+            let line = std::mem::take(&mut self.line);
+            let Some(chunk) = parse_stream_line(&String::from_utf8_lossy(&line)) else {
+                continue;
+            };
+            sentences.extend(self.splitter.push(&chunk.content));
+            if chunk.stop {
+                self.stopped = true;
+                sentences.extend(self.splitter.finish());
+            }
+        }
+        sentences
+    }
+
+    /// End of the body without a `stop` chunk: the unterminated tail.
+    pub fn finish(&mut self) -> Option<String> {
+        self.stopped = true;
+        self.splitter.finish()
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+}
+
+fn parse_stream_line(line: &str) -> Option<StreamChunk> {
+    let data = line.trim().strip_prefix("data:")?.trim();
+    serde_json::from_str(data).ok()
+}
+
+/// A reply being generated. Dropping it closes the connection, which stops generation.
+pub struct SpeechStream {
+    response: reqwest::Response,
+    decoder: StreamDecoder,
+    pending: VecDeque<String>,
+}
+
+impl SpeechStream {
+    /// The next complete sentence; `None` once the reply is done.
+    pub async fn next_sentence(&mut self) -> Result<Option<String>> {
+        loop {
+            if let Some(sentence) = self.pending.pop_front() {
+                return Ok(Some(sentence));
+            }
+            if self.decoder.is_stopped() {
+                return Ok(None);
+            }
+            match self.response.chunk().await? {
+                Some(bytes) => self.pending.extend(self.decoder.push(&bytes)),
+                None => self.pending.extend(self.decoder.finish()),
+            }
+        }
+    }
+}
+
 impl LLMService {
     pub fn new() -> Self {
         Self {
@@ -35,19 +149,9 @@ impl LLMService {
         }
     }
 
-    pub async fn generate_speech(&self, intent: SpeechIntent) -> Result<String> {
-        let system_prompt = "You are a quiet, thinking cognitive companion. You respond briefly, neutrally, and precisely. You do not offer advice unless asked. You are calm.";
-        
-        let user_prompt = match intent {
-            SpeechIntent::Clarification(context) => format!("The user's intent is ambiguous. Ask a neutral clarification question. Context: {}", context),
-            SpeechIntent::Confirmation(details) => format!("Confirm this action briefly: {}", details),
-            SpeechIntent::Offer(details) => format!("Offer these options neutrally: {}", details),
-        };
-
-        let full_prompt = format!("System: {}\nUser: {}\nAssistant:", system_prompt, user_prompt);
-
+    pub async fn generate_speech(&self, intent: SpeechIntent, style: OutputStyle, channel: OutputChannel, language: &str) -> Result<String> {
         let request_body = CompletionRequest {
-            prompt: full_prompt,
+            prompt: speech_prompt(&intent, &style, channel, language),
             stream: false, // One-shot only
             n_predict: 64, // Strict Token Limit
             temperature: 0.4, // Strict Temperature
@@ -67,4 +171,51 @@ impl LLMService {
         let resp_json: CompletionResponse = response.json().await?;
         Ok(resp_json.content.trim().to_string())
     }
+
+    /// Streamed `generate_speech`: the reply sentence by sentence, as it is generated.
+    pub async fn stream_speech(&self, intent: SpeechIntent, style: OutputStyle, channel: OutputChannel, language: &str) -> Result<SpeechStream> {
+        let request_body = CompletionRequest {
+            prompt: speech_prompt(&intent, &style, channel, language),
+            stream: true,
+            n_predict: 64,
+            temperature: 0.4,
+            stop: vec!["User:".to_string(), "System:".to_string()],
+        };
+
+        let response = self.client.post(format!("{}/completion", self.base_url))
+            .timeout(STREAM_TIMEOUT)
+            .json(&request_body)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+             return Err(anyhow!("LLM Server Error: {}", response.status()));
+        }
+
+        Ok(SpeechStream { response, decoder: StreamDecoder::new(), pending: VecDeque::new() })
+    }
+
+    /// One-line episodic summary of a completed exchange.
+    pub async fn summarize_exchange(&self, exchange: &Exchange) -> Result<String> {
+        let prompt = format!(
+            "System: Summarize this exchange in one short line, third person, no quotes.\nUser said: {}\nAssistant replied: {}\nSummary:",
+            exchange.user_text, exchange.reply
+        );
+        let request_body = CompletionRequest {
+            prompt,
+            stream: false,
+            n_predict: 48,
+            temperature: 0.2,
+            stop: vec!["\n".to_string()],
+        };
+        let response = self.client.post(format!("{}/completion", self.base_url))
+            .json(&request_body)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+             return Err(anyhow!("LLM Server Error: {}", response.status()));
+        }
+        let resp_json: CompletionResponse = response.json().await?;
+        Ok(resp_json.content.trim().to_string())
+    }
 }
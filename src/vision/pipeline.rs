@@ -66,6 +66,7 @@ impl VisionPipeline {
                            hash: hash_u64,
                            distance: dist as u32,
                         }),
+                        captured_at: None,
                     }));
                 } else {
                      // First frame
@@ -75,6 +76,7 @@ impl VisionPipeline {
                            hash: hash_u64,
                            distance: 0,
                         }),
+                        captured_at: None,
                     }));
                 }
                 
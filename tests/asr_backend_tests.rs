@@ -0,0 +1,107 @@
+use nexus::audio::asr::{AsrBackend, AsrError, MockAsr, Transcript};
+use nexus::audio::transcription::spawn_transcription;
+use nexus::kernel::audio::segment::WordConfidence;
+use nexus::kernel::event::{AudioSignal, Event, InputContent, InputEvent};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+mod common;
+
+// Records what it was asked to transcribe
+struct FakeAsr {
+    result: Result<Transcript, AsrError>,
+    heard: Mutex<Option<(usize, u32, Option<String>)>>,
+}
+
+impl FakeAsr {
+    fn new(result: Result<Transcript, AsrError>) -> Arc<Self> {
+        Arc::new(Self { result, heard: Mutex::new(None) })
+    }
+}
+
+impl AsrBackend for FakeAsr {
+    fn name(&self) -> &'static str {
+        "fake"
+    }
+
+    fn transcribe(&self, frames: &[f32], sample_rate: u32, language: Option<&str>) -> Result<Transcript, AsrError> {
+        *self.heard.lock().unwrap() = Some((frames.len(), sample_rate, language.map(str::to_string)));
+        self.result.clone()
+    }
+}
+
+fn word(word: &str, confidence: f32) -> WordConfidence {
+    WordConfidence { word: word.to_string(), confidence }
+}
+
+#[test]
+fn test_tokens_joined_into_words() {
+    let tokens: Vec<(String, f32)> = [(" Turn", 0.9), (" off", 0.8), (" the", 0.95), (" ligh", 0.6), ("ts", 0.4)]
+        .iter()
+        .map(|(t, p)| (t.to_string(), *p))
+        .collect();
+    let transcript = Transcript::from_tokens(&tokens);
+    assert_eq!(transcript.text, "Turn off the lights");
+    assert_eq!(transcript.words.len(), 4);
+    assert_eq!(transcript.words[3], word("lights", 0.5), "Mean of its tokens");
+    assert!((transcript.confidence() - 0.7875).abs() < 1e-6);
+
+    assert_eq!(Transcript::from_tokens(&[]).confidence(), 0.0);
+}
+
+#[test]
+fn test_mock_keeps_fixed_text() {
+    let mock = MockAsr { latency: Duration::ZERO, ..Default::default() };
+    let transcript = mock.transcribe(&[0.0; 160], 16_000, None).unwrap();
+    assert_eq!(transcript.text, nexus::audio::asr::MOCK_TEXT);
+    assert_eq!(transcript.language.as_deref(), Some("en"));
+    assert!(transcript.words.iter().all(|w| w.confidence == 0.9));
+    assert!((transcript.confidence() - 0.9).abs() < 1e-6);
+}
+
+#[tokio::test]
+async fn test_transcript_reported_with_words() {
+    let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+    let transcript = Transcript { text: "lights off".to_string(), words: vec![word("lights", 0.9), word("off", 0.5)], language: Some("en".to_string()) };
+    let backend = FakeAsr::new(Ok(transcript));
+    spawn_transcription(backend.clone(), "seg_asr_1".to_string(), vec![0.1; 4800], 48_000, None, None, tx).await.unwrap();
+
+    assert_eq!(*backend.heard.lock().unwrap(), Some((4800, 48_000, None)), "Frames at the segment's rate, language detected");
+    let Some(Event::Input(InputEvent { source, content: InputContent::ProvisionalText { content, confidence, source_id, words, language, is_final: true }, .. })) = rx.recv().await else {
+        panic!("Expected ProvisionalText");
+    };
+    assert_eq!(source, "ASR");
+    assert_eq!((content.as_str(), source_id.as_str()), ("lights off", "seg_asr_1"));
+    assert!((confidence - 0.7).abs() < 1e-6);
+    assert_eq!(words, vec![word("lights", 0.9), word("off", 0.5)]);
+    assert_eq!(language.as_deref(), Some("en"));
+}
+
+#[tokio::test]
+async fn test_failure_and_silence_send_nothing() {
+    let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+    let failing = FakeAsr::new(Err(AsrError::Inference("boom".to_string())));
+    spawn_transcription(failing, "seg_asr_2".to_string(), vec![0.1; 480], 48_000, None, None, tx.clone()).await.unwrap();
+    let empty = FakeAsr::new(Ok(Transcript::default()));
+    spawn_transcription(empty, "seg_asr_3".to_string(), vec![0.1; 480], 48_000, None, None, tx).await.unwrap();
+    assert!(rx.recv().await.is_none(), "All senders gone, nothing sent");
+}
+
+#[tokio::test]
+async fn test_words_kept_on_segment() {
+    let mut reactor = common::reactor();
+    let audio = |signal| Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::Audio(signal), captured_at: None });
+    reactor.tick_step(vec![audio(AudioSignal::SpeechStart)]);
+    let segment_id = reactor.state.active_segment_id().cloned().unwrap();
+    reactor.tick_step(vec![audio(AudioSignal::SpeechEnd)]);
+
+    let words = vec![word("what", 0.8), word("time", 0.3)];
+    reactor.tick_step(vec![Event::Input(InputEvent {
+        source: "ASR".to_string(),
+        content: InputContent::ProvisionalText { content: "what time".to_string(), confidence: 0.55, source_id: segment_id.clone(), words: words.clone(), language: None, is_final: true },
+        captured_at: None,
+    })]);
+    let segment = &reactor.state.audio_segments()[&segment_id];
+    assert_eq!(segment.transcription.as_deref(), Some("what time"));
+    assert_eq!(segment.words, words);
+}
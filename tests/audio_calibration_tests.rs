@@ -0,0 +1,206 @@
+use nexus::kernel::audio::calibration::{CalibrationProfile, CalibrationStore, DevicePair, NoiseCalibration, NOISE_WINDOW_MS};
+use nexus::kernel::audio::monitor::AudioMonitor;
+use nexus::kernel::event::{AudioSignal, AudioSummary, Event, InputContent, PlaybackId};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::shutdown::ShutdownOptions;
+use nexus::memory::store::FileSemanticStore;
+use std::path::PathBuf;
+
+mod common;
+use common::input;
+
+fn temp(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("nexus_calibration_{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn reactor(name: &str) -> Reactor {
+    common::builder()
+        .semantic_store(Box::new(FileSemanticStore::new(temp(&format!("{}_semantic.json", name)))))
+        .calibration(CalibrationStore::new())
+        .build()
+}
+
+fn envelope(rms: f32) -> Event {
+    input(InputContent::AudioSummary(AudioSummary::Envelope { rms, duration_ms: 20 }))
+}
+
+fn playback() -> PlaybackId {
+    PlaybackId::from(uuid::Uuid::new_v4())
+}
+
+/// Quiet audio until the calibration run has heard its window.
+fn calibrate(reactor: &mut Reactor, noise: f32) {
+    reactor.tick_step(vec![input(InputContent::CalibrateAudio)]);
+    for _ in 0..NOISE_WINDOW_MS / 20 {
+        reactor.tick_step(vec![envelope(noise)]);
+    }
+}
+
+#[test]
+fn test_profile_derivation() {
+    let quiet = CalibrationProfile::default().with_noise_floor(0.001);
+    assert_eq!(quiet.threshold_rms, 0.01, "Clamped: a silent room still needs a margin");
+    let noisy = CalibrationProfile::default().with_noise_floor(0.02);
+    assert!((noisy.threshold_rms - 0.06).abs() < 1e-6);
+    assert_eq!(noisy.noise_floor_rms, 0.02);
+
+    // Halfway towards the measured tail plus margin
+    let profile = CalibrationProfile::default().with_echo_tail(500);
+    assert_eq!(profile.echo_tail_ms, (300 + 600) / 2);
+    assert_eq!(CalibrationProfile::default().with_echo_tail(0).echo_tail_ms, 200);
+    assert_eq!(CalibrationProfile { echo_tail_ms: 150, ..Default::default() }.with_echo_tail(0).echo_tail_ms, 150, "Never below the minimum");
+}
+
+#[test]
+fn test_noise_floor_is_the_median() {
+    let mut run = NoiseCalibration::new();
+    let chunks = NOISE_WINDOW_MS / 20;
+    let mut floor = None;
+    for i in 0..chunks {
+        // A word in the middle of the run
+        let rms = if (40..60).contains(&i) { 0.4 } else { 0.01 };
+        floor = run.observe(rms, 20);
+        assert_eq!(floor.is_some(), i == chunks - 1);
+    }
+    assert_eq!(floor, Some(0.01));
+}
+
+#[test]
+fn test_monitor_applies_a_calibration_run() {
+    let mut monitor = AudioMonitor::new(16000);
+    monitor.start_noise_calibration();
+    // Playback audio is not the room
+    let id = playback();
+    monitor.playback_started(id);
+    for _ in 0..200 {
+        monitor.process_energy(0.3, 20);
+    }
+    assert!(monitor.is_calibrating());
+    monitor.playback_ended(&id);
+    for _ in 0..(300 + NOISE_WINDOW_MS) / 20 {
+        monitor.process_energy(0.02, 20);
+    }
+    assert!(!monitor.is_calibrating());
+    let update = monitor.take_calibration_update().expect("Run finished");
+    assert_eq!(update.noise_floor_rms, 0.02);
+    assert_eq!(monitor.calibration(), update);
+    assert!(monitor.take_calibration_update().is_none());
+
+    // 0.05 was speech at the default threshold; it is below this room's threshold
+    for _ in 0..20 {
+        assert_eq!(monitor.process_energy(0.05, 20), None);
+    }
+    let mut started = false;
+    for _ in 0..20 {
+        started |= monitor.process_energy(0.1, 20) == Some(AudioSignal::SpeechStart);
+    }
+    assert!(started);
+}
+
+#[test]
+fn test_echo_tail_is_measured_on_calibrated_pairs_only() {
+    let mut monitor = AudioMonitor::new(16000);
+    let echo = |monitor: &mut AudioMonitor| {
+        let id = playback();
+        monitor.playback_started(id);
+        monitor.process_energy(0.2, 20);
+        monitor.playback_ended(&id);
+        for _ in 0..30 {
+            monitor.process_energy(0.2, 20); // 600ms of echo
+        }
+        for _ in 0..10 {
+            monitor.process_energy(0.0, 20);
+        }
+    };
+
+    echo(&mut monitor);
+    assert!(monitor.take_calibration_update().is_none(), "Defaults are not tuned");
+
+    monitor.apply_calibration(&CalibrationProfile::default(), true);
+    echo(&mut monitor);
+    let update = monitor.take_calibration_update().expect("Echo tail measured");
+    assert_eq!(update.echo_tail_ms, (300 + 620 + 100) / 2);
+    assert_eq!(monitor.calibration().echo_tail_ms, update.echo_tail_ms);
+}
+
+#[tokio::test]
+async fn test_profiles_follow_the_device_pair() {
+    let mut reactor = reactor("pairs");
+    calibrate(&mut reactor, 0.02);
+    let speakers = reactor.device_pair();
+    assert_eq!(speakers, DevicePair::default());
+    let stored = reactor.calibration.get(&speakers).expect("Stored for the pair");
+    assert_eq!(stored.noise_floor_rms, 0.02);
+
+    // A headset never calibrated: defaults, and the speakers' profile is untouched
+    reactor.tick_step(vec![input(InputContent::InputDevice { device: Some("Headset Mic".to_string()) })]);
+    reactor.tick_step(vec![input(InputContent::OutputDevice { device: Some("Headset".to_string()) })]);
+    assert_eq!(reactor.audio_monitor.calibration(), CalibrationProfile::default());
+    calibrate(&mut reactor, 0.001);
+    let headset = DevicePair { input: Some("Headset Mic".to_string()), output: Some("Headset".to_string()) };
+    assert_eq!(reactor.calibration.get(&headset).map(|p| p.threshold_rms), Some(0.01));
+    assert_eq!(reactor.calibration.len(), 2);
+
+    // Back to the speakers: no recalibration
+    reactor.tick_step(vec![input(InputContent::InputDevice { device: None })]);
+    reactor.tick_step(vec![input(InputContent::OutputDevice { device: None })]);
+    assert_eq!(reactor.audio_monitor.calibration(), stored);
+
+    // Switching devices mid-run drops the run: its audio belonged to the old pair
+    reactor.tick_step(vec![input(InputContent::CalibrateAudio)]);
+    reactor.tick_step(vec![input(InputContent::OutputDevice { device: Some("Headset".to_string()) })]);
+    assert!(!reactor.audio_monitor.is_calibrating());
+
+    // Restart keeps the store; devices start as the defaults again
+    reactor.restart(&ShutdownOptions::default());
+    assert_eq!(reactor.calibration.len(), 2);
+    assert_eq!(reactor.audio_monitor.calibration(), stored);
+}
+
+#[test]
+fn test_store_persists() {
+    let path = temp("store.json");
+    let headset = DevicePair { input: Some("Headset Mic".to_string()), output: Some("Headset".to_string()) };
+    let profile = CalibrationProfile::default().with_noise_floor(0.02).with_echo_tail(400);
+    {
+        let mut store = CalibrationStore::open(&path).unwrap();
+        assert!(store.is_empty());
+        store.set(headset.clone(), profile);
+        // In memory until the snapshot is saved
+        assert!(!path.exists());
+        store.snapshot().unwrap().save().unwrap();
+    }
+    assert!(CalibrationStore::new().snapshot().is_none(), "In memory only");
+    let store = CalibrationStore::open(&path).unwrap();
+    assert_eq!(store.get(&headset), Some(profile));
+    assert_eq!(store.get(&DevicePair::default()), None);
+}
+
+#[tokio::test]
+async fn test_tick_emits_the_write_back() {
+    let path = temp("tick.json");
+    let (tx, rx) = tokio::sync::mpsc::channel(100);
+    let mut reactor = Reactor::builder(rx, tx)
+        .config(ReactorConfig { llm_planning: false, ..Default::default() })
+        .semantic_store(Box::new(FileSemanticStore::new(temp("tick_semantic.json"))))
+        .calibration(CalibrationStore::open(&path).unwrap())
+        .build();
+
+    reactor.tick_step(vec![input(InputContent::CalibrateAudio)]);
+    let mut snapshots = Vec::new();
+    for _ in 0..NOISE_WINDOW_MS / 20 {
+        snapshots.extend(reactor.tick_step(vec![envelope(0.02)]).iter().filter_map(|e| match e {
+            SideEffect::PersistCalibration(snapshot) => Some(snapshot.clone()),
+            _ => None,
+        }));
+    }
+    assert_eq!(snapshots.len(), 1, "One write-back per change");
+    assert!(!path.exists(), "The tick itself never writes");
+
+    snapshots[0].save().unwrap();
+    let saved = CalibrationStore::open(&path).unwrap();
+    assert_eq!(saved.get(&DevicePair::default()).map(|p| p.noise_floor_rms), Some(0.02));
+}
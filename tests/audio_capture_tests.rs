@@ -0,0 +1,30 @@
+#![cfg(feature = "vad")]
+use nexus::audio::capture::CaptureConfig;
+use nexus::audio::processing::AudioProcessor;
+use nexus::kernel::time::CaptureClock;
+use ringbuf::traits::{Producer, Split};
+use ringbuf::HeapRb;
+use tokio::sync::mpsc;
+
+#[test]
+fn test_capture_config_defaults_are_vad_rates() {
+    let config = CaptureConfig::default();
+    assert!(config.device_name.is_none());
+    assert_eq!(config.preferred_rates.first(), Some(&16000));
+    assert!(config.preferred_rates.iter().all(|r| [8000, 16000, 32000, 48000].contains(r)));
+}
+
+#[test]
+fn test_processor_exits_when_capture_closes() {
+    let (tx, _rx) = mpsc::channel(10);
+    let (mut producer, consumer) = HeapRb::<f32>::new(8192).split();
+    producer.push_slice(&[0.0; 100]);
+    drop(producer); // Stream stopped
+
+    let handle = std::thread::spawn(move || AudioProcessor::new(consumer, tx, 16000, CaptureClock::new()).run());
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(2);
+    while !handle.is_finished() {
+        assert!(std::time::Instant::now() < deadline, "Processor must exit once the producer is dropped");
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+}
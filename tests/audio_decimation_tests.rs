@@ -0,0 +1,122 @@
+use nexus::audio::decimate::{ChunkShaper, FidelitySwitch, ReducedMode};
+use nexus::kernel::event::{AudioSignal, AudioSummary, CaptureFidelity, InputContent};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use tokio::sync::mpsc;
+
+mod common;
+use common::input;
+
+fn fidelity_effects(effects: &[SideEffect]) -> Vec<CaptureFidelity> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::SetCaptureFidelity(f) => Some(*f),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn test_shaper_decimates_stereo_48k_to_16k_mono() {
+    let switch = FidelitySwitch::new();
+    let mut shaper = ChunkShaper::new(48000, 2, ReducedMode::default(), switch.clone());
+
+    // 10ms of 48kHz stereo
+    match shaper.shape(&[0.25; 960]) {
+        InputContent::AudioSummary(AudioSummary::Decimated { samples, sample_rate }) => {
+            assert_eq!(sample_rate, 16000);
+            assert_eq!(samples.len(), 160);
+        }
+        other => panic!("Expected decimated summary, got {:?}", other),
+    }
+
+    // Segment buffering: full-rate mono, the first chunk led by the pre-roll
+    switch.set(CaptureFidelity::Full);
+    for expected in [960, 480] {
+        match shaper.shape(&[0.25; 960]) {
+            InputContent::AudioChunk(samples) => assert_eq!(samples.len(), expected),
+            other => panic!("Expected full chunk, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_shaper_envelope_mode() {
+    let mut shaper = ChunkShaper::new(48000, 1, ReducedMode::Envelope, FidelitySwitch::new());
+    match shaper.shape(&[0.5; 960]) {
+        InputContent::AudioSummary(AudioSummary::Envelope { rms, duration_ms }) => {
+            assert!((rms - 0.5).abs() < 1e-6);
+            assert_eq!(duration_ms, 20);
+        }
+        other => panic!("Expected envelope, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_kernel_requests_full_fidelity_only_while_buffering() {
+    let (tx, rx) = mpsc::channel(100);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
+
+    let effects = reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechStart))]);
+    assert_eq!(fidelity_effects(&effects), vec![CaptureFidelity::Full]);
+
+    // Summaries never reach the segment buffer
+    let seg_id = reactor.state.active_segment_id().cloned().unwrap();
+    reactor.tick_step(vec![input(InputContent::AudioSummary(AudioSummary::Decimated { samples: vec![0.0; 160], sample_rate: 16000 }))]);
+    assert!(reactor.state.audio_segments()[&seg_id].frames.is_empty());
+
+    // No change -> no repeated effect
+    let effects = reactor.tick_step(vec![input(InputContent::AudioChunk(vec![0.0; 480]))]);
+    assert!(fidelity_effects(&effects).is_empty());
+
+    let effects = reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechEnd))]);
+    assert_eq!(fidelity_effects(&effects), vec![CaptureFidelity::Reduced]);
+}
+
+#[tokio::test]
+async fn test_envelope_drives_core_vad() {
+    let (tx, rx) = mpsc::channel(100);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
+
+    // 200ms of loud envelope (> 120ms min speech)
+    for _ in 0..10 {
+        reactor.tick_step(vec![input(InputContent::AudioSummary(AudioSummary::Envelope { rms: 0.5, duration_ms: 20 }))]);
+    }
+    assert!(reactor.state.active_segment_id().is_some(), "Envelope energy must open a segment");
+}
+
+#[tokio::test]
+async fn test_preroll_puts_the_onset_in_the_segment() {
+    let (tx, rx) = mpsc::channel(100);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
+    let switch = FidelitySwitch::new();
+    let mut shaper = ChunkShaper::new(48000, 1, ReducedMode::default(), switch.clone());
+
+    // 20ms chunks (the kernel reads full chunks as 48kHz) of onset speech, sent as summaries until the kernel asks for Full
+    let onset = [0.3; 960];
+    let mut onset_chunks = 0;
+    while fidelity_effects(&reactor.tick_step(vec![input(shaper.shape(&onset))])) != vec![CaptureFidelity::Full] {
+        onset_chunks += 1;
+        assert!(onset_chunks < 50, "Onset never opened a segment");
+    }
+    onset_chunks += 1;
+    let seg_id = reactor.state.active_segment_id().cloned().unwrap();
+    // The driver applies the switch a chunk late
+    reactor.tick_step(vec![input(shaper.shape(&onset))]);
+    onset_chunks += 1;
+    switch.set(CaptureFidelity::Full);
+
+    for _ in 0..10 {
+        reactor.tick_step(vec![input(shaper.shape(&[0.2; 960]))]);
+    }
+    for _ in 0..50 {
+        if reactor.state.active_segment_id().is_none() {
+            break;
+        }
+        reactor.tick_step(vec![input(shaper.shape(&[0.0; 960]))]);
+    }
+    assert!(reactor.state.active_segment_id().is_none(), "Silence must close the segment");
+
+    let frames = &reactor.state.audio_segments()[&seg_id].frames;
+    let onset_len = onset_chunks * 960;
+    assert!(frames[..onset_len].iter().all(|&x| x == 0.3), "Onset missing from the segment start");
+    assert_eq!(frames[onset_len], 0.2);
+}
@@ -0,0 +1,132 @@
+use nexus::kernel::backchannel::{
+    allows_cue, Backchannel, Cue, BACKCHANNEL_INTERVAL_TICKS, BACKCHANNEL_MIN_TURN_TICKS, BACKCHANNEL_PAUSE_TICKS, TURN_GAP_TICKS,
+};
+use nexus::kernel::event::{AudioSignal, Event, InputContent, InputEvent, Output, OutputId, OutputStatus};
+use nexus::kernel::presence::PresenceState;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::state::{SharedState, StateDelta};
+use nexus::kernel::time::Tick;
+
+mod common;
+use common::input;
+
+const WORDS: &[&str] = &["so", "I", "was", "thinking", "we", "could", "drive", "up", "the", "coast", "this", "weekend"];
+
+fn reactor(backchannel: bool) -> Reactor {
+    let mut reactor = common::builder().config(ReactorConfig { backchannel, ..common::config() }).build();
+    reactor.state.reduce(StateDelta::PresenceUpdate(PresenceState::Engaged));
+    reactor
+}
+
+fn fragment(text: &str) -> Event {
+    input(InputContent::ProvisionalText { content: text.to_string(), confidence: 0.9, source_id: "seg_1".to_string(), words: Vec::new(), language: None, is_final: true })
+}
+
+fn cues(effects: &[SideEffect]) -> Vec<Cue> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::PlayCue(cue) => Some(*cue),
+        _ => None,
+    }).collect()
+}
+
+/// Speak for `ticks`, with a growing transcript every 25 ticks (`rewrite`: each fragment
+/// replaces the last instead of extending it). Returns the cues played meanwhile.
+fn speak(reactor: &mut Reactor, ticks: u64, rewrite: bool) -> Vec<Cue> {
+    let mut played = cues(&reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechStart))]));
+    for t in 1..ticks {
+        let events = if t % 25 == 0 {
+            let n = (t / 25) as usize;
+            let text = if rewrite { format!("{} {}", WORDS[n % WORDS.len()], WORDS[(n + 5) % WORDS.len()]) } else { WORDS[..n.min(WORDS.len())].join(" ") };
+            vec![fragment(&text)]
+        } else {
+            vec![]
+        };
+        played.extend(cues(&reactor.tick_step(events)));
+    }
+    played
+}
+
+/// Stop speaking and stay silent for `ticks`. Returns the cues played meanwhile.
+fn pause(reactor: &mut Reactor, ticks: u64) -> Vec<Cue> {
+    let mut played = cues(&reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechEnd))]));
+    for _ in 1..ticks {
+        played.extend(cues(&reactor.tick_step(vec![])));
+    }
+    played
+}
+
+#[test]
+fn test_cue_in_a_pause_of_a_long_stable_turn() {
+    let mut reactor = reactor(true);
+    assert!(speak(&mut reactor, BACKCHANNEL_MIN_TURN_TICKS + 10, false).is_empty(), "Never over the user's voice");
+    assert!(pause(&mut reactor, BACKCHANNEL_PAUSE_TICKS).is_empty(), "Not at the first silent frames");
+    assert_eq!(cues(&reactor.tick_step(vec![])), [Cue::MmHm]);
+    assert!(reactor.state.active_outputs().is_empty(), "A cue is not an output");
+    assert!(pause(&mut reactor, 5).is_empty());
+
+    // Same turn (the pause was short): no second cue before the interval
+    assert!(speak(&mut reactor, BACKCHANNEL_INTERVAL_TICKS - 100, false).is_empty());
+    assert!(pause(&mut reactor, TURN_GAP_TICKS - 5).is_empty());
+    assert!(speak(&mut reactor, 100, false).is_empty());
+    assert_eq!(pause(&mut reactor, TURN_GAP_TICKS - 5), [Cue::Mm], "Consecutive cues alternate");
+    assert_eq!(reactor.backchannel.cues(), 2);
+}
+
+#[test]
+fn test_short_or_unstable_turns_get_no_cue() {
+    let mut reactor = reactor(true);
+    speak(&mut reactor, BACKCHANNEL_MIN_TURN_TICKS - 50, false);
+    assert!(pause(&mut reactor, TURN_GAP_TICKS + 5).is_empty());
+    // A long pause ended that turn: this one starts from zero
+    speak(&mut reactor, BACKCHANNEL_MIN_TURN_TICKS - 50, false);
+    assert!(pause(&mut reactor, TURN_GAP_TICKS).is_empty());
+
+    let mut reactor = self::reactor(true);
+    speak(&mut reactor, BACKCHANNEL_MIN_TURN_TICKS + 10, true);
+    assert!(reactor.backchannel.stability() < 0.5);
+    assert!(pause(&mut reactor, TURN_GAP_TICKS).is_empty(), "The transcript keeps changing");
+}
+
+#[test]
+fn test_switch_and_presence_gate_cues() {
+    let mut off = reactor(false);
+    speak(&mut off, BACKCHANNEL_MIN_TURN_TICKS + 10, false);
+    assert!(pause(&mut off, TURN_GAP_TICKS).is_empty());
+
+    let mut holding = reactor(true);
+    speak(&mut holding, BACKCHANNEL_MIN_TURN_TICKS + 10, false);
+    holding.state.reduce(StateDelta::PresenceUpdate(PresenceState::QuietlyHolding));
+    assert!(pause(&mut holding, TURN_GAP_TICKS).is_empty());
+}
+
+#[test]
+fn test_no_cue_while_speaking_or_contested() {
+    let mut state = SharedState::new();
+    state.reduce(StateDelta::PresenceUpdate(PresenceState::Engaged));
+    assert!(allows_cue(&state));
+
+    // Our own output is playing, and the user talks over it: turn pressure builds
+    state.reduce(StateDelta::OutputProposed(Output {
+        id: OutputId { tick: 1, ordinal: 0 },
+        content: "Here is the forecast".to_string(),
+        status: OutputStatus::Draft,
+        proposed_at: Tick { frame: 1 },
+        committed_at: None,
+        parent_id: None,
+    }));
+    assert!(!allows_cue(&state));
+    state.reduce(StateDelta::InputReceived(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::Audio(AudioSignal::SpeechStart),
+        captured_at: None,
+    }));
+    for frame in 2..6 {
+        state.reduce(StateDelta::Tick(Tick { frame }));
+    }
+    state.reduce(StateDelta::AllOutputsCleared);
+    assert!(state.turn_pressure() > 0.2);
+    assert!(!allows_cue(&state), "Contested turn");
+
+    assert_eq!(Backchannel::new().stability(), 0.0);
+}
@@ -0,0 +1,133 @@
+use nexus::kernel::bookmark::{keywords, parse_bookmark_request, BookmarkRequest};
+use nexus::kernel::context::ContextId;
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::intent::long_horizon::IntentStatus;
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::speech::planner::SpeechIntent;
+
+mod common;
+use common::reactor;
+
+fn text(text: &str) -> Event {
+    Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::Text(text.to_string()), captured_at: None })
+}
+
+fn heard(text: &str, is_final: bool) -> Event {
+    Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::ProvisionalText {
+            content: text.to_string(),
+            confidence: 0.9,
+            source_id: "seg_bookmark".to_string(),
+            words: Vec::new(),
+            language: None,
+            is_final,
+        },
+        captured_at: None,
+    })
+}
+
+fn confirmed(effects: &[SideEffect], details: &str) -> bool {
+    effects.iter().any(|e| matches!(e, SideEffect::RequestSpeech { intent: SpeechIntent::Confirmation(d), .. } if d == details))
+}
+
+fn spoken(effects: &[SideEffect]) -> Vec<String> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::SpawnAudio(_, text) => Some(text.clone()),
+        _ => None,
+    }).collect()
+}
+
+fn create(name: Option<&str>) -> Option<BookmarkRequest> {
+    Some(BookmarkRequest::Create { name: name.map(str::to_string) })
+}
+
+fn recall(query: Option<&str>) -> Option<BookmarkRequest> {
+    Some(BookmarkRequest::Recall { query: query.map(str::to_string) })
+}
+
+#[test]
+fn test_spoken_bookmark_requests() {
+    assert_eq!(parse_bookmark_request("Remember this point"), create(None));
+    assert_eq!(parse_bookmark_request("bookmark this"), create(None));
+    assert_eq!(parse_bookmark_request("bookmark this as the budget"), create(Some("budget")));
+    assert_eq!(parse_bookmark_request("bookmark the travel plans"), create(Some("travel plans")));
+    assert_eq!(parse_bookmark_request("save this moment and call it taxes"), create(Some("taxes")));
+    assert_eq!(parse_bookmark_request("remember that place we went last summer?"), None, "About the past");
+    assert_eq!(parse_bookmark_request("remember this point about the lease"), None, "A clause, not a name");
+
+    assert_eq!(parse_bookmark_request("go back to the budget discussion"), recall(Some("budget")));
+    assert_eq!(parse_bookmark_request("let's return to what we discussed"), recall(None));
+    assert_eq!(parse_bookmark_request("back to the taxes bookmark"), recall(Some("taxes")));
+    assert_eq!(parse_bookmark_request("back to the taxes"), None, "Names no bookmark or discussion");
+    assert_eq!(parse_bookmark_request("return the book to the library"), None);
+    assert_eq!(parse_bookmark_request("go back to the"), None, "A fragment names nothing yet");
+    assert_eq!(parse_bookmark_request("go back to my work context"), None, "A context switch");
+    assert_eq!(parse_bookmark_request("remember to buy milk"), None);
+    assert_eq!(parse_bookmark_request("what's the weather"), None);
+
+    assert_eq!(keywords("We need to cut the travel budget by ten percent"), ["travel", "budget", "percent"]);
+}
+
+#[test]
+fn test_recall_resumes_and_regrounds() {
+    let mut reactor = reactor();
+    reactor.tick_step(vec![text("How much can we cut from the travel budget?")]);
+    let budget: Vec<_> = reactor.state.active_intents().values().map(|i| i.id.clone()).collect();
+    assert_eq!(budget.len(), 1);
+
+    let effects = reactor.tick_step(vec![text("remember this point")]);
+    assert!(confirmed(&effects, "bookmark_created"));
+    let bookmark = &reactor.bookmarks.list()[0];
+    assert_eq!(bookmark.name, "travel budget", "Named from what was said");
+    assert_eq!(bookmark.intent_ids, budget);
+    assert_eq!(reactor.state.active_intents().len(), 1, "A command, not an utterance");
+
+    // Elsewhere, the budget intent is parked
+    reactor.tick_step(vec![text("switch to work context")]);
+    reactor.tick_step(vec![text("Schedule the team review for Friday")]);
+    assert_eq!(reactor.state.active_intents()[&budget[0]].status, IntentStatus::Suspended);
+
+    let effects = reactor.tick_step(vec![text("go back to the budget discussion")]);
+    assert_eq!(reactor.state.active_context(), &ContextId::default());
+    assert_eq!(reactor.state.active_intents()[&budget[0]].status, IntentStatus::Active, "Resumed");
+    let said = spoken(&effects);
+    assert_eq!(said.len(), 1, "{:?}", said);
+    assert!(said[0].contains("travel budget") && said[0].contains("How much can we cut from the travel budget"), "{}", said[0]);
+    let reground = reactor.state.active_outputs().values().any(|o| o.parent_id.as_ref() == Some(&budget[0]));
+    assert!(reground, "Linked to the resumed intent");
+}
+
+#[test]
+fn test_sensitive_turns_and_misses() {
+    let mut reactor = reactor();
+    reactor.tick_step(vec![text("My password is hunter2")]);
+    reactor.tick_step(vec![text("bookmark this as login")]);
+    assert_eq!(reactor.bookmarks.list()[0].summary, "", "Never quoted");
+
+    let effects = reactor.tick_step(vec![text("go back to the login bookmark")]);
+    let said = spoken(&effects);
+    assert!(said.len() == 1 && !said[0].contains("hunter2"), "{:?}", said);
+
+    // Nothing by that name
+    let effects = reactor.tick_step(vec![text("go back to what we discussed about the garden")]);
+    assert!(confirmed(&effects, "bookmark_not_found"));
+    // Not about a bookmark at all: the arbitrator hears it
+    let effects = reactor.tick_step(vec![text("go back to sleep")]);
+    assert!(!confirmed(&effects, "bookmark_not_found") && spoken(&effects).is_empty());
+    assert_eq!(reactor.bookmarks.len(), 1);
+}
+
+#[test]
+fn test_spoken_commands_wait_for_the_final_transcript() {
+    let mut reactor = reactor();
+    reactor.tick_step(vec![text("How much can we cut from the travel budget?")]);
+    // "remember this point..." may still go on ("...about the lease")
+    let effects = reactor.tick_step(vec![heard("remember this point", false)]);
+    assert!(!confirmed(&effects, "bookmark_created"));
+    assert!(reactor.bookmarks.is_empty());
+
+    let effects = reactor.tick_step(vec![heard("remember this point", true)]);
+    assert!(confirmed(&effects, "bookmark_created"));
+    assert_eq!(reactor.bookmarks.len(), 1);
+}
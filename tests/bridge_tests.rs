@@ -0,0 +1,176 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use nexus::kernel::bridge::{Bridge, BridgeConfig, BridgeError, ClientMessage, MicSwitch, ServerMessage, Subscription, Topic};
+use nexus::kernel::dialogue::Speaker;
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::memory::sensitivity::RedactionLevel;
+use nexus::kernel::reactor::Reactor;
+use tokio::sync::broadcast;
+
+mod common;
+use common::{input, reactor};
+
+fn attach(reactor: &mut Reactor, snapshot_interval_ticks: u64) -> Bridge {
+    let (tx, _) = tokio::sync::mpsc::channel(1);
+    Bridge::attach(reactor, tx, BridgeConfig { snapshot_interval_ticks, ..Default::default() })
+}
+
+fn drain(outbox: &mut broadcast::Receiver<ServerMessage>) -> Vec<ServerMessage> {
+    std::iter::from_fn(|| outbox.try_recv().ok()).collect()
+}
+
+struct Mic(AtomicBool);
+
+impl MicSwitch for Mic {
+    fn set_active(&self, active: bool) {
+        self.0.store(active, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn test_client_messages() {
+    let text = ClientMessage::parse(r#"{"type":"text","text":"What is gravity?"}"#).unwrap();
+    assert!(matches!(text.input().unwrap().content, InputContent::Text(t) if t == "What is gravity?"));
+    assert_eq!(text.input().unwrap().source, "Bridge");
+
+    let interim = ClientMessage::parse(r#"{"type":"transcript","text":"turn on","segment_id":"web1","confidence":0.5,"is_final":false}"#).unwrap();
+    assert!(matches!(
+        interim.input().unwrap().content,
+        InputContent::ProvisionalText { content, source_id, is_final: false, .. } if content == "turn on" && source_id == "web1"
+    ));
+
+    let subscribe = ClientMessage::parse(r#"{"type":"subscribe","topics":["presence","output"]}"#).unwrap();
+    assert_eq!(subscribe, ClientMessage::Subscribe { topics: vec![Topic::Presence, Topic::Output] });
+    assert!(subscribe.input().is_none(), "Handled by the bridge");
+    assert!(ClientMessage::parse(r#"{"type":"mic","active":false}"#).unwrap().input().is_none());
+
+    assert!(matches!(ClientMessage::parse(r#"{"type":"reboot"}"#), Err(BridgeError::Malformed(_))));
+    assert!(matches!(ClientMessage::parse("hello"), Err(BridgeError::Malformed(_))));
+}
+
+#[test]
+fn test_config_always_needs_token() {
+    // Loopback is reachable from any page in the user's browser
+    let config = BridgeConfig::default();
+    assert!(config.bind.ip().is_loopback());
+    assert_eq!(config.check(), Err(BridgeError::TokenRequired));
+    assert!(!config.authorized(None) && !config.authorized(Some("")));
+
+    let guarded = BridgeConfig { token: Some("s3cret".to_string()), ..Default::default() };
+    assert_eq!(guarded.check(), Ok(()));
+    assert!(guarded.authorized(Some("s3cret")));
+    assert!(!guarded.authorized(Some("guess")) && !guarded.authorized(None));
+
+    assert!(!guarded.authorized(Some("s3cre")) && !guarded.authorized(Some("s3cret!")), "Prefixes and extensions");
+
+    // Unset or empty: generated, never empty
+    let generated = BridgeConfig::token_or_generated(None);
+    assert!(generated.len() >= 32);
+    assert_ne!(generated, BridgeConfig::token_or_generated(None));
+    assert!(BridgeConfig::token_or_generated(Some(String::new())).len() >= 32);
+    assert_eq!(BridgeConfig::token_or_generated(Some("s3cret".to_string())), "s3cret");
+}
+
+#[test]
+fn test_browser_origins_must_be_allowed() {
+    let config = BridgeConfig { token: Some("s3cret".to_string()), allowed_origins: vec!["http://localhost:5173".to_string()], ..Default::default() };
+    assert!(config.origin_allowed(Some("http://localhost:5173")));
+    assert!(!config.origin_allowed(Some("https://evil.example")), "Cross-site page");
+    assert!(!config.origin_allowed(Some("null")));
+    assert!(config.origin_allowed(None), "Not a browser: the token decides");
+    assert!(!BridgeConfig::default().origin_allowed(Some("http://localhost:5173")), "None allowed by default");
+}
+
+#[tokio::test]
+async fn test_stream_of_presence_outputs_and_snapshots() {
+    let mut reactor = reactor();
+    let bridge = attach(&mut reactor, 5);
+    let mut outbox = bridge.subscribe();
+    assert!(matches!(bridge.greeting()[..], [ServerMessage::Hello { version: 1 }, ServerMessage::Snapshot { .. }]));
+
+    reactor.tick_step(vec![]);
+    reactor.tick_step(vec![input(InputContent::WakeWord { phrase: "nexus".to_string() })]);
+    reactor.tick_step(vec![input(InputContent::Text("Turn on the lights".to_string()))]);
+    for _ in 0..9 {
+        reactor.tick_step(vec![]);
+    }
+    let messages = drain(&mut outbox);
+
+    // Throttled: ticks 1, 6 and 11 of 12
+    let snapshots: Vec<u64> = messages.iter().filter_map(|m| match m {
+        ServerMessage::Snapshot { view } => Some(view.tick.frame),
+        _ => None,
+    }).collect();
+    assert_eq!(snapshots, [1, 6, 11]);
+    // Presence transitions at the tick they happened, even between snapshots
+    assert!(messages.iter().any(|m| matches!(m, ServerMessage::Presence { tick, from, to } if tick.frame == 2 && from != to)), "{:?}", messages);
+    assert!(messages.iter().any(|m| matches!(m, ServerMessage::Speech { description, .. } if description == "(confirmed: command_ack)")), "{:?}", messages);
+
+    // Topics filter per connection; Hello and Error always pass
+    let mut subscription = Subscription::default();
+    subscription.set_topics(&[Topic::Output]);
+    let kept: Vec<_> = messages.iter().filter(|m| subscription.wants(m)).collect();
+    assert!(!kept.is_empty() && kept.iter().all(|m| m.topic() == Some(Topic::Output)));
+    assert!(subscription.wants(&ServerMessage::Error { message: String::new() }));
+}
+
+#[tokio::test]
+async fn test_client_input_reaches_kernel_channel() {
+    let mut reactor = reactor();
+    let (tx, mut rx) = tokio::sync::mpsc::channel(4);
+    let bridge = Bridge::attach(&mut reactor, tx, BridgeConfig::default());
+    let mut subscription = Subscription::default();
+
+    bridge.handle(ClientMessage::Text { text: "hello".to_string() }, &mut subscription).await.unwrap();
+    match rx.try_recv() {
+        Ok(Event::Input(InputEvent { source, content: InputContent::Text(text), .. })) => assert_eq!((source.as_str(), text.as_str()), ("Bridge", "hello")),
+        other => panic!("Expected the typed text, got {:?}", other),
+    }
+
+    // The mic is the driver's; without one the toggle is refused
+    let mic_off = ClientMessage::Mic { active: false };
+    assert_eq!(bridge.handle(mic_off.clone(), &mut subscription).await, Err(BridgeError::MicUnavailable));
+    let mic = Arc::new(Mic(AtomicBool::new(true)));
+    let bridge = bridge.with_mic(mic.clone());
+    bridge.handle(mic_off, &mut subscription).await.unwrap();
+    assert!(!mic.0.load(Ordering::Relaxed));
+    assert!(rx.try_recv().is_err(), "Mic toggles are not kernel input");
+
+    bridge.handle(ClientMessage::Subscribe { topics: vec![Topic::Presence] }, &mut subscription).await.unwrap();
+    assert!(!subscription.wants(&ServerMessage::ConsentDismissed { prompt_id: "p".to_string() }));
+
+    drop(rx);
+    assert_eq!(bridge.handle(ClientMessage::Text { text: "bye".to_string() }, &mut subscription).await, Err(BridgeError::KernelClosed));
+}
+
+#[cfg(feature = "bridge")]
+#[tokio::test]
+async fn test_server_refuses_to_start_without_token() {
+    use nexus::kernel::bridge::server::{serve, token_param};
+    assert_eq!(token_param(Some("a=1&token=s3cret")).as_deref(), Some("s3cret"));
+    assert_eq!(token_param(Some("token=a%2Bb%3D%26c")).as_deref(), Some("a+b=&c"), "Percent-decoded");
+    assert_eq!(token_param(Some("a=1")), None);
+    assert_eq!(token_param(None), None);
+
+    let mut reactor = reactor();
+    let (tx, _rx) = tokio::sync::mpsc::channel(1);
+    let bridge = Bridge::attach(&mut reactor, tx, BridgeConfig { bind: "127.0.0.1:0".parse().unwrap(), ..Default::default() });
+    assert!(serve(bridge).await.is_err());
+}
+
+#[tokio::test]
+async fn test_interim_transcripts_make_one_dialogue_turn() {
+    let mut reactor = reactor();
+    let bridged = |frame: &str| Event::Input(ClientMessage::parse(frame).unwrap().input().unwrap());
+
+    reactor.tick_step(vec![bridged(r#"{"type":"transcript","text":"what's the","segment_id":"web1","confidence":0.5,"is_final":false}"#)]);
+    reactor.tick_step(vec![bridged(r#"{"type":"transcript","text":"what's the weather","segment_id":"web1","confidence":0.6,"is_final":false}"#)]);
+    reactor.tick_step(vec![bridged(r#"{"type":"transcript","text":"what's the weather in Paris","segment_id":"web1","confidence":0.9,"is_final":true}"#)]);
+
+    let user_turns: Vec<_> = reactor.dialogue.recent(16, RedactionLevel::None).into_iter()
+        .filter(|t| t.speaker == Speaker::User)
+        .collect();
+    assert_eq!(user_turns.len(), 1, "{:?}", user_turns);
+    assert_eq!(user_turns[0].text, "what's the weather in Paris");
+}
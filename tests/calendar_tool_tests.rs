@@ -0,0 +1,107 @@
+use nexus::integrations::calendar::{Calendar, CalendarQuery};
+use nexus::kernel::memory::sensitivity::SensitivityClass;
+use nexus::kernel::reactor::Reactor;
+use nexus::planner::tools::{parse_tool_call, tool_specs, tool_specs_for, ToolCall, ToolConsent, CALENDAR_EVENTS, RECALL_EPISODES};
+use serde_json::json;
+
+mod common;
+
+// 2026-03-02T00:00:00Z
+const MARCH_2: u64 = 1_772_409_600_000;
+const HOUR: u64 = 3_600_000;
+
+const ICS: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+BEGIN:VEVENT\r
+UID:2\r
+DTSTART:20260302T140000Z\r
+DTEND:20260302T150000Z\r
+SUMMARY:Design review\\, round 2\r
+DESCRIPTION:Dial-in code 998877\r
+LOCATION:Room 4\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:1\r
+DTSTART:20260302T090000Z\r
+DTEND:20260302T093000Z\r
+SUMMARY:Standup\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:3\r
+DTSTART;VALUE=DATE:20260303\r
+SUMMARY:Doctor appointment about my medication and the long title\r
+ continued on a folded line\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:4\r
+DTSTART:20260302T100000Z\r
+SUMMARY:Cancelled sync\r
+STATUS:CANCELLED\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+fn reactor(calendar: Calendar) -> Reactor {
+    common::builder()
+        .calendar(calendar)
+        .build()
+}
+
+fn names(specs: serde_json::Value) -> Vec<String> {
+    specs.as_array().unwrap().iter().map(|t| t["name"].as_str().unwrap().to_string()).collect()
+}
+
+#[test]
+fn test_ics_parsing_and_next_event() {
+    let calendar = Calendar::from_ics(ICS);
+    assert_eq!(calendar.len(), 3, "Cancelled events are dropped");
+
+    let next = calendar.query(&CalendarQuery::default(), MARCH_2 + 8 * HOUR);
+    assert_eq!(next.iter().map(|e| e.title.as_str()).collect::<Vec<_>>(), vec!["Standup", "Design review, round 2", "[private]"]);
+    assert_eq!(next[0].start_ms, MARCH_2 + 9 * HOUR);
+    assert_eq!(next[1].end_ms, Some(MARCH_2 + 15 * HOUR));
+    assert!(next[1].has_location);
+    assert!(next[2].all_day);
+    assert_eq!(next[2].start_ms, MARCH_2 + 24 * HOUR);
+
+    // An event in progress is still "next"; finished ones are not
+    let during = calendar.query(&CalendarQuery { limit: Some(1), ..Default::default() }, MARCH_2 + 14 * HOUR + 1);
+    assert_eq!(during[0].title, "Design review, round 2");
+    let window = CalendarQuery { from_ms: Some(MARCH_2), to_ms: Some(MARCH_2 + 12 * HOUR), limit: None };
+    assert_eq!(calendar.query(&window, 0).len(), 1);
+}
+
+#[test]
+fn test_summaries_pass_the_firewall() {
+    let summaries = Calendar::from_ics(ICS).query(&CalendarQuery::default(), 0);
+    let private = &summaries[2];
+    assert_eq!(private.title, "[private]");
+    assert_eq!(private.sensitivity, Some(SensitivityClass::Health));
+
+    let text = serde_json::to_string(&summaries).unwrap();
+    assert!(!text.contains("998877"), "Descriptions never reach the planner");
+    assert!(!text.contains("Room 4"), "Locations are reduced to a flag");
+}
+
+#[test]
+fn test_calendar_tool_requires_consent() {
+    assert_eq!(names(tool_specs()), vec![RECALL_EPISODES.to_string()]);
+    let mut consent = ToolConsent::default();
+    consent.set(CALENDAR_EVENTS, true);
+    assert_eq!(names(tool_specs_for(&consent)), vec![RECALL_EPISODES.to_string(), CALENDAR_EVENTS.to_string()]);
+
+    let mut reactor = reactor(Calendar::from_ics(ICS));
+    let call = parse_tool_call(CALENDAR_EVENTS, &json!({ "limit": 1 })).expect("valid call");
+    assert!(matches!(&call, ToolCall::CalendarEvents(q) if q.limit == Some(1)));
+
+    let denied = reactor.run_tool(&call, MARCH_2);
+    assert_eq!(denied["error"], "consent required");
+
+    reactor.tool_consent.set(CALENDAR_EVENTS, true);
+    let result = reactor.run_tool(&call, MARCH_2);
+    assert_eq!(result.as_array().unwrap().len(), 1);
+    assert_eq!(result[0]["title"], "Standup");
+
+    reactor.tool_consent.set(CALENDAR_EVENTS, false);
+    assert_eq!(reactor.run_tool(&call, MARCH_2)["error"], "consent required");
+}
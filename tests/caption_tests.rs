@@ -0,0 +1,64 @@
+use nexus::kernel::event::{OutputId, PlaybackId};
+use nexus::kernel::reactor::ReactorConfig;
+use nexus::outputs::captions::{playback_command, Caption, CaptionTiming};
+use nexus::outputs::playback::estimate_duration_ms;
+
+fn id() -> PlaybackId {
+    OutputId { tick: 7, ordinal: 0 }.into()
+}
+
+#[test]
+fn test_estimated_words_cover_the_utterance() {
+    let text = "Sure, the extraordinary meeting is at three.";
+    let caption = Caption::estimate(id(), text, true);
+    assert_eq!(caption.timing, CaptionTiming::Estimated);
+    assert_eq!(caption.duration_ms, estimate_duration_ms(text));
+    assert_eq!(caption.words.len(), 7);
+
+    // Contiguous, monotonic, ending exactly at the estimated duration
+    assert_eq!(caption.words[0].start_ms, 0);
+    for pair in caption.words.windows(2) {
+        assert_eq!(pair[0].end_ms, pair[1].start_ms);
+        assert!(pair[0].start_ms < pair[0].end_ms);
+    }
+    assert_eq!(caption.words.last().unwrap().end_ms, caption.duration_ms);
+
+    // Longer words and punctuation take longer than short ones
+    let span = |i: usize| caption.words[i].end_ms - caption.words[i].start_ms;
+    assert!(span(2) > span(1), "'extraordinary' outlasts 'the'");
+    assert!(span(0) > span(1), "the comma after 'Sure' adds a pause");
+
+    assert_eq!(caption.word_at(0), Some(0));
+    assert_eq!(caption.word_at(caption.words[3].start_ms), Some(3));
+    assert_eq!(caption.word_at(caption.duration_ms), None);
+}
+
+#[test]
+fn test_tts_offsets_take_precedence() {
+    let caption = Caption::from_word_offsets(id(), "Hello there friend", &[0, 300, 700], 1_000, true);
+    assert_eq!(caption.timing, CaptionTiming::Tts);
+    assert_eq!((caption.words[1].start_ms, caption.words[1].end_ms), (300, 700));
+    assert_eq!(caption.words[2].end_ms, 1_000);
+
+    // Metadata that does not match the words falls back to the estimate
+    let mismatched = Caption::from_word_offsets(id(), "Hello there friend", &[0, 300], 1_000, true);
+    assert_eq!(mismatched.timing, CaptionTiming::Estimated);
+    let unsorted = Caption::from_word_offsets(id(), "Hello there", &[300, 0], 1_000, true);
+    assert_eq!(unsorted.timing, CaptionTiming::Estimated);
+}
+
+#[test]
+fn test_caption_only_mode() {
+    assert!(!ReactorConfig::default().caption_only, "Speech is on by default");
+
+    let caption = Caption::estimate(id(), "Nothing is spoken", false);
+    let json = serde_json::to_value(&caption).unwrap();
+    assert_eq!(json["audible"], false);
+    assert_eq!(json["playback_id"]["Output"]["tick"], 7);
+
+    let silent = playback_command("Nothing is spoken", true);
+    assert_eq!(silent.as_std().get_program(), "sleep");
+    let args: Vec<_> = silent.as_std().get_args().collect();
+    assert_eq!(args, vec!["1.200"]);
+    assert_eq!(playback_command("Hi", false).as_std().get_program(), "say");
+}
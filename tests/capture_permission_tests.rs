@@ -0,0 +1,56 @@
+use nexus::audio::permission::{permission_event, SilenceProbe};
+use nexus::kernel::diagnostic::DiagnosticCode;
+use nexus::kernel::presence::{CapturePermission, PresenceGraph, PresenceRequest, PresenceState};
+use nexus::kernel::reactor::KernelMode;
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::state::StateDelta;
+
+mod common;
+use common::reactor;
+
+#[test]
+fn test_silence_probe_verdicts() {
+    let mut probe = SilenceProbe::new(1000); // 1500 samples window
+    assert_eq!(probe.observe(&[0.0; 1000]), None);
+    assert_eq!(probe.observe(&[0.0; 500]), Some(CapturePermission::Denied));
+    assert_eq!(probe.observe(&[0.0; 500]), None, "Reported once");
+
+    let mut probe = SilenceProbe::new(1000);
+    assert_eq!(probe.observe(&[0.0, 0.0, 0.0001]), Some(CapturePermission::Granted), "Any noise floor means audio flows");
+    assert!(probe.is_done());
+}
+
+#[test]
+fn test_attentive_requires_permission() {
+    use PresenceState::*;
+    assert_eq!(PresenceGraph::transition_gated(Dormant, PresenceRequest::SystemBoot, CapturePermission::Denied), None);
+    assert_eq!(PresenceGraph::transition_gated(Engaged, PresenceRequest::OutputCompleted, CapturePermission::NotDetermined), None);
+    assert_eq!(PresenceGraph::transition_gated(Dormant, PresenceRequest::SystemBoot, CapturePermission::Unknown), Some(Attentive), "No probe: unchanged behaviour");
+    assert_eq!(PresenceGraph::transition_gated(Attentive, PresenceRequest::InputActivity, CapturePermission::Denied), Some(Engaged), "Typing still engages");
+    assert_eq!(PresenceGraph::transition(Attentive, PresenceRequest::CaptureBlocked), Some(Dormant));
+    assert_eq!(PresenceGraph::transition(Dormant, PresenceRequest::CaptureGranted), Some(Attentive));
+}
+
+#[tokio::test]
+async fn test_kernel_tracks_permission_even_during_onboarding() {
+    let mut reactor = reactor();
+    reactor.set_mode(KernelMode::Onboarding);
+    reactor.state.reduce(StateDelta::PresenceUpdate(PresenceState::Attentive));
+
+    let effects = reactor.tick_step(vec![permission_event(CapturePermission::Denied)]);
+    assert_eq!(reactor.state.capture_permission(), CapturePermission::Denied);
+    assert_eq!(reactor.state.presence(), PresenceState::Dormant, "Not listening, so not Attentive");
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::Diagnostic { code: DiagnosticCode::CaptureBlocked, .. })));
+    assert_eq!(reactor.subscribe_view().borrow().capture_permission, CapturePermission::Denied);
+
+    // Blocked: forced updates cannot make it Attentive either
+    reactor.state.reduce(StateDelta::PresenceUpdate(PresenceState::Attentive));
+    assert_eq!(reactor.state.presence(), PresenceState::Dormant);
+
+    // Same report again is not re-diagnosed; a grant resumes listening
+    let effects = reactor.tick_step(vec![permission_event(CapturePermission::Denied)]);
+    assert!(effects.is_empty());
+    let effects = reactor.tick_step(vec![permission_event(CapturePermission::Granted)]);
+    assert!(effects.is_empty());
+    assert_eq!(reactor.state.presence(), PresenceState::Attentive);
+}
@@ -0,0 +1,128 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use nexus::audio::supervisor::{capture_restarted_event, CaptureSupervisor, Heartbeat, Supervised, HEARTBEAT_TIMEOUT, SUPERVISE_INTERVAL};
+use nexus::kernel::audio::segment::SegmentStatus;
+use nexus::kernel::diagnostic::DiagnosticCode;
+use nexus::kernel::event::{AudioSignal, Event, InputContent, InputEvent};
+use nexus::kernel::reactor::KernelMode;
+use nexus::kernel::scheduler::SideEffect;
+
+mod common;
+use common::reactor;
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Start,
+    Crash,
+}
+
+fn restarted(effects: &[SideEffect]) -> Vec<String> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::Diagnostic { code: DiagnosticCode::CaptureRestarted, context, .. } => Some(context.clone()),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn test_restart_policy() {
+    let timeout = Duration::from_millis(100);
+    let mut supervisor = CaptureSupervisor::new(timeout);
+    let heartbeat = supervisor.heartbeat();
+    heartbeat.beat();
+    let t0 = Instant::now();
+
+    assert!(!supervisor.check(t0, true, true), "Beating");
+    assert!(!supervisor.check(t0 + timeout * 5, false, true), "Stopped on purpose: silence is expected");
+    assert!(supervisor.check(t0 + timeout * 5, true, true), "Running but silent");
+    assert!(supervisor.check(t0, true, false), "Thread gone");
+
+    // A restart that does not come back waits twice as long, then four times
+    supervisor.restarted(t0);
+    assert_eq!(supervisor.restarts(), 1);
+    assert!(!supervisor.check(t0 + timeout, true, false));
+    assert!(supervisor.check(t0 + timeout * 2, true, false));
+    supervisor.restarted(t0 + timeout * 2);
+    assert!(!supervisor.check(t0 + timeout * 5, true, false));
+    assert!(supervisor.check(t0 + timeout * 6, true, false));
+
+    // Healthy again: the next loss waits one timeout, not the backoff
+    let mut supervisor = CaptureSupervisor::new(timeout);
+    let t1 = Instant::now();
+    supervisor.restarted(t1);
+    supervisor.restarted(t1);
+    assert!(!supervisor.check(t1 + timeout, true, false), "Backing off");
+    supervisor.heartbeat().beat();
+    assert!(!supervisor.check(Instant::now(), true, true));
+    assert!(supervisor.check(t1 + timeout, true, false));
+}
+
+#[test]
+fn test_heartbeat_age() {
+    let heartbeat = Heartbeat::new();
+    heartbeat.beat();
+    let now = Instant::now();
+    assert!(heartbeat.silent_for(now) < HEARTBEAT_TIMEOUT);
+    assert!(heartbeat.silent_for(now + HEARTBEAT_TIMEOUT) >= HEARTBEAT_TIMEOUT);
+    assert_eq!(heartbeat.clone().silent_for(now), heartbeat.silent_for(now), "Clones share the beat");
+}
+
+#[test]
+fn test_lost_actor_is_relaunched() {
+    let (core_tx, mut core_rx) = tokio::sync::mpsc::channel(10);
+    let launches = Arc::new(AtomicUsize::new(0));
+    let (started_tx, started_rx) = std::sync::mpsc::channel();
+    let counter = launches.clone();
+    let launch = move |heartbeat: Heartbeat| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        let (cmd_tx, mut cmd_rx) = tokio::sync::mpsc::channel(8);
+        let started = started_tx.clone();
+        let thread = std::thread::spawn(move || {
+            while let Some(cmd) = cmd_rx.blocking_recv() {
+                match cmd {
+                    Command::Start => {
+                        heartbeat.beat();
+                        let _ = started.send(());
+                    }
+                    Command::Crash => panic!("device error in the capture callback"),
+                }
+            }
+        });
+        (cmd_tx, thread)
+    };
+    let actor = Supervised::spawn(launch, || Command::Start, core_tx);
+    actor.set_running(true);
+    actor.sender().try_send(Command::Start).unwrap();
+    started_rx.recv_timeout(Duration::from_secs(1)).expect("First actor started");
+
+    actor.sender().try_send(Command::Crash).unwrap();
+    started_rx.recv_timeout(SUPERVISE_INTERVAL * 4).expect("Relaunched and told to start");
+    assert_eq!(launches.load(Ordering::SeqCst), 2);
+    assert_eq!(actor.restarts(), 1);
+    let event = core_rx.try_recv().expect("Kernel told");
+    assert!(matches!(event, Event::Input(InputEvent { content: InputContent::CaptureRestarted, .. })));
+    // Commands reach the new actor
+    actor.sender().try_send(Command::Start).unwrap();
+    started_rx.recv_timeout(Duration::from_secs(1)).unwrap();
+}
+
+#[tokio::test]
+async fn test_segment_spanning_the_gap_is_discarded() {
+    let mut reactor = reactor();
+    let speech = Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::Audio(AudioSignal::SpeechStart), captured_at: None });
+    reactor.tick_step(vec![speech]);
+    let segment = reactor.state.active_segment_id().cloned().expect("Segment open");
+    assert!(reactor.state.user_speaking());
+
+    let effects = reactor.tick_step(vec![capture_restarted_event()]);
+    assert_eq!(restarted(&effects), vec![format!("segment={}", segment)]);
+    assert_eq!(reactor.state.audio_segments()[&segment].status, SegmentStatus::Discarded);
+    assert!(reactor.state.active_segment_id().is_none());
+    assert!(!reactor.state.user_speaking(), "No SpeechEnd will come for it");
+
+    // Platform signal: handled during onboarding too
+    reactor.set_mode(KernelMode::Onboarding);
+    let effects = reactor.tick_step(vec![capture_restarted_event()]);
+    assert_eq!(restarted(&effects), vec!["segment=none".to_string()]);
+}
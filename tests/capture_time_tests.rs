@@ -0,0 +1,82 @@
+use nexus::kernel::event::{AudioSignal, AudioStatus, Event, InputContent, InputEvent, OutputId, PlaybackId};
+use nexus::kernel::time::{CaptureClock, Tick};
+
+mod common;
+use common::{jump, reactor};
+
+fn heard(signal: AudioSignal, frame: u64) -> Event {
+    Event::Input(InputEvent::captured("Audio", InputContent::Audio(signal), Tick { frame }))
+}
+
+#[test]
+fn test_capture_clock_follows_the_reactor() {
+    let clock = CaptureClock::new();
+    clock.publish(Tick { frame: 100 });
+    assert_eq!(clock.now(), Tick { frame: 100 });
+    assert_eq!(clock.ago_ms(200), Tick { frame: 90 });
+    assert_eq!(clock.ago_ms(10_000), Tick { frame: 0 });
+
+    let mut reactor = reactor();
+    let clock = reactor.capture_clock();
+    reactor.tick_step(vec![]);
+    reactor.tick_step(vec![]);
+    assert_eq!(clock.now(), reactor.tick);
+}
+
+#[tokio::test]
+async fn test_segments_and_hesitation_use_capture_time() {
+    let mut reactor = reactor();
+    jump(&mut reactor, 100);
+    reactor.tick_step(vec![heard(AudioSignal::SpeechStart, 96)]);
+    let id = reactor.state.active_segment_id().cloned().unwrap();
+    assert_eq!(reactor.state.audio_segments()[&id].start_tick, Tick { frame: 96 });
+
+    // A short burst, processed long after it ended (slow VAD confirmation)
+    jump(&mut reactor, 140);
+    reactor.tick_step(vec![heard(AudioSignal::SpeechEnd, 100)]);
+    assert_eq!(reactor.state.audio_segments()[&id].end_tick, Some(Tick { frame: 100 }));
+    assert!(reactor.state.hesitation_detected(), "4 ticks of speech, not 40");
+
+    let lag = reactor.telemetry.snapshot().capture_lag_stats;
+    assert_eq!((lag.count, lag.max_lag_ticks), (2, 41));
+}
+
+#[tokio::test]
+async fn test_latencies_run_from_capture_time() {
+    let mut reactor = reactor();
+    jump(&mut reactor, 200);
+
+    // Barge-in heard 5 ticks before the kernel saw it
+    reactor.tick_step(vec![heard(AudioSignal::SpeechStart, 196)]);
+    let interruptions = reactor.telemetry.snapshot().interruption_stats;
+    assert_eq!((interruptions.count, interruptions.total_latency_ticks), (1, 5));
+
+    // The user stopped at 210; the answer starts playing at 231
+    jump(&mut reactor, 229);
+    reactor.tick_step(vec![heard(AudioSignal::SpeechEnd, 210)]);
+    let output_id = PlaybackId::Output(OutputId { tick: 230, ordinal: 1 });
+    reactor.tick_step(vec![Event::Input(InputEvent {
+        source: "Driver".to_string(),
+        content: InputContent::AudioStatus(AudioStatus::PlaybackStarted { output_id }),
+        captured_at: None,
+    })]);
+    assert_eq!(reactor.telemetry.snapshot().response_gap_stats.max_gap_ticks, 21);
+}
+
+#[tokio::test]
+async fn test_unstamped_and_future_stamps_use_the_processing_tick() {
+    let mut reactor = reactor();
+    jump(&mut reactor, 50);
+    reactor.tick_step(vec![heard(AudioSignal::SpeechStart, 9_000)]);
+    let id = reactor.state.active_segment_id().cloned().unwrap();
+    assert_eq!(reactor.state.audio_segments()[&id].start_tick, Tick { frame: 51 });
+    assert_eq!(reactor.telemetry.snapshot().interruption_stats.total_latency_ticks, 0);
+
+    reactor.tick_step(vec![Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::Audio(AudioSignal::SpeechEnd),
+        captured_at: None,
+    })]);
+    assert_eq!(reactor.state.audio_segments()[&id].end_tick, Some(Tick { frame: 52 }));
+    assert_eq!(reactor.telemetry.snapshot().capture_lag_stats.count, 1, "Unstamped edges have no lag to report");
+}
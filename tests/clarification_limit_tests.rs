@@ -0,0 +1,80 @@
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::intent::clarification::{ClarificationFallback, ClarificationLimits, ClarificationState};
+use nexus::kernel::intent::types::{DialogueAct, IntentCandidate, IntentHypothesis, IntentStability, IntentState};
+use nexus::kernel::scheduler::SideEffect;
+
+mod common;
+
+fn forming(hash: u64) -> IntentState {
+    forming_in(hash, "seg_1")
+}
+
+fn forming_in(hash: u64, segment: &str) -> IntentState {
+    IntentState::Forming(vec![IntentCandidate {
+        id: format!("c{}", hash),
+        hypothesis: IntentHypothesis::Inquiry,
+        confidence: 0.6,
+        source_symbol_ids: vec![segment.to_string()],
+        semantic_hash: hash,
+        stability: IntentStability::Unstable,
+    }])
+}
+
+fn ask() -> DialogueAct {
+    DialogueAct::AskClarification("Do you want me to respond?".to_string())
+}
+
+fn fragment(text: &str) -> Event {
+    Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::ProvisionalText { content: text.to_string(), confidence: 0.6, source_id: "seg_1".to_string(), words: Vec::new(), language: None, is_final: true },
+        captured_at: None,
+    })
+}
+
+fn spoke(effects: &[SideEffect]) -> bool {
+    effects.iter().any(|e| matches!(e, SideEffect::RequestSpeech { .. }))
+}
+
+#[test]
+fn test_one_clarification_per_hash() {
+    let mut clarifications = ClarificationState::new();
+    let limits = ClarificationLimits::default();
+    assert_eq!(clarifications.admit(ask(), &forming(1), &limits), ask());
+    assert_eq!(clarifications.asked(1), 1);
+    assert_eq!(clarifications.admit(ask(), &forming(1), &limits), DialogueAct::Wait, "Handed off to the planner");
+    assert_eq!(clarifications.suppressed, 1);
+    // Other acts pass untouched
+    assert_eq!(clarifications.admit(DialogueAct::StaySilent, &forming(1), &limits), DialogueAct::StaySilent);
+}
+
+#[test]
+fn test_episode_cap_and_reset() {
+    let mut clarifications = ClarificationState::new();
+    let limits = ClarificationLimits { after_limit: ClarificationFallback::Silence, ..Default::default() };
+    assert_eq!(clarifications.admit(ask(), &forming(1), &limits), ask());
+    assert_eq!(clarifications.admit(ask(), &forming(2), &limits), ask());
+    // Noisy re-transcriptions: a new hash each time, still capped per episode
+    assert_eq!(clarifications.admit(ask(), &forming(3), &limits), DialogueAct::StaySilent);
+
+    // A fragment of another segment is a new utterance
+    assert_eq!(clarifications.admit(ask(), &forming_in(1, "seg_2"), &limits), ask());
+
+    // Leaving Forming ends the episode
+    clarifications.admit(DialogueAct::StaySilent, &IntentState::None, &limits);
+    assert_eq!(clarifications.asked(1), 0);
+    assert_eq!(clarifications.admit(ask(), &forming(1), &limits), ask());
+}
+
+#[tokio::test]
+async fn test_no_interrogation_loop() {
+    let mut reactor = common::reactor();
+
+    let effects: Vec<_> = reactor.tick_step(vec![fragment("maybe what?")]).into_iter().collect();
+    assert!(spoke(&effects), "First ambiguous fragment is clarified");
+    for _ in 0..3 {
+        let effects: Vec<_> = reactor.tick_step(vec![fragment("maybe what?")]).into_iter().collect();
+        assert!(!spoke(&effects), "Same fragment is not asked about again");
+    }
+    assert_eq!(reactor.clarifications.suppressed, 3);
+}
@@ -0,0 +1,34 @@
+//! Fixtures shared by the integration tests. Each test file that needs them declares `mod common;`.
+// Every test binary compiles this module, and none uses all of it
+#![allow(dead_code)]
+
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::reactor::{Reactor, ReactorBuilder, ReactorConfig};
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::time::Tick;
+
+/// Production defaults with the LLM planner off: replies come from the kernel's own templates.
+pub fn config() -> ReactorConfig {
+    ReactorConfig { llm_planning: false, ..Default::default() }
+}
+
+/// A builder with `config()`; override the config or inject sidecars and stores before `build()`.
+pub fn builder() -> ReactorBuilder {
+    let (tx, rx) = tokio::sync::mpsc::channel(100);
+    Reactor::builder(rx, tx).config(config())
+}
+
+pub fn reactor() -> Reactor {
+    builder().build()
+}
+
+/// User input as the test source.
+pub fn input(content: InputContent) -> Event {
+    Event::Input(InputEvent { source: "Test".to_string(), content, captured_at: None })
+}
+
+/// Move the clock to `frame` without running the ticks in between.
+pub fn jump(reactor: &mut Reactor, frame: u64) {
+    reactor.state.reduce(StateDelta::Tick(Tick { frame }));
+    reactor.tick.frame = frame;
+}
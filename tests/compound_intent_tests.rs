@@ -0,0 +1,120 @@
+use nexus::integrations::home::HomeMapping;
+use nexus::kernel::effects::EffectBatch;
+use nexus::kernel::event::InputContent;
+use nexus::kernel::ids::IdGenerator;
+use nexus::kernel::intent::arbitrator::IntentArbitrator;
+use nexus::kernel::intent::clauses::{clause_symbol, split_clauses};
+use nexus::kernel::intent::types::{IntentHypothesis, IntentState};
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::scheduler::SideEffect;
+use nexus::planner::tools::ToolCall;
+use serde_json::json;
+
+mod common;
+use common::input;
+
+const COMPOUND: &str = "Turn off the lights and remind me to call mom";
+
+fn reactor() -> Reactor {
+    let mapping: HomeMapping = serde_json::from_value(json!({
+        "rules": [
+            { "name": "lights off", "phrases": ["turn off the lights"],
+              "target": { "Mqtt": { "topic": "home/lights/set", "payload": "OFF" } } },
+            { "name": "fan on", "phrases": ["turn on the fan"],
+              "target": { "Mqtt": { "topic": "home/fan/set", "payload": "ON" } } },
+            { "name": "alarm off", "phrases": ["turn off the alarm"], "confirm": true,
+              "target": { "Mqtt": { "topic": "home/alarm/set", "payload": "OFF" } } }
+        ]
+    })).unwrap();
+    common::builder()
+        .home_mapping(mapping)
+        .build()
+}
+
+fn say(reactor: &mut Reactor, text: &str) -> EffectBatch {
+    reactor.tick_step(vec![input(InputContent::Text(text.to_string()))])
+}
+
+fn hear(reactor: &mut Reactor, text: &str, segment: &str) -> EffectBatch {
+    reactor.tick_step(vec![input(InputContent::ProvisionalText {
+        content: text.to_string(),
+        confidence: 0.9,
+        source_id: segment.to_string(),
+        words: Vec::new(),
+        language: None,
+        is_final: true,
+    })])
+}
+
+fn invoked(batch: &EffectBatch) -> Vec<String> {
+    batch.iter().filter_map(|e| match e {
+        SideEffect::InvokeTool { call: ToolCall::HomeAction(action), .. } => Some(action.rule.clone()),
+        _ => None,
+    }).collect()
+}
+
+fn spoken(batch: &EffectBatch) -> usize {
+    batch.iter().filter(|e| matches!(e, SideEffect::RequestSpeech { .. } | SideEffect::SpawnAudio(..))).count()
+}
+
+#[test]
+fn test_clause_segmentation() {
+    assert_eq!(split_clauses(COMPOUND), ["Turn off the lights", "remind me to call mom"]);
+    assert_eq!(split_clauses("turn off the lights, and then play some jazz"), ["turn off the lights", "play some jazz"]);
+    assert_eq!(split_clauses("What's the weather? Turn on the fan"), ["What's the weather?", "Turn on the fan"]);
+    // No clause opener after the connector, or too little before it
+    assert_eq!(split_clauses("I like salt and pepper"), ["I like salt and pepper"]);
+    assert_eq!(split_clauses("and what now"), ["and what now"]);
+    assert_eq!(clause_symbol("seg_1", 0), "seg_1");
+    assert_eq!(clause_symbol("seg_1", 1), "seg_1#2");
+}
+
+#[test]
+fn test_compound_only_when_every_clause_is_stable() {
+    let arbitrator = IntentArbitrator::new();
+    let mut ids = IdGenerator::new(7);
+    let IntentState::Compound(clauses) = arbitrator.assess(COMPOUND, "seg_1", &IntentState::None, &mut ids) else {
+        panic!("expected a compound");
+    };
+    assert_eq!(clauses.iter().map(|c| c.hypothesis.clone()).collect::<Vec<_>>(), [IntentHypothesis::Command, IntentHypothesis::Command]);
+    assert_eq!(clauses[1].source_symbol_ids, ["seg_1#2"]);
+    assert_ne!(clauses[0].semantic_hash, clauses[1].semantic_hash);
+
+    // A vague clause keeps the utterance whole
+    let whole = arbitrator.assess("turn off the lights and what now", "seg_2", &IntentState::None, &mut ids);
+    assert!(matches!(whole, IntentState::Stable(c) if c.hypothesis == IntentHypothesis::Command));
+    // Control commands stand alone
+    let control = arbitrator.assess("switch to work context and turn on the fan", "seg_3", &IntentState::None, &mut ids);
+    assert!(matches!(control, IntentState::Stable(c) if c.hypothesis == IntentHypothesis::SystemControl));
+}
+
+#[tokio::test]
+async fn test_each_clause_becomes_its_own_intent() {
+    let mut reactor = reactor();
+    let effects = say(&mut reactor, COMPOUND);
+    assert!(matches!(reactor.state.intent_state(), IntentState::Compound(c) if c.len() == 2));
+    assert_eq!(reactor.state.active_intents().len(), 2);
+    assert_eq!(invoked(&effects), ["lights off"]);
+    assert_eq!(spoken(&effects), 1, "One acknowledgement for the whole utterance");
+
+    // Re-transcription of the same utterance is not a new edge
+    let mut reactor = self::reactor();
+    hear(&mut reactor, COMPOUND, "seg_1");
+    hear(&mut reactor, COMPOUND, "seg_1");
+    assert_eq!(reactor.state.active_intents().len(), 2);
+}
+
+#[tokio::test]
+async fn test_command_clauses_run_in_order_and_ask_once() {
+    let mut reactor = reactor();
+    let effects = say(&mut reactor, "turn on the fan and turn off the lights");
+    assert_eq!(invoked(&effects), ["fan on", "lights off"]);
+    assert_eq!(spoken(&effects), 1);
+
+    // A confirmation question ends the sequence: one question, nothing after it runs
+    let mut reactor = self::reactor();
+    let effects = say(&mut reactor, "turn off the alarm and turn on the fan");
+    assert!(invoked(&effects).is_empty());
+    assert_eq!(spoken(&effects), 1);
+    assert!(reactor.home.awaiting_confirmation());
+}
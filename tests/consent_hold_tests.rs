@@ -0,0 +1,126 @@
+use std::sync::{Arc, Mutex};
+
+use nexus::kernel::crystallizer::{explain_gate, explain_proactive_gate, CrystallizationDecision, GateRule};
+use nexus::kernel::intent::long_horizon::{IntentContext, IntentId, LongHorizonIntentManager, ResumptionNotice};
+use nexus::kernel::intent::types::{IntentCandidate, IntentHypothesis};
+use nexus::kernel::memory::consent::MemoryConsentState;
+use nexus::kernel::memory::types::MemoryKey;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::sidecar::IntentManager;
+use nexus::kernel::state::{SharedState, StateDelta};
+use nexus::kernel::telemetry::recorder::TelemetryRecorder;
+use nexus::kernel::time::Tick;
+
+/// Real LHIM, plus resumption notices injected by the test.
+struct NoticeLhim {
+    inner: LongHorizonIntentManager,
+    notices: Arc<Mutex<Vec<ResumptionNotice>>>,
+}
+
+impl IntentManager for NoticeLhim {
+    fn register_intent(&mut self, candidate: &IntentCandidate, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        self.inner.register_intent(candidate, state, current_tick, telemetry)
+    }
+    fn suspend_intent(&mut self, id: &IntentId, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Option<StateDelta> {
+        self.inner.suspend_intent(id, state, current_tick, telemetry)
+    }
+    fn handle_interruption(&mut self, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        self.inner.handle_interruption(state, current_tick, telemetry)
+    }
+    fn try_resume(&mut self, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        self.inner.try_resume(state, current_tick, telemetry)
+    }
+    fn tick(&mut self, current_tick: Tick, state: &SharedState, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        self.inner.tick(current_tick, state, telemetry)
+    }
+    fn take_resumptions(&mut self) -> Vec<ResumptionNotice> {
+        std::mem::take(&mut *self.notices.lock().unwrap())
+    }
+    fn get_context(&self, state: &SharedState) -> IntentContext {
+        self.inner.get_context(state)
+    }
+}
+
+fn reactor() -> (Reactor, Arc<Mutex<Vec<ResumptionNotice>>>) {
+    let (tx, rx) = tokio::sync::mpsc::channel(100);
+    let notices = Arc::new(Mutex::new(Vec::new()));
+    let reactor = Reactor::builder(rx, tx)
+        .config(ReactorConfig { llm_planning: false, ..Default::default() })
+        .lhim(Box::new(NoticeLhim { inner: LongHorizonIntentManager::new(), notices: notices.clone() }))
+        .build();
+    (reactor, notices)
+}
+
+fn key() -> MemoryKey {
+    MemoryKey { hypothesis: IntentHypothesis::Command, symbol_fingerprint: 42 }
+}
+
+/// A consent prompt on screen until `expires_at`.
+fn prompt(reactor: &mut Reactor, expires_at: u64) {
+    reactor.state.reduce(StateDelta::MemoryConsentAsked(key(), reactor.tick));
+    reactor.state.reduce(StateDelta::MemoryConsentPrompted { key: key(), prompt_id: "p1".to_string(), expires_at: Tick { frame: expires_at }, topic_id: None });
+}
+
+fn answer(reactor: &mut Reactor) {
+    reactor.state.reduce(StateDelta::MemoryConsentResolved { key: key(), state: MemoryConsentState::Granted, resolved_at: reactor.tick });
+}
+
+fn notice() -> ResumptionNotice {
+    ResumptionNotice { intent_id: "i1".to_string(), hypothesis: IntentHypothesis::Command, dormant_ticks: 2000, was_interrupted: false }
+}
+
+fn offered(effects: &[SideEffect]) -> bool {
+    effects.iter().any(|e| matches!(e, SideEffect::SpawnAudio(..)))
+}
+
+#[tokio::test]
+async fn test_snapshot_reports_pending_consent() {
+    let (mut reactor, _) = reactor();
+    reactor.tick_step(vec![]);
+    let snapshot = |reactor: &Reactor| reactor.state.snapshot(reactor.tick, reactor.lhim.get_context(&reactor.state));
+    assert!(!snapshot(&reactor).pending_consent);
+
+    prompt(&mut reactor, 100);
+    assert!(snapshot(&reactor).pending_consent);
+    answer(&mut reactor);
+    assert!(!snapshot(&reactor).pending_consent);
+
+    // An expired prompt is no longer in flight
+    prompt(&mut reactor, 0);
+    reactor.tick_step(vec![]);
+    assert!(!snapshot(&reactor).pending_consent);
+}
+
+#[tokio::test]
+async fn test_gate_holds_proactive_output_only() {
+    let (mut reactor, _) = reactor();
+    reactor.tick_step(vec![]);
+    prompt(&mut reactor, 100);
+
+    let gate = explain_proactive_gate(&reactor.state);
+    assert_eq!((gate.decision, gate.rule), (CrystallizationDecision::Deny, GateRule::ConsentPending));
+    // Answers to the user still go out
+    assert_ne!(explain_gate(&reactor.state).rule, GateRule::ConsentPending);
+
+    answer(&mut reactor);
+    assert_ne!(explain_proactive_gate(&reactor.state).rule, GateRule::ConsentPending);
+}
+
+#[tokio::test]
+async fn test_offer_deferred_until_consent_answered() {
+    let (mut reactor, notices) = reactor();
+    reactor.tick_step(vec![]);
+    prompt(&mut reactor, 1000);
+    assert_eq!(reactor.deferred_outputs(), 0);
+
+    notices.lock().unwrap().push(notice());
+    assert!(!offered(&reactor.tick_step(vec![])));
+    assert!(!offered(&reactor.tick_step(vec![])));
+    assert_eq!(reactor.deferred_outputs(), 1, "Held, not dropped");
+
+    // Answered: the held offer goes out once
+    answer(&mut reactor);
+    assert!(offered(&reactor.tick_step(vec![])));
+    assert_eq!(reactor.deferred_outputs(), 0);
+}
@@ -0,0 +1,126 @@
+use nexus::kernel::diagnostic::DiagnosticCode;
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::memory::consent::MemoryConsentState;
+use nexus::kernel::protocol::{ConsentRequest, ConsentResponse, PROTOCOL_VERSION};
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::time::Tick;
+
+mod common;
+use common::reactor;
+
+fn utterance(text: &str, segment: &str) -> Event {
+    Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::ProvisionalText {
+            content: text.to_string(),
+            confidence: 0.9,
+            source_id: segment.to_string(),
+            words: Vec::new(),
+            language: None,
+            is_final: true,
+        },
+        captured_at: None,
+    })
+}
+
+fn answer(response: ConsentResponse) -> Event {
+    Event::Input(InputEvent { source: "Frontend".to_string(), content: InputContent::MemoryConsentResponse(response), captured_at: None })
+}
+
+/// A sensitive candidate old enough to be asked about; returns the request the kernel sent.
+fn asked(reactor: &mut Reactor) -> ConsentRequest {
+    reactor.tick_step(vec![utterance("What is my blood pressure?", "seg1")]);
+    let jump = 1250;
+    reactor.state.reduce(StateDelta::Tick(Tick { frame: jump }));
+    reactor.tick.frame = jump;
+    let effects = reactor.tick_step(vec![utterance("What is my blood pressure?", "seg2")]);
+    effects.into_iter().find_map(|e| match e {
+        SideEffect::AskMemoryConsent(request) => Some(request),
+        _ => None,
+    }).expect("consent asked")
+}
+
+fn rejected(effects: &[SideEffect], reason: &str) -> bool {
+    effects.iter().any(|e| matches!(e, SideEffect::Diagnostic { code: DiagnosticCode::ConsentRejected, context, .. } if context.ends_with(reason)))
+}
+
+fn consent_state(reactor: &Reactor, request: &ConsentRequest) -> MemoryConsentState {
+    reactor.state.memory_consent().get(&request.key).unwrap().state
+}
+
+#[tokio::test]
+async fn test_serde_round_trip() {
+    let mut reactor = reactor();
+    let request = asked(&mut reactor);
+    assert_eq!(request.version, PROTOCOL_VERSION);
+    assert!(request.expires_at > request.asked_at);
+
+    let json = serde_json::to_string(&request).unwrap();
+    assert_eq!(serde_json::from_str::<ConsentRequest>(&json).unwrap(), request);
+
+    let response = request.answer(MemoryConsentState::Granted);
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["prompt_id"], request.prompt_id.as_str());
+    assert_eq!(json["state"], "Granted");
+    assert_eq!(serde_json::from_value::<ConsentResponse>(json).unwrap(), response);
+}
+
+#[tokio::test]
+async fn test_matching_prompt_resolves_once() {
+    let mut reactor = reactor();
+    let request = asked(&mut reactor);
+
+    let effects = reactor.tick_step(vec![answer(request.answer(MemoryConsentState::Granted))]);
+    assert!(!rejected(&effects, ""));
+    assert_eq!(consent_state(&reactor, &request), MemoryConsentState::Granted);
+
+    // Replayed answer cannot flip the decision
+    let effects = reactor.tick_step(vec![answer(request.answer(MemoryConsentState::Declined))]);
+    assert!(rejected(&effects, "reason=AlreadyResolved"));
+    assert_eq!(consent_state(&reactor, &request), MemoryConsentState::Granted);
+}
+
+#[tokio::test]
+async fn test_mismatched_prompt_is_rejected() {
+    let mut reactor = reactor();
+    let request = asked(&mut reactor);
+
+    let mut forged = request.answer(MemoryConsentState::Granted);
+    forged.prompt_id = "stale".to_string();
+    let effects = reactor.tick_step(vec![answer(forged)]);
+    assert!(rejected(&effects, "reason=PromptMismatch"));
+
+    let mut future = request.answer(MemoryConsentState::Granted);
+    future.version = PROTOCOL_VERSION + 1;
+    let effects = reactor.tick_step(vec![answer(future)]);
+    assert!(rejected(&effects, "reason=Version"));
+
+    assert_eq!(consent_state(&reactor, &request), MemoryConsentState::Unknown);
+    assert!(reactor.state.long_term_memory().is_empty());
+}
+
+#[tokio::test]
+async fn test_expired_prompt_is_rejected() {
+    let mut reactor = reactor();
+    let request = asked(&mut reactor);
+
+    let late = request.expires_at.frame + 1;
+    reactor.state.reduce(StateDelta::Tick(Tick { frame: late }));
+    reactor.tick.frame = late;
+    let effects = reactor.tick_step(vec![answer(request.answer(MemoryConsentState::Granted))]);
+    assert!(rejected(&effects, "reason=Expired"));
+    assert_eq!(consent_state(&reactor, &request), MemoryConsentState::Unknown);
+}
+
+#[tokio::test]
+async fn test_unasked_key_is_rejected() {
+    let mut reactor = reactor();
+    let request = asked(&mut reactor);
+
+    let mut other = request.answer(MemoryConsentState::Granted);
+    other.key.symbol_fingerprint ^= 1;
+    let effects = reactor.tick_step(vec![answer(other)]);
+    assert!(rejected(&effects, "reason=NotAsked"));
+}
@@ -0,0 +1,149 @@
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::memory::consent::{parse_spoken_answer, MemoryConsentState};
+use nexus::kernel::protocol::ConsentRequest;
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::scheduler::SideEffect;
+
+mod common;
+use common::{jump, reactor};
+
+fn utterance(text: &str, segment: &str) -> Event {
+    transcript(text, segment, true)
+}
+
+fn transcript(text: &str, segment: &str, is_final: bool) -> Event {
+    Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::ProvisionalText {
+            content: text.to_string(),
+            confidence: 0.9,
+            source_id: segment.to_string(),
+            words: Vec::new(),
+            language: None,
+            is_final,
+        },
+        captured_at: None,
+    })
+}
+
+fn asks(effects: &[SideEffect]) -> Vec<ConsentRequest> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::AskMemoryConsent(request) => Some(request.clone()),
+        _ => None,
+    }).collect()
+}
+
+fn spoken(effects: &[SideEffect]) -> bool {
+    effects.iter().any(|e| matches!(e, SideEffect::RequestSpeech { .. } | SideEffect::SpawnAudio(..)))
+}
+
+/// Two sensitive candidates old enough to be asked about; returns the one prompt shown.
+fn asked(reactor: &mut Reactor) -> ConsentRequest {
+    reactor.tick_step(vec![utterance("What is my blood pressure?", "seg1")]);
+    reactor.tick_step(vec![utterance("When is my surgery?", "seg2")]);
+    jump(reactor, 1250);
+    let effects = reactor.tick_step(vec![
+        utterance("What is my blood pressure?", "seg3"),
+        utterance("When is my surgery?", "seg4"),
+    ]);
+    let mut requests = asks(&effects);
+    assert_eq!(requests.len(), 1, "One prompt on screen at a time");
+    requests.remove(0)
+}
+
+#[test]
+fn test_spoken_answers() {
+    assert_eq!(parse_spoken_answer("Yes please"), Some(MemoryConsentState::Granted));
+    assert_eq!(parse_spoken_answer("sure, go ahead"), Some(MemoryConsentState::Granted));
+    assert_eq!(parse_spoken_answer("No thanks"), Some(MemoryConsentState::Declined));
+    assert_eq!(parse_spoken_answer("don't"), Some(MemoryConsentState::Declined));
+    assert_eq!(parse_spoken_answer("no, remember it"), Some(MemoryConsentState::Declined), "Declines win");
+    assert_eq!(parse_spoken_answer("not sure"), None);
+    assert_eq!(parse_spoken_answer("I know"), None);
+    assert_eq!(parse_spoken_answer("yes and also turn on the lights"), None, "Too long for an answer");
+}
+
+#[tokio::test]
+async fn test_voice_answer_resolves_and_dismisses() {
+    let mut reactor = reactor();
+    let request = asked(&mut reactor);
+    assert!(reactor.state.consent_pending(reactor.tick).is_some());
+
+    let effects = reactor.tick_step(vec![utterance("yes", "seg5")]);
+    let dismissed = effects.iter().find_map(|e| match e {
+        SideEffect::DismissConsent(response) => Some(response.clone()),
+        _ => None,
+    }).expect("modal dismissed");
+    assert_eq!(dismissed, request.answer(MemoryConsentState::Granted));
+    assert_eq!(reactor.state.memory_consent()[&request.key].state, MemoryConsentState::Granted);
+    assert!(!spoken(&effects), "An answer, not an utterance");
+    assert!(reactor.state.consent_pending(reactor.tick).is_none_or(|c| c.memory_key != request.key));
+
+    // Later fragments of the same answer are swallowed too
+    let effects = reactor.tick_step(vec![utterance("yes please", "seg5")]);
+    assert!(!spoken(&effects));
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::DismissConsent(_))));
+}
+
+#[tokio::test]
+async fn test_only_final_text_answers() {
+    let mut reactor = reactor();
+    let request = asked(&mut reactor);
+
+    // "yes..." is still being refined
+    let effects = reactor.tick_step(vec![transcript("yes", "seg5", false)]);
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::DismissConsent(_))));
+    assert!(reactor.state.consent_pending(reactor.tick).is_some());
+
+    let effects = reactor.tick_step(vec![utterance("yes, no wait", "seg5")]);
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::DismissConsent(_))));
+    assert_eq!(reactor.state.memory_consent()[&request.key].state, MemoryConsentState::Declined);
+}
+
+#[tokio::test]
+async fn test_further_asks_wait_for_the_open_prompt() {
+    let mut reactor = reactor();
+    let first = asked(&mut reactor);
+    for _ in 0..5 {
+        assert!(asks(&reactor.tick_step(vec![])).is_empty(), "Paused while the prompt is open");
+    }
+    assert!(reactor.subscribe_view().borrow().consent_pending);
+
+    let effects = reactor.tick_step(vec![utterance("no", "seg5")]);
+    assert_eq!(reactor.state.memory_consent()[&first.key].state, MemoryConsentState::Declined);
+    let next = asks(&effects);
+    assert_eq!(next.len(), 1);
+    assert_ne!(next[0].key, first.key);
+}
+
+#[tokio::test]
+async fn test_no_prompt_no_interception() {
+    let mut reactor = reactor();
+    let effects = reactor.tick_step(vec![utterance("yes", "seg1")]);
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::DismissConsent(_))));
+
+    // With a prompt open, anything else is a normal utterance
+    let mut reactor = self::reactor();
+    asked(&mut reactor);
+    let effects = reactor.tick_step(vec![utterance("maybe later", "seg5")]);
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::DismissConsent(_))));
+    assert!(reactor.state.consent_pending(reactor.tick).is_some());
+}
+
+#[tokio::test]
+async fn test_interim_bridge_transcripts_do_not_answer() {
+    use nexus::kernel::bridge::ClientMessage;
+
+    // A browser's streaming recognizer: interim hypotheses, then the final one
+    let bridged = |frame: &str| Event::Input(ClientMessage::parse(frame).unwrap().input().unwrap());
+    let mut reactor = reactor();
+    let request = asked(&mut reactor);
+
+    let effects = reactor.tick_step(vec![bridged(r#"{"type":"transcript","text":"yes","segment_id":"web1","confidence":0.6,"is_final":false}"#)]);
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::DismissConsent(_))));
+    assert!(reactor.state.consent_pending(reactor.tick).is_some(), "An interim 'yes' may still turn around");
+
+    let effects = reactor.tick_step(vec![bridged(r#"{"type":"transcript","text":"yes please","segment_id":"web1","confidence":0.9,"is_final":true}"#)]);
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::DismissConsent(_))));
+    assert_eq!(reactor.state.memory_consent()[&request.key].state, MemoryConsentState::Granted);
+}
@@ -0,0 +1,116 @@
+use nexus::kernel::context::{parse_switch, ContextId};
+use nexus::kernel::event::{Event, InputEvent, InputContent};
+use nexus::kernel::intent::long_horizon::IntentStatus;
+use nexus::kernel::intent::types::{IntentHypothesis, IntentState};
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::time::Tick;
+
+mod common;
+use common::reactor;
+
+fn text(content: &str) -> Event {
+    Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::Text(content.to_string()), captured_at: None })
+}
+
+#[test]
+fn test_switch_grammar() {
+    assert_eq!(parse_switch("Switch to work context"), Some(ContextId::new("work")));
+    assert_eq!(parse_switch("go back to my personal context."), Some(ContextId::new("personal")));
+    assert_eq!(parse_switch("enter work mode"), Some(ContextId::new("work")));
+    assert_eq!(parse_switch("What is the context of this?"), None);
+    assert_eq!(parse_switch("switch the lights off"), None);
+    assert_eq!(parse_switch("switch to personal"), Some(ContextId::new("personal")), "A known context by name");
+    assert_eq!(parse_switch("use the garden context"), Some(ContextId::new("garden")));
+    assert_eq!(parse_switch("use dark mode"), None, "Not a context");
+    assert_eq!(parse_switch("change to airplane mode"), None);
+    assert_eq!(parse_switch("I have to go to work"), None);
+    assert_eq!(parse_switch("switch to dark"), None);
+    assert_eq!(ContextId::default().as_str(), "default");
+}
+
+#[tokio::test]
+async fn test_switch_is_a_control_intent() {
+    let mut reactor = reactor();
+    let effects = reactor.tick_step(vec![text("Switch to work context")]);
+
+    assert_eq!(reactor.state.active_context(), &ContextId::new("work"));
+    assert!(effects.is_empty(), "A context switch is not answered");
+    assert!(reactor.state.memory_candidates().is_empty(), "Control intents are never remembered");
+    assert!(reactor.state.active_intents().is_empty(), "Control intents never become long-horizon intents");
+}
+
+#[tokio::test]
+async fn test_intent_state_is_parked_per_context() {
+    let mut reactor = reactor();
+    reactor.tick_step(vec![text("What is gravity?")]);
+    let gravity = match reactor.state.intent_state() {
+        IntentState::Stable(c) => c.id.clone(),
+        other => panic!("unexpected {:?}", other),
+    };
+
+    reactor.tick_step(vec![text("Switch to work context")]);
+    assert_eq!(reactor.state.intent_state(), &IntentState::None);
+    let lhi = &reactor.state.active_intents()[&gravity];
+    assert_eq!(lhi.status, IntentStatus::Suspended, "Leaving a context parks its active intents");
+    assert_eq!(lhi.context, ContextId::default());
+
+    reactor.tick_step(vec![text("Switch to default context")]);
+    match reactor.state.intent_state() {
+        IntentState::Stable(c) => assert_eq!(c.id, gravity),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_memory_never_crosses_contexts() {
+    let mut reactor = reactor();
+    reactor.tick_step(vec![text("Switch to work context")]);
+    reactor.tick_step(vec![text("What is the quarterly target?")]);
+    reactor.tick_step(vec![text("Switch to personal context")]);
+    reactor.tick_step(vec![text("What is the quarterly target?")]);
+
+    let mut candidates: Vec<_> = reactor.state.memory_candidates().values().collect();
+    assert_eq!(candidates.len(), 2, "Same question in another context must not reinforce");
+    assert!(candidates.iter().all(|c| c.reinforcement_count == 1));
+    candidates.sort_by(|a, b| a.context.cmp(&b.context));
+    assert_eq!(candidates[0].context, ContextId::new("personal"));
+    assert_eq!(candidates[1].context, ContextId::new("work"));
+    assert_ne!(candidates[0].key, candidates[1].key);
+
+    // Retrieval is scoped too
+    let work = candidates[1].clone();
+    reactor.state.reduce(StateDelta::MemoryPromoted(nexus::kernel::memory::types::MemoryRecord {
+        id: work.id.clone(),
+        intent: work.intent.clone(),
+        first_committed_at: reactor.tick,
+        last_accessed_at: reactor.tick,
+        strength: 0.5,
+        topic_id: work.topic_id.clone(),
+        sensitivity: None,
+        context: work.context.clone(),
+    }));
+    assert_eq!(reactor.state.memories_in_context(&ContextId::new("work")).len(), 1);
+    assert!(reactor.state.memories_in_context(&ContextId::new("personal")).is_empty());
+    if let Some(topic) = &work.topic_id {
+        assert!(reactor.state.memories_for_topic(topic).is_empty(), "Personal context cannot see work topics");
+    }
+}
+
+#[tokio::test]
+async fn test_parked_intents_are_frozen() {
+    let mut reactor = reactor();
+    reactor.tick_step(vec![text("What is gravity?")]);
+    reactor.tick_step(vec![text("Switch to work context")]);
+    let parked = reactor.state.active_intents().values().next().unwrap().clone();
+
+    // Ten minutes in the other context
+    let jump = reactor.tick.frame + 30_000;
+    reactor.state.reduce(StateDelta::Tick(Tick { frame: jump }));
+    reactor.tick.frame = jump;
+    reactor.tick_step(vec![]);
+
+    let after = &reactor.state.active_intents()[&parked.id];
+    assert_eq!(after.decay_score, parked.decay_score, "Parked contexts do not decay");
+    assert_eq!(after.status, IntentStatus::Suspended);
+    assert_eq!(after.hypothesis, IntentHypothesis::Inquiry);
+}
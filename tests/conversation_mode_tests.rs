@@ -0,0 +1,86 @@
+use nexus::kernel::event::{AudioSignal, AudioStatus, Event, InputContent, InputEvent, PlaybackId};
+use nexus::kernel::presence::ConversationMode;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+
+mod common;
+
+fn reactor(conversation_mode: ConversationMode) -> Reactor {
+    common::builder().config(ReactorConfig { conversation_mode, ..common::config() }).build()
+}
+
+fn driver(content: InputContent) -> Event {
+    Event::Input(InputEvent { source: "Driver".to_string(), content, captured_at: None })
+}
+
+// 500ms well above the (speaking) VAD threshold
+fn loud_chunk() -> Event {
+    driver(InputContent::AudioChunk(vec![0.5; 24000]))
+}
+
+fn start_speaking(reactor: &mut Reactor) -> PlaybackId {
+    let id = PlaybackId::Speech(uuid::Uuid::new_v4());
+    reactor.tick_step(vec![driver(InputContent::AudioStatus(AudioStatus::PlaybackStarted { output_id: id }))]);
+    id
+}
+
+#[test]
+fn test_half_duplex_listens_only_in_silence() {
+    assert!(ConversationMode::FullDuplex.listening(true));
+    assert!(ConversationMode::HalfDuplex.listening(false));
+    assert!(!ConversationMode::HalfDuplex.listening(true));
+    assert_eq!(ConversationMode::default(), ConversationMode::FullDuplex);
+}
+
+#[tokio::test]
+async fn test_half_duplex_drops_audio_while_speaking() {
+    let mut reactor = reactor(ConversationMode::HalfDuplex);
+    let id = start_speaking(&mut reactor);
+
+    let effects = reactor.tick_step(vec![loud_chunk()]);
+    assert!(reactor.state.active_segment_id().is_none(), "No VAD, no buffering while speaking");
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::StopAudio)), "No barge-in");
+
+    // Playback over: listening again
+    reactor.tick_step(vec![driver(InputContent::AudioStatus(AudioStatus::PlaybackEnded { output_id: id, elapsed_ms: 800 }))]);
+    reactor.tick_step(vec![loud_chunk()]);
+    assert!(reactor.state.active_segment_id().is_some());
+}
+
+#[tokio::test]
+async fn test_half_duplex_ignores_capture_side_speech_while_speaking() {
+    // An external VAD (capture layer) reports speech: our own voice through the speakers
+    let mut reactor = reactor(ConversationMode::HalfDuplex);
+    start_speaking(&mut reactor);
+    let effects = reactor.tick_step(vec![driver(InputContent::Audio(AudioSignal::SpeechStart))]);
+    assert!(reactor.state.active_segment_id().is_none());
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::StopAudio)), "No barge-in");
+
+    let mut reactor = self::reactor(ConversationMode::FullDuplex);
+    start_speaking(&mut reactor);
+    reactor.tick_step(vec![driver(InputContent::Audio(AudioSignal::SpeechStart))]);
+    assert!(reactor.state.active_segment_id().is_some());
+}
+
+#[tokio::test]
+async fn test_full_duplex_hears_speech_over_playback() {
+    let mut reactor = reactor(ConversationMode::FullDuplex);
+    start_speaking(&mut reactor);
+    reactor.tick_step(vec![loud_chunk()]);
+    assert!(reactor.state.active_segment_id().is_some());
+}
+
+#[tokio::test]
+async fn test_mode_switch_at_runtime_is_published() {
+    let mut reactor = reactor(ConversationMode::FullDuplex);
+    let view = reactor.subscribe_view();
+    start_speaking(&mut reactor);
+    assert!(view.borrow().listening);
+
+    reactor.set_conversation_mode(ConversationMode::HalfDuplex);
+    assert_eq!(view.borrow().conversation_mode, ConversationMode::HalfDuplex);
+    assert!(!view.borrow().listening, "Published immediately, not on the next tick");
+
+    reactor.tick_step(vec![loud_chunk()]);
+    assert!(reactor.state.active_segment_id().is_none());
+}
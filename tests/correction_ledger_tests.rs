@@ -0,0 +1,125 @@
+use nexus::kernel::effects::EffectBatch;
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::intent::arbitrator::IntentArbitrator;
+use nexus::kernel::intent::corrections::{corrected_hypothesis, phrase_hash, CorrectionLedger, CORRECTION_WINDOW_TICKS};
+use nexus::kernel::intent::types::{IntentHypothesis, IntentState};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+
+mod common;
+use common::jump;
+
+fn reactor() -> Reactor {
+    common::builder()
+        .arbitrator(Box::new(IntentArbitrator::with_ledger(CorrectionLedger::new())))
+        .build()
+}
+
+fn say(reactor: &mut Reactor, text: &str) -> EffectBatch {
+    reactor.tick_step(vec![Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::Text(text.to_string()),
+        captured_at: None,
+    })])
+}
+
+fn hypothesis(reactor: &Reactor) -> Option<IntentHypothesis> {
+    match reactor.state.intent_state() {
+        IntentState::Stable(c) => Some(c.hypothesis.clone()),
+        IntentState::Forming(cands) => cands.first().map(|c| c.hypothesis.clone()),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_correction_parsing() {
+    assert_eq!(corrected_hypothesis("No, I was asking"), Some(IntentHypothesis::Inquiry));
+    assert_eq!(corrected_hypothesis("No! That wasn't a question."), Some(IntentHypothesis::Statement));
+    assert_eq!(corrected_hypothesis("wrong, I was just thinking out loud"), Some(IntentHypothesis::ThinkingAloud));
+    assert_eq!(corrected_hypothesis("No, that was a command"), Some(IntentHypothesis::Command));
+    assert_eq!(corrected_hypothesis("No"), None, "A bare no rejects the answer, not the reading");
+
+    assert_eq!(phrase_hash("Tell me about Paris."), phrase_hash("tell me   about paris"));
+    assert_ne!(phrase_hash("tell me about Paris"), phrase_hash("tell me about Rome"));
+}
+
+#[tokio::test]
+async fn test_correction_changes_next_classification() {
+    let mut reactor = reactor();
+    say(&mut reactor, "Tell me about Paris");
+    assert_eq!(hypothesis(&reactor), Some(IntentHypothesis::Statement));
+
+    say(&mut reactor, "No, I was asking");
+    say(&mut reactor, "tell me about paris!");
+    assert!(matches!(reactor.state.intent_state(), IntentState::Stable(c) if c.hypothesis == IntentHypothesis::Inquiry));
+
+    // Other phrasings still go through the heuristics
+    say(&mut reactor, "Tell me about Rome");
+    assert_eq!(hypothesis(&reactor), Some(IntentHypothesis::Statement));
+}
+
+#[tokio::test]
+async fn test_unrelated_corrections_teach_nothing() {
+    let mut reactor = reactor();
+
+    // Bare "no"
+    say(&mut reactor, "Tell me about Paris");
+    say(&mut reactor, "no");
+    say(&mut reactor, "Tell me about Paris");
+    assert_eq!(hypothesis(&reactor), Some(IntentHypothesis::Statement));
+
+    // Too late to refer to the phrase
+    let late = reactor.tick.frame + CORRECTION_WINDOW_TICKS + 1;
+    jump(&mut reactor, late);
+    say(&mut reactor, "No, I was asking");
+    say(&mut reactor, "Tell me about Paris");
+    assert_eq!(hypothesis(&reactor), Some(IntentHypothesis::Statement));
+
+    // Names the reading we already had
+    say(&mut reactor, "No, I was just saying");
+    say(&mut reactor, "Tell me about Paris");
+    assert_eq!(hypothesis(&reactor), Some(IntentHypothesis::Statement));
+}
+
+#[test]
+fn test_ledger_persists_hashes_only() {
+    let path = std::env::temp_dir().join(format!("nexus_corrections_{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut ledger = CorrectionLedger::open(&path).unwrap();
+    ledger.record(phrase_hash("Tell me about Paris"), IntentHypothesis::Inquiry);
+    ledger.record(phrase_hash("Tell me about Paris"), IntentHypothesis::Command);
+    assert_eq!(ledger.len(), 1, "Newest correction replaces the old one");
+    assert!(!path.exists(), "Recording changes memory only");
+
+    ledger.snapshot().unwrap().save().unwrap();
+    let raw = std::fs::read_to_string(&path).unwrap();
+    assert!(!raw.to_lowercase().contains("paris"));
+    let reopened = CorrectionLedger::open(&path).unwrap();
+    assert_eq!(reopened.lookup("tell me about paris"), Some(&IntentHypothesis::Command));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn test_learned_correction_is_written_by_the_driver() {
+    let path = std::env::temp_dir().join(format!("nexus_corrections_reactor_{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let (tx, rx) = tokio::sync::mpsc::channel(100);
+    let mut reactor = Reactor::builder(rx, tx)
+        .config(ReactorConfig { llm_planning: false, ..Default::default() })
+        .arbitrator(Box::new(IntentArbitrator::with_ledger(CorrectionLedger::open(&path).unwrap())))
+        .build();
+
+    say(&mut reactor, "Tell me about Paris");
+    let effects = say(&mut reactor, "No, I was asking");
+    assert!(!path.exists(), "No file I/O inside the tick");
+    let snapshot = effects.iter().find_map(|e| match e {
+        SideEffect::PersistCorrections(snapshot) => Some(snapshot.clone()),
+        _ => None,
+    }).expect("The ledger is handed to the driver");
+    assert_eq!(snapshot.path(), path.as_path());
+
+    snapshot.save().unwrap();
+    assert_eq!(CorrectionLedger::open(&path).unwrap().lookup("tell me about paris"), Some(&IntentHypothesis::Inquiry));
+    let _ = std::fs::remove_file(&path);
+}
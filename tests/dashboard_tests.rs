@@ -0,0 +1,86 @@
+use nexus::dashboard::{DashboardConfig, DashboardSnapshot};
+use nexus::kernel::dialogue::{DialogueLog, Speaker};
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::intent::long_horizon::{IntentStatus, LongHorizonIntent};
+use nexus::kernel::intent::types::IntentHypothesis;
+use nexus::kernel::memory::sensitivity::{RedactionLevel, SensitivityClass};
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::time::Tick;
+
+mod common;
+use common::reactor;
+
+fn intent(id: &str, decay_score: f32) -> LongHorizonIntent {
+    LongHorizonIntent {
+        id: id.to_string(),
+        hypothesis: IntentHypothesis::Statement,
+        source_symbol_ids: vec![],
+        created_at: Tick { frame: 0 },
+        last_active_at: Tick { frame: 0 },
+        last_updated_at: Tick { frame: 0 },
+        suspended_at: None,
+        decay_score,
+        status: IntentStatus::Active,
+        context: Default::default(),
+    }
+}
+
+#[test]
+fn test_dialogue_log_is_bounded_and_redacted() {
+    let mut log = DialogueLog::with_capacity(3);
+    log.record(Tick { frame: 1 }, Speaker::User, "Hello");
+    log.record(Tick { frame: 2 }, Speaker::System, "Hi there");
+    log.record(Tick { frame: 3 }, Speaker::User, "My bank account number is 1234");
+    log.record(Tick { frame: 4 }, Speaker::System, "Noted");
+    assert_eq!(log.len(), 3, "Oldest turn evicted");
+
+    let masked = log.recent(10, RedactionLevel::Mask);
+    assert_eq!(masked.len(), 3);
+    assert_eq!(masked[1].text, "[redacted]");
+    assert_eq!(masked[1].sensitivity, Some(SensitivityClass::Finance));
+
+    let omitted = log.recent(10, RedactionLevel::Omit);
+    assert_eq!(omitted.iter().map(|t| t.text.as_str()).collect::<Vec<_>>(), vec!["Hi there", "Noted"]);
+    assert_eq!(log.recent(1, RedactionLevel::Mask)[0].text, "Noted");
+}
+
+#[tokio::test]
+async fn test_snapshot_records_turns_and_redacts_intents() {
+    let mut reactor = reactor();
+    reactor.tick_step(vec![Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::Text("What is gravity?".to_string()),
+        captured_at: None,
+    })]);
+    reactor.state.reduce(StateDelta::LongHorizonIntentUpdate(intent("plain", 0.4)));
+    reactor.state.reduce(StateDelta::LongHorizonIntentUpdate(intent("secret", 0.9)));
+    reactor.state.reduce(StateDelta::IntentSensitivityMarked { intent_id: "secret".to_string(), class: SensitivityClass::Health });
+
+    let snapshot = DashboardSnapshot::capture(&reactor, &DashboardConfig::default());
+    assert_eq!(snapshot.view.tick, reactor.tick);
+    assert_eq!(snapshot.turns[0].speaker, Speaker::User);
+    assert_eq!(snapshot.turns[0].text, "What is gravity?");
+
+    let ids: Vec<&str> = snapshot.intents.iter().map(|c| c.id.as_str()).collect();
+    assert!(ids.contains(&"[redacted]") && ids.contains(&"plain"));
+    assert!(!ids.contains(&"secret"));
+
+    let omit = DashboardConfig { redaction: RedactionLevel::Omit, ..Default::default() };
+    let snapshot = DashboardSnapshot::capture(&reactor, &omit);
+    assert!(snapshot.intents.iter().all(|c| c.sensitivity.is_none()));
+}
+
+#[test]
+fn test_consent_is_off_by_default() {
+    let config = DashboardConfig::default();
+    assert!(!config.consent);
+    assert!(config.bind.ip().is_loopback());
+}
+
+#[cfg(feature = "dashboard")]
+#[tokio::test]
+async fn test_server_refuses_without_consent() {
+    let shared = std::sync::Arc::new(std::sync::Mutex::new(reactor()));
+    let result = nexus::dashboard::server::serve(shared, DashboardConfig::default()).await;
+    assert!(result.is_err());
+}
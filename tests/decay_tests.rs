@@ -0,0 +1,99 @@
+use nexus::kernel::decay::{exponential, half_life, linear, unit};
+use nexus::kernel::intent::long_horizon::IntentStatus;
+use nexus::kernel::intent::types::{IntentCandidate, IntentHypothesis, IntentStability};
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::state::StateDelta;
+use nexus::monitor::monitor::SelfObservationMonitor;
+use nexus::monitor::types::SelfObservation;
+
+mod common;
+use common::reactor;
+
+// A registered long-horizon intent
+fn intent(reactor: &mut Reactor) -> String {
+    let cand = IntentCandidate {
+        id: "cand1".to_string(),
+        hypothesis: IntentHypothesis::Inquiry,
+        confidence: 0.9,
+        source_symbol_ids: vec!["seg1".to_string()],
+        semantic_hash: 12345,
+        stability: IntentStability::Stable,
+    };
+    for delta in reactor.lhim.register_intent(&cand, &reactor.state, reactor.tick, &mut reactor.telemetry) {
+        reactor.state.reduce(delta);
+    }
+    reactor.state.active_intents().keys().next().unwrap().clone()
+}
+
+#[test]
+fn test_exponential_halves_and_stays_in_range() {
+    assert!((exponential(0.8, 100, 100.0) - 0.4).abs() < 1e-6);
+    assert!((exponential(1.0, 300, 100.0) - 0.125).abs() < 1e-6);
+    assert_eq!(exponential(0.8, 0, 100.0), 0.8);
+    assert!((half_life(0.9997) - 2310.0).abs() < 1.0);
+    assert_eq!(half_life(1.0), f32::INFINITY);
+    assert_eq!(half_life(-0.5), 0.0);
+
+    // Extreme jumps and bad inputs: finite, never negative, never growing
+    assert_eq!(exponential(1.0, u64::MAX, 2310.0), 0.0, "No subnormal left over");
+    assert_eq!(exponential(f32::NAN, 10, 100.0), 0.0);
+    assert_eq!(exponential(-0.5, 10, 100.0), 0.0);
+    assert_eq!(exponential(0.5, 10, 0.0), 0.0);
+    assert_eq!(exponential(0.5, 10, f32::NAN), 0.0);
+    assert_eq!(exponential(0.5, u64::MAX, f32::INFINITY), 0.5);
+    assert!(exponential(f32::INFINITY, 1, 100.0).is_finite());
+}
+
+#[test]
+fn test_linear_floors_at_zero() {
+    assert!((linear(0.5, 10, 0.01) - 0.4).abs() < 1e-6);
+    assert_eq!(linear(0.5, u64::MAX, 0.01), 0.0);
+    assert_eq!(linear(0.5, u64::MAX, f32::INFINITY), 0.0);
+    assert_eq!(linear(0.5, 10, -0.1), 0.5, "A negative rate does not grow");
+    assert_eq!(linear(0.5, 10, f32::NAN), 0.5);
+    assert_eq!(linear(f32::NAN, 10, 0.01), 0.0);
+    assert_eq!((unit(f32::NAN), unit(1.5), unit(-2.0), unit(0.3)), (0.0, 1.0, 0.0, 0.3));
+}
+
+#[test]
+fn test_long_idle_jump_invalidates_cleanly() {
+    let mut reactor = reactor();
+    let id = intent(&mut reactor);
+    reactor.tick_step(vec![]);
+    assert_eq!(reactor.state.active_intents()[&id].status, IntentStatus::Active);
+
+    // Asleep for ages: decays to nothing in one step, no NaN
+    reactor.advance_idle(1_000_000_000_000);
+    reactor.tick_step(vec![]);
+    let slept = &reactor.state.active_intents()[&id];
+    assert_eq!((slept.decay_score, &slept.status), (0.0, &IntentStatus::Invalidated));
+}
+
+#[test]
+fn test_corrupt_score_is_dropped() {
+    // A NaN score (hydrated, replicated) is dropped rather than carried
+    let mut reactor = reactor();
+    let id = intent(&mut reactor);
+    let mut corrupt = reactor.state.active_intents()[&id].clone();
+    corrupt.decay_score = f32::NAN;
+    if let Some(delta) = reactor.lhim.adopt_intent(corrupt) {
+        reactor.state.reduce(delta);
+    }
+    reactor.tick_step(vec![]);
+    let dropped = &reactor.state.active_intents()[&id];
+    assert_eq!((dropped.decay_score, &dropped.status), (0.0, &IntentStatus::Invalidated));
+}
+
+#[test]
+fn test_monitor_heals_over_any_gap() {
+    let mut monitor = SelfObservationMonitor::new();
+    let Some(StateDelta::MetaLatentUpdate { delta }) = monitor.tick(1, &[SelfObservation::UserCorrection { output_id: None }]) else {
+        panic!("The monitor always reports");
+    };
+    assert!(delta.confidence_penalty > 0.4 && delta.correction_bias > 0.3);
+
+    let Some(StateDelta::MetaLatentUpdate { delta }) = monitor.tick(u64::MAX, &[]) else {
+        panic!("The monitor always reports");
+    };
+    assert_eq!((delta.interruption_sensitivity, delta.confidence_penalty, delta.correction_bias), (0.0, 0.0, 0.0));
+}
@@ -0,0 +1,54 @@
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::event::Event;
+use nexus::kernel::trace::TraceEntry;
+use nexus::kernel::crystallizer::GateRule;
+use nexus::kernel::state::SharedState;
+use nexus::planner::types::{Intent, PlanningEpoch};
+use tokio::sync::mpsc;
+
+fn traced_reactor() -> Reactor {
+    let (tx, rx) = mpsc::channel(100);
+    let config = ReactorConfig { decision_trace: true, ..Default::default() };
+    Reactor::new(rx, tx, config)
+}
+
+#[tokio::test]
+async fn test_trace_records_gate_and_plan_acceptance() {
+    let mut reactor = traced_reactor();
+
+    let epoch = PlanningEpoch { tick: reactor.tick, state_version: 0 };
+    reactor.tick_step(vec![Event::PlanProposed(epoch, Intent::BeginResponse { confidence: 0.9 })]);
+
+    let traces = reactor.trace.recent(10);
+    assert_eq!(traces.len(), 1);
+    let entries = &traces[0].entries;
+    assert!(entries.iter().any(|e| matches!(e, TraceEntry::PlanAccepted { .. })));
+    assert!(entries.iter().any(|e| matches!(e, TraceEntry::Gate(g) if g.rule == GateRule::Clear)));
+}
+
+#[tokio::test]
+async fn test_trace_records_stale_plan() {
+    let mut reactor = traced_reactor();
+    reactor.state = SharedState::at_version(10);
+
+    let epoch = PlanningEpoch { tick: reactor.tick, state_version: 3 };
+    reactor.tick_step(vec![Event::PlanProposed(epoch, Intent::DoNothing)]);
+
+    let traces = reactor.trace.recent(10);
+    assert!(traces[0].entries.iter().any(|e| matches!(e, TraceEntry::PlanStale { epoch_version: 3, .. })));
+}
+
+#[tokio::test]
+async fn test_trace_disabled_by_default_and_skips_idle_ticks() {
+    let (tx, rx) = mpsc::channel(100);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
+    reactor.tick_step(vec![Event::PlanProposed(
+        PlanningEpoch { tick: reactor.tick, state_version: 0 },
+        Intent::BeginResponse { confidence: 0.9 },
+    )]);
+    assert!(reactor.trace.recent(10).is_empty());
+
+    let mut traced = traced_reactor();
+    for _ in 0..5 { traced.tick_step(vec![]); }
+    assert!(traced.trace.recent(10).is_empty(), "Idle ticks must not fill the ring");
+}
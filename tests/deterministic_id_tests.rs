@@ -0,0 +1,68 @@
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::ids::IdGenerator;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::time::Tick;
+
+mod common;
+
+fn reactor(seed: u64) -> Reactor {
+    common::builder().config(ReactorConfig { id_seed: Some(seed), ..common::config() }).build()
+}
+
+fn text(content: &str) -> Event {
+    Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::Text(content.to_string()), captured_at: None })
+}
+
+/// Every id a session produced: effects, intent candidates, topics.
+fn run(seed: u64) -> Vec<String> {
+    let mut reactor = reactor(seed);
+    let mut ids = Vec::new();
+    for input in ["remind me to call mom tomorrow", "what's the weather", "thanks"] {
+        for effect in reactor.tick_step(vec![text(input)]) {
+            match effect {
+                SideEffect::RequestSpeech { output_id, .. } => ids.push(output_id.to_string()),
+                SideEffect::InvokeTool { call_id, .. } => ids.push(call_id),
+                SideEffect::AskMemoryConsent(request) => ids.push(request.prompt_id),
+                _ => {}
+            }
+        }
+        ids.push(serde_json::to_string(reactor.state.intent_state()).unwrap());
+        ids.extend(reactor.topic_tracker.current.as_ref().map(|t| t.id.to_string()));
+    }
+    ids
+}
+
+#[test]
+fn test_same_seed_same_sequence() {
+    let mut a = IdGenerator::new(42);
+    let mut b = IdGenerator::new(42);
+    a.begin_tick(Tick { frame: 7 });
+    b.begin_tick(Tick { frame: 7 });
+    let first: Vec<String> = (0..3).map(|_| a.next_id()).collect();
+    assert_eq!(first, (0..3).map(|_| b.next_id()).collect::<Vec<_>>());
+    assert_ne!(first[0], first[1], "Ids within a tick are distinct");
+    assert_eq!(a.next_uuid().get_version_num(), 4, "Ids keep the v4 layout");
+
+    // The counter restarts per tick, so an id depends only on (seed, tick, position)
+    a.begin_tick(Tick { frame: 7 });
+    assert_eq!(a.next_id(), first[0]);
+    a.begin_tick(Tick { frame: 8 });
+    assert_ne!(a.next_id(), first[0]);
+}
+
+#[test]
+fn test_different_seeds_differ() {
+    let mut a = IdGenerator::new(1);
+    let mut b = IdGenerator::new(2);
+    assert_ne!(a.next_id(), b.next_id());
+    assert_eq!(IdGenerator::new(9).seed(), 9);
+}
+
+#[tokio::test]
+async fn test_replay_reproduces_kernel_ids() {
+    let first = run(1234);
+    assert!(!first.is_empty());
+    assert_eq!(first, run(1234), "Same seed and events, same ids");
+    assert_ne!(first, run(4321));
+}
@@ -0,0 +1,53 @@
+use nexus::kernel::diagnostic::{DiagnosticCode, Severity};
+use nexus::kernel::effects::EffectPhase;
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::scheduler::{Scheduler, SideEffect};
+use nexus::kernel::time::Tick;
+use nexus::planner::types::Intent;
+
+mod common;
+use common::reactor;
+
+fn diagnostics(effects: &[SideEffect]) -> Vec<(Severity, DiagnosticCode, String)> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::Diagnostic { severity, code, context } => Some((*severity, *code, context.clone())),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn test_codes_are_stable_and_ranked() {
+    assert_eq!(DiagnosticCode::PlanDelayed.as_str(), "NX-PLAN-001");
+    assert_eq!(DiagnosticCode::TranscriptionDenied.as_str(), "NX-ASR-001");
+    assert_eq!(DiagnosticCode::MemoryPressure.as_str(), "NX-MEM-001");
+    assert_eq!(DiagnosticCode::ToolFailed.as_str(), "NX-TOOL-001");
+    assert_eq!(DiagnosticCode::CaptureBlocked.as_str(), "NX-CAP-001");
+
+    assert!(Severity::Debug < Severity::Warning && Severity::Warning < Severity::Error);
+    assert!(DiagnosticCode::ToolFailed.severity().toast());
+    assert!(!DiagnosticCode::MemoryPressure.severity().toast());
+}
+
+#[test]
+fn test_scheduler_delay_is_a_diagnostic() {
+    let (delta, effect) = Scheduler.schedule(Intent::Delay { ticks: 5 }, Tick { frame: 3 }, 0);
+    assert!(delta.is_none());
+    let effect = effect.expect("delay is reported");
+    assert_eq!(effect.phase(), EffectPhase::Request);
+    assert_eq!(diagnostics(&[effect]), vec![(Severity::Debug, DiagnosticCode::PlanDelayed, "ticks=5".to_string())]);
+}
+
+#[tokio::test]
+async fn test_denied_transcription_is_reported() {
+    let mut reactor = reactor();
+    let effects = reactor.tick_step(vec![Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::TranscriptionRequest { segment_id: "seg-missing".to_string() },
+        captured_at: None,
+    })]);
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::RequestTranscription { .. })));
+    assert_eq!(
+        diagnostics(&effects),
+        vec![(Severity::Warning, DiagnosticCode::TranscriptionDenied, "segment=seg-missing".to_string())]
+    );
+}
@@ -0,0 +1,80 @@
+use std::time::{Duration, Instant};
+
+use nexus::kernel::cadence::{CadenceMode, DriverCadence, LowPowerConfig};
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::presence::PresenceState;
+use tokio::sync::mpsc;
+
+mod common;
+
+fn cadence(period_ms: u64, wake_ms: u64, enter_after_ms: u64) -> DriverCadence {
+    DriverCadence::new(Duration::from_millis(period_ms), LowPowerConfig { enabled: true, wake_ms, enter_after_ms })
+}
+
+fn text(text: &str) -> Event {
+    Event::Input(InputEvent { source: "UI".to_string(), content: InputContent::Text(text.to_string()), captured_at: None })
+}
+
+#[tokio::test]
+async fn test_low_power_only_while_dormant_and_idle() {
+    let mut cadence = cadence(20, 500, 0);
+    assert_eq!(cadence.follow(PresenceState::Attentive, true), None);
+    assert_eq!(cadence.follow(PresenceState::Dormant, false), None, "Driver work in flight");
+    assert_eq!(cadence.follow(PresenceState::Dormant, true), Some(CadenceMode::LowPower));
+    assert_eq!(cadence.follow(PresenceState::Dormant, true), None);
+
+    // Presence change or new driver work: back to fine ticks
+    assert_eq!(cadence.follow(PresenceState::Engaged, true), Some(CadenceMode::Fine));
+    cadence.follow(PresenceState::Dormant, true);
+    assert_eq!(cadence.follow(PresenceState::Dormant, false), Some(CadenceMode::Fine));
+}
+
+#[tokio::test]
+async fn test_enters_after_quiet_period() {
+    let mut cadence = cadence(20, 500, 50);
+    assert_eq!(cadence.follow(PresenceState::Dormant, true), None);
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert_eq!(cadence.follow(PresenceState::Dormant, true), Some(CadenceMode::LowPower));
+
+    let mut disabled = DriverCadence::new(Duration::from_millis(20), LowPowerConfig { enabled: false, ..Default::default() });
+    assert_eq!(disabled.follow(PresenceState::Dormant, true), None);
+    assert_eq!(disabled.mode(), CadenceMode::Fine);
+}
+
+#[tokio::test]
+async fn test_event_ends_low_power_wait() {
+    let (tx, mut rx) = mpsc::channel(4);
+    let mut cadence = cadence(20, 5_000, 0);
+    cadence.follow(PresenceState::Dormant, true);
+
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        let _ = tx.send(text("Are you there?")).await;
+    });
+    let started = Instant::now();
+    let wake = cadence.wait(&mut rx).await;
+    assert!(started.elapsed() < Duration::from_secs(2), "Not held to the coarse timer");
+    assert!(matches!(wake.event, Some(Event::Input(InputEvent { content: InputContent::Text(ref t), .. })) if t == "Are you there?"));
+}
+
+#[tokio::test]
+async fn test_coarse_timer_reports_skipped_ticks() {
+    let (_tx, mut rx) = mpsc::channel::<Event>(4);
+    let mut cadence = cadence(10, 100, 0);
+    cadence.follow(PresenceState::Dormant, true);
+
+    let wake = cadence.wait(&mut rx).await;
+    assert!(wake.event.is_none());
+    // 100ms at 10ms per tick: this step plus at least 9 skipped
+    assert!((9..40).contains(&wake.skipped), "skipped {}", wake.skipped);
+}
+
+#[test]
+fn test_advance_idle_keeps_wall_clock() {
+    let mut reactor = common::reactor();
+    reactor.tick_step(vec![]);
+    reactor.advance_idle(24);
+    reactor.tick_step(vec![]);
+    assert_eq!(reactor.tick.frame, 26);
+    assert_eq!(reactor.state.last_tick().frame, 26);
+}
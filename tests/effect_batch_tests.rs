@@ -0,0 +1,56 @@
+use nexus::kernel::effects::{EffectBatch, EffectPhase};
+use nexus::kernel::event::{CaptureFidelity, Event, InputContent, InputEvent, OutputId};
+use nexus::kernel::scheduler::SideEffect;
+
+mod common;
+
+fn spawn(tick: u64) -> SideEffect {
+    SideEffect::SpawnAudio(OutputId { tick, ordinal: 0 }, "hello".to_string())
+}
+
+fn phases(batch: &EffectBatch) -> Vec<EffectPhase> {
+    batch.iter().map(|e| e.phase()).collect()
+}
+
+#[test]
+fn test_control_before_output_regardless_of_push_order() {
+    let mut batch = EffectBatch::new();
+    batch.push(spawn(1));
+    batch.push(SideEffect::RequestTranscription { segment_id: "seg".to_string() });
+    batch.push(SideEffect::StopAudio);
+
+    assert!(matches!(batch[0], SideEffect::StopAudio), "Old audio is stopped before new audio starts");
+    assert_eq!(phases(&batch), vec![EffectPhase::Control, EffectPhase::Request, EffectPhase::Output]);
+}
+
+#[test]
+fn test_emission_order_kept_within_phase() {
+    let batch: EffectBatch = vec![
+        spawn(1),
+        SideEffect::CancelTranscription { segment_id: "a".to_string() },
+        spawn(2),
+        SideEffect::SetCaptureFidelity(CaptureFidelity::Reduced),
+    ].into_iter().collect();
+
+    let outputs: Vec<u64> = batch.phase(EffectPhase::Output).iter().map(|e| match e {
+        SideEffect::SpawnAudio(id, _) => id.tick,
+        other => panic!("unexpected {:?}", other),
+    }).collect();
+    assert_eq!(outputs, vec![1, 2]);
+    assert!(matches!(batch.phase(EffectPhase::Control), [SideEffect::CancelTranscription { .. }, SideEffect::SetCaptureFidelity(_)]));
+    assert!(batch.phase(EffectPhase::Request).is_empty());
+}
+
+#[tokio::test]
+async fn test_tick_step_returns_ordered_batch() {
+    let mut reactor = common::reactor();
+    for text in ["What is gravity?", "Maybe what?", "Switch to work context"] {
+        let batch = reactor.tick_step(vec![Event::Input(InputEvent {
+            source: "Test".to_string(),
+            content: InputContent::Text(text.to_string()),
+            captured_at: None,
+        })]);
+        let order = phases(&batch);
+        assert!(order.windows(2).all(|w| w[0] <= w[1]), "{:?}", order);
+    }
+}
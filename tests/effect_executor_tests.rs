@@ -0,0 +1,186 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use nexus::audio::transcription::QueueDepth;
+use nexus::kernel::audio::calibration::{CalibrationProfile, CalibrationStore, DevicePair};
+use nexus::kernel::backchannel::Cue;
+use nexus::kernel::diagnostic::{DiagnosticCode, Severity};
+use nexus::kernel::driver::{EffectContext, EffectExecutor, EffectHooks};
+use nexus::kernel::event::{AudioSignal, AudioStatus, CaptureFidelity, Event, InputContent, InputEvent, OutputId};
+use nexus::kernel::intent::types::IntentHypothesis;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::time::Tick;
+use nexus::memory::summary::Exchange;
+use nexus::outputs::captions::Caption;
+
+mod common;
+
+// What the hooks were asked to show, in order
+#[derive(Clone, Default)]
+struct Recorded(Arc<Mutex<Vec<String>>>);
+
+impl Recorded {
+    fn take(&self) -> Vec<String> {
+        std::mem::take(&mut self.0.lock().unwrap())
+    }
+}
+
+impl EffectHooks for Recorded {
+    fn caption(&self, caption: &Caption) {
+        self.0.lock().unwrap().push(format!("caption:{}", caption.text));
+    }
+
+    fn diagnostic(&self, severity: Severity, code: DiagnosticCode) {
+        self.0.lock().unwrap().push(format!("diagnostic:{:?}:{}", severity, code.as_str()));
+    }
+
+    fn capture_fidelity(&self, fidelity: CaptureFidelity) {
+        self.0.lock().unwrap().push(format!("fidelity:{:?}", fidelity));
+    }
+
+    fn cue(&self, cue: Cue) {
+        self.0.lock().unwrap().push(format!("cue:{}", cue.sound()));
+    }
+}
+
+fn reactor(caption_only: bool) -> Reactor {
+    common::builder().config(ReactorConfig { caption_only, ..common::config() }).build()
+}
+
+fn audio(signal: AudioSignal) -> Event {
+    Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::Audio(signal), captured_at: None })
+}
+
+async fn next_input(reactor: &mut Reactor) -> InputContent {
+    match tokio::time::timeout(Duration::from_secs(5), reactor.receiver.recv()).await {
+        Ok(Some(Event::Input(input))) => input.content,
+        other => panic!("Expected a driver input, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_transcription_uses_captured_frames() {
+    let mut reactor = reactor(true);
+    let mut executor = EffectExecutor::new(&reactor, 16);
+    reactor.tick_step(vec![audio(AudioSignal::SpeechStart)]);
+    let segment_id = reactor.state.active_segment_id().cloned().unwrap();
+    let mut effects = reactor.tick_step(vec![audio(AudioSignal::SpeechEnd)]).into_vec();
+    effects.push(SideEffect::RequestTranscription { segment_id: "seg_gone".to_string() });
+
+    // Only the frames of requested segments that still exist are taken
+    let context = EffectContext::capture(&reactor, &effects);
+    assert_eq!(context.segments.keys().collect::<Vec<_>>(), vec![&segment_id]);
+
+    for effect in effects {
+        executor.execute(effect, &context);
+    }
+    assert_eq!(executor.transcription_depth(), QueueDepth { queued: 1, in_flight: 0 });
+    executor.execute(SideEffect::CancelTranscription { segment_id }, &context);
+    assert_eq!(executor.transcription_depth(), QueueDepth::default());
+}
+
+#[tokio::test]
+async fn test_hooks_show_ui_effects() {
+    let reactor = reactor(true);
+    let hooks = Recorded::default();
+    let mut executor = EffectExecutor::new(&reactor, 16).with_hooks(hooks.clone());
+    let context = EffectContext::capture(&reactor, &[]);
+
+    executor.execute(SideEffect::SetCaptureFidelity(CaptureFidelity::Full), &context);
+    executor.execute(SideEffect::diagnostic(DiagnosticCode::PlanDelayed, "ticks=3"), &context);
+    // Caption-only: the cue is shown, not played
+    executor.execute(SideEffect::PlayCue(Cue::MmHm), &context);
+    assert_eq!(hooks.take(), vec![
+        "fidelity:Full".to_string(),
+        format!("diagnostic:{:?}:{}", DiagnosticCode::PlanDelayed.severity(), DiagnosticCode::PlanDelayed.as_str()),
+        format!("cue:{}", Cue::MmHm.sound()),
+    ]);
+}
+
+#[tokio::test]
+async fn test_summary_without_llm_falls_back() {
+    let mut reactor = reactor(true);
+    let mut executor = EffectExecutor::new(&reactor, 16);
+    let context = EffectContext::capture(&reactor, &[]);
+    let exchange = Exchange {
+        id: "intent-1".to_string(),
+        hypothesis: IntentHypothesis::Statement,
+        user_text: "I moved the meeting".to_string(),
+        reply: "Okay.".to_string(),
+        topic: None,
+        opened_at: Tick { frame: 1 },
+    };
+    executor.execute(SideEffect::RequestSummary { exchange_id: "intent-1".to_string(), exchange }, &context);
+    let summary = next_input(&mut reactor).await;
+    assert!(matches!(&summary, InputContent::ExchangeSummary { exchange_id, text: None } if exchange_id == "intent-1"), "{:?}", summary);
+}
+
+#[cfg(feature = "tts")]
+#[tokio::test]
+async fn test_stop_audio_ends_playback() {
+    let mut reactor = reactor(true);
+    let hooks = Recorded::default();
+    let mut executor = EffectExecutor::new(&reactor, 16).with_hooks(hooks.clone());
+    let context = EffectContext::capture(&reactor, &[]);
+    let id = OutputId { tick: 4, ordinal: 0 };
+
+    // Caption-only: a silent timer stands in for the voice
+    executor.execute(SideEffect::SpawnAudio(id, "A reply long enough to still be showing.".to_string()), &context);
+    assert_eq!(hooks.take(), vec!["caption:A reply long enough to still be showing.".to_string()]);
+    assert!(executor.is_playing());
+    let started = next_input(&mut reactor).await;
+    assert!(matches!(started, InputContent::AudioStatus(AudioStatus::PlaybackStarted { output_id }) if output_id == id.into()), "{:?}", started);
+
+    executor.execute(SideEffect::StopAudio, &context);
+    assert!(!executor.is_playing());
+    // Stopped: ended, never completed
+    match next_input(&mut reactor).await {
+        InputContent::AudioStatus(AudioStatus::PlaybackEnded { output_id, .. }) => assert_eq!(output_id, id.into()),
+        other => panic!("Expected PlaybackEnded, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_store_write_backs_run_off_the_tick() {
+    let reactor = reactor(true);
+    let mut executor = EffectExecutor::new(&reactor, 16);
+    let context = EffectContext::capture(&reactor, &[]);
+    let path = std::env::temp_dir().join(format!("nexus_executor_{}_calibration.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut store = CalibrationStore::open(&path).unwrap();
+    store.set(DevicePair::default(), CalibrationProfile::default().with_noise_floor(0.01));
+    executor.execute(SideEffect::PersistCalibration(store.snapshot().unwrap()), &context);
+    // Written in the background
+    for _ in 0..100 {
+        if CalibrationStore::open(&path).is_ok_and(|saved| saved.len() == 1) {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    panic!("Calibration never written to {}", path.display());
+}
+
+#[tokio::test]
+async fn test_telemetry_export_is_written_by_the_driver() {
+    use nexus::kernel::telemetry::exporter::{ExportConfig, TelemetryExporter};
+
+    let reactor = reactor(true);
+    let mut executor = EffectExecutor::new(&reactor, 16);
+    let context = EffectContext::capture(&reactor, &[]);
+    let path = std::env::temp_dir().join(format!("nexus_executor_{}_snapshots.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut exporter = TelemetryExporter::new(ExportConfig { interval_ticks: 1, file: true, otlp: false });
+    exporter.file = Some(path.clone());
+    executor.execute(SideEffect::ExportTelemetry(exporter.export(Tick { frame: 7 }, reactor.telemetry.snapshot())), &context);
+    for _ in 0..100 {
+        if std::fs::read_to_string(&path).is_ok_and(|text| text.contains("\"tick\":7")) {
+            let _ = std::fs::remove_file(&path);
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    panic!("Telemetry snapshot never written to {}", path.display());
+}
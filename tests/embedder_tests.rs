@@ -0,0 +1,52 @@
+// `cargo test` also builds `examples/`, so examples/minimal_embedder.rs stays compiling.
+use nexus::kernel::effects::{EffectBatch, EffectExecutor};
+use nexus::kernel::event::{Event, InputContent, InputEvent, OutputId};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::memory::{FileSemanticStore, InMemoryEpisodicStore};
+
+#[derive(Default)]
+struct RecordingExecutor {
+    executed: Vec<SideEffect>,
+}
+
+impl EffectExecutor for RecordingExecutor {
+    fn execute(&mut self, effect: SideEffect) {
+        self.executed.push(effect);
+    }
+}
+
+#[test]
+fn test_execute_batch_runs_in_phase_order() {
+    let mut batch = EffectBatch::new();
+    batch.push(SideEffect::SpawnAudio(OutputId { tick: 1, ordinal: 0 }, "hi".to_string()));
+    batch.push(SideEffect::StopAudio);
+
+    let mut executor = RecordingExecutor::default();
+    executor.execute_batch(batch);
+    assert!(matches!(executor.executed.as_slice(), [SideEffect::StopAudio, SideEffect::SpawnAudio(..)]));
+}
+
+#[tokio::test]
+async fn test_embedder_loop_with_typed_text() {
+    // Same wiring as the example: injected stores, offline planning, view subscription
+    let path = std::env::temp_dir().join(format!("nexus_embedder_{}.json", std::process::id()));
+    let (tx, rx) = tokio::sync::mpsc::channel(10);
+    let mut reactor = Reactor::builder(rx, tx)
+        .config(ReactorConfig { llm_planning: false, ..Default::default() })
+        .episodic_store(Box::new(InMemoryEpisodicStore::new()))
+        .semantic_store(Box::new(FileSemanticStore::new(path.clone())))
+        .build();
+    let view = reactor.subscribe_view();
+
+    let mut executor = RecordingExecutor::default();
+    executor.execute_batch(reactor.tick_step(vec![Event::Input(InputEvent {
+        source: "Embedder".to_string(),
+        content: InputContent::Text("Maybe what?".to_string()),
+        captured_at: None,
+    })]));
+
+    assert!(!executor.executed.is_empty(), "Ambiguous typed text asks for clarification");
+    assert_eq!(view.borrow().tick, reactor.tick);
+    let _ = std::fs::remove_file(path);
+}
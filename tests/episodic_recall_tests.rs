@@ -0,0 +1,85 @@
+use nexus::memory::{
+    Claim, ClaimValue, EntityId, EpisodicMemoryEntry, EpisodicQuery, EpisodicStore, InMemoryEpisodicStore, Modality,
+    Predicate, TimeRange,
+};
+use nexus::planner::tools::{execute, parse_tool_call, recall_episodes, ToolCall, RECALL_EPISODES};
+use serde_json::json;
+
+fn entry(subject: EntityId, text: &str, tick: u64, ms: Option<u64>) -> EpisodicMemoryEntry {
+    EpisodicMemoryEntry {
+        claim: Claim::new(subject, Predicate::Is, ClaimValue::Text(text.to_string()), Modality::Asserted),
+        confidence: 0.9,
+        created_at_tick: tick,
+        last_reinforced_tick: tick,
+        decay_rate: 0.0,
+        created_at_ms: ms,
+    }
+}
+
+fn store() -> InMemoryEpisodicStore {
+    let mut episodic = InMemoryEpisodicStore::new();
+    episodic.insert(entry(EntityId::Topic("garden".to_string()), "Tomatoes", 300, Some(3_000)));
+    episodic.insert(entry(EntityId::User, "Breakfast", 100, Some(1_000)));
+    episodic.insert(entry(EntityId::Topic("garden".to_string()), "Basil", 200, Some(2_000)));
+    episodic.insert(entry(EntityId::User, "Legacy", 150, None));
+    episodic
+}
+
+fn texts(hits: &[&EpisodicMemoryEntry]) -> Vec<String> {
+    hits.iter().map(|e| format!("{:?}", e.claim.object)).collect()
+}
+
+#[test]
+fn test_tick_range_oldest_first() {
+    let episodic = store();
+    let hits = episodic.in_range(&EpisodicQuery { range: TimeRange::Ticks { from: 100, to: 200 }, topic: None });
+    assert_eq!(texts(&hits), vec![r#"Text("Breakfast")"#, r#"Text("Legacy")"#, r#"Text("Basil")"#]);
+}
+
+#[test]
+fn test_wall_clock_range_and_topic() {
+    let episodic = store();
+    let morning = EpisodicQuery { range: TimeRange::WallClock { from_ms: 0, to_ms: 2_500 }, topic: None };
+    // Entries without a wall-clock stamp never match wall-clock bounds
+    assert_eq!(texts(&episodic.in_range(&morning)), vec![r#"Text("Breakfast")"#, r#"Text("Basil")"#]);
+
+    let garden = EpisodicQuery { topic: Some("garden".to_string()), ..morning };
+    assert_eq!(texts(&episodic.in_range(&garden)), vec![r#"Text("Basil")"#]);
+}
+
+#[test]
+fn test_planner_tool_roundtrip() {
+    let episodic = store();
+    let args = json!({ "range": { "WallClock": { "from_ms": 1_500, "to_ms": 5_000 } }, "topic": "garden" });
+    let call = parse_tool_call(RECALL_EPISODES, &args).expect("valid call");
+    assert!(matches!(&call, ToolCall::RecallEpisodes(q) if q.topic.as_deref() == Some("garden")));
+
+    let result = execute(&call, &episodic);
+    assert_eq!(result.as_array().unwrap().len(), 2);
+    assert_eq!(result[0]["created_at_tick"], 200);
+
+    assert!(parse_tool_call("delete_everything", &args).is_none());
+    assert!(parse_tool_call(RECALL_EPISODES, &json!({ "topic": "garden" })).is_none());
+}
+
+#[test]
+fn test_recall_is_bounded() {
+    let mut episodic = InMemoryEpisodicStore::new();
+    for tick in 0..50 {
+        episodic.insert(entry(EntityId::User, "fact", tick, None));
+    }
+    let hits = recall_episodes(&episodic, &EpisodicQuery { range: TimeRange::Ticks { from: 0, to: 100 }, topic: None });
+    assert_eq!(hits.len(), 20);
+    assert_eq!(hits[0].created_at_tick, 30, "Keeps the newest entries");
+}
+
+#[tokio::test]
+async fn test_reactor_recall() {
+    let (tx, rx) = tokio::sync::mpsc::channel(10);
+    let reactor = nexus::kernel::reactor::Reactor::builder(rx, tx)
+        .episodic_store(Box::new(store()))
+        .build();
+    let hits = reactor.recall_episodes(&EpisodicQuery { range: TimeRange::Ticks { from: 250, to: 400 }, topic: None });
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].created_at_ms, Some(3_000));
+}
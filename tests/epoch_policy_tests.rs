@@ -0,0 +1,144 @@
+use nexus::kernel::epoch::{EpochPolicy, IntentLagOverrides, StaleDomains, StateDomain, DEFAULT_MAX_VERSION_LAG};
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::state::{SharedState, StateDelta, VERSION_HISTORY};
+use nexus::kernel::time::Tick;
+use nexus::kernel::trace::TraceEntry;
+use nexus::planner::types::{Intent, PlanningEpoch};
+use tokio::sync::mpsc;
+
+fn epoch(state_version: u64) -> PlanningEpoch {
+    PlanningEpoch { tick: Tick { frame: 0 }, state_version }
+}
+
+// Perception: not counted by default
+fn device_change() -> StateDelta {
+    StateDelta::OutputDeviceChanged(Some("Headset".to_string()))
+}
+
+// Dialogue
+fn user_text(text: &str) -> StateDelta {
+    StateDelta::InputReceived(InputEvent { source: "Keyboard".to_string(), content: InputContent::Text(text.to_string()), captured_at: None })
+}
+
+#[test]
+fn test_default_lag_window() {
+    let policy = EpochPolicy { allow_debug_injections: false, ..Default::default() };
+    assert_eq!(policy.max_version_lag, DEFAULT_MAX_VERSION_LAG);
+    assert!(policy.accepts(&epoch(10), &Intent::DoNothing, 10));
+    assert!(policy.accepts(&epoch(9), &Intent::DoNothing, 10));
+    assert!(!policy.accepts(&epoch(8), &Intent::DoNothing, 10));
+    assert!(!policy.accepts(&epoch(11), &Intent::DoNothing, 10), "Plans from the future are stale");
+}
+
+#[test]
+fn test_debug_injection_is_gated() {
+    let strict = EpochPolicy { allow_debug_injections: false, ..Default::default() };
+    assert!(!strict.accepts(&epoch(0), &Intent::DoNothing, 10));
+    assert!(strict.accepts(&epoch(0), &Intent::DoNothing, 1), "Version 0 is still a real epoch at the start");
+
+    let debug = EpochPolicy { allow_debug_injections: true, ..Default::default() };
+    assert!(debug.accepts(&epoch(0), &Intent::DoNothing, 10));
+    assert_eq!(EpochPolicy::default().allow_debug_injections, cfg!(debug_assertions));
+}
+
+#[test]
+fn test_per_intent_overrides() {
+    let policy = EpochPolicy {
+        max_version_lag: 0,
+        allow_debug_injections: false,
+        overrides: IntentLagOverrides { delay: Some(5), ..Default::default() },
+        ..Default::default()
+    };
+    assert!(policy.accepts(&epoch(5), &Intent::Delay { ticks: 10 }, 10));
+    assert!(!policy.accepts(&epoch(4), &Intent::Delay { ticks: 10 }, 10));
+    assert!(!policy.accepts(&epoch(9), &Intent::BeginResponse { confidence: 0.9 }, 10), "No override: global lag");
+}
+
+#[tokio::test]
+async fn test_reactor_applies_configured_policy() {
+    let (tx, rx) = mpsc::channel(100);
+    let config = ReactorConfig {
+        decision_trace: true,
+        epoch: EpochPolicy { allow_debug_injections: false, ..Default::default() },
+        ..Default::default()
+    };
+    let mut reactor = Reactor::new(rx, tx, config);
+    reactor.state = SharedState::at_version(10);
+
+    reactor.tick_step(vec![Event::PlanProposed(epoch(0), Intent::DoNothing)]);
+    let traces = reactor.trace.recent(10);
+    assert!(traces[0].entries.iter().any(|e| matches!(e, TraceEntry::PlanStale { epoch_version: 0, .. })));
+}
+
+#[test]
+fn test_domain_versions_tracked() {
+    let mut state = SharedState::at_version(10);
+    assert_eq!(device_change().domain(), Some(StateDomain::Perception));
+    assert_eq!(StateDelta::Tick(Tick { frame: 1 }).domain(), None);
+
+    state.reduce(device_change());
+    state.reduce(user_text("hi"));
+    state.reduce(StateDelta::Tick(Tick { frame: 1 }));
+    assert_eq!(state.version(), 12, "Ticks are not versioned");
+    let lag = state.domain_lag(10).unwrap();
+    assert_eq!((lag.dialogue, lag.perception, lag.outputs), (1, 1, 0));
+    assert_eq!(state.domain_lag(11).unwrap().dialogue, 1);
+    assert_eq!(state.domain_lag(12).unwrap(), Default::default());
+    assert!(state.domain_lag(9).is_none(), "Before the state's history");
+    assert!(state.domain_lag(13).is_none());
+}
+
+#[test]
+fn test_lag_counts_declared_domains() {
+    let policy = EpochPolicy { allow_debug_injections: false, ..Default::default() };
+    let mut state = SharedState::at_version(10);
+    for _ in 0..3 {
+        state.reduce(device_change());
+    }
+    assert!(policy.accepts_at(&epoch(10), &Intent::DoNothing, &state), "Perception alone does not make a plan stale");
+    assert!(!policy.changed_since(&state, 10));
+    assert!(!policy.accepts(&epoch(10), &Intent::DoNothing, state.version()), "Global lag still counts everything");
+
+    let everything = EpochPolicy { domains: StaleDomains::ALL, ..policy };
+    assert!(!everything.accepts_at(&epoch(10), &Intent::DoNothing, &state));
+
+    state.reduce(user_text("one"));
+    assert!(policy.accepts_at(&epoch(10), &Intent::DoNothing, &state));
+    assert!(policy.changed_since(&state, 10));
+    state.reduce(user_text("two"));
+    assert!(!policy.accepts_at(&epoch(10), &Intent::DoNothing, &state));
+    assert!(!policy.accepts_at(&epoch(state.version() + 1), &Intent::DoNothing, &state), "Plans from the future are stale");
+}
+
+#[test]
+fn test_old_epoch_uses_global_lag() {
+    let policy = EpochPolicy { allow_debug_injections: false, ..Default::default() };
+    let mut state = SharedState::default();
+    for _ in 0..VERSION_HISTORY + 5 {
+        state.reduce(device_change());
+    }
+    assert!(state.domain_lag(1).is_none(), "Pushed out of the history");
+    assert!(policy.changed_since(&state, 1), "Unknown: assumed changed");
+    let lenient = EpochPolicy { max_version_lag: VERSION_HISTORY as u64 + 5, ..policy };
+    assert!(!policy.accepts_at(&epoch(1), &Intent::DoNothing, &state));
+    assert!(lenient.accepts_at(&epoch(1), &Intent::DoNothing, &state));
+}
+
+#[tokio::test]
+async fn test_reactor_ignores_perception_lag() {
+    let (tx, rx) = mpsc::channel(100);
+    let config = ReactorConfig {
+        decision_trace: true,
+        epoch: EpochPolicy { allow_debug_injections: false, ..Default::default() },
+        ..Default::default()
+    };
+    let mut reactor = Reactor::new(rx, tx, config);
+    reactor.state = SharedState::at_version(10);
+    reactor.state.reduce(device_change());
+    reactor.state.reduce(device_change());
+
+    reactor.tick_step(vec![Event::PlanProposed(epoch(10), Intent::DoNothing)]);
+    let traces = reactor.trace.recent(10);
+    assert!(traces[0].entries.iter().any(|e| matches!(e, TraceEntry::PlanAccepted { epoch_version: 10, .. })));
+}
@@ -0,0 +1,147 @@
+use nexus::kernel::diagnostic::DiagnosticCode;
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::queue::{QueueConfig, QueueMonitor, QueueStats, DEFAULT_EVENT_QUEUE_CAPACITY};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::shutdown::ShutdownOptions;
+use nexus::kernel::telemetry::event::TelemetryEvent;
+use nexus::kernel::telemetry::export::{bucket, ExportEvent, ExportSalt};
+use nexus::kernel::time::Tick;
+use nexus::memory::store::FileSemanticStore;
+use tokio::sync::mpsc;
+
+fn reactor(capacity: usize) -> (Reactor, mpsc::Sender<Event>) {
+    let semantic = std::env::temp_dir().join(format!("nexus_queue_{}_semantic.json", std::process::id()));
+    let (tx, rx) = mpsc::channel(capacity);
+    let reactor = Reactor::builder(rx, tx.clone())
+        .config(ReactorConfig { llm_planning: false, ..Default::default() })
+        .semantic_store(Box::new(FileSemanticStore::new(semantic)))
+        .build();
+    (reactor, tx)
+}
+
+fn clock(minute_of_day: u16) -> Event {
+    Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::LocalTime { minute_of_day }, captured_at: None })
+}
+
+/// Drain and tick, like the drivers do.
+fn step(reactor: &mut Reactor) -> Vec<SideEffect> {
+    let mut events = Vec::new();
+    while let Ok(event) = reactor.receiver.try_recv() {
+        events.push(event);
+    }
+    reactor.tick_step(events).into_iter().collect()
+}
+
+fn reports(reactor: &Reactor) -> Vec<QueueStats> {
+    reactor.telemetry.events().filter_map(|e| match e {
+        TelemetryEvent::EventQueue { stats } => Some(*stats),
+        _ => None,
+    }).collect()
+}
+
+fn dropped_diagnostic(effects: &[SideEffect]) -> bool {
+    effects.iter().any(|e| matches!(e, SideEffect::Diagnostic { code: DiagnosticCode::EventsDropped, .. }))
+}
+
+#[test]
+fn test_config_defaults() {
+    let config = QueueConfig::default();
+    assert_eq!(config.event_capacity, DEFAULT_EVENT_QUEUE_CAPACITY);
+    let (tx, _rx) = QueueConfig { event_capacity: 512, ..config }.event_channel();
+    assert_eq!(tx.max_capacity(), 512);
+}
+
+#[tokio::test]
+async fn test_depth_and_high_water_in_the_view() {
+    let (mut reactor, tx) = reactor(16);
+    let view = reactor.subscribe_view();
+    for minute in 0..5 {
+        tx.try_send(clock(minute)).unwrap();
+    }
+    step(&mut reactor);
+    assert_eq!(view.borrow().event_queue, QueueStats { depth: 5, capacity: 16, high_water: 5, dropped: 0 });
+
+    tx.try_send(clock(6)).unwrap();
+    step(&mut reactor);
+    let queue = view.borrow().event_queue;
+    assert_eq!((queue.depth, queue.high_water), (1, 5), "High-water mark is kept");
+}
+
+#[tokio::test]
+async fn test_drops_are_counted_and_reported() {
+    let (mut reactor, tx) = reactor(8);
+    let drops = reactor.queue.drops();
+    let effects = step(&mut reactor);
+    assert!(!dropped_diagnostic(&effects));
+    assert_eq!(reports(&reactor).len(), 1, "First tick reports");
+
+    for minute in 0..10 {
+        drops.try_send(&tx, clock(minute));
+    }
+    assert_eq!(drops.count(), 2, "Full after 8");
+    let effects = step(&mut reactor);
+    assert!(dropped_diagnostic(&effects), "Reported at once, not at the next interval");
+    let latest = *reports(&reactor).last().unwrap();
+    assert_eq!((latest.depth, latest.high_water, latest.dropped), (8, 8, 2));
+
+    let effects = step(&mut reactor);
+    assert!(!dropped_diagnostic(&effects), "Only new drops raise the diagnostic");
+    assert_eq!(reports(&reactor).len(), 2);
+
+    // A closed channel is not a drop
+    let (closed, rx) = mpsc::channel(8);
+    drop(rx);
+    assert!(!drops.try_send(&closed, clock(0)));
+    assert_eq!(drops.count(), 2);
+}
+
+#[test]
+fn test_periodic_reports() {
+    let mut monitor = QueueMonitor::new(100);
+    monitor.interval_ticks = 10;
+    monitor.observe(3);
+    assert_eq!(monitor.tick(Tick { frame: 1 }).map(|r| r.stats.depth), Some(3));
+    assert!(monitor.tick(Tick { frame: 5 }).is_none());
+    monitor.drops().record();
+    assert_eq!(monitor.tick(Tick { frame: 6 }).map(|r| r.new_drops), Some(1));
+    assert!(monitor.tick(Tick { frame: 15 }).is_none(), "Interval restarts after a report");
+    assert_eq!(monitor.tick(Tick { frame: 16 }).map(|r| r.new_drops), Some(0));
+}
+
+#[tokio::test]
+async fn test_telemetry_snapshot_and_export() {
+    let (mut reactor, tx) = reactor(8);
+    let drops = reactor.queue.drops();
+    for minute in 0..9 {
+        drops.try_send(&tx, clock(minute));
+    }
+    step(&mut reactor);
+
+    let snapshot = reactor.telemetry.snapshot();
+    assert_eq!((snapshot.event_queue_stats.capacity, snapshot.event_queue_stats.peak_depth, snapshot.event_queue_stats.dropped), (8, 8, 1));
+
+    let export = reactor.telemetry.export_content_free(&ExportSalt::new(1));
+    let exported = export.events.iter().find_map(|e| match e {
+        ExportEvent::EventQueue { stats } => Some(*stats),
+        _ => None,
+    }).unwrap();
+    assert_eq!(exported.capacity, 8, "Configuration, not content");
+    assert_eq!(exported.dropped, bucket(1));
+}
+
+#[tokio::test]
+async fn test_restart_keeps_the_counters() {
+    let (mut reactor, tx) = reactor(8);
+    let drops = reactor.queue.drops();
+    for minute in 0..9 {
+        drops.try_send(&tx, clock(minute));
+    }
+    step(&mut reactor);
+    reactor.restart(&ShutdownOptions::default());
+
+    // Producers keep their counter
+    drops.record();
+    assert_eq!(reactor.queue.stats().dropped, 2);
+    assert_eq!(reactor.queue.stats().high_water, 8);
+}
@@ -0,0 +1,115 @@
+use nexus::kernel::event::{AudioSignal, AudioStatus, Event, InputContent};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::memory::summary::{SummaryMode, EXCHANGE_PREDICATE, SUMMARY_TIMEOUT_TICKS};
+use nexus::memory::{ClaimValue, EntityId, EpisodicMemoryEntry, Modality, Predicate};
+
+mod common;
+use common::input;
+
+fn reactor(mode: SummaryMode) -> Reactor {
+    common::builder().config(ReactorConfig { exchange_summaries: mode, ..common::config() }).build()
+}
+
+fn say(text: &str, seg: &str) -> Event {
+    input(InputContent::ProvisionalText { content: text.to_string(), confidence: 0.9, source_id: seg.to_string(), words: Vec::new(), language: None, is_final: true })
+}
+
+/// Stable command -> reflex confirmation. Returns the reply's playback id.
+fn command(reactor: &mut Reactor, text: &str, seg: &str) -> uuid::Uuid {
+    let effects = reactor.tick_step(vec![say(text, seg)]);
+    effects.iter().find_map(|e| match e {
+        SideEffect::RequestSpeech { output_id, .. } => Some(*output_id),
+        _ => None,
+    }).expect("Stable command is acknowledged")
+}
+
+fn played(id: uuid::Uuid) -> Event {
+    input(InputContent::AudioStatus(AudioStatus::PlaybackEnded { output_id: id.into(), elapsed_ms: 900 }))
+}
+
+fn summaries(reactor: &Reactor) -> Vec<EpisodicMemoryEntry> {
+    reactor.episodic.all().into_iter()
+        .filter(|e| e.claim.predicate == Predicate::Custom(EXCHANGE_PREDICATE.to_string()))
+        .cloned()
+        .collect()
+}
+
+fn text(entry: &EpisodicMemoryEntry) -> String {
+    match &entry.claim.object {
+        ClaimValue::Text(t) => t.clone(),
+        other => panic!("Summary is text: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_acknowledged_exchange_becomes_one_line_entry() {
+    let mut reactor = reactor(SummaryMode::Template);
+    let reply = command(&mut reactor, "Turn off the lights", "seg_1");
+    assert!(summaries(&reactor).is_empty(), "Not complete before the reply is heard");
+
+    reactor.tick_step(vec![played(reply)]);
+    let entries = summaries(&reactor);
+    assert_eq!(entries.len(), 1);
+    let line = text(&entries[0]);
+    assert!(line.starts_with("User requested \"Turn off the lights\"; replied"), "{}", line);
+    assert!(!line.contains('\n'));
+    assert_eq!(entries[0].claim.subject, EntityId::Topic(reactor.state.active_topic().cloned().unwrap()));
+    assert_eq!(entries[0].claim.modality, Modality::Observed);
+
+    // Only once per exchange
+    reactor.tick_step(vec![played(reply)]);
+    assert_eq!(summaries(&reactor).len(), 1);
+}
+
+#[tokio::test]
+async fn test_interrupted_or_sensitive_exchange_is_not_summarized() {
+    let mut reactor = reactor(SummaryMode::Template);
+    let reply = command(&mut reactor, "Turn off the lights", "seg_1");
+    reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechStart))]);
+    reactor.tick_step(vec![played(reply)]);
+    assert!(summaries(&reactor).is_empty(), "Barge-in: the reply was not heard in full");
+
+    let mut reactor = self::reactor(SummaryMode::Template);
+    let reply = command(&mut reactor, "Turn off the lights and text my doctor about my medications", "seg_1");
+    reactor.tick_step(vec![played(reply)]);
+    assert!(summaries(&reactor).is_empty(), "Sensitive turns stay out of history");
+
+    let mut reactor = self::reactor(SummaryMode::Off);
+    let reply = command(&mut reactor, "Turn off the lights", "seg_1");
+    reactor.tick_step(vec![played(reply)]);
+    assert!(summaries(&reactor).is_empty());
+}
+
+#[tokio::test]
+async fn test_llm_summary_with_template_fallback() {
+    let mut reactor = reactor(SummaryMode::Llm);
+    let reply = command(&mut reactor, "Turn off the lights", "seg_1");
+    let effects = reactor.tick_step(vec![played(reply)]);
+    let exchange_id = effects.iter().find_map(|e| match e {
+        SideEffect::RequestSummary { exchange_id, exchange } => {
+            assert_eq!(exchange.user_text, "Turn off the lights");
+            Some(exchange_id.clone())
+        }
+        _ => None,
+    }).expect("Driver is asked for the line");
+    assert!(summaries(&reactor).is_empty());
+
+    reactor.tick_step(vec![input(InputContent::ExchangeSummary {
+        exchange_id,
+        text: Some("  User switched the lights off.\nAssistant: done".to_string()),
+    })]);
+    let entries = summaries(&reactor);
+    assert_eq!(text(&entries[0]), "User switched the lights off.");
+    assert_eq!(entries[0].claim.modality, Modality::Inferred);
+
+    // No answer: the template line is written after the timeout
+    let reply = command(&mut reactor, "Turn on the fan", "seg_2");
+    reactor.tick_step(vec![played(reply)]);
+    for _ in 0..SUMMARY_TIMEOUT_TICKS {
+        reactor.tick_step(vec![]);
+    }
+    let entries = summaries(&reactor);
+    assert_eq!(entries.len(), 2);
+    assert!(text(&entries[1]).starts_with("User requested \"Turn on the fan\""));
+}
@@ -0,0 +1,194 @@
+use nexus::federation::{Causality, Replica, SyncPayload, SyncRecord, SyncScope, VersionVector};
+use nexus::kernel::context::ContextId;
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::intent::long_horizon::{IntentStatus, LongHorizonIntent};
+use nexus::kernel::intent::types::{IntentCandidate, IntentHypothesis, IntentStability};
+use nexus::kernel::memory::sensitivity::SensitivityClass;
+use nexus::kernel::memory::types::MemoryRecord;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::time::Tick;
+
+fn reactor(device: &str, peer: &str, scope: SyncScope) -> Reactor {
+    let (tx, rx) = tokio::sync::mpsc::channel(10);
+    let mut replica = Replica::new(device);
+    replica.set_consent(peer, scope);
+    Reactor::builder(rx, tx)
+        .config(ReactorConfig { llm_planning: false, ..Default::default() })
+        .federation(replica)
+        .build()
+}
+
+fn memory(id: &str, strength: f32, sensitivity: Option<SensitivityClass>) -> MemoryRecord {
+    MemoryRecord {
+        id: id.to_string(),
+        intent: IntentCandidate {
+            id: format!("intent-{}", id),
+            hypothesis: IntentHypothesis::Statement,
+            confidence: 0.9,
+            source_symbol_ids: vec![],
+            semantic_hash: 7,
+            stability: IntentStability::Stable,
+        },
+        first_committed_at: Tick { frame: 1 },
+        last_accessed_at: Tick { frame: 1 },
+        strength,
+        topic_id: None,
+        sensitivity,
+        context: ContextId::default(),
+    }
+}
+
+fn intent(id: &str, status: IntentStatus) -> LongHorizonIntent {
+    LongHorizonIntent {
+        id: id.to_string(),
+        hypothesis: IntentHypothesis::Command,
+        source_symbol_ids: vec![],
+        created_at: Tick { frame: 1 },
+        last_active_at: Tick { frame: 1 },
+        last_updated_at: Tick { frame: 1 },
+        suspended_at: None,
+        decay_score: 1.0,
+        status,
+        context: ContextId::default(),
+    }
+}
+
+/// Deliver `from`'s outgoing records to `to`, as a driver would after opening the envelope.
+fn exchange(from: &mut Reactor, to: &mut Reactor) {
+    let peer = from.federation.device.clone();
+    let records = from.sync_outgoing(&to.federation.device.clone());
+    to.tick_step(vec![Event::Input(InputEvent {
+        source: "Federation".to_string(),
+        content: InputContent::SyncReceived { peer, records },
+        captured_at: None,
+    })]);
+}
+
+#[test]
+fn test_version_vector_causality() {
+    let mut a = VersionVector::new();
+    let mut b = VersionVector::new();
+    assert_eq!(a.compare(&b), Causality::Equal);
+    a.bump("desktop");
+    assert_eq!(a.compare(&b), Causality::After);
+    assert_eq!(b.compare(&a), Causality::Before);
+    b.bump("phone");
+    assert_eq!(a.compare(&b), Causality::Concurrent);
+    a.join(&b);
+    assert_eq!(a.compare(&b), Causality::After);
+    assert_eq!((a.get("desktop"), a.get("phone")), (1, 1));
+}
+
+#[tokio::test]
+async fn test_memories_and_intents_replicate_with_consent() {
+    let mut desktop = reactor("desktop", "phone", SyncScope::ALL);
+    let mut phone = reactor("phone", "desktop", SyncScope::ALL);
+    desktop.state.reduce(StateDelta::MemoryPromoted(memory("m1", 0.8, None)));
+    desktop.state.reduce(StateDelta::LongHorizonIntentUpdate(intent("i1", IntentStatus::Suspended)));
+
+    exchange(&mut desktop, &mut phone);
+    assert_eq!(phone.state.long_term_memory()["m1"].strength, 0.8);
+    assert_eq!(phone.state.active_intents()["i1"].status, IntentStatus::Suspended);
+    assert_eq!(phone.state.long_term_memory()["m1"].first_committed_at, phone.tick, "Ticks are re-stamped locally");
+
+    // Echoing back changes nothing on the origin
+    let echo = phone.sync_outgoing("desktop");
+    assert_eq!(echo.len(), 2);
+    assert!(desktop.federation.apply("phone", echo, &desktop.state, desktop.tick).is_empty());
+}
+
+#[tokio::test]
+async fn test_scope_is_per_device_and_sensitive_stays_local() {
+    let mut desktop = reactor("desktop", "phone", SyncScope { memories: false, intents: true });
+    desktop.federation.set_consent("laptop", SyncScope::NONE);
+    desktop.state.reduce(StateDelta::MemoryPromoted(memory("m1", 0.8, None)));
+    desktop.state.reduce(StateDelta::MemoryPromoted(memory("m2", 0.8, Some(SensitivityClass::Health))));
+    desktop.state.reduce(StateDelta::LongHorizonIntentUpdate(intent("i1", IntentStatus::Active)));
+
+    let keys: Vec<_> = desktop.sync_outgoing("phone").into_iter().map(|r| r.key).collect();
+    assert_eq!(keys.len(), 1, "Only intents go to the phone: {:?}", keys);
+    assert!(desktop.sync_outgoing("laptop").is_empty());
+    assert!(desktop.sync_outgoing("stranger").is_empty(), "No consent by default");
+
+    desktop.federation.set_consent("phone", SyncScope::ALL);
+    let out = desktop.sync_outgoing("phone");
+    assert_eq!(out.len(), 2);
+    assert!(!out.iter().any(|r| matches!(&r.payload, SyncPayload::Memory(m) if m.id == "m2")));
+
+    // Incoming records outside the scope are dropped too
+    let mut laptop = reactor("laptop", "desktop", SyncScope::ALL);
+    exchange(&mut laptop, &mut desktop);
+    let mut phone = reactor("phone", "desktop", SyncScope::NONE);
+    exchange(&mut desktop, &mut phone);
+    assert!(phone.state.long_term_memory().is_empty() && phone.state.active_intents().is_empty());
+}
+
+#[tokio::test]
+async fn test_concurrent_edits_converge() {
+    let mut desktop = reactor("desktop", "phone", SyncScope::ALL);
+    let mut phone = reactor("phone", "desktop", SyncScope::ALL);
+    desktop.state.reduce(StateDelta::LongHorizonIntentUpdate(intent("i1", IntentStatus::Active)));
+    exchange(&mut desktop, &mut phone);
+
+    // Both edit the same intent before syncing again
+    desktop.state.reduce(StateDelta::LongHorizonIntentUpdate(intent("i1", IntentStatus::Suspended)));
+    phone.state.reduce(StateDelta::LongHorizonIntentUpdate(intent("i1", IntentStatus::Completed)));
+    exchange(&mut desktop, &mut phone);
+    exchange(&mut phone, &mut desktop);
+
+    assert_eq!(desktop.state.active_intents()["i1"].status, IntentStatus::Completed, "Terminal status wins");
+    assert_eq!(phone.state.active_intents()["i1"].status, IntentStatus::Completed);
+}
+
+#[tokio::test]
+async fn test_forgetting_replicates() {
+    let mut desktop = reactor("desktop", "phone", SyncScope::ALL);
+    let mut phone = reactor("phone", "desktop", SyncScope::ALL);
+    desktop.state.reduce(StateDelta::MemoryPromoted(memory("m1", 0.8, None)));
+    exchange(&mut desktop, &mut phone);
+    assert!(phone.state.long_term_memory().contains_key("m1"));
+
+    // Concurrent: desktop forgets, phone reinforces. Forgetting wins on both.
+    desktop.state.reduce(StateDelta::MemoryForgotten("m1".to_string()));
+    phone.state.reduce(StateDelta::MemoryPromoted(memory("m1", 0.95, None)));
+    exchange(&mut desktop, &mut phone);
+    exchange(&mut phone, &mut desktop);
+    assert!(!phone.state.long_term_memory().contains_key("m1"));
+    assert!(!desktop.state.long_term_memory().contains_key("m1"));
+
+    let forged = SyncRecord { key: nexus::federation::SyncKey::Memory("m9".to_string()), clock: VersionVector::new(), origin: "phone".to_string(), payload: SyncPayload::Intent(intent("i9", IntentStatus::Active)) };
+    desktop.tick_step(vec![Event::Input(InputEvent {
+        source: "Federation".to_string(),
+        content: InputContent::SyncReceived { peer: "phone".to_string(), records: vec![forged] },
+        captured_at: None,
+    })]);
+    assert!(!desktop.state.active_intents().contains_key("i9"), "Key and payload must agree");
+}
+
+#[cfg(feature = "federation")]
+#[test]
+fn test_envelope_round_trip_and_tamper() {
+    use nexus::federation::channel::{DeviceKey, SecureChannel};
+    let desktop_key = DeviceKey::generate();
+    let phone_key = DeviceKey::generate();
+    let desktop = SecureChannel::new("desktop", &desktop_key, "phone", phone_key.public());
+    let phone = SecureChannel::new("phone", &phone_key, "desktop", desktop_key.public());
+
+    let records = vec![SyncRecord {
+        key: nexus::federation::SyncKey::Intent("i1".to_string()),
+        clock: VersionVector::new(),
+        origin: "desktop".to_string(),
+        payload: SyncPayload::Intent(intent("i1", IntentStatus::Active)),
+    }];
+    let envelope = desktop.seal(&records).unwrap();
+    assert!(!String::from_utf8_lossy(&envelope.ciphertext).contains("i1"));
+    assert_eq!(phone.open(&envelope).unwrap().len(), 1);
+
+    let mut tampered = envelope.clone();
+    tampered.ciphertext[0] ^= 1;
+    assert!(phone.open(&tampered).is_err());
+    assert!(desktop.open(&envelope).is_err(), "Own envelopes are not for us");
+    let stranger = SecureChannel::new("phone", &DeviceKey::generate(), "desktop", desktop_key.public());
+    assert!(stranger.open(&envelope).is_err());
+}
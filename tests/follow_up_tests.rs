@@ -0,0 +1,123 @@
+use nexus::kernel::event::{AudioSignal, AudioStatus, Event, InputContent, InputEvent, OutputId};
+use nexus::kernel::follow_up::{FollowUpOutcome, FollowUpTracker, FOLLOW_UP_HOLD_TICKS};
+use nexus::kernel::latent::{LatentSlot, Modality};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::{Scheduler, SideEffect};
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::time::Tick;
+use nexus::kernel::trace::TraceEntry;
+use nexus::outputs::phrases::PhraseTemplates;
+use nexus::outputs::realizer::summary_sentence;
+use nexus::planner::types::{Intent, PlanningEpoch};
+
+mod common;
+
+// Stable vision latent: the gate allows a hard commit
+fn reactor() -> Reactor {
+    let mut reactor = common::builder().config(ReactorConfig { decision_trace: true, ..common::config() }).build();
+    let slot = LatentSlot { values: vec![1.0], confidence: 0.95, created_at: Tick { frame: 0 }, modality: Modality::Visual, decay_rate: 0.0 };
+    reactor.state.reduce(StateDelta::LatentUpdate { slot });
+    reactor
+}
+
+fn driver(content: InputContent) -> Event {
+    Event::Input(InputEvent { source: "Driver".to_string(), content, captured_at: None })
+}
+
+fn spoken(effects: &[SideEffect]) -> Vec<(OutputId, String)> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::SpawnAudio(id, text) => Some((*id, text.clone())),
+        _ => None,
+    }).collect()
+}
+
+fn outcomes(reactor: &Reactor) -> Vec<FollowUpOutcome> {
+    reactor.trace.recent(50).into_iter().flat_map(|t| t.entries).filter_map(|e| match e {
+        TraceEntry::FollowUp { outcome, .. } => Some(outcome),
+        _ => None,
+    }).collect()
+}
+
+// Proposes the two-part reply; returns the answer
+fn answer(reactor: &mut Reactor, summary_first: bool) -> (OutputId, String) {
+    let epoch = PlanningEpoch { tick: reactor.tick, state_version: reactor.state.version() };
+    let effects = reactor.tick_step(vec![Event::PlanProposed(epoch, Intent::BeginResponseWithFollowUp { summary_first })]);
+    let spoken = spoken(&effects);
+    assert_eq!(spoken.len(), 1, "Only the answer is spoken at first");
+    spoken[0].clone()
+}
+
+#[tokio::test]
+async fn test_offer_follows_heard_summary() {
+    let mut reactor = reactor();
+    let (id, text) = answer(&mut reactor, true);
+    assert_eq!(text, summary_sentence(&text), "One sentence");
+    assert!(reactor.follow_ups.is_pending(&id));
+
+    reactor.tick_step(vec![driver(InputContent::AudioStatus(AudioStatus::PlaybackStarted { output_id: id.into() }))]);
+    let effects = reactor.tick_step(vec![
+        driver(InputContent::AudioStatus(AudioStatus::PlaybackCompleted { output_id: id })),
+        driver(InputContent::AudioStatus(AudioStatus::PlaybackEnded { output_id: id.into(), elapsed_ms: 2000 })),
+    ]);
+    let offer = spoken(&effects);
+    assert_eq!(offer.len(), 1);
+    assert!(PhraseTemplates::builtin().follow_up(true).contains(&offer[0].1), "{}", offer[0].1);
+    let output = &reactor.state.active_outputs()[&offer[0].0];
+    assert_eq!(output.parent_id.as_deref(), Some(format!("output:{}:{}", id.tick, id.ordinal).as_str()), "Linked to the answer");
+    assert_eq!(outcomes(&reactor), vec![FollowUpOutcome::Offered]);
+    assert!(reactor.follow_ups.is_empty());
+}
+
+#[tokio::test]
+async fn test_interrupted_answer_drops_offer() {
+    let mut reactor = reactor();
+    let (id, _) = answer(&mut reactor, false);
+    reactor.tick_step(vec![driver(InputContent::AudioStatus(AudioStatus::PlaybackStarted { output_id: id.into() }))]);
+
+    // Barge-in cancels the answer, then the driver reports the stopped playback
+    reactor.tick_step(vec![driver(InputContent::Audio(AudioSignal::SpeechStart))]);
+    let effects = reactor.tick_step(vec![driver(InputContent::AudioStatus(AudioStatus::PlaybackEnded { output_id: id.into(), elapsed_ms: 400 }))]);
+    assert!(spoken(&effects).is_empty());
+    assert_eq!(outcomes(&reactor), vec![FollowUpOutcome::Canceled]);
+    assert!(reactor.follow_ups.is_empty());
+}
+
+#[tokio::test]
+async fn test_answer_stopped_early_drops_offer() {
+    let mut reactor = reactor();
+    let (id, _) = answer(&mut reactor, true);
+    let effects = reactor.tick_step(vec![driver(InputContent::AudioStatus(AudioStatus::PlaybackEnded { output_id: id.into(), elapsed_ms: 0 }))]);
+    assert!(spoken(&effects).is_empty());
+    assert_eq!(outcomes(&reactor), vec![FollowUpOutcome::Ended]);
+}
+
+#[test]
+fn test_unheard_answer_expires() {
+    let mut tracker = FollowUpTracker::new();
+    let answer = OutputId { tick: 10, ordinal: 0 };
+    tracker.link(answer, true, Tick { frame: 10 });
+    assert!(tracker.expired(Tick { frame: 10 + FOLLOW_UP_HOLD_TICKS - 1 }).is_empty());
+    let expired = tracker.expired(Tick { frame: 10 + FOLLOW_UP_HOLD_TICKS });
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].answer, answer);
+
+    // Completed too late: nothing to offer
+    tracker.completed(&answer);
+    assert!(tracker.take_ready().is_empty());
+    assert!(tracker.is_empty());
+}
+
+#[test]
+fn test_scheduler_stub_and_offer() {
+    let scheduler = Scheduler;
+    let (delta, _) = scheduler.schedule(Intent::BeginResponseWithFollowUp { summary_first: true }, Tick { frame: 3 }, 0);
+    let Some(StateDelta::OutputProposed(output)) = delta else { panic!("Answer proposed") };
+    assert_eq!(output.content, "This is a long verification message to test the interruptibility of the Nexus system.");
+
+    let answer = output.id;
+    let (delta, effect) = scheduler.follow_up(answer, "Want the details?".to_string(), Tick { frame: 9 }, 2);
+    let StateDelta::OutputProposed(offer) = delta else { panic!("Offer proposed") };
+    assert_eq!(offer.id, OutputId { tick: 9, ordinal: 2 });
+    assert_eq!(offer.parent_id.as_deref(), Some("output:3:0"));
+    assert!(matches!(effect, SideEffect::SpawnAudio(id, _) if id == offer.id));
+}
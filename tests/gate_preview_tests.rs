@@ -0,0 +1,71 @@
+use nexus::kernel::crystallizer::{explain_gate, preview_gate, CrystallizationDecision, GateRule};
+use nexus::kernel::event::{AudioSignal, InputContent, InputEvent};
+use nexus::kernel::intent::long_horizon::IntentContext;
+use nexus::kernel::latent::{LatentSlot, Modality};
+use nexus::kernel::state::{SharedState, StateDelta};
+use nexus::kernel::subsystem::Subsystem;
+use nexus::kernel::time::Tick;
+
+fn input(state: &mut SharedState, content: InputContent) {
+    state.reduce(StateDelta::InputReceived(InputEvent { source: "test".to_string(), content, captured_at: None }));
+}
+
+fn context() -> IntentContext {
+    IntentContext { active_focus: None, strength: 0.0 }
+}
+
+// State with a recent user question and latents at `confidence`
+fn asked(confidence: f32) -> SharedState {
+    let mut state = SharedState::new();
+    state.reduce(StateDelta::Tick(Tick { frame: 10 }));
+    input(&mut state, InputContent::Text("What is gravity?".to_string()));
+    state.reduce(StateDelta::LatentUpdate { slot: LatentSlot {
+        values: vec![0.0],
+        confidence,
+        created_at: Tick { frame: 10 },
+        modality: Modality::Text,
+        decay_rate: 0.0,
+    } });
+    state
+}
+
+#[test]
+fn test_preview_follows_uncertainty() {
+    let clear = asked(0.9);
+    assert_eq!(preview_gate(&clear).decision, CrystallizationDecision::AllowHard);
+
+    // Uncertainty 0.7: a response now would be delayed
+    let unsure = asked(0.3);
+    let preview = preview_gate(&unsure);
+    assert_eq!(preview.decision, CrystallizationDecision::Delay { ms: 500 });
+    assert_eq!(preview.rule, GateRule::DelayThreshold);
+
+    let mut speaking = asked(0.9);
+    input(&mut speaking, InputContent::Audio(AudioSignal::SpeechStart));
+    assert_eq!(preview_gate(&speaking).rule, GateRule::UserSpeaking);
+}
+
+#[test]
+fn test_preview_picks_direct_or_proactive_gate() {
+    let mut state = asked(0.9);
+    state.reduce(StateDelta::SubsystemToggled { subsystem: Subsystem::ProactiveSpeech, enabled: false });
+    // A reply to the user is not proactive
+    assert_eq!(preview_gate(&state).decision, CrystallizationDecision::AllowHard);
+
+    // Long after the question, the same response would be proactive
+    state.reduce(StateDelta::Tick(Tick { frame: 10_000 }));
+    assert_eq!(preview_gate(&state).rule, GateRule::SubsystemDisabled);
+    assert_eq!(explain_gate(&state).decision, CrystallizationDecision::AllowHard, "Not what the plain gate says");
+}
+
+#[test]
+fn test_snapshot_carries_preview() {
+    let state = asked(0.3);
+    let snapshot = state.snapshot(state.last_tick(), context());
+    assert_eq!(snapshot.gate_preview, preview_gate(&state));
+
+    // The planner sees it in the prompt's STATE json
+    let json: serde_json::Value = serde_json::to_value(&snapshot).unwrap();
+    assert_eq!(json["gate_preview"]["decision"]["Delay"]["ms"], 500);
+    assert_eq!(json["gate_preview"]["rule"], "DelayThreshold");
+}
@@ -0,0 +1,112 @@
+use nexus::federation::Replica;
+use nexus::integrations::calendar::Calendar;
+use nexus::integrations::home::HomeMapping;
+use nexus::kernel::event::{AudioSignal, Event, InputContent, InputEvent};
+use nexus::kernel::intent::arbitrator::IntentArbitrator;
+use nexus::kernel::profile::ProfileStore;
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::replay::{replay, ReplayCheckpoint, SessionLog};
+use nexus::kernel::time::Tick;
+use nexus::memory::store::FileSemanticStore;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+
+mod common;
+
+// Golden files are rewritten instead of compared when this is set (after an intended behavior change).
+const BLESS_VAR: &str = "NEXUS_BLESS";
+
+fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures")
+}
+
+fn temp(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("nexus_replay_{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn mapping() -> HomeMapping {
+    serde_json::from_value(json!({
+        "mqtt": { "host": "localhost" },
+        "rules": [
+            { "name": "kitchen light on", "phrases": ["turn on the kitchen light", "kitchen light on"],
+              "target": { "Mqtt": { "topic": "home/kitchen/light/set", "payload": "ON" } } }
+        ]
+    })).unwrap()
+}
+
+/// Nothing read from the working directory or the environment: stores, ledger, home rules and calendar are fixed.
+fn reactor(name: &str) -> Reactor {
+    common::builder()
+        .semantic_store(Box::new(FileSemanticStore::new(temp(&format!("{}_semantic.json", name)))))
+        .arbitrator(Box::new(IntentArbitrator::new()))
+        .home_mapping(mapping())
+        .calendar(Calendar::new(vec![]))
+        .federation(Replica::new("replay"))
+        .profiles(ProfileStore::new())
+        .build()
+}
+
+fn replay_session(name: &str) -> Vec<ReplayCheckpoint> {
+    let log = SessionLog::load(&fixtures().join("sessions").join(format!("{}.json", name))).unwrap();
+    replay(&mut reactor(name), &log)
+}
+
+fn assert_golden(name: &str) {
+    let actual = serde_json::to_string_pretty(&replay_session(name)).unwrap();
+    let golden = fixtures().join("golden").join(format!("{}.json", name));
+    if std::env::var(BLESS_VAR).is_ok() {
+        std::fs::write(&golden, format!("{}\n", actual)).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&golden)
+        .unwrap_or_else(|_| panic!("Missing {}; run with {}=1 to create it", golden.display(), BLESS_VAR));
+    let expected: Vec<ReplayCheckpoint> = serde_json::from_str(&expected).unwrap();
+    let actual: Vec<ReplayCheckpoint> = serde_json::from_str(&actual).unwrap();
+    for (expected, actual) in expected.iter().zip(&actual) {
+        assert_eq!(actual, expected, "{}: first divergence at tick {}", name, expected.tick.min(actual.tick));
+    }
+    assert_eq!(actual.len(), expected.len(), "{}: checkpoint count", name);
+}
+
+#[tokio::test]
+async fn test_golden_small_talk() {
+    assert_golden("small_talk");
+}
+
+#[tokio::test]
+async fn test_golden_barge_in() {
+    assert_golden("barge_in");
+}
+
+#[tokio::test]
+async fn test_golden_home_command() {
+    assert_golden("home_command");
+}
+
+#[tokio::test]
+async fn test_golden_sensitive_memory() {
+    assert_golden("sensitive_memory");
+}
+
+#[tokio::test]
+async fn test_replay_is_deterministic() {
+    assert_eq!(replay_session("barge_in"), replay_session("barge_in"));
+}
+
+#[test]
+fn test_session_log_round_trip() {
+    let mut log = SessionLog::new(5);
+    let speech = Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::Audio(AudioSignal::SpeechStart), captured_at: None });
+    log.record(Tick { frame: 3 }, &[]);
+    log.record(Tick { frame: 4 }, &[speech]);
+    assert_eq!(log.steps.len(), 1, "Idle ticks are implied");
+
+    let path = temp("round_trip.json");
+    log.save(&path).unwrap();
+    let loaded = SessionLog::load(&path).unwrap();
+    assert_eq!(loaded.seed, 5);
+    assert_eq!(loaded.steps[0].tick, 4);
+    assert!(matches!(&loaded.steps[0].events[..], [Event::Input(InputEvent { content: InputContent::Audio(AudioSignal::SpeechStart), .. })]));
+}
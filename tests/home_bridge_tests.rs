@@ -0,0 +1,119 @@
+use nexus::integrations::home::{tool_result, HomeMapping, HomeTarget};
+use nexus::kernel::effects::EffectBatch;
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::speech::planner::SpeechIntent;
+use nexus::planner::tools::{tool_specs, ToolCall, RECALL_EPISODES};
+use serde_json::json;
+
+mod common;
+
+fn mapping() -> HomeMapping {
+    serde_json::from_value(json!({
+        "mqtt": { "host": "localhost" },
+        "rules": [
+            { "name": "kitchen light on", "phrases": ["turn on the kitchen light", "kitchen light on"],
+              "target": { "Mqtt": { "topic": "home/kitchen/light/set", "payload": "ON" } } },
+            { "name": "light on", "phrases": ["turn on the light"],
+              "target": { "Mqtt": { "topic": "home/light/set", "payload": "ON" } } },
+            { "name": "alarm off", "phrases": ["turn off the alarm"], "confirm": true,
+              "target": { "HomeAssistant": { "domain": "alarm_control_panel", "service": "alarm_disarm", "entity_id": "alarm_control_panel.home" } } }
+        ]
+    })).unwrap()
+}
+
+fn reactor() -> Reactor {
+    common::builder()
+        .home_mapping(mapping())
+        .build()
+}
+
+fn say(reactor: &mut Reactor, text: &str) -> EffectBatch {
+    reactor.tick_step(vec![Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::Text(text.to_string()),
+        captured_at: None,
+    })])
+}
+
+fn invoked(batch: &EffectBatch) -> Vec<(String, String)> {
+    batch.iter().filter_map(|e| match e {
+        SideEffect::InvokeTool { call_id, call: ToolCall::HomeAction(action) } => Some((call_id.clone(), action.rule.clone())),
+        _ => None,
+    }).collect()
+}
+
+fn speech(batch: &EffectBatch) -> Vec<SpeechIntent> {
+    batch.iter().filter_map(|e| match e {
+        SideEffect::RequestSpeech { intent, .. } => Some(intent.clone()),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn test_mapping_resolves_longest_whole_phrase() {
+    let mapping = mapping();
+    assert_eq!(mapping.resolve("Please turn on the kitchen light!").unwrap().name, "kitchen light on");
+    assert_eq!(mapping.resolve("turn on the light").unwrap().name, "light on");
+    assert!(mapping.resolve("turn on the kitchen lights").is_none(), "Whole words only");
+    assert!(matches!(&mapping.rules[2].target, HomeTarget::HomeAssistant { service, .. } if service == "alarm_disarm"));
+    assert_eq!(mapping.mqtt.as_ref().unwrap().port, 1883);
+}
+
+#[tokio::test]
+async fn test_command_dispatches_and_result_is_confirmed() {
+    let mut reactor = reactor();
+    let effects = say(&mut reactor, "Turn on the kitchen light");
+    let calls = invoked(&effects);
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].1, "kitchen light on");
+    assert_eq!(reactor.home.in_flight(), 1);
+
+    let effects = reactor.tick_step(vec![tool_result(calls[0].0.clone(), true)]);
+    assert!(speech(&effects).iter().any(|s| matches!(s, SpeechIntent::Confirmation(seed) if seed.starts_with("home_action_done"))));
+    assert_eq!(reactor.home.in_flight(), 0);
+
+    // Unknown call ids are ignored
+    let effects = reactor.tick_step(vec![tool_result("stranger".to_string(), false)]);
+    assert!(speech(&effects).is_empty());
+}
+
+#[tokio::test]
+async fn test_confirm_policy_waits_for_yes() {
+    let mut reactor = reactor();
+    let effects = say(&mut reactor, "Turn off the alarm");
+    assert!(invoked(&effects).is_empty(), "Nothing runs before the user agrees");
+    assert_eq!(speech(&effects), vec![SpeechIntent::Clarification("confirm_home_action: alarm off".to_string())]);
+    assert!(reactor.home.awaiting_confirmation());
+
+    let effects = say(&mut reactor, "yes");
+    assert_eq!(invoked(&effects).iter().map(|(_, r)| r.as_str()).collect::<Vec<_>>(), vec!["alarm off"]);
+    assert!(!reactor.home.awaiting_confirmation());
+}
+
+#[tokio::test]
+async fn test_confirm_policy_no_cancels() {
+    let mut reactor = reactor();
+    say(&mut reactor, "Turn off the alarm");
+    let effects = say(&mut reactor, "no");
+    assert!(invoked(&effects).is_empty());
+    assert!(!reactor.home.awaiting_confirmation());
+    assert!(invoked(&say(&mut reactor, "yes")).is_empty(), "A late yes does not revive it");
+}
+
+#[test]
+fn test_action_tools_are_not_offered_to_the_planner() {
+    let names: Vec<String> = tool_specs().as_array().unwrap().iter().map(|t| t["name"].as_str().unwrap().to_string()).collect();
+    assert_eq!(names, vec![RECALL_EPISODES.to_string()]);
+}
+
+#[tokio::test]
+async fn test_failed_action_raises_diagnostic() {
+    use nexus::kernel::diagnostic::DiagnosticCode;
+    let mut reactor = reactor();
+    let calls = invoked(&say(&mut reactor, "Turn on the kitchen light"));
+    let effects = reactor.tick_step(vec![tool_result(calls[0].0.clone(), false)]);
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::Diagnostic { code: DiagnosticCode::ToolFailed, severity, .. } if severity.toast())));
+    assert!(speech(&effects).iter().any(|s| matches!(s, SpeechIntent::Confirmation(seed) if seed.starts_with("home_action_failed"))));
+}
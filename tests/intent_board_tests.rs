@@ -0,0 +1,107 @@
+use nexus::kernel::intent::long_horizon::{IntentAges, IntentBoard, IntentStatus, LongHorizonIntentManager};
+use nexus::kernel::intent::types::{IntentCandidate, IntentHypothesis, IntentStability};
+use nexus::kernel::state::SharedState;
+use nexus::kernel::telemetry::event::TelemetryEvent;
+use nexus::kernel::telemetry::export::{ExportEvent, ExportSalt};
+use nexus::kernel::telemetry::recorder::TelemetryRecorder;
+use nexus::kernel::time::Tick;
+
+mod common;
+
+fn candidate(id: &str, hash: u64) -> IntentCandidate {
+    IntentCandidate {
+        id: id.to_string(),
+        hypothesis: IntentHypothesis::Inquiry,
+        confidence: 0.9,
+        source_symbol_ids: vec![format!("sym-{}", id)],
+        semantic_hash: hash,
+        stability: IntentStability::Stable,
+    }
+}
+
+fn boards(recorder: &TelemetryRecorder) -> Vec<IntentBoard> {
+    recorder.events().filter_map(|e| match e {
+        TelemetryEvent::IntentBoard { board } => Some(*board),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn test_board_counts_by_status_and_age() {
+    let state = SharedState::new();
+    let mut telemetry = TelemetryRecorder::new();
+    let mut lhim = LongHorizonIntentManager::new();
+    for (i, created) in [0u64, 20_000, 39_000, 39_500].iter().enumerate() {
+        lhim.register_intent(&candidate(&format!("i{}", i), i as u64 + 1), &state, Tick { frame: *created }, &mut telemetry);
+    }
+    lhim.active_intents.get_mut("i0").unwrap().status = IntentStatus::Dormant;
+    lhim.active_intents.get_mut("i1").unwrap().status = IntentStatus::Dormant;
+    lhim.suspend_intent(&"i2".to_string(), &state, Tick { frame: 39_800 }, &mut telemetry);
+
+    let board = lhim.board(Tick { frame: 40_000 });
+    assert_eq!(board.active, IntentAges([1, 0, 0, 0]), "Registered 500 ticks ago");
+    assert_eq!(board.suspended, IntentAges([1, 0, 0, 0]));
+    assert_eq!(board.dormant, IntentAges([0, 1, 1, 0]), "20_000 and 40_000 ticks old");
+    assert_eq!(board.dormant.count(), 2);
+
+    // Retired intents are not on the board
+    lhim.active_intents.get_mut("i3").unwrap().status = IntentStatus::Invalidated;
+    assert_eq!(lhim.board(Tick { frame: 40_000 }).active.count(), 0);
+    assert_eq!(lhim.board(Tick { frame: 400_000 }).dormant, IntentAges([0, 0, 0, 2]), "Older than an hour");
+}
+
+#[test]
+fn test_board_reported_once_a_minute() {
+    let state = SharedState::new();
+    let mut telemetry = TelemetryRecorder::new();
+    let mut lhim = LongHorizonIntentManager::new();
+    lhim.register_intent(&candidate("a", 1), &state, Tick { frame: 0 }, &mut telemetry);
+
+    lhim.tick(Tick { frame: 1 }, &state, &mut telemetry);
+    lhim.tick(Tick { frame: 2_999 }, &state, &mut telemetry);
+    assert_eq!(boards(&telemetry).len(), 1);
+    lhim.tick(Tick { frame: 3_001 }, &state, &mut telemetry);
+    let reported = boards(&telemetry);
+    assert_eq!(reported.len(), 2);
+    assert_eq!(reported[1].active.count() + reported[1].dormant.count(), 1, "Computed from the board");
+}
+
+#[tokio::test]
+async fn test_reactor_reports_the_board() {
+    let mut reactor = common::reactor();
+    reactor.tick_step(vec![]);
+    reactor.tick_step(vec![]);
+    assert_eq!(boards(&reactor.telemetry), vec![IntentBoard::default()]);
+}
+
+#[test]
+fn test_stats_track_peak_dormant() {
+    let mut telemetry = TelemetryRecorder::new();
+    let board = |dormant: [usize; 4]| TelemetryEvent::IntentBoard {
+        board: IntentBoard { dormant: IntentAges(dormant), ..Default::default() },
+    };
+    telemetry.record(board([2, 1, 0, 0]));
+    telemetry.record(board([0, 3, 3, 1]));
+    telemetry.record(board([0, 0, 1, 0]));
+
+    let stats = telemetry.snapshot().intent_stats;
+    assert_eq!(stats.peak_dormant, 7);
+    assert_eq!(stats.latest_board.unwrap().dormant, IntentAges([0, 0, 1, 0]));
+}
+
+#[test]
+fn test_export_buckets_the_histograms() {
+    let mut telemetry = TelemetryRecorder::new();
+    telemetry.record(TelemetryEvent::IntentBoard {
+        board: IntentBoard { active: IntentAges([3, 0, 0, 0]), suspended: IntentAges([0, 5, 0, 0]), dormant: IntentAges([0, 0, 37, 1]) },
+    });
+    let export = telemetry.export_content_free(&ExportSalt::random());
+    match &export.events[0] {
+        ExportEvent::IntentBoard { board } => {
+            assert_eq!(board.active, IntentAges([2, 0, 0, 0]));
+            assert_eq!(board.suspended, IntentAges([0, 4, 0, 0]));
+            assert_eq!(board.dormant, IntentAges([0, 0, 32, 1]));
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
@@ -0,0 +1,96 @@
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::intent::hysteresis::{IntentHysteresis, Settled, CONSISTENT_ASSESSMENTS};
+use nexus::kernel::intent::types::{IntentCandidate, IntentHypothesis, IntentStability, IntentState};
+use nexus::kernel::scheduler::SideEffect;
+
+mod common;
+
+fn candidate(id: &str, hypothesis: IntentHypothesis, confidence: f32, hash: u64, seg: &str) -> IntentCandidate {
+    IntentCandidate {
+        id: id.to_string(),
+        hypothesis,
+        confidence,
+        source_symbol_ids: vec![seg.to_string()],
+        semantic_hash: hash,
+        stability: IntentStability::Stable,
+    }
+}
+
+fn forming(seg: &str) -> IntentState {
+    let mut c = candidate("f", IntentHypothesis::ThinkingAloud, 0.7, 99, seg);
+    c.stability = IntentStability::Ambiguous;
+    IntentState::Forming(vec![c])
+}
+
+fn fragment(text: &str, seg: &str) -> Event {
+    Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::ProvisionalText { content: text.to_string(), confidence: 0.9, source_id: seg.to_string(), words: Vec::new(), language: None, is_final: true },
+        captured_at: None,
+    })
+}
+
+#[test]
+fn test_weak_stable_needs_consistent_assessments() {
+    let mut hysteresis = IntentHysteresis::new();
+    let weak = |id: &str| IntentState::Stable(candidate(id, IntentHypothesis::Statement, 0.6, 1, "seg1"));
+
+    let first = hysteresis.settle(&IntentState::None, weak("a"), "seg1");
+    assert!(matches!(&first, Settled::Changed { state: IntentState::Forming(_), became_stable: false }));
+
+    // An interleaved verdict from another segment breaks the run
+    let Settled::Changed { state, .. } = first else { unreachable!() };
+    let other = hysteresis.settle(&state, IntentState::Stable(candidate("b", IntentHypothesis::Statement, 0.6, 1, "seg2")), "seg2");
+    assert!(matches!(other, Settled::Changed { became_stable: false, .. }));
+
+    let mut current = IntentState::None;
+    for n in 1..=CONSISTENT_ASSESSMENTS {
+        match hysteresis.settle(&current, weak("c"), "seg1") {
+            Settled::Changed { state, became_stable } => {
+                assert_eq!(became_stable, n == CONSISTENT_ASSESSMENTS);
+                current = state;
+            }
+            Settled::Held => panic!("nothing to hold yet"),
+        }
+    }
+    assert!(matches!(current, IntentState::Stable(_)));
+}
+
+#[test]
+fn test_stable_is_revoked_only_on_consistent_evidence() {
+    let mut hysteresis = IntentHysteresis::new();
+    let stable = IntentState::Stable(candidate("a", IntentHypothesis::Command, 0.9, 1, "seg1"));
+
+    // Flapping fragment: held, then one Stable repeat resets the run
+    assert_eq!(hysteresis.settle(&stable, forming("seg1"), "seg1"), Settled::Held);
+    assert_eq!(hysteresis.settle(&stable, stable.clone(), "seg1"), Settled::Held);
+    assert_eq!(hysteresis.settle(&stable, forming("seg1"), "seg1"), Settled::Held);
+
+    // Consistent evidence revokes
+    assert!(matches!(hysteresis.settle(&stable, forming("seg1"), "seg1"), Settled::Changed { state: IntentState::Forming(_), .. }));
+
+    // A new utterance is not held by the previous one
+    assert!(matches!(hysteresis.settle(&stable, forming("seg2"), "seg2"), Settled::Changed { .. }));
+    let next = IntentState::Stable(candidate("b", IntentHypothesis::Command, 0.9, 1, "seg2"));
+    assert_eq!(hysteresis.settle(&stable, next.clone(), "seg2"), Settled::Changed { state: next, became_stable: true });
+}
+
+#[tokio::test]
+async fn test_flapping_fragments_register_once() {
+    let mut reactor = common::reactor();
+    let acks = |effects: &[SideEffect]| effects.iter().filter(|e| matches!(e, SideEffect::RequestSpeech { .. })).count();
+
+    let mut spoken = acks(&reactor.tick_step(vec![fragment("Turn off the lights", "seg1")]));
+    let id = match reactor.state.intent_state() {
+        IntentState::Stable(c) => c.id.clone(),
+        other => panic!("unexpected {:?}", other),
+    };
+    for text in ["um", "Turn off the lights", "uh", "Turn off the lights"] {
+        spoken += acks(&reactor.tick_step(vec![fragment(text, "seg1")]));
+        assert!(matches!(reactor.state.intent_state(), IntentState::Stable(c) if c.id == id), "held on '{}'", text);
+    }
+
+    assert_eq!(reactor.state.active_intents().len(), 1, "One utterance, one long-horizon intent");
+    assert_eq!(reactor.state.memory_candidates().values().map(|c| c.reinforcement_count).sum::<u32>(), 1);
+    assert_eq!(spoken, 1, "Acknowledged once");
+}
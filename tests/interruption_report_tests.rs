@@ -0,0 +1,148 @@
+use nexus::kernel::cancel::{describe_source, interruption_source, MAX_INTERRUPTION_RECORDS};
+use nexus::kernel::event::{AudioSignal, Event, InputContent, InputEvent, Output, OutputId, OutputStatus, VisualSignal};
+use nexus::kernel::intent::long_horizon::{IntentStatus, LongHorizonIntent};
+use nexus::kernel::intent::types::IntentHypothesis;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::telemetry::event::{InterruptionSource, TelemetryEvent};
+use nexus::kernel::time::Tick;
+use nexus::memory::store::FileSemanticStore;
+
+fn reactor() -> Reactor {
+    let (tx, rx) = tokio::sync::mpsc::channel(100);
+    let path = std::env::temp_dir().join(format!("nexus_interruption_{}_semantic.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    Reactor::builder(rx, tx)
+        .config(ReactorConfig { llm_planning: false, ..Default::default() })
+        .semantic_store(Box::new(FileSemanticStore::new(path)))
+        .build()
+}
+
+fn input(content: InputContent) -> InputEvent {
+    InputEvent { source: "Test".to_string(), content, captured_at: None }
+}
+
+fn screen(distance: u32) -> InputContent {
+    InputContent::Visual(VisualSignal::PerceptUpdate { hash: 0xBEEF, distance })
+}
+
+/// An output in flight, `percent` of it already played.
+fn speaking(reactor: &mut Reactor, ordinal: u16, content: &str, percent: u8) -> OutputId {
+    let id = OutputId { tick: reactor.tick.frame, ordinal };
+    reactor.state.reduce(StateDelta::OutputProposed(Output {
+        id,
+        parent_id: Some("root_task".to_string()),
+        content: content.into(),
+        status: OutputStatus::Draft,
+        proposed_at: Tick::new(),
+        committed_at: None,
+    }));
+    reactor.state.reduce(StateDelta::OutputPlaybackProgress { id, percent });
+    id
+}
+
+fn intent(id: &str, decay_score: f32) -> LongHorizonIntent {
+    LongHorizonIntent {
+        id: id.to_string(),
+        hypothesis: IntentHypothesis::Command,
+        source_symbol_ids: vec![],
+        created_at: Tick::new(),
+        last_active_at: Tick::new(),
+        last_updated_at: Tick::new(),
+        suspended_at: None,
+        decay_score,
+        status: IntentStatus::Active,
+        context: Default::default(),
+    }
+}
+
+fn barge_in() -> Event {
+    Event::Input(input(InputContent::Audio(AudioSignal::SpeechStart)))
+}
+
+#[test]
+fn test_sources_are_classified() {
+    assert_eq!(interruption_source(&[input(InputContent::Audio(AudioSignal::SpeechStart))]), InterruptionSource::AudioSpeechStart);
+    assert_eq!(interruption_source(&[input(screen(10))]), InterruptionSource::VisualContextShift);
+    assert_eq!(interruption_source(&[input(InputContent::Text("stop".to_string()))]), InterruptionSource::ExplicitCancel);
+    // A small screen change is not a context shift
+    assert_eq!(interruption_source(&[input(screen(2)), input(InputContent::Text("STOP".to_string()))]), InterruptionSource::ExplicitCancel);
+    // The user speaking over a screen change: the barge-in is the cause
+    assert_eq!(
+        interruption_source(&[input(screen(10)), input(InputContent::Audio(AudioSignal::SpeechStart))]),
+        InterruptionSource::AudioSpeechStart
+    );
+}
+
+#[test]
+fn test_causes_in_the_users_words() {
+    assert_eq!(describe_source(InterruptionSource::AudioSpeechStart), "you started speaking");
+    assert_eq!(describe_source(InterruptionSource::VisualContextShift), "your screen changed a lot");
+    assert_eq!(describe_source(InterruptionSource::ExplicitCancel), "you asked me to stop");
+}
+
+#[tokio::test]
+async fn test_recent_interruptions_newest_first() {
+    let mut reactor = reactor();
+    assert!(reactor.recent_interruptions(5).is_empty());
+
+    let first = speaking(&mut reactor, 0, "The forecast for tomorrow is sunny", 50);
+    reactor.tick_step(vec![Event::Input(input(screen(10)))]);
+    for _ in 0..10 {
+        reactor.tick_step(vec![]);
+    }
+    let second = speaking(&mut reactor, 1, "Your next meeting is at noon", 0);
+    reactor.tick_step(vec![Event::Input(input(InputContent::Audio(AudioSignal::SpeechStart)))]);
+
+    let reports = reactor.recent_interruptions(5);
+    assert_eq!(reports.len(), 2);
+    assert_eq!((reports[0].output_id, reports[0].source), (second, InterruptionSource::AudioSpeechStart));
+    assert_eq!(reports[0].cause, "you started speaking");
+    assert_eq!(reports[0].age_ticks, 0);
+    assert_eq!((reports[1].output_id, reports[1].cause.as_str()), (first, "your screen changed a lot"));
+    assert_eq!((reports[1].spoken_chars, reports[1].total_chars), (17, 34));
+    assert!(reports[1].age_ticks > 10);
+
+    assert_eq!(reactor.recent_interruptions(1).len(), 1);
+
+    // Telemetry carries the same classification
+    let sources: Vec<_> = reactor.telemetry.events().filter_map(|e| match e {
+        TelemetryEvent::Interruption { source, .. } => Some(*source),
+        _ => None,
+    }).collect();
+    assert_eq!(sources, vec![InterruptionSource::VisualContextShift, InterruptionSource::AudioSpeechStart]);
+}
+
+#[tokio::test]
+async fn test_barge_in_records_what_was_being_said() {
+    let mut reactor = reactor();
+    reactor.state.reduce(StateDelta::LongHorizonIntentUpdate(intent("intent_faded", 0.4)));
+    reactor.state.reduce(StateDelta::LongHorizonIntentUpdate(intent("intent_lights", 0.9)));
+    reactor.tick_step(vec![]);
+
+    // 40 characters, a quarter played
+    let output = speaking(&mut reactor, 0, "Turning on the lights in the living room", 25);
+    reactor.tick_step(vec![barge_in()]);
+
+    let record = reactor.state.interruptions().back().cloned().expect("barge-in recorded");
+    assert_eq!(record.output_id, output);
+    assert_eq!((record.spoken_chars, record.total_chars), (10, 40), "Spoken prefix from playback progress");
+    assert_eq!(record.active_intent.as_deref(), Some("intent_lights"), "The freshest active intent");
+    assert_eq!(record.tick, reactor.tick, "Stamped with the barge-in tick");
+    assert_eq!(record.source, InterruptionSource::AudioSpeechStart);
+}
+
+#[tokio::test]
+async fn test_interruption_records_are_capped() {
+    let mut reactor = reactor();
+    let mut outputs = Vec::new();
+    for _ in 0..MAX_INTERRUPTION_RECORDS + 3 {
+        reactor.tick_step(vec![]);
+        outputs.push(speaking(&mut reactor, 0, "Here is the weather", 0));
+        reactor.tick_step(vec![barge_in()]);
+    }
+
+    let kept: Vec<_> = reactor.state.interruptions().iter().map(|r| r.output_id).collect();
+    assert_eq!(kept.len(), MAX_INTERRUPTION_RECORDS);
+    assert_eq!(kept, outputs[3..], "Oldest records evicted first");
+}
@@ -0,0 +1,175 @@
+use nexus::federation::Replica;
+use nexus::integrations::calendar::Calendar;
+use nexus::kernel::event::{AudioSignal, Event, InputContent, InputEvent};
+use nexus::kernel::intent::arbitrator::IntentArbitrator;
+use nexus::kernel::journal::{Journal, JournalEntry, JournalError, JournalWriter};
+use nexus::kernel::persist::PersistedState;
+use nexus::kernel::profile::ProfileStore;
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::replay::SessionLog;
+use nexus::kernel::time::Tick;
+use nexus::memory::store::FileSemanticStore;
+use std::path::{Path, PathBuf};
+
+mod common;
+
+fn temp(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("nexus_journal_{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+// Hermetic, as in golden_replay_tests.rs
+fn reactor(name: &str) -> Reactor {
+    common::builder()
+        .semantic_store(Box::new(FileSemanticStore::new(temp(&format!("{}_semantic.json", name)))))
+        .arbitrator(Box::new(IntentArbitrator::new()))
+        .calendar(Calendar::new(vec![]))
+        .federation(Replica::new("journal"))
+        .profiles(ProfileStore::new())
+        .build()
+}
+
+fn speech(signal: AudioSignal) -> Event {
+    Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::Audio(signal), captured_at: None })
+}
+
+// What replay has to reproduce (maps as sorted keys: iteration order is not state)
+fn fingerprint(reactor: &Reactor) -> Vec<String> {
+    let state = &reactor.state;
+    let keys = |mut keys: Vec<String>| { keys.sort(); keys.join(",") };
+    vec![
+        format!("tick={}", reactor.tick.frame),
+        format!("version={} {:?}", state.version(), state.domain_versions()),
+        format!("presence={:?}", state.presence()),
+        format!("intent={:?}", state.intent_state()),
+        format!("segments={}", keys(state.audio_segments().keys().cloned().collect())),
+        format!("candidates={}", keys(state.memory_candidates().keys().map(|k| format!("{:?}", k)).collect())),
+        format!("outputs={}", keys(state.active_outputs().keys().map(|k| format!("{:?}", k)).collect())),
+        format!("intents={}", keys(state.active_intents().keys().map(|k| format!("{:?}", k)).collect())),
+    ]
+}
+
+// Drive the barge-in session as a driver would, journaled, with a low-power wait in the long gaps
+fn run_live(path: &Path) -> Reactor {
+    let log = SessionLog::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/sessions/barge_in.json")).unwrap();
+    let mut live = reactor("live");
+    live.ids = nexus::kernel::ids::IdGenerator::new(log.seed);
+    let mut journal = JournalWriter::create(path, live.ids.seed()).unwrap();
+    for step in &log.steps {
+        if step.tick > live.tick.frame + 8 {
+            live.tick_step(vec![]);
+            let skipped = step.tick - live.tick.frame - 2;
+            journal.idle(live.tick, skipped);
+            live.advance_idle(skipped);
+        }
+        while live.tick.frame + 1 < step.tick {
+            journal.step(live.tick.next(), &[]);
+            live.tick_step(vec![]);
+        }
+        journal.step(live.tick.next(), &step.events);
+        live.tick_step(step.events.clone());
+    }
+    live.tick_step(vec![]);
+    journal.end(live.tick);
+    live
+}
+
+#[tokio::test]
+async fn test_replay_reconstructs_state() {
+    let path = temp("live.jsonl");
+    let live = run_live(&path);
+    let journal = Journal::load(&path).unwrap();
+    assert!(journal.entries.iter().any(|e| matches!(e, JournalEntry::Idle { .. })));
+    assert!(!journal.torn);
+    assert_eq!(journal.last_tick(), live.tick);
+
+    let mut replayed = reactor("replayed");
+    assert_eq!(replayed.replay(&journal), Ok(live.tick));
+    assert_eq!(fingerprint(&replayed), fingerprint(&live));
+
+    // Replay needs a fresh kernel
+    assert_eq!(replayed.replay(&journal), Err(JournalError::NotFresh { tick: live.tick.frame }));
+}
+
+#[tokio::test]
+async fn test_hydrated_session_replays() {
+    // The next process resumes the first session's state, journaled
+    let resumed = PersistedState::capture(&run_live(&temp("first.jsonl")).state);
+    let path = temp("resumed.jsonl");
+    let mut live = reactor("resumed_live");
+    let mut journal = JournalWriter::create(&path, live.ids.seed()).unwrap();
+    journal.hydrated(&resumed);
+    live.hydrate(&resumed);
+    for signal in [AudioSignal::SpeechStart, AudioSignal::SpeechEnd] {
+        journal.step(live.tick.next(), &[speech(signal.clone())]);
+        live.tick_step(vec![speech(signal)]);
+    }
+    journal.end(live.tick);
+
+    let journal = Journal::load(&path).unwrap();
+    assert!(matches!(journal.entries[0], JournalEntry::Hydrated(_)));
+    assert_eq!(journal.last_tick(), live.tick);
+    let mut replayed = reactor("resumed_replayed");
+    assert_eq!(replayed.replay(&journal), Ok(live.tick));
+    assert_eq!(fingerprint(&replayed), fingerprint(&live));
+
+    // Only before the first step
+    let text = std::fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    let late = format!("{}
+{}
+{}
+", lines[0], lines[2], lines[1]);
+    assert_eq!(Journal::parse(&late).unwrap_err(), JournalError::OutOfOrder { line: 3 });
+}
+
+#[test]
+fn test_idle_steps_are_implied() {
+    let path = temp("idle.jsonl");
+    let mut journal = JournalWriter::create(&path, 7).unwrap();
+    journal.step(Tick { frame: 1 }, &[]);
+    journal.idle(Tick { frame: 1 }, 0);
+    journal.step(Tick { frame: 2 }, &[speech(AudioSignal::SpeechStart)]);
+    journal.idle(Tick { frame: 2 }, 20);
+    journal.step(Tick { frame: 23 }, &[speech(AudioSignal::SpeechEnd)]);
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(text.lines().count(), 4, "Start, two steps and one skip:\n{}", text);
+    let journal = Journal::load(&path).unwrap();
+    assert_eq!(journal.seed, 7);
+    assert!(matches!(journal.entries[..], [JournalEntry::Step { tick: 2, .. }, JournalEntry::Idle { after: 2, ticks: 20 }, JournalEntry::Step { tick: 23, .. }]));
+    // No End: the replay stops at the last entry
+    assert_eq!(journal.last_tick(), Tick { frame: 23 });
+}
+
+#[test]
+fn test_torn_last_line_is_dropped() {
+    let path = temp("torn.jsonl");
+    let mut journal = JournalWriter::create(&path, 3).unwrap();
+    journal.step(Tick { frame: 4 }, &[speech(AudioSignal::SpeechStart)]);
+    drop(journal);
+    let mut text = std::fs::read_to_string(&path).unwrap();
+    text.push_str(r#"{"Step":{"tick":5,"eve"#);
+
+    let journal = Journal::parse(&text).unwrap();
+    assert!(journal.torn);
+    assert_eq!(journal.entries.len(), 1);
+
+    // A bad line in the middle is not a crash artifact
+    let lines: Vec<&str> = text.lines().collect();
+    let middle = format!("{}\n{}\n{}\n", lines[0], lines[2], lines[1]);
+    assert_eq!(Journal::parse(&middle).unwrap_err(), JournalError::Malformed { line: 2 });
+}
+
+#[test]
+fn test_rejects_foreign_and_disordered_journals() {
+    assert_eq!(Journal::parse("").unwrap_err(), JournalError::Empty);
+    assert_eq!(Journal::parse("{\"End\":{\"tick\":3}}\n").unwrap_err(), JournalError::Empty);
+    assert_eq!(Journal::parse("{\"Start\":{\"version\":99,\"seed\":1}}\n").unwrap_err(), JournalError::Version(99));
+
+    let start = "{\"Start\":{\"version\":1,\"seed\":1}}";
+    let skipped = format!("{}\n{{\"Idle\":{{\"after\":2,\"ticks\":10}}}}\n{{\"Step\":{{\"tick\":12,\"events\":[]}}}}\n", start);
+    assert_eq!(Journal::parse(&skipped).unwrap_err(), JournalError::OutOfOrder { line: 3 }, "Tick 12 was skipped");
+    assert!(Journal::load(&temp("missing.jsonl")).is_err());
+}
@@ -0,0 +1,104 @@
+use nexus::kernel::driver::EffectContext;
+use nexus::kernel::event::{AudioSignal, InputContent};
+use nexus::kernel::intent::types::IntentState;
+use nexus::kernel::language::{normalize, reply_language, LanguagePreference};
+use nexus::kernel::reactor::Reactor;
+use nexus::outputs::phrases::{PhraseAct, PhraseTemplates};
+
+mod common;
+use common::{input, reactor};
+
+// One finalized segment, transcribed as `text` in `language`
+fn heard(reactor: &mut Reactor, text: &str, language: Option<&str>) -> String {
+    reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechStart))]);
+    let segment_id = reactor.state.active_segment_id().cloned().unwrap();
+    reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechEnd))]);
+    reactor.tick_step(vec![input(InputContent::ProvisionalText {
+        content: text.to_string(),
+        confidence: 0.9,
+        source_id: segment_id.clone(),
+        words: Vec::new(),
+        language: language.map(str::to_string),
+        is_final: true,
+    })]);
+    segment_id
+}
+
+// The built-in table plus a German one (one variant per act)
+fn with_german() -> PhraseTemplates {
+    let acts: serde_json::Map<String, serde_json::Value> = PhraseAct::ALL.iter().map(|act| {
+        let key = serde_json::to_value(act).unwrap().as_str().unwrap().to_string();
+        (key.clone(), serde_json::json!([format!("[de {}]", key)]))
+    }).collect();
+    PhraseTemplates::from_json(&serde_json::json!({ "templates": { "de": acts } }).to_string()).unwrap()
+}
+
+#[test]
+fn test_codes_and_reply_language() {
+    assert_eq!(normalize("de-DE").as_deref(), Some("de"));
+    assert_eq!(normalize(" PT_br ").as_deref(), Some("pt"));
+    assert_eq!(normalize("auto"), None);
+    assert_eq!(normalize(""), None);
+    assert_eq!(LanguagePreference::parse("fr-CA"), LanguagePreference::Pinned("fr".to_string()));
+    assert_eq!(LanguagePreference::parse("Auto"), LanguagePreference::Auto);
+
+    let pinned = LanguagePreference::Pinned("fr".to_string());
+    assert_eq!(reply_language(&pinned, Some("de"), "en"), "fr", "A pin wins over what was heard");
+    assert_eq!(reply_language(&LanguagePreference::Auto, Some("de"), "en"), "de");
+    assert_eq!(reply_language(&LanguagePreference::Auto, None, "en"), "en");
+}
+
+#[tokio::test]
+async fn test_segment_and_intent_tagged() {
+    let mut reactor = reactor();
+    let segment_id = heard(&mut reactor, "What is gravity?", Some("de-DE"));
+    assert_eq!(reactor.state.audio_segments()[&segment_id].language.as_deref(), Some("de"));
+    assert_eq!(reactor.state.spoken_language(), Some("de"));
+    let IntentState::Stable(intent) = reactor.state.intent_state() else {
+        panic!("Expected a stable intent, got {:?}", reactor.state.intent_state());
+    };
+    assert_eq!(reactor.state.intent_language(&intent.id), Some("de"));
+    assert_eq!(reactor.reply_language(), "de");
+
+    // Typed text has no language: the last one heard stays
+    reactor.tick_step(vec![input(InputContent::Text("hello".to_string()))]);
+    assert_eq!(reactor.state.spoken_language(), Some("de"));
+}
+
+#[tokio::test]
+async fn test_memory_keys_do_not_cross_languages() {
+    let mut reactor = reactor();
+    heard(&mut reactor, "What is gravity?", Some("de"));
+    heard(&mut reactor, "What is gravity?", Some("en"));
+    assert_eq!(reactor.state.memory_candidates().len(), 2, "Same words, two languages");
+
+    // Untagged text keys like the base language: memories from before tagging still match
+    heard(&mut reactor, "What is gravity?", None);
+    assert_eq!(reactor.state.memory_candidates().len(), 2);
+    let mut counts: Vec<u32> = reactor.state.memory_candidates().values().map(|c| c.reinforcement_count).collect();
+    counts.sort();
+    assert_eq!(counts, [1, 2]);
+}
+
+#[tokio::test]
+async fn test_pinned_language_drives_replies_and_asr() {
+    let mut reactor = reactor();
+    reactor.templates = with_german();
+    heard(&mut reactor, "What is gravity?", Some("de"));
+    assert_eq!(reactor.templates.locale(), "de", "Phrased in the language heard");
+    assert_eq!(reactor.templates.variants(PhraseAct::Clarification), ["[de clarification]"]);
+
+    // French has no templates: phrasing falls back to the configured locale, the rest follows the pin
+    reactor.tick_step(vec![input(InputContent::Language(LanguagePreference::Pinned("fr".to_string())))]);
+    assert_eq!(reactor.reply_language(), "fr");
+    assert_eq!(reactor.templates.locale(), "en");
+    let context = EffectContext::capture(&reactor, &[]);
+    assert_eq!(context.language, "fr");
+    assert_eq!(context.transcription_language.as_deref(), Some("fr"));
+
+    // Back to detecting
+    reactor.tick_step(vec![input(InputContent::Language(LanguagePreference::Auto))]);
+    assert_eq!(reactor.templates.locale(), "de");
+    assert_eq!(EffectContext::capture(&reactor, &[]).transcription_language, None);
+    assert_eq!(reactor.state.language_preference(), &LanguagePreference::Auto);
+}
@@ -0,0 +1,177 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::profile::{Profile, ProfileStore};
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::speech::planner::SpeechIntent;
+use nexus::outputs::loudness::{apply_gain, decode_wav, integrated_loudness, normalize, parse_volume_request, VolumeRequest, DEFAULT_VOLUME, MAX_VOLUME, MIN_VOLUME, TARGET_LUFS};
+use nexus::outputs::tts::TtsEngineKind;
+use nexus::outputs::tts_cache::{TtsCache, TtsCacheLimits};
+
+mod common;
+
+fn temp(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("nexus_loudness_{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn sine(amplitude: f32, sample_rate: u32, seconds: f32) -> Vec<f32> {
+    (0..(sample_rate as f32 * seconds) as usize)
+        .map(|i| amplitude * (2.0 * std::f32::consts::PI * 997.0 * i as f32 / sample_rate as f32).sin())
+        .collect()
+}
+
+// Mono 16-bit PCM WAV
+fn wav(samples: &[f32], sample_rate: u32) -> Vec<u8> {
+    let data: Vec<u8> = samples.iter().flat_map(|s| ((s * 32767.0) as i16).to_le_bytes()).collect();
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    bytes.extend_from_slice(&2u16.to_le_bytes());
+    bytes.extend_from_slice(&16u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&data);
+    bytes
+}
+
+fn args(command: &tokio::process::Command) -> Vec<String> {
+    command.as_std().get_args().map(|a| a.to_string_lossy().into_owned()).collect()
+}
+
+fn reactor(profiles: ProfileStore) -> Reactor {
+    common::builder()
+        .profiles(profiles)
+        .build()
+}
+
+fn text(text: &str) -> Event {
+    Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::Text(text.to_string()), captured_at: None })
+}
+
+#[test]
+fn test_integrated_loudness_reference() {
+    // BS.1770: a full scale 997 Hz sine on one channel reads -3.01 LUFS
+    let full = integrated_loudness(&sine(1.0, 48_000, 2.0), 1, 48_000).unwrap();
+    assert!((full + 3.01).abs() < 0.1, "{}", full);
+    // Half the amplitude is 6 dB quieter, at any sample rate
+    let half = integrated_loudness(&sine(0.5, 22_050, 2.0), 1, 22_050).unwrap();
+    assert!((half - full + 6.02).abs() < 0.1, "{} vs {}", half, full);
+
+    assert_eq!(integrated_loudness(&vec![0.0; 48_000], 1, 48_000), None, "Silence");
+    assert!(integrated_loudness(&sine(0.5, 16_000, 0.2), 1, 16_000).is_some(), "Shorter than one block");
+}
+
+#[test]
+fn test_normalize_wav_buffers() {
+    let quiet = wav(&sine(0.05, 22_050, 1.0), 22_050);
+    let decoded = decode_wav(&quiet).unwrap();
+    assert_eq!((decoded.sample_rate, decoded.channels, decoded.samples.len()), (22_050, 1, 22_050));
+
+    let (normalized, measured) = normalize(&quiet, TARGET_LUFS).unwrap();
+    assert!(measured < TARGET_LUFS - 6.0, "{}", measured);
+    let after = normalize(&normalized, TARGET_LUFS).unwrap().1;
+    assert!((after - TARGET_LUFS).abs() < 0.2, "{}", after);
+    assert_eq!(normalized.len(), quiet.len(), "Header kept");
+
+    // Clipped at full scale, never wrapped
+    let loud = decode_wav(&apply_gain(&wav(&sine(0.9, 22_050, 0.5), 22_050), 4.0).unwrap()).unwrap();
+    assert!(loud.samples.iter().all(|s| (-1.0..=1.0).contains(s)));
+    assert!(loud.samples.iter().any(|s| *s > 0.99));
+
+    assert_eq!(normalize(&[1; 64], TARGET_LUFS), None, "Not a WAV");
+    assert_eq!(normalize(&wav(&[0.0; 4_000], 22_050), TARGET_LUFS), None, "Silent");
+}
+
+#[test]
+fn test_spoken_volume_requests() {
+    assert_eq!(parse_volume_request("Quieter please"), Some(VolumeRequest::Quieter));
+    assert_eq!(parse_volume_request("turn it down"), Some(VolumeRequest::Quieter));
+    assert_eq!(parse_volume_request("lower the volume"), Some(VolumeRequest::Quieter));
+    assert_eq!(parse_volume_request("speak up"), Some(VolumeRequest::Louder));
+    assert_eq!(parse_volume_request("volume up a bit"), Some(VolumeRequest::Louder));
+    assert_eq!(parse_volume_request("a little louder"), Some(VolumeRequest::Louder));
+    assert_eq!(parse_volume_request("normal volume"), Some(VolumeRequest::Reset));
+    assert_eq!(parse_volume_request("write it down"), None);
+    assert_eq!(parse_volume_request("turn the volume up"), Some(VolumeRequest::Louder));
+    assert_eq!(parse_volume_request("turn down the volume"), Some(VolumeRequest::Quieter));
+    assert_eq!(parse_volume_request("turn down the lights"), None, "Not the voice");
+    assert_eq!(parse_volume_request("turn up the heating"), None);
+    assert_eq!(parse_volume_request("what's the weather"), None);
+    assert_eq!(parse_volume_request("the neighbours got louder every single night this week"), None, "Too long for a request");
+
+    assert_eq!(VolumeRequest::Quieter.apply(DEFAULT_VOLUME), 80);
+    assert_eq!(VolumeRequest::Quieter.apply(MIN_VOLUME), MIN_VOLUME);
+    assert_eq!(VolumeRequest::Louder.apply(MAX_VOLUME - 5), MAX_VOLUME);
+    assert_eq!(VolumeRequest::Reset.apply(40), DEFAULT_VOLUME);
+}
+
+#[test]
+fn test_cache_normalizes_and_applies_volume() {
+    let now = Instant::now();
+    let mut cache = TtsCache::new(TtsCacheLimits { max_entries: 4, max_bytes: 1 << 20, ttl: Duration::from_secs(60) })
+        .with_engine(TtsEngineKind::Espeak.engine());
+    assert_eq!((cache.engine_loudness(), cache.speech_gain()), (None, 1.0), "Unmeasured engine at its own level");
+    // Uncached speech at the engine's own level
+    assert_eq!(args(&cache.playback_command("Something new", false, None)), ["Something new"]);
+
+    // A loud engine: stored buffers are turned down, and so is its live speech
+    cache.insert("Okay.", wav(&sine(0.4, 22_050, 0.5), 22_050), now);
+    assert!(cache.engine_loudness().unwrap() > TARGET_LUFS);
+    assert!(cache.speech_gain() < 1.0);
+    let stored = cache.get("Okay.", now).unwrap();
+    let level = integrated_loudness(&decode_wav(&stored).unwrap().samples, 1, 22_050).unwrap();
+    assert!((level - TARGET_LUFS).abs() < 0.2, "{}", level);
+
+    // The volume scales both on top of normalization
+    let normal = cache.speech_gain();
+    cache.set_volume(50);
+    assert!((cache.speech_gain() - normal * 0.5).abs() < 1e-6);
+    let cached = cache.playback_command("Okay.", false, None);
+    assert!(args(&cached)[1].ends_with("-50.wav"), "{:?}", args(&cached));
+    let amplitude: u32 = args(&cache.playback_command("Something new", false, None))[1].parse().unwrap();
+    assert!(amplitude < 50, "{}", amplitude);
+    // Non-WAV buffers are left alone
+    cache.insert("Hmm.", vec![1; 16], now);
+    assert_eq!(cache.get("Hmm.", now).unwrap().as_slice(), [1; 16]);
+}
+
+#[tokio::test]
+async fn test_voice_request_sets_profile_volume() {
+    let path = temp("profiles.json");
+    let mut reactor = reactor(ProfileStore::open(&path).unwrap());
+    let view = reactor.subscribe_view();
+    assert_eq!(reactor.output_volume(), DEFAULT_VOLUME);
+
+    let effects = reactor.tick_step(vec![text("quieter please")]);
+    assert_eq!(reactor.output_volume(), 80);
+    assert_eq!(view.borrow().volume, 80, "Visible in the state view");
+    let confirmed = effects.iter().any(|e| matches!(e,
+        SideEffect::RequestSpeech { intent: SpeechIntent::Confirmation(d), .. } if d == "output_volume_updated"));
+    assert!(confirmed, "Acknowledged");
+    assert!(reactor.state.active_intents().is_empty(), "A setting, not an utterance");
+    assert!(!path.exists(), "No file I/O inside the tick");
+    let snapshot = effects.iter().find_map(|e| match e {
+        SideEffect::PersistProfiles(snapshot) => Some(snapshot.clone()),
+        _ => None,
+    }).expect("The profiles are handed to the driver");
+    snapshot.save().unwrap();
+    assert_eq!(ProfileStore::open(&path).unwrap().volume(), 80, "Saved with the profile");
+
+    reactor.tick_step(vec![text("speak up")]);
+    reactor.tick_step(vec![text("speak up")]);
+    assert_eq!(reactor.output_volume(), 120);
+
+    // Profiles saved before volumes existed load at the default
+    let old: Profile = serde_json::from_str(r#"{"name":"old"}"#).unwrap();
+    assert_eq!(old.volume, DEFAULT_VOLUME);
+}
@@ -0,0 +1,192 @@
+use nexus::kernel::event::{Event, InputContent};
+use nexus::kernel::maintenance::{JobOutcome, MaintenanceJob, MaintenanceProgress, MaintenanceTrigger, MAINTENANCE_JOB_TIMEOUT_TICKS};
+use nexus::kernel::reactor::{KernelMode, Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::time::Tick;
+use nexus::memory::store::{FileSemanticStore, SemanticStore};
+use nexus::memory::types::{Claim, ClaimValue, EntityId, Modality, Predicate, Provenance, SemanticMemoryEntry};
+use std::path::PathBuf;
+
+mod common;
+use common::input;
+
+fn temp(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("nexus_maintenance_mode_{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn reactor(semantic: PathBuf) -> Reactor {
+    common::builder()
+        .semantic_store(Box::new(FileSemanticStore::new(semantic)))
+        .build()
+}
+
+fn enter(jobs: &[MaintenanceJob]) -> Event {
+    input(InputContent::EnterMaintenance { jobs: jobs.to_vec() })
+}
+
+fn progress(effects: &[SideEffect]) -> Vec<MaintenanceProgress> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::MaintenanceProgress(p) => Some(p.clone()),
+        _ => None,
+    }).collect()
+}
+
+// What the driver does with `RunMaintenance`: run the task, answer with its result
+fn driver(effects: &[SideEffect]) -> Vec<Event> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::RunMaintenance(task) => Some(input(InputContent::MaintenanceResult(task.run()))),
+        _ => None,
+    }).collect()
+}
+
+fn entry(id: &str, object: &str) -> SemanticMemoryEntry {
+    SemanticMemoryEntry {
+        id: id.to_string(),
+        claim: Claim::new(EntityId::User, Predicate::Prefers, ClaimValue::Text(object.to_string()), Modality::Asserted),
+        confidence: 0.9,
+        provenance: Provenance::User,
+        created_at_tick: 0,
+        last_accessed_tick: 0,
+        version: 1,
+        previous_version_id: None,
+    }
+}
+
+#[tokio::test]
+async fn test_one_job_at_a_time_then_resume() {
+    let mut reactor = reactor(temp("ticks.json"));
+    let view = reactor.subscribe_view();
+
+    let effects = reactor.tick_step(vec![enter(&MaintenanceJob::MIGRATION)]);
+    assert_eq!(reactor.mode, KernelMode::Maintenance);
+    assert_eq!(view.borrow().mode, KernelMode::Maintenance, "The UI sees the mode");
+    let started = progress(&effects);
+    assert_eq!(started.len(), 1);
+    assert_eq!((started[0].completed, started[0].total), (0, 2));
+    assert_eq!(started[0].next, Some(MaintenanceJob::StoreMigration));
+    assert!(started[0].last.is_none());
+    assert!(driver(&effects).is_empty(), "Nothing runs in the entry tick");
+
+    // The disk work goes to the driver; nothing else starts until it answers
+    let migration = reactor.tick_step(vec![]);
+    assert_eq!(driver(&migration).len(), 1);
+    assert!(progress(&migration).is_empty());
+    assert!(driver(&reactor.tick_step(vec![])).is_empty(), "Still waiting for the driver");
+
+    let effects = reactor.tick_step(driver(&migration));
+    let first = progress(&effects);
+    assert_eq!((first[0].completed, first[0].total), (1, 2));
+    assert_eq!(first[0].last.as_ref().map(|r| r.job), Some(MaintenanceJob::StoreMigration));
+    assert_eq!(reactor.mode, KernelMode::Maintenance);
+    let compaction = driver(&effects);
+    assert_eq!(compaction.len(), 1, "The next job starts with the result");
+
+    let second = progress(&reactor.tick_step(compaction));
+    assert_eq!((second[0].completed, second[0].total), (2, 2));
+    assert_eq!(second[0].next, None);
+    assert_eq!(reactor.mode, KernelMode::Active);
+    assert_eq!(view.borrow().mode, KernelMode::Active);
+
+    let summary = reactor.maintenance.last_summary().expect("Run kept");
+    assert_eq!(summary.trigger, MaintenanceTrigger::Requested);
+    assert_eq!(summary.jobs.iter().map(|r| r.job).collect::<Vec<_>>(), MaintenanceJob::MIGRATION.to_vec());
+    assert!(reactor.maintenance_run.is_none());
+}
+
+#[tokio::test]
+async fn test_planning_and_output_suspended() {
+    let mut reactor = reactor(temp("suspended.json"));
+    reactor.tick_step(vec![enter(&[MaintenanceJob::LogRotation, MaintenanceJob::LogRotation])]);
+
+    // A command that would get a reflex acknowledgement in Active mode
+    let effects = reactor.tick_step(vec![input(InputContent::Text("turn on the lights".to_string()))]);
+    assert!(effects.iter().all(|e| matches!(e, SideEffect::MaintenanceProgress(_) | SideEffect::Diagnostic { .. })), "{:?}", effects);
+    assert!(reactor.state.last_user_input().is_none(), "User content is dropped, not queued");
+
+    // Control inputs still apply
+    reactor.tick_step(vec![input(InputContent::LocalTime { minute_of_day: 180 })]);
+    assert_eq!(reactor.mode, KernelMode::Active);
+}
+
+#[tokio::test]
+async fn test_unanswered_job_fails_after_the_deadline() {
+    let mut reactor = reactor(temp("deadline.json"));
+    reactor.tick_step(vec![enter(&MaintenanceJob::MIGRATION)]);
+    let migration = reactor.tick_step(vec![]);
+    assert_eq!(driver(&migration).len(), 1);
+
+    // The driver never answers (writer thread gone, task panicked)
+    let frame = reactor.tick.frame + MAINTENANCE_JOB_TIMEOUT_TICKS;
+    reactor.state.reduce(StateDelta::Tick(Tick { frame }));
+    reactor.tick.frame = frame;
+    let effects = reactor.tick_step(vec![]);
+    let failed = progress(&effects);
+    assert_eq!(failed[0].last.as_ref().map(|r| (r.job, r.outcome)), Some((MaintenanceJob::StoreMigration, JobOutcome::Failed)));
+    let compaction = driver(&effects);
+    assert_eq!(compaction.len(), 1, "The run moves on");
+
+    // A late result is dropped
+    assert!(progress(&reactor.tick_step(driver(&migration))).is_empty());
+    reactor.tick_step(compaction);
+    assert_eq!(reactor.mode, KernelMode::Active);
+    let jobs = &reactor.maintenance.last_summary().unwrap().jobs;
+    assert_eq!(jobs.iter().map(|r| r.outcome).collect::<Vec<_>>(), vec![JobOutcome::Failed, JobOutcome::Done]);
+}
+
+#[tokio::test]
+async fn test_resumes_the_interrupted_mode() {
+    let mut reactor = reactor(temp("resume.json"));
+    reactor.set_mode(KernelMode::Onboarding);
+    reactor.tick_step(vec![enter(&[MaintenanceJob::LogRotation])]);
+    // A second request queues behind the first
+    let effects = reactor.tick_step(vec![enter(&[MaintenanceJob::StoreCompaction])]);
+    assert_eq!(progress(&effects).last().map(|p| (p.completed, p.total)), Some((0, 2)), "Nothing ran yet");
+    assert_eq!(reactor.mode, KernelMode::Maintenance);
+
+    // Each job answered by the driver (no spool: the rotation is skipped in the tick)
+    let mut events = vec![];
+    for _ in 0..4 {
+        events = driver(&reactor.tick_step(events));
+    }
+    assert_eq!(reactor.mode, KernelMode::Onboarding);
+    assert_eq!(reactor.maintenance.last_summary().unwrap().jobs.len(), 2);
+}
+
+#[tokio::test]
+async fn test_store_migration_rewrites_the_file() {
+    let path = temp("migration.json");
+    let mut store = FileSemanticStore::new(path.clone());
+    store.insert(entry("a", "tea")).unwrap();
+    store.insert(entry("b", "jazz")).unwrap();
+    // Written by an older build: no version links yet
+    let mut old: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    for e in old.as_array_mut().unwrap() {
+        e.as_object_mut().unwrap().remove("previous_version_id");
+    }
+    std::fs::write(&path, old.to_string()).unwrap();
+
+    let mut reactor = reactor(path.clone());
+    reactor.tick_step(vec![enter(&[MaintenanceJob::StoreMigration])]);
+    let migration = reactor.tick_step(vec![]);
+    assert!(!std::fs::read_to_string(&path).unwrap().contains("previous_version_id"), "Not written in the tick");
+    let done = progress(&reactor.tick_step(driver(&migration)));
+    let result = done[0].last.clone().unwrap();
+    assert_eq!((result.outcome, result.items), (JobOutcome::Done, 2));
+    assert!(std::fs::read_to_string(&path).unwrap().contains("previous_version_id"), "Saved in the current schema");
+}
+
+#[tokio::test]
+async fn test_safe_mode_skips_store_jobs() {
+    let (tx, rx) = tokio::sync::mpsc::channel(100);
+    let mut reactor = Reactor::builder(rx, tx)
+        .config(ReactorConfig { llm_planning: false, safe_mode: true, ..Default::default() })
+        .semantic_store(Box::new(FileSemanticStore::new(temp("safe.json"))))
+        .build();
+    reactor.tick_step(vec![enter(&[MaintenanceJob::StoreMigration])]);
+    let done = progress(&reactor.tick_step(vec![]));
+    assert_eq!(done[0].last.as_ref().map(|r| r.outcome), Some(JobOutcome::Skipped));
+    assert_eq!(reactor.mode, KernelMode::Active);
+}
@@ -0,0 +1,229 @@
+use nexus::kernel::event::{Event, InputContent};
+use nexus::kernel::maintenance::{
+    rotate, JobOutcome, MaintenanceConfig, MaintenanceJob, MaintenanceTrigger, MAINTENANCE_DORMANT_TICKS,
+    MAINTENANCE_IDLE_TICKS, MAINTENANCE_INTERVAL_TICKS,
+};
+use nexus::kernel::presence::PresenceState;
+use nexus::kernel::quiet::QuietHours;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::telemetry::event::{LifecycleEvent, TelemetryEvent};
+use nexus::memory::store::FileSemanticStore;
+use nexus::memory::types::{Claim, ClaimValue, EntityId, EpisodicMemoryEntry, Modality, Predicate};
+use std::path::PathBuf;
+
+mod common;
+use common::{input, jump};
+
+fn temp(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("nexus_maintenance_{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn reactor(name: &str, maintenance: MaintenanceConfig, safe_mode: bool) -> Reactor {
+    common::builder()
+        .config(ReactorConfig { safe_mode, maintenance, ..common::config() })
+        .semantic_store(Box::new(FileSemanticStore::new(temp(&format!("{}_semantic.json", name)))))
+        .build()
+}
+
+fn window() -> MaintenanceConfig {
+    MaintenanceConfig { window: QuietHours::parse("02:00-05:00"), when_dormant: false }
+}
+
+fn clock(minute_of_day: u16) -> Event {
+    input(InputContent::LocalTime { minute_of_day })
+}
+
+// Tick with `events`, then answer every `RunMaintenance` as the driver would until none is left
+fn step(reactor: &mut Reactor, events: Vec<Event>) {
+    let mut effects = reactor.tick_step(events);
+    loop {
+        let results: Vec<Event> = effects.iter().filter_map(|e| match e {
+            SideEffect::RunMaintenance(task) => Some(input(InputContent::MaintenanceResult(task.run()))),
+            _ => None,
+        }).collect();
+        if results.is_empty() {
+            return;
+        }
+        effects = reactor.tick_step(results);
+    }
+}
+
+fn fact(object: &str, confidence: f32, modality: Modality) -> EpisodicMemoryEntry {
+    EpisodicMemoryEntry {
+        claim: Claim::new(EntityId::User, Predicate::Prefers, ClaimValue::Text(object.to_string()), modality),
+        confidence,
+        created_at_tick: 0,
+        last_reinforced_tick: 0,
+        decay_rate: 0.0,
+        created_at_ms: None,
+    }
+}
+
+fn recorded(reactor: &Reactor) -> Vec<(MaintenanceJob, JobOutcome)> {
+    reactor.telemetry.events().filter_map(|e| match e {
+        TelemetryEvent::Lifecycle(LifecycleEvent::Maintenance { job, outcome, .. }) => Some((*job, *outcome)),
+        _ => None,
+    }).collect()
+}
+
+#[tokio::test]
+async fn test_runs_once_inside_the_window() {
+    let mut reactor = reactor("window", window(), false);
+    step(&mut reactor, vec![clock(23 * 60)]);
+    assert!(reactor.maintenance.last_summary().is_none(), "Outside the window");
+
+    step(&mut reactor, vec![clock(3 * 60)]);
+    let summary = reactor.maintenance.last_summary().expect("ran in the window").clone();
+    assert_eq!(summary.trigger, MaintenanceTrigger::Window);
+    assert_eq!(summary.jobs.iter().map(|r| r.job).collect::<Vec<_>>(), MaintenanceJob::ALL);
+    assert_eq!(recorded(&reactor).len(), MaintenanceJob::ALL.len(), "One lifecycle event per job");
+
+    // Nightly: still in the window, but not again until the interval has passed
+    for _ in 0..3 {
+        step(&mut reactor, vec![clock(3 * 60 + 1)]);
+    }
+    assert_eq!(reactor.maintenance.last_summary().unwrap().tick, summary.tick);
+    jump(&mut reactor, summary.tick.frame + MAINTENANCE_INTERVAL_TICKS);
+    step(&mut reactor, vec![clock(3 * 60)]);
+    assert!(reactor.maintenance.last_summary().unwrap().tick.frame > summary.tick.frame);
+}
+
+#[tokio::test]
+async fn test_waits_for_low_activity() {
+    let mut reactor = reactor("activity", window(), false);
+    step(&mut reactor, vec![input(InputContent::Text("hello there".to_string()))]);
+    step(&mut reactor, vec![clock(3 * 60)]);
+    assert!(reactor.maintenance.last_summary().is_none(), "The user just spoke");
+
+    let spoke = reactor.tick.frame;
+    jump(&mut reactor, spoke + MAINTENANCE_IDLE_TICKS);
+    reactor.state.reduce(StateDelta::AllOutputsCleared);
+    step(&mut reactor, vec![]);
+    assert_eq!(reactor.maintenance.last_summary().map(|s| s.trigger), Some(MaintenanceTrigger::Window));
+}
+
+#[tokio::test]
+async fn test_runs_during_long_dormant_periods() {
+    let config = MaintenanceConfig { window: None, when_dormant: true };
+    let mut reactor = reactor("dormant", config, false);
+    reactor.state.reduce(StateDelta::PresenceUpdate(PresenceState::Dormant));
+    step(&mut reactor, vec![]);
+    let since = reactor.tick.frame;
+    jump(&mut reactor, since + MAINTENANCE_DORMANT_TICKS - 2);
+    step(&mut reactor, vec![]);
+    assert!(reactor.maintenance.last_summary().is_none(), "Not dormant long enough");
+
+    step(&mut reactor, vec![]);
+    assert_eq!(reactor.maintenance.last_summary().map(|s| s.trigger), Some(MaintenanceTrigger::Dormant));
+
+    // Off by default
+    let mut reactor = self::reactor("dormant_off", MaintenanceConfig::default(), false);
+    reactor.state.reduce(StateDelta::PresenceUpdate(PresenceState::Dormant));
+    step(&mut reactor, vec![]);
+    let since = reactor.tick.frame;
+    jump(&mut reactor, since + MAINTENANCE_DORMANT_TICKS);
+    step(&mut reactor, vec![]);
+    assert!(reactor.maintenance.last_summary().is_none());
+}
+
+#[tokio::test]
+async fn test_promotion_and_compaction() {
+    let mut reactor = reactor("promotion", MaintenanceConfig::default(), false);
+    reactor.episodic.insert(fact("tea", 0.95, Modality::Asserted));
+    reactor.episodic.insert(fact("tea", 0.97, Modality::Asserted));
+    reactor.episodic.insert(fact("coffee", 0.95, Modality::Inferred));
+    reactor.episodic.insert(fact("water", 0.5, Modality::Asserted));
+
+    let summary = reactor.run_maintenance(MaintenanceTrigger::OnDemand);
+    let promotion = summary.result(MaintenanceJob::SemanticPromotion).unwrap();
+    assert_eq!((promotion.outcome, promotion.items), (JobOutcome::Done, 1), "Only confident assertions, once");
+    assert_eq!(summary.result(MaintenanceJob::StoreCompaction).unwrap().outcome, JobOutcome::Done);
+    assert_eq!(reactor.semantic.entries().unwrap().len(), 1);
+    let saved = std::env::temp_dir().join(format!("nexus_maintenance_{}_promotion_semantic.json", std::process::id()));
+    assert!(saved.exists(), "Saved");
+
+    // Already promoted
+    let summary = reactor.run_maintenance(MaintenanceTrigger::OnDemand);
+    assert_eq!(summary.result(MaintenanceJob::SemanticPromotion).unwrap().items, 0);
+}
+
+#[tokio::test]
+async fn test_skipped_jobs() {
+    let mut reactor = reactor("skipped", MaintenanceConfig::default(), true);
+    reactor.episodic.insert(fact("tea", 0.95, Modality::Asserted));
+    let summary = reactor.run_maintenance(MaintenanceTrigger::OnDemand);
+    // Safe mode leaves memory alone; no spool path leaves telemetry alone
+    assert!(summary.jobs.iter().all(|r| r.outcome == JobOutcome::Skipped), "{:?}", summary.jobs);
+    assert!(reactor.semantic.entries().unwrap().is_empty());
+    assert_eq!(recorded(&reactor), MaintenanceJob::ALL.iter().map(|j| (*j, JobOutcome::Skipped)).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn test_spool_rotation_and_export() {
+    let spool = temp("export_spool.jsonl");
+    for n in 1..=3 {
+        let _ = std::fs::remove_file(format!("{}.{}", spool.display(), n));
+    }
+    std::fs::write(&spool, "old\n").unwrap();
+
+    let mut reactor = reactor("export", MaintenanceConfig::default(), false);
+    reactor.maintenance.telemetry_spool = Some(spool.clone());
+    reactor.tick_step(vec![]);
+    let summary = reactor.run_maintenance(MaintenanceTrigger::OnDemand);
+    let rotation = summary.result(MaintenanceJob::LogRotation).unwrap();
+    assert_eq!((rotation.outcome, rotation.items), (JobOutcome::Done, 1));
+    assert_eq!(std::fs::read_to_string(format!("{}.1", spool.display())).unwrap(), "old\n");
+    let export = summary.result(MaintenanceJob::TelemetryExport).unwrap();
+    assert_eq!(export.outcome, JobOutcome::Done);
+    assert!(export.items > 0);
+    let written = std::fs::read_to_string(&spool).unwrap();
+    assert!(written.contains("Maintenance"), "Earlier jobs are in the export");
+}
+
+#[test]
+fn test_rotate_keeps_generations() {
+    let path = temp("rotate.jsonl");
+    let numbered = |n: usize| PathBuf::from(format!("{}.{}", path.display(), n));
+    for n in 1..=3 {
+        let _ = std::fs::remove_file(numbered(n));
+    }
+    assert_eq!(rotate(&path, 2).unwrap(), 0, "Nothing to rotate");
+
+    for generation in ["a", "b", "c"] {
+        std::fs::write(&path, generation).unwrap();
+        rotate(&path, 2).unwrap();
+    }
+    assert!(!path.exists());
+    assert_eq!(std::fs::read_to_string(numbered(1)).unwrap(), "c");
+    assert_eq!(std::fs::read_to_string(numbered(2)).unwrap(), "b");
+    assert!(!numbered(3).exists(), "Oldest dropped");
+}
+
+#[tokio::test]
+async fn test_nightly_writes_go_through_the_driver() {
+    let mut reactor = reactor("nightly_io", window(), false);
+    reactor.episodic.insert(fact("tea", 0.95, Modality::Asserted));
+    let saved = std::env::temp_dir().join(format!("nexus_maintenance_{}_nightly_io_semantic.json", std::process::id()));
+
+    // Promotion inserts into the store (which writes its log); the job itself hands the snapshot over
+    let effects = reactor.tick_step(vec![clock(3 * 60)]);
+    let tasks: Vec<_> = effects.iter().filter_map(|e| match e {
+        SideEffect::RunMaintenance(task) => Some(task.clone()),
+        _ => None,
+    }).collect();
+    assert_eq!(tasks.iter().map(|t| t.job).collect::<Vec<_>>(), vec![MaintenanceJob::SemanticPromotion], "One job at a time");
+    let _ = std::fs::remove_file(&saved);
+    assert!(reactor.maintenance.last_summary().is_none(), "Waiting for the driver");
+
+    // Until the driver answers, nothing else starts
+    assert!(!reactor.tick_step(vec![]).iter().any(|e| matches!(e, SideEffect::RunMaintenance(_))));
+    step(&mut reactor, vec![input(InputContent::MaintenanceResult(tasks[0].run()))]);
+    assert!(saved.exists(), "Written by the driver");
+    let summary = reactor.maintenance.last_summary().expect("Finished once the results are back");
+    assert_eq!(summary.result(MaintenanceJob::SemanticPromotion).map(|r| (r.outcome, r.items)), Some((JobOutcome::Done, 1)));
+    assert_eq!(recorded(&reactor).len(), MaintenanceJob::ALL.len());
+}
@@ -0,0 +1,167 @@
+use nexus::kernel::ids::IdGenerator;
+use nexus::kernel::intent::types::IntentHypothesis;
+use nexus::kernel::memory::consent::MemoryConsentState;
+use nexus::kernel::memory::types::MemoryKey;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::time::Tick;
+use nexus::memory::bundle::{ConflictPolicy, MemoryBundle, BUNDLE_FORMAT, BUNDLE_VERSION};
+use nexus::memory::{
+    Claim, ClaimValue, EntityId, FileSemanticStore, MemoryError, Modality, Predicate, Provenance, SemanticMemoryEntry,
+};
+use std::path::PathBuf;
+
+fn path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("nexus_bundle_{}_{}.json", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn reactor(name: &str) -> (Reactor, PathBuf) {
+    let path = path(name);
+    let (tx, rx) = tokio::sync::mpsc::channel(10);
+    let reactor = Reactor::builder(rx, tx)
+        .config(ReactorConfig { llm_planning: false, ..Default::default() })
+        .semantic_store(Box::new(FileSemanticStore::new(path.clone())))
+        .build();
+    (reactor, path)
+}
+
+fn fact(id: &str, predicate: Predicate, value: &str) -> SemanticMemoryEntry {
+    SemanticMemoryEntry {
+        id: id.to_string(),
+        claim: Claim::new(EntityId::User, predicate, ClaimValue::Text(value.to_string()), Modality::Asserted),
+        confidence: 0.9,
+        provenance: Provenance::User,
+        created_at_tick: 100,
+        last_accessed_tick: 100,
+        version: 1,
+        previous_version_id: None,
+    }
+}
+
+fn key(fingerprint: u64) -> MemoryKey {
+    MemoryKey { hypothesis: IntentHypothesis::Statement, symbol_fingerprint: fingerprint }
+}
+
+fn resolve(reactor: &mut Reactor, fingerprint: u64, state: MemoryConsentState) {
+    reactor.state.reduce(StateDelta::MemoryConsentResolved { key: key(fingerprint), state, resolved_at: Tick { frame: 5 } });
+}
+
+fn values(reactor: &Reactor, id: &str) -> Vec<(u32, String)> {
+    reactor.semantic.history(id).unwrap().into_iter()
+        .map(|e| match e.claim.object {
+            ClaimValue::Text(t) => (e.version, t),
+            other => (e.version, format!("{:?}", other)),
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_round_trip_keeps_history_and_consent() {
+    let (mut source, source_path) = reactor("source");
+    source.semantic.insert(fact("tea", Predicate::Prefers, "Tea")).unwrap();
+    let v2 = source.semantic.update_confidence("tea", 0.5, &mut source.ids).unwrap();
+    resolve(&mut source, 1, MemoryConsentState::Granted);
+    resolve(&mut source, 2, MemoryConsentState::Declined);
+    source.state.reduce(StateDelta::TopicConsentResolved { topic_id: "topic_1".to_string(), state: MemoryConsentState::Declined });
+
+    let json = source.export_memory().unwrap().to_json().unwrap();
+    let bundle = MemoryBundle::from_json(&json).unwrap();
+    assert_eq!((bundle.format.as_str(), bundle.version), (BUNDLE_FORMAT, BUNDLE_VERSION));
+    assert_eq!(bundle.semantic.len(), 2, "Superseded versions travel too");
+    assert_eq!(bundle.consent.len(), 2);
+
+    let (mut target, target_path) = reactor("target");
+    let report = target.import_memory(&bundle, ConflictPolicy::KeepExisting).unwrap();
+    assert_eq!((report.added, report.remapped, report.consent_applied), (2, 0, 3));
+    assert_eq!(target.semantic.history(&v2).unwrap(), source.semantic.history(&v2).unwrap());
+    assert_eq!(target.state.memory_consent()[&key(1)].state, MemoryConsentState::Granted);
+    assert_eq!(target.state.memory_consent()[&key(2)].state, MemoryConsentState::Declined);
+    assert_eq!(target.state.topic_consent()["topic_1"], MemoryConsentState::Declined);
+
+    // Importing again changes nothing
+    let again = target.import_memory(&bundle, ConflictPolicy::PreferImported).unwrap();
+    assert_eq!((again.added, again.duplicates, again.consent_kept), (0, 2, 3));
+    assert_eq!(target.semantic.entries().unwrap().len(), 2);
+
+    let _ = std::fs::remove_file(source_path);
+    let _ = std::fs::remove_file(target_path);
+}
+
+#[tokio::test]
+async fn test_taken_ids_are_remapped_with_their_links() {
+    let (mut source, source_path) = reactor("remap_source");
+    source.semantic.insert(fact("f1", Predicate::Prefers, "Tea")).unwrap();
+    let v2 = source.semantic.update_confidence("f1", 0.5, &mut source.ids).unwrap();
+    let bundle = source.export_memory().unwrap();
+
+    // Same id, unrelated fact on this machine
+    let (mut target, target_path) = reactor("remap_target");
+    target.semantic.insert(fact("f1", Predicate::Knows, "Rust")).unwrap();
+    let report = target.import_memory(&bundle, ConflictPolicy::KeepExisting).unwrap();
+    assert_eq!((report.added, report.remapped, report.conflicts), (2, 1, 0));
+
+    assert_eq!(values(&target, "f1"), vec![(1, "Rust".to_string())], "Existing entry untouched");
+    let imported = values(&target, &v2);
+    assert_eq!(imported, vec![(1, "Tea".to_string()), (2, "Tea".to_string())], "Link follows the new id");
+
+    let _ = std::fs::remove_file(source_path);
+    let _ = std::fs::remove_file(target_path);
+}
+
+#[tokio::test]
+async fn test_remapped_ids_replay_from_the_seed() {
+    let (mut source, source_path) = reactor("replay_source");
+    source.semantic.insert(fact("f1", Predicate::Prefers, "Tea")).unwrap();
+    let bundle = source.export_memory().unwrap();
+
+    let remapped = |name: &str| {
+        let (mut target, target_path) = reactor(name);
+        target.ids = IdGenerator::new(7);
+        target.semantic.insert(fact("f1", Predicate::Knows, "Rust")).unwrap();
+        assert_eq!(target.import_memory(&bundle, ConflictPolicy::KeepExisting).unwrap().remapped, 1);
+        let _ = std::fs::remove_file(target_path);
+        target.semantic.entries().unwrap().into_iter().map(|e| e.id).find(|id| id != "f1").unwrap()
+    };
+    assert_eq!(remapped("replay_a"), remapped("replay_b"), "Same seed, same new id");
+
+    let _ = std::fs::remove_file(source_path);
+}
+
+#[tokio::test]
+async fn test_conflicts_follow_the_policy() {
+    let (mut source, source_path) = reactor("conflict_source");
+    source.semantic.insert(fact("imported", Predicate::Prefers, "Coffee")).unwrap();
+    resolve(&mut source, 1, MemoryConsentState::Granted);
+    resolve(&mut source, 2, MemoryConsentState::Granted);
+    let bundle = source.export_memory().unwrap();
+
+    let (mut target, target_path) = reactor("conflict_target");
+    target.semantic.insert(fact("local", Predicate::Prefers, "Tea")).unwrap();
+    resolve(&mut target, 1, MemoryConsentState::Declined);
+    resolve(&mut target, 2, MemoryConsentState::Ignored);
+
+    let kept = target.import_memory(&bundle, ConflictPolicy::KeepExisting).unwrap();
+    assert_eq!((kept.conflicts, kept.skipped, kept.added, kept.consent_kept), (1, 1, 0, 2));
+    assert_eq!(values(&target, "local"), vec![(1, "Tea".to_string())]);
+
+    let preferred = target.import_memory(&bundle, ConflictPolicy::PreferImported).unwrap();
+    assert_eq!((preferred.conflicts, preferred.added), (1, 1));
+    assert_eq!(values(&target, "local"), vec![(1, "Tea".to_string()), (2, "Coffee".to_string())], "Old value stays in history");
+    assert_eq!(target.state.memory_consent()[&key(1)].state, MemoryConsentState::Declined, "A decline is never overridden");
+    assert_eq!(target.state.memory_consent()[&key(2)].state, MemoryConsentState::Granted);
+
+    let _ = std::fs::remove_file(source_path);
+    let _ = std::fs::remove_file(target_path);
+}
+
+#[test]
+fn test_foreign_and_newer_bundles_are_refused() {
+    let newer = format!(r#"{{"format": "{}", "version": {}, "semantic": []}}"#, BUNDLE_FORMAT, BUNDLE_VERSION + 1);
+    assert!(matches!(MemoryBundle::from_json(&newer), Err(MemoryError::SerializationError(_))));
+    assert!(matches!(MemoryBundle::from_json(r#"{"format": "other", "version": 1, "semantic": []}"#), Err(MemoryError::SerializationError(_))));
+
+    let minimal = MemoryBundle::from_json(&format!(r#"{{"format": "{}", "version": 1, "semantic": []}}"#, BUNDLE_FORMAT)).unwrap();
+    assert!(minimal.consent.is_empty() && minimal.topic_consent.is_empty());
+}
@@ -0,0 +1,139 @@
+use nexus::kernel::diagnostic::DiagnosticCode;
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::intent::types::{IntentCandidate, IntentHypothesis, IntentStability};
+use nexus::kernel::memory::consent::MemoryConsentState;
+use nexus::kernel::memory::inspect::{manual_action, CandidateAction, ManualActionRefusal};
+use nexus::kernel::memory::sensitivity::SensitivityClass;
+use nexus::kernel::memory::types::{MemoryCandidate, MemoryKey};
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::subsystem::Subsystem;
+use nexus::kernel::time::Tick;
+use nexus::memory::store::FileSemanticStore;
+
+mod common;
+
+fn reactor(name: &str) -> Reactor {
+    let semantic = std::env::temp_dir().join(format!("nexus_inspect_{}_{}_semantic.json", std::process::id(), name));
+    let _ = std::fs::remove_file(&semantic);
+    common::builder()
+        .semantic_store(Box::new(FileSemanticStore::new(semantic)))
+        .build()
+}
+
+fn candidate(id: &str, hash: u64, created_at: u64) -> MemoryCandidate {
+    let intent = IntentCandidate {
+        id: format!("intent_{}", id),
+        hypothesis: IntentHypothesis::Inquiry,
+        confidence: 0.9,
+        source_symbol_ids: vec![],
+        semantic_hash: hash,
+        stability: IntentStability::Stable,
+    };
+    MemoryCandidate {
+        id: id.to_string(),
+        key: MemoryKey::from_intent(&intent),
+        intent,
+        created_at: Tick { frame: created_at },
+        reinforcement_count: 1,
+        last_reinforced_at: Tick { frame: created_at },
+        topic_id: None,
+        sensitivity: None,
+        context: Default::default(),
+    }
+}
+
+fn act(id: &str, action: CandidateAction) -> Event {
+    Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::MemoryCandidateAction { id: id.to_string(), action },
+        captured_at: None,
+    })
+}
+
+fn refused(effects: &[SideEffect]) -> bool {
+    effects.iter().any(|e| matches!(e, SideEffect::Diagnostic { code: DiagnosticCode::MemoryActionRefused, .. }))
+}
+
+#[tokio::test]
+async fn test_list_candidates() {
+    let mut reactor = reactor("list");
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(candidate("b", 2, 0)));
+    reactor.state.reduce(StateDelta::MemoryCandidateReinforced("b".to_string(), Tick { frame: 0 }));
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(candidate("a", 1, 0)));
+    for _ in 0..5 {
+        reactor.tick_step(vec![]);
+    }
+
+    let listed = reactor.memory_candidates();
+    assert_eq!(listed.iter().map(|c| c.id.as_str()).collect::<Vec<_>>(), vec!["a", "b"]);
+    assert_eq!(listed[1].reinforcement_count, 2);
+    assert_eq!(listed[1].age_ticks, 5);
+    assert_eq!(listed[0].consent, None);
+    assert!(!listed[0].sensitive);
+}
+
+#[tokio::test]
+async fn test_manual_promotion_grants_consent() {
+    let mut reactor = reactor("promote");
+    let cand = candidate("c1", 7, 0);
+    let key = cand.key.clone();
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(cand));
+
+    // One sighting, an inquiry: the heuristics would never promote it
+    let effects: Vec<_> = reactor.tick_step(vec![act("c1", CandidateAction::Promote)]).into_iter().collect();
+    assert!(!refused(&effects));
+    assert!(reactor.state.long_term_memory().contains_key("c1"));
+    assert!(reactor.state.memory_candidates().is_empty());
+    assert_eq!(reactor.state.memory_consent()[&key].state, MemoryConsentState::Granted);
+}
+
+#[tokio::test]
+async fn test_manual_promotion_of_sensitive_candidate() {
+    let mut reactor = reactor("sensitive");
+    let mut cand = candidate("s1", 8, 0);
+    cand.sensitivity = Some(SensitivityClass::Health);
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(cand));
+    assert!(reactor.memory_candidates()[0].sensitive);
+
+    reactor.tick_step(vec![act("s1", CandidateAction::Promote)]);
+    let record = &reactor.state.long_term_memory()["s1"];
+    assert_eq!(record.sensitivity, Some(SensitivityClass::Health), "Still marked sensitive");
+}
+
+#[tokio::test]
+async fn test_declined_key_stays_declined() {
+    let mut reactor = reactor("declined");
+    let cand = candidate("d1", 9, 0);
+    reactor.state.reduce(StateDelta::MemoryConsentResolved { key: cand.key.clone(), state: MemoryConsentState::Declined, resolved_at: Tick { frame: 0 } });
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(cand));
+
+    assert_eq!(manual_action(&"d1".to_string(), CandidateAction::Promote, &reactor.state, Tick { frame: 1 }).err(), Some(ManualActionRefusal::ConsentDeclined));
+    let effects: Vec<_> = reactor.tick_step(vec![act("d1", CandidateAction::Promote)]).into_iter().collect();
+    assert!(refused(&effects));
+    assert!(reactor.state.long_term_memory().is_empty());
+
+    // Discarding is always allowed
+    reactor.tick_step(vec![act("d1", CandidateAction::Discard)]);
+    assert!(reactor.state.memory_candidates().is_empty());
+    assert!(reactor.state.long_term_memory().is_empty());
+}
+
+#[tokio::test]
+async fn test_refused_when_unknown_or_memory_off() {
+    let mut reactor = reactor("off");
+    let effects: Vec<_> = reactor.tick_step(vec![act("missing", CandidateAction::Discard)]).into_iter().collect();
+    assert!(refused(&effects));
+
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(candidate("m1", 10, 0)));
+    let off = Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::SubsystemControl { subsystem: Subsystem::Memory, enabled: false },
+        captured_at: None,
+    });
+    let effects: Vec<_> = reactor.tick_step(vec![off, act("m1", CandidateAction::Promote)]).into_iter().collect();
+    assert!(refused(&effects));
+    assert!(reactor.state.long_term_memory().is_empty());
+    assert_eq!(reactor.state.memory_candidates().len(), 1);
+}
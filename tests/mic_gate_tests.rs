@@ -0,0 +1,63 @@
+use nexus::audio::mic::{forwards_audio, needs_wake_word, MicGate, MicMode};
+use nexus::kernel::presence::PresenceState;
+
+#[test]
+fn test_forwards_unless_suspended() {
+    for presence in PresenceState::ALL {
+        assert_eq!(forwards_audio(presence), presence != PresenceState::Suspended, "{:?}", presence);
+        assert_eq!(needs_wake_word(presence), presence == PresenceState::Dormant, "{:?}", presence);
+    }
+}
+
+#[test]
+fn test_manual_is_always_open() {
+    let gate = MicGate::default();
+    assert_eq!(gate.mode(), MicMode::Manual);
+    for presence in PresenceState::ALL {
+        gate.follow(presence);
+        assert!(gate.is_open(), "{:?}", presence);
+        assert!(!gate.requires_wake_word(), "{:?}", presence);
+    }
+}
+
+#[test]
+fn test_auto_follows_presence() {
+    let gate = MicGate::new(MicMode::Auto);
+    assert!(!gate.is_open(), "Closed until presence is known");
+    gate.follow(PresenceState::Attentive);
+    assert!(gate.is_open());
+    gate.follow(PresenceState::Engaged);
+    assert!(gate.is_open());
+    assert!(!gate.requires_wake_word());
+    gate.follow(PresenceState::Suspended);
+    assert!(!gate.is_open());
+
+    // Dormant: still hearing, for the wake word
+    gate.follow(PresenceState::Dormant);
+    assert!(gate.is_open());
+    assert!(gate.requires_wake_word());
+}
+
+#[test]
+fn test_mode_switch_applies_at_once() {
+    let gate = MicGate::new(MicMode::Manual);
+    gate.set_mode(MicMode::Auto, PresenceState::Suspended);
+    assert!(!gate.is_open(), "Not waiting for the next tick");
+    gate.set_mode(MicMode::Manual, PresenceState::Suspended);
+    assert!(gate.is_open());
+    gate.set_mode(MicMode::Auto, PresenceState::Dormant);
+    assert!(gate.requires_wake_word());
+    gate.set_mode(MicMode::Manual, PresenceState::Dormant);
+    assert!(!gate.requires_wake_word());
+}
+
+#[test]
+fn test_clones_share_the_gate() {
+    // The driver holds one clone, the audio callback another
+    let driver = MicGate::new(MicMode::Auto);
+    let callback = driver.clone();
+    driver.follow(PresenceState::Engaged);
+    assert!(callback.is_open());
+    driver.set_mode(MicMode::Manual, PresenceState::Dormant);
+    assert_eq!(callback.mode(), MicMode::Manual);
+}
@@ -0,0 +1,79 @@
+use nexus::kernel::event::{AudioSignal, AudioStatus, Event, InputContent, PlaybackId};
+use nexus::kernel::memory::consent::MemoryConsentState;
+use nexus::kernel::reactor::KernelMode;
+use nexus::kernel::scheduler::SideEffect;
+
+mod common;
+use common::{input, jump, reactor};
+
+fn utterance(text: &str, segment: &str) -> Event {
+    input(InputContent::ProvisionalText { content: text.to_string(), confidence: 0.9, source_id: segment.to_string(), words: Vec::new(), language: None, is_final: true })
+}
+
+#[test]
+fn test_allowlist_classification() {
+    let id = PlaybackId::Speech(uuid::Uuid::new_v4());
+    assert!(InputContent::AudioStatus(AudioStatus::PlaybackStarted { output_id: id }).is_control());
+    assert!(InputContent::LocalTime { minute_of_day: 600 }.is_control());
+    assert!(InputContent::ToolResult { call_id: "c".to_string(), ok: true }.is_control());
+    assert!(InputContent::OutputDevice { device: None }.is_control());
+
+    assert!(!InputContent::Text("hello".to_string()).is_control());
+    assert!(!InputContent::Audio(AudioSignal::SpeechStart).is_control());
+    assert!(!InputContent::AudioChunk(vec![0.5; 160]).is_control());
+    assert!(!InputContent::TranscriptionRequest { segment_id: "seg1".to_string() }.is_control());
+    assert!(!InputContent::ExchangeSummary { exchange_id: "x".to_string(), text: Some("summary".to_string()) }.is_control());
+}
+
+#[tokio::test]
+async fn test_playback_lifecycle_passes_during_onboarding() {
+    let mut reactor = reactor();
+    reactor.set_mode(KernelMode::Onboarding);
+    let id = PlaybackId::Speech(uuid::Uuid::new_v4());
+
+    reactor.tick_step(vec![input(InputContent::AudioStatus(AudioStatus::PlaybackStarted { output_id: id }))]);
+    assert!(reactor.audio_monitor.is_system_speaking());
+    reactor.tick_step(vec![input(InputContent::AudioStatus(AudioStatus::PlaybackEnded { output_id: id, elapsed_ms: 400 }))]);
+    assert!(!reactor.audio_monitor.is_system_speaking(), "Not wedged in speaking after onboarding");
+}
+
+#[tokio::test]
+async fn test_onboarding_cannot_leak_user_content_into_memory() {
+    let mut reactor = reactor();
+    reactor.set_mode(KernelMode::Onboarding);
+
+    // Everything that would create, reinforce and promote a memory in Active mode
+    let effects = reactor.tick_step(vec![
+        input(InputContent::Text("What is my blood pressure?".to_string())),
+        utterance("What is my blood pressure?", "seg1"),
+    ]);
+    jump(&mut reactor, 1250);
+    reactor.tick_step(vec![utterance("What is my blood pressure?", "seg2"), input(InputContent::Audio(AudioSignal::SpeechStart))]);
+    reactor.tick_step(vec![]);
+
+    assert!(reactor.state.memory_candidates().is_empty());
+    assert!(effects.is_empty());
+    assert!(reactor.state.long_term_memory().is_empty());
+    assert!(reactor.state.memory_consent().is_empty());
+    assert_eq!(reactor.dialogue.len(), 0);
+    assert!(!reactor.state.user_speaking());
+}
+
+#[tokio::test]
+async fn test_consent_answer_passes_during_onboarding() {
+    let mut reactor = reactor();
+    reactor.tick_step(vec![utterance("What is my blood pressure?", "seg1")]);
+    jump(&mut reactor, 1250);
+    let request = reactor.tick_step(vec![utterance("What is my blood pressure?", "seg2")])
+        .into_iter()
+        .find_map(|e| match e {
+            SideEffect::AskMemoryConsent(request) => Some(request),
+            _ => None,
+        })
+        .expect("consent asked");
+
+    // Onboarding restarted (e.g. settings reset) while the prompt was on screen
+    reactor.set_mode(KernelMode::Onboarding);
+    reactor.tick_step(vec![input(InputContent::MemoryConsentResponse(request.answer(MemoryConsentState::Declined)))]);
+    assert_eq!(reactor.state.memory_consent().get(&request.key).unwrap().state, MemoryConsentState::Declined);
+}
@@ -0,0 +1,79 @@
+use nexus::kernel::diagnostic::DiagnosticCode;
+use nexus::kernel::event::{Event, InputContent, InputEvent, PlaybackId};
+use nexus::kernel::reactor::KernelMode;
+use nexus::kernel::scheduler::SideEffect;
+use nexus::outputs::captions::playback_command_on;
+use nexus::outputs::device::{resolve, OutputDeviceConfig, OutputRouter};
+
+mod common;
+
+fn names(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+fn route(device: Option<&str>) -> Event {
+    Event::Input(InputEvent {
+        source: "Driver".to_string(),
+        content: InputContent::OutputDevice { device: device.map(str::to_string) },
+        captured_at: None,
+    })
+}
+
+#[test]
+fn test_fallback_chain_resolution() {
+    let chain = OutputDeviceConfig::parse(" Headset, MacBook Pro Speakers ,,").preferred;
+    assert_eq!(chain, names(&["Headset", "MacBook Pro Speakers"]));
+
+    let both = names(&["MacBook Pro Speakers", "AirPods Headset"]);
+    assert_eq!(resolve(&chain, &both).as_deref(), Some("AirPods Headset"), "Chain order wins over device order");
+    assert_eq!(resolve(&chain, &names(&["MacBook Pro Speakers"])).as_deref(), Some("MacBook Pro Speakers"));
+    assert_eq!(resolve(&chain, &names(&["HDMI"])), None, "Nothing matches: system default");
+    assert_eq!(resolve(&[], &both), None);
+}
+
+#[test]
+fn test_router_reports_route_changes_only() {
+    let mut router = OutputRouter::new(OutputDeviceConfig::parse("Headset,Speakers"));
+    assert!(router.refresh(&names(&["Speakers", "Headset"])));
+    assert_eq!(router.current(), Some("Headset"));
+    assert!(!router.refresh(&names(&["Headset", "Speakers"])), "Same route");
+
+    // Headset unplugged, then settings reset to the system default
+    assert!(router.refresh(&names(&["Speakers"])));
+    assert_eq!(router.current(), Some("Speakers"));
+    router.set_config(OutputDeviceConfig::default());
+    assert!(router.refresh(&names(&["Speakers"])));
+    assert_eq!(router.current(), None);
+}
+
+#[test]
+fn test_playback_is_routed() {
+    let routed = playback_command_on("Hi", false, Some("Headset"));
+    let args: Vec<_> = routed.as_std().get_args().collect();
+    assert_eq!(args, ["-a", "Headset", "Hi"]);
+    assert_eq!(playback_command_on("Hi", false, None).as_std().get_args().count(), 1);
+    assert_eq!(playback_command_on("Hi", true, Some("Headset")).as_std().get_program(), "sleep");
+}
+
+#[tokio::test]
+async fn test_kernel_stops_speech_when_route_changes() {
+    let mut reactor = common::reactor();
+    reactor.set_mode(KernelMode::Onboarding);
+
+    // Platform signal: tracked even during onboarding, silent while nothing plays
+    let effects = reactor.tick_step(vec![route(Some("Headset"))]);
+    assert!(effects.is_empty());
+    assert_eq!(reactor.state.output_device().map(String::as_str), Some("Headset"));
+    assert_eq!(reactor.subscribe_view().borrow().output_device.as_deref(), Some("Headset"));
+
+    // Unplugged mid-utterance: stop instead of continuing on the speakers
+    reactor.audio_monitor.playback_started(PlaybackId::Speech(uuid::Uuid::new_v4()));
+    let effects = reactor.tick_step(vec![route(None)]);
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::StopAudio)));
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::Diagnostic { code: DiagnosticCode::OutputRouteChanged, context, .. } if context == "device=default")));
+    assert_eq!(reactor.state.output_device(), None);
+
+    // Same route again is not a change
+    let effects = reactor.tick_step(vec![route(None)]);
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::StopAudio)));
+}
@@ -0,0 +1,167 @@
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::profile::{ProfileStore, DEFAULT_PROFILE};
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::speech::planner::SpeechIntent;
+use nexus::outputs::realizer::restyle;
+use nexus::outputs::safety::SafetyConfig;
+use nexus::outputs::style::{parse_style_request, Formality, OutputChannel, OutputStyle, ProfanityTolerance};
+use std::path::PathBuf;
+
+mod common;
+
+fn temp(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("nexus_style_{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn reactor(profiles: ProfileStore) -> Reactor {
+    common::builder()
+        .profiles(profiles)
+        .build()
+}
+
+fn utterance(text: &str, segment: &str) -> Event {
+    transcript(text, segment, true)
+}
+
+fn transcript(text: &str, segment: &str, is_final: bool) -> Event {
+    Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::ProvisionalText { content: text.to_string(), confidence: 0.9, source_id: segment.to_string(), words: Vec::new(), language: None, is_final },
+        captured_at: None,
+    })
+}
+
+fn style(formality: Formality, emoji: bool, profanity: ProfanityTolerance) -> OutputStyle {
+    OutputStyle { formality, emoji, profanity }
+}
+
+#[test]
+fn test_restyle_formality() {
+    let neutral = OutputStyle::default();
+    let formal = style(Formality::Formal, false, ProfanityTolerance::None);
+    let casual = style(Formality::Casual, false, ProfanityTolerance::None);
+
+    assert_eq!(restyle("I'm here. Don't worry.", &neutral, OutputChannel::Voice), "I'm here. Don't worry.");
+    assert_eq!(restyle("I'm here. Don't worry, it's fine.", &formal, OutputChannel::Voice), "I am here. Do not worry, it is fine.");
+    assert_eq!(restyle("I am here. Do not worry.", &casual, OutputChannel::Voice), "I'm here. Don't worry.");
+    // Only mid-clause: a final "it is" does not contract
+    assert_eq!(restyle("That is what it is.", &casual, OutputChannel::Voice), "That's what it is.");
+    // Whole words only
+    assert_eq!(restyle("Bit's and I'mport", &formal, OutputChannel::Voice), "Bit's and I'mport");
+}
+
+#[test]
+fn test_emoji_only_in_the_text_channel() {
+    let with_emoji = style(Formality::Neutral, true, ProfanityTolerance::None);
+    let text = "Welcome back 👋 all set ✅";
+    assert_eq!(restyle(text, &with_emoji, OutputChannel::Text), text);
+    assert_eq!(restyle(text, &with_emoji, OutputChannel::Voice), "Welcome back all set", "Never spoken");
+    assert_eq!(restyle(text, &OutputStyle::default(), OutputChannel::Text), "Welcome back all set");
+}
+
+#[test]
+fn test_prompt_guidance_and_screening() {
+    let style = style(Formality::Formal, true, ProfanityTolerance::Any);
+    let guidance = style.prompt_guidance(OutputChannel::Text);
+    assert!(guidance.contains("formal register"));
+    assert!(guidance.contains("emoji is welcome"));
+    assert!(guidance.contains("Profanity is acceptable"));
+    assert!(style.prompt_guidance(OutputChannel::Voice).contains("Never use emoji"));
+    assert!(OutputStyle::default().prompt_guidance(OutputChannel::Text).contains("Never use profanity"));
+
+    let base = SafetyConfig::default();
+    assert!(!style.screening(base).profanity, "Tolerated profanity is not filtered");
+    assert!(style.screening(base).pii, "PII is never relaxed");
+    assert_eq!(OutputStyle { profanity: ProfanityTolerance::Mild, ..style }.screening(base), base);
+}
+
+#[cfg(feature = "llm")]
+#[test]
+fn test_speech_prompt_carries_the_style() {
+    use nexus::services::llm::client::speech_prompt;
+    let formal = style(Formality::Formal, false, ProfanityTolerance::None);
+    let prompt = speech_prompt(&SpeechIntent::Confirmation("lights off".to_string()), &formal, OutputChannel::Voice, "en");
+    assert!(prompt.starts_with("System: "));
+    assert!(prompt.contains(&formal.prompt_guidance(OutputChannel::Voice)));
+    assert!(prompt.contains("Confirm this action briefly: lights off"));
+}
+
+#[test]
+fn test_spoken_style_requests() {
+    let neutral = OutputStyle::default();
+    assert_eq!(parse_style_request("Be more formal", &neutral).map(|s| s.formality), Some(Formality::Formal));
+    assert_eq!(parse_style_request("could you be a bit more casual", &neutral).map(|s| s.formality), Some(Formality::Casual));
+    let formal = style(Formality::Formal, false, ProfanityTolerance::None);
+    assert_eq!(parse_style_request("more casual please", &formal).map(|s| s.formality), Some(Formality::Neutral), "One step");
+    assert_eq!(parse_style_request("use emojis", &neutral).map(|s| s.emoji), Some(true));
+    assert_eq!(parse_style_request("no emoji", &style(Formality::Neutral, true, ProfanityTolerance::None)).map(|s| s.emoji), Some(false));
+    assert_eq!(parse_style_request("you can swear", &neutral).map(|s| s.profanity), Some(ProfanityTolerance::Any));
+    assert_eq!(parse_style_request("don't swear", &formal).map(|s| s.profanity), Some(ProfanityTolerance::None));
+    assert_eq!(parse_style_request("what's the weather", &neutral), None);
+    assert_eq!(parse_style_request("my boss says my emails should be more formal than they are", &neutral), None, "Too long for a request");
+}
+
+#[test]
+fn test_profiles_persist_the_style() {
+    let path = temp("profiles.json");
+    let formal = style(Formality::Formal, false, ProfanityTolerance::Mild);
+    let mut store = ProfileStore::open(&path).unwrap();
+    assert_eq!(store.style(), OutputStyle::default());
+    store.set_style(formal);
+    assert!(!path.exists(), "Changes are made in memory");
+    store.snapshot().unwrap().save().unwrap();
+
+    let mut store = ProfileStore::open(&path).unwrap();
+    assert_eq!(store.style(), formal);
+    store.select("work");
+    assert_eq!(store.style(), OutputStyle::default(), "New profiles start with the default style");
+    store.snapshot().unwrap().save().unwrap();
+
+    let mut store = ProfileStore::open(&path).unwrap();
+    assert_eq!(store.active_profile().name, "work");
+    store.select(DEFAULT_PROFILE);
+    assert_eq!(store.style(), formal);
+    assert_eq!(store.names(), [DEFAULT_PROFILE, "work"]);
+}
+
+#[tokio::test]
+async fn test_voice_request_updates_the_profile() {
+    let path = temp("voice_profiles.json");
+    let mut reactor = reactor(ProfileStore::open(&path).unwrap());
+    reactor.set_output_style(style(Formality::Formal, false, ProfanityTolerance::None)).unwrap().save().unwrap();
+
+    let effects = reactor.tick_step(vec![utterance("be more casual", "seg1")]);
+    assert_eq!(reactor.output_style().formality, Formality::Neutral);
+    assert_eq!(ProfileStore::open(&path).unwrap().style().formality, Formality::Formal, "No file I/O inside the tick");
+    let snapshot = effects.iter().find_map(|e| match e {
+        SideEffect::PersistProfiles(snapshot) => Some(snapshot.clone()),
+        _ => None,
+    }).expect("The profiles are handed to the driver");
+    let confirmed = effects.iter().any(|e| matches!(e,
+        SideEffect::RequestSpeech { intent: SpeechIntent::Confirmation(d), .. } if d == "output_style_updated"));
+    assert!(confirmed, "Acknowledged");
+    assert!(reactor.state.active_intents().is_empty(), "A setting, not an utterance");
+
+    // A later fragment of the same segment is the same request
+    let effects = reactor.tick_step(vec![utterance("be more casual please", "seg1")]);
+    assert_eq!(reactor.output_style().formality, Formality::Neutral);
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::PersistProfiles(_))));
+
+    snapshot.save().unwrap();
+    assert_eq!(ProfileStore::open(&path).unwrap().style().formality, Formality::Neutral, "Saved with the profile");
+}
+
+#[tokio::test]
+async fn test_style_request_waits_for_the_final_transcript() {
+    let mut reactor = reactor(ProfileStore::new());
+
+    let effects = reactor.tick_step(vec![transcript("be more formal", "seg1", false)]);
+    assert_eq!(reactor.output_style(), OutputStyle::default(), "A fragment can still turn around");
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::PersistProfiles(_))));
+
+    reactor.tick_step(vec![transcript("be more formal", "seg1", true)]);
+    assert_eq!(reactor.output_style().formality, Formality::Formal);
+}
@@ -1,21 +1,26 @@
 use tokio::sync::mpsc;
-use nexus::kernel::reactor::Reactor;
-use nexus::kernel::event::{Event, InputEvent, OutputStatus};
+use nexus::kernel::epoch::EpochPolicy;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::event::{Event, InputEvent};
 use nexus::planner::types::{PlanningEpoch, Intent};
 use nexus::kernel::time::Tick;
 
 #[tokio::test]
 async fn test_a_stale_intent_rejection() {
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    // Version 0 is the debug injection epoch in debug builds: turn that off so the plan is just stale
+    let epoch = EpochPolicy { allow_debug_injections: false, ..Default::default() };
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig { epoch, ..Default::default() });
 
     // Initial State Version: 0. Tick: 0.
-    assert_eq!(reactor.state.version, 0);
+    assert_eq!(reactor.state.version(), 0);
     
     // Simulate Input Event to increment State Version
+    // (one input tick applies several deltas: tick, user input, presence, latents)
     let input = InputEvent::text("User", "Hi");
     reactor.tick_step(vec![Event::Input(input)]);
-    assert_eq!(reactor.state.version, 1);
+    let version = reactor.state.version();
+    assert!(version >= 1);
     
     // Now inject a "Late" Plan from Epoch (Tick 0, Version 0)
     let stale_plan = Event::PlanProposed(
@@ -29,8 +34,8 @@ async fn test_a_stale_intent_rejection() {
     // VERIFY: No effects, No output proposed. Stale plan ignored.
     assert!(effects.is_empty(), "Stale plan should not produce effects");
     assert!(reactor.state.active_outputs().is_empty(), "Stale plan should not mutate state");
-    // Explicit Invariant Assertion: State version must be exactly what we expect (1), proving no hidden delta.
-    assert_eq!(reactor.state.version, 1, "State version should not change after rejecting stale plan");
+    // Explicit Invariant Assertion: only the tick itself advanced the version, proving no hidden delta.
+    assert_eq!(reactor.state.version(), version + 1, "State version should not change after rejecting stale plan");
     
     println!("Test A Passed: Stale Intent Rejected");
 }
@@ -46,7 +51,7 @@ async fn test_b_cancellation_safety() {
     // This test covers the "Trigger" case.
     
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // 1. Trigger Planning (Tick 0)
     // No inputs, no outputs -> Dispatch happens
@@ -61,8 +66,8 @@ async fn test_b_cancellation_safety() {
     // 3. Assert State Version Advanced
     // Note: Use >= 1, as internal logic might trigger multiple deltas (e.g. cancellation checks).
     // The critical invariant is that version != 0 (Tick 0 Epoch), so the old plan is stale.
-    println!("State Version after Input: {}", reactor.state.version);
-    assert!(reactor.state.version >= 1, "State should advance on input, ensuring any surviving plan is Stale");
+    println!("State Version after Input: {}", reactor.state.version());
+    assert!(reactor.state.version() >= 1, "State should advance on input, ensuring any surviving plan is Stale");
     
     println!("Test B Passed: Input triggers state advancement (and planner abort via reactor logic)");
 }
@@ -70,7 +75,7 @@ async fn test_b_cancellation_safety() {
 #[tokio::test]
 async fn test_valid_intent_accepted() {
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
 
     // Current State Version: 0
     let valid_plan = Event::PlanProposed(
@@ -78,7 +83,7 @@ async fn test_valid_intent_accepted() {
         Intent::BeginResponse { confidence: 0.99 }
     );
     
-    let effects = reactor.tick_step(vec![valid_plan]);
+    reactor.tick_step(vec![valid_plan]);
     
     // VERIFY: Output Proposed
     assert!(!reactor.state.active_outputs().is_empty(), "Valid intent should be accepted");
@@ -89,7 +94,7 @@ async fn test_valid_intent_accepted() {
 #[tokio::test]
 async fn test_c_invalid_plan_safety() {
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // Behavior Check: If Planner fails (Malformed JSON) -> Sends DoNothing.
     // Reactor must handle DoNothing by doing... nothing.
@@ -1,5 +1,5 @@
 use nexus::kernel::event::{Event, InputEvent, InputContent, VisualSignal, Output};
-use nexus::kernel::reactor::Reactor;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
 use nexus::kernel::state::StateDelta;
 use nexus::kernel::time::Tick;
 use tokio::sync::mpsc;
@@ -9,7 +9,7 @@ use tokio::sync::mpsc;
 async fn test_phase3_1_context_shift_interruption() {
     // Test 2: Context Shift Interrupt (Hash change cancels output)
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // 1. Setup: System is speaking/displaying
     let output = Output {
@@ -29,6 +29,7 @@ async fn test_phase3_1_context_shift_interruption() {
             hash: 0xDEADBEEF,
             distance: 10,
         }),
+        captured_at: None,
     });
     
     // Run Tick
@@ -48,7 +49,7 @@ async fn test_phase3_1_context_shift_interruption() {
 async fn test_phase3_2_stability_dynamics() {
     // Test 3: Stability Gating (Unstable hash prevention)
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // 1. Stable Inputs
     // Inject "Good" update (Distance 0 < 5)
@@ -59,6 +60,7 @@ async fn test_phase3_2_stability_dynamics() {
                 hash: 0xABC,
                 distance: 0,
             }),
+            captured_at: None,
         });
         reactor.tick_step(vec![update]);
     }
@@ -66,10 +68,10 @@ async fn test_phase3_2_stability_dynamics() {
     // Physics: Decay (-0.01) happens BEFORE Boost (+0.1) in each tick.
     // Net per tick = +0.09.
     // 10 ticks = 0.9.
-    assert!(reactor.state.visual.stability_score >= 0.9, "Stability should rise to ~0.9");
+    assert!(reactor.state.visual().stability_score >= 0.9, "Stability should rise to ~0.9");
     
     // 2. Unstable Input
-    let score_before = reactor.state.visual.stability_score;
+    let score_before = reactor.state.visual().stability_score;
     
     // Inject "Bad" update (Distance 10 > 5)
     let bad_update = Event::Input(InputEvent {
@@ -78,10 +80,11 @@ async fn test_phase3_2_stability_dynamics() {
             hash: 0xDEF,
             distance: 10,
         }),
+        captured_at: None,
     });
     reactor.tick_step(vec![bad_update]);
     
-    let score_after = reactor.state.visual.stability_score;
+    let score_after = reactor.state.visual().stability_score;
     
     // Should drop by 0.3 (penalty) + 0.01 (decay) = ~0.31
     let drop = score_before - score_after;
@@ -89,11 +92,11 @@ async fn test_phase3_2_stability_dynamics() {
     assert!(drop < 0.4, "Stability shouldn't drop too much");
     
     // 3. Silence Decay
-    let score_after_drop = reactor.state.visual.stability_score;
+    let score_after_drop = reactor.state.visual().stability_score;
     // Advance tick without update
     reactor.tick_step(vec![]);
     // Should drop by 0.01
-    let score_final = reactor.state.visual.stability_score;
+    let score_final = reactor.state.visual().stability_score;
     
     assert!(score_final < score_after_drop, "Stability should have decayed");
     assert!((score_after_drop - score_final - 0.01).abs() < 0.001, "Decay should be exactly 0.01");
@@ -106,16 +109,16 @@ async fn test_phase3_3_no_hallucination_on_silence() {
     // System should NOT invent a context change.
     
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // Explicitly set hash to something
-    reactor.state.visual.hash = 0x123;
+    reactor.state.reduce(StateDelta::VisualStateUpdate { hash: 0x123, stability: 1.0 });
     
     // Run empty ticks
     reactor.tick_step(vec![]);
     
     // Validate Hash unchanging
-    assert_eq!(reactor.state.visual.hash, 0x123);
+    assert_eq!(reactor.state.visual().hash, 0x123);
     
     // Validate no cancellation signal (implicit: active outputs remain active)
     let output = Output {
@@ -1,5 +1,5 @@
 use nexus::kernel::event::{Event, InputEvent, InputContent, AudioSignal, VisualSignal, Output};
-use nexus::kernel::reactor::Reactor;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
 use nexus::kernel::state::StateDelta;
 use nexus::kernel::time::Tick;
 use tokio::sync::mpsc;
@@ -9,7 +9,7 @@ use nexus::kernel::latent::{LatentSlot, Modality};
 async fn test_phase5_1_decay_physics() {
     // Test 1: Decay Physics
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // Manually inject a Latent Slot
     let slot = LatentSlot {
@@ -26,7 +26,7 @@ async fn test_phase5_1_decay_physics() {
     reactor.tick_step(vec![]);
     
     // Assert Confidence Drop
-    let slot = &reactor.state.latents.slots[0];
+    let slot = &reactor.state.latents().slots[0];
     assert!(slot.confidence < 1.0, "Confidence should decay");
     assert!(slot.confidence > 0.8, "Confidence should be around 0.9");
     
@@ -37,7 +37,7 @@ async fn test_phase5_1_decay_physics() {
     
     // Should be significantly lower
     // 0.9 ^ 10 ~ 0.34
-    if let Some(slot) = reactor.state.latents.slots.first() {
+    if let Some(slot) = reactor.state.latents().slots.first() {
          assert!(slot.confidence < 0.5, "Confidence should decay exponentially");
     } else {
          // It might be pruned if < 0.05. But 0.35 > 0.05.
@@ -48,31 +48,33 @@ async fn test_phase5_1_decay_physics() {
 async fn test_phase5_2_modality_separation() {
     // Test 2: Audio (Fast) vs Vision (Slow)
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // Inject Audio
     reactor.tick_step(vec![Event::Input(InputEvent { 
         source: "Audio".into(), 
-        content: InputContent::Audio(AudioSignal::SpeechStart) 
+        content: InputContent::Audio(AudioSignal::SpeechStart),
+        captured_at: None,
     })]);
     
     // Inject Vision
     reactor.tick_step(vec![Event::Input(InputEvent { 
         source: "Vision".into(), 
-        content: InputContent::Visual(VisualSignal::PerceptUpdate { hash: 123, distance: 0 }) 
+        content: InputContent::Visual(VisualSignal::PerceptUpdate { hash: 123, distance: 0 }),
+        captured_at: None,
     })]);
     
     // Get slots
-    let audio_conf = reactor.state.latents.slots.iter().find(|s| s.modality == Modality::Audio).unwrap().confidence;
-    let visual_conf = reactor.state.latents.slots.iter().find(|s| s.modality == Modality::Visual).unwrap().confidence;
+    let audio_conf = reactor.state.latents().slots.iter().find(|s| s.modality == Modality::Audio).unwrap().confidence;
+    let visual_conf = reactor.state.latents().slots.iter().find(|s| s.modality == Modality::Visual).unwrap().confidence;
     
     // Run 5 ticks
     for _ in 0..5 {
         reactor.tick_step(vec![]);
     }
     
-    let audio_conf_after = reactor.state.latents.slots.iter().find(|s| s.modality == Modality::Audio).unwrap().confidence;
-    let visual_conf_after = reactor.state.latents.slots.iter().find(|s| s.modality == Modality::Visual).unwrap().confidence;
+    let audio_conf_after = reactor.state.latents().slots.iter().find(|s| s.modality == Modality::Audio).unwrap().confidence;
+    let visual_conf_after = reactor.state.latents().slots.iter().find(|s| s.modality == Modality::Visual).unwrap().confidence;
     
     let audio_decay = audio_conf - audio_conf_after;
     let visual_decay = visual_conf - visual_conf_after;
@@ -84,7 +86,7 @@ async fn test_phase5_2_modality_separation() {
 async fn test_phase5_3_invariant_latent_non_authority() {
     // Test 3: Latents cannot override STOP
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // 1. Proposed Output
     let output = Output {
@@ -126,15 +128,16 @@ async fn test_phase5_3_invariant_latent_non_authority() {
 async fn test_phase5_4_snapshot_integration() {
     // Test 4: Snapshot contains latent summary
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // Inject Audio
     reactor.tick_step(vec![Event::Input(InputEvent { 
         source: "Audio".into(), 
-        content: InputContent::Audio(AudioSignal::SpeechStart) 
+        content: InputContent::Audio(AudioSignal::SpeechStart),
+        captured_at: None,
     })]);
     
-    let snapshot = reactor.state.snapshot(reactor.tick);
+    let snapshot = reactor.state.snapshot(reactor.tick, reactor.lhim.get_context(&reactor.state));
     println!("Snapshot Summary: {}", snapshot.latent_summary);
     
     assert!(snapshot.latent_summary.contains("Audio"), "Snapshot must mention Audio");
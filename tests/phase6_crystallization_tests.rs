@@ -1,5 +1,5 @@
-use nexus::kernel::event::{Event, InputEvent, OutputStatus};
-use nexus::kernel::reactor::Reactor;
+use nexus::kernel::event::{AudioSignal, Event, InputContent, InputEvent, OutputStatus};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
 use nexus::kernel::state::StateDelta;
 use nexus::kernel::time::Tick;
 use tokio::sync::mpsc;
@@ -55,7 +55,7 @@ fn test_phase6_0_uncertainty_math() {
 async fn test_phase6_1_gate_instability() {
     // Test 1: High Uncertainty -> Gate DENIES output
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // Inject Low Confidence Latent (Uncertainty ~ 0.9)
     let slot = LatentSlot {
@@ -69,7 +69,7 @@ async fn test_phase6_1_gate_instability() {
     
     // Propose Response
     let intent = Intent::BeginResponse { confidence: 0.9 }; // Planner is confident, but Gate checks State
-    let epoch = PlanningEpoch { tick: reactor.tick, state_version: reactor.state.version };
+    let epoch = PlanningEpoch { tick: reactor.tick, state_version: reactor.state.version() };
     
     // Run Step
     reactor.tick_step(vec![Event::PlanProposed(epoch, intent)]);
@@ -82,7 +82,7 @@ async fn test_phase6_1_gate_instability() {
 async fn test_phase6_2_interruption_retraction() {
     // Test 2: SoftCommit -> Interruption -> Retracted (Canceled)
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // 1. Manually inject a SoftCommit output (simulating AllowedPartial)
     let output_id = nexus::kernel::event::OutputId { tick: 0, ordinal: 0 };
@@ -110,13 +110,17 @@ async fn test_phase6_3_silence_preference() {
     // Test 3: Planner wants to speak, Gate says No (Silence)
     // Similar to Test 1 but explicitly checking for Silence
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // Ensure UserSpeaking = true -> Gate Deny
-    reactor.state.user_speaking = true;
+    reactor.state.reduce(StateDelta::InputReceived(InputEvent {
+        source: "VAD".to_string(),
+        content: InputContent::Audio(AudioSignal::SpeechStart),
+        captured_at: None,
+    }));
     
     let intent = Intent::BeginResponse { confidence: 1.0 };
-    let epoch = PlanningEpoch { tick: reactor.tick, state_version: reactor.state.version };
+    let epoch = PlanningEpoch { tick: reactor.tick, state_version: reactor.state.version() };
     
     reactor.tick_step(vec![Event::PlanProposed(epoch, intent)]);
     
@@ -128,7 +132,7 @@ async fn test_phase6_4_hard_vs_soft() {
     // Test 4: Stable State -> HardCommit
     // Note: Depends on Gate Logic and SymbolicSnapshot extraction
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // Inject Stable Latent (Vision)
     let slot = LatentSlot {
@@ -142,7 +146,7 @@ async fn test_phase6_4_hard_vs_soft() {
     
     // Propose Response
     let intent = Intent::BeginResponse { confidence: 1.0 };
-    let epoch = PlanningEpoch { tick: reactor.tick, state_version: reactor.state.version };
+    let epoch = PlanningEpoch { tick: reactor.tick, state_version: reactor.state.version() };
     
     reactor.tick_step(vec![Event::PlanProposed(epoch, intent)]);
     
@@ -159,7 +163,7 @@ async fn test_phase6_4_hard_vs_soft() {
 async fn test_phase6_5_monotonic_commitment() {
     // Test 5: SoftCommit DOES NOT upgrade to HardCommit automatically
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
     
     // 1. Create SoftCommit Output
     let output_id = nexus::kernel::event::OutputId { tick: 0, ordinal: 0 };
@@ -1,11 +1,10 @@
-use nexus::kernel::event::{Output, OutputId, OutputStatus, Event};
+use nexus::kernel::event::{Output, OutputId, OutputStatus};
 use nexus::kernel::time::Tick;
 use nexus::kernel::crystallizer::{SymbolicSnapshot, Claim as SnapshotClaim};
 use nexus::memory::{
     MemoryObserver, MemoryConsolidator, InMemoryEpisodicStore, FileSemanticStore,
     EpisodicStore, SemanticStore, Claim, EntityId, Predicate, ClaimValue, Modality
 };
-use std::path::PathBuf;
 use std::fs;
 
 // Helper to create a dummy output
@@ -143,6 +142,7 @@ async fn test_revocation() {
         created_at_tick: 100,
         last_reinforced_tick: 100,
         decay_rate: 0.01,
+        created_at_ms: None,
     });
 
     // Store "Name is Alex" (Later, same subject/predicate)
@@ -158,6 +158,7 @@ async fn test_revocation() {
         created_at_tick: 200,
         last_reinforced_tick: 200,
         decay_rate: 0.01,
+        created_at_ms: None,
     });
     
     // Retrieve
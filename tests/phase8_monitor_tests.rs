@@ -1,18 +1,9 @@
-use nexus::kernel::event::{Output, OutputId, OutputStatus, Event, InputEvent, InputContent};
 use nexus::kernel::time::Tick;
-use nexus::kernel::crystallizer::{self, CrystallizationDecision, SymbolicSnapshot, Claim};
+use nexus::kernel::crystallizer::{self, CrystallizationDecision};
 use nexus::kernel::state::{SharedState, StateDelta, MetaLatents};
 use nexus::monitor::{SelfObservationMonitor, SelfObservation};
 
 
-// Helper to create a dummy input
-fn create_text_input(content: &str) -> InputEvent {
-    InputEvent {
-        content: InputContent::Text(content.to_string()),
-        source: "test".to_string(), 
-    }
-}
-
 // Helper to create state with latents
 fn create_state_with_uncertainty(uncertainty: f32) -> SharedState {
     let mut state = SharedState::new();
@@ -25,13 +16,13 @@ fn create_state_with_uncertainty(uncertainty: f32) -> SharedState {
     // We need to inject a slot.
     
     use nexus::kernel::latent::{LatentSlot, Modality};
-    state.latents.slots.push(LatentSlot {
+    state.reduce(StateDelta::LatentUpdate { slot: LatentSlot {
         values: vec![0.0],
         confidence: 1.0 - uncertainty, // If uncertainty is 0.8, confidence is 0.2
         created_at: Tick { frame: 0 },
         modality: Modality::Text,
         decay_rate: 0.0,
-    });
+    } });
     
     state
 }
@@ -61,22 +52,16 @@ fn test_unprompted_correction_monitor() {
 #[test]
 fn test_confidence_adjustment_gate() {
     // Test 2: High Penalty -> Gate Denies 
-    let mut state = create_state_with_uncertainty(0.65); // Uncertainty 0.65
     // Default Deny is 0.8. Default Partial is 0.4.
-    // With 0.65, normally it might be AllowPartial or Delay (if > 0.6).
-    // Let's set it to exactly 0.7 to be sure it's barely allowed or delayed.
-    
-    state.latents.slots[0].confidence = 0.3; // Uncertainty = 0.7
+    // Exactly 0.7 to be sure it's barely allowed or delayed.
+    let mut state = create_state_with_uncertainty(0.7); // Uncertainty = 0.7
     
     // Validate baseline: 0.7 < 0.8 (Deny). So it is NOT Denied by default logic (only > 0.8 is denied).
     // Crystallizer: if > 0.8 return Deny. if > 0.6 return Delay.
     // So baseline is Delay.
     
-    let decision = crystallizer::check_gate(&state);
-    // assert_eq!(decision, CrystallizationDecision::Delay { ms: 500 }); // Assuming logic
-    
     // Now apply Penalty
-    state.meta_latents.confidence_penalty = 1.0; // Max penalty
+    state.reduce(StateDelta::MetaLatentUpdate { delta: MetaLatents { confidence_penalty: 1.0, ..state.meta_latents().clone() } }); // Max penalty
     // Effective Threshold = 0.8 - (1.0 * 0.3) = 0.5.
     // Uncertainty 0.7 > 0.5? Yes.
     // Expect: Deny.
@@ -123,7 +108,7 @@ fn test_silence_logic() {
     assert_eq!(decision, CrystallizationDecision::AllowPartial, "Baseline should allow partial");
     
     // Apply Penalty
-    state.meta_latents.confidence_penalty = 1.0;
+    state.reduce(StateDelta::MetaLatentUpdate { delta: MetaLatents { confidence_penalty: 1.0, ..state.meta_latents().clone() } });
     // Effective Deny Threshold = 0.5. Input 0.45.
     // Wait, 0.45 < 0.5. So it shouldn't be Denied by the *modified* Deny threshold?
     // Crystallizer logic: if uncertainty > effective_deny_threshold { Deny }.
@@ -136,7 +121,7 @@ fn test_silence_logic() {
     // But let's try 0.55.
     
     let mut state2 = create_state_with_uncertainty(0.55);
-    state2.meta_latents.confidence_penalty = 1.0;
+    state2.reduce(StateDelta::MetaLatentUpdate { delta: MetaLatents { confidence_penalty: 1.0, ..state2.meta_latents().clone() } });
     // 0.55 > 0.5 -> Deny.
     
     let decision2 = crystallizer::check_gate(&state2);
@@ -1,109 +1,128 @@
 use nexus::kernel::state::{SharedState, StateDelta};
 use nexus::kernel::time::Tick;
-use nexus::intent::{LongHorizonIntentManager, IntentStatus, IntentId};
-use std::collections::HashMap;
+use nexus::kernel::intent::long_horizon::{LongHorizonIntentManager, IntentStatus, IntentId};
+use nexus::kernel::intent::types::{IntentCandidate, IntentHypothesis, IntentStability};
+use nexus::kernel::telemetry::recorder::TelemetryRecorder;
+
+fn trip_candidate() -> IntentCandidate {
+    IntentCandidate {
+        id: "TestTrip".to_string(),
+        hypothesis: IntentHypothesis::Command,
+        confidence: 0.9,
+        source_symbol_ids: vec!["seg_trip".to_string()],
+        semantic_hash: 42,
+        stability: IntentStability::Stable,
+    }
+}
 
 // Helper to create state with an active intent
-fn create_state_with_intent(lhim: &LongHorizonIntentManager) -> (SharedState, IntentId) {
+fn create_state_with_intent(lhim: &mut LongHorizonIntentManager, telemetry: &mut TelemetryRecorder) -> (SharedState, IntentId) {
     let mut state = SharedState::new();
     let tick = Tick { frame: 0 };
-    
+
     // Manual register via delta
-    let delta = lhim.register_goal("TestTrip".to_string(), tick);
-    if let StateDelta::IntentUpdate { intent } = &delta {
-        let id = intent.id;
-        state.reduce(delta);
-        (state, id)
-    } else {
-        panic!("Failed to register goal");
+    let deltas = lhim.register_intent(&trip_candidate(), &state, tick, telemetry);
+    match deltas.first() {
+        Some(StateDelta::LongHorizonIntentUpdate(intent)) => {
+            let id = intent.id.clone();
+            for d in deltas { state.reduce(d); }
+            (state, id)
+        }
+        _ => panic!("Failed to register goal"),
     }
 }
 
 #[test]
 fn test_graceful_resumption() {
-    let lhim = LongHorizonIntentManager::new();
-    let (mut state, id) = create_state_with_intent(&lhim);
-    let mut tick = Tick { frame: 10 };
-    
+    let mut telemetry = TelemetryRecorder::new();
+    let mut lhim = LongHorizonIntentManager::new();
+    let (mut state, id) = create_state_with_intent(&mut lhim, &mut telemetry);
+    let tick = Tick { frame: 10 };
+
     // 1. Interrupt!
-    let deltas = lhim.handle_interruption(&state);
+    let deltas = lhim.handle_interruption(&state, tick, &mut telemetry);
     for d in deltas { state.reduce(d); }
-    
+
     // Verify Suspended
-    let intent = state.active_intents.get(&id).unwrap();
+    let intent = state.active_intents().get(&id).unwrap();
     assert_eq!(intent.status, IntentStatus::Suspended, "Interruption must suspend intent");
-    
+
     // 2. Reinforce (Resume)
-    if let Some(delta) = lhim.handle_reinforcement(id, &state, tick) {
-        state.reduce(delta);
+    for d in lhim.register_intent(&trip_candidate(), &state, tick, &mut telemetry) {
+        state.reduce(d);
     }
-    
+
     // Verify Active
-    let intent = state.active_intents.get(&id).unwrap();
+    let intent = state.active_intents().get(&id).unwrap();
     assert_eq!(intent.status, IntentStatus::Active, "Reinforcement must resume intent");
 }
 
 #[test]
 fn test_abandonment_decay() {
-    let lhim = LongHorizonIntentManager::new();
-    let (mut state, id) = create_state_with_intent(&lhim);
-    
-    // Initial confidence ~0.5
-    let start_conf = state.active_intents.get(&id).unwrap().confidence;
-    
+    let mut telemetry = TelemetryRecorder::new();
+    let mut lhim = LongHorizonIntentManager::new();
+    let (mut state, id) = create_state_with_intent(&mut lhim, &mut telemetry);
+
+    let start_conf = state.active_intents().get(&id).unwrap().decay_score;
+
     // Run 50 ticks of decay
     for i in 0..50 {
-        let t = Tick { frame: 10 + i };
-        let deltas = lhim.tick(t, &state);
+        let t = Tick { frame: 10 + i * 10 };
+        let deltas = lhim.tick(t, &state, &mut telemetry);
         for d in deltas { state.reduce(d); }
     }
-    
-    let end_conf = state.active_intents.get(&id).unwrap().confidence;
+
+    let end_conf = state.active_intents().get(&id).unwrap().decay_score;
     assert!(end_conf < start_conf, "Confidence must decay over time without reinforcement");
-    
-    // Run until dissolved (approx 200 ticks at 1% rate)
-    for i in 0..300 {
-         let t = Tick { frame: 60 + i };
-        let deltas = lhim.tick(t, &state);
+
+    // Run until dissolved (decay is delta-based: ~7700 ticks at 0.9997/tick)
+    for i in 0..100 {
+        let t = Tick { frame: 600 + i * 100 };
+        let deltas = lhim.tick(t, &state, &mut telemetry);
         for d in deltas { state.reduce(d); }
     }
-    
-    let intent = state.active_intents.get(&id).unwrap();
-    assert_eq!(intent.status, IntentStatus::Dissolved, "Intent must dissolve when confidence drops low enough");
+
+    let intent = state.active_intents().get(&id).unwrap();
+    assert_eq!(intent.status, IntentStatus::Invalidated, "Intent must dissolve when confidence drops low enough");
 }
 
 #[test]
 fn test_interruption_penalty() {
-    let lhim = LongHorizonIntentManager::new();
-    let (mut state, id) = create_state_with_intent(&lhim);
-    let start_conf = state.active_intents.get(&id).unwrap().confidence;
+    let mut telemetry = TelemetryRecorder::new();
+    let mut lhim = LongHorizonIntentManager::new();
+    let (mut state, id) = create_state_with_intent(&mut lhim, &mut telemetry);
+    let start_conf = state.active_intents().get(&id).unwrap().decay_score;
 
     // Interrupt
-    let deltas = lhim.handle_interruption(&state);
+    let deltas = lhim.handle_interruption(&state, Tick { frame: 1 }, &mut telemetry);
     for d in deltas { state.reduce(d); }
-    
-    let after_interruption = state.active_intents.get(&id).unwrap();
+
+    let after_interruption = state.active_intents().get(&id).unwrap();
     assert_eq!(after_interruption.status, IntentStatus::Suspended);
-    assert!(after_interruption.confidence < start_conf, "Interruption must apply penalty");
+    assert!(after_interruption.decay_score < start_conf, "Interruption must apply penalty");
 }
 
 #[test]
 fn test_no_agentic_drift() {
-    // Verify that LHIM outputs ONLY StateDelta::IntentUpdate, nothing else.
-    // This is structurally guaranteed by the return types of LHIM methods, 
+    // Verify that LHIM outputs ONLY StateDelta::LongHorizonIntentUpdate, nothing else.
+    // This is structurally guaranteed by the return types of LHIM methods,
     // but we can verify that the Manager doesn't return `BeginResponse` or similar if we were mocking it.
     // Here we strictly check that `get_context` is pure data.
-    
-    let lhim = LongHorizonIntentManager::new();
-    let (mut state, id) = create_state_with_intent(&lhim);
-    
+
+    let mut telemetry = TelemetryRecorder::new();
+    let mut lhim = LongHorizonIntentManager::new();
+    let (mut state, _id) = create_state_with_intent(&mut lhim, &mut telemetry);
+
     let context = lhim.get_context(&state);
-    assert_eq!(context.active_focus, Some("TestTrip".to_string()));
-    
+    assert_eq!(context.active_focus, Some("Command".to_string()));
+
     // Now interrupt
-    let deltas = lhim.handle_interruption(&state);
+    let deltas = lhim.handle_interruption(&state, Tick { frame: 1 }, &mut telemetry);
+    for d in &deltas {
+        assert!(matches!(d, StateDelta::LongHorizonIntentUpdate(_)), "LHIM may only emit intent updates");
+    }
     for d in deltas { state.reduce(d); }
-    
+
     // Context should be None/Weak if suspended?
     // Implementation: "active_intents.filter(Active)"
     // So Suspended intents do NOT show up in context! Excellent.
@@ -1,10 +1,10 @@
 use nexus::kernel::presence::{PresenceGraph, PresenceState, PresenceRequest};
-use nexus::kernel::state::{SharedState, StateDelta};
+use nexus::kernel::state::SharedState;
 
 #[test]
 fn test_presence_initial_state() {
     let state = SharedState::default();
-    assert_eq!(state.presence, PresenceState::Dormant, "System must boot into Dormant state (Silence Default)");
+    assert_eq!(state.presence(), PresenceState::Dormant, "System must boot into Dormant state (Silence Default)");
 }
 
 #[test]
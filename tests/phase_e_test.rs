@@ -1,63 +1,65 @@
 use tokio::sync::mpsc;
 use nexus::kernel::event::{Event, InputEvent, InputContent, AudioSignal};
-use nexus::kernel::reactor::Reactor;
-use std::time::Duration;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
 
 #[tokio::test]
 async fn test_phase_e_gated_asr() {
     // 1. Setup Reactor
-    let (tx, rx) = mpsc::channel(100);
+    let (tx, _rx) = mpsc::channel(100);
     // Use a separate channel for driver loop to avoid stealing events? 
     // Actually Reactor::new takes rx and tx.
     // We need to spawn the reactor in a background task to process events.
     
-    let (tx_in, rx_in) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx_in, tx.clone());
+    let (_tx_in, rx_in) = mpsc::channel(100);
+    let mut reactor = Reactor::new(rx_in, tx.clone(), ReactorConfig::default());
     
     // Spawn Reactor Driver (Mocked Run Loop)
     // We can't easily run the full `reactor.run()` because it has infinite loop and SideEffects.
     // But we CAN use `tick_step`.
     
-    // === Test 1: Buffering & Gate Closed ===
-    println!("Step 1: Check Buffering & Gate Closed");
+    // === Test 1: Buffering & Segment Close ===
+    println!("Step 1: Check Buffering & Segment Close");
     
     // Simulate Speech Start (High Energy)
     // We bypass audio_monitor and inject VAD signals directly for precision
     let start_evt = Event::Input(InputEvent {
         source: "Test".to_string(),
         content: InputContent::Audio(AudioSignal::SpeechStart),
+        captured_at: None,
     });
     
-    let effects = reactor.tick_step(vec![start_evt]);
-    assert!(reactor.state.active_segment_id.is_some(), "Should have active segment after SpeechStart");
-    let initial_seg_id = reactor.state.active_segment_id.clone().unwrap();
+    reactor.tick_step(vec![start_evt]);
+    assert!(reactor.state.active_segment_id().is_some(), "Should have active segment after SpeechStart");
+    let initial_seg_id = reactor.state.active_segment_id().cloned().unwrap();
 
     // Simulate Audio Chunks
     let chunk_evt = Event::Input(InputEvent {
         source: "Test".to_string(),
-        content: InputContent::AudioChunk(vec![0.5; 480]), // 10ms chunk
+        content: InputContent::AudioChunk(vec![0.5; 480]), // 10ms chunk,
+        captured_at: None,
     });
     let _ = reactor.tick_step(vec![chunk_evt]);
     
     // Verify frames appended
-    let seg = reactor.state.audio_segments.get(&initial_seg_id).unwrap();
+    let seg = reactor.state.audio_segments().get(&initial_seg_id).unwrap();
     assert_eq!(seg.frames.len(), 480, "Should buffer frames");
 
     // Simulate Speech End
     let end_evt = Event::Input(InputEvent {
         source: "Test".to_string(),
         content: InputContent::Audio(AudioSignal::SpeechEnd),
+        captured_at: None,
     });
     let effects_end = reactor.tick_step(vec![end_evt]);
     
     // Verify Segment Finalized
-    assert!(reactor.state.active_segment_id.is_none(), "Active segment should be cleared");
-    let seg_final = reactor.state.audio_segments.get(&initial_seg_id).unwrap();
+    assert!(reactor.state.active_segment_id().is_none(), "Active segment should be cleared");
+    let seg_final = reactor.state.audio_segments().get(&initial_seg_id).unwrap();
     assert_eq!(seg_final.status, nexus::kernel::audio::segment::SegmentStatus::Pending, "Segment should be Pending");
     
-    // Verify NO Transcription SideEffect (Gate Closed)
-    let has_transcription = effects_end.iter().any(|e| matches!(e, nexus::kernel::scheduler::SideEffect::RequestTranscription { .. }));
-    assert!(!has_transcription, "Should NOT request transcription automatically");
+    // Speech end asks the driver to transcribe the closed segment; it stays Pending until a request is granted
+    let has_transcription = effects_end.iter().any(|e| matches!(e, nexus::kernel::scheduler::SideEffect::RequestTranscription { segment_id } if *segment_id == initial_seg_id));
+    assert!(has_transcription, "Should request transcription of the finished segment");
 
     // === Test 2: Gate Open (Explicit Request) ===
     println!("Step 2: Check Gate Open");
@@ -65,6 +67,7 @@ async fn test_phase_e_gated_asr() {
     let request_evt = Event::Input(InputEvent {
         source: "Planner".to_string(),
         content: InputContent::TranscriptionRequest { segment_id: initial_seg_id.clone() },
+        captured_at: None,
     });
     
     let effects_req = reactor.tick_step(vec![request_evt]);
@@ -78,7 +81,7 @@ async fn test_phase_e_gated_asr() {
     }
     
     // Verify State Update
-    let seg_transcribing = reactor.state.audio_segments.get(&initial_seg_id).unwrap();
+    let seg_transcribing = reactor.state.audio_segments().get(&initial_seg_id).unwrap();
     assert_eq!(seg_transcribing.status, nexus::kernel::audio::segment::SegmentStatus::Transcribing, "Status should be Transcribing");
 
     // === Test 3: Provisional Text Ingestion ===
@@ -90,12 +93,16 @@ async fn test_phase_e_gated_asr() {
             content: "Hello World".to_string(),
             confidence: 0.9,
             source_id: initial_seg_id.clone(),
-        }
+            words: Vec::new(),
+            language: None,
+            is_final: true,
+        },
+        captured_at: None,
     });
     
     let _ = reactor.tick_step(vec![text_evt]);
     
-    let seg_done = reactor.state.audio_segments.get(&initial_seg_id).unwrap();
+    let seg_done = reactor.state.audio_segments().get(&initial_seg_id).unwrap();
     assert_eq!(seg_done.status, nexus::kernel::audio::segment::SegmentStatus::Transcribed, "Status should be Transcribed");
     assert_eq!(seg_done.transcription.as_deref(), Some("Hello World"));
 
@@ -1,5 +1,5 @@
 use nexus::kernel::audio::monitor::AudioMonitor;
-use nexus::kernel::event::AudioSignal;
+use nexus::kernel::event::{AudioSignal, OutputId, PlaybackId};
 
 #[test]
 fn test_adaptive_thresholding() {
@@ -73,3 +73,26 @@ fn test_adaptive_thresholding() {
 
     println!("Step 4 passed: Recovery successful.");
 }
+
+#[test]
+fn test_overlapping_playback_keeps_system_speaking() {
+    let mut monitor = AudioMonitor::new(48000);
+    let a: PlaybackId = OutputId { tick: 1, ordinal: 0 }.into();
+    let b: PlaybackId = uuid::Uuid::new_v4().into();
+
+    monitor.playback_started(a);
+    monitor.playback_started(b);
+    assert!(monitor.is_system_speaking());
+
+    // First stream ends while the second is still audible
+    assert!(!monitor.playback_ended(&a), "One stream still playing");
+    assert!(monitor.is_system_speaking(), "Must not clear while an output is playing");
+    assert_eq!(monitor.playing_count(), 1);
+
+    // Duplicate/late end for an already-finished stream is a no-op
+    assert!(!monitor.playback_ended(&a));
+    assert!(monitor.is_system_speaking());
+
+    assert!(monitor.playback_ended(&b), "Last stream ended");
+    assert!(!monitor.is_system_speaking());
+}
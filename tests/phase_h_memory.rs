@@ -1,12 +1,11 @@
-use nexus::kernel::reactor::Reactor;
-use nexus::kernel::event::{Event, InputEvent, InputContent, AudioSignal};
-use nexus::kernel::intent::types::{IntentState, IntentStability, IntentHypothesis};
-use nexus::kernel::memory::types::{MemoryKey, MemoryCandidate};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::event::{Event, InputEvent, InputContent};
+use nexus::kernel::intent::types::{IntentStability, IntentHypothesis};
+use nexus::kernel::memory::consent::MemoryConsentState;
+use nexus::kernel::memory::types::MemoryKey;
 use nexus::kernel::state::StateDelta;
 use nexus::kernel::time::Tick;
 use tokio::sync::mpsc;
-use std::hash::{Hash, Hasher};
-use std::collections::hash_map::DefaultHasher;
 
 fn make_stable_inquiry(text: &str, symbol_id: &str) -> Event {
     // We send ProvisionalText. The reactor will assess it.
@@ -18,14 +17,18 @@ fn make_stable_inquiry(text: &str, symbol_id: &str) -> Event {
             content: text.to_string(),
             confidence: 0.9,
             source_id: symbol_id.to_string(),
-        }
+            words: Vec::new(),
+            language: None,
+            is_final: true,
+        },
+        captured_at: None,
     })
 }
 
 #[tokio::test]
 async fn test_identity_separation() {
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx.clone());
+    let mut reactor = Reactor::new(rx, tx.clone(), ReactorConfig::default());
 
     // 1. "What is gravity?"
     reactor.tick_step(vec![make_stable_inquiry("What is gravity?", "seg1")]);
@@ -34,41 +37,41 @@ async fn test_identity_separation() {
     reactor.tick_step(vec![make_stable_inquiry("What is taxes?", "seg2")]);
 
     // Assert: 2 separate candidates
-    assert_eq!(reactor.state.memory_candidates.len(), 2, "Should have 2 distinct candidates");
+    assert_eq!(reactor.state.memory_candidates().len(), 2, "Should have 2 distinct candidates");
     
-    let keys: Vec<&MemoryKey> = reactor.state.memory_candidates.values().map(|c| &c.key).collect();
+    let keys: Vec<&MemoryKey> = reactor.state.memory_candidates().values().map(|c| &c.key).collect();
     assert_ne!(keys[0], keys[1], "Keys must differ despite both being Inquiries");
 }
 
 #[tokio::test]
 async fn test_temporal_gating() {
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx.clone());
+    let mut reactor = Reactor::new(rx, tx.clone(), ReactorConfig::default());
 
     // 1. First trigger
     reactor.tick_step(vec![make_stable_inquiry("What is time?", "seg1")]);
-    assert_eq!(reactor.state.memory_candidates.len(), 1);
-    let id = reactor.state.memory_candidates.keys().next().unwrap().clone();
+    assert_eq!(reactor.state.memory_candidates().len(), 1);
+    let id = reactor.state.memory_candidates().keys().next().unwrap().clone();
 
     // 2. Rapid fire reinforcement (same tick or close)
     // We force same key by sending same text
     reactor.tick_step(vec![make_stable_inquiry("What is time?", "seg2")]);
     reactor.tick_step(vec![make_stable_inquiry("What is time?", "seg3")]);
     
-    let cand = reactor.state.memory_candidates.get(&id).unwrap();
+    let cand = reactor.state.memory_candidates().get(&id).unwrap();
     assert_eq!(cand.reinforcement_count, 3);
     
     // 3. Advancing time BUT NOT ENOUGH (Window is 1200 ticks)
     // Current tick: ~4.
     // Consolidator checks promotion.
     // Age < Window. Should NOT promote.
-    assert!(reactor.state.long_term_memory.is_empty(), "Should NOT promote rapidly");
+    assert!(reactor.state.long_term_memory().is_empty(), "Should NOT promote rapidly");
 }
 
 #[tokio::test]
 async fn test_valid_promotion() {
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx.clone());
+    let mut reactor = Reactor::new(rx, tx.clone(), ReactorConfig::default());
 
     // 1. Create
     reactor.tick_step(vec![make_stable_inquiry("What is valid?", "seg1")]); // Count 1
@@ -83,18 +86,22 @@ async fn test_valid_promotion() {
     reactor.tick_step(vec![make_stable_inquiry("What is valid?", "seg2")]); // Count 2
     reactor.tick_step(vec![make_stable_inquiry("What is valid?", "seg3")]); // Count 3
     
-    // 4. Tick to trigger maintenance
+    // 4. The user agreed to remember it (an Inquiry is never asked about: consent comes from elsewhere)
+    let key = reactor.state.memory_candidates().values().next().unwrap().key.clone();
+    reactor.state.reduce(StateDelta::MemoryConsentResolved { key, state: MemoryConsentState::Granted, resolved_at: reactor.tick });
+
+    // 5. Tick to trigger maintenance
     reactor.tick_step(vec![]); 
 
     // Assert: Candidate gone, Record exists
-    assert!(reactor.state.memory_candidates.is_empty(), "Candidate should be promoted/removed");
-    assert_eq!(reactor.state.long_term_memory.len(), 1, "Long term memory should have 1 record");
+    assert!(reactor.state.memory_candidates().is_empty(), "Candidate should be promoted/removed");
+    assert_eq!(reactor.state.long_term_memory().len(), 1, "Long term memory should have 1 record");
 }
 
 #[tokio::test]
 async fn test_pruning() {
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx.clone());
+    let mut reactor = Reactor::new(rx, tx.clone(), ReactorConfig::default());
 
     // 1. Create
     reactor.tick_step(vec![make_stable_inquiry("Prune me", "seg1")]);
@@ -107,13 +114,13 @@ async fn test_pruning() {
     // 3. Tick
     reactor.tick_step(vec![]);
     
-    assert!(reactor.state.memory_candidates.is_empty(), "Stale candidate should be pruned");
+    assert!(reactor.state.memory_candidates().is_empty(), "Stale candidate should be pruned");
 }
 
 #[tokio::test]
 async fn test_decay() {
     let (tx, rx) = mpsc::channel(100);
-    let mut reactor = Reactor::new(rx, tx.clone());
+    let mut reactor = Reactor::new(rx, tx.clone(), ReactorConfig::default());
 
     // 1. Inject a fake Record
     let rec = nexus::kernel::memory::types::MemoryRecord {
@@ -129,6 +136,9 @@ async fn test_decay() {
         first_committed_at: Tick { frame: 0 },
         last_accessed_at: Tick { frame: 0 },
         strength: 0.15, // Near threshold (0.1)
+        topic_id: None,
+        sensitivity: None,
+        context: Default::default(),
     };
     reactor.state.reduce(StateDelta::MemoryPromoted(rec));
 
@@ -155,7 +165,7 @@ async fn test_decay() {
     // The implementation uses simple tick-based decay.
     // I will verify that `tick()` reduces strength.
     
-    let str_before = reactor.state.long_term_memory.get("mem1").unwrap().strength;
+    let str_before = reactor.state.long_term_memory().get("mem1").unwrap().strength;
     assert!(str_before < 0.15, "Strength should have decreased"); 
     // Wait, 0.15 * 0.9995 = 0.1499.
     
@@ -167,5 +177,5 @@ async fn test_decay() {
     // 0.10001 * 0.9995 < 0.1
     // 0.0999...
     
-    assert!(reactor.state.long_term_memory.is_empty(), "Weak memory should be forgotten");
+    assert!(reactor.state.long_term_memory().is_empty(), "Weak memory should be forgotten");
 }
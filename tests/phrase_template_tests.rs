@@ -0,0 +1,147 @@
+use nexus::kernel::crystallizer::CrystallizationDecision;
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::intent::types::IntentHypothesis;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::{Scheduler, SideEffect};
+use nexus::kernel::speech::planner::SpeechIntent;
+use nexus::kernel::time::Tick;
+use nexus::kernel::warm_start::WarmStart;
+use nexus::outputs::phrases::{fill, PhraseAct, PhraseTemplates, CLARIFICATION_VARIANTS};
+use nexus::outputs::realizer::{realize_resumption_variants, realize_warm_start};
+use nexus::outputs::tts_cache::canned_phrases_in;
+use nexus::planner::types::Intent;
+
+/// A complete table for `locale`: one variant per act, using every variable the act provides.
+fn complete(locale: &str) -> serde_json::Value {
+    let acts: serde_json::Map<String, serde_json::Value> = PhraseAct::ALL.iter().map(|act| {
+        let key = serde_json::to_value(act).unwrap().as_str().unwrap().to_string();
+        let vars: Vec<String> = act.variables().iter().map(|v| format!("{{{}}}", v)).collect();
+        (key.clone(), serde_json::json!([format!("[{} {}] {}", locale, key, vars.join(" ")).trim()]))
+    }).collect();
+    serde_json::json!({ locale: acts })
+}
+
+fn provisional(text: &str, source_id: &str) -> Event {
+    Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::ProvisionalText { content: text.to_string(), confidence: 0.6, source_id: source_id.to_string(), words: Vec::new(), language: None, is_final: true },
+        captured_at: None,
+    })
+}
+
+#[test]
+fn test_builtin_table_is_complete() {
+    let builtin = PhraseTemplates::builtin();
+    builtin.validate().unwrap();
+    assert_eq!(builtin.locale(), "en");
+    assert_eq!(builtin.clarifications(), CLARIFICATION_VARIANTS);
+    assert_eq!(
+        builtin.resumption(&IntentHypothesis::Statement, false, &CrystallizationDecision::AllowHard)[0],
+        "Earlier you mentioned something. Picking that back up."
+    );
+    assert!(builtin.resumption(&IntentHypothesis::Statement, false, &CrystallizationDecision::Deny).is_empty());
+    assert_eq!(fill("{a} and {b}, {a}", &[("a", "x"), ("b", "y")]), "x and y, x");
+}
+
+#[test]
+fn test_locale_with_variables() {
+    let json = serde_json::json!({ "locale": "de", "templates": complete("de") }).to_string();
+    let templates = PhraseTemplates::from_json(&json).unwrap();
+    assert_eq!(templates.locale(), "de");
+    assert_eq!(templates.locales(), vec!["de", "en"]);
+    assert_eq!(templates.clarifications(), ["[de clarification]"]);
+    assert_eq!(
+        templates.resumption(&IntentHypothesis::Inquiry, true, &CrystallizationDecision::AllowPartial),
+        ["[de interrupted_resumption_offer] [de topic_inquiry] [de name_inquiry]"]
+    );
+    let summary = WarmStart { held: 2, lead: None, deferred_offer: true };
+    assert_eq!(
+        templates.warm_start(&summary, &CrystallizationDecision::AllowHard),
+        ["[de warm_start] [de held_many] 3 [de topic_unknown] [de name_unknown]"]
+    );
+    assert_eq!(templates.plan_clarification("the lamp"), "[de plan_clarification] the lamp");
+
+    // Pre-warmed in the selected locale
+    assert!(canned_phrases_in(&templates).contains(&"[de clarification]".to_string()));
+}
+
+#[test]
+fn test_partial_overrides_keep_the_builtin_rest() {
+    let json = r#"{ "templates": { "en": { "clarification": ["Talking to me?"], "name_inquiry": ["that question"],
+        "resumption": ["Back to {intent}: {topic}."] } } }"#;
+    let templates = PhraseTemplates::from_json(json).unwrap();
+    assert_eq!(templates.clarifications(), ["Talking to me?"]);
+    assert_eq!(
+        templates.resumption(&IntentHypothesis::Inquiry, false, &CrystallizationDecision::AllowHard),
+        ["Back to that question: you were asking about something."]
+    );
+    let summary = WarmStart { held: 1, lead: Some(("i1".to_string(), IntentHypothesis::Inquiry)), deferred_offer: false };
+    assert_eq!(templates.warm_start(&summary, &CrystallizationDecision::AllowHard)[0], realize_warm_start(&summary, &CrystallizationDecision::AllowHard));
+}
+
+#[test]
+fn test_validation() {
+    // A locale must cover every act
+    let mut partial = complete("fr");
+    partial["fr"].as_object_mut().unwrap().remove("warm_start");
+    let err = PhraseTemplates::from_json(&serde_json::json!({ "templates": partial }).to_string()).unwrap_err();
+    assert!(err.to_string().contains("fr") && err.to_string().contains("WarmStart"), "{}", err);
+
+    // Empty variants do not count
+    let err = PhraseTemplates::from_json(r#"{ "templates": { "en": { "held_one": [" "] } } }"#).unwrap_err();
+    assert!(err.to_string().contains("HeldOne"), "{}", err);
+
+    // Variables must be ones the act provides
+    let err = PhraseTemplates::from_json(r#"{ "templates": { "en": { "clarification": ["About {topic}?"] } } }"#).unwrap_err();
+    assert!(err.to_string().contains("{topic}"), "{}", err);
+
+    // The selected locale must exist
+    assert!(PhraseTemplates::from_json(r#"{ "locale": "it" }"#).is_err());
+    assert!(PhraseTemplates::from_json(r#"{ "templates": { "en": { "no_such_act": ["x"] } } }"#).is_err());
+}
+
+#[test]
+fn test_open_falls_back_to_builtin() {
+    let missing = std::env::temp_dir().join(format!("nexus_phrases_{}_missing.json", std::process::id()));
+    let _ = std::fs::remove_file(&missing);
+    assert_eq!(PhraseTemplates::open(&missing).unwrap().clarifications(), CLARIFICATION_VARIANTS);
+
+    let invalid = std::env::temp_dir().join(format!("nexus_phrases_{}_invalid.json", std::process::id()));
+    std::fs::write(&invalid, r#"{ "locale": "it" }"#).unwrap();
+    assert!(PhraseTemplates::open(&invalid).is_err());
+    let _ = std::fs::remove_file(&invalid);
+}
+
+#[test]
+fn test_scheduler_phrases_planner_acts() {
+    let json = r#"{ "templates": { "en": { "plan_clarification": ["Which {context}?"], "correction": ["Sorry: {correction}"] } } }"#;
+    let templates = PhraseTemplates::from_json(json).unwrap();
+    let spoken = |effect: Option<SideEffect>| match effect {
+        Some(SideEffect::SpawnAudio(_, text)) => text,
+        other => panic!("{:?}", other),
+    };
+    let (_, effect) = Scheduler.schedule_in(&templates, Intent::AskClarification { context: "lamp".to_string() }, Tick::new(), 0);
+    assert_eq!(spoken(effect), "Which lamp?");
+    let (_, effect) = Scheduler.schedule(Intent::AskClarification { context: "lamp".to_string() }, Tick::new(), 0);
+    assert_eq!(spoken(effect), "Could you clarify? Context: lamp");
+}
+
+#[tokio::test]
+async fn test_reactor_speaks_the_configured_locale() {
+    let json = serde_json::json!({ "locale": "de", "templates": complete("de") }).to_string();
+    let (tx, rx) = tokio::sync::mpsc::channel(100);
+    let mut reactor = Reactor::builder(rx, tx)
+        .config(ReactorConfig { llm_planning: false, ..Default::default() })
+        .templates(PhraseTemplates::from_json(&json).unwrap())
+        .build();
+
+    let effects = reactor.tick_step(vec![provisional("maybe what?", "seg_1")]);
+    let asked: Vec<_> = effects.iter().filter_map(|e| match e {
+        SideEffect::RequestSpeech { intent: SpeechIntent::Clarification(text), .. } => Some(text.clone()),
+        _ => None,
+    }).collect();
+    assert_eq!(asked, ["[de clarification]"]);
+
+    // Free functions stay on the built-in table
+    assert!(realize_resumption_variants(&IntentHypothesis::Inquiry, false, &CrystallizationDecision::AllowHard)[0].starts_with("Earlier"));
+}
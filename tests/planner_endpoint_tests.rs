@@ -0,0 +1,119 @@
+use nexus::kernel::event::Event;
+use nexus::kernel::intent::long_horizon::IntentContext;
+use nexus::kernel::state::{SharedState, StateDelta};
+use nexus::kernel::telemetry::recorder::TelemetryRecorder;
+use nexus::kernel::time::Tick;
+use nexus::planner::async_planner::plan_prompt;
+use nexus::planner::endpoint::{PlannerBackend, PlannerEndpoint, DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL};
+use nexus::planner::grammar::{PlanParseError, PlanParseOutcome};
+use nexus::planner::types::{Intent, StateSnapshot};
+
+fn snapshot() -> StateSnapshot {
+    let mut state = SharedState::new();
+    state.reduce(StateDelta::Tick(Tick { frame: 7 }));
+    state.snapshot(state.last_tick(), IntentContext { active_focus: None, strength: 0.0 })
+}
+
+// Answers one HTTP request with `status` and `body`
+#[cfg(feature = "llm")]
+async fn serve_once(status: &'static str, body: String) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/v1/chat/completions", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = vec![0; 64 * 1024];
+        let _ = socket.read(&mut request).await;
+        let response = format!("HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}", status, body.len(), body);
+        let _ = socket.write_all(response.as_bytes()).await;
+    });
+    url
+}
+
+#[cfg(feature = "llm")]
+async fn plan_from(endpoint: PlannerEndpoint) -> (Event, StateSnapshot) {
+    let (tx, mut rx) = tokio::sync::mpsc::channel(4);
+    let mut planner = nexus::planner::async_planner::AsyncPlanner::with_endpoint(tx, TelemetryRecorder::new().handle(), endpoint);
+    let snapshot = snapshot();
+    planner.dispatch(snapshot.clone());
+    let event = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv()).await.unwrap().unwrap();
+    (event, snapshot)
+}
+
+#[test]
+fn test_backend_defaults() {
+    assert_eq!(PlannerBackend::parse("Ollama"), Some(PlannerBackend::Ollama));
+    assert_eq!(PlannerBackend::parse("openai-compatible"), Some(PlannerBackend::OpenAi));
+    assert_eq!(PlannerBackend::parse("llama-server"), Some(PlannerBackend::LlamaServer));
+    assert_eq!(PlannerBackend::parse("gpt"), None);
+
+    let default = PlannerEndpoint::default();
+    assert_eq!((default.backend, default.url.as_str(), default.model), (PlannerBackend::LlamaServer, "http://localhost:8080/completion", None));
+    let ollama = PlannerEndpoint::for_backend(PlannerBackend::Ollama);
+    assert_eq!((ollama.url.as_str(), ollama.model.as_deref()), (DEFAULT_OLLAMA_URL, Some(DEFAULT_OLLAMA_MODEL)));
+}
+
+#[test]
+fn test_request_bodies() {
+    let prompt = plan_prompt(&snapshot());
+    assert!(prompt.starts_with("STATE: {") && prompt.contains("gate_preview"));
+
+    let llama = PlannerEndpoint::default().request_body(&prompt);
+    assert_eq!(llama["prompt"], prompt.as_str());
+    assert!(llama["json_schema"]["oneOf"].is_array() && llama.get("grammar").is_none());
+    let gbnf = PlannerEndpoint { use_gbnf: true, ..Default::default() }.request_body(&prompt);
+    assert!(gbnf["grammar"].as_str().unwrap().starts_with("root ::=") && gbnf.get("json_schema").is_none());
+
+    let openai = PlannerEndpoint { model: Some("qwen2.5".to_string()), ..PlannerEndpoint::for_backend(PlannerBackend::OpenAi) }.request_body(&prompt);
+    assert_eq!(openai["model"], "qwen2.5");
+    assert_eq!(openai["response_format"]["type"], "json_object");
+    let message = openai["messages"][0]["content"].as_str().unwrap();
+    assert!(message.starts_with(&prompt) && message.contains("SCHEMA: "), "Schema in the prompt");
+
+    let ollama = PlannerEndpoint::for_backend(PlannerBackend::Ollama).request_body(&prompt);
+    assert_eq!((&ollama["model"], &ollama["stream"]), (&serde_json::json!(DEFAULT_OLLAMA_MODEL), &serde_json::json!(false)));
+    assert!(ollama["format"]["oneOf"].is_array(), "Schema-constrained");
+}
+
+#[test]
+fn test_replies_parse_per_backend() {
+    let delay = r#"{\"intent\":\"Delay\",\"data\":{\"ticks\":5}}"#;
+    let llama = PlannerEndpoint::default();
+    assert!(matches!(llama.parse_reply(&format!(r#"{{"content":"{}"}}"#, delay)), PlanParseOutcome::Parsed(Intent::Delay { ticks: 5 })));
+    assert!(matches!(llama.parse_reply(r#"{"intent":"DoNothing"}"#), PlanParseOutcome::Parsed(Intent::DoNothing)), "Raw text");
+
+    let openai = PlannerEndpoint::for_backend(PlannerBackend::OpenAi);
+    let reply = format!(r#"{{"choices":[{{"index":0,"message":{{"role":"assistant","content":"{}"}}}}]}}"#, delay);
+    assert!(matches!(openai.parse_reply(&reply), PlanParseOutcome::Parsed(Intent::Delay { ticks: 5 })));
+    // Repaired inside the wrapper too
+    let fenced = r#"{"choices":[{"message":{"content":"```json\n{'intent': 'Delay', 'ticks': 3}\n```"}}]}"#;
+    assert!(matches!(openai.parse_reply(fenced), PlanParseOutcome::Repaired(Intent::Delay { ticks: 3 })));
+    assert!(matches!(openai.parse_reply(r#"{"error":{"message":"overloaded"}}"#), PlanParseOutcome::Failed(PlanParseError::NoJson)));
+
+    let ollama = PlannerEndpoint::for_backend(PlannerBackend::Ollama);
+    let reply = format!(r#"{{"model":"llama3.2","message":{{"role":"assistant","content":"{}"}},"done":true}}"#, delay);
+    assert!(matches!(ollama.parse_reply(&reply), PlanParseOutcome::Parsed(Intent::Delay { ticks: 5 })));
+    assert!(matches!(ollama.parse_reply("not json").into_intent(), Intent::DoNothing));
+}
+
+#[cfg(feature = "llm")]
+#[tokio::test]
+async fn test_dispatch_proposes_for_its_epoch() {
+    let reply = r#"{"choices":[{"message":{"content":"{\"intent\":\"BeginResponse\",\"data\":{\"confidence\":0.9}}"}}]}"#;
+    let url = serve_once("200 OK", reply.to_string()).await;
+    let endpoint = PlannerEndpoint { url, timeout_ms: 2_000, ..PlannerEndpoint::for_backend(PlannerBackend::OpenAi) };
+    let (event, snapshot) = plan_from(endpoint).await;
+    match event {
+        Event::PlanProposed(epoch, Intent::BeginResponse { confidence }) => {
+            assert_eq!(epoch, snapshot.epoch);
+            assert_eq!(confidence, 0.9);
+        }
+        other => panic!("Expected the plan, got {:?}", other),
+    }
+
+    // A server error is a DoNothing for the same epoch, never silence
+    let url = serve_once("500 Internal Server Error", reply.to_string()).await;
+    let endpoint = PlannerEndpoint { url, timeout_ms: 2_000, ..PlannerEndpoint::for_backend(PlannerBackend::OpenAi) };
+    let (event, snapshot) = plan_from(endpoint).await;
+    assert!(matches!(event, Event::PlanProposed(epoch, Intent::DoNothing) if epoch == snapshot.epoch));
+}
@@ -0,0 +1,61 @@
+use nexus::planner::grammar::{parse_intent, PlanParseOutcome, PlanParseError};
+use nexus::planner::types::Intent;
+
+#[test]
+fn test_strict_parse() {
+    let out = parse_intent(r#"{"intent":"BeginResponse","data":{"confidence":0.8}}"#);
+    assert!(matches!(out, PlanParseOutcome::Parsed(Intent::BeginResponse { .. })));
+}
+
+#[test]
+fn test_follow_up_intent() {
+    let out = parse_intent(r#"{"intent":"BeginResponseWithFollowUp","data":{"summary_first":false}}"#);
+    assert!(matches!(out, PlanParseOutcome::Parsed(Intent::BeginResponseWithFollowUp { summary_first: false })));
+    let out = parse_intent(r#"{"intent": "begin_response_with_follow_up", "summary_first": "true"}"#);
+    assert!(matches!(out, PlanParseOutcome::Repaired(Intent::BeginResponseWithFollowUp { summary_first: true })), "{:?}", out);
+    assert!(nexus::planner::grammar::INTENT_GBNF.contains("summary_first"));
+}
+
+#[test]
+fn test_repairs_common_deviations() {
+    let cases = [
+        // Code fences + prose
+        "Sure! ```json\n{\"intent\": \"Delay\", \"data\": {\"ticks\": 5}}\n```",
+        // Flattened data
+        r#"{"intent": "Delay", "ticks": 5}"#,
+        // Trailing comma
+        r#"{"intent": "Delay", "data": {"ticks": 5,},}"#,
+        // Single quotes
+        "{'intent': 'Delay', 'data': {'ticks': 5}}",
+        // Externally tagged, stringly typed
+        r#"{"Delay": {"ticks": "5"}}"#,
+        // Snake case tag, unquoted keys
+        r#"{intent: "delay", data: {ticks: 5}}"#,
+    ];
+
+    for raw in cases {
+        match parse_intent(raw) {
+            PlanParseOutcome::Repaired(Intent::Delay { ticks }) => assert_eq!(ticks, 5, "{}", raw),
+            other => panic!("Expected repaired Delay for {:?}, got {:?}", raw, other),
+        }
+    }
+}
+
+#[test]
+fn test_truncated_and_bare_outputs() {
+    assert!(matches!(parse_intent("DoNothing"), PlanParseOutcome::Repaired(Intent::DoNothing)));
+    assert!(matches!(
+        parse_intent(r#"{"intent":"BeginResponse","data":{"confidence":0.9"#),
+        PlanParseOutcome::Repaired(Intent::BeginResponse { .. })
+    ));
+}
+
+#[test]
+fn test_failures_fall_back_to_do_nothing() {
+    let out = parse_intent("I think you should respond now.");
+    assert!(matches!(out, PlanParseOutcome::Failed(PlanParseError::NoJson)));
+    assert!(matches!(out.into_intent(), Intent::DoNothing));
+
+    let out = parse_intent(r#"{"intent": "Dance"}"#);
+    assert!(matches!(out, PlanParseOutcome::Failed(PlanParseError::UnknownIntent)));
+}
@@ -0,0 +1,106 @@
+use nexus::kernel::event::{AudioSignal, Event, InputContent, InputEvent, VisualSignal};
+use nexus::kernel::latent::{LatentSlot, LatentState, Modality};
+use nexus::kernel::presence::{
+    activity_request, screen_active, CapturePermission, PresenceGraph, PresenceRequest, PresenceState, PRESENCE_QUIET_TICKS,
+    SCREEN_ACTIVE_FRAMES,
+};
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::time::Tick;
+
+mod common;
+use common::reactor;
+
+fn percept(distance: u32) -> Event {
+    Event::Input(InputEvent {
+        source: "Vision".to_string(),
+        content: InputContent::Visual(VisualSignal::PerceptUpdate { hash: 0xBEEF, distance }),
+        captured_at: None,
+    })
+}
+
+fn visual(changed: f32, confidence: f32) -> LatentSlot {
+    LatentSlot { values: vec![0.5, changed], confidence, created_at: Tick::new(), modality: Modality::Visual, decay_rate: 0.01 }
+}
+
+/// `ticks` ticks with a percept every 10 (5 FPS), each `distance` from the last.
+fn screen(reactor: &mut Reactor, ticks: u64, distance: u32) {
+    for i in 0..ticks {
+        let events = if i % 10 == 0 { vec![percept(distance)] } else { vec![] };
+        reactor.tick_step(events);
+    }
+}
+
+#[test]
+fn test_sustained_change_is_activity() {
+    let mut latents = LatentState::new();
+    latents.slots.extend((0..SCREEN_ACTIVE_FRAMES - 1).map(|_| visual(1.0, 0.8)));
+    assert!(!screen_active(&latents), "A single burst is not sustained");
+    latents.slots.push(visual(1.0, 0.3));
+    assert!(!screen_active(&latents), "Faded frames do not count");
+    latents.slots.push(visual(0.0, 0.8));
+    assert!(!screen_active(&latents), "A still screen is not activity");
+    latents.slots.push(visual(1.0, 0.8));
+    assert!(screen_active(&latents));
+
+    assert_eq!(activity_request(true, PRESENCE_QUIET_TICKS), Some(PresenceRequest::UserActivityDetected));
+    assert_eq!(activity_request(false, PRESENCE_QUIET_TICKS - 1), None);
+    assert_eq!(activity_request(false, PRESENCE_QUIET_TICKS), Some(PresenceRequest::Timeout));
+}
+
+#[test]
+fn test_graph_edges() {
+    use PresenceState::*;
+    assert_eq!(PresenceGraph::transition(Dormant, PresenceRequest::UserActivityDetected), Some(Attentive));
+    assert_eq!(PresenceGraph::transition(Attentive, PresenceRequest::UserActivityDetected), None, "Stays Attentive");
+    assert_eq!(PresenceGraph::transition(Engaged, PresenceRequest::UserActivityDetected), None, "Never demotes");
+    assert_eq!(PresenceGraph::transition(Attentive, PresenceRequest::Timeout), Some(Dormant));
+    assert_eq!(PresenceGraph::transition(Suspended, PresenceRequest::UserActivityDetected), None);
+    // Screen activity does not listen for the user when the microphone is blocked
+    assert_eq!(PresenceGraph::transition_gated(Dormant, PresenceRequest::UserActivityDetected, CapturePermission::Denied), None);
+}
+
+#[tokio::test]
+async fn test_busy_screen_keeps_the_user_present() {
+    let mut reactor = reactor();
+    assert_eq!(reactor.state.presence(), PresenceState::Dormant);
+    screen(&mut reactor, 20, 8);
+    assert_eq!(reactor.state.presence(), PresenceState::Dormant, "Two changed frames are not sustained");
+    screen(&mut reactor, 20, 8);
+    assert_eq!(reactor.state.presence(), PresenceState::Attentive);
+
+    // Working well past the quiet window: still Attentive
+    screen(&mut reactor, PRESENCE_QUIET_TICKS + 500, 8);
+    assert_eq!(reactor.state.presence(), PresenceState::Attentive);
+
+    // The screen goes still and nobody speaks: back to Dormant after the quiet window
+    screen(&mut reactor, PRESENCE_QUIET_TICKS - 100, 0);
+    assert_eq!(reactor.state.presence(), PresenceState::Attentive);
+    screen(&mut reactor, 400, 0);
+    assert_eq!(reactor.state.presence(), PresenceState::Dormant);
+}
+
+#[tokio::test]
+async fn test_quiet_channels_decay_one_step_at_a_time() {
+    let mut reactor = reactor();
+    reactor.state.reduce(StateDelta::PresenceUpdate(PresenceState::Engaged));
+    screen(&mut reactor, PRESENCE_QUIET_TICKS + 10, 0);
+    assert_eq!(reactor.state.presence(), PresenceState::Attentive);
+    screen(&mut reactor, PRESENCE_QUIET_TICKS, 0);
+    assert_eq!(reactor.state.presence(), PresenceState::Dormant);
+
+    // Speech alone does not wake it, the wake word does
+    let input = |content| Event::Input(InputEvent { source: "Test".to_string(), content, captured_at: None });
+    reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechStart))]);
+    assert_eq!(reactor.state.presence(), PresenceState::Dormant);
+    reactor.tick_step(vec![input(InputContent::WakeWord { phrase: "nexus".to_string() })]);
+    assert_eq!(reactor.state.presence(), PresenceState::Engaged);
+}
+
+#[tokio::test]
+async fn test_blocked_microphone_stays_dormant() {
+    let mut reactor = reactor();
+    reactor.state.reduce(StateDelta::CapturePermissionChanged(CapturePermission::Denied));
+    screen(&mut reactor, 100, 8);
+    assert_eq!(reactor.state.presence(), PresenceState::Dormant);
+}
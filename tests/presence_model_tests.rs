@@ -0,0 +1,158 @@
+// Model check of the presence graph.
+//
+// The model is (presence, capture permission), stepped by the real reducer: a presence
+// request (`StateDelta::PresenceTransition`) or a permission report
+// (`StateDelta::CapturePermissionChanged`). The space is tiny, so every reachable
+// configuration and every edge is explored instead of sampled.
+
+use std::collections::{HashSet, VecDeque};
+
+use nexus::kernel::presence::{CapturePermission, PresenceGraph, PresenceRequest, PresenceState, RequestOrigin};
+use nexus::kernel::state::{SharedState, StateDelta};
+
+type Config = (PresenceState, CapturePermission);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Action {
+    Request(PresenceRequest),
+    Permission(CapturePermission),
+}
+
+// Compile-time guards: a new variant breaks these matches until it is added to `ALL`.
+fn _all_states(s: PresenceState) {
+    match s {
+        PresenceState::Dormant | PresenceState::Attentive | PresenceState::Engaged
+        | PresenceState::QuietlyHolding | PresenceState::Suspended => {}
+    }
+}
+
+fn _all_requests(r: PresenceRequest) {
+    match r {
+        PresenceRequest::SystemBoot | PresenceRequest::WakeWordDetected | PresenceRequest::InputActivity
+        | PresenceRequest::OutputDrafted | PresenceRequest::OutputCompleted | PresenceRequest::LongTermIntentDetected
+        | PresenceRequest::IntentResolved | PresenceRequest::UserSuspend | PresenceRequest::UserResume
+        | PresenceRequest::Timeout | PresenceRequest::AudioActivity | PresenceRequest::CaptureGranted
+        | PresenceRequest::CaptureBlocked | PresenceRequest::UserActivityDetected => {}
+    }
+}
+
+fn _all_permissions(p: CapturePermission) {
+    match p {
+        CapturePermission::Unknown | CapturePermission::NotDetermined | CapturePermission::Granted
+        | CapturePermission::Denied | CapturePermission::Restricted => {}
+    }
+}
+
+fn actions() -> Vec<Action> {
+    PresenceRequest::ALL.iter().map(|r| Action::Request(*r))
+        .chain(CapturePermission::ALL.iter().map(|p| Action::Permission(*p)))
+        .collect()
+}
+
+fn config(state: &SharedState) -> Config {
+    (state.presence(), state.capture_permission())
+}
+
+fn step(state: &SharedState, action: Action) -> SharedState {
+    let mut next = state.clone();
+    next.reduce(match action {
+        Action::Request(r) => StateDelta::PresenceTransition(r),
+        Action::Permission(p) => StateDelta::CapturePermissionChanged(p),
+    });
+    next
+}
+
+/// Breadth-first over configurations from `start`, following only `allowed` actions.
+/// Returns each reached configuration with a representative state.
+fn explore(start: SharedState, allowed: impl Fn(Config, Action) -> bool) -> Vec<SharedState> {
+    let mut seen = HashSet::from([config(&start)]);
+    let mut found = vec![start.clone()];
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        for action in actions() {
+            if !allowed(config(&state), action) {
+                continue;
+            }
+            let next = step(&state, action);
+            if seen.insert(config(&next)) {
+                found.push(next.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    found
+}
+
+/// Every configuration reachable from boot, through any sequence of requests and reports.
+fn reachable() -> Vec<SharedState> {
+    explore(SharedState::new(), |_, _| true)
+}
+
+#[test]
+fn test_model_covers_the_graph() {
+    let configs: HashSet<Config> = reachable().iter().map(config).collect();
+    for state in PresenceState::ALL {
+        assert!(configs.iter().any(|(p, _)| *p == state), "{:?} is unreachable", state);
+    }
+    // Microphone gate holds everywhere
+    assert!(!configs.iter().any(|(p, perm)| *p == PresenceState::Attentive && !perm.allows_listening()));
+}
+
+#[test]
+fn test_suspended_reaches_engaged_only_through_resume() {
+    for suspended in reachable().into_iter().filter(|s| s.presence() == PresenceState::Suspended) {
+        let without_resume = explore(suspended, |_, a| a != Action::Request(PresenceRequest::UserResume));
+        assert!(
+            without_resume.iter().all(|s| s.presence() == PresenceState::Suspended),
+            "Left Suspended without UserResume: {:?}",
+            without_resume.iter().map(config).collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_dormant_reachable_from_everywhere() {
+    for state in reachable() {
+        // Under any permission the user might be stuck with, not only after granting access
+        let (_, permission) = config(&state);
+        let onward = explore(state.clone(), |_, a| !matches!(a, Action::Permission(p) if p != permission));
+        assert!(
+            onward.iter().any(|s| s.presence() == PresenceState::Dormant),
+            "No way back to Dormant from {:?}",
+            config(&state)
+        );
+    }
+}
+
+#[test]
+fn test_resume_without_microphone_ends_the_pause() {
+    for permission in [CapturePermission::NotDetermined, CapturePermission::Denied, CapturePermission::Restricted] {
+        assert_eq!(
+            PresenceGraph::transition_gated(PresenceState::Suspended, PresenceRequest::UserResume, permission),
+            Some(PresenceState::Dormant)
+        );
+    }
+    assert_eq!(
+        PresenceGraph::transition_gated(PresenceState::Suspended, PresenceRequest::UserResume, CapturePermission::Granted),
+        Some(PresenceState::Attentive)
+    );
+}
+
+#[test]
+fn test_ui_requests_never_transition() {
+    for request in PresenceRequest::ALL.into_iter().filter(|r| r.origin() == RequestOrigin::Ui) {
+        for state in PresenceState::ALL {
+            for permission in CapturePermission::ALL {
+                assert_eq!(PresenceGraph::transition_gated(state, request, permission), None, "{:?} moved {:?}", request, state);
+            }
+        }
+    }
+    // Every edge of the graph has a non-UI cause
+    for state in PresenceState::ALL {
+        for request in PresenceRequest::ALL {
+            if PresenceGraph::transition(state, request).is_some() {
+                assert_ne!(request.origin(), RequestOrigin::Ui);
+            }
+        }
+    }
+}
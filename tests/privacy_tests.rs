@@ -0,0 +1,130 @@
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::event::{Event, InputEvent, InputContent};
+use nexus::kernel::memory::consent::MemoryConsentState;
+use nexus::kernel::memory::sensitivity::{classify, RedactionLevel, SensitivityClass};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::time::Tick;
+
+mod common;
+use common::reactor;
+
+fn utterance(text: &str, segment: &str) -> Event {
+    Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::ProvisionalText {
+            content: text.to_string(),
+            confidence: 0.9,
+            source_id: segment.to_string(),
+            words: Vec::new(),
+            language: None,
+            is_final: true,
+        },
+        captured_at: None,
+    })
+}
+
+/// Create a candidate, age it past the consolidation window and reinforce it.
+fn eligible_candidate(reactor: &mut Reactor, text: &str) {
+    reactor.tick_step(vec![utterance(text, "seg1")]);
+    let jump = 1250;
+    reactor.state.reduce(StateDelta::Tick(Tick { frame: jump }));
+    reactor.tick.frame = jump;
+    reactor.tick_step(vec![utterance(text, "seg2")]);
+}
+
+#[test]
+fn test_keyword_classes() {
+    assert_eq!(classify("What is my bank password?"), Some(SensitivityClass::Credentials));
+    assert_eq!(classify("How do I pay off my mortgage"), Some(SensitivityClass::Finance));
+    assert_eq!(classify("My doctor changed my medications"), Some(SensitivityClass::Health));
+    assert_eq!(classify("What is gravity?"), None);
+    // Word boundaries: no substring hits
+    assert_eq!(classify("I love pineapple and banking apps"), None);
+}
+
+#[tokio::test]
+async fn test_sensitive_transcript_marks_candidate() {
+    let mut reactor = reactor();
+    reactor.tick_step(vec![utterance("What is my bank password?", "seg1")]);
+
+    assert_eq!(reactor.state.memory_candidates().len(), 1);
+    let cand = reactor.state.memory_candidates().values().next().unwrap();
+    assert_eq!(cand.sensitivity, Some(SensitivityClass::Credentials));
+    assert!(reactor.state.sensitive_unconsented(&cand.intent.id));
+}
+
+#[tokio::test]
+async fn test_sensitive_requires_explicit_consent() {
+    let mut reactor = reactor();
+    reactor.tick_step(vec![utterance("What is my blood pressure?", "seg1")]);
+    let cand = reactor.state.memory_candidates().values().next().unwrap().clone();
+
+    // Topic-level consent is not enough for sensitive content
+    reactor.state.reduce(StateDelta::TopicConsentResolved {
+        topic_id: cand.topic_id.clone().unwrap(),
+        state: MemoryConsentState::Granted,
+    });
+    let jump = 1250;
+    reactor.state.reduce(StateDelta::Tick(Tick { frame: jump }));
+    reactor.tick.frame = jump;
+    let effects = reactor.tick_step(vec![utterance("What is my blood pressure?", "seg2")]);
+    assert!(reactor.state.long_term_memory().is_empty(), "Sensitive candidate must not promote on topic consent");
+    // Inquiry, not Statement: the non-sensitive heuristic would never ask
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::AskMemoryConsent(request) if request.key == cand.key)));
+
+    // Asked once only
+    let effects = reactor.tick_step(vec![]);
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::AskMemoryConsent(_))));
+
+    // Explicit grant promotes and marks the record
+    reactor.state.reduce(StateDelta::MemoryConsentResolved {
+        key: cand.key.clone(),
+        state: MemoryConsentState::Granted,
+        resolved_at: reactor.tick,
+    });
+    reactor.tick_step(vec![]);
+    let record = reactor.state.long_term_memory().values().next().expect("promoted");
+    assert_eq!(record.sensitivity, Some(SensitivityClass::Health));
+}
+
+#[tokio::test]
+async fn test_planner_context_excludes_unconsented() {
+    let mut reactor = reactor();
+    reactor.tick_step(vec![utterance("What is my bank password?", "seg1")]);
+    assert!(reactor.lhim.get_context(&reactor.state).active_focus.is_none());
+
+    let key = reactor.state.memory_candidates().values().next().unwrap().key.clone();
+    reactor.state.reduce(StateDelta::MemoryConsentResolved {
+        key,
+        state: MemoryConsentState::Granted,
+        resolved_at: reactor.tick,
+    });
+    assert!(reactor.lhim.get_context(&reactor.state).active_focus.is_some());
+
+    // Non-sensitive intents are unaffected
+    let mut reactor = self::reactor();
+    reactor.tick_step(vec![utterance("What is gravity?", "seg1")]);
+    assert!(reactor.lhim.get_context(&reactor.state).active_focus.is_some());
+}
+
+#[tokio::test]
+async fn test_redacted_export() {
+    let mut reactor = reactor();
+    eligible_candidate(&mut reactor, "What is my salary?");
+    let key = reactor.state.memory_candidates().values().next().unwrap().key.clone();
+    reactor.state.reduce(StateDelta::MemoryConsentResolved { key, state: MemoryConsentState::Granted, resolved_at: reactor.tick });
+    reactor.tick_step(vec![]);
+    assert_eq!(reactor.state.long_term_memory().len(), 1);
+
+    let full = reactor.state.export_memories(RedactionLevel::None);
+    assert_ne!(full[0].intent.semantic_hash, 0);
+
+    let masked = reactor.state.export_memories(RedactionLevel::Mask);
+    assert_eq!(masked.len(), 1);
+    assert_eq!(masked[0].intent.semantic_hash, 0);
+    assert!(masked[0].intent.source_symbol_ids.is_empty());
+    assert_eq!(masked[0].sensitivity, Some(SensitivityClass::Finance));
+
+    assert!(reactor.state.export_memories(RedactionLevel::Omit).is_empty());
+}
@@ -0,0 +1,123 @@
+use std::sync::{Arc, Mutex};
+
+use nexus::kernel::crystallizer::{explain_proactive_gate, CrystallizationDecision, GateRule};
+use nexus::kernel::event::{Event, InputContent};
+use nexus::kernel::intent::long_horizon::{IntentContext, IntentId, LongHorizonIntentManager, ResumptionNotice};
+use nexus::kernel::intent::types::{IntentCandidate, IntentHypothesis};
+use nexus::kernel::quiet::{parse_override, QuietHours, QuietHoursState};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::sidecar::IntentManager;
+use nexus::kernel::speech::planner::SpeechIntent;
+use nexus::kernel::state::{SharedState, StateDelta};
+use nexus::kernel::telemetry::recorder::TelemetryRecorder;
+use nexus::kernel::time::Tick;
+
+mod common;
+use common::input;
+
+/// Real LHIM, plus resumption notices injected by the test.
+struct NoticeLhim {
+    inner: LongHorizonIntentManager,
+    notices: Arc<Mutex<Vec<ResumptionNotice>>>,
+}
+
+impl IntentManager for NoticeLhim {
+    fn register_intent(&mut self, candidate: &IntentCandidate, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        self.inner.register_intent(candidate, state, current_tick, telemetry)
+    }
+    fn suspend_intent(&mut self, id: &IntentId, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Option<StateDelta> {
+        self.inner.suspend_intent(id, state, current_tick, telemetry)
+    }
+    fn handle_interruption(&mut self, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        self.inner.handle_interruption(state, current_tick, telemetry)
+    }
+    fn try_resume(&mut self, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        self.inner.try_resume(state, current_tick, telemetry)
+    }
+    fn tick(&mut self, current_tick: Tick, state: &SharedState, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        self.inner.tick(current_tick, state, telemetry)
+    }
+    fn take_resumptions(&mut self) -> Vec<ResumptionNotice> {
+        std::mem::take(&mut *self.notices.lock().unwrap())
+    }
+    fn get_context(&self, state: &SharedState) -> IntentContext {
+        self.inner.get_context(state)
+    }
+}
+
+fn reactor() -> (Reactor, Arc<Mutex<Vec<ResumptionNotice>>>) {
+    let (tx, rx) = tokio::sync::mpsc::channel(100);
+    let notices = Arc::new(Mutex::new(Vec::new()));
+    let reactor = Reactor::builder(rx, tx)
+        .config(ReactorConfig { llm_planning: false, quiet_hours: QuietHours::parse("22:00-08:00"), ..Default::default() })
+        .lhim(Box::new(NoticeLhim { inner: LongHorizonIntentManager::new(), notices: notices.clone() }))
+        .build();
+    (reactor, notices)
+}
+
+fn clock(hh: u16, mm: u16) -> Event {
+    input(InputContent::LocalTime { minute_of_day: hh * 60 + mm })
+}
+
+fn notice() -> ResumptionNotice {
+    ResumptionNotice { intent_id: "i1".to_string(), hypothesis: IntentHypothesis::Command, dormant_ticks: 2000, was_interrupted: false }
+}
+
+fn offered(effects: &[SideEffect]) -> bool {
+    effects.iter().any(|e| matches!(e, SideEffect::SpawnAudio(..)))
+}
+
+#[test]
+fn test_schedule_parsing_and_midnight_wrap() {
+    let night = QuietHours::parse("22:00-08:00").unwrap();
+    assert!(night.contains(22 * 60) && night.contains(3 * 60) && night.contains(8 * 60 - 1));
+    assert!(!night.contains(8 * 60) && !night.contains(12 * 60));
+    let lunch = QuietHours::parse(" 12:30 – 13:00 ").unwrap();
+    assert!(lunch.contains(12 * 60 + 45) && !lunch.contains(13 * 60));
+    assert!(QuietHours::parse("25:00-08:00").is_none());
+    assert!(QuietHours::parse("22:00").is_none());
+
+    assert!(parse_override("It's fine, you can talk."));
+    assert!(!parse_override("Can you talk about the weather?"));
+}
+
+#[tokio::test]
+async fn test_offer_deferred_until_window_ends() {
+    let (mut reactor, notices) = reactor();
+    reactor.tick_step(vec![clock(23, 15)]);
+    assert_eq!(reactor.state.quiet_hours(), QuietHoursState::Active);
+    assert_eq!(reactor.subscribe_view().borrow().quiet_hours, QuietHoursState::Active);
+    let gate = explain_proactive_gate(&reactor.state);
+    assert_eq!((gate.decision, gate.rule), (CrystallizationDecision::Deny, GateRule::QuietHours));
+
+    notices.lock().unwrap().push(notice());
+    assert!(!offered(&reactor.tick_step(vec![])));
+    assert!(!offered(&reactor.tick_step(vec![])));
+
+    // Morning: the held offer goes out once
+    assert!(offered(&reactor.tick_step(vec![clock(8, 0)])));
+    assert_eq!(reactor.state.quiet_hours(), QuietHoursState::Off);
+}
+
+#[tokio::test]
+async fn test_direct_answers_and_voice_override() {
+    let (mut reactor, notices) = reactor();
+    reactor.tick_step(vec![clock(23, 15)]);
+
+    // Explicit input still gets its answer
+    let effects = reactor.tick_step(vec![input(InputContent::Text("Turn off the lights".to_string()))]);
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::RequestSpeech { intent: SpeechIntent::Confirmation(_), .. })));
+
+    let effects = reactor.tick_step(vec![input(InputContent::Text("It's fine, you can talk".to_string()))]);
+    assert_eq!(reactor.state.quiet_hours(), QuietHoursState::Overridden);
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::RequestSpeech { intent: SpeechIntent::Confirmation(_), .. })));
+
+    notices.lock().unwrap().push(notice());
+    assert!(offered(&reactor.tick_step(vec![clock(23, 30)])), "Override holds for the rest of the window");
+
+    // The override ends with the window; the next night is quiet again
+    reactor.tick_step(vec![clock(8, 0)]);
+    reactor.tick_step(vec![clock(22, 0)]);
+    assert_eq!(reactor.state.quiet_hours(), QuietHoursState::Active);
+}
@@ -0,0 +1,89 @@
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::event::{Event, InputEvent, InputContent};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::sidecar::{Disabled, IntentManager};
+use nexus::kernel::state::{SharedState, StateDelta};
+use nexus::kernel::time::Tick;
+use nexus::kernel::intent::types::IntentCandidate;
+use nexus::kernel::intent::long_horizon::{LongHorizonIntentManager, IntentId, IntentContext, ResumptionNotice};
+use nexus::kernel::telemetry::recorder::TelemetryRecorder;
+use nexus::memory::InMemoryEpisodicStore;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::mpsc;
+
+/// Spy: delegates to the real LHIM, counts registrations.
+struct SpyLhim {
+    inner: LongHorizonIntentManager,
+    registered: Arc<AtomicUsize>,
+}
+
+impl IntentManager for SpyLhim {
+    fn register_intent(&mut self, candidate: &IntentCandidate, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        self.registered.fetch_add(1, Ordering::SeqCst);
+        self.inner.register_intent(candidate, state, current_tick, telemetry)
+    }
+    fn suspend_intent(&mut self, id: &IntentId, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Option<StateDelta> {
+        self.inner.suspend_intent(id, state, current_tick, telemetry)
+    }
+    fn handle_interruption(&mut self, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        self.inner.handle_interruption(state, current_tick, telemetry)
+    }
+    fn try_resume(&mut self, state: &SharedState, current_tick: Tick, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        self.inner.try_resume(state, current_tick, telemetry)
+    }
+    fn tick(&mut self, current_tick: Tick, state: &SharedState, telemetry: &mut TelemetryRecorder) -> Vec<StateDelta> {
+        self.inner.tick(current_tick, state, telemetry)
+    }
+    fn take_resumptions(&mut self) -> Vec<ResumptionNotice> {
+        self.inner.take_resumptions()
+    }
+    fn get_context(&self, state: &SharedState) -> IntentContext {
+        self.inner.get_context(state)
+    }
+}
+
+fn command(text: &str) -> Event {
+    Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::ProvisionalText {
+            content: text.to_string(),
+            confidence: 0.9,
+            source_id: "seg_1".to_string(),
+            words: Vec::new(),
+            language: None,
+            is_final: true,
+        },
+        captured_at: None,
+    })
+}
+
+#[tokio::test]
+async fn test_builder_injects_spy_lhim() {
+    let (tx, rx) = mpsc::channel(100);
+    let registered = Arc::new(AtomicUsize::new(0));
+    let mut reactor = Reactor::builder(rx, tx)
+        .config(ReactorConfig::default())
+        .lhim(Box::new(SpyLhim { inner: LongHorizonIntentManager::new(), registered: registered.clone() }))
+        .episodic_store(Box::new(InMemoryEpisodicStore::new()))
+        .build();
+
+    reactor.tick_step(vec![command("Turn off the lights")]);
+
+    assert_eq!(registered.load(Ordering::SeqCst), 1, "Stable intent must reach the injected LHIM");
+    assert_eq!(reactor.state.active_intents().len(), 1);
+}
+
+#[tokio::test]
+async fn test_disabled_arbitrator_keeps_system_silent() {
+    let (tx, rx) = mpsc::channel(100);
+    let mut reactor = Reactor::builder(rx, tx)
+        .arbitrator(Box::new(Disabled))
+        .lhim(Box::new(Disabled))
+        .build();
+
+    let effects = reactor.tick_step(vec![command("Turn off the lights")]);
+
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::RequestSpeech { .. })));
+    assert!(reactor.state.active_intents().is_empty());
+}
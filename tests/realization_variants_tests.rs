@@ -0,0 +1,104 @@
+use nexus::kernel::crystallizer::CrystallizationDecision;
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::intent::types::{DialogueAct, IntentHypothesis};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::speech::planner::SpeechIntent;
+use nexus::kernel::warm_start::WarmStart;
+use nexus::outputs::realizer::{
+    realize_resumption, realize_resumption_variants, realize_warm_start, realize_warm_start_variants, PhraseMemory,
+    CLARIFICATION_VARIANTS, PHRASE_MEMORY_SIZE,
+};
+use nexus::outputs::tts_cache::canned_phrases;
+
+mod common;
+
+fn provisional(text: &str, source_id: &str) -> Event {
+    Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::ProvisionalText { content: text.to_string(), confidence: 0.6, source_id: source_id.to_string(), words: Vec::new(), language: None, is_final: true },
+        captured_at: None,
+    })
+}
+
+fn clarifications(effects: &[SideEffect]) -> Vec<String> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::RequestSpeech { intent: SpeechIntent::Clarification(seed), .. } => Some(seed.clone()),
+        _ => None,
+    }).collect()
+}
+
+fn pool(phrases: &[&str]) -> Vec<String> {
+    phrases.iter().map(|p| p.to_string()).collect()
+}
+
+#[test]
+fn test_memory_rotates_through_the_pool() {
+    let mut memory = PhraseMemory::new();
+    let said: Vec<String> = (0..4).map(|_| memory.choose(pool(&["a", "b", "c"]))).collect();
+    assert_eq!(said, ["a", "b", "c", "a"], "Least recently said once the pool is exhausted");
+
+    // A single phrasing repeats; an empty pool says nothing and is not remembered
+    assert_eq!(memory.choose(pool(&["only"])), "only");
+    assert_eq!(memory.choose(pool(&["only"])), "only");
+    assert_eq!(memory.choose(vec![]), "");
+}
+
+#[test]
+fn test_memory_is_short_term() {
+    let mut memory = PhraseMemory::new();
+    assert_eq!(memory.choose(pool(&["a", "b"])), "a");
+    for i in 0..PHRASE_MEMORY_SIZE {
+        memory.choose(vec![format!("filler {}", i)]);
+    }
+    assert_eq!(memory.choose(pool(&["a", "b"])), "a", "Forgotten after PHRASE_MEMORY_SIZE other phrases");
+}
+
+#[test]
+fn test_vary_only_rephrases_pooled_seeds() {
+    let mut memory = PhraseMemory::new();
+    let ask = || DialogueAct::AskClarification(CLARIFICATION_VARIANTS[0].to_string());
+    assert_eq!(memory.vary(ask()), DialogueAct::AskClarification(CLARIFICATION_VARIANTS[0].to_string()));
+    assert_eq!(memory.vary(ask()), DialogueAct::AskClarification(CLARIFICATION_VARIANTS[1].to_string()));
+
+    let home = DialogueAct::AskClarification("confirm_home_action: lights".to_string());
+    assert_eq!(memory.vary(home.clone()), home);
+    assert_eq!(memory.vary(DialogueAct::Confirm("affirmed".to_string())), DialogueAct::Confirm("affirmed".to_string()));
+}
+
+#[test]
+fn test_canonical_realization_is_the_first_variant() {
+    for decision in [CrystallizationDecision::AllowPartial, CrystallizationDecision::AllowHard] {
+        let variants = realize_resumption_variants(&IntentHypothesis::Inquiry, true, &decision);
+        assert!(variants.len() > 1);
+        assert_eq!(variants[0], realize_resumption(&IntentHypothesis::Inquiry, true, &decision));
+        assert!(variants.iter().all(|v| v.contains("you were asking about something")), "Variants differ in wording, not content");
+
+        let summary = WarmStart { held: 2, lead: Some(("i1".to_string(), IntentHypothesis::Command)), deferred_offer: false };
+        let variants = realize_warm_start_variants(&summary, &decision);
+        assert_eq!(variants[0], realize_warm_start(&summary, &decision));
+        assert!(variants.iter().all(|v| v.contains("2 things") && v.contains("you wanted something done")));
+    }
+    assert!(realize_resumption_variants(&IntentHypothesis::Inquiry, false, &CrystallizationDecision::Deny).is_empty());
+}
+
+#[test]
+fn test_every_variant_is_prewarmed() {
+    let phrases = canned_phrases();
+    for variant in CLARIFICATION_VARIANTS {
+        assert!(phrases.iter().any(|p| p == variant), "{} not pre-warmed", variant);
+    }
+    for phrase in realize_resumption_variants(&IntentHypothesis::Command, false, &CrystallizationDecision::AllowHard) {
+        assert!(phrases.contains(&phrase));
+    }
+}
+
+#[tokio::test]
+async fn test_repeated_clarification_is_rephrased() {
+    let mut reactor = common::reactor();
+
+    let first = clarifications(&reactor.tick_step(vec![provisional("maybe what?", "seg_1")]));
+    let second = clarifications(&reactor.tick_step(vec![provisional("maybe what?", "seg_2")]));
+    assert_eq!(first, [CLARIFICATION_VARIANTS[0]]);
+    assert_eq!(second.len(), 1);
+    assert_ne!(first, second, "The same question, not the same sentence");
+}
@@ -0,0 +1,54 @@
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::event::{Event, InputEvent, InputContent};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::speech::planner::SpeechIntent;
+use tokio::sync::mpsc;
+
+fn provisional(text: &str, seg: &str) -> Event {
+    Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::ProvisionalText {
+            content: text.to_string(),
+            confidence: 0.9,
+            source_id: seg.to_string(),
+            words: Vec::new(),
+            language: None,
+            is_final: true,
+        },
+        captured_at: None,
+    })
+}
+
+#[tokio::test]
+async fn test_yes_after_clarification_is_confirmed_by_reflex() {
+    let (tx, rx) = mpsc::channel(100);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
+
+    // 1. Ambiguous inquiry -> we ask "Do you want me to respond?"
+    let effects = reactor.tick_step(vec![provisional("maybe what?", "seg_1")]);
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::RequestSpeech { intent: SpeechIntent::Clarification(_), .. })));
+
+    // 2. Bare "yes" -> synchronous confirmation (no LLM round-trip)
+    let effects = reactor.tick_step(vec![provisional("Yes.", "seg_2")]);
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::RequestSpeech { intent: SpeechIntent::Confirmation(_), .. })));
+}
+
+#[tokio::test]
+async fn test_stable_command_is_acknowledged() {
+    let (tx, rx) = mpsc::channel(100);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
+
+    let effects = reactor.tick_step(vec![provisional("Turn off the lights", "seg_1")]);
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::RequestSpeech { intent: SpeechIntent::Confirmation(_), .. })));
+    // Still no direct audio: acknowledgement goes through the speech pipeline
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::SpawnAudio(..))));
+}
+
+#[tokio::test]
+async fn test_reflex_silent_in_safe_mode() {
+    let (tx, rx) = mpsc::channel(100);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig { safe_mode: true, ..Default::default() });
+
+    let effects = reactor.tick_step(vec![provisional("Turn off the lights", "seg_1")]);
+    assert!(!effects.iter().any(|e| matches!(e, SideEffect::RequestSpeech { .. })));
+}
@@ -0,0 +1,45 @@
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::repl::{ReplCommand, ReplDriver, ReplError};
+
+fn driver() -> ReplDriver {
+    let (tx, rx) = tokio::sync::mpsc::channel(100);
+    ReplDriver::new(Reactor::new(rx, tx, ReactorConfig { llm_planning: false, id_seed: Some(3), ..Default::default() }))
+}
+
+fn run(driver: &mut ReplDriver, line: &str) -> Vec<String> {
+    let command = ReplCommand::parse(line).unwrap().unwrap();
+    driver.run(command).iter().map(|t| t.render()).collect()
+}
+
+#[test]
+fn test_parse_lines() {
+    assert_eq!(ReplCommand::parse("  hello there "), Ok(Some(ReplCommand::Text("hello there".to_string()))));
+    assert_eq!(ReplCommand::parse(":say what time is it"), Ok(Some(ReplCommand::Say("what time is it".to_string()))));
+    assert_eq!(ReplCommand::parse(":tick"), Ok(Some(ReplCommand::Tick(1))));
+    assert_eq!(ReplCommand::parse(":tick 25"), Ok(Some(ReplCommand::Tick(25))));
+    assert_eq!(ReplCommand::parse(":q"), Ok(Some(ReplCommand::Quit)));
+    assert_eq!(ReplCommand::parse("   "), Ok(None));
+    assert_eq!(ReplCommand::parse(":tick soon"), Err(ReplError::BadArgument("soon".to_string())));
+    assert_eq!(ReplCommand::parse(":dance"), Err(ReplError::UnknownCommand("dance".to_string())));
+}
+
+#[tokio::test]
+async fn test_spoken_turn_runs_gate_and_arbitration() {
+    let mut driver = driver();
+    let lines = run(&mut driver, ":say Turn on the lights");
+    assert!(lines.iter().any(|l| l.contains("stable Command")), "{:?}", lines);
+    assert!(!driver.reactor.state.audio_segments().is_empty(), "Went through the audio gate");
+    assert!(lines.iter().any(|l| l.contains("speech: (confirmed: command_ack)")), "Reflex acknowledgement: {:?}", lines);
+}
+
+#[tokio::test]
+async fn test_typed_text_and_idle_ticks() {
+    let mut driver = driver();
+    let lines = run(&mut driver, "What is gravity?");
+    assert!(!lines.is_empty());
+    // Settled: further ticks have nothing new to report
+    assert!(run(&mut driver, ":tick 20").is_empty());
+    let summary = driver.summary();
+    assert_eq!(summary.len(), 3);
+    assert!(summary[2].starts_with("memory: "), "{:?}", summary);
+}
@@ -0,0 +1,53 @@
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::event::{Event, InputEvent, InputContent};
+use tokio::sync::mpsc;
+
+fn reactor() -> Reactor {
+    let (tx, rx) = mpsc::channel(100);
+    let config = ReactorConfig { decision_trace: true, llm_planning: false, ..Default::default() };
+    let mut reactor = Reactor::new(rx, tx, config);
+    reactor.tick_step(vec![Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::Text("What is gravity?".to_string()),
+        captured_at: None,
+    })]);
+    reactor
+}
+
+fn temp_zip(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("nexus_{}_{}.zip", name, std::process::id()))
+}
+
+#[tokio::test]
+async fn test_manifest_lists_every_part() {
+    let bundle = reactor().report_bundle();
+    let names: Vec<&str> = bundle.manifest().unwrap().iter().map(|e| e.name).collect();
+    assert_eq!(names, vec!["trace.json", "state.json", "telemetry.json", "config.json", "platform.json"]);
+    assert!(!bundle.trace.is_empty(), "Decision trace segment should be captured");
+}
+
+#[tokio::test]
+async fn test_requires_confirmation() {
+    let path = temp_zip("unconfirmed");
+    let _ = std::fs::remove_file(&path);
+    assert!(reactor().report_bundle().write_zip(&path, false).is_err());
+    assert!(!path.exists(), "Nothing may be written without confirmation");
+}
+
+#[tokio::test]
+async fn test_zip_contents_are_content_free() {
+    std::env::set_var("NEXUS_BUNDLE_TEST_SECRET", "hunter2");
+    let path = temp_zip("confirmed");
+    reactor().report_bundle().write_zip(&path, true).unwrap();
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(&path).unwrap()).unwrap();
+    assert_eq!(archive.len(), 5);
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).unwrap();
+        let mut body = String::new();
+        std::io::Read::read_to_string(&mut file, &mut body).unwrap();
+        assert!(!body.contains("gravity"), "{} leaks user text", file.name());
+        assert!(!body.contains("hunter2"), "{} leaks an env value", file.name());
+    }
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,114 @@
+use nexus::kernel::event::{AudioStatus, Event, InputContent, InputEvent, OutputId};
+use nexus::kernel::intent::long_horizon::IntentStatus;
+use nexus::kernel::intent::types::{IntentCandidate, IntentHypothesis, IntentStability, IntentState};
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::speech::budget::ProactiveBudget;
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::time::Tick;
+
+mod common;
+use common::{jump, reactor};
+
+fn at(frame: u64) -> Tick {
+    Tick { frame }
+}
+
+fn candidate(id: &str, stability: IntentStability) -> IntentCandidate {
+    IntentCandidate {
+        id: id.to_string(),
+        hypothesis: IntentHypothesis::Inquiry,
+        confidence: 0.9,
+        source_symbol_ids: vec!["seg1".to_string()],
+        semantic_hash: 12345,
+        stability,
+    }
+}
+
+/// Suspend the intent, wait `dormant` ticks, then let its segment come back.
+fn resume_after(reactor: &mut Reactor, id: &str, dormant: u64) -> Vec<SideEffect> {
+    let delta = reactor.lhim.suspend_intent(&id.to_string(), &reactor.state, reactor.tick, &mut reactor.telemetry).unwrap();
+    reactor.state.reduce(delta);
+    let frame = reactor.tick.frame + dormant;
+    jump(reactor, frame);
+    reactor.state.reduce(StateDelta::AssessmentUpdate(IntentState::Forming(vec![candidate("forming", IntentStability::Unstable)])));
+    let effects = reactor.tick_step(vec![]).to_vec();
+    assert_eq!(reactor.state.active_intents()[id].status, IntentStatus::Active, "Resumed");
+    effects
+}
+
+fn status(status: AudioStatus) -> Event {
+    Event::Input(InputEvent { source: "Driver".to_string(), content: InputContent::AudioStatus(status), captured_at: None })
+}
+
+/// Count the offers and play them out: an offer is never made over an active output.
+fn offers(reactor: &mut Reactor, effects: &[SideEffect]) -> usize {
+    let said: Vec<OutputId> = effects.iter().filter_map(|e| match e {
+        SideEffect::SpawnAudio(id, _) => Some(*id),
+        _ => None,
+    }).collect();
+    for &id in &said {
+        reactor.tick_step(vec![status(AudioStatus::PlaybackStarted { output_id: id.into() })]);
+        reactor.tick_step(vec![
+            status(AudioStatus::PlaybackCompleted { output_id: id }),
+            status(AudioStatus::PlaybackEnded { output_id: id.into(), elapsed_ms: 1000 }),
+        ]);
+    }
+    assert!(reactor.state.active_outputs().is_empty());
+    said.len()
+}
+
+#[test]
+fn test_budget_refuses_when_spent() {
+    let mut budget = ProactiveBudget::with_limits(2, 100);
+    assert!(budget.available(at(0)));
+    assert!(budget.try_spend(at(0)));
+    assert!(budget.try_spend(at(10)));
+    assert!(!budget.available(at(20)));
+    assert!(!budget.try_spend(at(20)), "Two per window");
+    assert!(!budget.try_spend(at(99)), "Still inside the window of the first");
+}
+
+#[test]
+fn test_budget_refills_after_the_window() {
+    let mut budget = ProactiveBudget::new();
+    assert!(budget.try_spend(at(1000)));
+    assert!(!budget.try_spend(at(3999)));
+    assert!(budget.available(at(4000)), "The window slides past the spend");
+    assert!(budget.try_spend(at(4000)));
+    assert!(!budget.available(at(4001)));
+
+    // A refused attempt spends nothing
+    let mut budget = ProactiveBudget::with_limits(1, 100);
+    assert!(budget.try_spend(at(0)));
+    assert!(!budget.try_spend(at(50)));
+    assert!(budget.try_spend(at(100)));
+}
+
+#[tokio::test]
+async fn test_resume_after_long_dormancy_offers_once_within_budget() {
+    let mut reactor = reactor();
+    let deltas = reactor.lhim.register_intent(&candidate("cand1", IntentStability::Stable), &reactor.state, reactor.tick, &mut reactor.telemetry);
+    for delta in deltas {
+        reactor.state.reduce(delta);
+    }
+    let id = reactor.state.active_intents().keys().next().unwrap().clone();
+
+    // A short pause resumes silently
+    let effects = resume_after(&mut reactor, &id, 100);
+    assert_eq!(offers(&mut reactor, &effects), 0);
+
+    // After long dormancy the resumption is announced, once
+    let effects = resume_after(&mut reactor, &id, 2000);
+    assert_eq!(offers(&mut reactor, &effects), 1);
+    let effects = reactor.tick_step(vec![]).to_vec();
+    assert_eq!(offers(&mut reactor, &effects), 0, "Not repeated on the next tick");
+
+    // A second long-dormancy resume inside the budget window stays silent
+    let effects = resume_after(&mut reactor, &id, 1600);
+    assert_eq!(offers(&mut reactor, &effects), 0, "Budget spent");
+
+    // Once the window has passed, the next one is announced again
+    let effects = resume_after(&mut reactor, &id, 2000);
+    assert_eq!(offers(&mut reactor, &effects), 1);
+}
@@ -0,0 +1,109 @@
+use nexus::memory::{
+    Claim, ClaimValue, EntityId, EpisodicMemoryEntry, EpisodicStore, FileSemanticStore, InMemoryEpisodicStore,
+    MemoryRetriever, Modality, Predicate, Provenance, RankingPolicy, RetrievalSource, SemanticMemoryEntry, SemanticStore,
+};
+use std::fs;
+use std::path::PathBuf;
+
+fn name_is(value: &str) -> Claim {
+    Claim::new(EntityId::User, Predicate::Is, ClaimValue::Text(value.to_string()), Modality::Asserted)
+}
+
+fn episode(value: &str, confidence: f32, tick: u64) -> EpisodicMemoryEntry {
+    EpisodicMemoryEntry {
+        claim: name_is(value),
+        confidence,
+        created_at_tick: tick,
+        last_reinforced_tick: tick,
+        decay_rate: 0.0,
+        created_at_ms: None,
+    }
+}
+
+fn semantic(name: &str, value: &str, confidence: f32, tick: u64) -> (FileSemanticStore, PathBuf) {
+    let path = std::env::temp_dir().join(format!("nexus_ranking_{}_{}.json", name, std::process::id()));
+    let _ = fs::remove_file(&path);
+    let mut store = FileSemanticStore::new(path.clone());
+    store.insert(SemanticMemoryEntry {
+        id: "fact".to_string(),
+        claim: name_is(value),
+        confidence,
+        provenance: Provenance::User,
+        created_at_tick: tick,
+        last_accessed_tick: tick,
+        version: 1,
+        previous_version_id: None,
+    }).unwrap();
+    (store, path)
+}
+
+fn object(value: &str) -> ClaimValue {
+    ClaimValue::Text(value.to_string())
+}
+
+#[test]
+fn test_fresh_correction_beats_old_confident_fact() {
+    // "My name is Sid" long ago (very confident, semantic) -> "Call me Alex" just now (episodic)
+    let (store, path) = semantic("correction", "Sid", 0.99, 0);
+    let mut episodic = InMemoryEpisodicStore::new();
+    episodic.insert(episode("Alex", 0.7, 10_000));
+
+    let hits = MemoryRetriever::retrieve(name_is("Sid").key_hash(), &episodic, &store);
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].content.object, object("Alex"));
+    assert_eq!(hits[0].source, RetrievalSource::Episodic);
+    assert!(!hits[0].rationale.superseded);
+    assert_eq!(hits[0].rationale.conflicts, 1);
+
+    assert_eq!(hits[1].content.object, object("Sid"));
+    assert!(hits[1].rationale.superseded, "Older contradicting fact is flagged");
+    assert!(hits[1].rationale.recency_term < hits[0].rationale.recency_term);
+    assert!(hits[0].score > hits[1].score);
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn test_policy_is_configurable() {
+    let (store, path) = semantic("policy", "Sid", 0.99, 0);
+    let mut episodic = InMemoryEpisodicStore::new();
+    episodic.insert(episode("Alex", 0.7, 10_000));
+
+    let confidence_only = RankingPolicy { confidence_weight: 1.0, recency_weight: 0.0, superseded_penalty: 1.0, ..Default::default() };
+    let hits = MemoryRetriever::retrieve_with(name_is("Sid").key_hash(), &episodic, &store, &confidence_only);
+    assert_eq!(hits[0].content.object, object("Sid"));
+    assert!(hits[0].rationale.superseded, "Flags are reported regardless of weights");
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn test_agreement_across_stores_is_not_a_conflict() {
+    let (store, path) = semantic("agree", "Alex", 0.9, 100);
+    let mut episodic = InMemoryEpisodicStore::new();
+    episodic.insert(episode("Alex", 0.8, 200));
+
+    let hits = MemoryRetriever::retrieve(name_is("Alex").key_hash(), &episodic, &store);
+    assert_eq!(hits.len(), 2);
+    assert!(hits.iter().all(|h| h.rationale.conflicts == 0 && !h.rationale.superseded));
+    let _ = fs::remove_file(path);
+}
+
+#[tokio::test]
+async fn test_reactor_uses_configured_policy() {
+    let (tx, rx) = tokio::sync::mpsc::channel(10);
+    let (store, path) = semantic("reactor", "Sid", 0.99, 0);
+    let mut episodic = InMemoryEpisodicStore::new();
+    episodic.insert(episode("Alex", 0.7, 10_000));
+    let config = nexus::kernel::reactor::ReactorConfig {
+        retrieval: RankingPolicy { recency_weight: 0.0, superseded_penalty: 1.0, ..Default::default() },
+        ..Default::default()
+    };
+    let reactor = nexus::kernel::reactor::Reactor::builder(rx, tx)
+        .config(config)
+        .episodic_store(Box::new(episodic))
+        .semantic_store(Box::new(store))
+        .build();
+
+    let hits = reactor.retrieve_memory(name_is("Sid").key_hash());
+    assert_eq!(hits[0].content.object, object("Sid"));
+    let _ = fs::remove_file(path);
+}
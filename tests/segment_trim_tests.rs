@@ -0,0 +1,83 @@
+use nexus::kernel::audio::calibration::CalibrationStore;
+use nexus::kernel::audio::trim::{trim, SegmentTrim};
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+
+const RATE: u32 = 16_000;
+const THRESHOLD: f32 = 0.03;
+
+fn audio(level: f32, ms: usize) -> Vec<f32> {
+    vec![level; RATE as usize * ms / 1000]
+}
+
+fn concat(parts: &[Vec<f32>]) -> Vec<f32> {
+    parts.concat()
+}
+
+#[test]
+fn test_leading_and_trailing_silence() {
+    let frames = concat(&[audio(0.005, 300), audio(0.3, 500), audio(0.0, 600)]);
+    let (kept, cut) = trim(&frames, RATE, THRESHOLD);
+    assert_eq!(cut, SegmentTrim { leading_ms: 200, trailing_ms: 500, compressed_ms: 0 }, "100ms pad kept on each side");
+    assert_eq!(kept.len(), RATE as usize * 700 / 1000);
+    assert_eq!(kept[RATE as usize / 10], 0.3, "Speech starts after the pad");
+}
+
+#[test]
+fn test_long_pause_compressed() {
+    let frames = concat(&[audio(0.3, 200), audio(0.0, 1000), audio(0.3, 200), audio(0.0, 200)]);
+    let (kept, cut) = trim(&frames, RATE, THRESHOLD);
+    assert_eq!(cut.compressed_ms, 700, "Shortened to 300ms");
+    assert_eq!(cut.trailing_ms, 100);
+    assert_eq!(cut.leading_ms, 0, "Speech from the first sample");
+    assert_eq!(kept.len(), RATE as usize * 800 / 1000);
+
+    // A short pause between words stays as it is
+    let frames = concat(&[audio(0.3, 200), audio(0.0, 250), audio(0.3, 200)]);
+    assert_eq!(trim(&frames, RATE, THRESHOLD).1.compressed_ms, 0);
+}
+
+#[test]
+fn test_quiet_segment_untouched() {
+    let frames = audio(0.01, 400);
+    let (kept, cut) = trim(&frames, RATE, THRESHOLD);
+    assert_eq!(kept, frames);
+    assert_eq!(cut, SegmentTrim::default());
+    assert_eq!(trim(&[], RATE, THRESHOLD).0, Vec::<f32>::new());
+}
+
+#[tokio::test]
+async fn test_trimmed_when_finalized() {
+    let (tx, rx) = tokio::sync::mpsc::channel(100);
+    let mut reactor = Reactor::builder(rx, tx)
+        .config(ReactorConfig { llm_planning: false, ..Default::default() })
+        .calibration(CalibrationStore::new())
+        .build();
+    let chunk = |level: f32| Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::AudioChunk(vec![level; 960]), // 20ms @ 48kHz
+        captured_at: None,
+    });
+
+    // Speech, then the quiet that ends the segment
+    let mut segment = None;
+    for _ in 0..20 {
+        reactor.tick_step(vec![chunk(0.3)]);
+        segment = segment.or_else(|| reactor.state.active_segment_id().cloned());
+    }
+    let mut effects = Vec::new();
+    for _ in 0..30 {
+        effects.extend(reactor.tick_step(vec![chunk(0.0)]));
+    }
+    let segment = segment.expect("Speech started");
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::RequestTranscription { segment_id } if *segment_id == segment)));
+
+    let finalized = &reactor.state.audio_segments()[&segment];
+    let cut = finalized.trim.expect("Trim recorded");
+    assert!(cut.trailing_ms >= 300, "End-of-speech silence cut: {:?}", cut);
+    assert_eq!(cut.leading_ms, 0);
+    // Speech kept, plus the 100ms pad
+    assert!(finalized.frames.iter().filter(|s| **s > 0.0).count() >= 960 * 14);
+    assert!(finalized.frames.len() <= (48_000 * 100 / 1000) + 960 * 20);
+}
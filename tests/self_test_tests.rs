@@ -0,0 +1,52 @@
+use nexus::kernel::event::{AudioSignal, Event, InputContent, InputEvent};
+use nexus::kernel::footprint::FootprintCeilings;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::self_test::Subsystem;
+use tokio::sync::mpsc;
+
+#[tokio::test]
+async fn test_self_test_passes_on_fresh_kernel() {
+    let (tx, rx) = mpsc::channel(10);
+    let reactor = Reactor::new(rx, tx, ReactorConfig::default());
+
+    let report = reactor.run_self_test();
+
+    for check in &report.checks {
+        assert!(check.passed, "{:?} failed: {}", check.subsystem, check.detail);
+    }
+    assert_eq!(report.checks.len(), 5);
+}
+
+#[tokio::test]
+async fn test_self_test_leaves_live_state_untouched() {
+    let (tx, rx) = mpsc::channel(10);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
+    reactor.tick_step(vec![]);
+
+    let tick = reactor.tick;
+    let version = reactor.state.version();
+    let _ = reactor.run_self_test();
+
+    assert_eq!(reactor.tick, tick);
+    assert_eq!(reactor.state.version(), version);
+    assert!(reactor.state.audio_segments().is_empty());
+    assert!(reactor.state.active_intents().is_empty());
+}
+
+#[tokio::test]
+async fn test_self_test_reports_footprint_pressure() {
+    let (tx, rx) = mpsc::channel(10);
+    let config = ReactorConfig { footprint: FootprintCeilings { audio_segments: 1, ..Default::default() }, ..Default::default() };
+    let mut reactor = Reactor::new(rx, tx, config);
+    reactor.tick_step(vec![Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::Audio(AudioSignal::SpeechStart),
+        captured_at: None,
+    })]);
+
+    let report = reactor.run_self_test();
+
+    assert!(!report.passed());
+    let failed: Vec<Subsystem> = report.failures().map(|c| c.subsystem).collect();
+    assert_eq!(failed, vec![Subsystem::Footprint]);
+}
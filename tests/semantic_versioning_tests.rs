@@ -0,0 +1,113 @@
+use nexus::memory::{
+    Claim, ClaimValue, EntityId, FileSemanticStore, MemoryError, MemoryRetriever, Modality, Predicate, Provenance,
+    InMemoryEpisodicStore, SemanticMemoryEntry, SemanticStore,
+};
+use nexus::kernel::ids::IdGenerator;
+use std::fs;
+use std::path::PathBuf;
+
+fn store(name: &str) -> (FileSemanticStore, PathBuf) {
+    let path = std::env::temp_dir().join(format!("nexus_semantic_{}_{}.json", name, std::process::id()));
+    let _ = fs::remove_file(&path);
+    (FileSemanticStore::new(path.clone()), path)
+}
+
+fn fact(id: &str, value: &str) -> SemanticMemoryEntry {
+    SemanticMemoryEntry {
+        id: id.to_string(),
+        claim: Claim::new(EntityId::User, Predicate::Prefers, ClaimValue::Text(value.to_string()), Modality::Asserted),
+        confidence: 0.9,
+        provenance: Provenance::User,
+        created_at_tick: 100,
+        last_accessed_tick: 100,
+        version: 1,
+        previous_version_id: None,
+    }
+}
+
+#[test]
+fn test_update_appends_a_version() {
+    let (mut semantic, path) = store("append");
+    let mut ids = IdGenerator::new(7);
+    semantic.insert(fact("tea", "Tea")).unwrap();
+
+    let v2 = semantic.update_confidence("tea", 0.6, &mut ids).unwrap();
+    let v3 = semantic.update_confidence(&v2, 0.4, &mut ids).unwrap();
+
+    assert_eq!(semantic.version_count(), 3, "Updates never overwrite");
+    let chain = semantic.history("tea").unwrap();
+    let versions: Vec<(u32, f32)> = chain.iter().map(|e| (e.version, e.confidence)).collect();
+    assert_eq!(versions, vec![(1, 0.9), (2, 0.6), (3, 0.4)]);
+    assert_eq!(chain[1].previous_version_id.as_deref(), Some("tea"));
+    assert_eq!(chain[2].previous_version_id.as_deref(), Some(v2.as_str()));
+    assert_eq!(chain[2].id, v3);
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn test_retrieval_collapses_to_latest() {
+    let (mut semantic, path) = store("collapse");
+    let mut ids = IdGenerator::new(7);
+    let entry = fact("tea", "Tea");
+    let query_hash = entry.claim.key_hash();
+    semantic.insert(entry).unwrap();
+    semantic.insert(fact("coffee", "Coffee")).unwrap();
+    semantic.update_confidence("tea", 0.5, &mut ids).unwrap();
+    // Updating a superseded version extends the chain, it does not fork it
+    semantic.update_confidence("tea", 0.3, &mut ids).unwrap();
+
+    let hits = semantic.retrieve(query_hash).unwrap();
+    assert_eq!(hits.len(), 2, "One entry per chain");
+    let tea = hits.iter().find(|e| e.claim.object == ClaimValue::Text("Tea".to_string())).unwrap();
+    assert_eq!((tea.version, tea.confidence), (3, 0.3));
+
+    let evidence = MemoryRetriever::retrieve(query_hash, &InMemoryEpisodicStore::new(), &semantic);
+    assert_eq!(evidence.len(), 2);
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn test_compaction_is_explicit() {
+    let (mut semantic, path) = store("compact");
+    let mut ids = IdGenerator::new(7);
+    semantic.insert(fact("tea", "Tea")).unwrap();
+    let head = semantic.update_confidence("tea", 0.7, &mut ids).unwrap();
+    semantic.update_confidence(&head, 0.8, &mut ids).unwrap();
+
+    // Survives a reload untouched
+    let mut reloaded = FileSemanticStore::new(path.clone());
+    reloaded.load().unwrap();
+    assert_eq!(reloaded.version_count(), 3);
+
+    assert_eq!(reloaded.compact().unwrap(), 2);
+    assert_eq!(reloaded.version_count(), 1);
+    assert!(matches!(reloaded.history("tea"), Err(MemoryError::NotFound)));
+    let latest = reloaded.retrieve(fact("x", "Tea").claim.key_hash()).unwrap();
+    assert_eq!(latest.len(), 1);
+    assert_eq!((latest[0].version, latest[0].confidence), (3, 0.8));
+    assert_eq!(latest[0].previous_version_id, None);
+    assert_eq!(reloaded.compact().unwrap(), 0);
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn test_unknown_id() {
+    let (mut semantic, path) = store("unknown");
+    let mut ids = IdGenerator::new(7);
+    assert!(matches!(semantic.update_confidence("missing", 0.5, &mut ids), Err(MemoryError::NotFound)));
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn test_version_ids_replay_from_the_seed() {
+    let chain = |name: &str| {
+        let (mut semantic, path) = store(name);
+        let mut ids = IdGenerator::new(7);
+        semantic.insert(fact("tea", "Tea")).unwrap();
+        let v2 = semantic.update_confidence("tea", 0.6, &mut ids).unwrap();
+        let v3 = semantic.update_confidence(&v2, 0.4, &mut ids).unwrap();
+        let _ = fs::remove_file(path);
+        (v2, v3)
+    };
+    assert_eq!(chain("replay_a"), chain("replay_b"), "Same seed, same version ids");
+}
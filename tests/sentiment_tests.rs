@@ -0,0 +1,103 @@
+use nexus::kernel::event::{AudioSignal, InputContent};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::sidecar::{Disabled, SentimentEstimator};
+use nexus::kernel::state::StateDelta;
+use nexus::monitor::{LexiconSentiment, SelfObservation, SelfObservationMonitor, Sentiment};
+
+mod common;
+use common::input;
+
+fn build(sentiment: Option<Box<dyn SentimentEstimator>>) -> Reactor {
+    let (tx, rx) = tokio::sync::mpsc::channel(100);
+    let mut builder = Reactor::builder(rx, tx).config(ReactorConfig { llm_planning: false, ..Default::default() });
+    if let Some(sentiment) = sentiment {
+        builder = builder.sentiment(sentiment);
+    }
+    builder.build()
+}
+
+// Estimator that records what it was asked, and only reads "awful"
+struct Recording(std::sync::Arc<std::sync::Mutex<Vec<String>>>);
+
+impl SentimentEstimator for Recording {
+    fn estimate(&mut self, transcript: &str, _language: Option<&str>) -> Option<Sentiment> {
+        self.0.lock().unwrap().push(transcript.to_string());
+        transcript.contains("awful").then_some(Sentiment { valence: -0.9, confidence: 1.0 })
+    }
+}
+
+#[test]
+fn test_lexicon_scoring() {
+    let lexicon = LexiconSentiment::new();
+    let frustrated = lexicon.score("Ugh, this is really annoying and useless", None).unwrap();
+    assert!(frustrated.is_frustrated(), "{:?}", frustrated);
+    assert_eq!(frustrated.confidence, 1.0);
+
+    let pleased = lexicon.score("Thanks, that was great", Some("en")).unwrap();
+    assert!(pleased.valence > 0.3, "{:?}", pleased);
+
+    // Negation flips (damped), intensifiers amplify
+    assert!(lexicon.score("that was not good", None).unwrap().valence < 0.0);
+    let plain = lexicon.score("annoying", None).unwrap().valence;
+    assert!(lexicon.score("very annoying", None).unwrap().valence < plain);
+
+    assert_eq!(lexicon.score("Turn on the lights", None), None, "No sentiment words");
+    assert_eq!(lexicon.score("c'est nul, terrible", Some("fr")), None, "Lexicon covers English only");
+    assert!(LexiconSentiment::new().with_words(&[("laggy", -0.7)]).score("so laggy", None).is_some());
+}
+
+#[test]
+fn test_monitor_frustration_raises_sensitivity_and_penalty() {
+    let mut monitor = SelfObservationMonitor::new();
+    let delta = monitor.tick(1, &[SelfObservation::UserSentiment { valence: -0.8, confidence: 1.0 }]).expect("Should emit delta");
+    let StateDelta::MetaLatentUpdate { delta: frustrated } = delta else { panic!("Wrong delta type") };
+    assert!(frustrated.interruption_sensitivity > 0.0, "{:?}", frustrated);
+    assert!(frustrated.confidence_penalty > 0.0, "{:?}", frustrated);
+
+    // A pleased turn heals faster than decay alone
+    let mut healed = SelfObservationMonitor::new();
+    healed.tick(1, &[SelfObservation::UserSentiment { valence: -0.8, confidence: 1.0 }]);
+    let mut decayed = SelfObservationMonitor::new();
+    decayed.tick(1, &[SelfObservation::UserSentiment { valence: -0.8, confidence: 1.0 }]);
+    let penalty = |d: Option<StateDelta>| match d {
+        Some(StateDelta::MetaLatentUpdate { delta }) => delta.confidence_penalty,
+        _ => panic!("Should emit delta"),
+    };
+    let with_praise = penalty(healed.tick(2, &[SelfObservation::UserSentiment { valence: 0.8, confidence: 1.0 }]));
+    let without = penalty(decayed.tick(2, &[]));
+    assert!(with_praise < without, "{} vs {}", with_praise, without);
+}
+
+#[tokio::test]
+async fn test_frustrated_turn_adjusts_meta_latents() {
+    let mut reactor = build(None);
+    reactor.tick_step(vec![input(InputContent::Text("This is useless, really frustrating".to_string()))]);
+    let latents = reactor.state.meta_latents();
+    assert!(latents.interruption_sensitivity > 0.0 && latents.confidence_penalty > 0.0, "{:?}", latents);
+
+    // Switched off: the same turn leaves meta-latents alone
+    let mut reactor = build(Some(Box::new(Disabled)));
+    reactor.tick_step(vec![input(InputContent::Text("This is useless, really frustrating".to_string()))]);
+    assert_eq!(reactor.state.meta_latents().confidence_penalty, 0.0);
+}
+
+#[tokio::test]
+async fn test_segment_estimated_once() {
+    let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mut reactor = build(Some(Box::new(Recording(seen.clone()))));
+    reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechStart))]);
+    let segment_id = reactor.state.active_segment_id().cloned().expect("Segment opened");
+    reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechEnd))]);
+    for content in ["this is", "this is awful", "this is awful, honestly"] {
+        reactor.tick_step(vec![input(InputContent::ProvisionalText {
+            content: content.to_string(),
+            confidence: 0.9,
+            source_id: segment_id.clone(),
+            words: Vec::new(),
+            language: None,
+            is_final: true,
+        })]);
+    }
+    assert_eq!(*seen.lock().unwrap(), ["this is", "this is awful"], "Reported once per segment");
+    assert!(reactor.state.meta_latents().confidence_penalty > 0.0);
+}
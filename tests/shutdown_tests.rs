@@ -0,0 +1,121 @@
+use nexus::integrations::home::HomeMapping;
+use nexus::kernel::event::{AudioSignal, Event, InputContent};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::shutdown::ShutdownOptions;
+use nexus::memory::store::FileSemanticStore;
+use serde_json::json;
+use std::path::PathBuf;
+use tokio::sync::mpsc;
+
+mod common;
+use common::input;
+
+fn temp(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("nexus_shutdown_{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn reactor(name: &str) -> (Reactor, mpsc::Sender<Event>) {
+    let mapping: HomeMapping = serde_json::from_value(json!({
+        "rules": [
+            { "name": "lights off", "phrases": ["turn off the lights"],
+              "target": { "Mqtt": { "topic": "home/lights/set", "payload": "OFF" } } }
+        ]
+    })).unwrap();
+    let (tx, rx) = mpsc::channel(100);
+    let reactor = Reactor::builder(rx, tx.clone())
+        .config(ReactorConfig { llm_planning: false, ..Default::default() })
+        .semantic_store(Box::new(FileSemanticStore::new(temp(&format!("{}_semantic.json", name)))))
+        .home_mapping(mapping)
+        .build();
+    (reactor, tx)
+}
+
+fn options(name: &str) -> ShutdownOptions {
+    ShutdownOptions {
+        checkpoint: Some(temp(&format!("{}_checkpoint.json", name))),
+        telemetry_spool: Some(temp(&format!("{}_spool.jsonl", name))),
+        state: None,
+    }
+}
+
+#[tokio::test]
+async fn test_shutdown_drops_work_in_flight() {
+    let (mut reactor, tx) = reactor("drops");
+    // A segment waiting for its transcript, then a home command (tool call + requested ack)
+    reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechStart))]);
+    reactor.tick_step(vec![input(InputContent::Audio(AudioSignal::SpeechEnd))]);
+    let effects = reactor.tick_step(vec![input(InputContent::Text("turn off the lights".to_string()))]);
+    let call_id = effects.iter().find_map(|e| match e {
+        SideEffect::InvokeTool { call_id, .. } => Some(call_id.clone()),
+        _ => None,
+    }).expect("tool call");
+    let outputs = reactor.state.active_outputs().len();
+    let transcriptions = reactor.transcriptions_in_flight.len();
+    assert!(transcriptions > 0);
+    // Arrives after the last tick: never processed
+    tx.send(input(InputContent::Text("never mind".to_string()))).await.unwrap();
+
+    let (report, effects) = reactor.shutdown(&options("drops"));
+    assert_eq!(report.events_dropped, 1);
+    assert_eq!(report.pending_effects_dropped, outputs + transcriptions + 1);
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::StopAudio)), "The ack may still be generating");
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::CancelTool { call_id: id } if *id == call_id)));
+    assert_eq!(effects.iter().filter(|e| matches!(e, SideEffect::CancelTranscription { .. })).count(), transcriptions);
+    assert!(reactor.state.active_outputs().is_empty());
+    assert!(reactor.transcriptions_in_flight.is_empty());
+    assert_eq!(reactor.home.in_flight(), 0);
+}
+
+#[tokio::test]
+async fn test_shutdown_flushes_and_checkpoints() {
+    let (mut reactor, _tx) = reactor("flush");
+    reactor.tick_step(vec![input(InputContent::Text("hello there".to_string()))]);
+    let options = options("flush");
+
+    let (report, effects) = reactor.shutdown(&options);
+    assert!(report.is_clean(), "{:?}", report.flushed);
+    assert_eq!(report.flushed.iter().map(|f| f.store).collect::<Vec<_>>(), ["semantic", "telemetry", "checkpoint"]);
+    assert_eq!(report.checkpoint_path, options.checkpoint);
+    assert!(effects.iter().all(|e| matches!(e, SideEffect::StopAudio)), "Nothing else was running");
+
+    let checkpoint: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(options.checkpoint.unwrap()).unwrap()).unwrap();
+    assert_eq!(checkpoint["view"]["tick"]["frame"], report.tick.frame);
+    assert!(!checkpoint.to_string().contains("hello there"), "Content-free");
+    assert!(options.telemetry_spool.unwrap().exists());
+
+    // A store that cannot be written is reported, not fatal
+    let (mut reactor, _tx) = self::reactor("unwritable");
+    let (report, _) = reactor.shutdown(&ShutdownOptions { checkpoint: Some(PathBuf::from("/nonexistent/dir/checkpoint.json")), telemetry_spool: None, state: None });
+    assert!(!report.is_clean());
+    assert!(report.checkpoint_path.is_none());
+    assert_eq!(report.flushed.iter().map(|f| f.store).collect::<Vec<_>>(), ["semantic", "checkpoint"]);
+}
+
+#[tokio::test]
+async fn test_restart_keeps_driver_handles() {
+    let (mut reactor, tx) = reactor("restart");
+    let view = reactor.subscribe_view();
+    reactor.tick_step(vec![input(InputContent::Text("turn off the lights".to_string()))]);
+    assert!(!reactor.state.active_intents().is_empty());
+
+    let (report, _) = reactor.restart(&options("restart"));
+    assert!(report.is_clean());
+    assert!(report.pending_effects_dropped > 0);
+    assert_eq!(reactor.tick.frame, 0);
+    assert!(reactor.state.active_intents().is_empty());
+    assert_eq!(reactor.home.mapping().rules.len(), 1, "Home mapping carried over");
+
+    // Same event channel, same view subscription
+    tx.send(input(InputContent::Text("turn off the lights".to_string()))).await.unwrap();
+    let mut events = Vec::new();
+    while let Ok(event) = reactor.receiver.try_recv() {
+        events.push(event);
+    }
+    assert_eq!(events.len(), 1);
+    reactor.tick_step(events);
+    assert_eq!(view.borrow().tick.frame, reactor.tick.frame);
+    assert!(view.borrow().active_intents > 0);
+}
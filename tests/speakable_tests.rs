@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use nexus::outputs::speakable::speakable;
+use nexus::outputs::tts_cache::{TtsCache, TtsCacheLimits};
+
+#[test]
+fn test_dates() {
+    assert_eq!(speakable("The release is on 2025-11-03.", "en"), "The release is on November 3, 2025.");
+    assert_eq!(speakable("Am 2025-11-03 ist es soweit", "de"), "Am 3. November 2025 ist es soweit");
+    assert_eq!(speakable("(2025-01-09)", "en-US"), "(January 9, 2025)");
+    // Not a calendar date
+    assert_eq!(speakable("Ticket 2025-13-40", "en"), "Ticket 2025-13-40");
+}
+
+#[test]
+fn test_numbers_and_units() {
+    assert_eq!(speakable("It is 42km away", "en"), "It is 42 kilometers away");
+    assert_eq!(speakable("Only 1 km left.", "en"), "Only 1 kilometer left.");
+    assert_eq!(speakable("Battery at 85%, 3.5GB free", "en"), "Battery at 85 percent, 3 point 5 gigabytes free");
+    assert_eq!(speakable("It is -4°C and 1,250 people came", "en"), "It is minus 4 degrees Celsius and 1250 people came");
+    assert_eq!(speakable("Es sind 3,5 km und 1.250 Leute", "de"), "Es sind 3 Komma 5 Kilometer und 1250 Leute");
+    // A version number is not a decimal
+    assert_eq!(speakable("Update to 1.2.3", "en"), "Update to 1.2.3");
+}
+
+#[test]
+fn test_abbreviations() {
+    assert_eq!(speakable("Fruit, e.g. apples, etc.", "en"), "Fruit, for example apples, et cetera.");
+    assert_eq!(speakable("Dr. Smith vs. the rest", "en"), "Doctor Smith versus the rest");
+    assert_eq!(speakable("Obst, z.B. Äpfel usw.", "de"), "Obst, zum Beispiel Äpfel und so weiter.");
+}
+
+#[test]
+fn test_code_identifiers() {
+    assert_eq!(speakable("Set `max_retries` to 3", "en"), "Set max retries to 3");
+    assert_eq!(speakable("Call parseJson() in std::fs", "en"), "Call parse Json in std fs");
+    // Ordinary words and brand casing stay whole; any locale splits identifiers
+    assert_eq!(speakable("My iPhone and JSON", "en"), "My iPhone and JSON");
+    assert_eq!(speakable("Utilise max_retries", "fr"), "Utilise max retries");
+    assert_eq!(speakable("Il fait 42km", "fr"), "Il fait 42km", "No lexicon: numbers untouched");
+}
+
+#[test]
+fn test_voice_only() {
+    let mut cache = TtsCache::new(TtsCacheLimits { max_entries: 4, max_bytes: 1024, ttl: Duration::from_secs(60) }).with_locale("en");
+    let text = "Meet on 2025-11-03";
+    assert_eq!(cache.spoken(text), "Meet on November 3, 2025");
+
+    let voice = cache.playback_command(text, false, None);
+    let args: Vec<_> = voice.as_std().get_args().collect();
+    assert_eq!(args, vec!["Meet on November 3, 2025"]);
+
+    // Caption-only: nothing is spoken, the timer is sized from the text as written
+    let silent = cache.playback_command(text, true, None);
+    assert_eq!(silent.as_std().get_program(), "sleep");
+}
@@ -0,0 +1,67 @@
+use nexus::kernel::footprint::{FootprintCeilings, FootprintDomain, FootprintMonitor};
+use nexus::kernel::state::{SharedState, StateDelta};
+use nexus::kernel::audio::segment::{AudioSegment, SegmentStatus};
+use nexus::kernel::time::Tick;
+
+fn transcribed_segment(state: &mut SharedState, id: &str, frame: u64) {
+    state.reduce(StateDelta::AudioSegmentCreated(AudioSegment::new(id.to_string(), Tick { frame })));
+    state.reduce(StateDelta::AudioFrameAppended { segment_id: id.to_string(), frames: vec![0.0; 480] });
+    state.reduce(StateDelta::AudioSegmentFinalized { segment_id: id.to_string(), end_tick: Tick { frame: frame + 1 } });
+    state.reduce(StateDelta::AudioSegmentTranscribed { segment_id: id.to_string(), text: "x".to_string(), words: Vec::new(), language: None });
+}
+
+fn small_ceilings() -> FootprintCeilings {
+    FootprintCeilings { audio_segments: 10, ..Default::default() }
+}
+
+#[test]
+fn test_footprint_counts_and_bytes() {
+    let mut state = SharedState::new();
+    transcribed_segment(&mut state, "seg_a", 1);
+    transcribed_segment(&mut state, "seg_b", 2);
+
+    let monitor = FootprintMonitor::new(FootprintCeilings::default());
+    let report = monitor.account(Tick { frame: 10 }, &state, 3);
+
+    assert_eq!(report.footprint.audio_segments.count, 2);
+    assert!(report.footprint.audio_segments.approx_bytes >= 2 * 480 * 4, "Frames must be accounted");
+    assert_eq!(report.footprint.conversation_log.count, 3);
+    assert!(report.pressured.is_empty());
+    assert!(report.deltas.is_empty());
+}
+
+#[test]
+fn test_janitor_prunes_oldest_finished_segments_under_pressure() {
+    let mut state = SharedState::new();
+    for i in 0..9 {
+        transcribed_segment(&mut state, &format!("seg_{}", i), i * 10);
+    }
+    // Still capturing: must survive the janitor
+    state.reduce(StateDelta::AudioSegmentCreated(AudioSegment::new("live".to_string(), Tick { frame: 0 })));
+
+    let monitor = FootprintMonitor::new(small_ceilings());
+    let report = monitor.account(Tick { frame: 100 }, &state, 0);
+    assert_eq!(report.pressured, vec![FootprintDomain::AudioSegments]);
+
+    for d in report.deltas {
+        state.reduce(d);
+    }
+
+    // 10 segments, target 60% of 10 -> 6 remain
+    assert_eq!(state.audio_segments().len(), 6);
+    assert!(state.audio_segments().contains_key("live"));
+    assert!(!state.audio_segments().contains_key("seg_0"), "Oldest finished segment pruned first");
+    assert!(state.audio_segments().contains_key("seg_8"));
+    assert_eq!(state.audio_segments()["live"].status, SegmentStatus::Buffering);
+}
+
+#[test]
+fn test_accounting_runs_on_interval() {
+    let state = SharedState::new();
+    let mut monitor = FootprintMonitor::new(FootprintCeilings::default());
+    monitor.interval_ticks = 50;
+
+    assert!(monitor.tick(Tick { frame: 1 }, &state, 0).is_some());
+    assert!(monitor.tick(Tick { frame: 20 }, &state, 0).is_none());
+    assert!(monitor.tick(Tick { frame: 51 }, &state, 0).is_some());
+}
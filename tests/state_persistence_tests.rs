@@ -0,0 +1,162 @@
+use nexus::kernel::context::ContextId;
+use nexus::kernel::intent::long_horizon::{IntentStatus, LongHorizonIntent};
+use nexus::kernel::intent::types::{IntentCandidate, IntentHypothesis, IntentStability};
+use nexus::kernel::memory::consent::MemoryConsentState;
+use nexus::kernel::memory::sensitivity::SensitivityClass;
+use nexus::kernel::memory::types::{MemoryKey, MemoryRecord};
+use nexus::kernel::persist::{PersistError, STATE_SCHEMA_VERSION};
+use nexus::kernel::presence::PresenceState;
+use nexus::kernel::shutdown::ShutdownOptions;
+use nexus::kernel::state::{SharedState, StateDelta};
+use nexus::kernel::time::Tick;
+use std::path::PathBuf;
+
+mod common;
+use common::reactor;
+
+fn temp(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("nexus_persist_{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn candidate(id: &str, hash: u64) -> IntentCandidate {
+    IntentCandidate {
+        id: id.to_string(),
+        hypothesis: IntentHypothesis::Statement,
+        confidence: 0.9,
+        source_symbol_ids: vec![format!("seg_{}", id)],
+        semantic_hash: hash,
+        stability: IntentStability::Stable,
+    }
+}
+
+fn intent(id: &str, status: IntentStatus, at: u64) -> LongHorizonIntent {
+    LongHorizonIntent {
+        id: id.to_string(),
+        hypothesis: IntentHypothesis::Inquiry,
+        source_symbol_ids: vec![],
+        created_at: Tick { frame: at },
+        last_active_at: Tick { frame: at },
+        last_updated_at: Tick { frame: at },
+        suspended_at: None,
+        decay_score: 0.9,
+        status,
+        context: ContextId::default(),
+    }
+}
+
+// A session worth keeping, plus what belongs to it alone
+fn session() -> Vec<StateDelta> {
+    let kept = MemoryKey::from_intent(&candidate("intent-1", 11));
+    vec![
+        StateDelta::Tick(Tick { frame: 4_000 }),
+        StateDelta::MemoryPromoted(MemoryRecord {
+            id: "mem-1".to_string(),
+            intent: candidate("intent-1", 11),
+            first_committed_at: Tick { frame: 1_200 },
+            last_accessed_at: Tick { frame: 3_000 },
+            strength: 0.7,
+            topic_id: Some("topic-1".to_string()),
+            sensitivity: None,
+            context: ContextId::default(),
+        }),
+        StateDelta::LongHorizonIntentUpdate(intent("lh-active", IntentStatus::Active, 3_900)),
+        StateDelta::LongHorizonIntentUpdate(intent("lh-dormant", IntentStatus::Dormant, 500)),
+        StateDelta::LongHorizonIntentUpdate(intent("lh-done", IntentStatus::Completed, 100)),
+        StateDelta::IntentSensitivityMarked { intent_id: "lh-dormant".to_string(), class: SensitivityClass::Health },
+        StateDelta::IntentSensitivityMarked { intent_id: "lh-done".to_string(), class: SensitivityClass::Finance },
+        StateDelta::MemoryConsentAsked(kept.clone(), Tick { frame: 1_000 }),
+        StateDelta::MemoryConsentResolved { key: kept, state: MemoryConsentState::Granted, resolved_at: Tick { frame: 1_100 } },
+        // Asked, never answered: the prompt ends with the session
+        StateDelta::MemoryConsentAsked(MemoryKey::from_intent(&candidate("intent-2", 22)), Tick { frame: 3_950 }),
+        StateDelta::TopicConsentResolved { topic_id: "topic-2".to_string(), state: MemoryConsentState::Declined },
+        StateDelta::PresenceUpdate(PresenceState::Dormant),
+    ]
+}
+
+fn assert_restored(state: &SharedState) {
+    assert_eq!(state.last_tick(), Tick { frame: 4_000 });
+    assert_eq!(state.presence(), PresenceState::Dormant);
+    assert_eq!(state.long_term_memory()["mem-1"].strength, 0.7);
+    let mut intents: Vec<&String> = state.active_intents().keys().collect();
+    intents.sort();
+    assert_eq!(intents, ["lh-active", "lh-dormant"], "Completed intents are not kept");
+    assert_eq!(state.sensitive_intents().len(), 1);
+    assert!(state.sensitive_unconsented(&"lh-dormant".to_string()));
+    let consent: Vec<_> = state.memory_consent().values().map(|c| (c.state, c.resolved_at)).collect();
+    assert_eq!(consent, [(MemoryConsentState::Granted, Some(Tick { frame: 1_100 }))]);
+    assert_eq!(state.topic_consent()["topic-2"], MemoryConsentState::Declined);
+}
+
+#[test]
+fn test_serialize_round_trip() {
+    let mut state = SharedState::new();
+    for delta in session() {
+        state.reduce(delta);
+    }
+    let text = state.serialize().unwrap();
+    let restored = SharedState::deserialize(&text).unwrap();
+    assert_restored(&restored);
+    assert_eq!(restored.serialize().unwrap(), text, "Stable output");
+}
+
+#[test]
+fn test_rejects_other_schema_versions() {
+    let text = SharedState::new().serialize().unwrap();
+    let newer = text.replacen(&format!("\"version\": {}", STATE_SCHEMA_VERSION), "\"version\": 99", 1);
+    assert_eq!(SharedState::deserialize(&newer).unwrap_err(), PersistError::Version(99));
+    assert!(matches!(SharedState::deserialize("{\"tick\": 3}"), Err(PersistError::Malformed(_))));
+    assert!(matches!(SharedState::deserialize("not json"), Err(PersistError::Malformed(_))));
+    assert!(matches!(reactor().hydrate_from_disk(&temp("missing.json")), Err(PersistError::Io(_))));
+}
+
+#[tokio::test]
+async fn test_hydrate_resumes_after_shutdown() {
+    let path = temp("shutdown_state.json");
+    let mut before = reactor();
+    for delta in session() {
+        before.state.reduce(delta);
+    }
+    before.tick = before.state.last_tick();
+    let (report, _) = before.shutdown(&ShutdownOptions { state: Some(path.clone()), ..Default::default() });
+    assert!(report.is_clean());
+
+    let mut after = reactor();
+    let hydrated = after.hydrate_from_disk(&path).unwrap();
+    assert_eq!((hydrated.memories, hydrated.intents), (1, 2));
+    assert_restored(&after.state);
+    // Kernel time continues
+    assert_eq!(after.tick, Tick { frame: 4_000 });
+
+    // The intent manager took the intents over: they keep decaying
+    for _ in 0..5 {
+        after.tick_step(vec![]);
+    }
+    assert_eq!(after.tick.frame, 4_005);
+    assert!(after.state.active_intents()["lh-active"].decay_score < 0.9);
+}
+
+#[test]
+fn test_exchange_in_progress_is_not_restored() {
+    let mut state = SharedState::new();
+    state.reduce(StateDelta::PresenceUpdate(PresenceState::Engaged));
+    let restored = SharedState::deserialize(&state.serialize().unwrap()).unwrap();
+    assert_eq!(restored.presence(), PresenceState::Attentive);
+}
+
+#[tokio::test]
+async fn test_restart_keeps_persisted_state() {
+    let mut reactor = reactor();
+    for delta in session() {
+        reactor.state.reduce(delta);
+    }
+    reactor.restart(&ShutdownOptions::default());
+    assert!(reactor.state.long_term_memory().is_empty(), "Nothing persisted: state starts over");
+
+    for delta in session() {
+        reactor.state.reduce(delta);
+    }
+    reactor.restart(&ShutdownOptions { state: Some(temp("restart_state.json")), ..Default::default() });
+    assert_restored(&reactor.state);
+}
@@ -0,0 +1,85 @@
+use nexus::kernel::event::{AudioSignal, Event, InputContent, InputEvent};
+use nexus::kernel::reactor::{KernelMode, Reactor, ReactorConfig};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+
+fn speech(signal: AudioSignal) -> Event {
+    Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::Audio(signal), captured_at: None })
+}
+
+#[tokio::test]
+async fn test_view_published_every_tick() {
+    let (tx, rx) = mpsc::channel(10);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
+    let mut view = reactor.subscribe_view();
+
+    reactor.tick_step(vec![speech(AudioSignal::SpeechStart)]);
+    assert!(view.has_changed().unwrap());
+    {
+        let v = view.borrow_and_update();
+        assert_eq!(v.tick, reactor.tick);
+        assert_eq!(v.version, reactor.state.version());
+        assert!(v.buffering);
+    }
+
+    reactor.tick_step(vec![speech(AudioSignal::SpeechEnd)]);
+    assert!(!view.borrow().buffering);
+}
+
+#[tokio::test]
+async fn test_mode_change_published_immediately() {
+    let (tx, rx) = mpsc::channel(10);
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig::default());
+    let view = reactor.subscribe_view();
+
+    reactor.set_mode(KernelMode::Onboarding);
+    assert_eq!(view.borrow().mode, KernelMode::Onboarding);
+}
+
+#[tokio::test]
+async fn test_view_readable_while_reactor_locked() {
+    let (tx, rx) = mpsc::channel(10);
+    let reactor = Reactor::new(rx, tx, ReactorConfig::default());
+    let view = reactor.subscribe_view();
+    let shared = Arc::new(Mutex::new(reactor));
+
+    let guard = shared.lock().unwrap();
+    // Reader on another thread must not wait for the kernel lock
+    let reader = std::thread::spawn(move || view.borrow().tick);
+    assert_eq!(reader.join().unwrap(), guard.tick);
+}
+
+#[cfg(feature = "llm")]
+#[tokio::test]
+async fn test_planning_indicator_follows_dispatch() {
+    use nexus::kernel::epoch::{EpochPolicy, StaleDomains};
+    use nexus::kernel::view::Cognition;
+    use nexus::planner::types::{Intent, PlanningEpoch};
+
+    let (tx, rx) = mpsc::channel(10);
+    // Every mutation counts, so a plain tick re-dispatches after a plan
+    let epoch = EpochPolicy { domains: StaleDomains::ALL, ..Default::default() };
+    let mut reactor = Reactor::new(rx, tx, ReactorConfig { epoch, ..Default::default() });
+    let view = reactor.subscribe_view();
+    assert_eq!(view.borrow().cognition, Cognition::Idle);
+
+    // Quiescent state: dispatched to the LLM planner
+    reactor.tick_step(vec![]);
+    assert_eq!(view.borrow().cognition, Cognition::Planning);
+
+    // No re-dispatch from here on, so each clearing edge is visible
+    reactor.config.llm_planning = false;
+
+    // Plan received: done thinking
+    let epoch = PlanningEpoch { tick: reactor.tick, state_version: reactor.state.version() };
+    reactor.tick_step(vec![Event::PlanProposed(epoch, Intent::DoNothing)]);
+    assert_eq!(view.borrow().cognition, Cognition::Idle);
+
+    // Aborted by new input
+    reactor.config.llm_planning = true;
+    reactor.tick_step(vec![]);
+    assert_eq!(view.borrow().cognition, Cognition::Planning);
+    reactor.config.llm_planning = false;
+    reactor.tick_step(vec![speech(AudioSignal::SpeechStart)]);
+    assert_eq!(view.borrow().cognition, Cognition::Idle);
+}
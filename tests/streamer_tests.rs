@@ -0,0 +1,146 @@
+use nexus::kernel::event::{AudioSignal, AudioStatus, Event, InputContent, InputEvent, OutputId};
+use nexus::kernel::memory::sensitivity::RedactionLevel;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::outputs::streamer::{chunk_id, parent_of, play_chunks, sentence_chunks, ChunkCompletion, SpeechStreamer, MAX_CHUNKS};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+
+mod common;
+
+fn reactor() -> Reactor {
+    common::builder()
+        .config(ReactorConfig { chunked_speech: true, ..common::config() })
+        .build()
+}
+
+fn text(text: &str) -> Event {
+    Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::Text(text.to_string()), captured_at: None })
+}
+
+fn driver(content: InputContent) -> Event {
+    Event::Input(InputEvent { source: "Driver".to_string(), content, captured_at: None })
+}
+
+fn status(status: AudioStatus) -> Event {
+    driver(InputContent::AudioStatus(status))
+}
+
+fn spoken(effects: &[SideEffect]) -> Vec<(OutputId, String)> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::SpawnAudio(id, text) => Some((*id, text.clone())),
+        _ => None,
+    }).collect()
+}
+
+// A bookmark recall: "Back to <name>. Where we left it: <summary>."
+fn recall(reactor: &mut Reactor) -> Vec<(OutputId, String)> {
+    reactor.tick_step(vec![text("How much can we cut from the travel budget?")]);
+    reactor.tick_step(vec![text("remember this point")]);
+    reactor.tick_step(vec![text("switch to work context")]);
+    spoken(&reactor.tick_step(vec![text("go back to the budget discussion")]))
+}
+
+fn sleep(secs: &str) -> tokio::process::Command {
+    let mut command = tokio::process::Command::new("sleep");
+    command.arg(secs);
+    command
+}
+
+#[test]
+fn test_chunk_ids_and_sentences() {
+    let parent = OutputId { tick: 7, ordinal: 2 };
+    let chunk = chunk_id(parent, 3).unwrap();
+    assert_eq!(chunk.tick, 7);
+    assert_eq!(parent_of(chunk), Some((parent, 3)));
+    assert_eq!(parent_of(parent), None, "A whole output");
+    assert_eq!(chunk_id(chunk, 0), None, "Chunks are not split again");
+    assert_eq!(chunk_id(parent, MAX_CHUNKS), None);
+
+    assert_eq!(sentence_chunks("Back to the budget. Where we left it: travel costs."), ["Back to the budget.", "Where we left it: travel costs."]);
+    assert_eq!(sentence_chunks("Done."), ["Done."]);
+    let long = "This is one more sentence. ".repeat(MAX_CHUNKS as usize + 5);
+    let chunks = sentence_chunks(&long);
+    assert_eq!(chunks.len(), MAX_CHUNKS as usize, "The rest is spoken with the last one");
+    assert_eq!(chunks.last().unwrap().matches("sentence").count(), 6);
+}
+
+#[test]
+fn test_completions_track_the_parent() {
+    let mut streamer = SpeechStreamer::new();
+    let parent = OutputId { tick: 3, ordinal: 0 };
+    assert_eq!(spoken(&streamer.split(parent, "Done.".to_string())), vec![(parent, "Done.".to_string())], "One sentence: as it is");
+    assert!(streamer.is_empty());
+
+    let effects = streamer.split(parent, "The first sentence is here. The second one is as long.".to_string());
+    let ids: Vec<OutputId> = spoken(&effects).into_iter().map(|(id, _)| id).collect();
+    assert_eq!(ids, vec![chunk_id(parent, 0).unwrap(), chunk_id(parent, 1).unwrap()]);
+    assert_eq!(streamer.completed(ids[0]), ChunkCompletion::Partial { parent, percent: 50 });
+    assert_eq!(streamer.completed(ids[1]), ChunkCompletion::Last(parent));
+    assert!(streamer.is_empty());
+    assert_eq!(streamer.completed(parent), ChunkCompletion::Whole);
+}
+
+#[tokio::test]
+async fn test_chunked_output_commits_on_its_last_sentence() {
+    let mut reactor = reactor();
+    let said = recall(&mut reactor);
+    assert_eq!(said.len(), 2, "{:?}", said);
+    let (parent, first) = parent_of(said[0].0).unwrap();
+    assert_eq!((first, parent_of(said[1].0)), (0, Some((parent, 1))));
+    let output = &reactor.state.active_outputs()[&parent];
+    assert_eq!(output.content.as_str(), format!("{} {}", said[0].1, said[1].1), "State holds the whole output");
+    let last_turn = reactor.dialogue.recent(1, RedactionLevel::None);
+    assert_eq!(last_turn[0].text, output.content.as_str(), "Logged whole");
+
+    reactor.tick_step(vec![status(AudioStatus::PlaybackStarted { output_id: parent.into() })]);
+    reactor.tick_step(vec![status(AudioStatus::PlaybackCompleted { output_id: said[0].0 })]);
+    let heard = reactor.state.playback_progress()[&parent];
+    assert!(heard > 0 && heard < 100, "{}", heard);
+    assert!(reactor.state.active_outputs()[&parent].committed_at.is_none(), "Not yet committed");
+
+    reactor.tick_step(vec![status(AudioStatus::PlaybackCompleted { output_id: said[1].0 })]);
+    assert!(reactor.state.active_outputs()[&parent].committed_at.is_some());
+    reactor.tick_step(vec![status(AudioStatus::PlaybackEnded { output_id: parent.into(), elapsed_ms: 3000 })]);
+    assert!(reactor.streamer.is_empty());
+}
+
+#[tokio::test]
+async fn test_interruption_keeps_the_sentences_heard() {
+    let mut reactor = reactor();
+    let said = recall(&mut reactor);
+    let (parent, _) = parent_of(said[0].0).unwrap();
+    reactor.tick_step(vec![status(AudioStatus::PlaybackStarted { output_id: parent.into() })]);
+    reactor.tick_step(vec![status(AudioStatus::PlaybackCompleted { output_id: said[0].0 })]);
+
+    let effects = reactor.tick_step(vec![driver(InputContent::Audio(AudioSignal::SpeechStart))]);
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::StopAudio)));
+    let report = &reactor.recent_interruptions(1)[0];
+    assert_eq!(report.output_id, parent);
+    assert!(report.spoken_chars.abs_diff(said[0].1.chars().count()) <= 1, "{} of {:?}", report.spoken_chars, said[0].1);
+
+    // The driver's stopped stream ends the parent; nothing of it is left to track
+    reactor.tick_step(vec![status(AudioStatus::PlaybackEnded { output_id: parent.into(), elapsed_ms: 1200 })]);
+    assert!(reactor.streamer.is_empty());
+}
+
+#[tokio::test]
+async fn test_stop_kills_only_the_remaining_chunks() {
+    let parent = OutputId { tick: 1, ordinal: 0 };
+    let (queue, chunks) = mpsc::unbounded_channel();
+    let (stop_tx, stop_rx) = oneshot::channel();
+    let (tx, mut rx) = mpsc::channel(10);
+    for (index, secs) in [(0, "0.05"), (1, "5"), (2, "5")] {
+        queue.send((chunk_id(parent, index).unwrap(), sleep(secs))).unwrap();
+    }
+    drop(queue);
+    let player = tokio::spawn(play_chunks(chunks, stop_rx, tx, |chunk| status(AudioStatus::PlaybackCompleted { output_id: chunk })));
+
+    // The first chunk is heard, then the user cuts in
+    let first = tokio::time::timeout(Duration::from_secs(2), rx.recv()).await.unwrap();
+    assert!(matches!(first, Some(Event::Input(InputEvent { content: InputContent::AudioStatus(AudioStatus::PlaybackCompleted { output_id }), .. })) if output_id == chunk_id(parent, 0).unwrap()));
+    stop_tx.send(()).unwrap();
+    let finished = tokio::time::timeout(Duration::from_secs(2), player).await.unwrap().unwrap();
+    assert!(!finished);
+    assert!(rx.recv().await.is_none(), "No completion for the stopped chunks");
+}
@@ -0,0 +1,116 @@
+use nexus::kernel::telemetry::event::TelemetryEvent;
+use nexus::kernel::telemetry::export::{bucket, ExportEvent, ExportSalt};
+use nexus::kernel::telemetry::recorder::TelemetryRecorder;
+use nexus::outputs::streaming::{play_in_order, SentenceSplitter};
+use nexus::services::llm::client::StreamDecoder;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, oneshot};
+
+fn data(content: &str, stop: bool) -> String {
+    format!("data: {}\n\n", serde_json::json!({ "content": content, "stop": stop }))
+}
+
+fn sleep(secs: &str) -> tokio::process::Command {
+    let mut command = tokio::process::Command::new("sleep");
+    command.arg(secs);
+    command
+}
+
+#[test]
+fn test_sentences_complete_as_text_streams_in() {
+    let mut splitter = SentenceSplitter::new();
+    assert!(splitter.push("Which light do you").is_empty());
+    assert!(splitter.push(" mean.").is_empty(), "The next character decides");
+    assert_eq!(splitter.push(" The kitchen or"), vec!["Which light do you mean."]);
+    assert_eq!(splitter.push(" the hall? The price is 3."), vec!["The kitchen or the hall?"]);
+    assert!(splitter.push("5 euros.").is_empty(), "Not a sentence end");
+    assert_eq!(splitter.finish().as_deref(), Some("The price is 3.5 euros."));
+    assert_eq!(splitter.finish(), None);
+}
+
+#[test]
+fn test_short_sentences_are_joined() {
+    let mut splitter = SentenceSplitter::new();
+    assert!(splitter.push("Yes. Dr. ").is_empty());
+    assert_eq!(splitter.push("Smith is in at noon. "), vec!["Yes. Dr. Smith is in at noon."]);
+    assert_eq!(splitter.push("Line one is here\nand"), vec!["Line one is here"]);
+}
+
+#[test]
+fn test_decoder_handles_split_lines_and_stop() {
+    let body = [data("Turning on the kitchen", false), data(" light now. Anything", false), data(" else?", true), data("Ignored.", false)].concat();
+    let bytes = body.as_bytes();
+    let mut decoder = StreamDecoder::new();
+    let mut sentences = Vec::new();
+    // Arbitrary network chunking, including mid-line
+    for chunk in bytes.chunks(7) {
+        sentences.extend(decoder.push(chunk));
+    }
+    assert!(decoder.is_stopped());
+    assert_eq!(sentences, vec!["Turning on the kitchen light now.", "Anything else?"]);
+    assert_eq!(decoder.finish(), None);
+}
+
+#[test]
+fn test_decoder_keeps_multibyte_characters_across_chunks() {
+    let body = data("Schön, das Licht ist an… Gut.", false);
+    let mut decoder = StreamDecoder::new();
+    let mut sentences = Vec::new();
+    for byte in body.as_bytes() {
+        sentences.extend(decoder.push(std::slice::from_ref(byte)));
+    }
+    assert_eq!(sentences, vec!["Schön, das Licht ist an…"]);
+    assert!(!decoder.is_stopped(), "No stop chunk yet");
+    assert_eq!(decoder.finish().as_deref(), Some("Gut."));
+}
+
+#[tokio::test]
+async fn test_sentences_play_in_order_until_the_queue_closes() {
+    let (queue, players) = mpsc::unbounded_channel();
+    let (_stop_tx, stop_rx) = oneshot::channel();
+    queue.send(sleep("0.05")).unwrap();
+    let player = tokio::spawn(play_in_order(players, stop_rx));
+
+    // Generation is still running: a later sentence joins the queue
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    queue.send(sleep("0.05")).unwrap();
+    drop(queue);
+    assert!(player.await.unwrap(), "Every sentence played");
+}
+
+#[tokio::test]
+async fn test_stop_cancels_playback_and_the_rest_of_the_queue() {
+    let (queue, players) = mpsc::unbounded_channel();
+    let (stop_tx, stop_rx) = oneshot::channel();
+    queue.send(sleep("5")).unwrap();
+    queue.send(sleep("5")).unwrap();
+    let started = Instant::now();
+    let player = tokio::spawn(play_in_order(players, stop_rx));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    stop_tx.send(()).unwrap();
+    assert!(!player.await.unwrap());
+    assert!(started.elapsed() < Duration::from_secs(2), "Killed mid-sentence");
+
+    // Waiting for the next sentence is cancellable too
+    let (_queue, players) = mpsc::unbounded_channel();
+    let (stop_tx, stop_rx) = oneshot::channel();
+    drop(stop_tx);
+    assert!(!play_in_order(players, stop_rx).await);
+}
+
+#[test]
+fn test_first_audio_latency_telemetry() {
+    let mut telemetry = TelemetryRecorder::new();
+    telemetry.record(TelemetryEvent::FirstAudio { latency_ms: 400 });
+    telemetry.record(TelemetryEvent::FirstAudio { latency_ms: 800 });
+    let stats = telemetry.snapshot().first_audio_stats;
+    assert_eq!((stats.count, stats.max_latency_ms), (2, 800));
+    assert_eq!(stats.avg_latency_ms, 600.0);
+
+    let export = telemetry.export_content_free(&ExportSalt::new(1));
+    let exported: Vec<u64> = export.events.iter().filter_map(|e| match e {
+        ExportEvent::FirstAudio { latency_bucket } => Some(*latency_bucket),
+        _ => None,
+    }).collect();
+    assert_eq!(exported, vec![bucket(400), bucket(800)]);
+}
@@ -0,0 +1,108 @@
+use nexus::kernel::crystallizer::{explain_proactive_gate, CrystallizationDecision, GateRule};
+use nexus::kernel::event::{Event, InputContent, VisualSignal};
+use nexus::kernel::maintenance::{JobOutcome, MaintenanceJob, MaintenanceTrigger};
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::shutdown::ShutdownOptions;
+use nexus::kernel::subsystem::{Subsystem, SubsystemFlags};
+use nexus::kernel::telemetry::event::TelemetryEvent;
+use nexus::kernel::telemetry::export::{ExportEvent, ExportSalt};
+use nexus::memory::store::FileSemanticStore;
+use nexus::memory::types::{Claim, ClaimValue, EntityId, EpisodicMemoryEntry, Modality, Predicate};
+
+mod common;
+use common::input;
+
+fn reactor(name: &str) -> Reactor {
+    let semantic = std::env::temp_dir().join(format!("nexus_subsystem_{}_{}_semantic.json", std::process::id(), name));
+    let _ = std::fs::remove_file(&semantic);
+    common::builder()
+        .semantic_store(Box::new(FileSemanticStore::new(semantic)))
+        .build()
+}
+
+fn switch(subsystem: Subsystem, enabled: bool) -> Event {
+    input(InputContent::SubsystemControl { subsystem, enabled })
+}
+
+fn percept(hash: u64) -> Event {
+    input(InputContent::Visual(VisualSignal::PerceptUpdate { hash, distance: 0 }))
+}
+
+#[test]
+fn test_flags_default_on() {
+    let mut flags = SubsystemFlags::default();
+    assert!(Subsystem::ALL.iter().all(|s| flags.is_enabled(*s)));
+    flags.set(Subsystem::Memory, false);
+    assert!(!flags.memory);
+    assert!(flags.vision && flags.proactive_speech && flags.telemetry_export);
+    assert!(InputContent::SubsystemControl { subsystem: Subsystem::Vision, enabled: false }.is_control());
+}
+
+#[tokio::test]
+async fn test_toggle_applies_at_the_tick_boundary() {
+    let mut reactor = reactor("view");
+    let view = reactor.subscribe_view();
+    reactor.tick_step(vec![switch(Subsystem::Vision, false), percept(42)]);
+    assert!(!view.borrow().subsystems.vision);
+    assert_eq!(reactor.state.visual().hash, 0, "Percepts after the switch are dropped");
+
+    reactor.tick_step(vec![switch(Subsystem::Vision, true), percept(42)]);
+    assert!(view.borrow().subsystems.vision);
+    assert_eq!(reactor.state.visual().hash, 42);
+}
+
+#[tokio::test]
+async fn test_proactive_speech_denied_at_the_gate() {
+    let mut reactor = reactor("proactive");
+    reactor.tick_step(vec![switch(Subsystem::ProactiveSpeech, false)]);
+    let gate = explain_proactive_gate(&reactor.state);
+    assert_eq!((gate.decision, gate.rule), (CrystallizationDecision::Deny, GateRule::SubsystemDisabled));
+
+    reactor.tick_step(vec![switch(Subsystem::ProactiveSpeech, true)]);
+    assert_ne!(explain_proactive_gate(&reactor.state).rule, GateRule::SubsystemDisabled);
+}
+
+#[tokio::test]
+async fn test_memory_and_export_jobs_skipped() {
+    let mut reactor = reactor("maintenance");
+    reactor.maintenance.telemetry_spool = Some(std::env::temp_dir().join(format!("nexus_subsystem_{}_spool.jsonl", std::process::id())));
+    reactor.episodic.insert(EpisodicMemoryEntry {
+        claim: Claim::new(EntityId::User, Predicate::Prefers, ClaimValue::Text("tea".to_string()), Modality::Asserted),
+        confidence: 0.95,
+        created_at_tick: 0,
+        last_reinforced_tick: 0,
+        decay_rate: 0.0,
+        created_at_ms: None,
+    });
+    reactor.tick_step(vec![switch(Subsystem::Memory, false), switch(Subsystem::TelemetryExport, false)]);
+
+    let summary = reactor.run_maintenance(MaintenanceTrigger::OnDemand);
+    for job in [MaintenanceJob::SemanticPromotion, MaintenanceJob::StoreCompaction, MaintenanceJob::TelemetryExport] {
+        assert_eq!(summary.result(job).unwrap().outcome, JobOutcome::Skipped, "{:?}", job);
+    }
+    assert_eq!(summary.result(MaintenanceJob::LogRotation).unwrap().outcome, JobOutcome::Done, "Only the export is switched off");
+    assert!(reactor.semantic.entries().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_toggles_recorded_once() {
+    let mut reactor = reactor("telemetry");
+    reactor.tick_step(vec![switch(Subsystem::Memory, false), switch(Subsystem::Memory, false)]);
+    let toggles: Vec<_> = reactor.telemetry.events().filter_map(|e| match e {
+        TelemetryEvent::SubsystemToggled { subsystem, enabled } => Some((*subsystem, *enabled)),
+        _ => None,
+    }).collect();
+    assert_eq!(toggles, vec![(Subsystem::Memory, false)], "No change, no event");
+
+    let export = reactor.telemetry.export_content_free(&ExportSalt::new(1));
+    assert!(export.events.iter().any(|e| matches!(e, ExportEvent::SubsystemToggled { subsystem: Subsystem::Memory, enabled: false })));
+}
+
+#[tokio::test]
+async fn test_restart_keeps_the_flags() {
+    let mut reactor = reactor("restart");
+    reactor.tick_step(vec![switch(Subsystem::Vision, false)]);
+    reactor.restart(&ShutdownOptions::default());
+    assert!(!reactor.state.subsystems().vision);
+    assert!(reactor.state.subsystems().memory);
+}
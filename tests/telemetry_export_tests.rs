@@ -0,0 +1,80 @@
+use nexus::kernel::intent::long_horizon::IntentStatus;
+use nexus::kernel::telemetry::event::{MemoryEventKind, TelemetryEvent};
+use nexus::kernel::telemetry::export::{bucket, ExportEvent, ExportSalt, TelemetryExport};
+use nexus::kernel::telemetry::recorder::TelemetryRecorder;
+use nexus::kernel::telemetry::silence::SilenceContext;
+
+fn session() -> TelemetryRecorder {
+    let mut recorder = TelemetryRecorder::new();
+    recorder.record(TelemetryEvent::IntentLifecycle {
+        intent_id: "intent-7f3a".to_string(),
+        from: IntentStatus::Active,
+        to: IntentStatus::Suspended,
+    });
+    recorder.record(TelemetryEvent::MemoryEvent {
+        kind: MemoryEventKind::CandidateCreated,
+        memory_id: "memory-91bc".to_string(),
+    });
+    recorder.record(TelemetryEvent::SilencePeriod { duration_ticks: 37, context: SilenceContext::InConversation });
+    recorder.record(TelemetryEvent::SessionSummary {
+        duration_ticks: 5000,
+        silence_ratio: 0.437,
+        interruptions: 3,
+        resumed_intents: 0,
+        memory_consents: 1,
+    });
+    recorder
+}
+
+#[test]
+fn test_buckets_are_powers_of_two() {
+    assert_eq!(bucket(0), 0);
+    assert_eq!(bucket(1), 1);
+    assert_eq!(bucket(3), 2);
+    assert_eq!(bucket(37), 32);
+    assert_eq!(bucket(5000), 4096);
+}
+
+#[test]
+fn test_ids_are_salted_hashes() {
+    let recorder = session();
+    let a = recorder.export_content_free(&ExportSalt::new(1));
+    let b = recorder.export_content_free(&ExportSalt::new(2));
+
+    assert!(a.leaked_ids(recorder.events()).is_empty(), "Raw IDs must never be exported");
+
+    let intent = |export: &TelemetryExport| match &export.events[0] {
+        ExportEvent::IntentLifecycle { intent, .. } => intent.clone(),
+        other => panic!("unexpected {:?}", other),
+    };
+    // Stable within one export, uncorrelated across salts
+    assert_eq!(intent(&a), ExportSalt::new(1).hash_id("intent-7f3a"));
+    assert_ne!(intent(&a), intent(&b));
+}
+
+#[test]
+fn test_counts_and_durations_bucketed() {
+    let export = session().export_content_free(&ExportSalt::random());
+
+    assert!(matches!(export.events[2], ExportEvent::SilencePeriod { duration_bucket: 32, context: SilenceContext::InConversation }));
+    match &export.events[3] {
+        ExportEvent::SessionSummary { duration_bucket, silence_ratio, interruptions_bucket, .. } => {
+            assert_eq!(*duration_bucket, 4096);
+            assert_eq!(*silence_ratio, 0.4);
+            assert_eq!(*interruptions_bucket, 2);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn test_spool_roundtrip() {
+    let path = std::env::temp_dir().join(format!("nexus_spool_{}.jsonl", std::process::id()));
+    let recorder = session();
+    recorder.save_spool(&path).unwrap();
+
+    let loaded = TelemetryRecorder::load_spool(&path).unwrap();
+    assert_eq!(loaded.events().count(), 4);
+    assert!(loaded.export_content_free(&ExportSalt::random()).leaked_ids(loaded.events()).is_empty());
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,117 @@
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::intent::long_horizon::IntentStatus;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::shutdown::ShutdownOptions;
+use nexus::kernel::subsystem::Subsystem;
+use nexus::kernel::telemetry::event::{MemoryEventKind, TelemetryEvent};
+use nexus::kernel::telemetry::exporter::{metric_points, otlp_payload, ExportConfig, ExportError, DEFAULT_EXPORT_INTERVAL_TICKS};
+use nexus::kernel::telemetry::silence::SilenceContext;
+
+mod common;
+
+fn snapshot_file(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("nexus_exporter_{}_{}.jsonl", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn reactor(export: ExportConfig, path: &std::path::Path) -> Reactor {
+    let mut reactor = common::builder().config(ReactorConfig { telemetry_export: export, ..common::config() }).build();
+    reactor.exporter.file = Some(path.to_path_buf());
+    reactor.telemetry.record(TelemetryEvent::IntentLifecycle {
+        intent_id: "intent-7f3a".to_string(),
+        from: IntentStatus::Active,
+        to: IntentStatus::Suspended,
+    });
+    reactor.telemetry.record(TelemetryEvent::MemoryEvent { kind: MemoryEventKind::CandidateCreated, memory_id: "memory-91bc".to_string() });
+    reactor.telemetry.record(TelemetryEvent::SilencePeriod { duration_ticks: 37, context: SilenceContext::InConversation });
+    reactor
+}
+
+fn lines(path: &std::path::Path) -> Vec<serde_json::Value> {
+    std::fs::read_to_string(path).unwrap_or_default().lines().map(|l| serde_json::from_str(l).unwrap()).collect()
+}
+
+#[test]
+fn test_config_from_targets() {
+    assert_eq!(ExportConfig::parse("file, OTLP", Some(10)), ExportConfig { interval_ticks: 500, file: true, otlp: true });
+    assert_eq!(ExportConfig::parse("file", None).interval_ticks, DEFAULT_EXPORT_INTERVAL_TICKS);
+    assert!(!ExportConfig::parse("", Some(10)).is_enabled(), "No target: off");
+    assert!(!ExportConfig::parse("file", Some(0)).is_enabled());
+    assert!(!ExportConfig::default().is_enabled());
+}
+
+#[tokio::test]
+async fn test_snapshots_appended_periodically_and_at_shutdown() {
+    let path = snapshot_file("periodic");
+    let mut reactor = reactor(ExportConfig { interval_ticks: 4, file: true, otlp: false }, &path);
+    let mut exports = Vec::new();
+    for _ in 0..10 {
+        exports.extend(reactor.tick_step(vec![]).iter().filter_map(|e| match e {
+            SideEffect::ExportTelemetry(export) => Some(export.clone()),
+            _ => None,
+        }));
+    }
+    assert!(lines(&path).is_empty(), "The tick only emits the export; the driver writes it");
+    assert_eq!(exports.iter().map(|e| e.tick.frame).collect::<Vec<_>>(), [4, 8]);
+    for export in &exports {
+        export.append().unwrap();
+    }
+    let written = lines(&path);
+    assert_eq!(written.iter().map(|l| l["tick"].as_u64().unwrap()).collect::<Vec<_>>(), [4, 8]);
+    assert_eq!(written[0]["snapshot"]["memory_stats"]["candidates_created"], 1);
+    assert_eq!(written[0]["snapshot"]["intent_stats"]["suspended"], 1);
+
+    let (report, _) = reactor.shutdown(&ShutdownOptions::default());
+    assert!(report.flushed.iter().any(|f| f.store == "telemetry_export" && f.error.is_none()));
+    assert_eq!(lines(&path).len(), 3, "Final snapshot at shutdown");
+    assert_eq!(reactor.exporter.flushes(), 3);
+
+    // Aggregates only: no ID recorded in the ring reaches the file
+    let text = std::fs::read_to_string(&path).unwrap();
+    assert!(!text.contains("intent-7f3a") && !text.contains("memory-91bc"));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn test_subsystem_switch_pauses_export() {
+    let path = snapshot_file("paused");
+    let mut reactor = reactor(ExportConfig { interval_ticks: 2, file: true, otlp: false }, &path);
+    let off = InputContent::SubsystemControl { subsystem: Subsystem::TelemetryExport, enabled: false };
+    reactor.tick_step(vec![Event::Input(InputEvent { source: "Test".to_string(), content: off, captured_at: None })]);
+    for _ in 0..6 {
+        assert!(!reactor.tick_step(vec![]).iter().any(|e| matches!(e, SideEffect::ExportTelemetry(_))));
+    }
+    reactor.shutdown(&ShutdownOptions::default());
+    assert!(lines(&path).is_empty());
+    assert_eq!(reactor.exporter.flushes(), 0);
+}
+
+#[tokio::test]
+async fn test_otlp_metrics_are_bucketed_counts() {
+    let reactor = reactor(ExportConfig::default(), &snapshot_file("otlp"));
+    let points = metric_points(&reactor.telemetry.snapshot());
+    let value = |name: &str| points.iter().find(|p| p.name == name).map(|p| p.value);
+    assert_eq!(value("nexus.silence_stats.total_ticks"), Some(32), "37 bucketed");
+    assert_eq!(value("nexus.memory_stats.candidates_created"), Some(1));
+    assert_eq!(value("nexus.footprint_stats.latest"), None, "Absent optionals are skipped");
+
+    let payload = otlp_payload(&points, 1_700_000_000_000_000_000).to_string();
+    let request: serde_json::Value = serde_json::from_str(&payload).unwrap();
+    let metrics = &request["resourceMetrics"][0]["scopeMetrics"][0]["metrics"];
+    assert_eq!(metrics.as_array().unwrap().len(), points.len());
+    assert_eq!(metrics[0]["gauge"]["dataPoints"][0]["timeUnixNano"], "1700000000000000000");
+    assert!(!payload.contains("intent-7f3a") && !payload.contains("memory-91bc"));
+}
+
+#[cfg(not(feature = "otlp"))]
+#[tokio::test]
+async fn test_otlp_without_feature_reports_unavailable() {
+    let path = snapshot_file("no_otlp");
+    let mut reactor = reactor(ExportConfig { interval_ticks: 1, file: true, otlp: true }, &path);
+    let snapshot = reactor.telemetry.snapshot();
+    assert_eq!(reactor.exporter.flush(reactor.tick, &snapshot), Err(ExportError::OtlpUnavailable));
+    assert_eq!(lines(&path).len(), 1, "The file target still gets its line");
+    let _ = std::fs::remove_file(&path);
+}
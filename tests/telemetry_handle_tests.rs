@@ -0,0 +1,50 @@
+use nexus::kernel::telemetry::event::{SpeechLifecycleEvent, TelemetryEvent};
+use nexus::kernel::telemetry::recorder::TelemetryRecorder;
+
+mod common;
+
+fn speech_events(recorder: &TelemetryRecorder) -> usize {
+    recorder.events().filter(|e| matches!(e, TelemetryEvent::SpeechLifecycle(_))).count()
+}
+
+#[test]
+fn test_handles_record_from_many_threads() {
+    let mut recorder = TelemetryRecorder::new();
+    let workers: Vec<_> = (0..4).map(|_| {
+        let handle = recorder.handle();
+        std::thread::spawn(move || {
+            for _ in 0..25 {
+                handle.record(TelemetryEvent::SpeechLifecycle(SpeechLifecycleEvent::Requested));
+            }
+        })
+    }).collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    assert_eq!(speech_events(&recorder), 0, "Nothing lands until drained");
+    assert_eq!(recorder.drain(), 100);
+    assert_eq!(speech_events(&recorder), 100);
+    assert_eq!(recorder.drain(), 0);
+}
+
+#[test]
+fn test_handle_outliving_recorder_is_harmless() {
+    let handle = TelemetryRecorder::new().handle();
+    handle.record(TelemetryEvent::SpeechLifecycle(SpeechLifecycleEvent::Failed));
+}
+
+#[tokio::test]
+async fn test_reactor_drains_handle_on_tick() {
+    let mut reactor = common::reactor();
+    let handle = reactor.telemetry.handle();
+
+    let driver = std::thread::spawn(move || {
+        handle.record(TelemetryEvent::SpeechLifecycle(SpeechLifecycleEvent::Generated));
+        handle.record(TelemetryEvent::SpeechLifecycle(SpeechLifecycleEvent::Aborted));
+    });
+    driver.join().unwrap();
+
+    reactor.tick_step(vec![]);
+    assert_eq!(speech_events(&reactor.telemetry), 2);
+}
@@ -0,0 +1,127 @@
+use nexus::kernel::event::{AudioSignal, Event, InputContent};
+use nexus::kernel::observer::{TickObserver, TickRecord};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::shutdown::ShutdownOptions;
+use nexus::kernel::state::StateDelta;
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::input;
+
+// What one observed tick looked like: tick, event count, delta names, effect count
+type Seen = Arc<Mutex<Vec<(u64, usize, Vec<String>, usize)>>>;
+
+struct Recorder {
+    seen: Seen,
+}
+
+impl TickObserver for Recorder {
+    fn name(&self) -> &str {
+        "recorder"
+    }
+
+    fn observe(&mut self, record: &TickRecord) {
+        let deltas = record.deltas.iter().map(|d| format!("{:?}", d).split([' ', '(', '{']).next().unwrap().to_string()).collect();
+        self.seen.lock().unwrap().push((record.tick.frame, record.events.len(), deltas, record.effects.len()));
+    }
+}
+
+struct Faulty;
+
+impl TickObserver for Faulty {
+    fn name(&self) -> &str {
+        "faulty"
+    }
+
+    fn observe(&mut self, record: &TickRecord) {
+        if record.tick.frame == 2 {
+            panic!("plugin bug");
+        }
+    }
+}
+
+fn reactor() -> Reactor {
+    common::builder().config(ReactorConfig { id_seed: Some(7), ..common::config() }).build()
+}
+
+fn recorder(reactor: &mut Reactor) -> Seen {
+    let seen = Seen::default();
+    reactor.register_observer(Box::new(Recorder { seen: seen.clone() }));
+    seen
+}
+
+fn session() -> Vec<Vec<Event>> {
+    vec![
+        vec![input(InputContent::Audio(AudioSignal::SpeechStart))],
+        vec![],
+        vec![input(InputContent::Audio(AudioSignal::SpeechEnd))],
+        vec![input(InputContent::Text("What is gravity?".to_string()))],
+        vec![],
+    ]
+}
+
+#[tokio::test]
+async fn test_observer_sees_events_deltas_and_effects() {
+    let mut reactor = reactor();
+    reactor.tick_step(vec![]);
+    let seen = recorder(&mut reactor);
+    assert_eq!(reactor.observers(), ["recorder"]);
+
+    let mut effects = Vec::new();
+    for events in session() {
+        effects.push(reactor.tick_step(events).len());
+    }
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 5, "Registered after tick 1: sees the five that follow");
+    assert_eq!(seen.iter().map(|s| s.0).collect::<Vec<_>>(), [2, 3, 4, 5, 6]);
+    assert_eq!(seen.iter().map(|s| s.1).collect::<Vec<_>>(), [1, 0, 1, 1, 0]);
+    assert!(seen.iter().all(|s| s.2[0] == "Tick"), "The tick's own delta comes first");
+    assert!(seen[0].2.iter().any(|d| d == "AudioSegmentCreated"));
+    assert_eq!(seen.iter().map(|s| s.3).collect::<Vec<_>>(), effects, "Effects as returned");
+}
+
+#[tokio::test]
+async fn test_observers_do_not_change_decisions() {
+    let run = |observed: bool| {
+        let mut reactor = reactor();
+        if observed {
+            recorder(&mut reactor);
+            reactor.register_observer(Box::new(Faulty));
+        }
+        let effects: Vec<String> = session().into_iter().map(|events| format!("{:?}", reactor.tick_step(events).into_vec())).collect();
+        (effects, reactor.state.version(), format!("{:?}", reactor.state.intent_state()))
+    };
+    assert_eq!(run(true), run(false));
+}
+
+#[tokio::test]
+async fn test_panicking_observer_is_removed() {
+    let mut reactor = reactor();
+    reactor.register_observer(Box::new(Faulty));
+    let seen = recorder(&mut reactor);
+    for _ in 0..3 {
+        reactor.tick_step(vec![]);
+    }
+    assert_eq!(reactor.observers(), ["recorder"]);
+    assert_eq!(seen.lock().unwrap().len(), 3, "The others still see every tick");
+}
+
+#[tokio::test]
+async fn test_deltas_recorded_only_while_observed() {
+    let mut reactor = reactor();
+    reactor.state.reduce(StateDelta::AllOutputsCleared);
+    assert!(reactor.state.take_applied().is_empty());
+
+    // Restart keeps the observers, and the next record carries what happened in between
+    let seen = recorder(&mut reactor);
+    reactor.tick_step(vec![]);
+    reactor.restart(&ShutdownOptions::default());
+    assert_eq!(reactor.observers(), ["recorder"]);
+    reactor.state.reduce(StateDelta::AllOutputsCleared);
+    reactor.tick_step(vec![]);
+    let seen = seen.lock().unwrap();
+    let deltas = &seen[1].2;
+    assert!(deltas.contains(&"SubsystemToggled".to_string()), "Restored by restart: {:?}", deltas);
+    let at = |name: &str| deltas.iter().position(|d| d == name).unwrap();
+    assert!(at("SubsystemToggled") < at("AllOutputsCleared") && at("AllOutputsCleared") < at("Tick"));
+}
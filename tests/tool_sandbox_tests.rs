@@ -0,0 +1,146 @@
+use nexus::integrations::home::{tool_result, HomeAction, HomeMapping, HomeTarget};
+use nexus::kernel::diagnostic::DiagnosticCode;
+use nexus::kernel::effects::EffectBatch;
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::speech::planner::SpeechIntent;
+use nexus::kernel::time::Tick;
+use nexus::planner::sandbox::{Admission, SandboxConfig, ToolSandbox, HOME_ACTION_TIMEOUT_TICKS};
+use nexus::planner::tools::ToolCall;
+use serde_json::json;
+
+mod common;
+use common::jump;
+
+fn reactor(tool_sandbox: SandboxConfig) -> Reactor {
+    let mapping: HomeMapping = serde_json::from_value(json!({
+        "mqtt": { "host": "localhost" },
+        "rules": [
+            { "name": "kitchen light on", "phrases": ["turn on the kitchen light"],
+              "target": { "Mqtt": { "topic": "home/kitchen/light/set", "payload": "ON" } } },
+            { "name": "fan on", "phrases": ["turn on the fan"],
+              "target": { "Mqtt": { "topic": "home/fan/set", "payload": "ON" } } }
+        ]
+    })).unwrap();
+    common::builder()
+        .config(ReactorConfig { tool_sandbox, ..common::config() })
+        .home_mapping(mapping)
+        .build()
+}
+
+fn say(reactor: &mut Reactor, text: &str) -> EffectBatch {
+    reactor.tick_step(vec![Event::Input(InputEvent {
+        source: "Test".to_string(),
+        content: InputContent::Text(text.to_string()),
+        captured_at: None,
+    })])
+}
+
+fn invoked(batch: &EffectBatch) -> Vec<String> {
+    batch.iter().filter_map(|e| match e {
+        SideEffect::InvokeTool { call_id, .. } => Some(call_id.clone()),
+        _ => None,
+    }).collect()
+}
+
+fn cancelled(batch: &EffectBatch) -> Vec<String> {
+    batch.iter().filter_map(|e| match e {
+        SideEffect::CancelTool { call_id } => Some(call_id.clone()),
+        _ => None,
+    }).collect()
+}
+
+fn confirmed(batch: &EffectBatch, seed: &str) -> bool {
+    batch.iter().any(|e| matches!(e, SideEffect::RequestSpeech { intent: SpeechIntent::Confirmation(s), .. } if s.starts_with(seed)))
+}
+
+fn diagnosed(batch: &EffectBatch, code: DiagnosticCode) -> bool {
+    batch.iter().any(|e| matches!(e, SideEffect::Diagnostic { code: c, .. } if *c == code))
+}
+
+fn light() -> ToolCall {
+    ToolCall::HomeAction(HomeAction {
+        rule: "light".to_string(),
+        target: HomeTarget::Mqtt { topic: "t".to_string(), payload: "ON".to_string(), retain: false },
+    })
+}
+
+#[test]
+fn test_sandbox_bookkeeping() {
+    let config = SandboxConfig { max_concurrent: 1, ..Default::default() };
+    let mut sandbox = ToolSandbox::new();
+    let now = Tick { frame: 10 };
+
+    assert_eq!(sandbox.admit("a", &light(), &config, true, now), Admission::DryRun);
+    assert!(sandbox.in_flight().is_empty(), "Dry runs are not recorded");
+    assert_eq!(sandbox.admit("a", &light(), &config, false, now), Admission::Run);
+    assert_eq!(sandbox.admit("b", &light(), &config, false, now), Admission::Busy);
+
+    let record = &sandbox.in_flight()[0];
+    assert_eq!((record.tool, record.deadline.frame), ("home_action", 10 + HOME_ACTION_TIMEOUT_TICKS));
+    assert!(sandbox.expired(record.deadline).is_empty(), "The deadline tick itself is still in time");
+    assert_eq!(sandbox.expired(Tick { frame: 11 + HOME_ACTION_TIMEOUT_TICKS }).len(), 1);
+    assert!(sandbox.complete("a").is_none(), "Timed out calls are gone");
+
+    sandbox.admit("c", &light(), &config, false, now);
+    assert_eq!(sandbox.abort_all().len(), 1);
+    assert!(sandbox.in_flight().is_empty());
+}
+
+#[tokio::test]
+async fn test_timeout_cancels_and_ignores_late_result() {
+    let mut reactor = reactor(SandboxConfig::default());
+    let calls = invoked(&say(&mut reactor, "Turn on the kitchen light"));
+    assert_eq!(calls.len(), 1);
+    let deadline = reactor.tool_sandbox.in_flight()[0].deadline.frame;
+
+    jump(&mut reactor, deadline + 1);
+    let effects = reactor.tick_step(vec![]);
+    assert_eq!(cancelled(&effects), calls);
+    assert!(diagnosed(&effects, DiagnosticCode::ToolTimedOut));
+    assert!(confirmed(&effects, "home_action_failed"));
+    assert_eq!(reactor.home.in_flight(), 0);
+
+    let effects = reactor.tick_step(vec![tool_result(calls[0].clone(), true)]);
+    assert!(!confirmed(&effects, "home_action_done"), "Late result is ignored");
+}
+
+#[tokio::test]
+async fn test_concurrency_cap_refuses_extra_calls() {
+    let mut reactor = reactor(SandboxConfig { max_concurrent: 1, ..Default::default() });
+    let first = invoked(&say(&mut reactor, "Turn on the kitchen light"));
+    assert_eq!(first.len(), 1);
+
+    let effects = say(&mut reactor, "Turn on the fan");
+    assert!(invoked(&effects).is_empty());
+    assert!(diagnosed(&effects, DiagnosticCode::ToolBusy));
+    assert!(confirmed(&effects, "home_action_failed: fan on"));
+
+    // A slot frees up once the first call reports back
+    reactor.tick_step(vec![tool_result(first[0].clone(), true)]);
+    assert_eq!(invoked(&say(&mut reactor, "Turn on the fan")).len(), 1);
+}
+
+#[tokio::test]
+async fn test_dry_run_reports_instead_of_acting() {
+    let mut reactor = reactor(SandboxConfig { dry_run: true, ..Default::default() });
+    let effects = say(&mut reactor, "Turn on the kitchen light");
+    assert!(invoked(&effects).is_empty());
+    assert!(diagnosed(&effects, DiagnosticCode::ToolDryRun));
+    assert!(confirmed(&effects, "home_action_dry_run: kitchen light on"));
+    assert!(reactor.tool_sandbox.in_flight().is_empty());
+    assert_eq!(reactor.home.in_flight(), 0);
+}
+
+#[tokio::test]
+async fn test_interruption_aborts_in_flight_tools() {
+    let mut reactor = reactor(SandboxConfig::default());
+    let calls = invoked(&say(&mut reactor, "Turn on the kitchen light"));
+    assert_eq!(reactor.tool_sandbox.in_flight().len(), 1);
+
+    let effects = say(&mut reactor, "STOP");
+    assert_eq!(cancelled(&effects), calls);
+    assert!(reactor.tool_sandbox.in_flight().is_empty());
+    assert_eq!(reactor.home.in_flight(), 0);
+}
@@ -0,0 +1,144 @@
+use nexus::kernel::event::InputContent;
+use nexus::kernel::ids::IdGenerator;
+use nexus::kernel::intent::types::{IntentCandidate, IntentHypothesis, IntentStability};
+use nexus::kernel::memory::consent::MemoryConsentState;
+use nexus::kernel::memory::topic::{consent_scope, parse_forget_request, TopicTracker};
+use nexus::kernel::memory::types::{MemoryCandidate, MemoryKey};
+use nexus::kernel::protocol::ConsentRequest;
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::time::Tick;
+
+mod common;
+use common::{input, jump, reactor};
+
+fn at(frame: u64) -> Tick {
+    Tick { frame }
+}
+
+/// A confident statement, reinforced enough to be asked about once it is old enough.
+fn candidate(id: &str, hash: u64, topic: &str) -> MemoryCandidate {
+    let intent = IntentCandidate {
+        id: format!("intent_{}", id),
+        hypothesis: IntentHypothesis::Statement,
+        confidence: 0.96,
+        source_symbol_ids: vec![],
+        semantic_hash: hash,
+        stability: IntentStability::Stable,
+    };
+    MemoryCandidate {
+        id: id.to_string(),
+        key: MemoryKey::from_intent(&intent),
+        intent,
+        created_at: at(0),
+        reinforcement_count: 2,
+        last_reinforced_at: at(0),
+        topic_id: Some(topic.to_string()),
+        sensitivity: None,
+        context: Default::default(),
+    }
+}
+
+fn asks(effects: &[SideEffect]) -> Vec<ConsentRequest> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::AskMemoryConsent(request) => Some(request.clone()),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn test_related_utterances_share_a_topic() {
+    let mut tracker = TopicTracker::default();
+    let mut ids = IdGenerator::new(7);
+    let trip = tracker.observe("I'm planning a trip to Japan next spring", at(0), &mut ids);
+    assert_eq!(tracker.observe("The trip to Japan needs a visa", at(100), &mut ids), trip, "Shared keywords stay on topic");
+    assert_eq!(tracker.observe("um, ok", at(150), &mut ids), trip, "Fillers never break a topic");
+    assert_eq!(tracker.current.as_ref().unwrap().utterances, 3);
+
+    let weather = tracker.observe("What's the weather forecast tomorrow?", at(200), &mut ids);
+    assert_ne!(weather, trip, "No overlap starts a new topic");
+    assert_eq!(tracker.current_id(), Some(weather));
+}
+
+#[test]
+fn test_idle_gap_ends_the_topic() {
+    let mut tracker = TopicTracker::new();
+    let mut ids = IdGenerator::new(7);
+    let first = tracker.observe("Planning the garden beds", at(0), &mut ids);
+    assert_eq!(tracker.observe("The garden beds need compost", at(3000), &mut ids), first);
+    let later = tracker.observe("The garden beds need compost", at(6001), &mut ids);
+    assert_ne!(later, first, "A minute of silence ends the topic, whatever the overlap");
+    assert_eq!(tracker.current.as_ref().unwrap().started_at, at(6001));
+}
+
+#[test]
+fn test_forget_requests() {
+    assert!(parse_forget_request("Forget what we just talked about"));
+    assert!(parse_forget_request("please forget this topic"));
+    assert!(parse_forget_request("can you forget all of that please"));
+    assert!(!parse_forget_request("forget it"), "A consent decline, not a topic");
+    assert!(!parse_forget_request("don't forget what we talked about"));
+    assert!(!parse_forget_request("I always forget what we talked about"), "Not a command");
+    assert!(!parse_forget_request("forget this topic and tell me about the weather in Paris"));
+}
+
+#[test]
+fn test_consent_scope_needs_several_plain_facts() {
+    let mut reactor = reactor();
+    let first = candidate("m1", 1, "topic_a");
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(first.clone()));
+    assert_eq!(consent_scope(&first.key, &reactor.state), None, "One fact is asked about on its own");
+
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(candidate("m2", 2, "topic_a")));
+    assert_eq!(consent_scope(&first.key, &reactor.state), Some("topic_a".to_string()));
+
+    reactor.state.reduce(StateDelta::TopicConsentResolved { topic_id: "topic_a".to_string(), state: MemoryConsentState::Declined });
+    assert_eq!(consent_scope(&first.key, &reactor.state), None, "An answered topic is not asked again");
+}
+
+#[tokio::test]
+async fn test_topic_prompt_answer_resolves_the_topic() {
+    let mut reactor = reactor();
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(candidate("m1", 1, "topic_a")));
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(candidate("m2", 2, "topic_a")));
+    jump(&mut reactor, 1250);
+
+    let effects = reactor.tick_step(vec![]);
+    let requests = asks(&effects);
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].topic_id.as_deref(), Some("topic_a"));
+
+    reactor.tick_step(vec![input(InputContent::MemoryConsentResponse(requests[0].answer(MemoryConsentState::Granted)))]);
+    assert_eq!(reactor.state.topic_consent().get("topic_a"), Some(&MemoryConsentState::Granted));
+
+    // The other fact on the topic is promoted without a prompt of its own
+    let effects = reactor.tick_step(vec![]);
+    assert!(asks(&effects).is_empty());
+    assert!(reactor.state.memory_candidates().is_empty());
+    assert!(reactor.state.long_term_memory().values().all(|r| r.topic_id.as_deref() == Some("topic_a")));
+}
+
+#[tokio::test]
+async fn test_forget_by_voice_drops_the_active_topic() {
+    let mut reactor = reactor();
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(candidate("m1", 1, "topic_a")));
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(candidate("m2", 2, "topic_b")));
+    reactor.state.reduce(StateDelta::TopicChanged("topic_a".to_string()));
+
+    let effects = reactor.tick_step(vec![input(InputContent::Text("Forget what we just talked about".to_string()))]);
+    assert!(!reactor.state.memory_candidates().contains_key("m1"));
+    assert!(reactor.state.memory_candidates().contains_key("m2"), "Other topics are kept");
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::RequestSpeech { .. })), "Confirmed");
+}
+
+#[tokio::test]
+async fn test_forget_input_names_the_topic() {
+    let mut reactor = reactor();
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(candidate("m1", 1, "topic_a")));
+    reactor.state.reduce(StateDelta::MemoryCandidateCreated(candidate("m2", 2, "topic_b")));
+    reactor.state.reduce(StateDelta::TopicChanged("topic_a".to_string()));
+
+    reactor.tick_step(vec![input(InputContent::ForgetTopic { topic_id: Some("topic_b".to_string()) })]);
+    assert!(reactor.state.memory_candidates().contains_key("m1"));
+    assert!(!reactor.state.memory_candidates().contains_key("m2"));
+}
@@ -0,0 +1,122 @@
+use nexus::audio::transcription::{QueueDepth, TranscriptionQueue};
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::event::{Event, InputEvent, InputContent, AudioSignal};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::state::StateDelta;
+
+mod common;
+
+fn audio(signal: AudioSignal) -> Event {
+    Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::Audio(signal), captured_at: None })
+}
+
+fn cancelled(effects: &[SideEffect]) -> Vec<String> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::CancelTranscription { segment_id } => Some(segment_id.clone()),
+        _ => None,
+    }).collect()
+}
+
+/// Reactor with one finalized segment handed to the driver for transcription.
+fn requested() -> (Reactor, String) {
+    let mut reactor = common::reactor();
+    reactor.tick_step(vec![audio(AudioSignal::SpeechStart)]);
+    let segment_id = reactor.state.active_segment_id().cloned().unwrap();
+    let effects = reactor.tick_step(vec![audio(AudioSignal::SpeechEnd)]);
+    assert!(effects.iter().any(|e| matches!(e, SideEffect::RequestTranscription { .. })));
+    assert!(reactor.transcriptions_in_flight.contains(&segment_id));
+    (reactor, segment_id)
+}
+
+#[tokio::test]
+async fn test_concurrency_limit_and_fifo() {
+    let mut queue = TranscriptionQueue::new(2);
+    for id in ["a", "b", "c"] {
+        queue.enqueue(id.to_string(), vec![0.0; 16]);
+    }
+    queue.enqueue("a".to_string(), vec![]); // Duplicate ignored
+
+    let mut started = Vec::new();
+    queue.pump(|id, _| { started.push(id); tokio::spawn(std::future::pending()) });
+    assert_eq!(started, vec!["a", "b"]);
+    assert_eq!(queue.depth(), QueueDepth { queued: 1, in_flight: 2 });
+
+    // Cancelling a running job frees a slot for the next queued one
+    assert!(queue.cancel("a"));
+    queue.pump(|id, _| { started.push(id); tokio::spawn(std::future::pending()) });
+    assert_eq!(started, vec!["a", "b", "c"]);
+    assert_eq!(queue.depth(), QueueDepth { queued: 0, in_flight: 2 });
+}
+
+#[tokio::test]
+async fn test_cancel_queued_job() {
+    let mut queue = TranscriptionQueue::new(1);
+    queue.enqueue("a".to_string(), vec![]);
+    queue.enqueue("b".to_string(), vec![]);
+    assert!(queue.cancel("b"));
+    assert!(!queue.cancel("unknown"));
+
+    queue.pump(|_, _| tokio::spawn(async {}));
+    assert_eq!(queue.depth(), QueueDepth { queued: 0, in_flight: 1 });
+}
+
+#[tokio::test]
+async fn test_purged_segment_cancels_transcription() {
+    let (mut reactor, segment_id) = requested();
+    reactor.state.reduce(StateDelta::AudioSegmentsPurged(vec![segment_id.clone()]));
+
+    let effects = reactor.tick_step(vec![]);
+    assert_eq!(cancelled(&effects), vec![segment_id.clone()]);
+    assert!(!reactor.transcriptions_in_flight.contains(&segment_id));
+
+    // Only once
+    assert!(cancelled(&reactor.tick_step(vec![])).is_empty());
+}
+
+#[tokio::test]
+async fn test_discarded_segment_cancels_transcription() {
+    let (mut reactor, segment_id) = requested();
+    reactor.state.reduce(StateDelta::AudioSegmentDiscarded(segment_id.clone()));
+    assert_eq!(cancelled(&reactor.tick_step(vec![])), vec![segment_id]);
+}
+
+#[tokio::test]
+async fn test_transcribed_segment_is_not_cancelled() {
+    let (mut reactor, segment_id) = requested();
+    let effects = reactor.tick_step(vec![Event::Input(InputEvent {
+        source: "ASR".to_string(),
+        content: InputContent::ProvisionalText {
+            content: "Turn off the lights".to_string(),
+            confidence: 0.9,
+            source_id: segment_id.clone(),
+            words: Vec::new(),
+            language: None,
+            is_final: true,
+        },
+        captured_at: None,
+    })]);
+    assert!(cancelled(&effects).is_empty());
+    assert!(!reactor.transcriptions_in_flight.contains(&segment_id));
+}
+
+#[tokio::test]
+async fn test_invalidated_intent_cancels_transcription() {
+    use nexus::kernel::intent::long_horizon::{IntentStatus, LongHorizonIntent};
+    use nexus::kernel::intent::types::IntentHypothesis;
+
+    let (mut reactor, segment_id) = requested();
+    let now = reactor.tick;
+    reactor.state.reduce(StateDelta::LongHorizonIntentUpdate(LongHorizonIntent {
+        id: "intent-1".to_string(),
+        hypothesis: IntentHypothesis::Statement,
+        source_symbol_ids: vec![segment_id.clone()],
+        created_at: now,
+        last_active_at: now,
+        last_updated_at: now,
+        suspended_at: None,
+        decay_score: 0.0,
+        status: IntentStatus::Invalidated,
+        context: Default::default(),
+    }));
+    assert_eq!(cancelled(&reactor.tick_step(vec![])), vec![segment_id]);
+}
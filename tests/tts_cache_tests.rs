@@ -0,0 +1,64 @@
+use std::time::{Duration, Instant};
+
+use nexus::kernel::crystallizer::CrystallizationDecision;
+use nexus::kernel::intent::types::IntentHypothesis;
+use nexus::outputs::realizer::realize_resumption;
+use nexus::outputs::tts_cache::{canned_phrases, phrase_key, TtsCache, TtsCacheLimits};
+
+fn cache(max_entries: usize, max_bytes: usize) -> TtsCache {
+    TtsCache::new(TtsCacheLimits { max_entries, max_bytes, ttl: Duration::from_secs(60) })
+}
+
+#[test]
+fn test_hit_is_keyed_by_spoken_phrase() {
+    let now = Instant::now();
+    let mut cache = cache(4, 1024);
+    cache.insert("Do you want me to respond?", vec![1; 10], now);
+
+    assert_eq!(phrase_key("do you  want me to respond?"), phrase_key("Do you want me to respond?"));
+    assert_eq!(cache.get(" do you want me to respond? ", now).map(|a| a.len()), Some(10));
+    assert!(cache.get("Do you want me to answer?", now).is_none());
+}
+
+#[test]
+fn test_entry_and_byte_limits_evict_least_recently_used() {
+    let now = Instant::now();
+    let mut cache = cache(2, 100);
+    cache.insert("one", vec![0; 40], now);
+    cache.insert("two", vec![0; 40], now + Duration::from_millis(1));
+    assert!(cache.get("one", now + Duration::from_millis(2)).is_some());
+
+    // Entry limit: "two" is the least recently used
+    cache.insert("three", vec![0; 40], now + Duration::from_millis(3));
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get("two", now + Duration::from_millis(4)).is_none());
+
+    // Byte limit: 40 + 70 > 100
+    cache.insert("four", vec![0; 70], now + Duration::from_millis(5));
+    assert_eq!(cache.len(), 1);
+    assert!(cache.bytes() <= 100);
+
+    // Oversized buffers are never cached
+    cache.insert("five", vec![0; 101], now + Duration::from_millis(6));
+    assert!(cache.get("five", now + Duration::from_millis(7)).is_none());
+}
+
+#[test]
+fn test_entries_expire_after_ttl() {
+    let now = Instant::now();
+    let mut cache = cache(4, 1024);
+    cache.insert("Picking that back up.", vec![1; 8], now);
+    assert!(cache.contains("Picking that back up.", now + Duration::from_secs(59)));
+    assert!(cache.get("Picking that back up.", now + Duration::from_secs(60)).is_none());
+    assert!(cache.is_empty());
+    assert_eq!(cache.bytes(), 0);
+}
+
+#[test]
+fn test_canned_phrases_cover_verbatim_realizations() {
+    let phrases = canned_phrases();
+    assert!(phrases.iter().any(|p| p == "Do you want me to respond?"));
+    let offer = realize_resumption(&IntentHypothesis::ThinkingAloud, true, &CrystallizationDecision::AllowPartial);
+    assert!(phrases.iter().any(|p| phrase_key(p) == phrase_key(&offer)));
+    assert!(phrases.len() <= TtsCacheLimits::default().max_entries, "Pre-warm fits in the default cache");
+}
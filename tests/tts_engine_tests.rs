@@ -0,0 +1,84 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use nexus::outputs::captions::playback_command_with;
+use nexus::outputs::tts::{EspeakEngine, PiperEngine, SapiEngine, SayEngine, TtsEngine, TtsEngineKind};
+use nexus::outputs::tts_cache::{TtsCache, TtsCacheLimits};
+
+fn args(command: &tokio::process::Command) -> Vec<String> {
+    command.as_std().get_args().map(|a| a.to_string_lossy().into_owned()).collect()
+}
+
+fn program(command: &tokio::process::Command) -> String {
+    command.as_std().get_program().to_string_lossy().into_owned()
+}
+
+#[test]
+fn test_engine_selection() {
+    assert_eq!(TtsEngineKind::parse("espeak-ng"), Some(TtsEngineKind::Espeak));
+    assert_eq!(TtsEngineKind::parse(" Piper "), Some(TtsEngineKind::Piper));
+    assert_eq!(TtsEngineKind::parse("festival"), None);
+    for kind in [TtsEngineKind::Say, TtsEngineKind::Sapi, TtsEngineKind::Espeak, TtsEngineKind::Piper] {
+        assert_eq!(kind.engine().kind(), kind);
+    }
+    #[cfg(target_os = "linux")]
+    assert_eq!(TtsEngineKind::default(), TtsEngineKind::Espeak);
+    #[cfg(target_os = "macos")]
+    assert_eq!(TtsEngineKind::default(), TtsEngineKind::Say);
+}
+
+#[test]
+fn test_say_and_espeak_commands() {
+    let say = SayEngine.speak("Hi", Some("Headset"));
+    assert_eq!((program(&say), args(&say)), ("say".to_string(), vec!["-a".to_string(), "Headset".to_string(), "Hi".to_string()]));
+    assert_eq!(program(&SayEngine.play_file(Path::new("/tmp/a.aiff"))), "afplay");
+
+    assert_eq!(args(&EspeakEngine.speak("Hi", None)), vec!["Hi"]);
+    // A named device goes through aplay; the text stays a separate argument
+    let routed = EspeakEngine.speak("it's $HOME", Some("plughw:1,0"));
+    assert_eq!(program(&routed), "sh");
+    assert!(args(&routed)[1].contains("aplay -q -D"));
+    assert_eq!(&args(&routed)[2..], ["it's $HOME", "plughw:1,0"]);
+    assert_eq!(args(&EspeakEngine.synthesize_to("Hi", Path::new("/tmp/a.wav"))), vec!["-w", "/tmp/a.wav", "Hi"]);
+}
+
+#[test]
+fn test_sapi_text_not_in_script() {
+    let text = "'); Remove-Item C:\\ -Recurse; ('";
+    let speak = SapiEngine.speak(text, None);
+    assert_eq!(program(&speak), "powershell");
+    assert!(args(&speak).iter().all(|a| !a.contains("Remove-Item")));
+    let env: Vec<_> = speak.as_std().get_envs().collect();
+    assert_eq!(env, vec![(OsStr::new("NEXUS_TTS_TEXT"), Some(OsStr::new(text)))]);
+
+    // Caption-only timer without `sleep`
+    let silent = playback_command_with(&SapiEngine, "Nothing is spoken", true, None);
+    assert!(args(&silent).last().unwrap().starts_with("Start-Sleep -Milliseconds "));
+}
+
+#[test]
+fn test_piper_streams_to_player() {
+    let piper = PiperEngine { model: "voices/amy-low.onnx".to_string(), sample_rate: 16_000 };
+    let speak = piper.speak("Hello there", None);
+    let a = args(&speak);
+    assert!(a[1].contains("--output-raw | aplay"), "Played while synthesized: {}", a[1]);
+    assert_eq!(&a[2..], ["Hello there", "voices/amy-low.onnx", "16000", "default"]);
+    assert!(args(&piper.synthesize_to("Hi", Path::new("/tmp/p.wav"))).contains(&"/tmp/p.wav".to_string()));
+}
+
+#[test]
+fn test_cache_uses_engine() {
+    let now = Instant::now();
+    let mut cache = TtsCache::new(TtsCacheLimits { max_entries: 4, max_bytes: 1024, ttl: Duration::from_secs(60) });
+    cache.insert("Okay.", vec![1; 16], now);
+    let mut cache = cache.with_engine(TtsEngineKind::Espeak.engine());
+    assert!(cache.is_empty(), "Buffers from another engine dropped");
+
+    cache.insert("Okay.", vec![1; 16], now);
+    let cached = cache.playback_command("Okay.", false, None);
+    assert_eq!(program(&cached), "aplay");
+    assert!(args(&cached)[1].ends_with(".wav"));
+    assert_eq!(program(&cache.playback_command("Something new", false, None)), "espeak-ng");
+    assert_eq!(program(&cache.playback_command("Something new", true, None)), "sleep");
+}
@@ -0,0 +1,91 @@
+use nexus::kernel::audio::calibration::{CalibrationProfile, CalibrationStore, NOISE_WINDOW_MS};
+use nexus::kernel::audio::monitor::AudioMonitor;
+use nexus::kernel::audio::vad::VadConfig;
+use nexus::kernel::event::{AudioSignal, AudioSummary, InputContent};
+use nexus::kernel::reactor::Reactor;
+use std::path::PathBuf;
+
+mod common;
+use common::input;
+
+fn temp(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("nexus_vad_{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn reactor() -> Reactor {
+    common::builder()
+        .calibration(CalibrationStore::new())
+        .build()
+}
+
+// Chunks of 20ms at `rms` until the monitor reports something
+fn chunks_until_signal(monitor: &mut AudioMonitor, rms: f32) -> Option<(usize, AudioSignal)> {
+    (1..=100).find_map(|n| monitor.process_energy(rms, 20).map(|signal| (n, signal)))
+}
+
+#[test]
+fn test_toml_keeps_missing_defaults_and_clamps() {
+    let vad = VadConfig::parse("threshold_rms = 0.02\nmin_silence_ms = 700\n").unwrap();
+    assert_eq!(vad, VadConfig { threshold_rms: 0.02, min_silence_ms: 700, ..Default::default() });
+    assert_eq!(VadConfig::parse("").unwrap(), VadConfig::default());
+
+    let wild = VadConfig::parse("threshold_rms = -1.0\nmin_speech_ms = 0\ngrace_period_ms = 99999\nadaptive_factor = 0.5\n").unwrap();
+    assert!(wild.threshold_rms > 0.0);
+    assert_eq!((wild.min_speech_ms, wild.grace_period_ms, wild.adaptive_factor), (20, 1500, 1.0));
+    assert_eq!(VadConfig { threshold_rms: f32::NAN, ..Default::default() }.sanitized(), VadConfig::default());
+    assert!(VadConfig::parse("threshold_rms = \"loud\"").is_err());
+
+    let path = temp("config.toml");
+    assert!(VadConfig::load(&path).is_err(), "No file");
+    std::fs::write(&path, "min_speech_ms = 200\n").unwrap();
+    assert_eq!(VadConfig::load(&path).unwrap().min_speech_ms, 200);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_monitor_follows_its_config() {
+    let quick = VadConfig { threshold_rms: 0.1, min_speech_ms: 40, min_silence_ms: 200, ..Default::default() };
+    let mut monitor = AudioMonitor::with_config(16000, &quick);
+    assert_eq!(monitor.calibration(), CalibrationProfile::from_vad(&quick));
+    assert_eq!(chunks_until_signal(&mut monitor, 0.08), None, "Below the configured threshold");
+    assert_eq!(chunks_until_signal(&mut monitor, 0.2), Some((2, AudioSignal::SpeechStart)));
+    assert_eq!(chunks_until_signal(&mut monitor, 0.0), Some((10, AudioSignal::SpeechEnd)));
+
+    // Timing retuned in place
+    monitor.apply_vad(&VadConfig::default());
+    assert_eq!(chunks_until_signal(&mut monitor, 0.2), Some((6, AudioSignal::SpeechStart)));
+    assert_eq!(chunks_until_signal(&mut monitor, 0.0), Some((25, AudioSignal::SpeechEnd)));
+}
+
+#[test]
+fn test_config_update_at_runtime() {
+    let mut reactor = reactor();
+    let vad = VadConfig { threshold_rms: 0.08, min_speech_ms: 40, ..Default::default() };
+    reactor.tick_step(vec![input(InputContent::ConfigUpdate { vad })]);
+    assert_eq!(reactor.config.vad, vad);
+    assert_eq!(reactor.audio_monitor.calibration().threshold_rms, 0.08);
+    assert_eq!(chunks_until_signal(&mut reactor.audio_monitor, 0.05), None);
+    assert_eq!(chunks_until_signal(&mut reactor.audio_monitor, 0.1), Some((2, AudioSignal::SpeechStart)));
+
+    // Clamped on the way in
+    reactor.tick_step(vec![input(InputContent::ConfigUpdate { vad: VadConfig { min_silence_ms: 0, ..vad } })]);
+    assert_eq!(reactor.config.vad.min_silence_ms, 100);
+}
+
+#[test]
+fn test_calibrated_pair_keeps_its_thresholds() {
+    let mut reactor = reactor();
+    reactor.tick_step(vec![input(InputContent::CalibrateAudio)]);
+    for _ in 0..NOISE_WINDOW_MS / 20 {
+        reactor.tick_step(vec![input(InputContent::AudioSummary(AudioSummary::Envelope { rms: 0.02, duration_ms: 20 }))]);
+    }
+    let measured = reactor.audio_monitor.calibration();
+    assert!((measured.threshold_rms - 0.06).abs() < 1e-6);
+
+    let vad = VadConfig { threshold_rms: 0.01, min_speech_ms: 40, ..Default::default() };
+    reactor.tick_step(vec![input(InputContent::ConfigUpdate { vad })]);
+    assert_eq!(reactor.audio_monitor.calibration(), measured, "Measured, not configured");
+    assert_eq!(chunks_until_signal(&mut reactor.audio_monitor, 0.1), Some((2, AudioSignal::SpeechStart)), "Timing still applies");
+}
@@ -0,0 +1,108 @@
+use nexus::audio::asr::{AsrBackend, AsrError, Transcript};
+use nexus::audio::transcription::spawn_transcription;
+use nexus::audio::wake::{WakeSpotting, WakeWordDetector, WakeWordMatch};
+use nexus::kernel::event::{Event, InputContent, InputEvent};
+use nexus::kernel::presence::{CapturePermission, PresenceState};
+use nexus::kernel::reactor::Reactor;
+use nexus::kernel::state::StateDelta;
+use std::sync::Arc;
+
+mod common;
+
+// Always hears the same thing
+struct FixedAsr(&'static str);
+
+impl AsrBackend for FixedAsr {
+    fn name(&self) -> &'static str {
+        "fixed"
+    }
+
+    fn transcribe(&self, _frames: &[f32], _sample_rate: u32, _language: Option<&str>) -> Result<Transcript, AsrError> {
+        Ok(Transcript { text: self.0.to_string(), ..Default::default() })
+    }
+}
+
+fn reactor_in(presence: PresenceState) -> Reactor {
+    let mut reactor = common::reactor();
+    reactor.state.reduce(StateDelta::PresenceUpdate(presence));
+    reactor
+}
+
+fn wake() -> Event {
+    Event::Input(InputEvent { source: "ASR".to_string(), content: InputContent::WakeWord { phrase: "nexus".to_string() }, captured_at: None })
+}
+
+async fn transcribe(heard: &'static str, required: bool) -> Vec<InputContent> {
+    let (tx, mut rx) = tokio::sync::mpsc::channel(10);
+    let wake = WakeSpotting { detector: Arc::new(WakeWordDetector::default()), required };
+    spawn_transcription(Arc::new(FixedAsr(heard)), "seg_wake".to_string(), vec![0.1; 480], 16_000, None, Some(wake), tx).await.unwrap();
+    let mut sent = Vec::new();
+    while let Some(Event::Input(input)) = rx.recv().await {
+        sent.push(input.content);
+    }
+    sent
+}
+
+#[test]
+fn test_phrase_only_at_the_start() {
+    let detector = WakeWordDetector::default();
+    let heard = |text: &str| detector.detect(text).map(|m| m.trailing);
+    assert_eq!(detector.detect("Nexus, what time is it?"), Some(WakeWordMatch { phrase: "nexus".to_string(), trailing: "what time is it?".to_string() }));
+    assert_eq!(heard("Hey Nexus"), Some(String::new()));
+    assert_eq!(heard("ok hey nexus lights off"), Some("lights off".to_string()));
+    assert_eq!(heard("I told nexus yesterday"), None, "Mentioned, not called");
+    assert_eq!(heard("hey hey hey nexus"), None, "Too many greetings");
+    assert_eq!(heard("nexuses"), None);
+    assert_eq!(heard(""), None);
+
+    let custom = WakeWordDetector::new(&["hey computer", " ", "jarvis"]);
+    assert_eq!(custom.detect("Hey, computer.").map(|m| m.phrase), Some("hey computer".to_string()));
+    assert_eq!(custom.detect("computer").map(|m| m.phrase), None);
+    assert_eq!(custom.detect("Jarvis!").map(|m| m.phrase), Some("jarvis".to_string()));
+}
+
+#[test]
+fn test_wake_word_engages_from_dormant() {
+    let mut reactor = reactor_in(PresenceState::Dormant);
+    reactor.tick_step(vec![wake()]);
+    assert_eq!(reactor.state.presence(), PresenceState::Engaged);
+
+    // Already listening: one step
+    let mut reactor = reactor_in(PresenceState::Attentive);
+    reactor.tick_step(vec![wake()]);
+    assert_eq!(reactor.state.presence(), PresenceState::Engaged);
+
+    // A pause is only ended by the user
+    let mut reactor = reactor_in(PresenceState::Suspended);
+    reactor.tick_step(vec![wake()]);
+    assert_eq!(reactor.state.presence(), PresenceState::Suspended);
+}
+
+#[test]
+fn test_no_wake_without_microphone_access() {
+    let mut reactor = reactor_in(PresenceState::Dormant);
+    reactor.state.reduce(StateDelta::CapturePermissionChanged(CapturePermission::Denied));
+    reactor.tick_step(vec![wake()]);
+    assert_eq!(reactor.state.presence(), PresenceState::Dormant);
+}
+
+#[tokio::test]
+async fn test_wake_word_sent_ahead_of_the_transcript() {
+    let sent = transcribe("Hey Nexus, lights off", false).await;
+    assert_eq!(sent.len(), 2, "{:?}", sent);
+    assert!(matches!(&sent[0], InputContent::WakeWord { phrase } if phrase == "nexus"));
+    assert!(matches!(&sent[1], InputContent::ProvisionalText { content, .. } if content == "lights off"), "Without the phrase");
+
+    // A bare wake phrase: nothing to answer
+    let sent = transcribe("Nexus.", false).await;
+    assert!(matches!(sent.as_slice(), [InputContent::WakeWord { .. }]), "{:?}", sent);
+    assert_eq!(transcribe("lights off", false).await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_dormant_auto_mic_needs_the_wake_word() {
+    // Overheard while Dormant: never reaches the kernel
+    assert!(transcribe("lights off", true).await.is_empty());
+    let sent = transcribe("nexus lights off", true).await;
+    assert!(matches!(sent.as_slice(), [InputContent::WakeWord { .. }, InputContent::ProvisionalText { .. }]), "{:?}", sent);
+}
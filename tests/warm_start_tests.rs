@@ -0,0 +1,129 @@
+use nexus::kernel::context::ContextId;
+use nexus::kernel::crystallizer::CrystallizationDecision;
+use nexus::kernel::event::{AudioSignal, Event, InputContent, InputEvent};
+use nexus::kernel::intent::long_horizon::{IntentStatus, LongHorizonIntent};
+use nexus::kernel::intent::types::IntentHypothesis;
+use nexus::kernel::presence::PresenceState;
+use nexus::kernel::reactor::{Reactor, ReactorConfig};
+use nexus::kernel::scheduler::SideEffect;
+use nexus::kernel::state::StateDelta;
+use nexus::kernel::time::Tick;
+use nexus::kernel::warm_start::{WarmStart, WARM_START_GAP_TICKS, WARM_START_HOLD_TICKS};
+use nexus::outputs::realizer::realize_warm_start;
+
+mod common;
+
+fn reactor(warm_start: bool) -> Reactor {
+    common::builder().config(ReactorConfig { warm_start, ..common::config() }).build()
+}
+
+fn hold(reactor: &mut Reactor, id: &str, hypothesis: IntentHypothesis) {
+    reactor.state.reduce(StateDelta::LongHorizonIntentUpdate(LongHorizonIntent {
+        id: id.to_string(),
+        hypothesis,
+        source_symbol_ids: vec![],
+        created_at: Tick { frame: 1 },
+        last_active_at: Tick { frame: 1 },
+        last_updated_at: Tick { frame: 1 },
+        suspended_at: Some(Tick { frame: 1 }),
+        decay_score: 0.8,
+        status: IntentStatus::Suspended,
+        context: ContextId::default(),
+    }));
+}
+
+/// Listening, idle since the start of the session, now at `frame`.
+fn asleep_until(reactor: &mut Reactor, frame: u64) {
+    reactor.state.reduce(StateDelta::PresenceUpdate(PresenceState::Attentive));
+    reactor.state.reduce(StateDelta::Tick(Tick { frame }));
+    reactor.tick.frame = frame;
+}
+
+fn audio(signal: AudioSignal) -> Event {
+    Event::Input(InputEvent { source: "Test".to_string(), content: InputContent::Audio(signal), captured_at: None })
+}
+
+fn spoken(effects: &[SideEffect]) -> Vec<String> {
+    effects.iter().filter_map(|e| match e {
+        SideEffect::SpawnAudio(_, text) => Some(text.clone()),
+        _ => None,
+    }).collect()
+}
+
+/// The user starts talking (wake), then stops. Everything spoken across both ticks.
+fn wake(reactor: &mut Reactor) -> Vec<String> {
+    let mut said = spoken(&reactor.tick_step(vec![audio(AudioSignal::SpeechStart)]));
+    assert_eq!(reactor.state.presence(), PresenceState::Engaged);
+    said.extend(spoken(&reactor.tick_step(vec![audio(AudioSignal::SpeechEnd)])));
+    said
+}
+
+#[test]
+fn test_realization() {
+    let one = WarmStart { held: 1, lead: Some(("i1".to_string(), IntentHypothesis::Inquiry)), deferred_offer: false };
+    assert_eq!(
+        realize_warm_start(&one, &CrystallizationDecision::AllowHard),
+        "Welcome back. While you were away I kept one thing on hold: you were asking about something."
+    );
+    let deferred_only = WarmStart { held: 0, lead: None, deferred_offer: true };
+    assert!(realize_warm_start(&deferred_only, &CrystallizationDecision::AllowPartial).contains("something I wanted to bring up"));
+    let many = WarmStart { held: 2, deferred_offer: true, ..one };
+    assert!(realize_warm_start(&many, &CrystallizationDecision::AllowHard).contains("3 things"));
+    assert!(realize_warm_start(&many, &CrystallizationDecision::Deny).is_empty());
+}
+
+#[tokio::test]
+async fn test_wake_after_long_gap_summarises_held_intents() {
+    let mut reactor = reactor(true);
+    hold(&mut reactor, "trip", IntentHypothesis::Command);
+    asleep_until(&mut reactor, WARM_START_GAP_TICKS + 10);
+
+    // Not over the user's voice: it waits for the gate
+    let effects = reactor.tick_step(vec![audio(AudioSignal::SpeechStart)]);
+    assert!(spoken(&effects).is_empty());
+    let effects = reactor.tick_step(vec![audio(AudioSignal::SpeechEnd)]);
+    let said = spoken(&effects);
+    assert_eq!(said.len(), 1);
+    assert!(said[0].contains("on hold: you wanted something done"), "{}", said[0]);
+    assert!(reactor.state.active_outputs().values().any(|o| o.parent_id.as_deref() == Some("trip")));
+
+    // Once per wake
+    assert!(spoken(&reactor.tick_step(vec![])).is_empty());
+}
+
+#[tokio::test]
+async fn test_no_summary_without_gap_or_held_work() {
+    // Short gap
+    let mut reactor = reactor(true);
+    hold(&mut reactor, "trip", IntentHypothesis::Command);
+    asleep_until(&mut reactor, WARM_START_GAP_TICKS / 2);
+    assert!(wake(&mut reactor).is_empty());
+
+    // Nothing on hold
+    let mut reactor = self::reactor(true);
+    asleep_until(&mut reactor, WARM_START_GAP_TICKS + 10);
+    assert!(wake(&mut reactor).is_empty());
+
+    // Toggled off
+    let mut reactor = self::reactor(false);
+    hold(&mut reactor, "trip", IntentHypothesis::Command);
+    asleep_until(&mut reactor, WARM_START_GAP_TICKS + 10);
+    assert!(wake(&mut reactor).is_empty());
+}
+
+#[tokio::test]
+async fn test_budget_applies_and_summary_expires() {
+    let mut reactor = reactor(true);
+    hold(&mut reactor, "trip", IntentHypothesis::Command);
+    let frame = WARM_START_GAP_TICKS + 10;
+    asleep_until(&mut reactor, frame);
+    // Something proactive was just said
+    assert!(reactor.proactive_budget.try_spend(Tick { frame }));
+
+    assert!(wake(&mut reactor).is_empty());
+    // Budget is back, but the wake is long past
+    let late = frame + reactor.proactive_budget.window_ticks.max(WARM_START_HOLD_TICKS) + 10;
+    reactor.state.reduce(StateDelta::Tick(Tick { frame: late }));
+    reactor.tick.frame = late;
+    assert!(spoken(&reactor.tick_step(vec![])).is_empty(), "Dropped, not spoken late");
+}